@@ -0,0 +1,62 @@
+//! Times comparisons of large arrays of floats, to show the effect of the
+//! `simd` feature, which compares their elements in chunks:
+//!
+//! ```text
+//! cargo bench --bench elements
+//! cargo bench --bench elements --features simd
+//! ```
+//!
+//! Each line gives the average time taken by a single comparison.
+
+use float_eq::FloatEqAll;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const N: usize = 4096;
+const RUNS: u32 = 2_000;
+
+fn time<F: FnMut() -> bool>(name: &str, mut compare: F) {
+    // warm up, and make sure the comparison does not fail part way through
+    assert!(compare());
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(compare());
+    }
+    let each: Duration = start.elapsed() / RUNS;
+    println!("{:<28} {:>10.2?}", name, each);
+}
+
+fn main() {
+    let a32: Vec<f32> = (0..N).map(|i| (i as f32).sin()).collect();
+    let b32: Vec<f32> = a32.iter().map(|x| x * (1.0 + f32::EPSILON)).collect();
+    let a64: Vec<f64> = (0..N).map(|i| (i as f64).sin()).collect();
+    let b64: Vec<f64> = a64.iter().map(|x| x * (1.0 + f64::EPSILON)).collect();
+
+    let mut arr32 = ([0.0f32; N], [0.0f32; N]);
+    arr32.0.copy_from_slice(&a32);
+    arr32.1.copy_from_slice(&b32);
+
+    println!(
+        "simd feature {}",
+        if cfg!(feature = "simd") {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    );
+    time("[f32; 4096] eq_ulps_all", || {
+        black_box(&arr32.0).eq_ulps_all(black_box(&arr32.1), &2)
+    });
+    time("[f32; 4096] eq_abs_all", || {
+        black_box(&arr32.0).eq_abs_all(black_box(&arr32.1), &1e-6)
+    });
+    time("[f32] eq_ulps_all", || {
+        black_box(&a32[..]).eq_ulps_all(black_box(&b32[..]), &2)
+    });
+    time("[f64] eq_ulps_all", || {
+        black_box(&a64[..]).eq_ulps_all(black_box(&b64[..]), &2)
+    });
+    time("[f64] eq_abs_all", || {
+        black_box(&a64[..]).eq_abs_all(black_box(&b64[..]), &1e-12)
+    });
+}
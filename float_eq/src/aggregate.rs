@@ -0,0 +1,99 @@
+use crate::FloatPrimitive;
+
+/// The largest absolute difference between corresponding values of two
+/// slices, as checked by `max_abs <= tol` in [`float_eq!`].
+///
+/// Values that are equal, including the same infinity, differ by zero. Returns
+/// `None` if the slices have different lengths, zero if they are empty, and
+/// NaN if any value is NaN.
+///
+/// # Examples
+/// ```
+/// # use float_eq::max_abs_diff;
+/// assert_eq!(max_abs_diff(&[1.0f32, 2.0, 3.0], &[1.5, 2.0, 2.25]), Some(0.75));
+/// assert_eq!(max_abs_diff(&[1.0f32, 2.0], &[1.0]), None);
+/// ```
+///
+/// [`float_eq!`]: macro.float_eq.html
+pub fn max_abs_diff<T: FloatPrimitive>(a: &[T], b: &[T]) -> Option<T> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let mut max = T::ZERO;
+    for diff in a.iter().zip(b).map(|(a, b)| abs_diff(*a, *b)) {
+        if diff.is_nan_value() {
+            return Some(diff);
+        }
+        if diff > max {
+            max = diff;
+        }
+    }
+    Some(max)
+}
+
+/// The mean absolute difference between corresponding values of two slices,
+/// as checked by `mean_abs <= tol` in [`float_eq!`].
+///
+/// Values that are equal, including the same infinity, differ by zero. Returns
+/// `None` if the slices have different lengths, zero if they are empty, and
+/// NaN if any value is NaN.
+///
+/// # Examples
+/// ```
+/// # use float_eq::mean_abs_diff;
+/// assert_eq!(mean_abs_diff(&[1.0f32, 2.0, 3.0], &[1.5, 2.0, 2.25]), Some(0.41666666));
+/// assert_eq!(mean_abs_diff::<f32>(&[], &[]), Some(0.0));
+/// ```
+///
+/// [`float_eq!`]: macro.float_eq.html
+pub fn mean_abs_diff<T: FloatPrimitive>(a: &[T], b: &[T]) -> Option<T> {
+    if a.len() != b.len() {
+        return None;
+    }
+    if a.is_empty() {
+        return Some(T::ZERO);
+    }
+    let sum = a
+        .iter()
+        .zip(b)
+        .fold(T::ZERO, |sum, (a, b)| sum + abs_diff(*a, *b));
+    Some(sum / T::from_ratio(a.len() as u64, 1))
+}
+
+/// The largest difference in [ULPs] between corresponding values of two
+/// slices, as checked by `max_ulps <= tol` in [`float_eq!`].
+///
+/// Returns `None` if the slices have different lengths, or if any pair of
+/// values has no ULPs difference because one is NaN or they have differing
+/// signs, as for [`FloatBits::ulps_diff`]. Returns zero if they are empty.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{max_ulps_diff, FloatBits};
+/// let a = [1.0f32, 2.0];
+/// let b = [f32::from_float_bits(1.0f32.to_float_bits() + 3), 2.0];
+/// assert_eq!(max_ulps_diff(&a, &b), Some(3));
+/// assert_eq!(max_ulps_diff(&a, &[1.0, -2.0]), None);
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`float_eq!`]: macro.float_eq.html
+/// [`FloatBits::ulps_diff`]: trait.FloatBits.html#method.ulps_diff
+pub fn max_ulps_diff<T: FloatPrimitive>(a: &[T], b: &[T]) -> Option<T::Bits> {
+    if a.len() != b.len() {
+        return None;
+    }
+    a.iter().zip(b).try_fold(T::Bits::default(), |max, (a, b)| {
+        a.ulps_diff(*b).map(|diff| max.max(diff))
+    })
+}
+
+#[inline]
+#[allow(clippy::float_cmp)]
+fn abs_diff<T: FloatPrimitive>(a: T, b: T) -> T {
+    if a == b {
+        T::ZERO
+    } else {
+        (a - b).abs_value()
+    }
+}
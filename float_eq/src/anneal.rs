@@ -0,0 +1,79 @@
+/// The outcome of retrying a failed check with wider tolerances, as reported
+/// by [`assert_float_eq_annealed!`].
+///
+/// Numerical tests that only fail in CI, on another platform or with another
+/// compiler are often out by a small factor, and finding out how small is the
+/// first step in deciding whether the tolerance or the code is wrong. When
+/// annealing is [enabled], a failing [`assert_float_eq_annealed!`] retries
+/// the check at each of the [`FACTORS`] times its tolerance in turn, and adds
+/// the outcome to the failure message, so that a single CI run shows how far
+/// out the values are. Annealing is enabled by setting the `FLOAT_EQ_ANNEAL`
+/// environment variable to `1` in `std` builds. The assert still fails either
+/// way.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, Annealed};
+/// let (a, b) = (1.0f64, 1.3);
+///
+/// let annealed = Annealed::retry(&0.1, |t| t * 2.0, |t| float_eq!(a, b, abs <= *t));
+/// assert_eq!(annealed, Annealed::PassesAt(4));
+///
+/// let annealed = Annealed::retry(&0.01, |t| t * 2.0, |t| float_eq!(a, b, abs <= *t));
+/// assert_eq!(annealed, Annealed::FailsAt(8));
+/// ```
+///
+/// [`assert_float_eq_annealed!`]: macro.assert_float_eq_annealed.html
+/// [enabled]: #method.enabled
+/// [`FACTORS`]: #associatedconstant.FACTORS
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Annealed {
+    /// The check passes at this many times the tolerance, but no fewer.
+    PassesAt(u32),
+    /// The check still fails at this many times the tolerance, the largest of
+    /// the [`FACTORS`].
+    ///
+    /// [`FACTORS`]: #associatedconstant.FACTORS
+    FailsAt(u32),
+}
+
+impl Annealed {
+    /// The multiples of the tolerance that a failed check is retried at, in
+    /// order. Each is twice the one before.
+    pub const FACTORS: [u32; 3] = [2, 4, 8];
+
+    /// Whether failing asserts are annealed, which is when the
+    /// `FLOAT_EQ_ANNEAL` environment variable is set to `1`.
+    ///
+    /// Always false in `no_std` builds.
+    pub fn enabled() -> bool {
+        #[cfg(feature = "std")]
+        {
+            if let Some(v) = std::env::var_os("FLOAT_EQ_ANNEAL") {
+                return v == "1";
+            }
+        }
+        false
+    }
+
+    /// Retries a `check` at each of the [`FACTORS`] times `tol`, until it
+    /// passes.
+    ///
+    /// Since each factor is twice the last, the tolerances are found by
+    /// applying `double` to `tol` once for the first, then to the result for
+    /// each one after.
+    ///
+    /// [`FACTORS`]: #associatedconstant.FACTORS
+    pub fn retry<T>(tol: &T, double: impl Fn(&T) -> T, check: impl Fn(&T) -> bool) -> Self {
+        let mut tol = double(tol);
+        for (i, factor) in Self::FACTORS.iter().enumerate() {
+            if i > 0 {
+                tol = double(&tol);
+            }
+            if check(&tol) {
+                return Annealed::PassesAt(*factor);
+            }
+        }
+        Annealed::FailsAt(Self::FACTORS[Self::FACTORS.len() - 1])
+    }
+}
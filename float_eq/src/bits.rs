@@ -0,0 +1,224 @@
+use core::fmt;
+use core::ops::Sub;
+
+/// Safe access to the bit representation of a floating point type, which is
+/// what [ULPs] comparisons are measured in.
+///
+/// This is implemented for `f32` and `f64`, and underpins their
+/// [`FloatEq::eq_ulps`] and [`AssertFloatEq::debug_ulps_diff`] implementations.
+/// Types that wrap a primitive, or that provide a floating point format of
+/// their own, may use or implement it so that their ULPs comparisons follow
+/// the same rules without reinterpreting any bits themselves.
+///
+/// # Examples
+/// ```
+/// # use float_eq::FloatBits;
+/// let a = 1.0f32;
+/// let b = f32::from_float_bits(a.to_float_bits() + 2);
+///
+/// assert_eq!(a.ulps_diff(b), Some(2));
+/// assert!(a.eq_ulps_within(b, 2));
+/// assert!(!a.eq_ulps_within(b, 1));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`FloatEq::eq_ulps`]: trait.FloatEq.html#tymethod.eq_ulps
+/// [`AssertFloatEq::debug_ulps_diff`]: trait.AssertFloatEq.html#tymethod.debug_ulps_diff
+pub trait FloatBits: Copy + PartialEq {
+    /// The unsigned integer type of the same width, used to measure ULPs.
+    type Bits: Copy + Ord + Default + Sub<Output = Self::Bits> + fmt::Debug + fmt::Display;
+
+    /// The raw bits of `self`.
+    fn to_float_bits(self) -> Self::Bits;
+
+    /// The value with the given raw bits.
+    fn from_float_bits(bits: Self::Bits) -> Self;
+
+    /// Whether `self` is NaN.
+    fn is_nan_value(self) -> bool;
+
+    /// Whether the sign bit of `self` is clear, including for positive zero
+    /// and NaNs without their sign bit set.
+    fn is_sign_positive_value(self) -> bool;
+
+    /// The difference between `self` and `other` in ULPs.
+    ///
+    /// Returns:
+    /// - `Some(0)` if both are equal, including `0.0` and `-0.0`
+    /// - `None` if either is NaN
+    /// - `None` if they have differing signs
+    /// - `Some(bitwise-difference)` otherwise
+    #[inline]
+    fn ulps_diff(self, other: Self) -> Option<Self::Bits> {
+        if self == other {
+            Some(Self::Bits::default())
+        } else if self.is_nan_value()
+            || other.is_nan_value()
+            || self.is_sign_positive_value() != other.is_sign_positive_value()
+        {
+            None
+        } else {
+            let a = self.to_float_bits();
+            let b = other.to_float_bits();
+            Some(a.max(b) - a.min(b))
+        }
+    }
+
+    /// Whether `self` and `other` are no more than `tol` ULPs apart.
+    ///
+    /// NaNs are never equal, and values of differing signs are only equal if
+    /// they are both zero.
+    #[inline]
+    fn eq_ulps_within(self, other: Self, tol: Self::Bits) -> bool {
+        if self.is_nan_value() || other.is_nan_value() {
+            false
+        } else if self.is_sign_positive_value() != other.is_sign_positive_value() {
+            self == other
+        } else {
+            let a = self.to_float_bits();
+            let b = other.to_float_bits();
+            a.max(b) - a.min(b) <= tol
+        }
+    }
+}
+
+/// A type whose representable values map in order onto an integer, so that
+/// neighbouring values differ by exactly one.
+///
+/// This is the simplest way for a custom number format, such as a fixed point
+/// "soft float", to support [ULPs] comparisons: implement this trait, then
+/// forward [`FloatEq::eq_ulps`] to [`eq_ulps_by_bits`] and
+/// [`AssertFloatEq::debug_ulps_diff`] to [`ulps_diff_by_bits`].
+///
+/// Unlike the sign-magnitude bits of [`FloatBits`], the bit pattern must be an
+/// unsigned integer ordered the same way as the values themselves. IEEE floats
+/// do not meet that requirement, so `f32` and `f64` do not implement it.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{eq_ulps_by_bits, ulps_diff_by_bits, FloatBitPattern};
+/// // 16.16 fixed point
+/// struct Fixed(i32);
+///
+/// impl FloatBitPattern for Fixed {
+///     type Bits = u32;
+///
+///     fn to_bits(&self) -> u32 {
+///         // flip the sign bit so that negative values are ordered first
+///         (self.0 as u32) ^ (1 << 31)
+///     }
+/// }
+///
+/// let a = Fixed(-1);
+/// let b = Fixed(2);
+/// assert_eq!(ulps_diff_by_bits(&a, &b), 3);
+/// assert!(eq_ulps_by_bits(&a, &b, &3));
+/// assert!(!eq_ulps_by_bits(&a, &b, &2));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`FloatEq::eq_ulps`]: trait.FloatEq.html#tymethod.eq_ulps
+/// [`AssertFloatEq::debug_ulps_diff`]: trait.AssertFloatEq.html#tymethod.debug_ulps_diff
+/// [`eq_ulps_by_bits`]: fn.eq_ulps_by_bits.html
+/// [`ulps_diff_by_bits`]: fn.ulps_diff_by_bits.html
+/// [`FloatBits`]: trait.FloatBits.html
+pub trait FloatBitPattern {
+    /// The integer type of the bit pattern.
+    type Bits: Copy + Ord + Sub<Output = Self::Bits>;
+
+    /// The bit pattern of `self`.
+    fn to_bits(&self) -> Self::Bits;
+}
+
+/// The difference between two values in ULPs, as measured by their
+/// [`FloatBitPattern`].
+///
+/// [`FloatBitPattern`]: trait.FloatBitPattern.html
+#[inline]
+pub fn ulps_diff_by_bits<T: ?Sized + FloatBitPattern>(a: &T, b: &T) -> T::Bits {
+    let a = a.to_bits();
+    let b = b.to_bits();
+    a.max(b) - a.min(b)
+}
+
+/// Whether two values are no more than `tol` ULPs apart, as measured by their
+/// [`FloatBitPattern`].
+///
+/// [`FloatBitPattern`]: trait.FloatBitPattern.html
+#[inline]
+pub fn eq_ulps_by_bits<T: ?Sized + FloatBitPattern>(a: &T, b: &T, tol: &T::Bits) -> bool {
+    ulps_diff_by_bits(a, b) <= *tol
+}
+
+/// A measure of the distance between two values in units in the last place,
+/// for number formats that define their own.
+///
+/// This generalises [ULPs] beyond the IEEE formats, so that a type whose
+/// precision is not uniform in the same way, such as a posit, may still be
+/// compared in terms of how many representable values lie between two of its
+/// values. Implementations of [`FloatEq::eq_ulps`] may then forward to
+/// [`eq_ulps_by_metric`].
+///
+/// It is implemented by `f32` and `f64`, following the same rules as
+/// [`FloatBits::ulps_diff`], and by every type that implements
+/// [`FloatBitPattern`].
+///
+/// # Examples
+/// ```
+/// # use float_eq::{eq_ulps_by_metric, UlpsMetric};
+/// // An 8-bit posit, whose bit patterns are ordered as two's complement
+/// // integers, with a single "not a real" value.
+/// struct Posit8(u8);
+///
+/// impl UlpsMetric for Posit8 {
+///     type Ulps = u8;
+///
+///     fn ulps_between(&self, other: &Self) -> Option<u8> {
+///         const NAR: u8 = 0x80;
+///         if self.0 == NAR || other.0 == NAR {
+///             None
+///         } else {
+///             Some((self.0 as i8).abs_diff(other.0 as i8))
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Posit8(0x40).ulps_between(&Posit8(0xc0)), Some(128));
+/// assert!(eq_ulps_by_metric(&Posit8(0x40), &Posit8(0x42), &2));
+/// assert!(!eq_ulps_by_metric(&Posit8(0x80), &Posit8(0x80), &u8::MAX));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`FloatEq::eq_ulps`]: trait.FloatEq.html#tymethod.eq_ulps
+/// [`eq_ulps_by_metric`]: fn.eq_ulps_by_metric.html
+/// [`FloatBits::ulps_diff`]: trait.FloatBits.html#method.ulps_diff
+/// [`FloatBitPattern`]: trait.FloatBitPattern.html
+pub trait UlpsMetric {
+    /// The type used to count ULPs.
+    type Ulps: Copy + Ord + fmt::Debug;
+
+    /// The number of ULPs between `self` and `other`, or `None` if they are
+    /// not comparable, for example because one of them is NaN.
+    fn ulps_between(&self, other: &Self) -> Option<Self::Ulps>;
+}
+
+impl<T: FloatBitPattern> UlpsMetric for T
+where
+    T::Bits: fmt::Debug,
+{
+    type Ulps = T::Bits;
+
+    #[inline]
+    fn ulps_between(&self, other: &Self) -> Option<Self::Ulps> {
+        Some(ulps_diff_by_bits(self, other))
+    }
+}
+
+/// Whether two values are no more than `tol` ULPs apart, as measured by their
+/// [`UlpsMetric`]. Values that are not comparable are never equal.
+///
+/// [`UlpsMetric`]: trait.UlpsMetric.html
+#[inline]
+pub fn eq_ulps_by_metric<T: ?Sized + UlpsMetric>(a: &T, b: &T, tol: &T::Ulps) -> bool {
+    matches!(a.ulps_between(b), Some(diff) if diff <= *tol)
+}
@@ -0,0 +1,70 @@
+use crate::FloatEq;
+use core::ops::{Bound, RangeBounds};
+
+/// Whether a range contains `x`, where `x` is compared with the endpoints of
+/// the range using an [absolute tolerance comparison].
+///
+/// The tolerance widens an included endpoint and narrows an excluded one: a
+/// value that is equal to an endpoint within `tol` is always contained if the
+/// endpoint is included, such as the `b` of `a..=b`, and never if it is
+/// excluded, such as the `b` of `a..b`. This works with any kind of range, such
+/// as `a..b`, `a..=b`, `a..` or a pair of [`Bound`]s. NaNs are only contained
+/// by ranges without either endpoint.
+///
+/// # Examples
+/// ```
+/// # use float_eq::approx_contains;
+/// let x = 0.1 + 0.2;
+/// assert!(!(0.0..=0.3).contains(&x));
+/// assert!(approx_contains(&(0.0..=0.3), &x, 1e-12));
+///
+/// // an excluded endpoint also excludes values just below it
+/// assert!(approx_contains(&(0.0..0.3), &0.25, 1e-12));
+/// assert!(!approx_contains(&(0.0..0.3), &0.299_999_999_999_999_9, 1e-12));
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+/// [`Bound`]: https://doc.rust-lang.org/core/ops/enum.Bound.html
+#[inline]
+pub fn approx_contains<T, R>(range: &R, x: &T, tol: T::Tol) -> bool
+where
+    T: PartialOrd + FloatEq,
+    T::Tol: Sized,
+    R: ?Sized + RangeBounds<T>,
+{
+    approx_contains_by(range, x, tol, FloatEq::eq_abs)
+}
+
+/// Whether a range contains `x`, where `check` decides whether `x` is equal to
+/// an endpoint given `tol`.
+///
+/// See [`approx_contains`] for details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{approx_contains_by, FloatEq};
+/// let end = 1.0f32;
+/// let x = f32::from_bits(end.to_bits() + 1);
+/// assert!(approx_contains_by(&(0.0..=end), &x, 1, |a, b, tol| a.eq_ulps(b, tol)));
+/// assert!(!approx_contains_by(&(0.0..=end), &x, 0, |a, b, tol| a.eq_ulps(b, tol)));
+/// ```
+///
+/// [`approx_contains`]: fn.approx_contains.html
+pub fn approx_contains_by<T, R, Tol, F>(range: &R, x: &T, tol: Tol, mut check: F) -> bool
+where
+    T: PartialOrd,
+    R: ?Sized + RangeBounds<T>,
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    let above_start = match range.start_bound() {
+        Bound::Included(start) => x >= start || check(x, start, &tol),
+        Bound::Excluded(start) => x > start && !check(x, start, &tol),
+        Bound::Unbounded => true,
+    };
+    above_start
+        && match range.end_bound() {
+            Bound::Included(end) => x <= end || check(x, end, &tol),
+            Bound::Excluded(end) => x < end && !check(x, end, &tol),
+            Bound::Unbounded => true,
+        }
+}
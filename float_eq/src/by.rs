@@ -0,0 +1,292 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, UlpsDiffReason};
+use crate::{CheckSet, FloatEq, FloatEqAll, FloatEqBits, UlpsTol};
+use core::fmt;
+
+/// A value that is compared via a projection of itself.
+///
+/// `By` applies its projection to both operands before they are compared, so
+/// that a single aspect of a value may be checked, for example the length of
+/// a vector or the real part of a complex number. The tolerance and debug
+/// output of a comparison are those of the projected type. Projections may
+/// differ between operands, as long as their results may be compared.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "checks-only"))]
+/// # use float_eq::assert_float_eq;
+/// # use float_eq::By;
+/// fn len(v: &[f32; 2]) -> f32 {
+///     (v[0] * v[0] + v[1] * v[1]).sqrt()
+/// }
+///
+/// let a = By::new([3.0f32, 4.0], len);
+/// let b = By::new([0.0f32, 5.000_001], len);
+/// # #[cfg(not(feature = "checks-only"))]
+/// assert_float_eq!(a, b, abs <= 0.000_01);
+/// ```
+///
+/// On failure, both the original and projected values are displayed:
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `float_eq!(left, right, abs <= t)`
+///         left: `By { value: [3.0, 4.0], projected: 5.0 }`,
+///        right: `By { value: [0.0, 6.0], projected: 6.0 }`,
+///     abs_diff: `1.0`,
+///    ulps_diff: `Some(1048576)`,
+///      [abs] t: `0.1`', src/main.rs:9:1
+/// ```
+#[derive(Clone, Copy)]
+pub struct By<T, F> {
+    value: T,
+    projection: F,
+}
+
+impl<T, F, P> By<T, F>
+where
+    F: Fn(&T) -> P,
+{
+    /// Wrap `value` so that it is compared via `projection`.
+    #[inline]
+    pub fn new(value: T, projection: F) -> Self {
+        By { value, projection }
+    }
+
+    /// The wrapped value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The result of applying the projection to the wrapped value.
+    #[inline]
+    pub fn projected(&self) -> P {
+        (self.projection)(&self.value)
+    }
+
+    /// Unwrap the original value.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T, F, P> fmt::Debug for By<T, F>
+where
+    T: fmt::Debug,
+    F: Fn(&T) -> P,
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("By")
+            .field("value", &self.value)
+            .field("projected", &self.projected())
+            .finish()
+    }
+}
+
+impl<TA, FA, PA, TB, FB, PB> FloatEq<By<TB, FB>> for By<TA, FA>
+where
+    FA: Fn(&TA) -> PA,
+    FB: Fn(&TB) -> PB,
+    PA: FloatEq<PB>,
+{
+    type Tol = PA::Tol;
+    const SUPPORTED_CHECKS: CheckSet = PA::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &By<TB, FB>, tol: &Self::Tol) -> bool {
+        self.projected().eq_abs(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &By<TB, FB>, tol: &Self::Tol) -> bool {
+        self.projected().eq_rmax(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &By<TB, FB>, tol: &Self::Tol) -> bool {
+        self.projected().eq_rmin(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &By<TB, FB>, tol: &Self::Tol) -> bool {
+        self.projected().eq_r1st(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &By<TB, FB>, tol: &Self::Tol) -> bool {
+        self.projected().eq_r2nd(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &By<TB, FB>, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.projected().eq_ulps(&other.projected(), tol)
+    }
+}
+
+impl<TA, FA, PA, TB, FB, PB> FloatEqAll<By<TB, FB>> for By<TA, FA>
+where
+    FA: Fn(&TA) -> PA,
+    FB: Fn(&TB) -> PB,
+    PA: FloatEqAll<PB>,
+{
+    type AllTol = PA::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> bool {
+        self.projected().eq_abs_all(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> bool {
+        self.projected().eq_rmax_all(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> bool {
+        self.projected().eq_rmin_all(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> bool {
+        self.projected().eq_r1st_all(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> bool {
+        self.projected().eq_r2nd_all(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &By<TB, FB>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.projected().eq_ulps_all(&other.projected(), tol)
+    }
+}
+
+impl<TA, FA, PA, TB, FB, PB> FloatEqBits<By<TB, FB>> for By<TA, FA>
+where
+    FA: Fn(&TA) -> PA,
+    FB: Fn(&TB) -> PB,
+    PA: FloatEqBits<PB>,
+{
+    #[inline]
+    fn eq_bits(&self, other: &By<TB, FB>) -> bool {
+        self.projected().eq_bits(&other.projected())
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<TA, FA, PA, TB, FB, PB> AssertFloatEq<By<TB, FB>> for By<TA, FA>
+where
+    FA: Fn(&TA) -> PA,
+    FB: Fn(&TB) -> PB,
+    PA: AssertFloatEq<PB>,
+{
+    type DebugAbsDiff = PA::DebugAbsDiff;
+    type DebugTol = PA::DebugTol;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &By<TB, FB>) -> Self::DebugAbsDiff {
+        self.projected().debug_abs_diff(&other.projected())
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &By<TB, FB>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        self.projected().debug_ulps_diff(&other.projected())
+    }
+
+    #[inline]
+    fn debug_categories(&self, other: &By<TB, FB>) -> Option<DebugCategories> {
+        self.projected().debug_categories(&other.projected())
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &By<TB, FB>) -> Option<UlpsDiffReason> {
+        self.projected().debug_ulps_diff_reason(&other.projected())
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &By<TB, FB>, tol: &Self::Tol) -> Self::DebugTol {
+        self.projected().debug_abs_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &By<TB, FB>, tol: &Self::Tol) -> Self::DebugTol {
+        self.projected().debug_rmax_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &By<TB, FB>, tol: &Self::Tol) -> Self::DebugTol {
+        self.projected().debug_rmin_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &By<TB, FB>, tol: &Self::Tol) -> Self::DebugTol {
+        self.projected().debug_r1st_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &By<TB, FB>, tol: &Self::Tol) -> Self::DebugTol {
+        self.projected().debug_r2nd_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &By<TB, FB>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        self.projected().debug_ulps_tol(&other.projected(), tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<TA, FA, PA, TB, FB, PB> AssertFloatEqAll<By<TB, FB>> for By<TA, FA>
+where
+    FA: Fn(&TA) -> PA,
+    FB: Fn(&TB) -> PB,
+    PA: AssertFloatEqAll<PB>,
+{
+    type AllDebugTol = PA::AllDebugTol;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.projected().debug_abs_all_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.projected().debug_rmax_all_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.projected().debug_rmin_all_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.projected().debug_r1st_all_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &By<TB, FB>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.projected().debug_r2nd_all_tol(&other.projected(), tol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &By<TB, FB>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        self.projected().debug_ulps_all_tol(&other.projected(), tol)
+    }
+}
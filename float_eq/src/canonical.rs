@@ -0,0 +1,122 @@
+use crate::FloatPrimitive;
+use core::hash::{Hash, Hasher};
+
+/// Map `x` to the centre of the bucket of width `tol` that contains it, so
+/// that approximately equal values share a single representative.
+///
+/// The buckets are centred on the multiples of `tol`, and every value in a
+/// bucket is mapped to its centre. Negative zero is mapped to positive zero,
+/// and every NaN to the same NaN, so that equal representatives also have
+/// equal bits and may be hashed. Infinities, and values whose buckets cannot
+/// be represented because `tol` is so small relative to them, are unchanged,
+/// as are all values if `tol` is not positive and finite.
+///
+/// # Interaction with the comparison traits
+/// Canonicalization is consistent with an [absolute tolerance comparison] in
+/// one direction only:
+/// - A value is always equal to its representative within `tol / 2`, give or
+///   take the round-off of calculating it.
+/// - Two values with the same representative are always equal within `tol`.
+/// - Two values that are equal within `tol` may still have different
+///   representatives, if they lie either side of the boundary between two
+///   buckets, however small the difference between them.
+///
+/// This means that grouping, hashing or memoizing values by representative
+/// will never merge values that are too far apart, but may occasionally keep
+/// apart values that are close together.
+///
+/// Only available in `std` builds.
+///
+/// # Examples
+/// ```
+/// # use float_eq::canonicalize;
+/// assert_eq!(canonicalize(0.1 + 0.2, 0.01), 0.3);
+/// assert_eq!(canonicalize(0.304, 0.01), 0.3);
+/// assert_eq!(canonicalize(0.306, 0.01), 0.31);
+/// assert_eq!(canonicalize(-0.001f64, 0.01).to_bits(), 0.0f64.to_bits());
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+pub fn canonicalize<T: FloatPrimitive>(x: T, tol: T) -> T {
+    if x.is_nan_value() {
+        return T::NAN;
+    }
+    if !is_finite(tol) || tol <= T::ZERO {
+        return x + T::ZERO;
+    }
+    let buckets = x / tol;
+    if !is_finite(buckets) {
+        return x + T::ZERO;
+    }
+    // adding positive zero turns a negative zero into a positive one
+    buckets.round_value() * tol + T::ZERO
+}
+
+fn is_finite<T: FloatPrimitive>(x: T) -> bool {
+    x.abs_value() < T::INFINITY
+}
+
+/// A value that has been [canonicalized], so that values which are
+/// approximately equal may be hashed and compared exactly, such as when
+/// grouping them in a `HashMap` or memoizing a function of them.
+///
+/// Two `Canonical` values are equal if their representatives have the same
+/// bits, so unlike the primitives they wrap they are `Eq` and `Hash`, and
+/// every NaN is equal to every other. See [canonicalize] for how the
+/// representative relates to the comparison traits.
+///
+/// Only available in `std` builds.
+///
+/// # Examples
+/// ```
+/// # use float_eq::Canonical;
+/// # use std::collections::HashMap;
+/// let mut counts = HashMap::new();
+/// for x in [0.1 + 0.2, 0.3, 0.299_999_9, 0.7] {
+///     *counts.entry(Canonical::new(x, 1e-3)).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts[&Canonical::new(0.3, 1e-3)], 3);
+/// assert_eq!(counts[&Canonical::new(0.7, 1e-3)], 1);
+/// ```
+///
+/// [canonicalized]: fn.canonicalize.html
+/// [canonicalize]: fn.canonicalize.html
+#[derive(Clone, Copy, Debug)]
+pub struct Canonical<T: FloatPrimitive> {
+    value: T,
+}
+
+impl<T: FloatPrimitive> Canonical<T> {
+    /// The representative of `x` for buckets of width `tol`.
+    #[inline]
+    pub fn new(x: T, tol: T) -> Self {
+        Canonical {
+            value: canonicalize(x, tol),
+        }
+    }
+
+    /// The representative value.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+}
+
+impl<T: FloatPrimitive> PartialEq for Canonical<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value.to_float_bits() == other.value.to_float_bits()
+    }
+}
+
+impl<T: FloatPrimitive> Eq for Canonical<T> {}
+
+impl<T: FloatPrimitive> Hash for Canonical<T>
+where
+    T::Bits: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_float_bits().hash(state)
+    }
+}
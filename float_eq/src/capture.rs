@@ -0,0 +1,53 @@
+use core::cell::RefCell;
+use core::fmt;
+
+std::thread_local! {
+    static CAPTURED: RefCell<Vec<(&'static str, Captured)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The values of the operands captured by `assert_float_eq_captured!` and
+/// `assert_float_ne_captured!` while they are evaluated. Failed asserts display
+/// them after their own fields.
+#[doc(hidden)]
+pub struct CaptureScope {
+    len: usize,
+}
+
+impl CaptureScope {
+    pub fn new(values: &[(&'static str, &dyn fmt::Debug)]) -> Self {
+        CAPTURED.with(|captured| {
+            let mut captured = captured.borrow_mut();
+            let len = captured.len();
+            captured.extend(
+                values
+                    .iter()
+                    .map(|(label, value)| (*label, Captured(format!("{:?}", value)))),
+            );
+            CaptureScope { len }
+        })
+    }
+}
+
+impl Drop for CaptureScope {
+    fn drop(&mut self) {
+        // Ignored if the thread local has already been destroyed.
+        let _ = CAPTURED.try_with(|captured| captured.borrow_mut().truncate(self.len));
+    }
+}
+
+/// The operands captured on this thread, innermost last.
+pub(crate) fn current() -> Vec<(&'static str, Captured)> {
+    CAPTURED
+        .try_with(|captured| captured.borrow().clone())
+        .unwrap_or_default()
+}
+
+/// An operand's `fmt::Debug` representation, which is written as it is.
+#[derive(Clone)]
+pub(crate) struct Captured(String);
+
+impl fmt::Debug for Captured {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,156 @@
+use core::fmt;
+
+/// A category of floating point value that is likely to cause a comparison to
+/// fail, or to give surprising results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatCategory {
+    /// Not a number, which is never equal to any value.
+    Nan,
+    /// Positive or negative infinity.
+    Infinite,
+    /// A subnormal value, with less precision than a normal one.
+    Subnormal,
+}
+
+impl FloatCategory {
+    /// The category of a value with the given properties, or `None` if it is
+    /// zero or normal.
+    #[inline]
+    pub fn classify(is_nan: bool, is_infinite: bool, is_subnormal: bool) -> Option<Self> {
+        if is_nan {
+            Some(FloatCategory::Nan)
+        } else if is_infinite {
+            Some(FloatCategory::Infinite)
+        } else if is_subnormal {
+            Some(FloatCategory::Subnormal)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for FloatCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FloatCategory::Nan => "NaN",
+            FloatCategory::Infinite => "infinite",
+            FloatCategory::Subnormal => "subnormal",
+        })
+    }
+}
+
+/// The categories of a pair of operands that are likely to cause a comparison
+/// to fail, displayed when an assert fails.
+///
+/// See [`AssertFloatEq::debug_categories`].
+///
+/// # Examples
+/// ```
+/// # use float_eq::{DebugCategories, FloatCategory};
+/// let categories = DebugCategories {
+///     left: Some(FloatCategory::Nan),
+///     right: Some(FloatCategory::Subnormal),
+/// };
+/// assert_eq!(
+///     categories.to_string(),
+///     "left operand is NaN, right operand is subnormal"
+/// );
+/// ```
+///
+/// [`AssertFloatEq::debug_categories`]: trait.AssertFloatEq.html#method.debug_categories
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DebugCategories {
+    /// The category of the left operand, if it is not zero or normal.
+    pub left: Option<FloatCategory>,
+    /// The category of the right operand, if it is not zero or normal.
+    pub right: Option<FloatCategory>,
+}
+
+impl DebugCategories {
+    /// The categories of a pair of operands, or `None` if both are zero or
+    /// normal.
+    #[inline]
+    pub fn new(left: Option<FloatCategory>, right: Option<FloatCategory>) -> Option<Self> {
+        if left.is_some() || right.is_some() {
+            Some(DebugCategories { left, right })
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for DebugCategories {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.left, self.right) {
+            (Some(left), Some(right)) if left == right => {
+                write!(f, "both operands are {}", left)
+            }
+            (Some(left), Some(right)) => {
+                write!(f, "left operand is {}, right operand is {}", left, right)
+            }
+            (Some(left), None) => write!(f, "left operand is {}", left),
+            (None, Some(right)) => write!(f, "right operand is {}", right),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// The reason that the ULPs difference between two values is not a count of
+/// the representable values between them, displayed in its place when an
+/// assert fails.
+///
+/// See [`AssertFloatEq::debug_ulps_diff_reason`].
+///
+/// # Examples
+/// ```
+/// # use float_eq::UlpsDiffReason;
+/// let reason = UlpsDiffReason::classify(false, true);
+/// assert_eq!(reason, Some(UlpsDiffReason::DifferentSigns));
+/// assert_eq!(
+///     UlpsDiffReason::DifferentSigns.to_string(),
+///     "operands differ in sign; ULPs comparison not applicable"
+/// );
+/// ```
+///
+/// [`AssertFloatEq::debug_ulps_diff_reason`]: trait.AssertFloatEq.html#method.debug_ulps_diff_reason
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UlpsDiffReason {
+    /// At least one operand is NaN, so there is no difference.
+    Nan,
+    /// The operands are not both zero and differ in sign, so there is no
+    /// difference.
+    DifferentSigns,
+    /// The difference is too large to be held by the ULPs type, and has been
+    /// clamped to its maximum. This is not produced by the implementations for
+    /// primitives, whose differences always fit, but may be by other types.
+    Saturated,
+}
+
+impl UlpsDiffReason {
+    /// The reason for a pair of values with the given properties, or `None` if
+    /// they have a ULPs difference.
+    #[inline]
+    pub fn classify(is_nan: bool, is_different_signs: bool) -> Option<Self> {
+        if is_nan {
+            Some(UlpsDiffReason::Nan)
+        } else if is_different_signs {
+            Some(UlpsDiffReason::DifferentSigns)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for UlpsDiffReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UlpsDiffReason::Nan => "an operand is NaN; ULPs comparison not applicable",
+            UlpsDiffReason::DifferentSigns => {
+                "operands differ in sign; ULPs comparison not applicable"
+            }
+            UlpsDiffReason::Saturated => {
+                "operands are too far apart to count; ULPs difference saturated"
+            }
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt;
+
+/// A set of the checks that may be made by [`FloatEq`], such as those that are
+/// meaningful for a type, as given by [`FloatEq::SUPPORTED_CHECKS`].
+///
+/// Each of the checks is a constant, and sets of them are built and queried by
+/// `const fn`s, so that they may be used in the definition of
+/// `SUPPORTED_CHECKS`. The `rel` and `percent` checks are included in
+/// [`RMAX`], since they are relative tolerance checks scaled to the larger
+/// magnitude operand, and `abs_ulps` requires both [`ABS`] and [`ULPS`].
+///
+/// A set is displayed as the names of its checks, in the order `abs`, `rmax`,
+/// `rmin`, `r1st`, `r2nd` and `ulps`, separated by commas.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{CheckSet, FloatEq};
+/// let set = CheckSet::ABS.union(CheckSet::ULPS);
+/// assert!(set.contains(CheckSet::ULPS));
+/// assert!(!set.contains(CheckSet::RMAX));
+/// assert_eq!(set.to_string(), "abs, ulps");
+///
+/// assert_eq!(<f64 as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+/// assert_eq!(CheckSet::from_name("rel"), Some(CheckSet::RMAX));
+/// ```
+///
+/// [`FloatEq`]: trait.FloatEq.html
+/// [`FloatEq::SUPPORTED_CHECKS`]: trait.FloatEq.html#associatedconstant.SUPPORTED_CHECKS
+/// [`ABS`]: #associatedconstant.ABS
+/// [`RMAX`]: #associatedconstant.RMAX
+/// [`ULPS`]: #associatedconstant.ULPS
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckSet(u8);
+
+impl CheckSet {
+    /// No checks.
+    pub const NONE: CheckSet = CheckSet(0);
+    /// The [absolute tolerance] check.
+    ///
+    /// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    pub const ABS: CheckSet = CheckSet(1);
+    /// The [relative tolerance] check scaled to the larger magnitude operand,
+    /// which is also made by `rel` and `percent`.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub const RMAX: CheckSet = CheckSet(1 << 1);
+    /// The [relative tolerance] check scaled to the smaller magnitude operand.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub const RMIN: CheckSet = CheckSet(1 << 2);
+    /// The [relative tolerance] check scaled to the first operand.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub const R1ST: CheckSet = CheckSet(1 << 3);
+    /// The [relative tolerance] check scaled to the second operand.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub const R2ND: CheckSet = CheckSet(1 << 4);
+    /// The [ULPs] check.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub const ULPS: CheckSet = CheckSet(1 << 5);
+    /// Every check.
+    pub const ALL: CheckSet = CheckSet((1 << 6) - 1);
+
+    const NAMED: [(&'static str, CheckSet); 6] = [
+        ("abs", CheckSet::ABS),
+        ("rmax", CheckSet::RMAX),
+        ("rmin", CheckSet::RMIN),
+        ("r1st", CheckSet::R1ST),
+        ("r2nd", CheckSet::R2ND),
+        ("ulps", CheckSet::ULPS),
+    ];
+
+    /// The checks that are in either `self` or `other`.
+    #[inline]
+    pub const fn union(self, other: CheckSet) -> CheckSet {
+        CheckSet(self.0 | other.0)
+    }
+
+    /// The checks that are in both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: CheckSet) -> CheckSet {
+        CheckSet(self.0 & other.0)
+    }
+
+    /// The checks that are in `self` but not in `other`.
+    #[inline]
+    pub const fn difference(self, other: CheckSet) -> CheckSet {
+        CheckSet(self.0 & !other.0)
+    }
+
+    /// Whether every check in `other` is also in `self`.
+    #[inline]
+    pub const fn contains(self, other: CheckSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether there are no checks in the set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The check made by the name it is written as in [`float_eq!`], such as
+    /// `rmax`, or `None` if there is no such check. The `_all` form of a name
+    /// is the same check, and `abs_ulps` is both `abs` and `ulps`.
+    ///
+    /// [`float_eq!`]: macro.float_eq.html
+    pub fn from_name(name: &str) -> Option<CheckSet> {
+        let name = name.strip_suffix("_all").unwrap_or(name);
+        match name {
+            "rel" | "percent" => Some(CheckSet::RMAX),
+            "abs_ulps" => Some(CheckSet::ABS.union(CheckSet::ULPS)),
+            _ => CheckSet::NAMED
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, set)| *set),
+        }
+    }
+
+    /// The names of the checks in the set, in the order `abs`, `rmax`, `rmin`,
+    /// `r1st`, `r2nd` and `ulps`.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        CheckSet::NAMED
+            .iter()
+            .filter(move |(_, set)| self.contains(*set))
+            .map(|(name, _)| *name)
+    }
+}
+
+impl Default for CheckSet {
+    /// An empty set.
+    fn default() -> Self {
+        CheckSet::NONE
+    }
+}
+
+impl fmt::Debug for CheckSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+impl fmt::Display for CheckSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for name in self.names() {
+            write!(f, "{}{}", separator, name)?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+//! Marker types that select a check at compile time, for generic algorithms.
+//!
+//! A library that compares floats on behalf of its callers may accept a
+//! [`Check`] as a type parameter, instead of picking one itself or taking a
+//! runtime flag, so that each caller chooses the semantics that suit their
+//! data and every instance of the algorithm is monomorphized with a single,
+//! statically known check. [`eq_with_check`] and [`ne_with_check`] apply one.
+//!
+//! # Examples
+//! ```
+//! use float_eq::checks::{self, eq_with_check, Check};
+//!
+//! /// The index of the first value equal to `x` by the check `C`.
+//! fn find<C: Check<f64>>(values: &[f64], x: f64, tol: &C::Tol) -> Option<usize> {
+//!     values.iter().position(|v| eq_with_check::<C, _, _>(v, &x, tol))
+//! }
+//!
+//! let values = [1.0, 100.0, 1e6];
+//! assert_eq!(find::<checks::Abs>(&values, 100.1, &0.5), Some(1));
+//! assert_eq!(find::<checks::Rmax>(&values, 1_000_100.0, &1e-3), Some(2));
+//! assert_eq!(find::<checks::Ulps>(&values, 100.0, &0), Some(1));
+//! ```
+//!
+//! [`Check`]: trait.Check.html
+//! [`eq_with_check`]: fn.eq_with_check.html
+//! [`ne_with_check`]: fn.ne_with_check.html
+
+use crate::{FloatEq, FloatEqUlpsTol, UlpsTol};
+
+/// A check between values of type `T` and `Rhs`, selected by type.
+///
+/// Implemented by the marker types in this module, one for each of the checks
+/// of [`FloatEq`], with the same tolerance types as the methods they call.
+///
+/// [`FloatEq`]: ../trait.FloatEq.html
+pub trait Check<T: ?Sized, Rhs: ?Sized = T> {
+    /// Type of the tolerance of the check.
+    type Tol: ?Sized;
+
+    /// The name of the check, as it is written in [`float_eq!`].
+    ///
+    /// [`float_eq!`]: ../macro.float_eq.html
+    const NAME: &'static str;
+
+    /// Check whether `a` is equal to `b` given the tolerance `tol`.
+    fn eq(a: &T, b: &Rhs, tol: &Self::Tol) -> bool;
+
+    /// Check whether `a` is not equal to `b` given the tolerance `tol`.
+    #[inline]
+    fn ne(a: &T, b: &Rhs, tol: &Self::Tol) -> bool {
+        !Self::eq(a, b, tol)
+    }
+}
+
+/// Check whether `a` is equal to `b` via the check `C`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::checks::{self, eq_with_check};
+/// assert!(eq_with_check::<checks::Rmin, _, _>(&4.0f32, &3.0, &0.5));
+/// assert!(eq_with_check::<checks::Ulps, _, _>(&1.0f32, &1.000_000_1, &1));
+/// ```
+#[inline]
+pub fn eq_with_check<C, T, Rhs>(a: &T, b: &Rhs, tol: &C::Tol) -> bool
+where
+    C: Check<T, Rhs>,
+    T: ?Sized,
+    Rhs: ?Sized,
+{
+    C::eq(a, b, tol)
+}
+
+/// Check whether `a` is not equal to `b` via the check `C`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::checks::{self, ne_with_check};
+/// assert!(ne_with_check::<checks::Abs, _, _>(&[1.0f64, 2.0], &[1.0, 2.5], &[0.1, 0.1]));
+/// ```
+#[inline]
+pub fn ne_with_check<C, T, Rhs>(a: &T, b: &Rhs, tol: &C::Tol) -> bool
+where
+    C: Check<T, Rhs>,
+    T: ?Sized,
+    Rhs: ?Sized,
+{
+    C::ne(a, b, tol)
+}
+
+macro_rules! impl_check {
+    ($(#[$meta:meta])* $name:ident, $check:ident, $check_name:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $name;
+
+        impl<T, Rhs> Check<T, Rhs> for $name
+        where
+            T: ?Sized + FloatEq<Rhs>,
+            Rhs: ?Sized,
+        {
+            type Tol = T::Tol;
+
+            const NAME: &'static str = $check_name;
+
+            #[inline]
+            fn eq(a: &T, b: &Rhs, tol: &Self::Tol) -> bool {
+                a.$check(b, tol)
+            }
+        }
+    };
+}
+
+impl_check!(
+    /// The [absolute tolerance] check, [`FloatEq::eq_abs`].
+    ///
+    /// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    /// [`FloatEq::eq_abs`]: ../trait.FloatEq.html#tymethod.eq_abs
+    Abs,
+    eq_abs,
+    "abs"
+);
+
+impl_check!(
+    /// The [relative tolerance] check scaled to the larger magnitude operand,
+    /// [`FloatEq::eq_rmax`].
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`FloatEq::eq_rmax`]: ../trait.FloatEq.html#tymethod.eq_rmax
+    Rmax,
+    eq_rmax,
+    "rmax"
+);
+
+impl_check!(
+    /// The [relative tolerance] check scaled to the smaller magnitude operand,
+    /// [`FloatEq::eq_rmin`].
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`FloatEq::eq_rmin`]: ../trait.FloatEq.html#tymethod.eq_rmin
+    Rmin,
+    eq_rmin,
+    "rmin"
+);
+
+impl_check!(
+    /// The [relative tolerance] check scaled to the first operand,
+    /// [`FloatEq::eq_r1st`].
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`FloatEq::eq_r1st`]: ../trait.FloatEq.html#tymethod.eq_r1st
+    R1st,
+    eq_r1st,
+    "r1st"
+);
+
+impl_check!(
+    /// The [relative tolerance] check scaled to the second operand,
+    /// [`FloatEq::eq_r2nd`].
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`FloatEq::eq_r2nd`]: ../trait.FloatEq.html#tymethod.eq_r2nd
+    R2nd,
+    eq_r2nd,
+    "r2nd"
+);
+
+/// The [ULPs] check, [`FloatEq::eq_ulps`].
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`FloatEq::eq_ulps`]: ../trait.FloatEq.html#tymethod.eq_ulps
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ulps;
+
+impl<T, Rhs> Check<T, Rhs> for Ulps
+where
+    T: ?Sized + FloatEq<Rhs>,
+    Rhs: ?Sized,
+    T::Tol: FloatEqUlpsTol,
+{
+    type Tol = UlpsTol<T::Tol>;
+
+    const NAME: &'static str = "ulps";
+
+    #[inline]
+    fn eq(a: &T, b: &Rhs, tol: &Self::Tol) -> bool {
+        a.eq_ulps(b, tol)
+    }
+}
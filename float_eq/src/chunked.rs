@@ -0,0 +1,42 @@
+use core::mem::size_of;
+
+// The size of each chunk of elements compared together, in bytes, which is
+// that of the vector registers of AVX.
+const CHUNK_BYTES: usize = 32;
+
+/// Whether `eq` holds for each pair of elements of `a` and `b`, which must be
+/// of the same length.
+///
+/// The elements are compared a chunk at a time, without stopping part way
+/// through a chunk, so that `eq` may be vectorised if it does not branch.
+#[inline]
+pub(crate) fn all_lanes<T, F>(a: &[T], b: &[T], eq: F) -> bool
+where
+    T: Copy,
+    F: Fn(T, T) -> bool,
+{
+    // Plain loops over indices rather than iterator adaptors, since these are
+    // also much faster in unoptimised builds, which tests usually are.
+    let lanes = CHUNK_BYTES / size_of::<T>();
+    let len = a.len().min(b.len());
+    let whole_chunks = len - len % lanes;
+    let mut i = 0;
+    while i < whole_chunks {
+        let (a, b) = (&a[i..i + lanes], &b[i..i + lanes]);
+        let mut all = true;
+        for j in 0..lanes {
+            all &= eq(a[j], b[j]);
+        }
+        if !all {
+            return false;
+        }
+        i += lanes;
+    }
+    while i < len {
+        if !eq(a[i], b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,272 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, UlpsDiffReason};
+use crate::{CheckSet, FloatEq, FloatEqAll, UlpsTol};
+
+/// A value that has been clamped to the range `min..=max`, which is compared
+/// against an expected value with those rails taken into account.
+///
+/// If both the value and the expected value are at or beyond the same rail,
+/// or equal to it given the tolerance of the check, then they are equal, even
+/// though they may not be equal to each other. Otherwise they are compared as
+/// usual. This suits the outputs of pipelines such as audio or image
+/// processing, where values that saturate are clamped to a normalized range
+/// and the difference between values on the far side of a rail is lost, so
+/// that a reference computed before clamping, or clamped at a slightly
+/// different point, may still be compared against them.
+///
+/// The rails are compared with the operands using `PartialOrd`, so `Clamped`
+/// is intended for scalar values. To compare arrays or other collections,
+/// wrap each element, since `[Clamped<f32>; N]` may be compared against
+/// `[f32; N]`. NaNs are never at a rail.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "checks-only"))]
+/// # use float_eq::assert_float_eq;
+/// # use float_eq::{float_eq, Clamped};
+/// let output = Clamped::new(1.0f32, 0.0, 1.0);
+/// assert!(float_eq!(output, 1.2, abs <= 0.001));
+/// assert!(float_eq!(output, 0.999_9, abs <= 0.001));
+/// assert!(!float_eq!(output, 0.99, abs <= 0.001));
+///
+/// let pixels = [0.0f32, 0.5, 1.0].map(|x| Clamped::new(x, 0.0, 1.0));
+/// # #[cfg(not(feature = "checks-only"))]
+/// assert_float_eq!(pixels, [-0.25, 0.5, 1.5], ulps <= [0; 3]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clamped<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T> Clamped<T> {
+    /// Wrap `value`, which has been clamped to the range `min..=max`.
+    #[inline]
+    pub fn new(value: T, min: T, max: T) -> Self {
+        Clamped { value, min, max }
+    }
+
+    /// The clamped value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The lower rail.
+    #[inline]
+    pub fn min(&self) -> &T {
+        &self.min
+    }
+
+    /// The upper rail.
+    #[inline]
+    pub fn max(&self) -> &T {
+        &self.max
+    }
+
+    /// Unwrap the clamped value.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T: PartialOrd> Clamped<T> {
+    /// Whether the value and `other` are both at or beyond the same rail, where
+    /// `at` is the check of whether an operand is equal to a rail.
+    #[inline]
+    fn same_rail<F: Fn(&T, &T) -> bool>(&self, other: &T, at: F) -> bool {
+        let at_min = |x: &T| *x <= self.min || at(x, &self.min);
+        let at_max = |x: &T| *x >= self.max || at(x, &self.max);
+        (at_min(&self.value) && at_min(other)) || (at_max(&self.value) && at_max(other))
+    }
+}
+
+impl<T> FloatEq<T> for Clamped<T>
+where
+    T: FloatEq + PartialOrd,
+{
+    type Tol = T::Tol;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &T, tol: &Self::Tol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_abs(rail, tol)) || self.value.eq_abs(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &T, tol: &Self::Tol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_rmax(rail, tol)) || self.value.eq_rmax(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &T, tol: &Self::Tol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_rmin(rail, tol)) || self.value.eq_rmin(other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &T, tol: &Self::Tol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_r1st(rail, tol)) || self.value.eq_r1st(other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &T, tol: &Self::Tol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_r2nd(rail, tol)) || self.value.eq_r2nd(other, tol)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &T, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.same_rail(other, |x, rail| x.eq_ulps(rail, tol)) || self.value.eq_ulps(other, tol)
+    }
+}
+
+impl<T> FloatEqAll<T> for Clamped<T>
+where
+    T: FloatEqAll + PartialOrd,
+{
+    type AllTol = T::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &T, tol: &Self::AllTol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_abs_all(rail, tol))
+            || self.value.eq_abs_all(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &T, tol: &Self::AllTol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_rmax_all(rail, tol))
+            || self.value.eq_rmax_all(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &T, tol: &Self::AllTol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_rmin_all(rail, tol))
+            || self.value.eq_rmin_all(other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &T, tol: &Self::AllTol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_r1st_all(rail, tol))
+            || self.value.eq_r1st_all(other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &T, tol: &Self::AllTol) -> bool {
+        self.same_rail(other, |x, rail| x.eq_r2nd_all(rail, tol))
+            || self.value.eq_r2nd_all(other, tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &T, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.same_rail(other, |x, rail| x.eq_ulps_all(rail, tol))
+            || self.value.eq_ulps_all(other, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEq<T> for Clamped<T>
+where
+    T: AssertFloatEq + PartialOrd,
+{
+    type DebugAbsDiff = T::DebugAbsDiff;
+    type DebugTol = T::DebugTol;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &T) -> Self::DebugAbsDiff {
+        self.value.debug_abs_diff(other)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &T) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        self.value.debug_ulps_diff(other)
+    }
+
+    #[inline]
+    fn debug_categories(&self, other: &T) -> Option<DebugCategories> {
+        self.value.debug_categories(other)
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &T) -> Option<UlpsDiffReason> {
+        self.value.debug_ulps_diff_reason(other)
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &T, tol: &Self::Tol) -> Self::DebugTol {
+        self.value.debug_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &T, tol: &Self::Tol) -> Self::DebugTol {
+        self.value.debug_rmax_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &T, tol: &Self::Tol) -> Self::DebugTol {
+        self.value.debug_rmin_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &T, tol: &Self::Tol) -> Self::DebugTol {
+        self.value.debug_r1st_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &T, tol: &Self::Tol) -> Self::DebugTol {
+        self.value.debug_r2nd_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &T, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        self.value.debug_ulps_tol(other, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEqAll<T> for Clamped<T>
+where
+    T: AssertFloatEqAll + PartialOrd,
+{
+    type AllDebugTol = T::AllDebugTol;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &T, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.value.debug_abs_all_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &T, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.value.debug_rmax_all_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &T, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.value.debug_rmin_all_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &T, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.value.debug_r1st_all_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &T, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.value.debug_r2nd_all_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &T,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        self.value.debug_ulps_all_tol(other, tol)
+    }
+}
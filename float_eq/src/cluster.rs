@@ -0,0 +1,126 @@
+use crate::FloatEq;
+
+/// The clusters found by [`cluster`] and [`cluster_by`].
+///
+/// [`cluster`]: fn.cluster.html
+/// [`cluster_by`]: fn.cluster_by.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clusters {
+    assignments: Vec<usize>,
+    representatives: Vec<usize>,
+}
+
+impl Clusters {
+    /// The index of the cluster of each value, in the same order as the
+    /// values. Clusters are numbered in order of their first member.
+    #[inline]
+    pub fn assignments(&self) -> &[usize] {
+        &self.assignments
+    }
+
+    /// The index of the value that represents each cluster, which is always
+    /// its first member.
+    #[inline]
+    pub fn representatives(&self) -> &[usize] {
+        &self.representatives
+    }
+
+    /// The number of clusters.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.representatives.len()
+    }
+
+    /// Whether there are no clusters, which is only the case if there were no
+    /// values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.representatives.is_empty()
+    }
+
+    /// The indices of the members of each cluster, in order.
+    pub fn members(&self) -> Vec<Vec<usize>> {
+        let mut members = vec![Vec::new(); self.len()];
+        for (index, &cluster) in self.assignments.iter().enumerate() {
+            members[cluster].push(index);
+        }
+        members
+    }
+}
+
+/// Group values into clusters whose members are all equal to the cluster's
+/// representative using an [absolute tolerance comparison].
+///
+/// This is useful for merging near-duplicate values, such as the vertices of
+/// a mesh or the roots found by a solver from several starting points. Values
+/// may be of any type that may be compared, so points may be given as arrays
+/// or tuples of coordinates.
+///
+/// Each value in turn joins the first cluster whose representative it is
+/// equal to, or else starts a new cluster with itself as the representative.
+/// Members of a cluster are therefore within the tolerance of its
+/// representative, but not necessarily of each other, and a value may be
+/// close to the representatives of several clusters. The result depends on
+/// the order of the values, and takes time proportional to the number of
+/// values times the number of clusters. Values that are not equal to
+/// themselves, such as NaNs, are each placed in a cluster of their own.
+///
+/// # Examples
+/// ```
+/// # use float_eq::cluster;
+/// let roots = [1.0f64, 2.999_999_9, -0.5, 1.000_000_1, 3.000_000_2];
+/// let clusters = cluster(&roots, 1e-6);
+///
+/// assert_eq!(clusters.assignments(), &[0, 1, 2, 0, 1]);
+/// assert_eq!(clusters.representatives(), &[0, 1, 2]);
+///
+/// let vertices = [[0.0f32, 1.0], [1.0, 0.0], [1e-7, 1.0]];
+/// assert_eq!(cluster(&vertices, [1e-6; 2]).members(), vec![vec![0, 2], vec![1]]);
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+#[inline]
+pub fn cluster<T>(values: &[T], tol: T::Tol) -> Clusters
+where
+    T: FloatEq,
+    T::Tol: Sized,
+{
+    cluster_by(values, tol, FloatEq::eq_abs)
+}
+
+/// Group values into clusters whose members are all equal to the cluster's
+/// representative, where `check` decides whether a value is equal to a
+/// representative given `tol`.
+///
+/// See [`cluster`] for details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{cluster_by, FloatEqAll};
+/// let vertices = [[0.0f32, 1.0], [1.0, 0.0], [1e-7, 1.0]];
+/// let clusters = cluster_by(&vertices, 1e-6, |a, b, tol| a.eq_abs_all(b, tol));
+/// assert_eq!(clusters.assignments(), &[0, 1, 0]);
+/// ```
+///
+/// [`cluster`]: fn.cluster.html
+pub fn cluster_by<T, Tol, F>(values: &[T], tol: Tol, mut check: F) -> Clusters
+where
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    let mut clusters = Clusters {
+        assignments: Vec::with_capacity(values.len()),
+        representatives: Vec::new(),
+    };
+    for (index, value) in values.iter().enumerate() {
+        let found = clusters
+            .representatives
+            .iter()
+            .position(|&rep| check(value, &values[rep], &tol));
+        let assignment = found.unwrap_or_else(|| {
+            clusters.representatives.push(index);
+            clusters.representatives.len() - 1
+        });
+        clusters.assignments.push(assignment);
+    }
+    clusters
+}
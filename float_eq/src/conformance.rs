@@ -0,0 +1,268 @@
+//! Checks that hand-written implementations of the comparison traits are
+//! consistent with themselves.
+//!
+//! Implementing [`FloatEq`], [`FloatEqAll`], [`AssertFloatEq`] and
+//! [`AssertFloatEqAll`] by hand means writing each check several times over,
+//! and it is easy for one of them to drift from the others, for example by
+//! swapping the operands of `eq_r1st`, or by computing a debug tolerance that
+//! disagrees with the check it is meant to explain. [`check_impl`] exercises
+//! an implementation over every pair of a set of example values and reports
+//! the first [`Violation`] of the rules that all correct implementations
+//! follow. [`assert_impl_conforms!`] does the same, but panics with the
+//! offending values instead.
+//!
+//! [`AssertFloatEq`]: ../trait.AssertFloatEq.html
+//! [`AssertFloatEqAll`]: ../trait.AssertFloatEqAll.html
+//! [`assert_impl_conforms!`]: ../macro.assert_impl_conforms.html
+//! [`check_impl`]: fn.check_impl.html
+//! [`FloatEq`]: ../trait.FloatEq.html
+//! [`FloatEqAll`]: ../trait.FloatEqAll.html
+//! [`Violation`]: struct.Violation.html
+
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, UlpsTol};
+use core::fmt;
+
+/// A rule that a correct implementation of the comparison traits follows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// A value that is equal to itself by `PartialEq` must be equal to itself
+    /// by every check, whatever the tolerance.
+    Reflexive,
+    /// Swapping the operands of a check must not change its result, except
+    /// that `r1st` and `r2nd` swap with each other.
+    Symmetric,
+    /// Each `ne_` method must be the negation of its `eq_` counterpart.
+    Negation,
+    /// The `rel` checks must be the same as the `rmax` checks.
+    RelIsRmax,
+    /// Each debug tolerance, used as the tolerance of an `abs` check, or of a
+    /// `ulps` check for the `ulps` tolerances, must give the same result as the
+    /// check it was computed for. With the `validate-tol` feature, only the
+    /// `ulps` debug tolerances are checked, since the others may be NaN, such
+    /// as a relative tolerance scaled by an infinite value.
+    DebugTol,
+    /// Swapping the operands of `debug_abs_diff` and `debug_ulps_diff` must
+    /// not change their result, unless it is not equal to itself by
+    /// `PartialEq`, such as when it contains NaN.
+    DebugDiffSymmetric,
+}
+
+/// The first violation of a [`Rule`] found by [`check_impl`].
+///
+/// [`check_impl`]: fn.check_impl.html
+/// [`Rule`]: enum.Rule.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Violation {
+    /// The rule that was broken.
+    pub rule: Rule,
+    /// The check that broke it, such as `"rmax"` or `"ulps_all"`.
+    pub check: &'static str,
+    /// The index of the left hand operand in the example values.
+    pub left: usize,
+    /// The index of the right hand operand in the example values.
+    pub right: usize,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} rule broken by the {} check, comparing values {} and {}",
+            self.rule, self.check, self.left, self.right
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Violation {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for Violation {}
+
+/// Checks that the comparison traits of `T` are consistent with themselves,
+/// comparing every pair of `values` with the given tolerances.
+///
+/// Every pair is checked in both orders, as is each value with itself, so the
+/// values should include some that are equal, some that are close and some
+/// that are far apart, along with any special values such as zeros and
+/// infinities that the type may hold. See [`Rule`] for what is checked.
+///
+/// This applies to types whose debug tolerances are of the same type as their
+/// tolerances, which is usually the case for structs, including those that
+/// derive the traits.
+///
+/// Returns the first [`Violation`] found, if any.
+///
+/// # Examples
+/// ```
+/// # use float_eq::conformance::check_impl;
+/// let values = [0.0f64, -0.0, 1.0, 1.000_000_000_000_000_2, 2.0, f64::INFINITY];
+/// assert_eq!(check_impl(&values, &0.5, &4, &0.5, &4), Ok(()));
+/// ```
+///
+/// [`Rule`]: enum.Rule.html
+/// [`Violation`]: struct.Violation.html
+pub fn check_impl<T>(
+    values: &[T],
+    tol: &<T as FloatEq>::Tol,
+    ulps_tol: &UlpsTol<<T as FloatEq>::Tol>,
+    all_tol: &T::AllTol,
+    ulps_all_tol: &UlpsTol<T::AllTol>,
+) -> Result<(), Violation>
+where
+    T: PartialEq
+        + AssertFloatEq<DebugTol = <T as FloatEq>::Tol>
+        + AssertFloatEqAll<AllDebugTol = <T as FloatEq>::Tol>,
+    T::DebugAbsDiff: PartialEq,
+    DebugUlpsDiff<T::DebugAbsDiff>: PartialEq,
+    <T as FloatEq>::Tol: Sized,
+    UlpsTol<<T as FloatEq>::Tol>: Sized,
+{
+    for (left, a) in values.iter().enumerate() {
+        for (right, b) in values.iter().enumerate() {
+            let violation = |rule, check| Violation {
+                rule,
+                check,
+                left,
+                right,
+            };
+            let debug_tol = |check, is_eq: bool, debug_tol: &<T as FloatEq>::Tol| {
+                if cfg!(feature = "validate-tol") || a.eq_abs(b, debug_tol) == is_eq {
+                    Ok(())
+                } else {
+                    Err(violation(Rule::DebugTol, check))
+                }
+            };
+            let checks = [
+                ("abs", a.eq_abs(b, tol), b.eq_abs(a, tol), a.ne_abs(b, tol)),
+                ("rel", a.eq_rel(b, tol), b.eq_rel(a, tol), a.ne_rel(b, tol)),
+                (
+                    "rmax",
+                    a.eq_rmax(b, tol),
+                    b.eq_rmax(a, tol),
+                    a.ne_rmax(b, tol),
+                ),
+                (
+                    "rmin",
+                    a.eq_rmin(b, tol),
+                    b.eq_rmin(a, tol),
+                    a.ne_rmin(b, tol),
+                ),
+                (
+                    "r1st",
+                    a.eq_r1st(b, tol),
+                    b.eq_r2nd(a, tol),
+                    a.ne_r1st(b, tol),
+                ),
+                (
+                    "r2nd",
+                    a.eq_r2nd(b, tol),
+                    b.eq_r1st(a, tol),
+                    a.ne_r2nd(b, tol),
+                ),
+                (
+                    "ulps",
+                    a.eq_ulps(b, ulps_tol),
+                    b.eq_ulps(a, ulps_tol),
+                    a.ne_ulps(b, ulps_tol),
+                ),
+                (
+                    "abs_all",
+                    a.eq_abs_all(b, all_tol),
+                    b.eq_abs_all(a, all_tol),
+                    a.ne_abs_all(b, all_tol),
+                ),
+                (
+                    "rel_all",
+                    a.eq_rel_all(b, all_tol),
+                    b.eq_rel_all(a, all_tol),
+                    a.ne_rel_all(b, all_tol),
+                ),
+                (
+                    "rmax_all",
+                    a.eq_rmax_all(b, all_tol),
+                    b.eq_rmax_all(a, all_tol),
+                    a.ne_rmax_all(b, all_tol),
+                ),
+                (
+                    "rmin_all",
+                    a.eq_rmin_all(b, all_tol),
+                    b.eq_rmin_all(a, all_tol),
+                    a.ne_rmin_all(b, all_tol),
+                ),
+                (
+                    "r1st_all",
+                    a.eq_r1st_all(b, all_tol),
+                    b.eq_r2nd_all(a, all_tol),
+                    a.ne_r1st_all(b, all_tol),
+                ),
+                (
+                    "r2nd_all",
+                    a.eq_r2nd_all(b, all_tol),
+                    b.eq_r1st_all(a, all_tol),
+                    a.ne_r2nd_all(b, all_tol),
+                ),
+                (
+                    "ulps_all",
+                    a.eq_ulps_all(b, ulps_all_tol),
+                    b.eq_ulps_all(a, ulps_all_tol),
+                    a.ne_ulps_all(b, ulps_all_tol),
+                ),
+            ];
+
+            let is_reflexive = left == right && a == b;
+            for &(check, is_eq, is_swapped_eq, is_ne) in checks.iter() {
+                if is_reflexive && !is_eq {
+                    return Err(violation(Rule::Reflexive, check));
+                }
+                if is_eq != is_swapped_eq {
+                    return Err(violation(Rule::Symmetric, check));
+                }
+                if is_eq == is_ne {
+                    return Err(violation(Rule::Negation, check));
+                }
+            }
+            if checks[1].1 != checks[2].1 {
+                return Err(violation(Rule::RelIsRmax, "rel"));
+            }
+            if checks[8].1 != checks[9].1 {
+                return Err(violation(Rule::RelIsRmax, "rel_all"));
+            }
+
+            debug_tol("abs", checks[0].1, &a.debug_abs_tol(b, tol))?;
+            debug_tol("rel", checks[1].1, &a.debug_rel_tol(b, tol))?;
+            debug_tol("rmax", checks[2].1, &a.debug_rmax_tol(b, tol))?;
+            debug_tol("rmin", checks[3].1, &a.debug_rmin_tol(b, tol))?;
+            debug_tol("r1st", checks[4].1, &a.debug_r1st_tol(b, tol))?;
+            debug_tol("r2nd", checks[5].1, &a.debug_r2nd_tol(b, tol))?;
+            if a.eq_ulps(b, &a.debug_ulps_tol(b, ulps_tol)) != checks[6].1 {
+                return Err(violation(Rule::DebugTol, "ulps"));
+            }
+            debug_tol("abs_all", checks[7].1, &a.debug_abs_all_tol(b, all_tol))?;
+            debug_tol("rel_all", checks[8].1, &a.debug_rel_all_tol(b, all_tol))?;
+            debug_tol("rmax_all", checks[9].1, &a.debug_rmax_all_tol(b, all_tol))?;
+            debug_tol("rmin_all", checks[10].1, &a.debug_rmin_all_tol(b, all_tol))?;
+            debug_tol("r1st_all", checks[11].1, &a.debug_r1st_all_tol(b, all_tol))?;
+            debug_tol("r2nd_all", checks[12].1, &a.debug_r2nd_all_tol(b, all_tol))?;
+            if a.eq_ulps(b, &a.debug_ulps_all_tol(b, ulps_all_tol)) != checks[13].1 {
+                return Err(violation(Rule::DebugTol, "ulps_all"));
+            }
+
+            // a difference that is not equal to itself, such as one that
+            // contains NaN, cannot be compared
+            let abs_diff = a.debug_abs_diff(b);
+            #[allow(clippy::eq_op)]
+            let is_comparable = abs_diff == abs_diff;
+            if is_comparable && abs_diff != b.debug_abs_diff(a) {
+                return Err(violation(Rule::DebugDiffSymmetric, "abs"));
+            }
+            let ulps_diff = a.debug_ulps_diff(b);
+            #[allow(clippy::eq_op)]
+            let is_comparable = ulps_diff == ulps_diff;
+            if is_comparable && ulps_diff != b.debug_ulps_diff(a) {
+                return Err(violation(Rule::DebugDiffSymmetric, "ulps"));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::{FloatEqAll, FloatEqUlpsTol, FloatPrimitive, UlpsTol};
+
+/// A check and tolerance that are fixed at compile time, for use as a type
+/// parameter of generic code.
+///
+/// Since the tolerance is a constant, it is baked into each monomorphized
+/// comparison function instead of being passed in and carried around at
+/// runtime, which suits hot loops and generic algorithms that are written once
+/// and instanced with the precision each caller requires. It is implemented by
+/// [`ConstUlps`] and [`ConstAbs`].
+///
+/// The tolerance is applied to every field of a composite type, as with the
+/// `_all` checks, so that it may be expressed as a single constant.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{ConstAbs, ConstTol, ConstUlps};
+/// fn position<C: ConstTol<f32>>(values: &[f32], x: f32) -> Option<usize> {
+///     values.iter().position(|v| C::eq(v, &x))
+/// }
+///
+/// let values = [0.5, 1.000_000_1, 2.0];
+/// assert_eq!(position::<ConstUlps<1>>(&values, 1.0), Some(1));
+/// assert_eq!(position::<ConstUlps<0>>(&values, 1.0), None);
+/// assert_eq!(position::<ConstAbs<1, 10>>(&values, 1.95), Some(2));
+/// ```
+///
+/// [`ConstUlps`]: struct.ConstUlps.html
+/// [`ConstAbs`]: struct.ConstAbs.html
+pub trait ConstTol<T: ?Sized, Rhs: ?Sized = T> {
+    /// Check whether `a` is equal to `b` given the fixed tolerance.
+    fn eq(a: &T, b: &Rhs) -> bool;
+
+    /// Check whether `a` is not equal to `b` given the fixed tolerance.
+    #[inline]
+    fn ne(a: &T, b: &Rhs) -> bool {
+        !Self::eq(a, b)
+    }
+}
+
+/// A fixed [ULPs] tolerance of `N`, applied by [`ConstTol`].
+///
+/// Equivalent to `ulps_all <= N`, so that `ConstUlps<4>` compares `f32`, `f64`
+/// and composites of them such as `[f64; 3]` with a tolerance of 4 ULPs.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{ConstTol, ConstUlps};
+/// type Tol = ConstUlps<1>;
+/// assert!(Tol::eq(&1.0f64, &1.000_000_000_000_000_2));
+/// assert!(Tol::ne(&[1.0f32, 2.0], &[1.0, 2.000_000_5]));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+/// [`ConstTol`]: trait.ConstTol.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConstUlps<const N: u32>;
+
+impl<const N: u32> ConstUlps<N> {
+    /// The tolerance, in ULPs.
+    pub const ULPS: u32 = N;
+}
+
+impl<T, Rhs, const N: u32> ConstTol<T, Rhs> for ConstUlps<N>
+where
+    T: ?Sized + FloatEqAll<Rhs>,
+    Rhs: ?Sized,
+    T::AllTol: FloatEqUlpsTol,
+    UlpsTol<T::AllTol>: From<u32>,
+{
+    #[inline]
+    fn eq(a: &T, b: &Rhs) -> bool {
+        a.eq_ulps_all(b, &UlpsTol::<T::AllTol>::from(N))
+    }
+}
+
+/// A fixed [absolute tolerance] of `NUM / DEN`, applied by [`ConstTol`].
+///
+/// Equivalent to `abs_all <= NUM / DEN`, so that `ConstAbs<1, 1_000_000>`
+/// compares `f32`, `f64` and composites of them with a tolerance of `1e-6`.
+/// The tolerance is given as a ratio of integers since floats may not be used
+/// as const generic parameters. It is rounded to the nearest value of the type
+/// being compared.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{ConstAbs, ConstTol};
+/// type Tol = ConstAbs<1, 1_000>;
+/// assert!(Tol::eq(&1.0f32, &1.000_5));
+/// assert!(Tol::ne(&[1.0f64, 2.0], &[1.0, 2.01]));
+/// ```
+///
+/// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+/// [`ConstTol`]: trait.ConstTol.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConstAbs<const NUM: u64, const DEN: u64 = 1>;
+
+impl<const NUM: u64, const DEN: u64> ConstAbs<NUM, DEN> {
+    /// The tolerance, as a value of `T`.
+    #[inline]
+    pub fn tol<T: FloatPrimitive>() -> T {
+        T::from_ratio(NUM, DEN)
+    }
+}
+
+impl<T, Rhs, const NUM: u64, const DEN: u64> ConstTol<T, Rhs> for ConstAbs<NUM, DEN>
+where
+    T: ?Sized + FloatEqAll<Rhs>,
+    Rhs: ?Sized,
+    T::AllTol: FloatPrimitive,
+{
+    #[inline]
+    fn eq(a: &T, b: &Rhs) -> bool {
+        a.eq_abs_all(b, &Self::tol())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::FloatPrimitive;
+use core::cmp::Ordering;
+use core::fmt;
+
+/// A point at which a sequence of iterates failed to converge at the expected
+/// rate, returned by [`linear_rate_violation`].
+///
+/// [`linear_rate_violation`]: fn.linear_rate_violation.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateViolation<T: FloatPrimitive> {
+    /// The position of the iterate whose successor did not improve enough.
+    pub index: usize,
+    /// The absolute error of the iterate at `index`.
+    pub error: T,
+    /// The absolute error of the iterate after `index`.
+    pub next_error: T,
+}
+
+impl<T: FloatPrimitive> RateViolation<T> {
+    /// The ratio of `next_error` to `error`, which exceeded the rate bound.
+    #[inline]
+    pub fn rate(&self) -> T {
+        self.next_error / self.error
+    }
+}
+
+/// The first iterate whose successor is not closer to `target` by at least a
+/// factor of `rate`.
+///
+/// A sequence converges linearly at `rate` if the absolute error of each
+/// iterate is at most `rate` times that of the previous one, so a `rate` of
+/// `0.5` requires the error to at least halve at every step. Errors that are
+/// NaN always violate the bound.
+///
+/// Returns `None` if every step satisfies the bound.
+///
+/// # Examples
+/// ```
+/// # use float_eq::linear_rate_violation;
+/// let iterates = [2.0f64, 1.5, 1.25, 1.2, 1.01];
+///
+/// assert_eq!(linear_rate_violation(&iterates, 1.0, 0.9), None);
+/// let violation = linear_rate_violation(&iterates, 1.0, 0.5).unwrap();
+/// assert_eq!(violation.index, 2);
+/// ```
+pub fn linear_rate_violation<T: FloatPrimitive>(
+    iterates: &[T],
+    target: T,
+    rate: T,
+) -> Option<RateViolation<T>> {
+    let error = |x: T| (x - target).abs_value();
+    iterates
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| RateViolation {
+            index,
+            error: error(pair[0]),
+            next_error: error(pair[1]),
+        })
+        .find(|v| {
+            !matches!(
+                v.next_error.partial_cmp(&(rate * v.error)),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            )
+        })
+}
+
+/// The iterates of a sequence along with their absolute errors from a target,
+/// displayed by [`assert_converges!`] on failure.
+///
+/// The `fmt::Debug` output is a list of `(iterate, error)` pairs.
+///
+/// # Examples
+/// ```
+/// # use float_eq::ConvergenceHistory;
+/// let history = ConvergenceHistory::new(&[3.0f32, 2.0, 1.5], 1.0);
+/// assert_eq!(format!("{:?}", history), "[(3.0, 2.0), (2.0, 1.0), (1.5, 0.5)]");
+/// ```
+///
+/// [`assert_converges!`]: macro.assert_converges.html
+#[derive(Clone, Copy)]
+pub struct ConvergenceHistory<'a, T: FloatPrimitive> {
+    iterates: &'a [T],
+    target: T,
+}
+
+impl<'a, T: FloatPrimitive> ConvergenceHistory<'a, T> {
+    /// The history of `iterates` approaching `target`.
+    #[inline]
+    pub fn new(iterates: &'a [T], target: T) -> Self {
+        ConvergenceHistory { iterates, target }
+    }
+}
+
+impl<T: FloatPrimitive> fmt::Debug for ConvergenceHistory<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.iterates
+                    .iter()
+                    .map(|x| (*x, (*x - self.target).abs_value())),
+            )
+            .finish()
+    }
+}
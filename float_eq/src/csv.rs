@@ -0,0 +1,65 @@
+use crate::FloatPrimitive;
+use std::io;
+
+/// Write the per-element differences between two slices as CSV.
+///
+/// A header row is written first, followed by one row per element with the
+/// columns `index`, `a`, `b`, `abs_diff`, `rel_diff` and `ulps_diff`. The
+/// relative difference is scaled to the larger of the two operands and the
+/// ULPs difference is left empty when it is undefined (see
+/// [`AssertFloatEq::debug_ulps_diff`]).
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidInput`] without writing
+/// anything if the slices differ in length.
+///
+/// # Examples
+/// ```
+/// # use float_eq::write_diff_csv;
+/// let a = [1.0f32, 2.0];
+/// let b = [1.0f32, -2.0];
+///
+/// let mut csv = Vec::new();
+/// write_diff_csv(&mut csv, &a, &b).unwrap();
+/// assert_eq!(
+///     String::from_utf8(csv).unwrap(),
+///     "index,a,b,abs_diff,rel_diff,ulps_diff\n\
+///      0,1,1,0,0,0\n\
+///      1,2,-2,4,2,\n"
+/// );
+/// ```
+///
+/// [`AssertFloatEq::debug_ulps_diff`]: trait.AssertFloatEq.html#tymethod.debug_ulps_diff
+pub fn write_diff_csv<W, T>(mut writer: W, a: &[T], b: &[T]) -> io::Result<()>
+where
+    W: io::Write,
+    T: FloatPrimitive,
+{
+    if a.len() != b.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "cannot write diff of slices with different lengths ({} and {})",
+                a.len(),
+                b.len()
+            ),
+        ));
+    }
+
+    writeln!(writer, "index,a,b,abs_diff,rel_diff,ulps_diff")?;
+    for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+        write!(
+            writer,
+            "{},{},{},{},{},",
+            i,
+            a,
+            b,
+            (*a - *b).abs_value(),
+            a.rel_diff(*b)
+        )?;
+        match a.ulps_diff(*b) {
+            Some(ulps) => writeln!(writer, "{}", ulps)?,
+            None => writeln!(writer)?,
+        }
+    }
+    Ok(())
+}
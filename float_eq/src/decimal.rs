@@ -0,0 +1,418 @@
+use crate::FloatPrimitive;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
+
+// Enough digits to exactly represent any finite f32 or f64 in scientific
+// notation, the longest being the smallest f64 subnormals at 767 digits.
+const EXACT_DIGITS: usize = 800;
+
+/// Check whether a float agrees with a decimal number to `sig_figs`
+/// significant digits.
+///
+/// `expected` is parsed with full precision rather than being rounded to the
+/// nearest representable float first, so that reference values may be pasted
+/// verbatim from papers or tables. It may have a leading sign, a fractional
+/// part and an exponent, for example `"-1.602176634e-19"`.
+///
+/// The values agree if they differ by at most half a unit in the last of the
+/// `sig_figs` significant digits of `expected`. If `expected` is zero then it
+/// has no significant digits, so `value` must also be zero. Infinities and NaN
+/// never agree with any decimal number.
+///
+/// # Panics
+///
+/// Panics if `expected` is not a valid decimal number, or if `sig_figs` is
+/// zero.
+///
+/// # Examples
+/// ```
+/// # use float_eq::eq_sig_figs;
+/// let pi = core::f64::consts::PI;
+/// assert!(eq_sig_figs(pi, "3.14159265358979", 15));
+/// assert!(eq_sig_figs(pi as f32, "3.14159265358979", 7));
+/// assert!(!eq_sig_figs(pi as f32, "3.14159265358979", 8));
+/// ```
+#[track_caller]
+pub fn eq_sig_figs<T: FloatPrimitive>(value: T, expected: &str, sig_figs: u32) -> bool {
+    let expected = match Decimal::parse(expected) {
+        Some(expected) => expected,
+        None => panic!("`{}` is not a valid decimal number", expected),
+    };
+    match Decimal::from_float(value) {
+        Some(value) => value.eq_sig_figs(&expected, sig_figs),
+        None => false,
+    }
+}
+
+/// How [`eq_rounded`] and [`round_to_increment`] treat values that lie exactly
+/// half-way between two multiples of the increment.
+///
+/// [`eq_rounded`]: fn.eq_rounded.html
+/// [`round_to_increment`]: fn.round_to_increment.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round half-way values away from zero, so `0.125` rounds up to `0.13`.
+    HalfAwayFromZero,
+    /// Round half-way values to the even multiple, so `0.125` rounds down to
+    /// `0.12`, also known as banker's rounding. This avoids the upward bias of
+    /// always rounding half-way values up, and is required by many accounting
+    /// standards.
+    HalfEven,
+}
+
+/// Check whether two floats are equal once both are rounded to the nearest
+/// multiple of `increment`, such as `0.01` for cents.
+///
+/// This suits financial calculations, where amounts are only ever compared
+/// after being rounded, so values either side of a rounding boundary differ
+/// even if they are very close, and values that round alike are equal however
+/// far apart they are.
+///
+/// Values are rounded as decimals, using the shortest decimal number that
+/// converts back to them, which is how they are displayed. So `2.675`, which
+/// is stored as slightly less than `2.675`, rounds up to `2.68` as written
+/// rather than down to `2.67`. The rounding is then exact, and `rounding`
+/// chooses how values that lie exactly half-way between two multiples are
+/// treated. Infinities are only equal to the same infinity, and NaN is never
+/// equal to anything.
+///
+/// # Panics
+///
+/// Panics if `increment` is not positive and finite.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{eq_rounded, Rounding};
+/// assert!(eq_rounded(2.675, 2.68, 0.01, Rounding::HalfAwayFromZero));
+/// assert!(eq_rounded(0.125, 0.12, 0.01, Rounding::HalfEven));
+/// assert!(!eq_rounded(0.125, 0.12, 0.01, Rounding::HalfAwayFromZero));
+/// assert!(eq_rounded(10.02f32, 10.0, 0.05, Rounding::HalfEven));
+/// ```
+#[track_caller]
+pub fn eq_rounded<T: FloatPrimitive>(a: T, b: T, increment: T, rounding: Rounding) -> bool {
+    let increment = increment_decimal(increment);
+    match (
+        Decimal::from_float_shortest(a),
+        Decimal::from_float_shortest(b),
+    ) {
+        (Some(a), Some(b)) => a.round_to(&increment, rounding) == b.round_to(&increment, rounding),
+        // Both are infinite or NaN, and NaN is never equal to itself.
+        (None, None) => a == b,
+        _ => false,
+    }
+}
+
+/// Round a float to the nearest multiple of `increment`, as compared by
+/// [`eq_rounded`].
+///
+/// The exact decimal result is converted back to the nearest float, so
+/// rounding to `0.01` gives the same value as the corresponding literal, such
+/// as `2.68`. Infinities and NaN are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if `increment` is not positive and finite.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{round_to_increment, Rounding};
+/// assert_eq!(round_to_increment(2.675, 0.01, Rounding::HalfAwayFromZero), 2.68);
+/// assert_eq!(round_to_increment(2.665, 0.01, Rounding::HalfEven), 2.66);
+/// assert_eq!(round_to_increment(-7.3f32, 0.25, Rounding::HalfEven), -7.25);
+/// ```
+///
+/// [`eq_rounded`]: fn.eq_rounded.html
+#[track_caller]
+pub fn round_to_increment<T: FloatPrimitive>(value: T, increment: T, rounding: Rounding) -> T {
+    let increment = increment_decimal(increment);
+    match Decimal::from_float_shortest(value) {
+        Some(decimal) => decimal.round_to(&increment, rounding).to_float(),
+        None => value,
+    }
+}
+
+#[track_caller]
+fn increment_decimal<T: FloatPrimitive>(increment: T) -> Decimal {
+    assert!(
+        increment > T::ZERO && increment < T::INFINITY,
+        "increment must be positive and finite, not {}",
+        increment
+    );
+    // A positive, finite float always formats as a valid decimal.
+    Decimal::from_float_shortest(increment).unwrap()
+}
+
+// An exact decimal number, whose value is the sum of each digit multiplied by
+// 10^(exp - i), where i is the index of the digit. Digits have no leading or
+// trailing zeros, and zero has no digits.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Decimal {
+    neg: bool,
+    digits: Vec<u8>,
+    exp: i64,
+}
+
+impl Decimal {
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (neg, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], parse_exp(&s[i + 1..])?),
+            None => (s, 0),
+        };
+        let (int_part, frac_part) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+
+        let mut digits = Vec::with_capacity(int_part.len() + frac_part.len());
+        for c in int_part.bytes().chain(frac_part.bytes()) {
+            if !c.is_ascii_digit() {
+                return None;
+            }
+            digits.push(c - b'0');
+        }
+        let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+        digits.drain(..leading_zeros);
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        if digits.is_empty() {
+            Some(Decimal {
+                neg,
+                digits,
+                exp: 0,
+            })
+        } else {
+            let exp = exp.checked_add(int_part.len() as i64 - leading_zeros as i64 - 1)?;
+            Some(Decimal { neg, digits, exp })
+        }
+    }
+
+    // None if the value is an infinity or NaN.
+    pub(crate) fn from_float<T: FloatPrimitive>(value: T) -> Option<Self> {
+        let exact = format!("{:.*e}", EXACT_DIGITS, value);
+        Decimal::parse(&exact)
+    }
+
+    // The shortest decimal that converts back to the value, rather than its
+    // exact value. None if the value is an infinity or NaN.
+    fn from_float_shortest<T: FloatPrimitive>(value: T) -> Option<Self> {
+        Decimal::parse(&format!("{:e}", value))
+    }
+
+    // The nearest float to the value.
+    fn to_float<T: FloatPrimitive>(&self) -> T {
+        let digits: String = self.digits.iter().map(|d| char::from(b'0' + d)).collect();
+        let sign = if self.neg { "-" } else { "" };
+        let s = format!("{}0.{}e{}", sign, digits, self.exp + 1);
+        // The string is always a valid float, which saturates to infinity.
+        s.parse().unwrap_or(T::NAN)
+    }
+
+    // The value rounded to the nearest multiple of increment, which must be
+    // positive and have at most 37 digits.
+    fn round_to(&self, increment: &Decimal, rounding: Rounding) -> Decimal {
+        // Divide in units of a tenth of the last digit of increment, so that
+        // half of increment is a whole number of units. Digits below this only
+        // matter in deciding whether the remainder is exactly half.
+        let low = increment.low_exp() - 1;
+        let divisor = increment
+            .digits
+            .iter()
+            .fold(0u128, |n, &d| n * 10 + u128::from(d))
+            * 10;
+        let trailing_zeros = (self.low_exp() - low).max(0) as usize;
+        let mut quotient = Vec::new();
+        let mut rem = 0u128;
+        let mut below_half_unit = false;
+        for (i, &d) in self.digits.iter().enumerate() {
+            if self.exp - (i as i64) < low {
+                below_half_unit |= d != 0;
+                continue;
+            }
+            rem = rem * 10 + u128::from(d);
+            quotient.push((rem / divisor) as u8);
+            rem %= divisor;
+        }
+        for _ in 0..trailing_zeros {
+            rem *= 10;
+            quotient.push((rem / divisor) as u8);
+            rem %= divisor;
+        }
+
+        // The divisor is even, so a remainder below half of it stays below
+        // half with any digits beneath it added on.
+        let round_up = match (rem * 2).cmp(&divisor) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal if below_half_unit => true,
+            Ordering::Equal => match rounding {
+                Rounding::HalfAwayFromZero => true,
+                Rounding::HalfEven => matches!(quotient.last(), Some(d) if d % 2 == 1),
+            },
+        };
+        if round_up {
+            increment_digits(&mut quotient);
+        }
+
+        let multiple = mul_digits(&quotient, divisor / 10);
+        let mut rounded = Decimal::from_digits(&multiple, increment.low_exp());
+        rounded.neg = self.neg && !rounded.is_zero();
+        rounded
+    }
+
+    // A positive value from digits, most significant first, the last of which
+    // has the exponent low_exp.
+    fn from_digits(digits: &[u8], low_exp: i64) -> Self {
+        let s: String = digits.iter().map(|d| char::from(b'0' + d)).collect();
+        // The digits and exponent always form a valid decimal.
+        Decimal::parse(&format!("0{}e{}", s, low_exp)).unwrap()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    // The exponent of the last digit.
+    fn low_exp(&self) -> i64 {
+        self.exp - self.digits.len() as i64 + 1
+    }
+
+    #[track_caller]
+    pub(crate) fn eq_sig_figs(&self, expected: &Decimal, sig_figs: u32) -> bool {
+        assert!(sig_figs > 0, "sig_figs must be at least one");
+        if expected.is_zero() || self.is_zero() {
+            return self.is_zero() && expected.is_zero();
+        }
+        // A value an order of magnitude larger or smaller than expected always
+        // differs by more than a unit in its first significant digit, as does
+        // one whose exponent is too far away to subtract.
+        match self.exp.checked_sub(expected.exp) {
+            Some(-1..=1) => {}
+            _ => return false,
+        }
+
+        // Half a unit in the last significant digit of expected.
+        let tol_exp = expected.exp - i64::from(sig_figs);
+        let low = self.low_exp().min(expected.low_exp());
+        if tol_exp < low {
+            // The difference is a multiple of 10^low, so it must be zero.
+            return self == expected;
+        }
+        // Leave room for a carry when adding magnitudes.
+        let high = self.exp.max(expected.exp) + 1;
+
+        let a = self.aligned(low, high);
+        let b = expected.aligned(low, high);
+        let diff = if self.neg == expected.neg {
+            match cmp_aligned(&a, &b) {
+                Ordering::Less => sub_aligned(&b, &a),
+                _ => sub_aligned(&a, &b),
+            }
+        } else {
+            add_aligned(&a, &b)
+        };
+        let tol = Decimal {
+            neg: false,
+            digits: vec![5],
+            exp: tol_exp,
+        };
+        cmp_aligned(&diff, &tol.aligned(low, high)) != Ordering::Greater
+    }
+
+    // The digits of the magnitude from 10^low up to 10^high, least significant
+    // first.
+    fn aligned(&self, low: i64, high: i64) -> Vec<u8> {
+        let mut out = vec![0; (high - low + 1) as usize];
+        for (i, d) in self.digits.iter().enumerate() {
+            out[(self.exp - i as i64 - low) as usize] = *d;
+        }
+        out
+    }
+}
+
+fn parse_exp(s: &str) -> Option<i64> {
+    let digits = s
+        .strip_prefix('-')
+        .or_else(|| s.strip_prefix('+'))
+        .unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn cmp_aligned(a: &[u8], b: &[u8]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+fn add_aligned(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut carry = 0;
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| {
+            let sum = a + b + carry;
+            carry = sum / 10;
+            sum % 10
+        })
+        .collect()
+}
+
+// Requires that a >= b.
+fn sub_aligned(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut borrow = 0;
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| {
+            let sub = b + borrow;
+            if *a >= sub {
+                borrow = 0;
+                a - sub
+            } else {
+                borrow = 1;
+                a + 10 - sub
+            }
+        })
+        .collect()
+}
+
+// Adds one to digits, most significant first.
+fn increment_digits(digits: &mut Vec<u8>) {
+    for d in digits.iter_mut().rev() {
+        if *d == 9 {
+            *d = 0;
+        } else {
+            *d += 1;
+            return;
+        }
+    }
+    digits.insert(0, 1);
+}
+
+// Multiplies digits, most significant first, by a small factor.
+fn mul_digits(digits: &[u8], factor: u128) -> Vec<u8> {
+    let mut out = Vec::with_capacity(digits.len() + 40);
+    let mut carry = 0u128;
+    for &d in digits.iter().rev() {
+        let product = u128::from(d) * factor + carry;
+        out.push((product % 10) as u8);
+        carry = product / 10;
+    }
+    while carry > 0 {
+        out.push((carry % 10) as u8);
+        carry /= 10;
+    }
+    out.reverse();
+    out
+}
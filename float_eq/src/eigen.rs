@@ -0,0 +1,311 @@
+#[cfg(feature = "num")]
+use crate::FloatEq;
+use crate::{FloatPrimitive, SquareMatrix};
+use core::cmp::Ordering;
+#[cfg(feature = "num")]
+use num_complex::Complex;
+
+/// The eigenvalues of the symmetric part of a square matrix, `(M + Mᵀ) / 2`,
+/// in ascending order.
+///
+/// The eigenvalues are found using the cyclic Jacobi method, which is slow
+/// for large matrices but accurate to within a few ULPs of the largest
+/// eigenvalue, so it is well suited to checking the small matrices found in
+/// tests. Using the symmetric part means that a matrix which should be
+/// symmetric but is not quite, due to round-off, is still handled sensibly.
+///
+/// If any element of the matrix is NaN, the eigenvalues will be too, and they
+/// are ordered first.
+///
+/// # Examples
+/// ```
+/// # use float_eq::symmetric_eigenvalues;
+/// let m = [[2.0f64, 1.0], [1.0, 2.0]];
+/// let eigenvalues = symmetric_eigenvalues(&m);
+///
+/// assert!((eigenvalues[0] - 1.0).abs() < 1e-15);
+/// assert!((eigenvalues[1] - 3.0).abs() < 1e-15);
+/// ```
+pub fn symmetric_eigenvalues<M>(m: &M) -> Vec<M::Elem>
+where
+    M: ?Sized + SquareMatrix,
+    M::Elem: FloatPrimitive,
+{
+    const MAX_SWEEPS: usize = 64;
+
+    let n = m.dim();
+    let two = M::Elem::ONE + M::Elem::ONE;
+    let mut a: Vec<M::Elem> = Vec::with_capacity(n * n);
+    for row in 0..n {
+        for col in 0..n {
+            a.push((*m.elem(row, col) + *m.elem(col, row)) / two);
+        }
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                let app = a[p * n + p];
+                let aqq = a[q * n + q];
+                if is_negligible(apq, app, aqq) {
+                    a[p * n + q] = M::Elem::ZERO;
+                    a[q * n + p] = M::Elem::ZERO;
+                    continue;
+                }
+                rotated = true;
+
+                let theta = (aqq - app) / (two * apq);
+                let t = M::Elem::ONE
+                    / (theta.abs_value() + (theta * theta + M::Elem::ONE).sqrt_value());
+                let t = if theta < M::Elem::ZERO { -t } else { t };
+                let c = M::Elem::ONE / (t * t + M::Elem::ONE).sqrt_value();
+                let s = t * c;
+
+                for k in 0..n {
+                    let akp = a[k * n + p];
+                    let akq = a[k * n + q];
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let apk = a[p * n + k];
+                    let aqk = a[q * n + k];
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                a[p * n + q] = M::Elem::ZERO;
+                a[q * n + p] = M::Elem::ZERO;
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut eigenvalues: Vec<M::Elem> = (0..n).map(|i| a[i * n + i]).collect();
+    eigenvalues.sort_by(|x, y| match (x.partial_cmp(y), is_nan(*x), is_nan(*y)) {
+        (Some(ordering), _, _) => ordering,
+        (None, true, false) => Ordering::Less,
+        (None, false, true) => Ordering::Greater,
+        (None, _, _) => Ordering::Equal,
+    });
+    eigenvalues
+}
+
+/// Check whether every eigenvalue is at least `floor`, as used by
+/// [`assert_positive_definite!`].
+///
+/// Eigenvalues that are NaN are never at least the floor.
+///
+/// # Examples
+/// ```
+/// # use float_eq::eigenvalues_at_least;
+/// assert!(eigenvalues_at_least(&[-1e-12f64, 0.5, 2.0], -1e-10));
+/// assert!(!eigenvalues_at_least(&[-1e-12f64, 0.5, 2.0], 0.0));
+/// ```
+///
+/// [`assert_positive_definite!`]: macro.assert_positive_definite.html
+pub fn eigenvalues_at_least<T: FloatPrimitive>(eigenvalues: &[T], floor: T) -> bool {
+    eigenvalues.iter().all(|x| {
+        matches!(
+            x.partial_cmp(&floor),
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        )
+    })
+}
+
+/// Why a set of complex eigenvalues does not match the expected set, as
+/// returned by [`match_complex_eigenvalues`].
+///
+/// [`match_complex_eigenvalues`]: fn.match_complex_eigenvalues.html
+#[cfg(feature = "num")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EigenvalueMismatch<T> {
+    /// The sets hold different numbers of eigenvalues.
+    Len {
+        /// The number of actual eigenvalues.
+        actual: usize,
+        /// The number of expected eigenvalues.
+        expected: usize,
+    },
+    /// An actual eigenvalue with a nonzero imaginary part has no conjugate to
+    /// pair with.
+    Unpaired {
+        /// The position of the eigenvalue among the actual eigenvalues.
+        index: usize,
+        /// The eigenvalue.
+        value: Complex<T>,
+    },
+    /// An expected eigenvalue is not equal to any actual eigenvalue that is
+    /// not already matched with another.
+    Unmatched {
+        /// The position of the eigenvalue among the expected eigenvalues.
+        index: usize,
+        /// The eigenvalue.
+        value: Complex<T>,
+    },
+}
+
+/// Matches a set of complex eigenvalues against an expected set, in any
+/// order.
+///
+/// Eigen-solvers make no promise about the order of their output, and the
+/// eigenvalues of a real matrix come in conjugate pairs, whose members a
+/// solver may return in either order, or with imaginary parts that are only
+/// nonzero due to round-off. So first, each actual eigenvalue whose imaginary
+/// part is beyond `tol` is paired with a distinct actual eigenvalue that is
+/// equal to its conjugate, and then every expected eigenvalue is matched with
+/// a distinct actual one that is equal to it. Values are equal if both their
+/// real and imaginary parts are within an [absolute tolerance] of `tol`.
+///
+/// Returns the position among the actual eigenvalues matched with each
+/// expected eigenvalue, or the first [`EigenvalueMismatch`] found. Since
+/// values are matched one to one, a repeated eigenvalue must be repeated in
+/// both sets. Matching takes time proportional to the cube of the number of
+/// eigenvalues, in the worst case.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{match_complex_eigenvalues, EigenvalueMismatch};
+/// use num_complex::Complex;
+///
+/// let actual = [
+///     Complex::new(1.0f64, -2.0),
+///     Complex::new(3.0, 1e-12),
+///     Complex::new(1.0, 2.0),
+/// ];
+/// let expected = [Complex::new(3.0, 0.0), Complex::new(1.0, 2.0), Complex::new(1.0, -2.0)];
+/// assert_eq!(match_complex_eigenvalues(&actual, &expected, 1e-9), Ok(vec![1, 2, 0]));
+///
+/// let unpaired = [Complex::new(1.0f64, 2.0), Complex::new(1.0, 2.0), Complex::new(3.0, 0.0)];
+/// assert_eq!(
+///     match_complex_eigenvalues(&unpaired, &expected, 1e-9),
+///     Err(EigenvalueMismatch::Unpaired { index: 0, value: Complex::new(1.0, 2.0) })
+/// );
+/// ```
+///
+/// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+/// [`EigenvalueMismatch`]: enum.EigenvalueMismatch.html
+#[cfg(feature = "num")]
+pub fn match_complex_eigenvalues<T>(
+    actual: &[Complex<T>],
+    expected: &[Complex<T>],
+    tol: T,
+) -> Result<Vec<usize>, EigenvalueMismatch<T>>
+where
+    T: FloatPrimitive + FloatEq<Tol = T>,
+{
+    if actual.len() != expected.len() {
+        return Err(EigenvalueMismatch::Len {
+            actual: actual.len(),
+            expected: expected.len(),
+        });
+    }
+    let eq = |a: &Complex<T>, b: &Complex<T>| a.re.eq_abs(&b.re, &tol) && a.im.eq_abs(&b.im, &tol);
+
+    // Pair each eigenvalue above the real axis with one below it.
+    let is_real = |x: &Complex<T>| x.im.eq_abs(&T::ZERO, &tol);
+    let (upper, lower): (Vec<usize>, Vec<usize>) = (0..actual.len())
+        .filter(|&i| !is_real(&actual[i]))
+        .partition(|&i| actual[i].im > T::ZERO);
+    let pairs = bipartite_match(upper.len(), lower.len(), |u, l| {
+        let (a, b) = (actual[upper[u]], actual[lower[l]]);
+        eq(&a, &Complex::new(b.re, T::ZERO - b.im))
+    });
+    let paired_lower: Vec<bool> = {
+        let mut paired = vec![false; lower.len()];
+        for l in pairs.iter().flatten() {
+            paired[*l] = true;
+        }
+        paired
+    };
+    let unpaired = upper
+        .iter()
+        .zip(&pairs)
+        .filter(|(_, l)| l.is_none())
+        .map(|(i, _)| *i)
+        .chain(
+            lower
+                .iter()
+                .zip(&paired_lower)
+                .filter(|(_, paired)| !**paired)
+                .map(|(i, _)| *i),
+        )
+        .min();
+    if let Some(index) = unpaired {
+        return Err(EigenvalueMismatch::Unpaired {
+            index,
+            value: actual[index],
+        });
+    }
+
+    let matches = bipartite_match(expected.len(), actual.len(), |e, a| {
+        eq(&actual[a], &expected[e])
+    });
+    matches
+        .iter()
+        .enumerate()
+        .map(|(index, a)| {
+            a.ok_or(EigenvalueMismatch::Unmatched {
+                index,
+                value: expected[index],
+            })
+        })
+        .collect()
+}
+
+// A maximum matching of a bipartite graph, as the right vertex matched with
+// each left vertex, found by Kuhn's augmenting path algorithm.
+#[cfg(feature = "num")]
+fn bipartite_match<F>(left: usize, right: usize, edge: F) -> Vec<Option<usize>>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    fn augment<F: Fn(usize, usize) -> bool>(
+        l: usize,
+        edge: &F,
+        visited: &mut [bool],
+        right_match: &mut [Option<usize>],
+    ) -> bool {
+        for r in 0..right_match.len() {
+            if !visited[r] && edge(l, r) {
+                visited[r] = true;
+                let free = match right_match[r] {
+                    None => true,
+                    Some(other) => augment(other, edge, visited, right_match),
+                };
+                if free {
+                    right_match[r] = Some(l);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    let mut right_match = vec![None; right];
+    for l in 0..left {
+        let mut visited = vec![false; right];
+        augment(l, &edge, &mut visited, &mut right_match);
+    }
+    let mut left_match = vec![None; left];
+    for (r, l) in right_match.iter().enumerate() {
+        if let Some(l) = l {
+            left_match[*l] = Some(r);
+        }
+    }
+    left_match
+}
+
+// Whether an off-diagonal element is small enough relative to the diagonal
+// elements it would be rotated into that it may be treated as zero.
+fn is_negligible<T: FloatPrimitive>(apq: T, app: T, aqq: T) -> bool {
+    apq.abs_value() <= T::EPSILON * (app.abs_value() + aqq.abs_value())
+}
+
+#[allow(clippy::eq_op)]
+fn is_nan<T: FloatPrimitive>(value: T) -> bool {
+    value != value
+}
@@ -0,0 +1,131 @@
+use crate::{FloatEq, FloatEqUlpsTol};
+
+macro_rules! impl_check_op {
+    ($(#[$meta:meta])* $name:ident, $check:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<T, Tol>(pub T, pub Tol);
+
+        impl<T, Tol, Rhs> PartialEq<Rhs> for $name<T, Tol>
+        where
+            T: FloatEq<Rhs, Tol = Tol>,
+        {
+            #[inline]
+            fn eq(&self, other: &Rhs) -> bool {
+                self.0.$check(other, &self.1)
+            }
+        }
+    };
+}
+
+impl_check_op!(
+    /// A value that is compared via an [absolute tolerance] check when used
+    /// with `==` or `!=`.
+    ///
+    /// `Abs(a, tol) == b` is equivalent to `float_eq!(a, b, abs <= tol)`, for
+    /// use where an operator is more convenient than a macro, such as inside
+    /// the closures passed to `filter` or `position`. The other checks are
+    /// provided by [`Rmax`], [`Rmin`], [`R1st`], [`R2nd`] and [`Ulps`].
+    ///
+    /// The wrapper must be the left operand, and only values of the type it
+    /// may be compared against are accepted as the right operand.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::Abs;
+    /// assert!(Abs(1.0f64, 1e-6) == 1.000_000_1);
+    /// assert!(Abs([1.0f32, 2.0], [0.1, 0.2]) != [1.0, 2.5]);
+    ///
+    /// let values = [0.1f64, 0.2, 0.30000000000000004];
+    /// assert_eq!(values.iter().position(|&x| Abs(x, 1e-9) == 0.3), Some(2));
+    /// ```
+    ///
+    /// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    /// [`Rmax`]: struct.Rmax.html
+    /// [`Rmin`]: struct.Rmin.html
+    /// [`R1st`]: struct.R1st.html
+    /// [`R2nd`]: struct.R2nd.html
+    /// [`Ulps`]: struct.Ulps.html
+    Abs,
+    eq_abs
+);
+
+impl_check_op!(
+    /// A value that is compared via a [relative tolerance] check, scaled to
+    /// the larger magnitude operand, when used with `==` or `!=`.
+    ///
+    /// `Rmax(a, tol) == b` is equivalent to `float_eq!(a, b, rmax <= tol)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::Rmax;
+    /// let values = [10.0f32, 100.0, 1000.0];
+    /// assert_eq!(values.iter().filter(|&&x| Rmax(x, 0.01) == 99.5).count(), 1);
+    /// ```
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmax,
+    eq_rmax
+);
+
+impl_check_op!(
+    /// A value that is compared via a [relative tolerance] check, scaled to
+    /// the smaller magnitude operand, when used with `==` or `!=`.
+    ///
+    /// `Rmin(a, tol) == b` is equivalent to `float_eq!(a, b, rmin <= tol)`.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmin,
+    eq_rmin
+);
+
+impl_check_op!(
+    /// A value that is compared via a [relative tolerance] check, scaled to
+    /// the first operand, when used with `==` or `!=`.
+    ///
+    /// `R1st(a, tol) == b` is equivalent to `float_eq!(a, b, r1st <= tol)`.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R1st,
+    eq_r1st
+);
+
+impl_check_op!(
+    /// A value that is compared via a [relative tolerance] check, scaled to
+    /// the second operand, when used with `==` or `!=`.
+    ///
+    /// `R2nd(a, tol) == b` is equivalent to `float_eq!(a, b, r2nd <= tol)`.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R2nd,
+    eq_r2nd
+);
+
+/// A value that is compared via an [ULPs] check when used with `==` or `!=`.
+///
+/// `Ulps(a, tol) == b` is equivalent to `float_eq!(a, b, ulps <= tol)`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::Ulps;
+/// assert!(Ulps(1.0f32, 1) == 1.000_000_1);
+/// assert!(Ulps(1.0f32, 1) != 1.000_000_2);
+///
+/// let values = [1.0f64, 2.0, 3.0];
+/// assert!(values.iter().any(|&x| Ulps(x, 4) == 2.000_000_000_000_001));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+#[derive(Clone, Copy, Debug)]
+pub struct Ulps<T, Tol>(pub T, pub Tol);
+
+impl<T, Tol, Rhs> PartialEq<Rhs> for Ulps<T, Tol>
+where
+    T: FloatEq<Rhs>,
+    T::Tol: FloatEqUlpsTol<UlpsTol = Tol>,
+{
+    #[inline]
+    fn eq(&self, other: &Rhs) -> bool {
+        self.0.eq_ulps(other, &self.1)
+    }
+}
@@ -0,0 +1,82 @@
+use core::fmt;
+
+/// A field of a derived struct that was not equal to the same field of
+/// another, as listed by the `diff_fields` method generated when deriving
+/// [`AssertFloatEq`].
+///
+/// Values are held as their `fmt::Debug` representations, since the types of
+/// the fields are erased so that they may be listed together. This allows code
+/// such as state reconciliation or replay verification to find out which
+/// fields differ, and by how much, without parsing an assert's failure message.
+///
+/// Only available in `std` builds.
+///
+/// # Examples
+/// ```
+/// # #[cfg(all(feature = "derive", not(feature = "checks-only")))]
+/// # {
+/// # use float_eq::{derive_float_eq, FieldDiff};
+/// #[derive_float_eq(ulps_tol = "StateUlps", debug_ulps_diff = "StateDebugUlpsDiff")]
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct State {
+///     position: f32,
+///     velocity: f32,
+/// }
+///
+/// let expected = State { position: 1.0, velocity: 2.0 };
+/// let replayed = State { position: 1.0, velocity: 2.5 };
+/// let tol = State { position: 1e-6, velocity: 1e-6 };
+///
+/// let diffs = replayed.diff_fields(&expected, &tol);
+/// assert_eq!(diffs.len(), 1);
+/// assert_eq!(diffs[0].field, "velocity");
+/// assert_eq!(diffs[0].abs_diff, "0.5");
+/// # }
+/// ```
+///
+/// [`AssertFloatEq`]: trait.AssertFloatEq.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The name of the field, or its index for a tuple struct, as listed by
+    /// the `FIELDS` constant of the derived type.
+    pub field: &'static str,
+    /// The value of the field in the struct that `diff_fields` was called on.
+    pub left: String,
+    /// The value of the field in the struct it was compared with.
+    pub right: String,
+    /// The absolute difference between the values, as given by
+    /// [`AssertFloatEq::debug_abs_diff`].
+    ///
+    /// [`AssertFloatEq::debug_abs_diff`]: trait.AssertFloatEq.html#tymethod.debug_abs_diff
+    pub abs_diff: String,
+    /// The difference between the values in ULPs, as given by
+    /// [`AssertFloatEq::debug_ulps_diff`].
+    ///
+    /// [`AssertFloatEq::debug_ulps_diff`]: trait.AssertFloatEq.html#tymethod.debug_ulps_diff
+    pub ulps_diff: String,
+}
+
+impl FieldDiff {
+    /// Describe a field that was not equal, by formatting its values and the
+    /// differences between them.
+    pub fn new<T, A, U>(
+        field: &'static str,
+        left: &T,
+        right: &T,
+        abs_diff: &A,
+        ulps_diff: &U,
+    ) -> Self
+    where
+        T: ?Sized + fmt::Debug,
+        A: ?Sized + fmt::Debug,
+        U: ?Sized + fmt::Debug,
+    {
+        FieldDiff {
+            field,
+            left: format!("{:?}", left),
+            right: format!("{:?}", right),
+            abs_diff: format!("{:?}", abs_diff),
+            ulps_diff: format!("{:?}", ulps_diff),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+/// A signed fixed point number in a Q format, held as its raw integer value
+/// along with the number of fractional bits, such as the output of an integer
+/// DSP implementation.
+///
+/// Its value is `raw / 2^frac_bits`, so that one least significant bit (LSB)
+/// is `2^-frac_bits`. For example, the Q15 format of a 16-bit DSP has 15
+/// fractional bits, covering `[-1, 1)` in steps of about `3.05e-5`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::QFixed;
+/// let half = QFixed::q15(0x4000);
+/// assert_eq!(half.to_f64(), 0.5);
+/// assert_eq!(half.lsb(), 1.0 / 32768.0);
+/// assert_eq!(QFixed::new(-3, 2).to_f64(), -0.75);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QFixed {
+    raw: i64,
+    frac_bits: u32,
+}
+
+impl QFixed {
+    /// A fixed point number with the given raw value and number of fractional
+    /// bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frac_bits` is greater than 63.
+    #[track_caller]
+    pub fn new(raw: i64, frac_bits: u32) -> Self {
+        assert!(
+            frac_bits <= 63,
+            "a Q format has at most 63 fractional bits, not {}",
+            frac_bits
+        );
+        QFixed { raw, frac_bits }
+    }
+
+    /// A number in the Q15 format, a 16-bit integer with 15 fractional bits.
+    pub fn q15(raw: i16) -> Self {
+        Self::new(raw.into(), 15)
+    }
+
+    /// A number in the Q31 format, a 32-bit integer with 31 fractional bits.
+    pub fn q31(raw: i32) -> Self {
+        Self::new(raw.into(), 31)
+    }
+
+    /// The raw integer value.
+    pub fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    /// The number of fractional bits.
+    pub fn frac_bits(&self) -> u32 {
+        self.frac_bits
+    }
+
+    /// The value of one least significant bit, `2^-frac_bits`.
+    pub fn lsb(&self) -> f64 {
+        pow2(-(self.frac_bits as i32))
+    }
+
+    /// The value as an `f64`, which is exact unless the raw value needs more
+    /// than 53 bits.
+    pub fn to_f64(&self) -> f64 {
+        self.raw as f64 * self.lsb()
+    }
+
+    /// The absolute difference between `value` and `self`, measured in LSBs.
+    ///
+    /// This is NaN if `value` is NaN, and infinite if it is infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::QFixed;
+    /// let expected = QFixed::q15(16384);
+    /// assert_eq!(expected.lsb_diff(0.5), 0.0);
+    /// assert_eq!(expected.lsb_diff(0.5 + 1.5 / 32768.0), 1.5);
+    /// assert_eq!(expected.lsb_diff(0.25f32), 8192.0);
+    /// ```
+    pub fn lsb_diff<T: Into<f64>>(&self, value: T) -> f64 {
+        // Scaling by a power of two is exact, so the only rounding is in the
+        // subtraction, and of raw values that need more than 53 bits.
+        let scaled = value.into() * pow2(self.frac_bits as i32);
+        let diff = scaled - self.raw as f64;
+        if diff < 0.0 {
+            -diff
+        } else {
+            diff
+        }
+    }
+}
+
+/// Check whether a float is within `tol` least significant bits of a fixed
+/// point reference.
+///
+/// This is intended for validating a floating point reference model against
+/// an integer DSP implementation, where the expected rounding error of the
+/// fixed point calculation is naturally expressed in LSBs of its format. The
+/// tolerance may be fractional, such as `0.5` to allow only for rounding the
+/// float to the nearest fixed point value. NaN and infinite values are never
+/// equal to any fixed point number.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{eq_fixed, QFixed};
+/// let expected = QFixed::q15(9830); // 0.29998779296875
+/// assert!(eq_fixed(0.3f32, expected, 0.5));
+/// assert!(!eq_fixed(0.3001f32, expected, 0.5));
+/// assert!(eq_fixed(0.3001f32, expected, 4.0));
+/// ```
+pub fn eq_fixed<T: Into<f64>>(value: T, expected: QFixed, tol: f64) -> bool {
+    expected.lsb_diff(value) <= tol
+}
+
+// 2^exp for exponents in the range of normal f64s.
+fn pow2(exp: i32) -> f64 {
+    f64::from_bits(((1023 + exp) as u64) << 52)
+}
@@ -0,0 +1,511 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
+use crate::{CheckSet, FloatEq, FloatEqAll, FloatEqUlpsTol, UlpsTol};
+use core::fmt;
+use core::str::FromStr;
+
+/// A reusable set of checks and their tolerances, applied together.
+///
+/// This captures a composite tolerance such as `rmax <= 1e-6, ulps <= 4` as a
+/// value, so that it may be defined once and then stored, passed to helper
+/// functions and shared between tests, instead of being repeated at each
+/// assert. It is applied by [`eq`] and [`ne`], or by the `tol(cmp)` check of
+/// [`float_eq!`], [`assert_float_eq!`] and the related macros.
+///
+/// As with a list of checks passed to [`float_eq!`], the values are equal if
+/// any one of the checks that have been given a tolerance passes, trying them
+/// in the order `abs`, `rel`, `rmax`, `rmin`, `r1st`, `r2nd` and `ulps`. If no
+/// tolerances have been given, no values are equal. Checks that are not among
+/// the [`FloatEq::SUPPORTED_CHECKS`] of the values being compared are skipped
+/// by [`eq`], as if they had not been given, and may be found beforehand with
+/// [`unsupported_checks`].
+///
+/// The tolerances are of the [`FloatEq::Tol`] of the values being compared,
+/// which for a primitive is the same type as the values themselves. The `_all`
+/// forms, [`eq_all`] and `tol_all(cmp)`, instead take [`FloatEqAll::AllTol`],
+/// so that a single tolerance is applied to every field of a composite type.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, float_ne, FloatCmp};
+/// let cmp = FloatCmp::<f64>::new().abs(1e-9).rmax(1e-6).ulps(4);
+///
+/// assert!(cmp.eq(&1.0, &1.000_000_1));
+/// assert!(cmp.ne(&1.0, &1.01));
+/// assert!(float_eq!(0.0, 1e-10, tol(&cmp)));
+/// assert!(float_ne!(0.0, 1e-8, tol(&cmp)));
+///
+/// // The `_all` form applies each tolerance to every element.
+/// assert!(float_eq!([1.0, 2.0], [1.000_000_1, 2.0], tol_all(&cmp)));
+/// ```
+///
+/// A comparison is displayed as its list of checks, in the same form as they
+/// are written in [`float_eq!`], and may be parsed back from one, so that each
+/// case of a parameterized test may be written as a string:
+///
+/// ```
+/// # use float_eq::FloatCmp;
+/// let cmp: FloatCmp<f64> = "rmax <= 1e-6, ulps <= 4".parse().unwrap();
+/// assert_eq!(cmp, FloatCmp::new().rmax(1e-6).ulps(4));
+/// assert_eq!(cmp.to_string(), "rmax <= 1e-6, ulps <= 4");
+/// ```
+///
+/// This makes it suitable as an `rstest` argument, which converts string
+/// literal cases and values via `FromStr`, including when expanding a matrix of
+/// them:
+///
+/// ```ignore
+/// #[rstest]
+/// #[case::tight("ulps <= 2")]
+/// #[case::loose("abs <= 1e-9, rmax <= 1e-6")]
+/// fn sqrt_round_trips(
+///     #[case] cmp: FloatCmp<f64>,
+///     #[values(0.5, 2.0, 1e10)] x: f64,
+/// ) {
+///     assert_float_eq!(x.sqrt() * x.sqrt(), x, tol(&cmp), "{}", cmp);
+/// }
+/// ```
+///
+/// [`eq`]: #method.eq
+/// [`eq_all`]: #method.eq_all
+/// [`float_eq!`]: macro.float_eq.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`FloatEq::Tol`]: trait.FloatEq.html#associatedtype.Tol
+/// [`FloatEqAll::AllTol`]: trait.FloatEqAll.html#associatedtype.AllTol
+/// [`FloatEq::SUPPORTED_CHECKS`]: trait.FloatEq.html#associatedconstant.SUPPORTED_CHECKS
+/// [`ne`]: #method.ne
+/// [`unsupported_checks`]: #method.unsupported_checks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatCmp<T>
+where
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    abs: Option<T>,
+    rel: Option<T>,
+    rmax: Option<T>,
+    rmin: Option<T>,
+    r1st: Option<T>,
+    r2nd: Option<T>,
+    ulps: Option<T::UlpsTol>,
+}
+
+impl<T> FloatCmp<T>
+where
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    /// A comparison without any checks, to which they may be added.
+    pub fn new() -> Self {
+        FloatCmp {
+            abs: None,
+            rel: None,
+            rmax: None,
+            rmin: None,
+            r1st: None,
+            r2nd: None,
+            ulps: None,
+        }
+    }
+
+    /// Adds an [absolute tolerance] comparison.
+    ///
+    /// [absolute tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    pub fn abs(mut self, tol: T) -> Self {
+        self.abs = Some(tol);
+        self
+    }
+
+    /// Adds a [relative tolerance] comparison, which is the same as [`rmax`].
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`rmax`]: #method.rmax
+    pub fn rel(mut self, tol: T) -> Self {
+        self.rel = Some(tol);
+        self
+    }
+
+    /// Adds a [relative tolerance] comparison, scaled to the larger magnitude.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub fn rmax(mut self, tol: T) -> Self {
+        self.rmax = Some(tol);
+        self
+    }
+
+    /// Adds a [relative tolerance] comparison, scaled to the smaller magnitude.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub fn rmin(mut self, tol: T) -> Self {
+        self.rmin = Some(tol);
+        self
+    }
+
+    /// Adds a [relative tolerance] comparison, scaled to the magnitude of the
+    /// first operand.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub fn r1st(mut self, tol: T) -> Self {
+        self.r1st = Some(tol);
+        self
+    }
+
+    /// Adds a [relative tolerance] comparison, scaled to the magnitude of the
+    /// second operand.
+    ///
+    /// [relative tolerance]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    pub fn r2nd(mut self, tol: T) -> Self {
+        self.r2nd = Some(tol);
+        self
+    }
+
+    /// Adds an [ULPs comparison].
+    ///
+    /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub fn ulps(mut self, tol: UlpsTol<T>) -> Self {
+        self.ulps = Some(tol);
+        self
+    }
+
+    /// The `abs` tolerance, if one was given.
+    pub fn abs_tol(&self) -> Option<&T> {
+        self.abs.as_ref()
+    }
+
+    /// The `rel` tolerance, if one was given.
+    pub fn rel_tol(&self) -> Option<&T> {
+        self.rel.as_ref()
+    }
+
+    /// The `rmax` tolerance, if one was given.
+    pub fn rmax_tol(&self) -> Option<&T> {
+        self.rmax.as_ref()
+    }
+
+    /// The `rmin` tolerance, if one was given.
+    pub fn rmin_tol(&self) -> Option<&T> {
+        self.rmin.as_ref()
+    }
+
+    /// The `r1st` tolerance, if one was given.
+    pub fn r1st_tol(&self) -> Option<&T> {
+        self.r1st.as_ref()
+    }
+
+    /// The `r2nd` tolerance, if one was given.
+    pub fn r2nd_tol(&self) -> Option<&T> {
+        self.r2nd.as_ref()
+    }
+
+    /// The `ulps` tolerance, if one was given.
+    pub fn ulps_tol(&self) -> Option<&UlpsTol<T>> {
+        self.ulps.as_ref()
+    }
+
+    /// The checks that have been given a tolerance, with `rel` included as
+    /// `rmax`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::{CheckSet, FloatCmp};
+    /// let cmp = FloatCmp::<f32>::new().rel(1e-6).ulps(4);
+    /// assert_eq!(cmp.checks(), CheckSet::RMAX.union(CheckSet::ULPS));
+    /// ```
+    pub fn checks(&self) -> CheckSet {
+        let given = [
+            (self.abs.is_some(), CheckSet::ABS),
+            (self.rel.is_some() || self.rmax.is_some(), CheckSet::RMAX),
+            (self.rmin.is_some(), CheckSet::RMIN),
+            (self.r1st.is_some(), CheckSet::R1ST),
+            (self.r2nd.is_some(), CheckSet::R2ND),
+            (self.ulps.is_some(), CheckSet::ULPS),
+        ];
+        given
+            .iter()
+            .filter(|(is_given, _)| *is_given)
+            .fold(CheckSet::NONE, |checks, (_, check)| checks.union(*check))
+    }
+
+    /// The checks that have been given a tolerance but are not supported by
+    /// values of type `A`, which [`eq`] skips when comparing them.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "approx")]
+    /// # {
+    /// # use float_eq::{CheckSet, FloatCmp, FromApprox};
+    /// let cmp = FloatCmp::<f64>::new().rmin(1e-6).ulps(4);
+    /// assert_eq!(cmp.unsupported_checks::<FromApprox<f64>, _>(), CheckSet::RMIN);
+    /// assert!(cmp.unsupported_checks::<f64, f64>().is_empty());
+    /// # }
+    /// ```
+    ///
+    /// [`eq`]: #method.eq
+    pub fn unsupported_checks<A, B>(&self) -> CheckSet
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        self.checks().difference(A::SUPPORTED_CHECKS)
+    }
+
+    /// Whether `a` and `b` are equal by any of the checks that they support.
+    #[inline]
+    pub fn eq<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        let supported = |check| A::SUPPORTED_CHECKS.contains(check);
+        matches!(&self.abs, Some(tol) if supported(CheckSet::ABS) && a.eq_abs(b, tol))
+            || matches!(&self.rel, Some(tol) if supported(CheckSet::RMAX) && a.eq_rel(b, tol))
+            || matches!(&self.rmax, Some(tol) if supported(CheckSet::RMAX) && a.eq_rmax(b, tol))
+            || matches!(&self.rmin, Some(tol) if supported(CheckSet::RMIN) && a.eq_rmin(b, tol))
+            || matches!(&self.r1st, Some(tol) if supported(CheckSet::R1ST) && a.eq_r1st(b, tol))
+            || matches!(&self.r2nd, Some(tol) if supported(CheckSet::R2ND) && a.eq_r2nd(b, tol))
+            || matches!(&self.ulps, Some(tol) if supported(CheckSet::ULPS) && a.eq_ulps(b, tol))
+    }
+
+    /// Whether `a` and `b` are not equal by every one of the checks.
+    #[inline]
+    pub fn ne<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        !self.eq(a, b)
+    }
+
+    /// Whether `a` and `b` are equal by any of the `_all` checks, which apply
+    /// each tolerance to every field.
+    #[inline]
+    pub fn eq_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        matches!(&self.abs, Some(tol) if a.eq_abs_all(b, tol))
+            || matches!(&self.rel, Some(tol) if a.eq_rel_all(b, tol))
+            || matches!(&self.rmax, Some(tol) if a.eq_rmax_all(b, tol))
+            || matches!(&self.rmin, Some(tol) if a.eq_rmin_all(b, tol))
+            || matches!(&self.r1st, Some(tol) if a.eq_r1st_all(b, tol))
+            || matches!(&self.r2nd, Some(tol) if a.eq_r2nd_all(b, tol))
+            || matches!(&self.ulps, Some(tol) if a.eq_ulps_all(b, tol))
+    }
+
+    /// Whether `a` and `b` are not equal by every one of the `_all` checks.
+    #[inline]
+    pub fn ne_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        !self.eq_all(a, b)
+    }
+
+    /// The tolerances against which the difference between `a` and `b` is
+    /// compared by each check, as displayed when an assert using [`eq`] fails.
+    ///
+    /// Checks that `a` does not support are left out, since [`eq`] skips them.
+    ///
+    /// [`eq`]: #method.eq
+    #[cfg(not(feature = "checks-only"))]
+    pub fn debug_tol<A, B>(&self, a: &A, b: &B) -> FloatCmp<A::DebugTol>
+    where
+        A: ?Sized + AssertFloatEq<B, Tol = T>,
+        B: ?Sized,
+        UlpsTol<A::DebugTol>: Sized,
+    {
+        let supported = |check| A::SUPPORTED_CHECKS.contains(check);
+        let rel_supported = supported(CheckSet::RMAX);
+        FloatCmp {
+            abs: self
+                .abs
+                .as_ref()
+                .filter(|_| supported(CheckSet::ABS))
+                .map(|tol| a.debug_abs_tol(b, tol)),
+            rel: self
+                .rel
+                .as_ref()
+                .filter(|_| rel_supported)
+                .map(|tol| a.debug_rel_tol(b, tol)),
+            rmax: self
+                .rmax
+                .as_ref()
+                .filter(|_| rel_supported)
+                .map(|tol| a.debug_rmax_tol(b, tol)),
+            rmin: self
+                .rmin
+                .as_ref()
+                .filter(|_| supported(CheckSet::RMIN))
+                .map(|tol| a.debug_rmin_tol(b, tol)),
+            r1st: self
+                .r1st
+                .as_ref()
+                .filter(|_| supported(CheckSet::R1ST))
+                .map(|tol| a.debug_r1st_tol(b, tol)),
+            r2nd: self
+                .r2nd
+                .as_ref()
+                .filter(|_| supported(CheckSet::R2ND))
+                .map(|tol| a.debug_r2nd_tol(b, tol)),
+            ulps: self
+                .ulps
+                .as_ref()
+                .filter(|_| supported(CheckSet::ULPS))
+                .map(|tol| a.debug_ulps_tol(b, tol)),
+        }
+    }
+
+    /// The tolerances against which the difference between `a` and `b` is
+    /// compared by each check, as displayed when an assert using [`eq_all`]
+    /// fails.
+    ///
+    /// [`eq_all`]: #method.eq_all
+    #[cfg(not(feature = "checks-only"))]
+    pub fn debug_all_tol<A, B>(&self, a: &A, b: &B) -> FloatCmp<A::AllDebugTol>
+    where
+        A: ?Sized + AssertFloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+        UlpsTol<A::AllDebugTol>: Sized,
+    {
+        FloatCmp {
+            abs: self.abs.as_ref().map(|tol| a.debug_abs_all_tol(b, tol)),
+            rel: self.rel.as_ref().map(|tol| a.debug_rel_all_tol(b, tol)),
+            rmax: self.rmax.as_ref().map(|tol| a.debug_rmax_all_tol(b, tol)),
+            rmin: self.rmin.as_ref().map(|tol| a.debug_rmin_all_tol(b, tol)),
+            r1st: self.r1st.as_ref().map(|tol| a.debug_r1st_all_tol(b, tol)),
+            r2nd: self.r2nd.as_ref().map(|tol| a.debug_r2nd_all_tol(b, tol)),
+            ulps: self.ulps.as_ref().map(|tol| a.debug_ulps_all_tol(b, tol)),
+        }
+    }
+}
+
+impl<T> Default for FloatCmp<T>
+where
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Display for FloatCmp<T>
+where
+    T: FloatEqUlpsTol + fmt::Debug,
+    UlpsTol<T>: Sized + fmt::Debug,
+{
+    /// Writes the checks that have been given a tolerance, such as
+    /// `rmax <= 1e-6, ulps <= 4`, or nothing if there are none.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let checks: [(&str, Option<&dyn fmt::Debug>); 7] = [
+            ("abs", self.abs.as_ref().map(|t| t as _)),
+            ("rel", self.rel.as_ref().map(|t| t as _)),
+            ("rmax", self.rmax.as_ref().map(|t| t as _)),
+            ("rmin", self.rmin.as_ref().map(|t| t as _)),
+            ("r1st", self.r1st.as_ref().map(|t| t as _)),
+            ("r2nd", self.r2nd.as_ref().map(|t| t as _)),
+            ("ulps", self.ulps.as_ref().map(|t| t as _)),
+        ];
+        let mut separator = "";
+        for (name, tol) in checks.iter() {
+            if let Some(tol) = tol {
+                write!(f, "{}{} <= {:?}", separator, name, tol)?;
+                separator = ", ";
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> FromStr for FloatCmp<T>
+where
+    T: FloatEqUlpsTol + FromStr,
+    UlpsTol<T>: Sized + FromStr,
+{
+    type Err = ParseFloatCmpError;
+
+    /// Parses a comma separated list of checks, such as `rmax <= 1e-6,
+    /// ulps <= 4`, as they would be written in [`float_eq!`].
+    ///
+    /// The checks are `abs`, `rel`, `rmax`, `rmin`, `r1st`, `r2nd` and `ulps`,
+    /// each of which may appear at most once, in any order.
+    ///
+    /// [`float_eq!`]: macro.float_eq.html
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        const CHECKS: [&str; 7] = ["abs", "rel", "rmax", "rmin", "r1st", "r2nd", "ulps"];
+
+        if spec.trim().is_empty() {
+            return Err(ParseFloatCmpError(ParseErrorKind::Empty));
+        }
+        let mut cmp = FloatCmp::new();
+        for check in spec.split(',') {
+            let (name, tol) = check
+                .split_once("<=")
+                .ok_or(ParseFloatCmpError(ParseErrorKind::Malformed))?;
+            let name = *CHECKS
+                .iter()
+                .find(|&&check| check == name.trim())
+                .ok_or(ParseFloatCmpError(ParseErrorKind::UnknownCheck))?;
+            let tol = tol.trim();
+            cmp = match name {
+                "abs" if cmp.abs.is_none() => cmp.abs(parse_tol(name, tol)?),
+                "rel" if cmp.rel.is_none() => cmp.rel(parse_tol(name, tol)?),
+                "rmax" if cmp.rmax.is_none() => cmp.rmax(parse_tol(name, tol)?),
+                "rmin" if cmp.rmin.is_none() => cmp.rmin(parse_tol(name, tol)?),
+                "r1st" if cmp.r1st.is_none() => cmp.r1st(parse_tol(name, tol)?),
+                "r2nd" if cmp.r2nd.is_none() => cmp.r2nd(parse_tol(name, tol)?),
+                "ulps" if cmp.ulps.is_none() => cmp.ulps(parse_tol(name, tol)?),
+                _ => return Err(ParseFloatCmpError(ParseErrorKind::Repeated(name))),
+            };
+        }
+        Ok(cmp)
+    }
+}
+
+fn parse_tol<T: FromStr>(check: &'static str, tol: &str) -> Result<T, ParseFloatCmpError> {
+    tol.parse()
+        .map_err(|_| ParseFloatCmpError(ParseErrorKind::InvalidTol(check)))
+}
+
+/// The error returned when parsing a [`FloatCmp`] from a string fails.
+///
+/// [`FloatCmp`]: struct.FloatCmp.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFloatCmpError(ParseErrorKind);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    Empty,
+    Malformed,
+    UnknownCheck,
+    Repeated(&'static str),
+    InvalidTol(&'static str),
+}
+
+impl fmt::Display for ParseFloatCmpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ParseErrorKind::Empty => f.write_str("no checks were given"),
+            ParseErrorKind::Malformed => {
+                f.write_str("expected checks of the form `name <= tol`, separated by commas")
+            }
+            ParseErrorKind::UnknownCheck => f.write_str(
+                "unknown check, expected one of `abs`, `rel`, `rmax`, `rmin`, `r1st`, `r2nd` or `ulps`",
+            ),
+            ParseErrorKind::Repeated(check) => {
+                write!(f, "the `{}` check was given more than once", check)
+            }
+            ParseErrorKind::InvalidTol(check) => {
+                write!(f, "invalid tolerance for the `{}` check", check)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFloatCmpError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for ParseFloatCmpError {}
@@ -0,0 +1,56 @@
+use crate::FloatPrimitive;
+
+/// How multiply-add operations are evaluated, used for differential testing
+/// of fused and unfused arithmetic via [`fma_differential`].
+///
+/// Rust never contracts `a * b + c` into a fused multiply-add (FMA) by itself,
+/// but ports of numerical code frequently adopt [`f32::mul_add`] in places, and
+/// other languages and compilers may contract expressions freely. Writing the
+/// multiply-adds of an algorithm in terms of `MulAdd` allows it to be run both
+/// ways, to check that it is not overly sensitive to the difference in rounding.
+///
+/// [`fma_differential`]: fn.fma_differential.html
+/// [`f32::mul_add`]: https://doc.rust-lang.org/std/primitive.f32.html#method.mul_add
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MulAdd {
+    /// Compute `a * b + c` with a single rounding, as an FMA instruction would.
+    Fused,
+    /// Compute `a * b + c` with a rounding after both the multiply and the add.
+    Unfused,
+}
+
+impl MulAdd {
+    /// Compute `a * b + c`.
+    #[inline]
+    pub fn mul_add<T: FloatPrimitive>(self, a: T, b: T, c: T) -> T {
+        match self {
+            MulAdd::Fused => a.fused_mul_add(b, c),
+            MulAdd::Unfused => a * b + c,
+        }
+    }
+}
+
+/// Run `f` with fused and then unfused multiply-adds, returning both results
+/// as `(fused, unfused)`.
+///
+/// See [`assert_fma_agrees!`] to assert that the results are equal.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{fma_differential, float_eq};
+/// let x = 0.1f64;
+/// let (fused, unfused) = fma_differential(|m| m.mul_add(x, 10.0, -1.0));
+/// assert_eq!(fused, 5.551115123125783e-17);
+/// assert_eq!(unfused, 0.0);
+/// assert!(float_eq!(fused, unfused, abs <= 1e-16));
+/// ```
+///
+/// [`assert_fma_agrees!`]: macro.assert_fma_agrees.html
+pub fn fma_differential<R, F>(mut f: F) -> (R, R)
+where
+    F: FnMut(MulAdd) -> R,
+{
+    let fused = f(MulAdd::Fused);
+    let unfused = f(MulAdd::Unfused);
+    (fused, unfused)
+}
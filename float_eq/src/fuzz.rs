@@ -0,0 +1,193 @@
+//! Entry points for fuzzing the comparison and formatting code paths.
+//!
+//! These are not part of the public API and may change at any time. Each
+//! function takes inputs that a fuzzer can generate directly and panics if it
+//! finds the library disagreeing with itself, so that a fuzz target need only
+//! call it, for example:
+//!
+//! ```ignore
+//! fuzz_target!(|input: (&str, f64, f64)| {
+//!     let (spec, a, b) = input;
+//!     float_eq::fuzz::compare(spec, a, b);
+//! });
+//! ```
+
+use crate::{AssertFailure, AssertFloatEq, FailureFormat, FailureReport, FloatCmp};
+use core::fmt;
+
+/// Parses a tolerance spec, a comma separated list of checks of the form
+/// `rmax <= 1e-6`, as they would be written in [`float_eq!`], via the `FromStr`
+/// impl of [`FloatCmp`].
+///
+/// The checks are `abs`, `rel`, `rmax`, `rmin`, `r1st`, `r2nd` and `ulps`,
+/// each of which may appear at most once. Returns `None` if the spec is empty
+/// or malformed, or with the `validate-tol` feature if a tolerance is negative
+/// or NaN, since comparing with it would panic.
+///
+/// [`float_eq!`]: ../macro.float_eq.html
+/// [`FloatCmp`]: ../struct.FloatCmp.html
+pub fn parse_tol_spec(spec: &str) -> Option<FloatCmp<f64>> {
+    let cmp: FloatCmp<f64> = spec.parse().ok()?;
+    let tols = [
+        cmp.abs_tol(),
+        cmp.rel_tol(),
+        cmp.rmax_tol(),
+        cmp.rmin_tol(),
+        cmp.r1st_tol(),
+        cmp.r2nd_tol(),
+    ];
+    let is_invalid = |tol: &f64| tol.is_nan() || *tol < 0.0;
+    if cfg!(feature = "validate-tol") && tols.iter().flatten().any(|tol| is_invalid(tol)) {
+        None
+    } else {
+        Some(cmp)
+    }
+}
+
+/// Compares `a` and `b` with the checks of a tolerance spec, as parsed by
+/// [`parse_tol_spec`], returning whether they are equal.
+///
+/// Panics if the different ways of applying the same checks disagree, which
+/// are the [`FloatCmp`] methods, the `tol` and `tol_all` checks of
+/// [`float_eq!`] and [`float_ne!`], and each of the checks individually.
+/// Returns `None` if the spec could not be parsed.
+///
+/// [`float_eq!`]: ../macro.float_eq.html
+/// [`float_ne!`]: ../macro.float_ne.html
+/// [`FloatCmp`]: ../struct.FloatCmp.html
+/// [`parse_tol_spec`]: fn.parse_tol_spec.html
+pub fn compare(spec: &str, a: f64, b: f64) -> Option<bool> {
+    let cmp = parse_tol_spec(spec)?;
+    let is_eq = cmp.eq(&a, &b);
+
+    assert_eq!(cmp.ne(&a, &b), !is_eq, "ne disagrees with eq");
+    assert_eq!(
+        crate::float_eq!(a, b, tol(&cmp)),
+        is_eq,
+        "tol check disagrees"
+    );
+    assert_eq!(
+        crate::float_ne!(a, b, tol(&cmp)),
+        !is_eq,
+        "tol check disagrees"
+    );
+    assert_eq!(cmp.eq_all(&[a], &[b]), is_eq, "eq_all disagrees");
+    assert_eq!(
+        crate::float_eq!([a], [b], tol_all(&cmp)),
+        is_eq,
+        "tol_all check disagrees"
+    );
+
+    let is_any_eq = matches!(cmp.abs_tol(), Some(t) if crate::float_eq!(a, b, abs <= *t))
+        || matches!(cmp.rel_tol(), Some(t) if crate::float_eq!(a, b, rel <= *t))
+        || matches!(cmp.rmax_tol(), Some(t) if crate::float_eq!(a, b, rmax <= *t))
+        || matches!(cmp.rmin_tol(), Some(t) if crate::float_eq!(a, b, rmin <= *t))
+        || matches!(cmp.r1st_tol(), Some(t) if crate::float_eq!(a, b, r1st <= *t))
+        || matches!(cmp.r2nd_tol(), Some(t) if crate::float_eq!(a, b, r2nd <= *t))
+        || matches!(cmp.ulps_tol(), Some(t) if crate::float_eq!(a, b, ulps <= *t));
+    assert_eq!(is_any_eq, is_eq, "individual checks disagree");
+
+    Some(is_eq)
+}
+
+/// Renders the message that `assert_float_eq!(a, b, tol(&cmp))` displays on
+/// failure, where `cmp` is parsed from a tolerance spec by [`parse_tol_spec`],
+/// with an optional custom message.
+///
+/// The message is rendered whether or not the values are equal. Panics if the
+/// [`FailureReport`] for the same failure does not hold the same details, or
+/// if a [`FailureFormat::KeyValue`] message is not a single line. Returns
+/// `None` if the spec could not be parsed.
+///
+/// [`FailureFormat::KeyValue`]: ../enum.FailureFormat.html#variant.KeyValue
+/// [`FailureReport`]: ../struct.FailureReport.html
+/// [`parse_tol_spec`]: fn.parse_tol_spec.html
+pub fn render_report(
+    spec: &str,
+    a: f64,
+    b: f64,
+    message: Option<&str>,
+    format: FailureFormat,
+) -> Option<String> {
+    let cmp = parse_tol_spec(spec)?;
+    let fields: &[(&str, &dyn fmt::Debug)] = &[
+        ("left", &a),
+        ("right", &b),
+        ("abs_diff", &a.debug_abs_diff(&b)),
+        ("ulps_diff", &a.debug_ulps_diff(&b)),
+        ("[tol] t", &cmp.debug_tol(&a, &b)),
+    ];
+    let check = "float_eq!(left, right, tol(cmp))";
+    let note = a.debug_categories(&b);
+    let ulps_diff_reason = a.debug_ulps_diff_reason(&b);
+    let rendered = match message {
+        Some(message) => render(
+            &AssertFailure {
+                check,
+                fields,
+                note,
+                ulps_diff_reason,
+                message: Some(format_args!("{}", message)),
+            },
+            format,
+        ),
+        None => render(
+            &AssertFailure {
+                check,
+                fields,
+                note,
+                ulps_diff_reason,
+                message: None,
+            },
+            format,
+        ),
+    };
+    Some(rendered)
+}
+
+fn render(failure: &AssertFailure<'_>, format: FailureFormat) -> String {
+    let rendered = Rendered(failure, format).to_string();
+
+    let report = FailureReport::from(failure);
+    assert_eq!(report.check, failure.check, "report check differs");
+    assert_eq!(report.note, failure.note, "report note differs");
+    assert_eq!(
+        report.ulps_diff_reason, failure.ulps_diff_reason,
+        "report ulps_diff_reason differs"
+    );
+    assert_eq!(
+        report.message,
+        failure.message.map(|message| message.to_string()),
+        "report message differs"
+    );
+    assert_eq!(
+        report.fields.len(),
+        failure.fields.len(),
+        "report fields differ"
+    );
+    for ((label, value), (report_label, report_value)) in failure.fields.iter().zip(&report.fields)
+    {
+        assert_eq!(label, report_label, "report label differs");
+        assert_eq!(
+            &format!("{:?}", value),
+            report_value,
+            "report value differs"
+        );
+    }
+    if format == FailureFormat::KeyValue {
+        assert!(
+            !rendered.contains('\n'),
+            "key-value message is not one line"
+        );
+    }
+    rendered
+}
+
+// Displays a failure in a given format, rather than the current one.
+struct Rendered<'a, 'b>(&'a AssertFailure<'b>, FailureFormat);
+
+impl fmt::Display for Rendered<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_as(f, self.1)
+    }
+}
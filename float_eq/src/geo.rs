@@ -0,0 +1,88 @@
+/// The mean radius of the Earth in meters, as defined by the IUGG, which is
+/// used to convert great-circle angles to distances.
+pub const EARTH_MEAN_RADIUS: f64 = 6_371_008.8;
+
+/// A geodetic position, given by its latitude and longitude in degrees and its
+/// altitude in meters, which may be compared by [`assert_geo_eq!`].
+///
+/// Comparing latitude and longitude as separate components with a tolerance
+/// in degrees is misleading, since a degree of longitude shrinks to nothing
+/// towards the poles, and positions either side of the antimeridian differ by
+/// almost 360° of longitude despite being close together. Instead, positions
+/// are compared by the [`great_circle_distance`] between them, with their
+/// altitudes compared separately.
+///
+/// # Examples
+/// ```
+/// # use float_eq::GeoPoint;
+/// let fiji = GeoPoint::new(-17.0, 179.999_9);
+/// let across_antimeridian = GeoPoint::new(-17.0, -179.999_9);
+///
+/// assert!(fiji.eq_geo(&across_antimeridian, 25.0, 0.0));
+/// ```
+///
+/// [`assert_geo_eq!`]: macro.assert_geo_eq.html
+/// [`great_circle_distance`]: fn.great_circle_distance.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeoPoint {
+    /// Latitude in degrees, positive to the north.
+    pub lat: f64,
+    /// Longitude in degrees, positive to the east.
+    pub lon: f64,
+    /// Altitude in meters.
+    pub alt: f64,
+}
+
+impl GeoPoint {
+    /// A position at the given latitude and longitude in degrees, with an
+    /// altitude of zero.
+    #[inline]
+    pub fn new(lat: f64, lon: f64) -> Self {
+        GeoPoint { lat, lon, alt: 0.0 }
+    }
+
+    /// This position at the given altitude in meters.
+    #[inline]
+    pub fn with_alt(self, alt: f64) -> Self {
+        GeoPoint { alt, ..self }
+    }
+
+    /// Check whether this position is within `meters` of `other` along the
+    /// surface of the Earth, and within `alt` meters of it in altitude.
+    ///
+    /// Positions with a NaN coordinate are never equal.
+    #[inline]
+    pub fn eq_geo(&self, other: &Self, meters: f64, alt: f64) -> bool {
+        great_circle_distance(self, other) <= meters && (self.alt - other.alt).abs() <= alt
+    }
+}
+
+/// The distance in meters between two positions along the surface of a
+/// spherical Earth of radius [`EARTH_MEAN_RADIUS`], ignoring their altitudes.
+///
+/// The distance is calculated using the haversine formula, which remains
+/// accurate for the small distances typically used as tolerances. Modelling
+/// the Earth as a sphere rather than an ellipsoid introduces an error of up
+/// to around 0.5% of the distance, which is rarely significant at that scale.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{great_circle_distance, GeoPoint};
+/// let a = GeoPoint::new(89.999_9, 0.0);
+/// let b = GeoPoint::new(89.999_9, 180.0);
+///
+/// // About 22 meters apart, through the north pole.
+/// assert!((great_circle_distance(&a, &b) - 22.24).abs() < 0.01);
+/// ```
+///
+/// [`EARTH_MEAN_RADIUS`]: constant.EARTH_MEAN_RADIUS.html
+pub fn great_circle_distance(a: &GeoPoint, b: &GeoPoint) -> f64 {
+    let (lat_a, lat_b) = (a.lat.to_radians(), b.lat.to_radians());
+    let half_dlat = (lat_b - lat_a) / 2.0;
+    let half_dlon = (b.lon - a.lon).to_radians() / 2.0;
+    let h = half_dlat.sin().powi(2) + lat_a.cos() * lat_b.cos() * half_dlon.sin().powi(2);
+    // Round-off may push h just past one for antipodal positions. This is
+    // written as a comparison rather than using min so that NaN propagates.
+    let h = if h > 1.0 { 1.0 } else { h };
+    2.0 * EARTH_MEAN_RADIUS * h.sqrt().asin()
+}
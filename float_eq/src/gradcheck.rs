@@ -0,0 +1,166 @@
+use crate::FloatPrimitive;
+
+/// The absolute difference between two values, relative to the largest of
+/// their magnitudes and a `typical` magnitude.
+///
+/// This is the error measure of the standard gradient check recipe, used to
+/// validate analytic or automatic derivatives against numerical ones. Scaling
+/// by at least `typical` keeps the error meaningful for components that are
+/// close to zero, where a purely relative error would be dominated by noise.
+///
+/// Returns zero if the values are equal, including if both are zero or both
+/// are the same infinity.
+///
+/// # Examples
+/// ```
+/// # use float_eq::gradcheck_rel_diff;
+/// assert_eq!(gradcheck_rel_diff(2.0f64, 2.5, 1.0), 0.2);
+/// assert_eq!(gradcheck_rel_diff(1e-9f64, 2e-9, 1.0), 1e-9);
+/// ```
+#[inline]
+#[allow(clippy::float_cmp)]
+pub fn gradcheck_rel_diff<T: FloatPrimitive>(a: T, b: T, typical: T) -> T {
+    if a == b {
+        T::ZERO
+    } else {
+        let mut scale = a.abs_value();
+        for x in [b.abs_value(), typical.abs_value()].iter() {
+            if *x > scale {
+                scale = *x;
+            }
+        }
+        (a - b).abs_value() / scale
+    }
+}
+
+/// Check whether two values agree to within `tol` using
+/// [`gradcheck_rel_diff`].
+///
+/// # Examples
+/// ```
+/// # use float_eq::eq_gradcheck;
+/// assert!(eq_gradcheck(1e-9f64, 2e-9, 1.0, 1e-6));
+/// assert!(!eq_gradcheck(1e-9f64, 2e-9, 1e-9, 1e-6));
+/// ```
+///
+/// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+#[inline]
+pub fn eq_gradcheck<T: FloatPrimitive>(a: T, b: T, typical: T, tol: T) -> bool {
+    gradcheck_rel_diff(a, b, typical) <= tol
+}
+
+/// The pair of elements with the largest [`gradcheck_rel_diff`], returned by
+/// [`gradcheck_worst`].
+///
+/// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+/// [`gradcheck_worst`]: fn.gradcheck_worst.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradcheckWorst<T: FloatPrimitive> {
+    /// The position of the elements in their slices.
+    pub index: usize,
+    /// The element from the analytic gradient.
+    pub analytic: T,
+    /// The element from the numeric gradient.
+    pub numeric: T,
+    /// The [`gradcheck_rel_diff`] between the elements.
+    ///
+    /// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+    pub rel_diff: T,
+}
+
+/// The elements of an analytic and a numeric gradient with the largest
+/// [`gradcheck_rel_diff`].
+///
+/// Elements where the difference is NaN are considered the worst, and ties are
+/// broken in favour of the earliest index.
+///
+/// Returns `None` if the slices are empty or differ in length.
+///
+/// # Examples
+/// ```
+/// # use float_eq::gradcheck_worst;
+/// let analytic = [1.0f64, -2.0, 0.0];
+/// let numeric = [1.0f64, -2.1, 1e-7];
+///
+/// let worst = gradcheck_worst(&analytic, &numeric, 1.0).unwrap();
+/// assert_eq!(worst.index, 1);
+/// ```
+///
+/// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+pub fn gradcheck_worst<T: FloatPrimitive>(
+    analytic: &[T],
+    numeric: &[T],
+    typical: T,
+) -> Option<GradcheckWorst<T>> {
+    if analytic.len() != numeric.len() {
+        return None;
+    }
+    let mut worst: Option<GradcheckWorst<T>> = None;
+    for (index, (a, n)) in analytic.iter().zip(numeric.iter()).enumerate() {
+        let rel_diff = gradcheck_rel_diff(*a, *n, typical);
+        let is_worse = match &worst {
+            None => true,
+            Some(w) => !is_nan(w.rel_diff) && (is_nan(rel_diff) || rel_diff > w.rel_diff),
+        };
+        if is_worse {
+            worst = Some(GradcheckWorst {
+                index,
+                analytic: *a,
+                numeric: *n,
+                rel_diff,
+            });
+        }
+    }
+    worst
+}
+
+#[allow(clippy::eq_op)]
+fn is_nan<T: FloatPrimitive>(value: T) -> bool {
+    value != value
+}
+
+/// The central finite difference approximation of the derivative of `f` at
+/// `x`, with step size `h`.
+///
+/// This is `(f(x + h) - f(x - h)) / 2h`, whose truncation error is of order
+/// `h²`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::central_difference;
+/// let d = central_difference(|x: f64| x * x * x, 2.0, 1e-3);
+/// assert!((d - 12.0).abs() < 1e-5);
+/// ```
+#[inline]
+pub fn central_difference<T, F>(mut f: F, x: T, h: T) -> T
+where
+    T: FloatPrimitive,
+    F: FnMut(T) -> T,
+{
+    (f(x + h) - f(x - h)) / (h + h)
+}
+
+/// The tolerance used by [`assert_derivative_eq!`] when comparing an analytic
+/// derivative with a [`central_difference`] of step size `h`.
+///
+/// This is `tol + h² + EPSILON / h`, which widens `tol` by the order of the
+/// truncation and rounding errors of the finite difference, assuming that the
+/// function and its derivatives are of order one. Note that the rounding error
+/// grows as `h` shrinks, so steps near the cube root of `EPSILON` are best.
+///
+/// [`assert_derivative_eq!`]: macro.assert_derivative_eq.html
+/// [`central_difference`]: fn.central_difference.html
+#[inline]
+pub fn central_difference_tol<T: FloatPrimitive>(tol: T, h: T) -> T {
+    let h = h.abs_value();
+    tol + h * h + T::EPSILON / h
+}
+
+/// The [`gradcheck_rel_diff`] between an analytic derivative and a numeric
+/// one, with a typical magnitude of one.
+///
+/// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+#[inline]
+pub fn derivative_rel_diff<T: FloatPrimitive>(analytic: T, numeric: T) -> T {
+    gradcheck_rel_diff(analytic, numeric, T::ONE)
+}
@@ -0,0 +1,456 @@
+use crate::FloatPrimitive;
+use core::fmt;
+
+// The number of significands in each binade of an f32.
+const BINADE: u64 = 1 << 23;
+
+/// The `f32` inputs visited by a differential test of a single precision
+/// function, such as every one of them.
+///
+/// Testing every `f32` input is the standard way to validate a single
+/// precision math function, since there are only 2³² of them. When that takes
+/// too long, a stratified sample of each binade can stand in for it.
+///
+/// # Examples
+/// ```
+/// # use float_eq::F32Sweep;
+/// let report = F32Sweep::stratified(64).compare(
+///     |x| x.sqrt(),
+///     |x| (x as f64).sqrt() as f32,
+/// );
+/// assert!(report.is_within(0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct F32Sweep {
+    per_binade: Option<u32>,
+}
+
+impl F32Sweep {
+    /// Every `f32` bit pattern, including every NaN.
+    pub fn all() -> Self {
+        F32Sweep { per_binade: None }
+    }
+
+    /// `per_binade` inputs from each binade of both signs, evenly spaced
+    /// through its significands and always including the first and last of
+    /// them. The binades include the subnormals, and the infinities and NaNs.
+    ///
+    /// A binade only has 2²³ inputs, so larger samples visit all of them.
+    pub fn stratified(per_binade: u32) -> Self {
+        F32Sweep {
+            per_binade: Some(per_binade).filter(|&n| u64::from(n) < BINADE),
+        }
+    }
+
+    /// The inputs visited by this sweep, in order of their bit patterns.
+    pub fn inputs(&self) -> impl Iterator<Item = f32> {
+        let per_binade = self.per_binade.map_or(BINADE, u64::from);
+        (0..1u64 << 9).flat_map(move |binade| {
+            (0..per_binade).map(move |i| {
+                let significand = if per_binade == 1 {
+                    0
+                } else {
+                    i * (BINADE - 1) / (per_binade - 1)
+                };
+                f32::from_bits((binade * BINADE + significand) as u32)
+            })
+        })
+    }
+
+    /// Compare `f` against `reference` for each input of this sweep.
+    ///
+    /// Results are compared by their [ULPs] difference. Two NaN results are
+    /// considered equal, whatever their payloads.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub fn compare<F, R>(&self, f: F, reference: R) -> DifferentialReport<f32>
+    where
+        F: FnMut(f32) -> f32,
+        R: FnMut(f32) -> f32,
+    {
+        DifferentialReport::from_inputs(self.inputs(), f, reference)
+    }
+}
+
+/// A seeded random sample of `f64` inputs for a differential test of a double
+/// precision function, stratified by exponent.
+///
+/// There are far too many `f64` inputs to test them all, and sampling their
+/// bit patterns uniformly would rarely reach the values most likely to be
+/// mishandled. Instead, each input is given an exponent chosen uniformly from
+/// every finite binade, so that subnormals and values near overflow are as
+/// likely as those near one. A fixed set of edge cases, such as negative zero,
+/// the smallest subnormal and the infinities, is always tested first.
+///
+/// The same seed always produces the same inputs, so that a failure may be
+/// reproduced.
+///
+/// # Examples
+/// ```
+/// # use float_eq::F64Sample;
+/// let report = F64Sample::new(0x5eed, 10_000).compare(
+///     |x| x * 0.5,
+///     |x| x / 2.0,
+/// );
+/// assert!(report.is_within(0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct F64Sample {
+    seed: u64,
+    count: u64,
+}
+
+impl F64Sample {
+    /// The edge cases that are tested before any random inputs.
+    pub const EDGE_CASES: [f64; 17] = [
+        0.0,
+        -0.0,
+        F64_MIN_SUBNORMAL,
+        -F64_MIN_SUBNORMAL,
+        F64_MAX_SUBNORMAL,
+        -F64_MAX_SUBNORMAL,
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        1.0,
+        -1.0,
+        f64::MAX,
+        f64::MIN,
+        F64_MAX_BELOW_MAX,
+        -F64_MAX_BELOW_MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    /// The edge cases, followed by `count` random inputs generated from
+    /// `seed`.
+    pub fn new(seed: u64, count: u64) -> Self {
+        F64Sample { seed, count }
+    }
+
+    /// The inputs of this sample, starting with the [`EDGE_CASES`].
+    ///
+    /// [`EDGE_CASES`]: #associatedconstant.EDGE_CASES
+    pub fn inputs(&self) -> impl Iterator<Item = f64> {
+        let mut rng = SplitMix64(self.seed);
+        let random = (0..self.count).map(move |_| {
+            const SIGNIFICAND_BITS: u64 = 52;
+            const FINITE_EXPONENTS: u64 = 0x7ff;
+            let r = rng.next();
+            let sign = r >> 63;
+            let exponent = (r & 0xffff_ffff) % FINITE_EXPONENTS;
+            let significand = rng.next() & ((1 << SIGNIFICAND_BITS) - 1);
+            f64::from_bits(sign << 63 | exponent << SIGNIFICAND_BITS | significand)
+        });
+        Self::EDGE_CASES.iter().copied().chain(random)
+    }
+
+    /// Compare `f` against `reference` for each input of this sample.
+    ///
+    /// Results are compared by their [ULPs] difference. Two NaN results are
+    /// considered equal, whatever their payloads.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub fn compare<F, R>(&self, f: F, reference: R) -> DifferentialReport<f64>
+    where
+        F: FnMut(f64) -> f64,
+        R: FnMut(f64) -> f64,
+    {
+        DifferentialReport::from_inputs(self.inputs(), f, reference)
+    }
+}
+
+const F64_MIN_SUBNORMAL: f64 = 5e-324;
+const F64_MAX_SUBNORMAL: f64 = 2.225_073_858_507_201e-308;
+const F64_MAX_BELOW_MAX: f64 = 1.797_693_134_862_315_5e308;
+
+// The SplitMix64 generator, which is small, fast and good enough to pick test
+// inputs, so that no dependency on a random number crate is needed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// The input that produced the worst result in a [`DifferentialReport`].
+///
+/// [`DifferentialReport`]: struct.DifferentialReport.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifferentialCase<T: FloatPrimitive> {
+    /// The input passed to both functions.
+    pub input: T,
+    /// The result of the function under test.
+    pub actual: T,
+    /// The result of the reference function.
+    pub expected: T,
+    /// The difference between the results in [ULPs], or `None` if it is
+    /// undefined, such as when they have different signs or only one is NaN.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub ulps_diff: Option<T::Bits>,
+}
+
+/// A summary of the differences between a function and its reference over a
+/// set of inputs, as returned by [`F32Sweep::compare`] and
+/// [`F64Sample::compare`].
+///
+/// [`F32Sweep::compare`]: struct.F32Sweep.html#method.compare
+/// [`F64Sample::compare`]: struct.F64Sample.html#method.compare
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifferentialReport<T: FloatPrimitive> {
+    /// The number of inputs compared.
+    pub inputs: u64,
+    /// The number of inputs whose results differ.
+    pub mismatches: u64,
+    /// The input with the largest ULPs difference between its results, or
+    /// `None` if every result matched. Undefined differences are the worst.
+    pub worst: Option<DifferentialCase<T>>,
+}
+
+impl<T: FloatPrimitive> DifferentialReport<T> {
+    pub(crate) fn from_inputs<I, F, R>(inputs: I, mut f: F, mut reference: R) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(T) -> T,
+        R: FnMut(T) -> T,
+    {
+        let mut report = DifferentialReport {
+            inputs: 0,
+            mismatches: 0,
+            worst: None,
+        };
+        for input in inputs {
+            let actual = f(input);
+            let expected = reference(input);
+            report.inputs += 1;
+            if actual.is_nan_value() && expected.is_nan_value() {
+                continue;
+            }
+            let ulps_diff = actual.ulps_diff(expected);
+            if ulps_diff == Some(T::Bits::default()) {
+                continue;
+            }
+            report.mismatches += 1;
+            let is_worse = match report.worst {
+                Some(worst) => {
+                    (ulps_diff.is_none(), ulps_diff) > (worst.ulps_diff.is_none(), worst.ulps_diff)
+                }
+                None => true,
+            };
+            if is_worse {
+                report.worst = Some(DifferentialCase {
+                    input,
+                    actual,
+                    expected,
+                    ulps_diff,
+                });
+            }
+        }
+        report
+    }
+
+    /// The largest ULPs difference between any pair of results, or `None` if
+    /// one of them is undefined. Zero if every result matched.
+    pub fn max_ulps(&self) -> Option<T::Bits> {
+        self.worst.map_or(Some(T::Bits::default()), |w| w.ulps_diff)
+    }
+
+    /// Whether every pair of results is within `tol` ULPs of each other.
+    pub fn is_within(&self, tol: T::Bits) -> bool {
+        matches!(self.max_ulps(), Some(max) if max <= tol)
+    }
+
+    /// Asserts that every pair of results is within `tol` ULPs of each other.
+    ///
+    /// On panic, this displays the worst input and its results.
+    #[cfg(not(feature = "checks-only"))]
+    #[track_caller]
+    pub fn assert_ulps_within(&self, tol: T::Bits) {
+        if let (false, Some(worst)) = (self.is_within(tol), self.worst) {
+            crate::AssertFailure {
+                check: "differential!(actual, expected, ulps <= t)",
+                fields: &[
+                    ("input", &worst.input),
+                    ("actual", &worst.actual),
+                    ("expected", &worst.expected),
+                    ("ulps_diff", &worst.ulps_diff),
+                    ("[ulps] t", &tol),
+                ],
+                note: None,
+                ulps_diff_reason: None,
+                message: Some(format_args!(
+                    "{} of {} inputs differ",
+                    self.mismatches, self.inputs
+                )),
+            }
+            .panic()
+        }
+    }
+}
+
+impl<T: FloatPrimitive> fmt::Display for DifferentialReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {} inputs differ", self.mismatches, self.inputs)?;
+        if let Some(worst) = &self.worst {
+            write!(
+                f,
+                ", worst is input {:?}: actual {:?}, expected {:?}, ulps_diff {:?}",
+                worst.input, worst.actual, worst.expected, worst.ulps_diff
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a check over a grid of input scales and tolerances, to find the
+/// tightest tolerance that it passes at each scale.
+///
+/// Rather than guessing at a tolerance for a new test, a sweep shows how the
+/// error of a calculation grows with the magnitude of its inputs, so that a
+/// tolerance may be chosen to fit. The check is a closure taking a scale and a
+/// tolerance, which returns whether the calculation at that scale is within
+/// that tolerance. Tolerances are tried from first to last, which should run
+/// from the tightest to the loosest, and the first to pass at each scale is
+/// recorded.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, ToleranceSweep};
+/// let report = ToleranceSweep::new(&[1.0f32, 1e3, 1e6], &[1e-7, 1e-5, 1e-3, 1e-2])
+///     .run(|scale, tol| float_eq!((scale + 0.1) - scale, 0.1, abs <= tol));
+///
+/// assert_eq!(report.tightest(), vec![Some(1e-7), Some(1e-3), None]);
+/// assert_eq!(report.required(), None);
+/// # assert_eq!(report.to_string(), "scale      tightest\n1.0        1e-7\n1000.0     0.001\n1000000.0  none");
+/// println!("{}", report);
+/// ```
+///
+/// Which prints:
+///
+/// ```text
+/// scale      tightest
+/// 1.0        1e-7
+/// 1000.0     0.001
+/// 1000000.0  none
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToleranceSweep<'a, S, U> {
+    scales: &'a [S],
+    tols: &'a [U],
+}
+
+#[cfg(feature = "std")]
+impl<'a, S: Copy, U: Copy> ToleranceSweep<'a, S, U> {
+    /// A sweep of every scale against every tolerance, which should be given
+    /// in order from the tightest to the loosest.
+    pub fn new(scales: &'a [S], tols: &'a [U]) -> Self {
+        ToleranceSweep { scales, tols }
+    }
+
+    /// Run `check` for each scale with each tolerance in turn, until one
+    /// passes.
+    pub fn run<F>(&self, mut check: F) -> SweepReport<S, U>
+    where
+        F: FnMut(S, U) -> bool,
+    {
+        let rows = self
+            .scales
+            .iter()
+            .map(|&scale| SweepRow {
+                scale,
+                tightest: self.tols.iter().position(|&tol| check(scale, tol)),
+            })
+            .collect();
+        SweepReport {
+            tols: self.tols.to_vec(),
+            rows,
+        }
+    }
+}
+
+/// The tightest tolerance passed at a single scale of a [`ToleranceSweep`].
+///
+/// [`ToleranceSweep`]: struct.ToleranceSweep.html
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SweepRow<S> {
+    /// The input scale.
+    pub scale: S,
+    /// The index of the first tolerance that passed, or `None` if none did.
+    pub tightest: Option<usize>,
+}
+
+/// The results of a [`ToleranceSweep`], with a row per scale.
+///
+/// Its `Display` implementation lays the results out as a table.
+///
+/// [`ToleranceSweep`]: struct.ToleranceSweep.html
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SweepReport<S, U> {
+    tols: Vec<U>,
+    rows: Vec<SweepRow<S>>,
+}
+
+#[cfg(feature = "std")]
+impl<S: Copy, U: Copy> SweepReport<S, U> {
+    /// The tolerances that were swept, from the tightest to the loosest.
+    pub fn tols(&self) -> &[U] {
+        &self.tols
+    }
+
+    /// The results at each scale, in the order the scales were given.
+    pub fn rows(&self) -> &[SweepRow<S>] {
+        &self.rows
+    }
+
+    /// The tightest tolerance that passed at each scale, or `None` for those
+    /// at which none did.
+    pub fn tightest(&self) -> Vec<Option<U>> {
+        self.rows
+            .iter()
+            .map(|row| row.tightest.map(|i| self.tols[i]))
+            .collect()
+    }
+
+    /// The tightest tolerance that passed at every scale, or `None` if there
+    /// was a scale at which none did.
+    pub fn required(&self) -> Option<U> {
+        let mut loosest = 0;
+        for row in &self.rows {
+            loosest = loosest.max(row.tightest?);
+        }
+        self.tols.get(loosest).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: fmt::Debug, U: fmt::Debug> fmt::Display for SweepReport<S, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scales: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| format!("{:?}", row.scale))
+            .collect();
+        let width = scales
+            .iter()
+            .map(String::len)
+            .fold("scale".len(), usize::max);
+        write!(f, "{:<width$}  tightest", "scale", width = width)?;
+        for (scale, row) in scales.iter().zip(&self.rows) {
+            write!(f, "\n{:<width$}  ", scale, width = width)?;
+            match row.tightest {
+                Some(i) => write!(f, "{:?}", self.tols[i])?,
+                None => f.write_str("none")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::FloatPrimitive;
+
+/// A binned distribution, made up of the edges of its bins and the number of
+/// samples in each, which may be compared by [`assert_histogram_eq!`].
+///
+/// A histogram with `n` bins has `n + 1` edges, in ascending order, where bin
+/// `i` spans from `edges[i]` to `edges[i + 1]`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::Histogram;
+/// let h = Histogram::new(&[0.0f64, 0.5, 1.0], &[12, 30]);
+/// assert_eq!(h.bins(), 2);
+/// ```
+///
+/// [`assert_histogram_eq!`]: macro.assert_histogram_eq.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Histogram<'a, T: FloatPrimitive> {
+    edges: &'a [T],
+    counts: &'a [u64],
+}
+
+impl<'a, T: FloatPrimitive> Histogram<'a, T> {
+    /// A histogram with the given bin edges and counts.
+    ///
+    /// # Panics
+    /// If there is not exactly one more edge than there are counts, unless
+    /// both are empty.
+    #[track_caller]
+    pub fn new(edges: &'a [T], counts: &'a [u64]) -> Self {
+        assert!(
+            edges.len() == counts.len() + 1 || (edges.is_empty() && counts.is_empty()),
+            "a histogram with {} bins must have {} edges, not {}",
+            counts.len(),
+            counts.len() + 1,
+            edges.len()
+        );
+        Histogram { edges, counts }
+    }
+
+    /// The edges of the bins, in ascending order.
+    #[inline]
+    pub fn edges(&self) -> &'a [T] {
+        self.edges
+    }
+
+    /// The number of samples in each bin.
+    #[inline]
+    pub fn counts(&self) -> &'a [u64] {
+        self.counts
+    }
+
+    /// The number of bins.
+    #[inline]
+    pub fn bins(&self) -> usize {
+        self.counts.len()
+    }
+}
+
+/// The slack allowed between the counts of two histograms compared by
+/// [`assert_histogram_eq!`].
+///
+/// [`assert_histogram_eq!`]: macro.assert_histogram_eq.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CountTol {
+    /// Counts may differ by at most this many samples.
+    Abs(u64),
+    /// Counts may differ by at most this fraction of the larger count.
+    Rel(f64),
+}
+
+impl CountTol {
+    /// Counts that may differ by at most `tol` samples.
+    #[inline]
+    pub fn abs(tol: u64) -> Self {
+        CountTol::Abs(tol)
+    }
+
+    /// Counts that may differ by at most `tol` times the larger count.
+    #[inline]
+    pub fn rel(tol: f64) -> Self {
+        CountTol::Rel(tol)
+    }
+
+    /// Check whether two counts are equal within this tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::CountTol;
+    /// assert!(CountTol::abs(2).eq_counts(100, 98));
+    /// assert!(!CountTol::abs(2).eq_counts(100, 97));
+    /// assert!(CountTol::rel(0.05).eq_counts(100, 95));
+    /// assert!(!CountTol::rel(0.05).eq_counts(100, 94));
+    /// ```
+    #[inline]
+    pub fn eq_counts(&self, a: u64, b: u64) -> bool {
+        let diff = a.abs_diff(b);
+        match *self {
+            CountTol::Abs(tol) => diff <= tol,
+            CountTol::Rel(tol) => diff as f64 <= tol * (a.max(b) as f64),
+        }
+    }
+}
+
+/// The index of the first bin whose counts are not equal within `tol`.
+///
+/// If one histogram has more bins than the other, the first bin that only one
+/// of them has is a mismatch. Returns `None` if every count is equal.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{first_count_mismatch, CountTol};
+/// let a = [10, 20, 30];
+/// let b = [11, 20, 25];
+///
+/// assert_eq!(first_count_mismatch(&a, &b, CountTol::abs(1)), Some(2));
+/// assert_eq!(first_count_mismatch(&a, &b, CountTol::rel(0.2)), None);
+/// assert_eq!(first_count_mismatch(&a, &b[..2], CountTol::rel(0.2)), Some(2));
+/// ```
+pub fn first_count_mismatch(a: &[u64], b: &[u64], tol: CountTol) -> Option<usize> {
+    a.iter()
+        .zip(b.iter())
+        .position(|(a, b)| !tol.eq_counts(*a, *b))
+        .or_else(|| {
+            if a.len() == b.len() {
+                None
+            } else {
+                Some(a.len().min(b.len()))
+            }
+        })
+}
@@ -19,12 +19,19 @@
 //! And asserts via [`assert_float_eq!`] and [`assert_float_ne!`]:
 //!
 //! ```
+//! # #[cfg(not(feature = "checks-only"))]
+//! # {
 //! use float_eq::assert_float_eq;
 //!
 //! const TOL: f32 = 0.000_366_210_94;
 //! assert_float_eq!(0.1f32.recip(), 10.0, r2nd <= TOL);
+//! # }
 //! ```
 //!
+//! The asserts, along with the [`AssertFloatEq`] and [`AssertFloatEqAll`]
+//! traits that provide their debug output, are not available if the
+//! `checks-only` feature is enabled.
+//!
 //! Each of which invokes a specific comparison algorithm with an explictly
 //! provided toelrance. In these examples:
 //!
@@ -56,6 +63,49 @@
 //! *Note: `rel` and `rel_all` are legacy aliases for `rmax` and `rmax_all`, but
 //! using the more precise algorithm names is recommended.*
 //!
+//! `percent` and `percent_all` are the same as `rmax` and `rmax_all`, except that
+//! the tolerance is a percentage, so `percent <= 0.1` allows values to differ by
+//! 0.1% of the larger magnitude. They may be used with any type whose tolerance
+//! type implements [`TolOps`], which may be derived.
+//!
+//! When comparing slices, arrays or vectors of `f32` or `f64`, their
+//! differences may instead be checked as a whole:
+//!
+//! - `max_abs`: the largest absolute difference between corresponding values.
+//! - `max_ulps`: the largest difference in ULPs between corresponding values, as
+//!   measured by an [ULPs comparison].
+//! - `mean_abs`: the mean absolute difference between corresponding values.
+//!
+//! Operands of different lengths are never equal under these checks. The
+//! differences themselves are given by [`max_abs_diff`], [`max_ulps_diff`]
+//! and [`mean_abs_diff`].
+//!
+//! `abs_ulps` and `abs_ulps_all` combine an absolute tolerance comparison, for
+//! values near zero, with an ULPs comparison for those further from it. They
+//! take a pair of tolerances, for example `abs_ulps <= (1e-9, 4)`, and pass if
+//! either check would, in the same way as `abs <= 1e-9, ulps <= 4`, but display
+//! both tolerances together when an assert fails.
+//!
+//! Values that implement [`FloatEqBits`] may instead be checked for bitwise
+//! equality using `bits` on its own, with no tolerance, for example
+//! `float_eq!(a, b, bits)`. This may not be combined with other checks. To do
+//! so, use `bits` or `bits_all` with an empty tolerance instead, for example
+//! `float_eq!(a, b, abs <= 1e-9, bits <= ())`, which also passes if the values
+//! are bitwise identical, such as two NaNs with the same payload.
+//!
+//! Tolerances that are written as literals are checked at compile time, to
+//! catch typos before any tests are run. A negative literal, or a NaN constant
+//! such as `f64::NAN`, is never met by a difference and so fails to compile. A
+//! relative tolerance of 10 or more, which is likely to be a typo such as `1e6`
+//! for `1e-6`, and `ulps <= 0` or `max_ulps <= 0` in a not equal check both
+//! warn. The warnings are reported as the use of a deprecated item, so where
+//! they are intended they may be silenced with `#[allow(deprecated)]`.
+//!
+//! Tolerances computed at runtime are not checked by default, and a negative or
+//! NaN one silently fails every comparison of values that are not identical.
+//! The `validate-tol` feature makes such a tolerance panic in debug builds
+//! instead, with a message naming the check it was given to.
+//!
 //! # Combining checks
 //!
 //! If multiple checks are specified in either a boolean comparison or an assert,
@@ -77,6 +127,43 @@
 //! # ;
 //! ```
 //!
+//! A set of checks that is used in many places may instead be captured once as
+//! a [`FloatCmp`] and then applied with the `tol` check:
+//!
+//! ```
+//! # use float_eq::{float_eq, FloatCmp};
+//! # let a = 0.1; let b = 0.1;
+//! let cmp = FloatCmp::<f64>::new().abs(0.0).ulps(0);
+//! float_eq!(a, b, tol(&cmp))
+//! # ;
+//! ```
+//!
+//! [`FloatCmp`]: struct.FloatCmp.html
+//!
+//! # Capturing operands
+//!
+//! With the `capture` feature enabled, [`assert_float_eq_captured!`] and
+//! [`assert_float_ne_captured!`] may be used in place of the usual asserts. If
+//! they fail, they also display the value of each operand of any arithmetic at
+//! the top level of the expressions being compared, labelled with its source:
+//!
+//! ```
+//! # #[cfg(all(feature = "capture", not(feature = "checks-only")))]
+//! # {
+//! use float_eq::assert_float_eq_captured;
+//!
+//! fn area(r: f64) -> f64 {
+//!     std::f64::consts::PI * r * r
+//! }
+//!
+//! // On failure, also displays the values of `area(2.0)` and `area(1.0)`
+//! assert_float_eq_captured!(area(2.0) / area(1.0), 4.0, ulps <= 2);
+//! # }
+//! ```
+//!
+//! [`assert_float_eq_captured!`]: macro.assert_float_eq_captured.html
+//! [`assert_float_ne_captured!`]: macro.assert_float_ne_captured.html
+//!
 //! # Extending float_eq over custom types
 //!
 //! See [How to compare custom types].
@@ -86,10 +173,17 @@
 //! [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
 //! [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
 //! [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+//! [`max_abs_diff`]: fn.max_abs_diff.html
+//! [`max_ulps_diff`]: fn.max_ulps_diff.html
+//! [`mean_abs_diff`]: fn.mean_abs_diff.html
 
 #![warn(missing_docs)]
+#![forbid(unsafe_code)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 pub use crate::macros::*;
@@ -97,8 +191,195 @@ pub use crate::macros::*;
 mod traits;
 pub use crate::traits::*;
 
+mod bits;
+pub use crate::bits::*;
+
+mod ulps_ops;
+pub use crate::ulps_ops::*;
+
+mod check_set;
+pub use crate::check_set::*;
+
+mod tol_ops;
+pub use crate::tol_ops::*;
+
+mod widen_tol;
+pub use crate::widen_tol::*;
+
+mod tier;
+pub use crate::tier::*;
+
+#[cfg(not(feature = "checks-only"))]
+mod anneal;
+#[cfg(not(feature = "checks-only"))]
+pub use crate::anneal::*;
+
+mod test_vectors;
+pub use crate::test_vectors::*;
+
+#[cfg(not(feature = "checks-only"))]
+pub mod conformance;
+
+mod primitive;
+pub use crate::primitive::*;
+
+mod neighbors;
+pub use crate::neighbors::*;
+
+#[cfg(feature = "simd")]
+mod chunked;
+
+mod category;
+pub use crate::category::*;
+
+#[cfg(not(feature = "checks-only"))]
+mod message;
+#[cfg(not(feature = "checks-only"))]
+pub use crate::message::*;
+
 mod trait_impls;
+#[allow(unused_imports)]
 pub use crate::trait_impls::*;
 
+mod by;
+pub use crate::by::*;
+
+mod clamped;
+pub use crate::clamped::*;
+
+mod eq_ops;
+pub use crate::eq_ops::*;
+
+mod const_tol;
+pub use crate::const_tol::*;
+
+pub mod checks;
+
+mod float_cmp;
+pub use crate::float_cmp::*;
+
+#[cfg(not(feature = "checks-only"))]
+mod prepared;
+#[cfg(not(feature = "checks-only"))]
+pub use crate::prepared::*;
+
+#[cfg(not(feature = "checks-only"))]
+mod zip;
+#[cfg(not(feature = "checks-only"))]
+pub use crate::zip::*;
+
+mod gradcheck;
+pub use crate::gradcheck::*;
+
+mod reduction;
+pub use crate::reduction::*;
+
+mod aggregate;
+pub use crate::aggregate::*;
+
+mod convergence;
+pub use crate::convergence::*;
+
+mod matrix;
+pub use crate::matrix::*;
+
+mod probability;
+pub use crate::probability::*;
+
+mod histogram;
+pub use crate::histogram::*;
+
+mod search;
+pub use crate::search::*;
+
+mod bounds;
+pub use crate::bounds::*;
+
+mod snap;
+pub use crate::snap::*;
+
+mod fixed;
+pub use crate::fixed::*;
+
+mod measured;
+pub use crate::measured::*;
+
+mod sorted;
+pub use crate::sorted::*;
+
+#[cfg(feature = "std")]
+mod cluster;
+#[cfg(feature = "std")]
+pub use crate::cluster::*;
+
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "std")]
+pub use crate::csv::*;
+
+#[cfg(feature = "std")]
+mod mismatches;
+#[cfg(feature = "std")]
+pub use crate::mismatches::*;
+
+#[cfg(feature = "std")]
+mod canonical;
+#[cfg(feature = "std")]
+pub use crate::canonical::*;
+
+#[cfg(feature = "std")]
+mod policy;
+#[cfg(feature = "std")]
+pub use crate::policy::*;
+
+#[cfg(feature = "std")]
+mod field_diff;
+#[cfg(feature = "std")]
+pub use crate::field_diff::*;
+
+#[cfg(feature = "alloc")]
+mod decimal;
+#[cfg(feature = "alloc")]
+pub use crate::decimal::*;
+
+#[cfg(feature = "std")]
+mod fma;
+#[cfg(feature = "std")]
+pub use crate::fma::*;
+
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+mod report;
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+pub use crate::report::*;
+
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+mod table;
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+pub use crate::table::*;
+
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+#[doc(hidden)]
+pub mod fuzz;
+
+#[cfg(all(feature = "capture", not(feature = "checks-only")))]
+mod capture;
+#[cfg(all(feature = "capture", not(feature = "checks-only")))]
+pub use crate::capture::*;
+
+#[cfg(feature = "std")]
+mod eigen;
+#[cfg(feature = "std")]
+pub use crate::eigen::*;
+
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "geo")]
+pub use crate::geo::*;
+
+#[cfg(feature = "harness")]
+mod harness;
+#[cfg(feature = "harness")]
+pub use crate::harness::*;
+
 #[cfg(feature = "float_eq_derive")]
 pub use float_eq_derive::*;
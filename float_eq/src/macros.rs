@@ -1,4 +1,7 @@
-use crate::{AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, UlpsTol};
+use crate::{max_abs_diff, max_ulps_diff, mean_abs_diff};
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, UlpsTolOps};
+use crate::{FloatEq, FloatEqAll, FloatEqBits, FloatPrimitive, TolOps, UlpsTol};
 
 /// Checks if two floating point expressions are equal to each other.
 ///
@@ -20,7 +23,32 @@ use crate::{AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, UlpsTol};
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! float_eq {
+    ($a:expr, $b:expr, bits) => ({
+        match (&$a, &$b) {
+            (a_val, b_val) => $crate::FloatEqBits::eq_bits(a_val, b_val)
+        }
+    });
+    ($a:expr, $b:expr, bits,) => ({
+        $crate::float_eq!($a, $b, bits)
+    });
+    ($a:expr, $b:expr, tol($cmp:expr)) => ({
+        match (&$a, &$b, &$cmp) {
+            (a_val, b_val, cmp_val) => $crate::FloatCmp::eq(cmp_val, a_val, b_val)
+        }
+    });
+    ($a:expr, $b:expr, tol($cmp:expr),) => ({
+        $crate::float_eq!($a, $b, tol($cmp))
+    });
+    ($a:expr, $b:expr, tol_all($cmp:expr)) => ({
+        match (&$a, &$b, &$cmp) {
+            (a_val, b_val, cmp_val) => $crate::FloatCmp::eq_all(cmp_val, a_val, b_val)
+        }
+    });
+    ($a:expr, $b:expr, tol_all($cmp:expr),) => ({
+        $crate::float_eq!($a, $b, tol_all($cmp))
+    });
     ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+) => ({
+        $($crate::__float_eq_check_tol!(eq, $eq, $tol);)+
         match (&$a, &$b) {
             (a_val, b_val) => {
                 false $(|| $crate::FloatEqCmp::$eq(a_val, b_val, &$tol))+
@@ -52,11 +80,34 @@ macro_rules! float_eq {
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! float_ne {
+    ($a:expr, $b:expr, bits) => ({
+        !$crate::float_eq!($a, $b, bits)
+    });
+    ($a:expr, $b:expr, bits,) => ({
+        !$crate::float_eq!($a, $b, bits)
+    });
+    ($a:expr, $b:expr, tol($cmp:expr)) => ({
+        !$crate::float_eq!($a, $b, tol($cmp))
+    });
+    ($a:expr, $b:expr, tol($cmp:expr),) => ({
+        !$crate::float_eq!($a, $b, tol($cmp))
+    });
+    ($a:expr, $b:expr, tol_all($cmp:expr)) => ({
+        !$crate::float_eq!($a, $b, tol_all($cmp))
+    });
+    ($a:expr, $b:expr, tol_all($cmp:expr),) => ({
+        !$crate::float_eq!($a, $b, tol_all($cmp))
+    });
     ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+) => ({
-        !$crate::float_eq!($a, $b $(, $eq <= $tol)+)
+        $($crate::__float_eq_check_tol!(ne, $eq, $tol);)+
+        match (&$a, &$b) {
+            (a_val, b_val) => {
+                !(false $(|| $crate::FloatEqCmp::$eq(a_val, b_val, &$tol))+)
+            }
+        }
     });
     ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+,) => ({
-        !$crate::float_eq!($a, $b $(, $eq <= $tol)+)
+        $crate::float_ne!($a, $b $(, $eq <= $tol)+)
     });
 }
 
@@ -85,11 +136,160 @@ macro_rules! float_ne {
 /// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
+#[cfg(not(feature = "checks-only"))]
 #[macro_export]
 macro_rules! assert_float_eq {
     // the order of these rules matters a *lot* for the format string functionality
     // to work, otherwise we end up consuming the general case too early.
+    ($left:expr, $right:expr, bits) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, bits) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, bits)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, bits,) => ({
+        $crate::assert_float_eq!($left, $right, bits)
+    });
+    ($left:expr, $right:expr, bits, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, bits) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, bits)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol($cmp:expr)) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, tol(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, tol(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol] t", &&$crate::FloatCmp::debug_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol($cmp:expr),) => ({
+        $crate::assert_float_eq!($left, $right, tol($cmp))
+    });
+    ($left:expr, $right:expr, tol($cmp:expr), $($arg:tt)+) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, tol(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, tol(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol] t", &&$crate::FloatCmp::debug_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr)) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, tol_all(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, tol_all(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol_all] t", &&$crate::FloatCmp::debug_all_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr),) => ({
+        $crate::assert_float_eq!($left, $right, tol_all($cmp))
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr), $($arg:tt)+) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, tol_all(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, tol_all(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol_all] t", &&$crate::FloatCmp::debug_all_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(eq, $eq2, $tol_2);
+        $crate::__float_eq_check_tol!(eq, $eq3, $tol_3);
         match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
             (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
                 if !$crate::float_eq!(
@@ -99,31 +299,29 @@ macro_rules! assert_float_eq {
                     $eq2 <= *tol_2_val,
                     $eq3 <= *tol_3_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                            (concat!("[", stringify!($eq3), "] t"), &&$crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(eq, $eq2, $tol_2);
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
                 if !$crate::float_eq!(
@@ -132,28 +330,27 @@ macro_rules! assert_float_eq {
                     $eq1 <= *tol_1_val,
                     $eq2 <= *tol_2_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
                 if !$crate::float_eq!(
@@ -161,20 +358,20 @@ macro_rules! assert_float_eq {
                     *right_val,
                     $eq1 <= *tol_1_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
@@ -183,6 +380,9 @@ macro_rules! assert_float_eq {
         $crate::assert_float_eq!($left, $right $(, $eq <= $tol)+)
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(eq, $eq2, $tol_2);
+        $crate::__float_eq_check_tol!(eq, $eq3, $tol_3);
         match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
             (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
                 if !$crate::float_eq!(
@@ -192,32 +392,29 @@ macro_rules! assert_float_eq {
                     $eq2 <= *tol_2_val,
                     $eq3 <= *tol_3_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                            (concat!("[", stringify!($eq3), "] t"), &&$crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(eq, $eq2, $tol_2);
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
                 if !$crate::float_eq!(
@@ -226,29 +423,27 @@ macro_rules! assert_float_eq {
                     $eq1 <= *tol_1_val,
                     $eq2 <= *tol_2_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq1, $tol_1);
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
                 if !$crate::float_eq!(
@@ -256,21 +451,20 @@ macro_rules! assert_float_eq {
                     *right_val,
                     $eq1 <= *tol_1_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
@@ -302,11 +496,160 @@ macro_rules! assert_float_eq {
 /// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
+#[cfg(not(feature = "checks-only"))]
 #[macro_export]
 macro_rules! assert_float_ne {
     // the order of these rules matters a *lot* for the format string functionality
     // to work, otherwise we end up consuming the general case too early.
+    ($left:expr, $right:expr, bits) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, bits) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, bits)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, bits,) => ({
+        $crate::assert_float_ne!($left, $right, bits)
+    });
+    ($left:expr, $right:expr, bits, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, bits) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, bits)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol($cmp:expr)) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, tol(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, tol(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol] t", &&$crate::FloatCmp::debug_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol($cmp:expr),) => ({
+        $crate::assert_float_ne!($left, $right, tol($cmp))
+    });
+    ($left:expr, $right:expr, tol($cmp:expr), $($arg:tt)+) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, tol(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, tol(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol] t", &&$crate::FloatCmp::debug_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr)) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, tol_all(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, tol_all(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol_all] t", &&$crate::FloatCmp::debug_all_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr),) => ({
+        $crate::assert_float_ne!($left, $right, tol_all($cmp))
+    });
+    ($left:expr, $right:expr, tol_all($cmp:expr), $($arg:tt)+) => ({
+        match (&$left, &$right, &$cmp) {
+            (left_val, right_val, cmp_val) => {
+                if !$crate::float_ne!(*left_val, *right_val, tol_all(*cmp_val)) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_ne!(left, right, tol_all(cmp))",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("[tol_all] t", &&$crate::FloatCmp::debug_all_tol(*cmp_val, &*left_val, &*right_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(ne, $eq2, $tol_2);
+        $crate::__float_eq_check_tol!(ne, $eq3, $tol_3);
         match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
             (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
                 if !$crate::float_ne!(
@@ -316,31 +659,29 @@ macro_rules! assert_float_ne {
                     $eq2 <= *tol_2_val,
                     $eq3 <= *tol_3_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                            (concat!("[", stringify!($eq3), "] t"), &&$crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(ne, $eq2, $tol_2);
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
                 if !$crate::float_ne!(
@@ -349,28 +690,27 @@ macro_rules! assert_float_ne {
                     $eq1 <= *tol_1_val,
                     $eq2 <= *tol_2_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
                 if !$crate::float_ne!(
@@ -378,20 +718,20 @@ macro_rules! assert_float_ne {
                     *right_val,
                     $eq1 <= *tol_1_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
                 }
             }
         }
@@ -400,6 +740,9 @@ macro_rules! assert_float_ne {
         $crate::assert_float_ne!($left, $right $(, $eq <= $tol)+)
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(ne, $eq2, $tol_2);
+        $crate::__float_eq_check_tol!(ne, $eq3, $tol_3);
         match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
             (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
                 if !$crate::float_ne!(
@@ -409,32 +752,29 @@ macro_rules! assert_float_ne {
                     $eq2 <= *tol_2_val,
                     $eq3 <= *tol_3_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                            (concat!("[", stringify!($eq3), "] t"), &&$crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
+        $crate::__float_eq_check_tol!(ne, $eq2, $tol_2);
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
                 if !$crate::float_ne!(
@@ -443,29 +783,27 @@ macro_rules! assert_float_ne {
                     $eq1 <= *tol_1_val,
                     $eq2 <= *tol_2_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                            (concat!("[", stringify!($eq2), "] t"), &&$crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
     });
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(ne, $eq1, $tol_1);
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
                 if !$crate::float_ne!(
@@ -473,21 +811,20 @@ macro_rules! assert_float_ne {
                     *right_val,
                     $eq1 <= *tol_1_val) {
                     // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        format_args!($($arg)+)
-                    )
+                    $crate::AssertFailure {
+                        check: concat!("float_ne!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val)),
+                        ],
+                        note: None,
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
                 }
             }
         }
@@ -523,6 +860,7 @@ macro_rules! assert_float_ne {
 /// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
+#[cfg(not(feature = "checks-only"))]
 #[macro_export]
 macro_rules! debug_assert_float_eq {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_eq!($($arg)*); })
@@ -557,57 +895,3554 @@ macro_rules! debug_assert_float_eq {
 /// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
+#[cfg(not(feature = "checks-only"))]
 #[macro_export]
 macro_rules! debug_assert_float_ne {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_ne!($($arg)*); })
 }
 
-#[doc(hidden)]
-pub struct FloatEqCmp;
+/// Asserts that two floating point expressions are equal to within a fraction
+/// of a previously recorded tolerance.
+///
+/// This is intended for ratcheting tolerances down as an algorithm improves:
+/// the assertion fails if the observed error is worse than `factor` times
+/// `previous_tol`, which signals that the recorded tolerance may be tightened
+/// once the check passes. The scaled tolerance is applied to every field, as in
+/// an `abs_all` check, so it must be the [`FloatEqAll::AllTol`] of the operands.
+///
+/// On panic, this macro will print the values of the expressions with their debug
+/// representations, along with the recorded tolerance, the factor and the scaled
+/// tolerance. Like [`assert!`], this macro has a second form, where a custom panic
+/// message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_tighter_than;
+/// let previous_tol = 0.001;
+/// assert_float_eq_tighter_than!(1.0f64, 1.0002, previous_tol, factor = 0.5);
+/// assert_float_eq_tighter_than!([1.0f64, 2.0], [1.0002, 2.0], previous_tol, factor = 0.5);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`FloatEqAll::AllTol`]: trait.FloatEqAll.html#associatedtype.AllTol
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_tighter_than {
+    ($left:expr, $right:expr, $previous_tol:expr, factor = $factor:expr) => ({
+        $crate::__float_eq_check_tol!(eq, abs_all, $previous_tol);
+        match (&$left, &$right, &$previous_tol, &$factor) {
+            (left_val, right_val, previous_tol_val, factor_val) => {
+                let tol = *previous_tol_val * *factor_val;
+                if !$crate::FloatEqCmp::abs_all(&*left_val, &*right_val, &tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, abs_all <= previous * factor)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("previous", &&*previous_tol_val),
+                            ("factor", &&*factor_val),
+                            ("[abs_all] t", &&$crate::FloatCmpOpTol::abs_all(&*left_val, &*right_val, &tol)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $previous_tol:expr, factor = $factor:expr,) => ({
+        $crate::assert_float_eq_tighter_than!($left, $right, $previous_tol, factor = $factor)
+    });
+    ($left:expr, $right:expr, $previous_tol:expr, factor = $factor:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, abs_all, $previous_tol);
+        match (&$left, &$right, &$previous_tol, &$factor) {
+            (left_val, right_val, previous_tol_val, factor_val) => {
+                let tol = *previous_tol_val * *factor_val;
+                if !$crate::FloatEqCmp::abs_all(&*left_val, &*right_val, &tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, abs_all <= previous * factor)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                            ("previous", &&*previous_tol_val),
+                            ("factor", &&*factor_val),
+                            ("[abs_all] t", &&$crate::FloatCmpOpTol::abs_all(&*left_val, &*right_val, &tol)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
 
-#[doc(hidden)]
-impl FloatEqCmp {
-    #[inline]
-    pub fn abs<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
-    where
-        A: FloatEq<B>,
-    {
-        a.eq_abs(b, tol)
-    }
+/// Checks if two floating point expressions are equal to each other, using
+/// the tolerance of the current [`ToleranceTier`].
+///
+/// Both a `strict` and a `lenient` check are given, each of which may use any
+/// of the [comparison algorithms]. Only the check for the current tier is
+/// made, though both tolerances are always evaluated.
+///
+/// # Examples
+/// ```
+/// # use float_eq::float_eq_tiered;
+/// let a = 1.0f32;
+/// let b = 1.000_000_1;
+///
+/// assert!(float_eq_tiered!(a, b, strict: ulps <= 1, lenient: abs <= 0.001));
+/// ```
+///
+/// [`ToleranceTier`]: enum.ToleranceTier.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! float_eq_tiered {
+    ($a:expr, $b:expr, strict: $strict:ident <= $strict_tol:expr, lenient: $lenient:ident <= $lenient_tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $strict, $strict_tol);
+        $crate::__float_eq_check_tol!(eq, $lenient, $lenient_tol);
+        match (&$a, &$b, &$strict_tol, &$lenient_tol) {
+            (a_val, b_val, strict_tol_val, lenient_tol_val) => match $crate::ToleranceTier::current() {
+                $crate::ToleranceTier::Strict => $crate::float_eq!(*a_val, *b_val, $strict <= *strict_tol_val),
+                $crate::ToleranceTier::Lenient => $crate::float_eq!(*a_val, *b_val, $lenient <= *lenient_tol_val),
+            }
+        }
+    });
+    ($a:expr, $b:expr, strict: $strict:ident <= $strict_tol:expr, lenient: $lenient:ident <= $lenient_tol:expr,) => ({
+        $crate::float_eq_tiered!($a, $b, strict: $strict <= $strict_tol, lenient: $lenient <= $lenient_tol)
+    });
+}
 
-    #[inline]
-    pub fn abs_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
-    where
-        A: FloatEqAll<B>,
-    {
-        a.eq_abs_all(b, tol)
-    }
+/// Asserts that two floating point expressions are equal to each other, using
+/// the tolerance of the current [`ToleranceTier`].
+///
+/// Both a `strict` and a `lenient` check are given, each of which may use any
+/// of the [comparison algorithms]. Only the check for the current tier is
+/// made, though both tolerances are always evaluated.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the check that was made, along with the tier it belongs to. Like
+/// [`assert!`], this macro has a second form, where a custom panic message can
+/// be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_tiered;
+/// let a = 1.0f32;
+/// let b = 1.000_000_1;
+///
+/// assert_float_eq_tiered!(a, b, strict: ulps <= 1, lenient: abs <= 0.001);
+/// assert_float_eq_tiered!(a, b, strict: rmax <= f32::EPSILON, lenient: rmax <= 0.001);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`ToleranceTier`]: enum.ToleranceTier.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_tiered {
+    ($left:expr, $right:expr, strict: $strict:ident <= $strict_tol:expr, lenient: $lenient:ident <= $lenient_tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $strict, $strict_tol);
+        $crate::__float_eq_check_tol!(eq, $lenient, $lenient_tol);
+        match (&$left, &$right, &$strict_tol, &$lenient_tol) {
+            (left_val, right_val, strict_tol_val, lenient_tol_val) => match $crate::ToleranceTier::current() {
+                $crate::ToleranceTier::Strict => {
+                    if !$crate::float_eq!(*left_val, *right_val, $strict <= *strict_tol_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq!(left, right, ", stringify!($strict), " <= t)"),
+                            fields: &[
+                                ("left", &&*left_val),
+                                ("right", &&*right_val),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                                (concat!("[", stringify!($strict), "] t"), &&$crate::FloatCmpOpTol::$strict(&*left_val, &*right_val, &*strict_tol_val)),
+                                ("tier", &$crate::ToleranceTier::Strict),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                            message: None,
+                        }
+                        .panic()
+                    }
+                }
+                $crate::ToleranceTier::Lenient => {
+                    if !$crate::float_eq!(*left_val, *right_val, $lenient <= *lenient_tol_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq!(left, right, ", stringify!($lenient), " <= t)"),
+                            fields: &[
+                                ("left", &&*left_val),
+                                ("right", &&*right_val),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                                (concat!("[", stringify!($lenient), "] t"), &&$crate::FloatCmpOpTol::$lenient(&*left_val, &*right_val, &*lenient_tol_val)),
+                                ("tier", &$crate::ToleranceTier::Lenient),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                            message: None,
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, strict: $strict:ident <= $strict_tol:expr, lenient: $lenient:ident <= $lenient_tol:expr,) => ({
+        $crate::assert_float_eq_tiered!($left, $right, strict: $strict <= $strict_tol, lenient: $lenient <= $lenient_tol)
+    });
+    ($left:expr, $right:expr, strict: $strict:ident <= $strict_tol:expr, lenient: $lenient:ident <= $lenient_tol:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $strict, $strict_tol);
+        $crate::__float_eq_check_tol!(eq, $lenient, $lenient_tol);
+        match (&$left, &$right, &$strict_tol, &$lenient_tol) {
+            (left_val, right_val, strict_tol_val, lenient_tol_val) => match $crate::ToleranceTier::current() {
+                $crate::ToleranceTier::Strict => {
+                    if !$crate::float_eq!(*left_val, *right_val, $strict <= *strict_tol_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq!(left, right, ", stringify!($strict), " <= t)"),
+                            fields: &[
+                                ("left", &&*left_val),
+                                ("right", &&*right_val),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                                (concat!("[", stringify!($strict), "] t"), &&$crate::FloatCmpOpTol::$strict(&*left_val, &*right_val, &*strict_tol_val)),
+                                ("tier", &$crate::ToleranceTier::Strict),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                            message: Some(format_args!($($arg)+)),
+                        }
+                        .panic()
+                    }
+                }
+                $crate::ToleranceTier::Lenient => {
+                    if !$crate::float_eq!(*left_val, *right_val, $lenient <= *lenient_tol_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq!(left, right, ", stringify!($lenient), " <= t)"),
+                            fields: &[
+                                ("left", &&*left_val),
+                                ("right", &&*right_val),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                                (concat!("[", stringify!($lenient), "] t"), &&$crate::FloatCmpOpTol::$lenient(&*left_val, &*right_val, &*lenient_tol_val)),
+                                ("tier", &$crate::ToleranceTier::Lenient),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                            message: Some(format_args!($($arg)+)),
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+}
 
-    #[inline]
-    pub fn rel<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
-    where
-        A: FloatEq<B>,
-    {
-        a.eq_rel(b, tol)
-    }
+/// Asserts that two floating point expressions are equal to each other, and
+/// if they are not, optionally reports how much wider the tolerance would
+/// need to be for them to pass.
+///
+/// Behaves exactly like the single check form of [`assert_float_eq!`], unless
+/// the `FLOAT_EQ_ANNEAL` environment variable is set to `1`, in which case a
+/// failed check is retried at 2, 4 and 8 times its tolerance, and the lowest
+/// factor that passes is added to the panic message as an [`Annealed`] value.
+/// This speeds up triage of numerical tests that only fail in CI, since a
+/// single run shows whether the values are just outside of the tolerance or
+/// far from it. The assert still fails, whether or not a wider tolerance would
+/// pass.
+///
+/// The tolerance must implement [`TolOps`], or [`UlpsTolOps`] for ULPs
+/// checks, so that it may be widened.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_annealed;
+/// let a = 1.0f32;
+/// let b = 1.000_000_1;
+///
+/// assert_float_eq_annealed!(a, b, ulps <= 1);
+/// assert_float_eq_annealed!(a, b, rmax <= 2.0 * f32::EPSILON, "step {}", 4);
+/// ```
+///
+/// With annealing enabled, a failure such as
+/// `assert_float_eq_annealed!(1.0f64, 1.3, abs <= 0.1)` panics with:
+///
+/// ```text
+/// assertion failed: `float_eq!(left, right, abs <= t)`
+///         left: `1.0`,
+///        right: `1.3`,
+///     abs_diff: `0.30000000000000004`,
+///    ulps_diff: `Some(1351079888211149)`,
+///      [abs] t: `0.1`,
+///     annealed: `Some(PassesAt(4))`
+/// ```
+///
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`Annealed`]: enum.Annealed.html
+/// [`TolOps`]: trait.TolOps.html
+/// [`UlpsTolOps`]: trait.UlpsTolOps.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_annealed {
+    ($left:expr, $right:expr, $eq:ident <= $tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$left, &$right, &$tol) {
+            (left_val, right_val, tol_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, $eq <= *tol_val) {
+                    let annealed = $crate::Annealed::enabled().then(|| {
+                        $crate::Annealed::retry(&*tol_val, $crate::FloatAnnealTol::$eq, |t| {
+                            $crate::float_eq!(*left_val, *right_val, $eq <= *t)
+                        })
+                    });
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    let fields: &[(&str, &dyn ::core::fmt::Debug)] = &[
+                        ("left", &&*left_val),
+                        ("right", &&*right_val),
+                        ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                        ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        (concat!("[", stringify!($eq), "] t"), &&$crate::FloatCmpOpTol::$eq(&*left_val, &*right_val, &*tol_val)),
+                        ("annealed", &annealed),
+                    ];
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq), " <= t)"),
+                        fields: match annealed {
+                            Some(_) => fields,
+                            None => &fields[..fields.len() - 1],
+                        },
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $eq:ident <= $tol:expr,) => ({
+        $crate::assert_float_eq_annealed!($left, $right, $eq <= $tol)
+    });
+    ($left:expr, $right:expr, $eq:ident <= $tol:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$left, &$right, &$tol) {
+            (left_val, right_val, tol_val) => {
+                if !$crate::float_eq!(*left_val, *right_val, $eq <= *tol_val) {
+                    let annealed = $crate::Annealed::enabled().then(|| {
+                        $crate::Annealed::retry(&*tol_val, $crate::FloatAnnealTol::$eq, |t| {
+                            $crate::float_eq!(*left_val, *right_val, $eq <= *t)
+                        })
+                    });
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    let fields: &[(&str, &dyn ::core::fmt::Debug)] = &[
+                        ("left", &&*left_val),
+                        ("right", &&*right_val),
+                        ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val)),
+                        ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val)),
+                        (concat!("[", stringify!($eq), "] t"), &&$crate::FloatCmpOpTol::$eq(&*left_val, &*right_val, &*tol_val)),
+                        ("annealed", &annealed),
+                    ];
+                    $crate::AssertFailure {
+                        check: concat!("float_eq!(left, right, ", stringify!($eq), " <= t)"),
+                        fields: match annealed {
+                            Some(_) => fields,
+                            None => &fields[..fields.len() - 1],
+                        },
+                        note: $crate::AssertFloatEq::debug_categories(&*left_val, &*right_val),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_val, &*right_val),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
 
-    #[inline]
-    pub fn rel_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
-    where
-        A: FloatEqAll<B>,
-    {
-        a.eq_rel_all(b, tol)
-    }
+/// Asserts that two floating point expressions are both equal to a shared
+/// reference value, and optionally to each other.
+///
+/// This is useful when validating a port against both a specification and the
+/// output of a legacy implementation. Each candidate is compared against the
+/// reference using the given check, and then, if a `between` check is provided,
+/// against each other. The bound between candidates is often looser than the
+/// bound to the reference, or uses a different [comparison algorithm].
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the comparison that failed, with a message stating which one it was.
+/// Like [`assert!`], this macro has a second form, where a custom panic message
+/// can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_both_near_reference;
+/// let reference = 1.0f32;
+/// let port = 1.000_000_1;
+/// let legacy = 0.999_999_9;
+///
+/// assert_both_near_reference!(port, legacy, reference, ulps <= 2);
+/// assert_both_near_reference!(port, legacy, reference, ulps <= 2, between abs <= 0.000_001);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [comparison algorithm]: index.html#comparison-algorithms
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_both_near_reference {
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr, between $between_eq:ident <= $between_tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        $crate::__float_eq_check_tol!(eq, $between_eq, $between_tol);
+        match (&$a, &$b, &$reference, &$tol, &$between_tol) {
+            (a_val, b_val, reference_val, tol_val, between_tol_val) => {
+                $crate::assert_float_eq!(*a_val, *reference_val, $eq <= *tol_val, "first candidate is not near the reference");
+                $crate::assert_float_eq!(*b_val, *reference_val, $eq <= *tol_val, "second candidate is not near the reference");
+                $crate::assert_float_eq!(*a_val, *b_val, $between_eq <= *between_tol_val, "candidates are not near each other");
+            }
+        }
+    });
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr, between $between_eq:ident <= $between_tol:expr,) => ({
+        $crate::assert_both_near_reference!($a, $b, $reference, $eq <= $tol, between $between_eq <= $between_tol)
+    });
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr, between $between_eq:ident <= $between_tol:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        $crate::__float_eq_check_tol!(eq, $between_eq, $between_tol);
+        match (&$a, &$b, &$reference, &$tol, &$between_tol) {
+            (a_val, b_val, reference_val, tol_val, between_tol_val) => {
+                $crate::assert_float_eq!(*a_val, *reference_val, $eq <= *tol_val, "first candidate is not near the reference: {}", format_args!($($arg)+));
+                $crate::assert_float_eq!(*b_val, *reference_val, $eq <= *tol_val, "second candidate is not near the reference: {}", format_args!($($arg)+));
+                $crate::assert_float_eq!(*a_val, *b_val, $between_eq <= *between_tol_val, "candidates are not near each other: {}", format_args!($($arg)+));
+            }
+        }
+    });
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$a, &$b, &$reference, &$tol) {
+            (a_val, b_val, reference_val, tol_val) => {
+                $crate::assert_float_eq!(*a_val, *reference_val, $eq <= *tol_val, "first candidate is not near the reference");
+                $crate::assert_float_eq!(*b_val, *reference_val, $eq <= *tol_val, "second candidate is not near the reference");
+            }
+        }
+    });
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr,) => ({
+        $crate::assert_both_near_reference!($a, $b, $reference, $eq <= $tol)
+    });
+    ($a:expr, $b:expr, $reference:expr, $eq:ident <= $tol:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$a, &$b, &$reference, &$tol) {
+            (a_val, b_val, reference_val, tol_val) => {
+                $crate::assert_float_eq!(*a_val, *reference_val, $eq <= *tol_val, "first candidate is not near the reference: {}", format_args!($($arg)+));
+                $crate::assert_float_eq!(*b_val, *reference_val, $eq <= *tol_val, "second candidate is not near the reference: {}", format_args!($($arg)+));
+            }
+        }
+    });
+}
 
-    #[inline]
-    pub fn rmax<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
-    where
-        A: FloatEq<B>,
-    {
-        a.eq_rmax(b, tol)
-    }
+/// Asserts that a floating point expression agrees with a decimal number to a
+/// number of significant digits.
+///
+/// The decimal number is given as a string and parsed with full precision, so
+/// that reference values may be pasted verbatim without worrying about how the
+/// literal would be rounded. See [`eq_sig_figs`] for details of the check.
+///
+/// On panic, this macro will print the value of the expression with its debug
+/// representation, along with the decimal number and the number of significant
+/// digits. Like [`assert!`], this macro has a second form, where a custom panic
+/// message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_str;
+/// let x = core::f64::consts::PI;
+/// assert_float_eq_str!(x, "3.14159265358979", sig_figs <= 12);
+/// assert_float_eq_str!(x as f32, "3.14159265358979", sig_figs <= 7, "x = {}", x);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`eq_sig_figs`]: fn.eq_sig_figs.html
+#[cfg(not(feature = "checks-only"))]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_float_eq_str {
+    ($left:expr, $right:expr, sig_figs <= $sig_figs:expr) => ({
+        match (&$left, &$right, &$sig_figs) {
+            (left_val, right_val, sig_figs_val) => {
+                if !$crate::eq_sig_figs(*left_val, &*right_val, *sig_figs_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, sig_figs <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("[sig_figs] t", &&*sig_figs_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, sig_figs <= $sig_figs:expr,) => ({
+        $crate::assert_float_eq_str!($left, $right, sig_figs <= $sig_figs)
+    });
+    ($left:expr, $right:expr, sig_figs <= $sig_figs:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$sig_figs) {
+            (left_val, right_val, sig_figs_val) => {
+                if !$crate::eq_sig_figs(*left_val, &*right_val, *sig_figs_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, sig_figs <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("[sig_figs] t", &&*sig_figs_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
 
-    #[inline]
+/// Asserts that two floating point expressions are equal once both are
+/// rounded to the nearest multiple of an increment, such as cents.
+///
+/// The rounding mode may be given as a [`Rounding`], and defaults to rounding
+/// half-way values away from zero. See [`eq_rounded`] for details of the check.
+///
+/// On panic, this macro will print the values of the expressions and their
+/// rounded values with their debug representations, along with the increment
+/// and rounding mode. Like [`assert!`], this macro has a second form, where a
+/// custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_float_eq_rounded, Rounding};
+/// let subtotal = 19.99 * 3.0;
+/// assert_float_eq_rounded!(subtotal * 1.075, 64.47, increment = 0.01);
+/// assert_float_eq_rounded!(0.125, 0.12, increment = 0.01, rounding = Rounding::HalfEven);
+/// assert_float_eq_rounded!(12.6f32, 12.5, increment = 0.5, "subtotal = {}", subtotal);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`eq_rounded`]: fn.eq_rounded.html
+/// [`Rounding`]: enum.Rounding.html
+#[cfg(not(feature = "checks-only"))]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_float_eq_rounded {
+    ($left:expr, $right:expr, increment = $increment:expr, rounding = $rounding:expr) => ({
+        match (&$left, &$right, &$increment, &$rounding) {
+            (left_val, right_val, increment_val, rounding_val) => {
+                if !$crate::eq_rounded(*left_val, *right_val, *increment_val, *rounding_val) {
+                    let rounded_left = $crate::round_to_increment(*left_val, *increment_val, *rounding_val);
+                    let rounded_right = $crate::round_to_increment(*right_val, *increment_val, *rounding_val);
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq_rounded!(left, right, increment = t, rounding = r)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("rounded_left", &rounded_left),
+                            ("rounded_right", &rounded_right),
+                            ("[increment] t", &&*increment_val),
+                            ("rounding", &&*rounding_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, increment = $increment:expr, rounding = $rounding:expr,) => ({
+        $crate::assert_float_eq_rounded!($left, $right, increment = $increment, rounding = $rounding)
+    });
+    ($left:expr, $right:expr, increment = $increment:expr, rounding = $rounding:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$increment, &$rounding) {
+            (left_val, right_val, increment_val, rounding_val) => {
+                if !$crate::eq_rounded(*left_val, *right_val, *increment_val, *rounding_val) {
+                    let rounded_left = $crate::round_to_increment(*left_val, *increment_val, *rounding_val);
+                    let rounded_right = $crate::round_to_increment(*right_val, *increment_val, *rounding_val);
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq_rounded!(left, right, increment = t, rounding = r)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("rounded_left", &rounded_left),
+                            ("rounded_right", &rounded_right),
+                            ("[increment] t", &&*increment_val),
+                            ("rounding", &&*rounding_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, increment = $increment:expr) => ({
+        $crate::assert_float_eq_rounded!($left, $right, increment = $increment, rounding = $crate::Rounding::HalfAwayFromZero)
+    });
+    ($left:expr, $right:expr, increment = $increment:expr,) => ({
+        $crate::assert_float_eq_rounded!($left, $right, increment = $increment, rounding = $crate::Rounding::HalfAwayFromZero)
+    });
+    ($left:expr, $right:expr, increment = $increment:expr, $($arg:tt)+) => ({
+        $crate::assert_float_eq_rounded!($left, $right, increment = $increment, rounding = $crate::Rounding::HalfAwayFromZero, $($arg)+)
+    });
+}
+
+/// Asserts that a floating point expression is within a number of least
+/// significant bits of a fixed point reference.
+///
+/// The reference is given as a [`QFixed`], and the tolerance in LSBs of its
+/// format, which may be fractional. See [`eq_fixed`] for details of the check.
+///
+/// On panic, this macro will print the value of the expression and the fixed
+/// point reference with their debug representations, along with the value of
+/// the reference and the difference between them in LSBs. Like [`assert!`],
+/// this macro has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_float_eq_fixed, QFixed};
+/// let gain = 0.3f32;
+/// assert_float_eq_fixed!(gain, QFixed::q15(9830), lsb <= 0.5);
+/// assert_float_eq_fixed!(gain * 2.0, QFixed::q15(19661), lsb <= 1.0, "gain = {}", gain);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`eq_fixed`]: fn.eq_fixed.html
+/// [`QFixed`]: struct.QFixed.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_fixed {
+    ($left:expr, $right:expr, lsb <= $tol:expr) => ({
+        match (&$left, &$right, &$tol) {
+            (left_val, right_val, tol_val) => {
+                if !$crate::eq_fixed(*left_val, *right_val, *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, lsb <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("right_value", &right_val.to_f64()),
+                            ("lsb_diff", &right_val.lsb_diff(*left_val)),
+                            ("[lsb] t", &&*tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, lsb <= $tol:expr,) => ({
+        $crate::assert_float_eq_fixed!($left, $right, lsb <= $tol)
+    });
+    ($left:expr, $right:expr, lsb <= $tol:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$tol) {
+            (left_val, right_val, tol_val) => {
+                if !$crate::eq_fixed(*left_val, *right_val, *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "float_eq!(left, right, lsb <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("right_value", &right_val.to_f64()),
+                            ("lsb_diff", &right_val.lsb_diff(*left_val)),
+                            ("[lsb] t", &&*tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two measurements are equal at `k` sigma, since the intervals
+/// given by their uncertainties overlap.
+///
+/// Both operands must be [`Measured`] values of the same type. See
+/// [`Measured::overlaps`] for details of the check.
+///
+/// On panic, this macro will print both measurements with their debug
+/// representations, along with the number of sigmas separating them. Like
+/// [`assert!`], this macro has a second form, where a custom panic message can
+/// be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_measured_eq, Measured};
+/// let simulated = Measured::new(0.482f64, 0.004);
+/// let measured = Measured::new(0.475f64, 0.003);
+/// assert_measured_eq!(simulated, measured, k <= 2.0);
+/// assert_measured_eq!(simulated, Measured::exact(0.48), k <= 3.0, "after {} runs", 1000);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`Measured`]: struct.Measured.html
+/// [`Measured::overlaps`]: struct.Measured.html#method.overlaps
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_measured_eq {
+    ($left:expr, $right:expr, k <= $k:expr) => ({
+        match (&$left, &$right, &$k) {
+            (left_val, right_val, k_val) => {
+                if !left_val.overlaps(right_val, *k_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "measured_eq!(left, right, k <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("sigma_diff", &left_val.sigma_diff(right_val)),
+                            ("[k] t", &&*k_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, k <= $k:expr,) => ({
+        $crate::assert_measured_eq!($left, $right, k <= $k)
+    });
+    ($left:expr, $right:expr, k <= $k:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$k) {
+            (left_val, right_val, k_val) => {
+                if !left_val.overlaps(right_val, *k_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "measured_eq!(left, right, k <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("sigma_diff", &left_val.sigma_diff(right_val)),
+                            ("[k] t", &&*k_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a calculation gives equal results with fused and unfused
+/// multiply-adds.
+///
+/// The calculation is given as a closure taking a [`MulAdd`], which is run by
+/// [`fma_differential`] once with each mode. The fused result is compared as
+/// the left operand and the unfused result as the right, using any of the
+/// checks accepted by [`assert_float_eq!`].
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`],
+/// with a message stating that the results differ. Like [`assert!`], this macro
+/// has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_fma_agrees;
+/// fn dot(a: &[f32], b: &[f32], m: float_eq::MulAdd) -> f32 {
+///     a.iter().zip(b).fold(0.0, |acc, (a, b)| m.mul_add(*a, *b, acc))
+/// }
+///
+/// let a = [0.1, 0.2, 0.3];
+/// let b = [0.4, 0.5, 0.6];
+/// assert_fma_agrees!(|m| dot(&a, &b, m), ulps <= 4);
+/// assert_fma_agrees!(|m| dot(&a, &b, m), ulps <= 4, "dot of {:?} and {:?}", a, b);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`fma_differential`]: fn.fma_differential.html
+/// [`MulAdd`]: enum.MulAdd.html
+#[cfg(not(feature = "checks-only"))]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! assert_fma_agrees {
+    ($f:expr, $($eq:ident <= $tol:expr),+) => ({
+        let (fused, unfused) = $crate::fma_differential($f);
+        $crate::assert_float_eq!(fused, unfused, $($eq <= $tol),+, "fused (left) and unfused (right) results differ")
+    });
+    ($f:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_fma_agrees!($f, $($eq <= $tol),+)
+    });
+    ($f:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        let (fused, unfused) = $crate::fma_differential($f);
+        $crate::assert_float_eq!(fused, unfused, $eq1 <= $tol_1, $eq2 <= $tol_2, $eq3 <= $tol_3, "fused (left) and unfused (right) results differ: {}", format_args!($($arg)+))
+    });
+    ($f:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        let (fused, unfused) = $crate::fma_differential($f);
+        $crate::assert_float_eq!(fused, unfused, $eq1 <= $tol_1, $eq2 <= $tol_2, "fused (left) and unfused (right) results differ: {}", format_args!($($arg)+))
+    });
+    ($f:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        let (fused, unfused) = $crate::fma_differential($f);
+        $crate::assert_float_eq!(fused, unfused, $eq1 <= $tol_1, "fused (left) and unfused (right) results differ: {}", format_args!($($arg)+))
+    });
+}
+
+/// Asserts that an analytic gradient agrees with a numeric one, using the
+/// standard gradient check recipe.
+///
+/// Each pair of elements is compared by [`gradcheck_rel_diff`], their absolute
+/// difference relative to the largest of their magnitudes and a `typical`
+/// magnitude, which must be no greater than the tolerance. The gradients may
+/// be arrays, slices or vectors, such as the rows of a flattened Jacobian, and
+/// must have the same length.
+///
+/// On panic, this macro will print the pair of elements with the largest
+/// difference, along with their index, the typical magnitude and the tolerance.
+/// Like [`assert!`], this macro has a second form, where a custom panic message
+/// can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_gradcheck;
+/// let analytic = [2.0f64, -4.0, 0.0];
+/// let numeric = [2.000_000_1f64, -3.999_999_8, 1e-9];
+///
+/// assert_gradcheck!(analytic, numeric, rel <= 1e-7, typical = 1.0);
+/// assert_gradcheck!(&analytic[..], numeric.to_vec(), rel <= 1e-7, typical = 1.0, "at x = {}", 1.0);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`gradcheck_rel_diff`]: fn.gradcheck_rel_diff.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_gradcheck {
+    ($analytic:expr, $numeric:expr, rel <= $tol:expr, typical = $typical:expr) => ({
+        match (&$analytic, &$numeric, &$tol, &$typical) {
+            (analytic_val, numeric_val, tol_val, typical_val) => {
+                let analytic_slice = &analytic_val[..];
+                let numeric_slice = &numeric_val[..];
+                if analytic_slice.len() != numeric_slice.len() {
+                    $crate::AssertFailure {
+                        check: "gradcheck!(analytic, numeric, rel <= t, typical = s)",
+                        fields: &[
+                            ("analytic_len", &analytic_slice.len()),
+                            ("numeric_len", &numeric_slice.len()),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+                if let Some(worst) = $crate::gradcheck_worst(analytic_slice, numeric_slice, *typical_val) {
+                    if !(worst.rel_diff <= *tol_val) {
+                        $crate::AssertFailure {
+                            check: "gradcheck!(analytic, numeric, rel <= t, typical = s)",
+                            fields: &[
+                                ("index", &worst.index),
+                                ("analytic", &worst.analytic),
+                                ("numeric", &worst.numeric),
+                                ("rel_diff", &worst.rel_diff),
+                                ("typical", &&*typical_val),
+                                ("[rel] t", &&*tol_val),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&worst.analytic, &worst.numeric),
+                            ulps_diff_reason: None,
+                            message: None,
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+    ($analytic:expr, $numeric:expr, rel <= $tol:expr, typical = $typical:expr,) => ({
+        $crate::assert_gradcheck!($analytic, $numeric, rel <= $tol, typical = $typical)
+    });
+    ($analytic:expr, $numeric:expr, rel <= $tol:expr, typical = $typical:expr, $($arg:tt)+) => ({
+        match (&$analytic, &$numeric, &$tol, &$typical) {
+            (analytic_val, numeric_val, tol_val, typical_val) => {
+                let analytic_slice = &analytic_val[..];
+                let numeric_slice = &numeric_val[..];
+                if analytic_slice.len() != numeric_slice.len() {
+                    $crate::AssertFailure {
+                        check: "gradcheck!(analytic, numeric, rel <= t, typical = s)",
+                        fields: &[
+                            ("analytic_len", &analytic_slice.len()),
+                            ("numeric_len", &numeric_slice.len()),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+                if let Some(worst) = $crate::gradcheck_worst(analytic_slice, numeric_slice, *typical_val) {
+                    if !(worst.rel_diff <= *tol_val) {
+                        $crate::AssertFailure {
+                            check: "gradcheck!(analytic, numeric, rel <= t, typical = s)",
+                            fields: &[
+                                ("index", &worst.index),
+                                ("analytic", &worst.analytic),
+                                ("numeric", &worst.numeric),
+                                ("rel_diff", &worst.rel_diff),
+                                ("typical", &&*typical_val),
+                                ("[rel] t", &&*tol_val),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&worst.analytic, &worst.numeric),
+                            ulps_diff_reason: None,
+                            message: Some(format_args!($($arg)+)),
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that an analytic derivative agrees with a central finite difference.
+///
+/// The derivative of the function `f` at `x` is approximated by a
+/// [`central_difference`] with step size `h`, and compared with `df(x)` using
+/// [`derivative_rel_diff`]. The tolerance `tol` is widened by the expected
+/// error of the finite difference, as given by [`central_difference_tol`].
+///
+/// On panic, this macro will print the point, the step size, both derivatives,
+/// their difference and the widened tolerance. Like [`assert!`], this macro has
+/// a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_derivative_eq;
+/// let f = |x: f64| x.sin() * x;
+/// let df = |x: f64| x.cos() * x + x.sin();
+///
+/// assert_derivative_eq!(f, df, 0.5, 1e-5, 1e-8);
+/// assert_derivative_eq!(f, df, 2.0, 1e-5, 1e-8, "f(x) = x sin(x)");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`central_difference`]: fn.central_difference.html
+/// [`central_difference_tol`]: fn.central_difference_tol.html
+/// [`derivative_rel_diff`]: fn.derivative_rel_diff.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_derivative_eq {
+    ($f:expr, $df:expr, $x:expr, $h:expr, $tol:expr) => ({
+        match (&$x, &$h, &$tol) {
+            (x_val, h_val, tol_val) => {
+                let analytic = ($df)(*x_val);
+                let numeric = $crate::central_difference($f, *x_val, *h_val);
+                let rel_diff = $crate::derivative_rel_diff(analytic, numeric);
+                let tol = $crate::central_difference_tol(*tol_val, *h_val);
+                if !(rel_diff <= tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "derivative_eq!(f, df, x, h, t)",
+                        fields: &[
+                            ("x", &&*x_val),
+                            ("h", &&*h_val),
+                            ("analytic", &analytic),
+                            ("numeric", &numeric),
+                            ("rel_diff", &rel_diff),
+                            ("[rel] t", &tol),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&analytic, &numeric),
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($f:expr, $df:expr, $x:expr, $h:expr, $tol:expr,) => ({
+        $crate::assert_derivative_eq!($f, $df, $x, $h, $tol)
+    });
+    ($f:expr, $df:expr, $x:expr, $h:expr, $tol:expr, $($arg:tt)+) => ({
+        match (&$x, &$h, &$tol) {
+            (x_val, h_val, tol_val) => {
+                let analytic = ($df)(*x_val);
+                let numeric = $crate::central_difference($f, *x_val, *h_val);
+                let rel_diff = $crate::derivative_rel_diff(analytic, numeric);
+                let tol = $crate::central_difference_tol(*tol_val, *h_val);
+                if !(rel_diff <= tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "derivative_eq!(f, df, x, h, t)",
+                        fields: &[
+                            ("x", &&*x_val),
+                            ("h", &&*h_val),
+                            ("analytic", &analytic),
+                            ("numeric", &numeric),
+                            ("rel_diff", &rel_diff),
+                            ("[rel] t", &tol),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&analytic, &numeric),
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that the result of a parallel sum is equal to that of the
+/// sequential sum of the same values, given the rounding error that
+/// reordering the additions may cause.
+///
+/// The values are `len` in number, each of magnitude at most `max_abs`, and
+/// the parallel reduction sums up to `chunk` consecutive values sequentially
+/// before adding the partial sums pairwise. The results are compared with an
+/// absolute tolerance of [`parallel_sum_tol`], so that the error bound need not
+/// be derived by hand, or guessed at.
+///
+/// On panic, this macro will print both results, their difference, the
+/// parameters of the reduction and the tolerance they give. Like [`assert!`],
+/// this macro has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_parallel_sum_eq;
+/// let values: Vec<f64> = (1..=10_000).map(|i| 1.0 / i as f64).collect();
+/// let sequential: f64 = values.iter().sum();
+/// let parallel: f64 = values.chunks(256).map(|c| c.iter().sum::<f64>()).sum();
+///
+/// assert_parallel_sum_eq!(parallel, sequential, len = values.len(), max_abs = 1.0, chunk = 256);
+/// assert_parallel_sum_eq!(parallel, sequential, len = 10_000, max_abs = 1.0, chunk = 256, "harmonic");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`parallel_sum_tol`]: fn.parallel_sum_tol.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_parallel_sum_eq {
+    ($parallel:expr, $sequential:expr, len = $len:expr, max_abs = $max_abs:expr, chunk = $chunk:expr) => ({
+        match (&$parallel, &$sequential, &$len, &$max_abs, &$chunk) {
+            (parallel_val, sequential_val, len_val, max_abs_val, chunk_val) => {
+                let tol = $crate::parallel_sum_tol(*len_val, *max_abs_val, *chunk_val);
+                if !$crate::FloatEq::eq_abs(parallel_val, sequential_val, &tol) {
+                    $crate::AssertFailure {
+                        check: "parallel_sum_eq!(parallel, sequential, len = n, max_abs = m, chunk = c)",
+                        fields: &[
+                            ("parallel", &&*parallel_val),
+                            ("sequential", &&*sequential_val),
+                            ("abs_diff", &$crate::AssertFloatEq::debug_abs_diff(parallel_val, sequential_val)),
+                            ("len", &&*len_val),
+                            ("max_abs", &&*max_abs_val),
+                            ("chunk", &&*chunk_val),
+                            ("[abs] t", &tol),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(parallel_val, sequential_val),
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($parallel:expr, $sequential:expr, len = $len:expr, max_abs = $max_abs:expr, chunk = $chunk:expr,) => ({
+        $crate::assert_parallel_sum_eq!($parallel, $sequential, len = $len, max_abs = $max_abs, chunk = $chunk)
+    });
+    ($parallel:expr, $sequential:expr, len = $len:expr, max_abs = $max_abs:expr, chunk = $chunk:expr, $($arg:tt)+) => ({
+        match (&$parallel, &$sequential, &$len, &$max_abs, &$chunk) {
+            (parallel_val, sequential_val, len_val, max_abs_val, chunk_val) => {
+                let tol = $crate::parallel_sum_tol(*len_val, *max_abs_val, *chunk_val);
+                if !$crate::FloatEq::eq_abs(parallel_val, sequential_val, &tol) {
+                    $crate::AssertFailure {
+                        check: "parallel_sum_eq!(parallel, sequential, len = n, max_abs = m, chunk = c)",
+                        fields: &[
+                            ("parallel", &&*parallel_val),
+                            ("sequential", &&*sequential_val),
+                            ("abs_diff", &$crate::AssertFloatEq::debug_abs_diff(parallel_val, sequential_val)),
+                            ("len", &&*len_val),
+                            ("max_abs", &&*max_abs_val),
+                            ("chunk", &&*chunk_val),
+                            ("[abs] t", &tol),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(parallel_val, sequential_val),
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that applying a function to its own result gives the same value
+/// again, such that `f(f(x))` is equal to `f(x)`.
+///
+/// Idempotence is expected of operations such as normalization, projection,
+/// clamping and rounding, and checking it is a common test of their numerical
+/// stability. The function is applied to `x` and then to a clone of the result,
+/// which must be of the same type, and the two results are compared with any
+/// single check accepted by [`assert_float_eq!`], with `f(f(x))` as the first
+/// operand. `x` is passed by value, and `f` may be a closure or function that
+/// is called through a shared reference.
+///
+/// On panic, this macro will print the results of both applications, labelled
+/// `once` and `twice`, along with the difference between them and the
+/// tolerance. Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_idempotent;
+/// let normalize = |v: [f64; 2]| {
+///     let len = (v[0] * v[0] + v[1] * v[1]).sqrt();
+///     [v[0] / len, v[1] / len]
+/// };
+///
+/// assert_float_idempotent!(normalize, [3.0, 4.0], ulps_all <= 1);
+/// assert_float_idempotent!(f64::abs, -2.5, abs <= 0.0, "abs of {}", -2.5);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_idempotent {
+    ($f:expr, $x:expr, $eq:ident <= $tol:expr) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$f, &$tol) {
+            (f_val, tol_val) => {
+                let once = f_val($x);
+                let twice = f_val(::core::clone::Clone::clone(&once));
+                if !$crate::float_eq!(twice, once, $eq <= *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: concat!("float_idempotent!(f, x, ", stringify!($eq), " <= t)"),
+                        fields: &[
+                            ("once", &&once),
+                            ("twice", &&twice),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&twice, &once)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&twice, &once)),
+                            (concat!("[", stringify!($eq), "] t"), &&$crate::FloatCmpOpTol::$eq(&twice, &once, &*tol_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&twice, &once),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&twice, &once),
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($f:expr, $x:expr, $eq:ident <= $tol:expr,) => ({
+        $crate::assert_float_idempotent!($f, $x, $eq <= $tol)
+    });
+    ($f:expr, $x:expr, $eq:ident <= $tol:expr, $($arg:tt)+) => ({
+        $crate::__float_eq_check_tol!(eq, $eq, $tol);
+        match (&$f, &$tol) {
+            (f_val, tol_val) => {
+                let once = f_val($x);
+                let twice = f_val(::core::clone::Clone::clone(&once));
+                if !$crate::float_eq!(twice, once, $eq <= *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: concat!("float_idempotent!(f, x, ", stringify!($eq), " <= t)"),
+                        fields: &[
+                            ("once", &&once),
+                            ("twice", &&twice),
+                            ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&twice, &once)),
+                            ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&twice, &once)),
+                            (concat!("[", stringify!($eq), "] t"), &&$crate::FloatCmpOpTol::$eq(&twice, &once, &*tol_val)),
+                        ],
+                        note: $crate::AssertFloatEq::debug_categories(&twice, &once),
+                        ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&twice, &once),
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a sequence of iterates converges to a target.
+///
+/// The final iterate is compared with the target using any of the checks
+/// accepted by [`assert_float_eq!`]. Optionally, a `rate` bound may be given
+/// first, in which case the absolute error of each iterate must also be at most
+/// `rate` times that of the previous one, as checked by
+/// [`linear_rate_violation`]. The iterates may be an array, slice or vector of
+/// `f32` or `f64`, such as the history of an ODE or iterative solver, and must
+/// not be empty.
+///
+/// On panic, this macro will print the [`ConvergenceHistory`] of the iterates
+/// along with the details of the check that failed. Like [`assert!`], this
+/// macro has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_converges;
+/// // Newton's method for the square root of two
+/// let mut iterates = vec![1.0f64];
+/// for _ in 0..5 {
+///     let x = iterates.last().unwrap();
+///     iterates.push(x - (x * x - 2.0) / (2.0 * x));
+/// }
+///
+/// let target = 2.0f64.sqrt();
+/// assert_converges!(iterates, target, ulps <= 1);
+/// assert_converges!(iterates, target, rate <= 0.5, abs <= 1e-15, "newton");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`ConvergenceHistory`]: struct.ConvergenceHistory.html
+/// [`linear_rate_violation`]: fn.linear_rate_violation.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_converges {
+    // rate must be matched before the general case, since it is also an ident.
+    ($iterates:expr, $target:expr, rate <= $rate:expr, $($eq:ident <= $tol:expr),+) => ({
+        match (&$iterates, &$target, &$rate) {
+            (iterates_val, target_val, rate_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $($eq <= $tol),+, "iterates did not converge to the target, history: {:?}", history);
+                        if let Some(violation) = $crate::linear_rate_violation(iterates_slice, *target_val, *rate_val) {
+                            // The reborrows below are intentional. See assert_eq! in the standard library.
+                            $crate::AssertFailure {
+                                check: concat!("converges!(iterates, target, rate <= t" $(, ", ", stringify!($eq), " <= t")+, ")"),
+                                fields: &[
+                                    ("index", &violation.index),
+                                    ("error", &violation.error),
+                                    ("next_error", &violation.next_error),
+                                    ("rate", &violation.rate()),
+                                    ("history", &history),
+                                    ("[rate] t", &&*rate_val),
+                                ],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: None,
+                            }
+                            .panic()
+                        }
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target, rate <= t" $(, ", ", stringify!($eq), " <= t")+, ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates")),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, rate <= $rate:expr, $($eq:ident <= $tol:expr,)+) => ({
+        $crate::assert_converges!($iterates, $target, rate <= $rate $(, $eq <= $tol)+)
+    });
+    ($iterates:expr, $target:expr, rate <= $rate:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target, &$rate) {
+            (iterates_val, target_val, rate_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, $eq2 <= $tol_2, $eq3 <= $tol_3, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                        if let Some(violation) = $crate::linear_rate_violation(iterates_slice, *target_val, *rate_val) {
+                            // The reborrows below are intentional. See assert_eq! in the standard library.
+                            $crate::AssertFailure {
+                                check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                                fields: &[
+                                    ("index", &violation.index),
+                                    ("error", &violation.error),
+                                    ("next_error", &violation.next_error),
+                                    ("rate", &violation.rate()),
+                                    ("history", &history),
+                                    ("[rate] t", &&*rate_val),
+                                ],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: Some(format_args!($($arg)+)),
+                            }
+                            .panic()
+                        }
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, rate <= $rate:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target, &$rate) {
+            (iterates_val, target_val, rate_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, $eq2 <= $tol_2, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                        if let Some(violation) = $crate::linear_rate_violation(iterates_slice, *target_val, *rate_val) {
+                            // The reborrows below are intentional. See assert_eq! in the standard library.
+                            $crate::AssertFailure {
+                                check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                                fields: &[
+                                    ("index", &violation.index),
+                                    ("error", &violation.error),
+                                    ("next_error", &violation.next_error),
+                                    ("rate", &violation.rate()),
+                                    ("history", &history),
+                                    ("[rate] t", &&*rate_val),
+                                ],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: Some(format_args!($($arg)+)),
+                            }
+                            .panic()
+                        }
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, rate <= $rate:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target, &$rate) {
+            (iterates_val, target_val, rate_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                        if let Some(violation) = $crate::linear_rate_violation(iterates_slice, *target_val, *rate_val) {
+                            // The reborrows below are intentional. See assert_eq! in the standard library.
+                            $crate::AssertFailure {
+                                check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ")"),
+                                fields: &[
+                                    ("index", &violation.index),
+                                    ("error", &violation.error),
+                                    ("next_error", &violation.next_error),
+                                    ("rate", &violation.rate()),
+                                    ("history", &history),
+                                    ("[rate] t", &&*rate_val),
+                                ],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: Some(format_args!($($arg)+)),
+                            }
+                            .panic()
+                        }
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target, rate <= t", ", ", stringify!($eq1), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, $($eq:ident <= $tol:expr),+) => ({
+        match (&$iterates, &$target) {
+            (iterates_val, target_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $($eq <= $tol),+, "iterates did not converge to the target, history: {:?}", history);
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target" $(, ", ", stringify!($eq), " <= t")+, ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates")),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, $($eq:ident <= $tol:expr,)+) => ({
+        $crate::assert_converges!($iterates, $target $(, $eq <= $tol)+)
+    });
+    ($iterates:expr, $target:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target) {
+            (iterates_val, target_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, $eq2 <= $tol_2, $eq3 <= $tol_3, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target) {
+            (iterates_val, target_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, $eq2 <= $tol_2, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+    ($iterates:expr, $target:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$iterates, &$target) {
+            (iterates_val, target_val) => {
+                let iterates_slice = &iterates_val[..];
+                let history = $crate::ConvergenceHistory::new(iterates_slice, *target_val);
+                match iterates_slice.last() {
+                    Some(last) => {
+                        $crate::assert_float_eq!(*last, *target_val, $eq1 <= $tol_1, "iterates did not converge to the target, history: {:?}: {}", history, format_args!($($arg)+));
+                    }
+                    None => $crate::AssertFailure {
+                        check: concat!("converges!(iterates, target", ", ", stringify!($eq1), " <= t", ")"),
+                        fields: &[("iterates", &iterates_slice)],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("there are no iterates: {}", format_args!($($arg)+))),
+                    }
+                    .panic(),
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that every element of a time series is equal to its initial value,
+/// such as the total energy of a simulated physical system.
+///
+/// Each element is compared as the left operand against the first element as
+/// the right, using any of the checks accepted by [`assert_float_eq!`]. The
+/// series may be an array, slice or vector of any type that may be compared,
+/// and an empty series is trivially conserved.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first element that is not equal to the initial value, with a message
+/// stating its timestep. Like [`assert!`], this macro has a second form, where
+/// a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_invariant_conserved;
+/// let energy = [10.0f64, 10.000_000_1, 9.999_999_9, 10.000_000_05];
+///
+/// assert_invariant_conserved!(energy, rel <= 1e-6);
+/// assert_invariant_conserved!(energy, rel <= 1e-6, "with dt = {}", 0.01);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_invariant_conserved {
+    ($series:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$series, &$tol_1, &$tol_2, &$tol_3) {
+            (series_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "invariant not conserved at step {}", step);
+                    }
+                }
+            }
+        }
+    });
+    ($series:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$series, &$tol_1, &$tol_2) {
+            (series_val, tol_1_val, tol_2_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "invariant not conserved at step {}", step);
+                    }
+                }
+            }
+        }
+    });
+    ($series:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$series, &$tol_1) {
+            (series_val, tol_1_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, "invariant not conserved at step {}", step);
+                    }
+                }
+            }
+        }
+    });
+    ($series:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_invariant_conserved!($series, $($eq <= $tol),+)
+    });
+    ($series:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$series, &$tol_1, &$tol_2, &$tol_3) {
+            (series_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "invariant not conserved at step {}: {}", step, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($series:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$series, &$tol_1, &$tol_2) {
+            (series_val, tol_1_val, tol_2_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "invariant not conserved at step {}: {}", step, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($series:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$series, &$tol_1) {
+            (series_val, tol_1_val) => {
+                let series_slice = &series_val[..];
+                if let Some(initial) = series_slice.first() {
+                    for (step, value) in series_slice.iter().enumerate() {
+                        $crate::assert_float_eq!(*value, *initial, $eq1 <= *tol_1_val, "invariant not conserved at step {}: {}", step, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two slices of floating point values are equal to each other,
+/// element by element.
+///
+/// The operands may be arrays, slices, vectors or anything else that may be
+/// indexed by a full range to give a slice. They must have the same length,
+/// and each pair of elements at the same index is compared using the given
+/// check, so the tolerance is that of a single element. This is the `_all`
+/// tolerance of the slices themselves for checks such as `abs` and `rmax`.
+///
+/// On panic, this macro will print the lengths of the slices if they differ,
+/// or else only the first pair of elements that are not equal, along with
+/// their index and the same information as [`assert_float_eq!`]. Like
+/// [`assert!`], this macro has a second form, where a custom panic message can
+/// be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_slice;
+/// let expected = vec![1.0f32, 2.0, 3.0];
+/// let actual = [1.000_000_1f32, 2.0, 2.999_999_8];
+///
+/// assert_float_eq_slice!(actual, expected, ulps <= 2);
+/// assert_float_eq_slice!(&actual[..2], &expected[..2], abs <= 0.001, "prefix of {:?}", actual);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_slice {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                let left_slice = &left_val[..];
+                let right_slice = &right_val[..];
+                if left_slice.len() != right_slice.len() {
+                    $crate::AssertFailure {
+                        check: concat!("float_eq_slice!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left_len", &left_slice.len()),
+                            ("right_len", &right_slice.len()),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+                for (index, (left_elem, right_elem)) in left_slice.iter().zip(right_slice).enumerate() {
+                    if !$crate::float_eq!(*left_elem, *right_elem, $eq1 <= *tol_1_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq_slice!(left, right, ", stringify!($eq1), " <= t)"),
+                            fields: &[
+                                ("index", &index),
+                                ("left", &&*left_elem),
+                                ("right", &&*right_elem),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_elem, &*right_elem)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_elem, &*right_elem)),
+                                (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_elem, &*right_elem, &*tol_1_val)),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_elem, &*right_elem),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_elem, &*right_elem),
+                            message: None,
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr,) => ({
+        $crate::assert_float_eq_slice!($left, $right, $eq1 <= $tol_1)
+    });
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                let left_slice = &left_val[..];
+                let right_slice = &right_val[..];
+                if left_slice.len() != right_slice.len() {
+                    $crate::AssertFailure {
+                        check: concat!("float_eq_slice!(left, right, ", stringify!($eq1), " <= t)"),
+                        fields: &[
+                            ("left_len", &left_slice.len()),
+                            ("right_len", &right_slice.len()),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+                for (index, (left_elem, right_elem)) in left_slice.iter().zip(right_slice).enumerate() {
+                    if !$crate::float_eq!(*left_elem, *right_elem, $eq1 <= *tol_1_val) {
+                        // The reborrows below are intentional. See assert_eq! in the standard library.
+                        $crate::AssertFailure {
+                            check: concat!("float_eq_slice!(left, right, ", stringify!($eq1), " <= t)"),
+                            fields: &[
+                                ("index", &index),
+                                ("left", &&*left_elem),
+                                ("right", &&*right_elem),
+                                ("abs_diff", &&$crate::AssertFloatEq::debug_abs_diff(&*left_elem, &*right_elem)),
+                                ("ulps_diff", &&$crate::AssertFloatEq::debug_ulps_diff(&*left_elem, &*right_elem)),
+                                (concat!("[", stringify!($eq1), "] t"), &&$crate::FloatCmpOpTol::$eq1(&*left_elem, &*right_elem, &*tol_1_val)),
+                            ],
+                            note: $crate::AssertFloatEq::debug_categories(&*left_elem, &*right_elem),
+                            ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&*left_elem, &*right_elem),
+                            message: Some(format_args!($($arg)+)),
+                        }
+                        .panic()
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that the elements of two iterators are equal to each other, pair by
+/// pair.
+///
+/// The operands may be anything that implements `IntoIterator`, and are
+/// consumed. Each pair of elements is compared using the given check, so the
+/// tolerance is that of a single element, and both iterators must yield the
+/// same number of elements.
+///
+/// On panic, this macro will print only the first pair of elements that are
+/// not equal, along with their index and the same information as
+/// [`assert_float_eq!`]. If one iterator runs out before the other, it will
+/// instead print the number of elements each yields, which requires the
+/// longer one to be run to completion. Like [`assert!`], this macro has a
+/// second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_eq_iter;
+/// let samples = [0.0f64, 0.5, 1.0];
+/// let scaled = samples.iter().map(|x| x * 0.1);
+///
+/// assert_float_eq_iter!(scaled, [0.0f64, 0.05, 0.1], rmax <= f64::EPSILON);
+/// assert_float_eq_iter!(samples.iter(), &[0.0, 0.5, 1.0], abs <= 0.0);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_float_eq_iter {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$tol_1,) {
+            (tol_1_val,) => {
+                let mut left_iter = ::core::iter::IntoIterator::into_iter($left);
+                let mut right_iter = ::core::iter::IntoIterator::into_iter($right);
+                let mut index: usize = 0;
+                loop {
+                    match (left_iter.next(), right_iter.next()) {
+                        (Some(left_val), Some(right_val)) => {
+                            if !$crate::float_eq!(left_val, right_val, $eq1 <= *tol_1_val) {
+                                // The reborrows below are intentional. See assert_eq! in the standard library.
+                                $crate::AssertFailure {
+                                    check: concat!("float_eq_iter!(left, right, ", stringify!($eq1), " <= t)"),
+                                    fields: &[
+                                        ("index", &index),
+                                        ("left", &left_val),
+                                        ("right", &right_val),
+                                        ("abs_diff", &$crate::AssertFloatEq::debug_abs_diff(&left_val, &right_val)),
+                                        ("ulps_diff", &$crate::AssertFloatEq::debug_ulps_diff(&left_val, &right_val)),
+                                        (concat!("[", stringify!($eq1), "] t"), &$crate::FloatCmpOpTol::$eq1(&left_val, &right_val, &*tol_1_val)),
+                                    ],
+                                    note: $crate::AssertFloatEq::debug_categories(&left_val, &right_val),
+                                    ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&left_val, &right_val),
+                                    message: None,
+                                }
+                                .panic()
+                            }
+                        }
+                        (None, None) => break,
+                        (left_val, right_val) => {
+                            let left_len = index + left_val.map_or(0, |_| 1 + left_iter.count());
+                            let right_len = index + right_val.map_or(0, |_| 1 + right_iter.count());
+                            $crate::AssertFailure {
+                                check: concat!("float_eq_iter!(left, right, ", stringify!($eq1), " <= t)"),
+                                fields: &[("left_len", &left_len), ("right_len", &right_len)],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: None,
+                            }
+                            .panic()
+                        }
+                    }
+                    index += 1;
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr,) => ({
+        $crate::assert_float_eq_iter!($left, $right, $eq1 <= $tol_1)
+    });
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$tol_1,) {
+            (tol_1_val,) => {
+                let mut left_iter = ::core::iter::IntoIterator::into_iter($left);
+                let mut right_iter = ::core::iter::IntoIterator::into_iter($right);
+                let mut index: usize = 0;
+                loop {
+                    match (left_iter.next(), right_iter.next()) {
+                        (Some(left_val), Some(right_val)) => {
+                            if !$crate::float_eq!(left_val, right_val, $eq1 <= *tol_1_val) {
+                                // The reborrows below are intentional. See assert_eq! in the standard library.
+                                $crate::AssertFailure {
+                                    check: concat!("float_eq_iter!(left, right, ", stringify!($eq1), " <= t)"),
+                                    fields: &[
+                                        ("index", &index),
+                                        ("left", &left_val),
+                                        ("right", &right_val),
+                                        ("abs_diff", &$crate::AssertFloatEq::debug_abs_diff(&left_val, &right_val)),
+                                        ("ulps_diff", &$crate::AssertFloatEq::debug_ulps_diff(&left_val, &right_val)),
+                                        (concat!("[", stringify!($eq1), "] t"), &$crate::FloatCmpOpTol::$eq1(&left_val, &right_val, &*tol_1_val)),
+                                    ],
+                                    note: $crate::AssertFloatEq::debug_categories(&left_val, &right_val),
+                                    ulps_diff_reason: $crate::AssertFloatEq::debug_ulps_diff_reason(&left_val, &right_val),
+                                    message: Some(format_args!($($arg)+)),
+                                }
+                                .panic()
+                            }
+                        }
+                        (None, None) => break,
+                        (left_val, right_val) => {
+                            let left_len = index + left_val.map_or(0, |_| 1 + left_iter.count());
+                            let right_len = index + right_val.map_or(0, |_| 1 + right_iter.count());
+                            $crate::AssertFailure {
+                                check: concat!("float_eq_iter!(left, right, ", stringify!($eq1), " <= t)"),
+                                fields: &[("left_len", &left_len), ("right_len", &right_len)],
+                                note: None,
+                                ulps_diff_reason: None,
+                                message: Some(format_args!($($arg)+)),
+                            }
+                            .panic()
+                        }
+                    }
+                    index += 1;
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that every element of a collection is equal to an expected value.
+///
+/// Each element is compared as the left operand against the expected value as
+/// the right, using any of the checks accepted by [`assert_float_eq!`]. The
+/// collection may be an array, slice, vector or anything else with an `iter`
+/// method that yields references to its elements, and an empty collection
+/// always passes.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first element that is not equal to the expected value, with a
+/// message stating its index. Like [`assert!`], this macro has a second form,
+/// where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_all_float_eq_to;
+/// let weights = vec![0.25f64, 0.250_000_000_000_000_06, 0.25, 0.249_999_999_999_999_97];
+///
+/// assert_all_float_eq_to!(weights, 0.25, ulps <= 2);
+/// assert_all_float_eq_to!(weights, 0.25, abs <= 1e-15, "after {} updates", 3);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_all_float_eq_to {
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$values, &$expected, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, expected_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "element {} is not equal to the expected value", index);
+                }
+            }
+        }
+    });
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$values, &$expected, &$tol_1, &$tol_2) {
+            (values_val, expected_val, tol_1_val, tol_2_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "element {} is not equal to the expected value", index);
+                }
+            }
+        }
+    });
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$values, &$expected, &$tol_1) {
+            (values_val, expected_val, tol_1_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, "element {} is not equal to the expected value", index);
+                }
+            }
+        }
+    });
+    ($values:expr, $expected:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_all_float_eq_to!($values, $expected, $($eq <= $tol),+)
+    });
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$values, &$expected, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, expected_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "element {} is not equal to the expected value: {}", index, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$values, &$expected, &$tol_1, &$tol_2) {
+            (values_val, expected_val, tol_1_val, tol_2_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "element {} is not equal to the expected value: {}", index, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($values:expr, $expected:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$values, &$expected, &$tol_1) {
+            (values_val, expected_val, tol_1_val) => {
+                for (index, value) in values_val.iter().enumerate() {
+                    $crate::assert_float_eq!(*value, *expected_val, $eq1 <= *tol_1_val, "element {} is not equal to the expected value: {}", index, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that every pair of elements of a collection are equal to each
+/// other, when there is no expected value to compare them against.
+///
+/// Each element is compared as the left operand against every later element
+/// as the right, using any of the checks accepted by [`assert_float_eq!`].
+/// Unlike comparing every element against the first, this catches collections
+/// whose elements drift apart by less than the tolerance at a time. The
+/// collection may be an array, slice, vector or anything else with an `iter`
+/// method that yields references to its elements, and one with fewer than two
+/// elements always passes.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first pair of elements that are not equal, with a message stating
+/// their indices. Like [`assert!`], this macro has a second form, where a
+/// custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_all_pairwise_float_eq;
+/// let replicas = [3.0f32, 3.000_000_2, 2.999_999_8];
+///
+/// assert_all_pairwise_float_eq!(replicas, abs <= 0.000_001);
+/// assert_all_pairwise_float_eq!(replicas, ulps <= 4, "across {} threads", 3);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_all_pairwise_float_eq {
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$values, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "elements {} and {} are not equal", i, j);
+                    }
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$values, &$tol_1, &$tol_2) {
+            (values_val, tol_1_val, tol_2_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "elements {} and {} are not equal", i, j);
+                    }
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$values, &$tol_1) {
+            (values_val, tol_1_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, "elements {} and {} are not equal", i, j);
+                    }
+                }
+            }
+        }
+    });
+    ($values:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_all_pairwise_float_eq!($values, $($eq <= $tol),+)
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "elements {} and {} are not equal: {}", i, j, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1, &$tol_2) {
+            (values_val, tol_1_val, tol_2_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "elements {} and {} are not equal: {}", i, j, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1) {
+            (values_val, tol_1_val) => {
+                for (i, a) in values_val.iter().enumerate() {
+                    for (j, b) in values_val.iter().enumerate().skip(i + 1) {
+                        $crate::assert_float_eq!(*a, *b, $eq1 <= *tol_1_val, "elements {} and {} are not equal: {}", i, j, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a slice is sorted in ascending order, where elements that are
+/// equal within a tolerance may appear in either order.
+///
+/// This suits checking the output of a sort by a score that is subject to
+/// round-off, such as one computed in parallel, where near ties may be swapped
+/// from run to run. Each element is compared against the largest element
+/// before it, using any of the checks accepted by [`assert_float_eq!`], and
+/// only if it is less than that element. The slice may be an array, slice or
+/// vector of any type that may be both compared and ordered. NaNs are
+/// unordered, so they are ignored.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first pair of elements that are out of order, with a message
+/// stating their indices and the number of such inversions, as found by
+/// [`sort_inversions_by`]. Like [`assert!`], this macro has a second form,
+/// where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_sorted;
+/// let scores = [0.1f64, 0.300_000_000_000_000_04, 0.3, 0.5];
+///
+/// assert_sorted!(scores, ulps <= 1);
+/// assert_sorted!(scores, abs <= 1e-12, "ranking of {} items", scores.len());
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`sort_inversions_by`]: fn.sort_inversions_by.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_sorted {
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$values, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "elements {} and {} are out of order, of {} inversions", i, j, count);
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$values, &$tol_1, &$tol_2) {
+            (values_val, tol_1_val, tol_2_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "elements {} and {} are out of order, of {} inversions", i, j, count);
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$values, &$tol_1) {
+            (values_val, tol_1_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, "elements {} and {} are out of order, of {} inversions", i, j, count);
+                }
+            }
+        }
+    });
+    ($values:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_sorted!($values, $($eq <= $tol),+)
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1, &$tol_2, &$tol_3) {
+            (values_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "elements {} and {} are out of order, of {} inversions: {}", i, j, count, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1, &$tol_2) {
+            (values_val, tol_1_val, tol_2_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "elements {} and {} are out of order, of {} inversions: {}", i, j, count, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol_1) {
+            (values_val, tol_1_val) => {
+                let values_slice = &values_val[..];
+                let mut inversions = $crate::sort_inversions_by(values_slice, (), |a, b, _| $crate::float_eq!(*a, *b, $eq1 <= *tol_1_val));
+                if let Some((i, j)) = inversions.next() {
+                    let count = 1 + inversions.count();
+                    $crate::assert_float_eq!(values_slice[i], values_slice[j], $eq1 <= *tol_1_val, "elements {} and {} are out of order, of {} inversions: {}", i, j, count, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a square matrix is equal to its transpose.
+///
+/// Each element above the diagonal is compared as the left operand against
+/// the mirrored element below the diagonal as the right, using any of the
+/// checks accepted by [`assert_float_eq!`]. The matrix may be any
+/// [`SquareMatrix`], such as `[[f64; 3]; 3]`.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first pair of elements that differ, with a message stating their
+/// positions. Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_symmetric;
+/// let m = [[2.0f64, 0.5, 0.1], [0.5, 3.0, 0.2], [0.1, 0.200_000_000_000_000_1, 1.0]];
+///
+/// assert_symmetric!(m, ulps <= 4);
+/// assert_symmetric!(m, abs <= 1e-12, "covariance of {}", "x");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`SquareMatrix`]: trait.SquareMatrix.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_symmetric {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_symmetric!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row + 1..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            *$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not symmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a square matrix is equal to the negation of its transpose.
+///
+/// Each element on or above the diagonal is compared as the left operand
+/// against the negated mirrored element as the right, using any of the checks
+/// accepted by [`assert_float_eq!`], so the diagonal must be zero. The matrix
+/// may be any [`SquareMatrix`] whose elements may be negated.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first pair of elements that differ, with a message stating their
+/// positions. Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_antisymmetric;
+/// let m = [[0.0f32, 2.0, -1.0], [-2.0, 0.0, 0.5], [1.0, -0.5, 0.0]];
+///
+/// assert_antisymmetric!(m, abs <= 0.0);
+/// assert_antisymmetric!(m, ulps <= 1, "cross product matrix");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`SquareMatrix`]: trait.SquareMatrix.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_antisymmetric {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {})", row, col, col, row);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_antisymmetric!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in row..n {
+                        $crate::assert_float_eq!(
+                            *$crate::SquareMatrix::elem(m_val, row, col),
+                            -*$crate::SquareMatrix::elem(m_val, col, row),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not antisymmetric at ({}, {}) and ({}, {}): {}", row, col, col, row, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a real square matrix is orthogonal, so that its product with
+/// its transpose is the identity.
+///
+/// Each element of `M·Mᵀ`, as given by [`gram_entry`], is compared as the left
+/// operand against the same element of the identity matrix as the right, using
+/// any of the checks accepted by [`assert_float_eq!`]. Since the diagonal of
+/// the identity is one and the rest zero, an absolute tolerance check is
+/// usually most appropriate. The matrix may be any [`SquareMatrix`] of
+/// [`MatrixScalar`] elements.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the first element of the product that differs from the identity, with
+/// a message stating its position, which is that of the pair of rows that are
+/// not orthonormal. Like [`assert!`], this macro has a second form, where a
+/// custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_orthogonal;
+/// let (s, c) = 0.3f64.sin_cos();
+/// let rotation = [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]];
+///
+/// assert_orthogonal!(rotation, abs <= 1e-15);
+/// assert_orthogonal!(rotation, abs <= 1e-15, "rotation by {}", 0.3);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`gram_entry`]: fn.gram_entry.html
+/// [`MatrixScalar`]: trait.MatrixScalar.html
+/// [`SquareMatrix`]: trait.SquareMatrix.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_orthogonal {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_orthogonal!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not orthogonal, M·Mᵀ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a complex square matrix is unitary, so that its product with
+/// its conjugate transpose is the identity.
+///
+/// This is the complex equivalent of [`assert_orthogonal!`], comparing each
+/// element of `M·Mᴴ` against the identity. Complex elements require the `num`
+/// feature.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "num")]
+/// # {
+/// # use float_eq::assert_unitary;
+/// use num_complex::Complex64;
+/// let h = core::f64::consts::FRAC_1_SQRT_2;
+/// let m = [
+///     [Complex64::new(h, 0.0), Complex64::new(0.0, h)],
+///     [Complex64::new(0.0, h), Complex64::new(h, 0.0)],
+/// ];
+///
+/// assert_unitary!(m, abs_all <= 1e-15);
+/// assert_unitary!(m, abs_all <= 1e-15, "beam splitter");
+/// # }
+/// ```
+///
+/// [`assert_orthogonal!`]: macro.assert_orthogonal.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_unitary {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {})", row, col);
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_unitary!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val, $eq2 <= *tol_2_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                let n = $crate::SquareMatrix::dim(m_val);
+                for row in 0..n {
+                    for col in 0..n {
+                        $crate::assert_float_eq!(
+                            $crate::gram_entry(m_val, row, col),
+                            $crate::identity_entry(m_val, row, col),
+                            $eq1 <= *tol_1_val,
+                            "matrix is not unitary, M·Mᴴ differs from the identity at ({}, {}): {}", row, col, format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a vector of floats sums to one, such as the output of a softmax.
+///
+/// The [`probability_sum`] of the values is compared as the left operand against
+/// one as the right, using any of the checks accepted by [`assert_float_eq!`].
+/// The values may be an array, slice or vector of `f32` or `f64`. Unlike
+/// [`assert_simplex!`], the values themselves are not checked.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the sum. Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_sums_to_one;
+/// let p = [0.1f64, 0.2, 0.3, 0.4];
+///
+/// assert_sums_to_one!(p, abs <= 1e-12);
+/// assert_sums_to_one!(p, abs <= 1e-12, "softmax of {:?}", [1.0, 2.0]);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`assert_simplex!`]: macro.assert_simplex.html
+/// [`probability_sum`]: fn.probability_sum.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_sums_to_one {
+    ($values:expr, $($eq:ident <= $tol:expr),+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $($eq <= $tol),+, "values do not sum to one");
+            }
+        }
+    });
+    ($values:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_sums_to_one!($values, $($eq <= $tol),+)
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, $eq2 <= $tol_2, $eq3 <= $tol_3, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, $eq2 <= $tol_2, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+}
+
+/// Asserts that every row of a matrix of floats sums to one.
+///
+/// The [`probability_sum`] of each row is compared as the left operand against
+/// one as the right, using any of the checks accepted by [`assert_float_eq!`].
+/// The matrix may be an array, slice or vector of rows, each of which is an
+/// array, slice or vector of `f32` or `f64`, and need not be square. Unlike
+/// [`assert_row_stochastic!`], the elements themselves are not checked.
+///
+/// On panic, this macro will print the same information as [`assert_float_eq!`]
+/// for the sum of the first row that does not sum to one, with a message
+/// stating its index. Like [`assert!`], this macro has a second form, where a
+/// custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_rows_sum_to_one;
+/// let m = [[0.9f64, 0.1, 0.0], [0.25, 0.5, 0.25]];
+///
+/// assert_rows_sum_to_one!(m, abs <= 1e-12);
+/// assert_rows_sum_to_one!(m, abs <= 1e-12, "after {} steps", 10);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`assert_row_stochastic!`]: macro.assert_row_stochastic.html
+/// [`probability_sum`]: fn.probability_sum.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_rows_sum_to_one {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_rows_sum_to_one!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    $crate::assert_float_eq!($crate::probability_sum(&row_val[..]), 1.0, $eq1 <= *tol_1_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a vector of floats lies on the probability simplex, so that
+/// its values are non-negative and sum to one.
+///
+/// Every value must be zero or positive, exactly, since round-off alone does
+/// not make probabilities negative. The [`probability_sum`] of the values is
+/// then compared as the left operand against one as the right, using any of
+/// the checks accepted by [`assert_float_eq!`]. The values may be an array,
+/// slice or vector of `f32` or `f64`.
+///
+/// On panic, this macro will print the index and value of the first value that
+/// is negative or NaN, otherwise the same information as [`assert_float_eq!`]
+/// for the sum. Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_simplex;
+/// let logits = [1.0f64, 2.0, 3.0];
+/// let total: f64 = logits.iter().map(|x| x.exp()).sum();
+/// let softmax: Vec<f64> = logits.iter().map(|x| x.exp() / total).collect();
+///
+/// assert_simplex!(softmax, abs <= 1e-12);
+/// assert_simplex!(softmax, abs <= 1e-12, "softmax of {:?}", logits);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`probability_sum`]: fn.probability_sum.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_simplex {
+    ($values:expr, $($eq:ident <= $tol:expr),+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                if let Some(index) = $crate::first_negative(values_slice) {
+                    $crate::AssertFailure {
+                        check: concat!("simplex!(values"$(, ", ", stringify!($eq), " <= t")+, ")"),
+                        fields: &[("index", &index), ("value", &values_slice[index])],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("probability is negative")),
+                    }
+                    .panic()
+                }
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $($eq <= $tol),+, "values do not sum to one");
+            }
+        }
+    });
+    ($values:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_simplex!($values, $($eq <= $tol),+)
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                if let Some(index) = $crate::first_negative(values_slice) {
+                    $crate::AssertFailure {
+                        check: concat!("simplex!(values", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                        fields: &[("index", &index), ("value", &values_slice[index])],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                    }
+                    .panic()
+                }
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, $eq2 <= $tol_2, $eq3 <= $tol_3, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                if let Some(index) = $crate::first_negative(values_slice) {
+                    $crate::AssertFailure {
+                        check: concat!("simplex!(values", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                        fields: &[("index", &index), ("value", &values_slice[index])],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                    }
+                    .panic()
+                }
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, $eq2 <= $tol_2, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+    ($values:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match &$values {
+            values_val => {
+                let values_slice = &values_val[..];
+                if let Some(index) = $crate::first_negative(values_slice) {
+                    $crate::AssertFailure {
+                        check: concat!("simplex!(values", ", ", stringify!($eq1), " <= t", ")"),
+                        fields: &[("index", &index), ("value", &values_slice[index])],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                    }
+                    .panic()
+                }
+                $crate::assert_float_eq!($crate::probability_sum(values_slice), 1.0, $eq1 <= $tol_1, "values do not sum to one: {}", format_args!($($arg)+));
+            }
+        }
+    });
+}
+
+/// Asserts that a matrix of floats is row-stochastic, such as the transition
+/// matrix of a Markov chain, so that every row lies on the probability simplex.
+///
+/// Each row is checked in turn as by [`assert_simplex!`]. The matrix may be an
+/// array, slice or vector of rows, each of which is an array, slice or vector
+/// of `f32` or `f64`, and need not be square.
+///
+/// On panic, this macro will print the row, index and value of the first
+/// element that is negative or NaN, otherwise the same information as
+/// [`assert_float_eq!`] for the sum of the first row that does not sum to one.
+/// Like [`assert!`], this macro has a second form, where a custom panic message
+/// can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_row_stochastic;
+/// let transitions = [[0.9f64, 0.075, 0.025], [0.15, 0.8, 0.05], [0.25, 0.25, 0.5]];
+///
+/// assert_row_stochastic!(transitions, abs <= 1e-12);
+/// assert_row_stochastic!(transitions, ulps <= 1, "market states");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`assert_simplex!`]: macro.assert_simplex.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_row_stochastic {
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative")),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative")),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative")),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, "row {} does not sum to one", row);
+                }
+            }
+        }
+    });
+    ($m:expr, $($eq:ident <= $tol:expr),+,) => ({
+        $crate::assert_row_stochastic!($m, $($eq <= $tol),+)
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2, &$tol_3) {
+            (m_val, tol_1_val, tol_2_val, tol_3_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ", ", stringify!($eq3), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, $eq3 <= *tol_3_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1, &$tol_2) {
+            (m_val, tol_1_val, tol_2_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ", ", stringify!($eq2), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+    ($m:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
+        match (&$m, &$tol_1) {
+            (m_val, tol_1_val) => {
+                for (row, row_val) in m_val.iter().enumerate() {
+                    let row_slice = &row_val[..];
+                    if let Some(index) = $crate::first_negative(row_slice) {
+                        $crate::AssertFailure {
+                            check: concat!("row_stochastic!(m", ", ", stringify!($eq1), " <= t", ")"),
+                            fields: &[("row", &row), ("index", &index), ("value", &row_slice[index])],
+                            note: None,
+                            ulps_diff_reason: None,
+                            message: Some(format_args!("probability is negative: {}", format_args!($($arg)+))),
+                        }
+                        .panic()
+                    }
+                    $crate::assert_float_eq!($crate::probability_sum(row_slice), 1.0, $eq1 <= *tol_1_val, "row {} does not sum to one: {}", row, format_args!($($arg)+));
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a symmetric matrix is positive definite, allowing for tiny
+/// negative eigenvalues caused by round-off.
+///
+/// The [`symmetric_eigenvalues`] of the matrix must all be at least the
+/// `eig_floor`, which is typically a small negative number for matrices that
+/// are positive definite in exact arithmetic, such as covariance matrices,
+/// since an exact check would fail whenever round-off pushes the smallest
+/// eigenvalue just below zero. A floor of zero accepts positive semi-definite
+/// matrices, and a positive floor requires the matrix to be well away from
+/// singular. The matrix may be any [`SquareMatrix`] of `f32` or `f64`, and
+/// only its symmetric part is considered.
+///
+/// On panic, this macro will print the smallest eigenvalue, all of the
+/// eigenvalues and the floor. Like [`assert!`], this macro has a second form,
+/// where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_positive_definite;
+/// let covariance = [[4.0f64, 2.0, 0.6], [2.0, 2.0, 0.4], [0.6, 0.4, 1.0]];
+///
+/// assert_positive_definite!(covariance, eig_floor <= -1e-10);
+/// assert_positive_definite!(covariance, eig_floor <= -1e-10, "samples: {}", 100);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`SquareMatrix`]: trait.SquareMatrix.html
+/// [`symmetric_eigenvalues`]: fn.symmetric_eigenvalues.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_positive_definite {
+    ($m:expr, eig_floor <= $floor:expr) => ({
+        match (&$m, &$floor) {
+            (m_val, floor_val) => {
+                let eigenvalues = $crate::symmetric_eigenvalues(m_val);
+                if !$crate::eigenvalues_at_least(&eigenvalues, *floor_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "positive_definite!(m, eig_floor <= t)",
+                        fields: &[
+                            ("smallest", &eigenvalues[0]),
+                            ("eigenvalues", &eigenvalues),
+                            ("[eig_floor] t", &&*floor_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($m:expr, eig_floor <= $floor:expr,) => ({
+        $crate::assert_positive_definite!($m, eig_floor <= $floor)
+    });
+    ($m:expr, eig_floor <= $floor:expr, $($arg:tt)+) => ({
+        match (&$m, &$floor) {
+            (m_val, floor_val) => {
+                let eigenvalues = $crate::symmetric_eigenvalues(m_val);
+                if !$crate::eigenvalues_at_least(&eigenvalues, *floor_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "positive_definite!(m, eig_floor <= t)",
+                        fields: &[
+                            ("smallest", &eigenvalues[0]),
+                            ("eigenvalues", &eigenvalues),
+                            ("[eig_floor] t", &&*floor_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two sets of complex eigenvalues are equal, in any order and
+/// with conjugate pairs matched up.
+///
+/// The operands are slices, arrays or vectors of [`Complex`] values, such as
+/// the output of an eigen-solver and the eigenvalues it is expected to find.
+/// They are compared by [`match_complex_eigenvalues`], which requires every
+/// actual eigenvalue with a nonzero imaginary part to be paired with its
+/// conjugate, and every expected eigenvalue to be matched with a distinct
+/// actual one, where both the real and imaginary parts are within an absolute
+/// tolerance of `t`. Requires the `num` feature.
+///
+/// On panic, this macro will print both sets of eigenvalues, the
+/// [`EigenvalueMismatch`] that was found and the tolerance. Like [`assert!`],
+/// this macro has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_complex_eigenvalues_eq;
+/// use num_complex::Complex;
+///
+/// // Eigenvalues of [[0, -2, 0], [2, 0, 0], [0, 0, 3]], as returned by a solver.
+/// let actual = vec![Complex::new(3.0f64, 0.0), Complex::new(0.0, -2.0), Complex::new(0.0, 2.0)];
+/// let expected = [Complex::new(0.0, 2.0), Complex::new(0.0, -2.0), Complex::new(3.0, 0.0)];
+///
+/// assert_complex_eigenvalues_eq!(actual, expected, abs <= 1e-12);
+/// assert_complex_eigenvalues_eq!(actual, expected, abs <= 1e-12, "solver: {}", "qr");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`Complex`]: https://docs.rs/num-complex/0.4/num_complex/struct.Complex.html
+/// [`EigenvalueMismatch`]: enum.EigenvalueMismatch.html
+/// [`match_complex_eigenvalues`]: fn.match_complex_eigenvalues.html
+#[cfg(all(feature = "std", feature = "num", not(feature = "checks-only")))]
+#[macro_export]
+macro_rules! assert_complex_eigenvalues_eq {
+    ($a:expr, $b:expr, abs <= $tol:expr) => ({
+        match (&$a, &$b, &$tol) {
+            (a_val, b_val, tol_val) => {
+                if let Err(mismatch) = $crate::match_complex_eigenvalues(&a_val[..], &b_val[..], *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "complex_eigenvalues_eq!(left, right, abs <= t)",
+                        fields: &[
+                            ("left", &&*a_val),
+                            ("right", &&*b_val),
+                            ("mismatch", &mismatch),
+                            ("[abs] t", &&*tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($a:expr, $b:expr, abs <= $tol:expr,) => ({
+        $crate::assert_complex_eigenvalues_eq!($a, $b, abs <= $tol)
+    });
+    ($a:expr, $b:expr, abs <= $tol:expr, $($arg:tt)+) => ({
+        match (&$a, &$b, &$tol) {
+            (a_val, b_val, tol_val) => {
+                if let Err(mismatch) = $crate::match_complex_eigenvalues(&a_val[..], &b_val[..], *tol_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "complex_eigenvalues_eq!(left, right, abs <= t)",
+                        fields: &[
+                            ("left", &&*a_val),
+                            ("right", &&*b_val),
+                            ("mismatch", &mismatch),
+                            ("[abs] t", &&*tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two geodetic positions are equal, comparing their
+/// great-circle distance in meters and their altitudes separately.
+///
+/// The operands are [`GeoPoint`]s. They are equal if the
+/// [`great_circle_distance`] between them is at most the `meters` tolerance,
+/// and their altitudes differ by at most the `alt` tolerance, which is also
+/// in meters. This is correct near the poles and across the antimeridian,
+/// unlike comparing latitude and longitude with a tolerance in degrees.
+///
+/// On panic, this macro will print the values of the positions, the distance
+/// between them, the difference in their altitudes and both tolerances. Like
+/// [`assert!`], this macro has a second form, where a custom panic message can
+/// be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_geo_eq, GeoPoint};
+/// let expected = GeoPoint::new(51.4779, -0.0015).with_alt(46.0);
+/// let fix = GeoPoint::new(51.47791, -0.00148).with_alt(47.5);
+///
+/// assert_geo_eq!(fix, expected, meters <= 5.0, alt <= 2.0);
+/// assert_geo_eq!(fix, expected, meters <= 5.0, alt <= 2.0, "fix {}", 3);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`GeoPoint`]: struct.GeoPoint.html
+/// [`great_circle_distance`]: fn.great_circle_distance.html
+#[cfg(all(feature = "geo", not(feature = "checks-only")))]
+#[macro_export]
+macro_rules! assert_geo_eq {
+    ($left:expr, $right:expr, meters <= $meters:expr, alt <= $alt:expr) => ({
+        match (&$left, &$right, &$meters, &$alt) {
+            (left_val, right_val, meters_val, alt_val) => {
+                if !$crate::GeoPoint::eq_geo(left_val, right_val, *meters_val, *alt_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "geo_eq!(left, right, meters <= t, alt <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("distance", &$crate::great_circle_distance(left_val, right_val)),
+                            ("alt_diff", &(left_val.alt - right_val.alt).abs()),
+                            ("[meters] t", &&*meters_val),
+                            ("[alt] t", &&*alt_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, meters <= $meters:expr, alt <= $alt:expr,) => ({
+        $crate::assert_geo_eq!($left, $right, meters <= $meters, alt <= $alt)
+    });
+    ($left:expr, $right:expr, meters <= $meters:expr, alt <= $alt:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$meters, &$alt) {
+            (left_val, right_val, meters_val, alt_val) => {
+                if !$crate::GeoPoint::eq_geo(left_val, right_val, *meters_val, *alt_val) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: "geo_eq!(left, right, meters <= t, alt <= t)",
+                        fields: &[
+                            ("left", &&*left_val),
+                            ("right", &&*right_val),
+                            ("distance", &$crate::great_circle_distance(left_val, right_val)),
+                            ("alt_diff", &(left_val.alt - right_val.alt).abs()),
+                            ("[meters] t", &&*meters_val),
+                            ("[alt] t", &&*alt_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two histograms are equal, comparing their bin edges
+/// approximately and their counts with some slack.
+///
+/// The operands are [`Histogram`]s. Their edges are compared as slices using
+/// a single check accepted by [`assert_float_eq!`], usually one of the `_all`
+/// checks. Their counts are then compared bin by bin, with either an `abs`
+/// tolerance, which is a number of samples, or a `rel` tolerance, which is a
+/// fraction of the larger count, as described by [`CountTol`]. This is useful
+/// when validating rebinned or resampled statistical outputs, whose counts
+/// may legitimately shift by a few samples between runs.
+///
+/// On panic, if the edges differ this macro will print the same information
+/// as [`assert_float_eq!`]. Otherwise it prints the first bin whose counts
+/// differ, along with the counts themselves. Like [`assert!`], this macro has
+/// a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_histogram_eq, Histogram};
+/// let expected = Histogram::new(&[0.0f64, 0.1, 0.2, 0.3], &[1000, 2000, 1000]);
+/// let rebinned = Histogram::new(&[0.0f64, 0.1, 0.200_000_000_000_000_04, 0.3], &[995, 2010, 995]);
+///
+/// assert_histogram_eq!(rebinned, expected, edges: abs_all <= 1e-12, counts: abs <= 10);
+/// assert_histogram_eq!(rebinned, expected, edges: ulps_all <= 4, counts: rel <= 0.01, "seed {}", 42);
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`CountTol`]: enum.CountTol.html
+/// [`Histogram`]: struct.Histogram.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_histogram_eq {
+    ($left:expr, $right:expr, edges: $eq:ident <= $tol:expr, counts: $count_eq:ident <= $count_tol:expr) => ({
+        match (&$left, &$right, &$count_tol) {
+            (left_val, right_val, count_tol_val) => {
+                $crate::assert_float_eq!(left_val.edges(), right_val.edges(), $eq <= $tol, "histogram bin edges differ");
+                let count_tol = $crate::CountTol::$count_eq(*count_tol_val);
+                if let Some(bin) = $crate::first_count_mismatch(left_val.counts(), right_val.counts(), count_tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: concat!("histogram_eq!(left, right, edges: ", stringify!($eq), " <= t, counts: ", stringify!($count_eq), " <= t)"),
+                        fields: &[
+                            ("bin", &bin),
+                            ("left", &left_val.counts()[bin]),
+                            ("right", &right_val.counts()[bin]),
+                            (concat!("[", stringify!($count_eq), "] t"), &&*count_tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, edges: $eq:ident <= $tol:expr, counts: $count_eq:ident <= $count_tol:expr,) => ({
+        $crate::assert_histogram_eq!($left, $right, edges: $eq <= $tol, counts: $count_eq <= $count_tol)
+    });
+    ($left:expr, $right:expr, edges: $eq:ident <= $tol:expr, counts: $count_eq:ident <= $count_tol:expr, $($arg:tt)+) => ({
+        match (&$left, &$right, &$count_tol) {
+            (left_val, right_val, count_tol_val) => {
+                $crate::assert_float_eq!(left_val.edges(), right_val.edges(), $eq <= $tol, "histogram bin edges differ: {}", format_args!($($arg)+));
+                let count_tol = $crate::CountTol::$count_eq(*count_tol_val);
+                if let Some(bin) = $crate::first_count_mismatch(left_val.counts(), right_val.counts(), count_tol) {
+                    // The reborrows below are intentional. See assert_eq! in the standard library.
+                    $crate::AssertFailure {
+                        check: concat!("histogram_eq!(left, right, edges: ", stringify!($eq), " <= t, counts: ", stringify!($count_eq), " <= t)"),
+                        fields: &[
+                            ("bin", &bin),
+                            ("left", &left_val.counts()[bin]),
+                            ("right", &right_val.counts()[bin]),
+                            (concat!("[", stringify!($count_eq), "] t"), &&*count_tol_val),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that the comparison traits of a type are consistent with
+/// themselves, over every pair of a set of example values.
+///
+/// The arguments are the same as those of [`conformance::check_impl`]: the
+/// values, a tolerance and a `ulps` tolerance for the checks of [`FloatEq`],
+/// and the same for those of [`FloatEqAll`]. This is intended to be called
+/// from the tests of a type that implements the traits by hand.
+///
+/// On panic, this macro will print the [`Rule`] that was broken, the check
+/// that broke it and the pair of values it was comparing. Like [`assert!`],
+/// this macro has a second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_impl_conforms;
+/// let values = [0.0f32, 1.0, 1.000_000_1, -2.0, f32::INFINITY];
+///
+/// assert_impl_conforms!(values, 0.1, 2, 0.1, 2);
+/// assert_impl_conforms!(&values[1..], 0.0, 0, 0.0, 0, "with zero tolerances");
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`conformance::check_impl`]: conformance/fn.check_impl.html
+/// [`FloatEq`]: trait.FloatEq.html
+/// [`FloatEqAll`]: trait.FloatEqAll.html
+/// [`Rule`]: conformance/enum.Rule.html
+#[cfg(not(feature = "checks-only"))]
+#[macro_export]
+macro_rules! assert_impl_conforms {
+    ($values:expr, $tol:expr, $ulps_tol:expr, $all_tol:expr, $ulps_all_tol:expr) => ({
+        match (&$values, &$tol, &$ulps_tol, &$all_tol, &$ulps_all_tol) {
+            (values_val, tol_val, ulps_tol_val, all_tol_val, ulps_all_tol_val) => {
+                let values_slice = &values_val[..];
+                if let Err(violation) = $crate::conformance::check_impl(
+                    values_slice, tol_val, ulps_tol_val, all_tol_val, ulps_all_tol_val,
+                ) {
+                    $crate::AssertFailure {
+                        check: "conformance::check_impl(values, t, ulps_t, all_t, ulps_all_t)",
+                        fields: &[
+                            ("rule", &violation.rule),
+                            ("check", &violation.check),
+                            ("left", &values_slice[violation.left]),
+                            ("right", &values_slice[violation.right]),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: None,
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+    ($values:expr, $tol:expr, $ulps_tol:expr, $all_tol:expr, $ulps_all_tol:expr,) => ({
+        $crate::assert_impl_conforms!($values, $tol, $ulps_tol, $all_tol, $ulps_all_tol)
+    });
+    ($values:expr, $tol:expr, $ulps_tol:expr, $all_tol:expr, $ulps_all_tol:expr, $($arg:tt)+) => ({
+        match (&$values, &$tol, &$ulps_tol, &$all_tol, &$ulps_all_tol) {
+            (values_val, tol_val, ulps_tol_val, all_tol_val, ulps_all_tol_val) => {
+                let values_slice = &values_val[..];
+                if let Err(violation) = $crate::conformance::check_impl(
+                    values_slice, tol_val, ulps_tol_val, all_tol_val, ulps_all_tol_val,
+                ) {
+                    $crate::AssertFailure {
+                        check: "conformance::check_impl(values, t, ulps_t, all_t, ulps_all_t)",
+                        fields: &[
+                            ("rule", &violation.rule),
+                            ("check", &violation.check),
+                            ("left", &values_slice[violation.left]),
+                            ("right", &values_slice[violation.right]),
+                        ],
+                        note: None,
+                        ulps_diff_reason: None,
+                        message: Some(format_args!($($arg)+)),
+                    }
+                    .panic()
+                }
+            }
+        }
+    });
+}
+
+#[doc(hidden)]
+pub struct FloatEqCmp;
+
+#[doc(hidden)]
+#[allow(clippy::multiple_bound_locations)]
+impl FloatEqCmp {
+    #[inline]
+    pub fn abs<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    where
+        A: FloatEq<B>,
+    {
+        a.eq_abs(b, tol)
+    }
+
+    #[inline]
+    pub fn abs_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    where
+        A: FloatEqAll<B>,
+    {
+        a.eq_abs_all(b, tol)
+    }
+
+    #[inline]
+    pub fn rel<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    where
+        A: FloatEq<B>,
+    {
+        a.eq_rel(b, tol)
+    }
+
+    #[inline]
+    pub fn rel_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    where
+        A: FloatEqAll<B>,
+    {
+        a.eq_rel_all(b, tol)
+    }
+
+    #[inline]
+    pub fn percent<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    where
+        A: FloatEq<B>,
+        A::Tol: TolOps,
+    {
+        a.eq_percent(b, tol)
+    }
+
+    #[inline]
+    pub fn percent_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    where
+        A: FloatEqAll<B>,
+        A::AllTol: TolOps,
+    {
+        a.eq_percent_all(b, tol)
+    }
+
+    #[inline]
+    pub fn rmax<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    where
+        A: FloatEq<B>,
+    {
+        a.eq_rmax(b, tol)
+    }
+
+    #[inline]
     pub fn rmax_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
         A: FloatEqAll<B>,
@@ -678,12 +4513,88 @@ impl FloatEqCmp {
     {
         a.eq_ulps_all(b, tol)
     }
+
+    #[inline]
+    pub fn abs_ulps<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &(A::Tol, UlpsTol<A::Tol>)) -> bool
+    where
+        A: FloatEq<B>,
+        A::Tol: Sized,
+        UlpsTol<A::Tol>: Sized,
+    {
+        a.eq_abs_ulps(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    pub fn abs_ulps_all<A: ?Sized, B: ?Sized>(
+        a: &A,
+        b: &B,
+        tol: &(A::AllTol, UlpsTol<A::AllTol>),
+    ) -> bool
+    where
+        A: FloatEqAll<B>,
+        A::AllTol: Sized,
+        UlpsTol<A::AllTol>: Sized,
+    {
+        a.eq_abs_ulps_all(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn max_abs<A, B, T>(a: &A, b: &B, tol: &T) -> bool
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        max_abs_diff(a.as_ref(), b.as_ref()).map_or(false, |diff| diff <= *tol)
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn max_ulps<A, B, T>(a: &A, b: &B, tol: &T::Bits) -> bool
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        max_ulps_diff(a.as_ref(), b.as_ref()).map_or(false, |diff| diff <= *tol)
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn mean_abs<A, B, T>(a: &A, b: &B, tol: &T) -> bool
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        mean_abs_diff(a.as_ref(), b.as_ref()).map_or(false, |diff| diff <= *tol)
+    }
+
+    #[inline]
+    pub fn bits<A: ?Sized, B: ?Sized>(a: &A, b: &B, _tol: &()) -> bool
+    where
+        A: FloatEqBits<B>,
+    {
+        a.eq_bits(b)
+    }
+
+    #[inline]
+    pub fn bits_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, _tol: &()) -> bool
+    where
+        A: FloatEqBits<B>,
+    {
+        a.eq_bits(b)
+    }
 }
 
+#[cfg(not(feature = "checks-only"))]
 #[doc(hidden)]
 pub struct FloatCmpOpTol;
 
+#[cfg(not(feature = "checks-only"))]
 #[doc(hidden)]
+#[allow(clippy::multiple_bound_locations)]
 impl FloatCmpOpTol {
     #[inline]
     pub fn abs<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
@@ -717,6 +4628,24 @@ impl FloatCmpOpTol {
         a.debug_rel_all_tol(b, tol)
     }
 
+    #[inline]
+    pub fn percent<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    where
+        A: FloatEq<B> + AssertFloatEq<B>,
+        A::Tol: TolOps,
+    {
+        a.debug_percent_tol(b, tol)
+    }
+
+    #[inline]
+    pub fn percent_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    where
+        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A::AllTol: TolOps,
+    {
+        a.debug_percent_all_tol(b, tol)
+    }
+
     #[inline]
     pub fn rmax<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
@@ -802,4 +4731,411 @@ impl FloatCmpOpTol {
     {
         a.debug_ulps_all_tol(b, tol)
     }
+
+    #[inline]
+    pub fn abs_ulps<A: ?Sized, B: ?Sized>(
+        a: &A,
+        b: &B,
+        tol: &(A::Tol, UlpsTol<A::Tol>),
+    ) -> (A::DebugTol, UlpsTol<A::DebugTol>)
+    where
+        A: FloatEq<B> + AssertFloatEq<B>,
+        A::Tol: Sized,
+        UlpsTol<A::Tol>: Sized,
+        UlpsTol<A::DebugTol>: Sized,
+    {
+        a.debug_abs_ulps_tol(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    pub fn abs_ulps_all<A: ?Sized, B: ?Sized>(
+        a: &A,
+        b: &B,
+        tol: &(A::AllTol, UlpsTol<A::AllTol>),
+    ) -> (A::AllDebugTol, UlpsTol<A::AllDebugTol>)
+    where
+        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A::AllTol: Sized,
+        UlpsTol<A::AllTol>: Sized,
+        UlpsTol<A::AllDebugTol>: Sized,
+    {
+        a.debug_abs_ulps_all_tol(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    pub fn max_abs<A, B, T>(_a: &A, _b: &B, tol: &T) -> T
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        *tol
+    }
+
+    #[inline]
+    pub fn max_ulps<A, B, T>(_a: &A, _b: &B, tol: &T::Bits) -> T::Bits
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        *tol
+    }
+
+    #[inline]
+    pub fn mean_abs<A, B, T>(_a: &A, _b: &B, tol: &T) -> T
+    where
+        A: ?Sized + AsRef<[T]>,
+        B: ?Sized + AsRef<[T]>,
+        T: FloatPrimitive,
+    {
+        *tol
+    }
+
+    #[inline]
+    pub fn bits<A: ?Sized, B: ?Sized>(_a: &A, _b: &B, _tol: &())
+    where
+        A: FloatEqBits<B>,
+    {
+    }
+
+    #[inline]
+    pub fn bits_all<A: ?Sized, B: ?Sized>(_a: &A, _b: &B, _tol: &())
+    where
+        A: FloatEqBits<B>,
+    {
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+#[doc(hidden)]
+pub struct FloatAnnealTol;
+
+#[cfg(not(feature = "checks-only"))]
+#[doc(hidden)]
+impl FloatAnnealTol {
+    #[inline]
+    pub fn abs<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn abs_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rel<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rel_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn percent<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn percent_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rmax<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rmax_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rmin<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn rmin_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn r1st<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn r1st_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn r2nd<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn r2nd_all<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn ulps<T: UlpsTolOps>(tol: &T) -> T {
+        tol.saturating_add(tol)
+    }
+
+    #[inline]
+    pub fn ulps_all<T: UlpsTolOps>(tol: &T) -> T {
+        tol.saturating_add(tol)
+    }
+
+    #[inline]
+    pub fn abs_ulps<T: TolOps, U: UlpsTolOps>(tol: &(T, U)) -> (T, U) {
+        (tol.0.scale(2.0), tol.1.saturating_add(&tol.1))
+    }
+
+    #[inline]
+    pub fn abs_ulps_all<T: TolOps, U: UlpsTolOps>(tol: &(T, U)) -> (T, U) {
+        (tol.0.scale(2.0), tol.1.saturating_add(&tol.1))
+    }
+
+    #[inline]
+    pub fn max_abs<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn max_ulps<T: UlpsTolOps>(tol: &T) -> T {
+        tol.saturating_add(tol)
+    }
+
+    #[inline]
+    pub fn mean_abs<T: TolOps>(tol: &T) -> T {
+        tol.scale(2.0)
+    }
+
+    #[inline]
+    pub fn bits(_tol: &()) {}
+
+    #[inline]
+    pub fn bits_all(_tol: &()) {}
+}
+
+// Tolerances given as literals are checked at compile time, to catch typos
+// such as `rmax <= 1e6` for `rmax <= 1e-6` before any test runs. Invalid
+// tolerances fail to compile, by panicking in `tol_lint`, whilst suspicious
+// ones warn, by calling a deprecated `TolLint::check`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __float_eq_check_tol {
+    ($op:ident, $check:ident, $tol:literal) => {
+        $crate::TolLint::<
+            { $crate::tol_lint(stringify!($op), stringify!($check), stringify!($tol), true) },
+        >::check();
+    };
+    ($op:ident, $check:ident, $tol:expr) => {
+        $crate::TolLint::<
+            { $crate::tol_lint(stringify!($op), stringify!($check), stringify!($tol), false) },
+        >::check();
+    };
+}
+
+#[doc(hidden)]
+pub struct TolLint<const LINT: u8>;
+
+const TOL_OK: u8 = 0;
+const TOL_RELATIVE_TOO_LARGE: u8 = 1;
+const TOL_NE_ZERO_ULPS: u8 = 2;
+
+// The impls use literals rather than the constants, which would otherwise be
+// displayed in the warnings as an unreadable path.
+#[doc(hidden)]
+impl TolLint<0> {
+    #[inline]
+    pub fn check() {}
+}
+
+#[doc(hidden)]
+impl TolLint<1> {
+    #[deprecated(
+        note = "a relative tolerance of 10 or more accepts almost any values, did you mean a negative exponent such as `1e-6`?"
+    )]
+    #[inline]
+    pub fn check() {}
+}
+
+#[doc(hidden)]
+impl TolLint<2> {
+    #[deprecated(
+        note = "`ulps <= 0` in a not equal check passes for any values that are not identical, did you mean a larger tolerance?"
+    )]
+    #[inline]
+    pub fn check() {}
+}
+
+#[doc(hidden)]
+pub const fn tol_lint(op: &str, check: &str, tol: &str, is_literal: bool) -> u8 {
+    let tol = tol.as_bytes();
+    if ends_with(tol, b"NAN") && (tol.len() == 3 || tol[tol.len() - 4] == b':') {
+        panic!("float_eq: a NaN tolerance is never met, so only identical values may be equal");
+    }
+    if !is_literal {
+        return TOL_OK;
+    }
+    let lit = match LitValue::parse(tol) {
+        Some(lit) => lit,
+        None => return TOL_OK,
+    };
+    if lit.is_negative && !lit.is_zero {
+        panic!(
+            "float_eq: a negative tolerance is never met, so only identical values may be equal"
+        );
+    }
+
+    let check = check.as_bytes();
+    let check = if ends_with(check, b"_all") {
+        check.split_at(check.len() - 4).0
+    } else {
+        check
+    };
+    let is_relative = bytes_eq(check, b"rel")
+        || bytes_eq(check, b"rmax")
+        || bytes_eq(check, b"rmin")
+        || bytes_eq(check, b"r1st")
+        || bytes_eq(check, b"r2nd");
+    if is_relative && !lit.is_zero && lit.exponent >= 1 {
+        TOL_RELATIVE_TOO_LARGE
+    } else if bytes_eq(op.as_bytes(), b"ne")
+        && (bytes_eq(check, b"ulps") || bytes_eq(check, b"max_ulps"))
+        && lit.is_zero
+    {
+        TOL_NE_ZERO_ULPS
+    } else {
+        TOL_OK
+    }
+}
+
+// The parts of a numeric literal that the lints need.
+struct LitValue {
+    is_negative: bool,
+    is_zero: bool,
+    // The decimal exponent of the leading digit, so the value is at least 10
+    // if this is positive.
+    exponent: i32,
+}
+
+impl LitValue {
+    const fn parse(lit: &[u8]) -> Option<LitValue> {
+        let mut i = skip_spaces(lit, 0);
+        let is_negative = i < lit.len() && lit[i] == b'-';
+        if is_negative {
+            i = skip_spaces(lit, i + 1);
+        }
+        if i >= lit.len() || !lit[i].is_ascii_digit() {
+            return None;
+        }
+
+        // Integers in other bases need only be checked for zero.
+        if lit[i] == b'0' && i + 1 < lit.len() && matches!(lit[i + 1], b'x' | b'o' | b'b') {
+            i += 2;
+            let mut is_zero = true;
+            while i < lit.len() && (lit[i].is_ascii_hexdigit() || lit[i] == b'_') {
+                if lit[i] != b'0' && lit[i] != b'_' {
+                    is_zero = false;
+                }
+                i += 1;
+            }
+            return Some(LitValue {
+                is_negative,
+                is_zero,
+                exponent: 0,
+            });
+        }
+
+        // The position of the leading digit, counting the integer digits
+        // before it in the first case and the fractional ones in the second.
+        let mut int_digits = 0;
+        let mut lead_int = None;
+        let mut lead_frac = None;
+        while i < lit.len() && (lit[i].is_ascii_digit() || lit[i] == b'_') {
+            if lit[i] != b'_' {
+                if lit[i] != b'0' && lead_int.is_none() {
+                    lead_int = Some(int_digits);
+                }
+                int_digits += 1;
+            }
+            i += 1;
+        }
+        if i < lit.len() && lit[i] == b'.' {
+            i += 1;
+            let mut frac_digits = 0;
+            while i < lit.len() && (lit[i].is_ascii_digit() || lit[i] == b'_') {
+                if lit[i] != b'_' {
+                    frac_digits += 1;
+                    if lit[i] != b'0' && lead_int.is_none() && lead_frac.is_none() {
+                        lead_frac = Some(frac_digits);
+                    }
+                }
+                i += 1;
+            }
+        }
+        let mut exponent: i32 = 0;
+        if i < lit.len() && (lit[i] == b'e' || lit[i] == b'E') {
+            i += 1;
+            let is_exponent_negative = i < lit.len() && lit[i] == b'-';
+            if i < lit.len() && (lit[i] == b'-' || lit[i] == b'+') {
+                i += 1;
+            }
+            while i < lit.len() && (lit[i].is_ascii_digit() || lit[i] == b'_') {
+                if lit[i] != b'_' && exponent < 10_000 {
+                    exponent = exponent * 10 + (lit[i] - b'0') as i32;
+                }
+                i += 1;
+            }
+            if is_exponent_negative {
+                exponent = -exponent;
+            }
+        }
+
+        let (is_zero, lead) = match (lead_int, lead_frac) {
+            (Some(lead), _) => (false, int_digits - 1 - lead),
+            (None, Some(lead)) => (false, -lead),
+            (None, None) => (true, 0),
+        };
+        Some(LitValue {
+            is_negative,
+            is_zero,
+            exponent: lead + exponent,
+        })
+    }
+}
+
+const fn skip_spaces(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    i
+}
+
+const fn ends_with(bytes: &[u8], suffix: &[u8]) -> bool {
+    bytes.len() >= suffix.len() && bytes_eq(bytes.split_at(bytes.len() - suffix.len()).1, suffix)
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
@@ -0,0 +1,103 @@
+use core::ops::{Add, Mul};
+
+/// A square matrix whose elements may be checked by the matrix property
+/// asserts, such as [`assert_symmetric!`] and [`assert_orthogonal!`].
+///
+/// Implemented for row-major nested arrays, `[[T; N]; N]`. Other matrix types
+/// may be checked by wrapping them in a type that implements this trait.
+///
+/// [`assert_symmetric!`]: macro.assert_symmetric.html
+/// [`assert_orthogonal!`]: macro.assert_orthogonal.html
+pub trait SquareMatrix {
+    /// The type of the elements of the matrix.
+    type Elem;
+
+    /// The number of rows, which is also the number of columns.
+    fn dim(&self) -> usize;
+
+    /// The element at the given row and column.
+    fn elem(&self, row: usize, col: usize) -> &Self::Elem;
+}
+
+impl<T, const N: usize> SquareMatrix for [[T; N]; N] {
+    type Elem = T;
+
+    #[inline]
+    fn dim(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn elem(&self, row: usize, col: usize) -> &T {
+        &self[row][col]
+    }
+}
+
+/// Scalar types that may be multiplied together in the matrix property
+/// asserts, implemented for `f32` and `f64` and, with the `num` feature,
+/// `Complex<f32>` and `Complex<f64>`.
+pub trait MatrixScalar: Copy + Add<Output = Self> + Mul<Output = Self> {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// The complex conjugate, which is the value itself for real numbers.
+    fn conj(self) -> Self;
+}
+
+impl MatrixScalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+impl MatrixScalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+/// The element at `(row, col)` of the product of a matrix with its conjugate
+/// transpose, `M·Mᴴ`, which is `M·Mᵀ` for real matrices.
+///
+/// # Examples
+/// ```
+/// # use float_eq::gram_entry;
+/// let m = [[1.0f64, 2.0], [3.0, 4.0]];
+/// assert_eq!(gram_entry(&m, 0, 0), 5.0);
+/// assert_eq!(gram_entry(&m, 0, 1), 11.0);
+/// ```
+pub fn gram_entry<M>(m: &M, row: usize, col: usize) -> M::Elem
+where
+    M: ?Sized + SquareMatrix,
+    M::Elem: MatrixScalar,
+{
+    (0..m.dim()).fold(M::Elem::ZERO, |sum, k| {
+        sum + *m.elem(row, k) * m.elem(col, k).conj()
+    })
+}
+
+/// The element at `(row, col)` of the identity matrix with the same type and
+/// dimensions as `m`.
+#[inline]
+pub fn identity_entry<M>(_m: &M, row: usize, col: usize) -> M::Elem
+where
+    M: ?Sized + SquareMatrix,
+    M::Elem: MatrixScalar,
+{
+    if row == col {
+        M::Elem::ONE
+    } else {
+        M::Elem::ZERO
+    }
+}
@@ -0,0 +1,106 @@
+use crate::FloatPrimitive;
+
+/// A measured value along with the standard deviation of its uncertainty.
+///
+/// Two measurements are equal at `k` sigma if their intervals `value ± k *
+/// sigma` overlap, that is if the difference between their values is no more
+/// than `k` times the sum of their sigmas, as checked by [`overlaps`] and
+/// [`assert_measured_eq!`]. This suits comparing experimental results, or
+/// Monte Carlo estimates, whose precision is only known statistically. A
+/// value that is known exactly, such as an analytic reference, has a sigma of
+/// zero.
+///
+/// # Examples
+/// ```
+/// # use float_eq::Measured;
+/// let a = Measured::new(9.81f64, 0.02);
+/// let b = Measured::new(9.86f64, 0.01);
+/// assert!(a.overlaps(&b, 2.0));
+/// assert!(!a.overlaps(&b, 1.0));
+/// assert!(a.overlaps(&Measured::exact(9.80665), 1.0));
+/// ```
+///
+/// [`overlaps`]: #method.overlaps
+/// [`assert_measured_eq!`]: macro.assert_measured_eq.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measured<T> {
+    value: T,
+    sigma: T,
+}
+
+impl<T> Measured<T> {
+    /// A measurement of `value` with a standard deviation of `sigma`.
+    #[inline]
+    pub fn new(value: T, sigma: T) -> Self {
+        Measured { value, sigma }
+    }
+
+    /// The measured value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The standard deviation of the measurement.
+    #[inline]
+    pub fn sigma(&self) -> &T {
+        &self.sigma
+    }
+}
+
+impl<T: FloatPrimitive> Measured<T> {
+    /// A value that is known exactly, with a sigma of zero.
+    #[inline]
+    pub fn exact(value: T) -> Self {
+        Measured::new(value, T::ZERO)
+    }
+
+    /// The interval `value ± k * sigma`, as its lower and upper bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::Measured;
+    /// assert_eq!(Measured::new(1.0f32, 0.25).interval(2.0), (0.5, 1.5));
+    /// ```
+    #[inline]
+    pub fn interval(&self, k: T) -> (T, T) {
+        let half_width = k.abs_value() * self.sigma.abs_value();
+        (self.value - half_width, self.value + half_width)
+    }
+
+    /// The number of sigmas separating two measurements, the difference
+    /// between their values divided by the sum of their sigmas.
+    ///
+    /// This is the smallest `k` at which they [`overlap`], which is zero if
+    /// their values are equal, infinite if they differ but both are exact, and
+    /// NaN if either value or sigma is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::Measured;
+    /// let a = Measured::new(1.0f64, 0.1);
+    /// let b = Measured::new(1.6f64, 0.2);
+    /// assert_eq!(a.sigma_diff(&b), 2.0);
+    /// ```
+    ///
+    /// [`overlap`]: #method.overlaps
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    pub fn sigma_diff(&self, other: &Self) -> T {
+        let sigmas = self.sigma.abs_value() + other.sigma.abs_value();
+        if self.value == other.value && !sigmas.is_nan_value() {
+            T::ZERO
+        } else {
+            (self.value - other.value).abs_value() / sigmas
+        }
+    }
+
+    /// Whether two measurements are equal at `k` sigma, since their intervals
+    /// `value ± k * sigma` overlap.
+    ///
+    /// Measurements with NaN values or sigmas never overlap.
+    #[inline]
+    pub fn overlaps(&self, other: &Self, k: T) -> bool {
+        self.sigma_diff(other) <= k
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{DebugCategories, UlpsDiffReason};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// The layout of the messages printed when an assert fails.
+///
+/// Messages are laid out for people to read by default. Setting the
+/// `FLOAT_EQ_FAILURE_FORMAT` environment variable to `kv` when running tests
+/// selects a single line layout that is intended to be parsed by tools such as
+/// CI log scrapers and IDE test integrations. The environment variable is only
+/// read in `std` builds, since `no_std` builds always use the default layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureFormat {
+    /// The default, multi-line layout:
+    ///
+    /// ```text
+    /// assertion failed: `float_eq!(left, right, rmax <= t)`
+    ///         left: `4.0`,
+    ///        right: `4.000008`,
+    ///     abs_diff: `8.106232e-6`,
+    ///    ulps_diff: `Some(17)`,
+    ///     [rmax] t: `4.000008e-6`
+    /// ```
+    Human,
+    /// A single line of `key="value"` pairs, separated by single spaces:
+    ///
+    /// ```text
+    /// assertion failed: check="float_eq!(left, right, rmax <= t)" left="4.0" right="4.000008" abs_diff="8.106232e-6" ulps_diff="Some(17)" tol.rmax="4.000008e-6"
+    /// ```
+    ///
+    /// The grammar of the line is stable:
+    ///
+    /// ```text
+    /// line   = "assertion failed:" 1*(" " pair)
+    /// pair   = key "=" DQUOTE *(char / escape) DQUOTE
+    /// key    = 1*(%x61-7A / DIGIT / "_" / ".")
+    /// escape = "\\" / "\" DQUOTE / "\n" / "\r" / "\t"
+    /// ```
+    ///
+    /// Values are the `fmt::Debug` representations shown by [`Human`]. The
+    /// first key is always `check`, followed by `left`, `right` and any other
+    /// values in the same order as [`Human`]. Tolerances have keys of the form
+    /// `tol.<check>`, such as `tol.rmax`. If the ULPs difference is not a count,
+    /// for example because the operands differ in sign, it is followed by an
+    /// `ulps_diff_reason` key explaining why. Operands captured by
+    /// [`assert_float_eq_captured!`] are each written as a `capture.expr` key,
+    /// holding their source code, followed by a `capture.value` key. If
+    /// present, `note` and `message` are the final keys. New keys may be added
+    /// in future, so they should be matched by name rather than by position.
+    ///
+    /// [`Human`]: #variant.Human
+    /// [`assert_float_eq_captured!`]: macro.assert_float_eq_captured.html
+    KeyValue,
+    /// An aligned, multi-line table with a row for each value held by the
+    /// operands, such as each field of a struct, which is easier to read than
+    /// [`Human`] for large composite types:
+    ///
+    /// ```text
+    /// assertion failed: `float_eq!(left, right, abs <= t)`
+    ///   1 of 3 values differ:
+    ///   path      left  right  abs_diff  ulps_diff      [abs] t
+    ///   velocity  2.0   2.5    0.5       Some(1048576)  0.1
+    /// ```
+    ///
+    /// For `float_eq!` checks, only the values that are not within any of the
+    /// tolerances are listed. The rows are those given by
+    /// [`FailureReport::rows`]. If the `pretty` feature is enabled, the header
+    /// is bold and the failing values red, unless the `NO_COLOR` environment
+    /// variable is set.
+    ///
+    /// Selected by setting `FLOAT_EQ_FAILURE_FORMAT` to `table`. Only
+    /// available in `std` builds, and `no_std` builds use [`Human`] instead.
+    ///
+    /// [`Human`]: #variant.Human
+    /// [`FailureReport::rows`]: struct.FailureReport.html#method.rows
+    Table,
+}
+
+impl FailureFormat {
+    /// The layout selected by the `FLOAT_EQ_FAILURE_FORMAT` environment
+    /// variable, which is [`Human`] unless it is set to `kv` or `table`.
+    ///
+    /// The variable is read the first time this is called, and later changes
+    /// to it are ignored.
+    ///
+    /// [`Human`]: #variant.Human
+    pub fn current() -> Self {
+        #[cfg(feature = "std")]
+        {
+            match CURRENT_FORMAT.load(Ordering::Relaxed) {
+                UNREAD => {}
+                format => return FailureFormat::from_u8(format),
+            }
+            let format = match std::env::var_os("FLOAT_EQ_FAILURE_FORMAT") {
+                Some(v) if v == "kv" => FailureFormat::KeyValue,
+                Some(v) if v == "table" => FailureFormat::Table,
+                _ => FailureFormat::Human,
+            };
+            CURRENT_FORMAT.store(format as u8, Ordering::Relaxed);
+            format
+        }
+        #[cfg(not(feature = "std"))]
+        FailureFormat::Human
+    }
+
+    #[cfg(feature = "std")]
+    fn from_u8(format: u8) -> Self {
+        match format {
+            1 => FailureFormat::KeyValue,
+            2 => FailureFormat::Table,
+            _ => FailureFormat::Human,
+        }
+    }
+}
+
+// The format read from the environment, or UNREAD before it has been read.
+#[cfg(feature = "std")]
+const UNREAD: u8 = u8::MAX;
+#[cfg(feature = "std")]
+static CURRENT_FORMAT: AtomicU8 = AtomicU8::new(UNREAD);
+
+#[doc(hidden)]
+pub struct AssertFailure<'a> {
+    pub check: &'a str,
+    pub fields: &'a [(&'a str, &'a dyn fmt::Debug)],
+    pub note: Option<DebugCategories>,
+    pub ulps_diff_reason: Option<UlpsDiffReason>,
+    pub message: Option<fmt::Arguments<'a>>,
+}
+
+#[doc(hidden)]
+impl AssertFailure<'_> {
+    #[cold]
+    #[track_caller]
+    pub fn panic(&self) -> ! {
+        #[cfg(feature = "capture")]
+        {
+            let captured = crate::capture::current();
+            if !captured.is_empty() {
+                let mut fields = self.fields.to_vec();
+                fields.extend(
+                    captured
+                        .iter()
+                        .map(|(label, value)| (*label, value as &dyn fmt::Debug)),
+                );
+                AssertFailure {
+                    fields: &fields,
+                    ..*self
+                }
+                .raise()
+            }
+        }
+        self.raise()
+    }
+
+    #[cold]
+    #[track_caller]
+    fn raise(&self) -> ! {
+        #[cfg(feature = "std")]
+        {
+            if crate::FailurePayload::current() == crate::FailurePayload::Report {
+                // The default panic hook only prints string payloads.
+                std::eprintln!("{}", self);
+                std::panic::panic_any(crate::FailureReport::from(self))
+            }
+        }
+        panic!("{}", self)
+    }
+
+    pub(crate) fn fmt_as(&self, f: &mut fmt::Formatter<'_>, format: FailureFormat) -> fmt::Result {
+        match format {
+            FailureFormat::Human => self.fmt_human(f),
+            FailureFormat::KeyValue => self.fmt_key_value(f),
+            #[cfg(feature = "std")]
+            FailureFormat::Table => crate::FailureReport::from(self).fmt_table(f),
+            #[cfg(not(feature = "std"))]
+            FailureFormat::Table => self.fmt_human(f),
+        }
+    }
+
+    fn fmt_human(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assertion failed: `{}`", self.check)?;
+        for (i, (label, value)) in self.fields.iter().enumerate() {
+            let sep = if i == 0 { "\n" } else { ",\n" };
+            match self.ulps_diff_reason {
+                Some(reason) if *label == "ulps_diff" => {
+                    write!(f, "{}{:>12}: {}", sep, label, reason)?
+                }
+                _ => write!(f, "{}{:>12}: `{:?}`", sep, label, value)?,
+            }
+        }
+        if let Some(note) = &self.note {
+            write!(f, ",\n        note: {}", note)?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_key_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assertion failed: check=")?;
+        write_quoted(f, format_args!("{}", self.check))?;
+        for (label, value) in self.fields {
+            // Tolerance labels are of the form "[check] t"
+            match label.strip_prefix('[').and_then(|l| l.strip_suffix("] t")) {
+                Some(check) => write!(f, " tol.{}=", check)?,
+                None => match captured_expr(label) {
+                    Some(expr) => {
+                        write!(f, " capture.expr=")?;
+                        write_quoted(f, format_args!("{}", expr))?;
+                        write!(f, " capture.value=")?;
+                    }
+                    None => write!(f, " {}=", label)?,
+                },
+            }
+            write_quoted(f, format_args!("{:?}", value))?;
+            match self.ulps_diff_reason {
+                Some(reason) if *label == "ulps_diff" => {
+                    write!(f, " ulps_diff_reason=")?;
+                    write_quoted(f, format_args!("{}", reason))?;
+                }
+                _ => {}
+            }
+        }
+        if let Some(note) = &self.note {
+            write!(f, " note=")?;
+            write_quoted(f, format_args!("{}", note))?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, " message=")?;
+            write_quoted(f, format_args!("{}", message))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AssertFailure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_as(f, FailureFormat::current())
+    }
+}
+
+// Captured operand labels are their source code surrounded by backticks.
+fn captured_expr(label: &str) -> Option<&str> {
+    label.strip_prefix('`')?.strip_suffix('`')
+}
+
+fn write_quoted(f: &mut fmt::Formatter<'_>, value: fmt::Arguments<'_>) -> fmt::Result {
+    f.write_str("\"")?;
+    fmt::write(&mut Escaped(f), value)?;
+    f.write_str("\"")
+}
+
+// Escapes the characters that would otherwise break up a quoted value.
+struct Escaped<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl fmt::Write for Escaped<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '\\' => self.0.write_str("\\\\")?,
+                '"' => self.0.write_str("\\\"")?,
+                '\n' => self.0.write_str("\\n")?,
+                '\r' => self.0.write_str("\\r")?,
+                '\t' => self.0.write_str("\\t")?,
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::FloatPrimitive;
+use core::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// A pair of elements that differ, returned by [`worst_mismatches`] and
+/// [`worst_mismatches_rel`].
+///
+/// [`worst_mismatches`]: fn.worst_mismatches.html
+/// [`worst_mismatches_rel`]: fn.worst_mismatches_rel.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mismatch<T: FloatPrimitive> {
+    /// The position of the elements in their slices.
+    pub index: usize,
+    /// The element from the first slice.
+    pub a: T,
+    /// The element from the second slice.
+    pub b: T,
+    /// The absolute difference between the elements.
+    pub abs_diff: T,
+    /// The absolute difference between the elements, relative to the larger
+    /// of the two.
+    pub rel_diff: T,
+    /// The difference between the elements in [ULPs], or `None` if it is
+    /// undefined.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    pub ulps_diff: Option<T::Bits>,
+}
+
+impl<T: FloatPrimitive> Mismatch<T> {
+    fn new(index: usize, a: T, b: T) -> Self {
+        Mismatch {
+            index,
+            a,
+            b,
+            abs_diff: (a - b).abs_value(),
+            rel_diff: a.rel_diff(b),
+            ulps_diff: a.ulps_diff(b),
+        }
+    }
+}
+
+/// The `k` mismatching elements of two slices with the largest [ULPs]
+/// difference, in descending order.
+///
+/// Elements with an undefined ULPs difference, such as those with different
+/// signs or a NaN, are considered the worst. Elements that are equal are never
+/// returned, so fewer than `k` mismatches may be found. Only `k` mismatches
+/// are kept at a time, so large slices may be searched cheaply.
+///
+/// Returns `None` if the slices differ in length.
+///
+/// # Examples
+/// ```
+/// # use float_eq::worst_mismatches;
+/// let a = [1.0f32, 2.0, 3.0, 4.0];
+/// let b = [1.0f32, 2.000_001, 3.0, 4.000_001];
+///
+/// let worst = worst_mismatches(&a, &b, 1).unwrap();
+/// assert_eq!(worst.len(), 1);
+/// assert_eq!(worst[0].index, 1);
+/// assert_eq!(worst[0].ulps_diff, Some(4));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+pub fn worst_mismatches<T: FloatPrimitive>(a: &[T], b: &[T], k: usize) -> Option<Vec<Mismatch<T>>> {
+    let no_diff = T::ZERO.ulps_diff(T::ZERO);
+    worst_by_key(a, b, k, |m| {
+        if m.ulps_diff == no_diff {
+            None
+        } else {
+            Some((m.ulps_diff.is_none(), m.ulps_diff))
+        }
+    })
+}
+
+/// The `k` mismatching elements of two slices with the largest relative
+/// difference, in descending order.
+///
+/// The relative difference is scaled to the larger of the two elements, and
+/// elements where it is NaN are considered the worst. Elements that are equal
+/// are never returned, so fewer than `k` mismatches may be found. Only `k`
+/// mismatches are kept at a time, so large slices may be searched cheaply.
+///
+/// Returns `None` if the slices differ in length.
+///
+/// # Examples
+/// ```
+/// # use float_eq::worst_mismatches_rel;
+/// let a = [1.0f64, 100.0, 3.0];
+/// let b = [1.5f64, 101.0, 3.0];
+///
+/// let worst = worst_mismatches_rel(&a, &b, 5).unwrap();
+/// let indices: Vec<_> = worst.iter().map(|m| m.index).collect();
+/// assert_eq!(indices, vec![0, 1]);
+/// ```
+pub fn worst_mismatches_rel<T: FloatPrimitive>(
+    a: &[T],
+    b: &[T],
+    k: usize,
+) -> Option<Vec<Mismatch<T>>> {
+    worst_by_key(a, b, k, |m| {
+        if m.rel_diff == T::ZERO {
+            None
+        } else {
+            Some(RelKey(m.rel_diff))
+        }
+    })
+}
+
+fn worst_by_key<T, K, F>(a: &[T], b: &[T], k: usize, key: F) -> Option<Vec<Mismatch<T>>>
+where
+    T: FloatPrimitive,
+    K: Ord,
+    F: Fn(&Mismatch<T>) -> Option<K>,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+
+    // Min-heap of the worst mismatches found so far, where ties are broken in
+    // favour of the earliest index.
+    let mut heap = BinaryHeap::with_capacity(k.saturating_add(1).min(a.len()));
+    if k > 0 {
+        for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+            let mismatch = Mismatch::new(i, *a, *b);
+            if let Some(key) = key(&mismatch) {
+                heap.push(Reverse(HeapEntry {
+                    key,
+                    index: Reverse(i),
+                    mismatch,
+                }));
+                if heap.len() > k {
+                    heap.pop();
+                }
+            }
+        }
+    }
+
+    Some(
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(entry)| entry.mismatch)
+            .collect(),
+    )
+}
+
+struct HeapEntry<T: FloatPrimitive, K> {
+    key: K,
+    index: Reverse<usize>,
+    mismatch: Mismatch<T>,
+}
+
+impl<T: FloatPrimitive, K: Ord> PartialEq for HeapEntry<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: FloatPrimitive, K: Ord> Eq for HeapEntry<T, K> {}
+
+impl<T: FloatPrimitive, K: Ord> PartialOrd for HeapEntry<T, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: FloatPrimitive, K: Ord> Ord for HeapEntry<T, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.key, self.index).cmp(&(&other.key, other.index))
+    }
+}
+
+// Total order over relative differences, which are never negative, where NaN
+// is greater than any other value.
+struct RelKey<T>(T);
+
+impl<T: FloatPrimitive> PartialEq for RelKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: FloatPrimitive> Eq for RelKey<T> {}
+
+impl<T: FloatPrimitive> PartialOrd for RelKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: FloatPrimitive> Ord for RelKey<T> {
+    #[allow(clippy::eq_op)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_nan = self.0 != self.0;
+        let other_nan = other.0 != other.0;
+        match (self_nan, other_nan) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{FloatEq, FloatPrimitive};
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+/// The contiguous range of values that a check finds equal to some expected
+/// value, for constructing inputs in property based tests and fuzzing that are
+/// guaranteed to pass or fail that check.
+///
+/// Created by [`ulps_neighbors`] or [`abs_neighbors`]. Values are indexed in
+/// [ULPs] order from the smallest, so that any generator of integers, such as
+/// a proptest range strategy, may be mapped onto them via [`nth`]. With the
+/// `arbitrary` feature, [`arbitrary_within`] and [`arbitrary_outside`] draw
+/// them from an `arbitrary::Unstructured` instead.
+///
+/// The range never contains NaN, and positive and negative zero share a
+/// single index, at which positive zero is returned.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, ulps_neighbors};
+/// let neighbors = ulps_neighbors(1.0f32, 4);
+/// assert_eq!(neighbors.len(), 9);
+///
+/// for i in 0..neighbors.len() {
+///     let x = neighbors.nth(i).unwrap();
+///     assert!(float_eq!(x, 1.0, ulps <= 4));
+/// }
+/// assert!(!float_eq!(neighbors.below().unwrap(), 1.0, ulps <= 4));
+/// assert!(!float_eq!(neighbors.above().unwrap(), 1.0, ulps <= 4));
+/// ```
+///
+/// With proptest, a strategy over an index range yields the values:
+///
+/// ```ignore
+/// proptest! {
+///     #[test]
+///     fn round_trips(x in (0..NEIGHBORS.len()).prop_map(|i| NEIGHBORS.nth(i).unwrap())) {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// [`ulps_neighbors`]: fn.ulps_neighbors.html
+/// [`abs_neighbors`]: fn.abs_neighbors.html
+/// [`nth`]: #method.nth
+/// [`arbitrary_within`]: #method.arbitrary_within
+/// [`arbitrary_outside`]: #method.arbitrary_outside
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Neighbors<T> {
+    // Inclusive bounds as ULPs positions, where first > last if empty.
+    first: i64,
+    last: i64,
+    marker: PhantomData<T>,
+}
+
+/// The values within `n` [ULPs] of `x`, which pass `float_eq!(x, y, ulps <= n)`.
+///
+/// These never cross zero, since values of differing signs are only equal in
+/// a ULPs comparison if they are both zero. The range is empty if `x` is NaN.
+///
+/// # Examples
+/// ```
+/// # use float_eq::ulps_neighbors;
+/// let neighbors = ulps_neighbors(1.0f64, 1);
+/// assert_eq!(neighbors.min(), Some(1.0 - f64::EPSILON / 2.0));
+/// assert_eq!(neighbors.max(), Some(1.0 + f64::EPSILON));
+///
+/// let neighbors = ulps_neighbors(-0.0f32, 2);
+/// assert_eq!(neighbors.max(), Some(0.0));
+/// assert_eq!(neighbors.above(), Some(1e-45));
+/// ```
+///
+/// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+pub fn ulps_neighbors<T>(x: T, n: T::Bits) -> Neighbors<T>
+where
+    T: FloatPrimitive,
+    T::Bits: Into<u64>,
+{
+    if x.is_nan_value() {
+        return Neighbors::EMPTY;
+    }
+    let position = x.ulps_position();
+    let n = i64::try_from(n.into()).unwrap_or(i64::MAX);
+    let infinity = T::INFINITY.ulps_position();
+    if x.is_sign_positive_value() {
+        Neighbors::new(
+            position.saturating_sub(n).max(0),
+            position.saturating_add(n).min(infinity),
+        )
+    } else {
+        // Zero is returned as positive zero, which only equals a negative
+        // value if it is also zero.
+        let last = if position == 0 { 0 } else { -1 };
+        Neighbors::new(
+            position.saturating_sub(n).max(-infinity),
+            position.saturating_add(n).min(last),
+        )
+    }
+}
+
+/// The values within an absolute tolerance of `eps` of `x`, which pass
+/// `float_eq!(x, y, abs <= eps)`.
+///
+/// The bounds are found by evaluating the check itself, so they account for
+/// any rounding in calculating the difference, which may admit values a
+/// little further away than `eps`. The range is empty if `x` is
+/// NaN, and contains only `x` if `eps` is negative or NaN.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{abs_neighbors, float_eq};
+/// let neighbors = abs_neighbors(1.0f32, 0.25);
+/// assert_eq!(neighbors.min(), Some(0.75));
+/// assert_eq!(neighbors.max(), Some(1.25));
+/// assert!(!float_eq!(neighbors.above().unwrap(), 1.0, abs <= 0.25));
+/// ```
+pub fn abs_neighbors<T>(x: T, eps: T) -> Neighbors<T>
+where
+    T: FloatPrimitive + FloatEq<Tol = T>,
+{
+    let within = |position| x.eq_abs(&T::from_ulps_position(position), &eps);
+    let position = x.ulps_position();
+    if x.is_nan_value() || !within(position) {
+        return Neighbors::EMPTY;
+    }
+    let infinity = T::INFINITY.ulps_position();
+
+    // The values that pass are contiguous, since the rounded difference is
+    // monotonic, so each bound may be found by bisection between `x` and the
+    // infinity on that side.
+    let first = bisect(position, -infinity - 1, within);
+    let last = bisect(position, infinity + 1, within);
+    Neighbors::new(first, last)
+}
+
+/// The furthest position from `inside` towards `outside` for which `within` is
+/// true, given that it is true for every position in between.
+fn bisect(inside: i64, outside: i64, within: impl Fn(i64) -> bool) -> i64 {
+    let (mut inside, mut outside) = (i128::from(inside), i128::from(outside));
+    while (outside - inside).abs() > 1 {
+        let mid = (inside + outside) / 2;
+        if within(mid as i64) {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+    inside as i64
+}
+
+impl<T> Neighbors<T> {
+    const EMPTY: Self = Neighbors::new(1, 0);
+
+    const fn new(first: i64, last: i64) -> Self {
+        Neighbors {
+            first,
+            last,
+            marker: PhantomData,
+        }
+    }
+
+    /// Whether there are no values in the range.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.first > self.last
+    }
+
+    /// The number of values in the range, counting both zeros as one.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.last.abs_diff(self.first) + 1
+        }
+    }
+}
+
+impl<T: FloatPrimitive> Neighbors<T> {
+    /// The smallest value in the range.
+    #[inline]
+    pub fn min(&self) -> Option<T> {
+        self.nth(0)
+    }
+
+    /// The largest value in the range.
+    #[inline]
+    pub fn max(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(T::from_ulps_position(self.last))
+        }
+    }
+
+    /// The value `index` ULPs above the smallest, or `None` if that is beyond
+    /// the end of the range.
+    #[inline]
+    pub fn nth(&self, index: u64) -> Option<T> {
+        if index < self.len() {
+            let position = i128::from(self.first) + i128::from(index);
+            Some(T::from_ulps_position(position as i64))
+        } else {
+            None
+        }
+    }
+
+    /// The largest value below the range, which fails the check, or `None` if
+    /// the range is empty or starts at negative infinity.
+    #[inline]
+    pub fn below(&self) -> Option<T> {
+        let infinity = T::INFINITY.ulps_position();
+        if self.is_empty() || self.first == -infinity {
+            None
+        } else {
+            Some(T::from_ulps_position(self.first - 1))
+        }
+    }
+
+    /// The smallest value above the range, which fails the check, or `None`
+    /// if the range is empty or ends at positive infinity.
+    #[inline]
+    pub fn above(&self) -> Option<T> {
+        let infinity = T::INFINITY.ulps_position();
+        if self.is_empty() || self.last == infinity {
+            None
+        } else {
+            Some(T::from_ulps_position(self.last + 1))
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<T: FloatPrimitive> Neighbors<T> {
+    /// A value in the range, drawn from `u`.
+    ///
+    /// Returns `Err(EmptyChoose)` if the range is empty.
+    pub fn arbitrary_within(&self, u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<T> {
+        if self.is_empty() {
+            return Err(arbitrary::Error::EmptyChoose);
+        }
+        let position = u.int_in_range(self.first..=self.last)?;
+        Ok(T::from_ulps_position(position))
+    }
+
+    /// A value that is not NaN and not in the range, drawn from `u`. Values
+    /// either side of the range are equally likely to be chosen.
+    ///
+    /// Returns `Err(EmptyChoose)` if the range contains every other value.
+    pub fn arbitrary_outside(&self, u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<T> {
+        let infinity = T::INFINITY.ulps_position();
+        if self.is_empty() {
+            let position = u.int_in_range(-infinity..=infinity)?;
+            return Ok(T::from_ulps_position(position));
+        }
+        let below = -infinity..=self.first - 1;
+        let above = self.last + 1..=infinity;
+        let side = match (below.is_empty(), above.is_empty()) {
+            (false, false) => u.choose(&[below, above])?.clone(),
+            (false, true) => below,
+            (true, false) => above,
+            (true, true) => return Err(arbitrary::Error::EmptyChoose),
+        };
+        let position = u.int_in_range(side)?;
+        Ok(T::from_ulps_position(position))
+    }
+}
@@ -0,0 +1,460 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff};
+use crate::{CheckSet, FloatEq, FloatEqAll, UlpsTol};
+
+/// How the elements of two collections are matched up to be compared, used by
+/// [`WithPolicy`].
+///
+/// The comparison traits are implemented for slices, `Vec` and the other
+/// standard containers such that collections are only equal if they have the
+/// same length and each pair of elements at the same index are equal. A policy
+/// describes a different way of pairing up elements, such as [`Prefix`],
+/// [`MissingAsZero`] or [`Unordered`], and may be implemented to provide new
+/// ones.
+///
+/// [`WithPolicy`]: struct.WithPolicy.html
+/// [`Prefix`]: struct.Prefix.html
+/// [`MissingAsZero`]: struct.MissingAsZero.html
+/// [`Unordered`]: struct.Unordered.html
+pub trait CollectionCmpPolicy<A, B> {
+    /// Whether `a` and `b` are equal under this policy, where `eq` checks
+    /// whether a pair of elements are equal.
+    fn eq_by<F>(&self, a: &[A], b: &[B], eq: F) -> bool
+    where
+        F: FnMut(&A, &B) -> bool;
+
+    /// Call `f` with each pair of elements that is displayed when an assert
+    /// comparing `a` and `b` under this policy fails.
+    fn for_each_debug_pair<F>(&self, a: &[A], b: &[B], f: F)
+    where
+        F: FnMut(&A, &B);
+}
+
+/// Collections are equal if they have the same length and the elements at
+/// each index are equal, which is how the standard containers are compared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LengthStrict;
+
+impl<A, B> CollectionCmpPolicy<A, B> for LengthStrict {
+    #[inline]
+    fn eq_by<F>(&self, a: &[A], b: &[B], mut eq: F) -> bool
+    where
+        F: FnMut(&A, &B) -> bool,
+    {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| eq(a, b))
+    }
+
+    #[inline]
+    fn for_each_debug_pair<F>(&self, a: &[A], b: &[B], mut f: F)
+    where
+        F: FnMut(&A, &B),
+    {
+        a.iter().zip(b).for_each(|(a, b)| f(a, b))
+    }
+}
+
+/// Collections are equal if the elements at each index of the shorter one are
+/// equal to those of the longer one. Extra elements are ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Prefix;
+
+impl<A, B> CollectionCmpPolicy<A, B> for Prefix {
+    #[inline]
+    fn eq_by<F>(&self, a: &[A], b: &[B], mut eq: F) -> bool
+    where
+        F: FnMut(&A, &B) -> bool,
+    {
+        a.iter().zip(b).all(|(a, b)| eq(a, b))
+    }
+
+    #[inline]
+    fn for_each_debug_pair<F>(&self, a: &[A], b: &[B], mut f: F)
+    where
+        F: FnMut(&A, &B),
+    {
+        a.iter().zip(b).for_each(|(a, b)| f(a, b))
+    }
+}
+
+/// Collections are equal if the elements at each index are equal, where the
+/// shorter one is padded out with zeros, or rather the `Default` value of its
+/// elements.
+///
+/// This suits sparse data such as polynomial coefficients or spectra, where
+/// trailing zeros are often left out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MissingAsZero;
+
+impl MissingAsZero {
+    fn for_each_padded<A, B, F>(a: &[A], b: &[B], mut f: F) -> bool
+    where
+        A: Default,
+        B: Default,
+        F: FnMut(&A, &B) -> bool,
+    {
+        let zero_a = A::default();
+        let zero_b = B::default();
+        (0..a.len().max(b.len()))
+            .all(|i| f(a.get(i).unwrap_or(&zero_a), b.get(i).unwrap_or(&zero_b)))
+    }
+}
+
+impl<A: Default, B: Default> CollectionCmpPolicy<A, B> for MissingAsZero {
+    #[inline]
+    fn eq_by<F>(&self, a: &[A], b: &[B], eq: F) -> bool
+    where
+        F: FnMut(&A, &B) -> bool,
+    {
+        MissingAsZero::for_each_padded(a, b, eq)
+    }
+
+    #[inline]
+    fn for_each_debug_pair<F>(&self, a: &[A], b: &[B], mut f: F)
+    where
+        F: FnMut(&A, &B),
+    {
+        MissingAsZero::for_each_padded(a, b, |a, b| {
+            f(a, b);
+            true
+        });
+    }
+}
+
+/// Collections are equal if they have the same length and their elements can
+/// be paired up such that each pair is equal, whatever their order.
+///
+/// Every element of one collection is compared with every element of the
+/// other, so this is only suitable for small collections. When an assert
+/// fails, the elements displayed are those at the same index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unordered;
+
+impl<A, B> CollectionCmpPolicy<A, B> for Unordered {
+    fn eq_by<F>(&self, a: &[A], b: &[B], mut eq: F) -> bool
+    where
+        F: FnMut(&A, &B) -> bool,
+    {
+        if a.len() != b.len() {
+            return false;
+        }
+
+        // Find a perfect matching of the bipartite graph of equal elements,
+        // using Kuhn's augmenting path algorithm.
+        let n = a.len();
+        let equal: Vec<bool> = a
+            .iter()
+            .flat_map(|x| b.iter().map(move |y| (x, y)))
+            .map(|(x, y)| eq(x, y))
+            .collect();
+        let mut matched_to: Vec<Option<usize>> = vec![None; n];
+        for i in 0..n {
+            let mut visited = vec![false; n];
+            if !augment(i, n, &equal, &mut visited, &mut matched_to) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn for_each_debug_pair<F>(&self, a: &[A], b: &[B], mut f: F)
+    where
+        F: FnMut(&A, &B),
+    {
+        a.iter().zip(b).for_each(|(a, b)| f(a, b))
+    }
+}
+
+// Try to match element i of the first collection, reassigning the matches of
+// the second collection's elements along an augmenting path if need be.
+fn augment(
+    i: usize,
+    n: usize,
+    equal: &[bool],
+    visited: &mut [bool],
+    matched_to: &mut [Option<usize>],
+) -> bool {
+    for j in 0..n {
+        if equal[i * n + j] && !visited[j] {
+            visited[j] = true;
+            let free = match matched_to[j] {
+                Some(k) => augment(k, n, equal, visited, matched_to),
+                None => true,
+            };
+            if free {
+                matched_to[j] = Some(i);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// A collection that is compared according to a [`CollectionCmpPolicy`],
+/// rather than by requiring that each element at the same index be equal.
+///
+/// Both operands must use the same policy. A single tolerance is used for
+/// every pair of elements, so `FloatEq` comparisons take the tolerance of
+/// an element, and `FloatEqAll` comparisons take that of its `_all` checks.
+/// The debug output of asserts holds the differences between each pair of
+/// elements displayed by the policy.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "checks-only"))]
+/// # use float_eq::assert_float_eq;
+/// # use float_eq::{float_eq, MissingAsZero, Unordered, WithPolicy};
+/// let a = WithPolicy::new(&[1.0, 2.0, 0.0][..], MissingAsZero);
+/// let b = WithPolicy::new(&[1.0, 2.000_001][..], MissingAsZero);
+/// assert!(float_eq!(a, b, abs <= 0.000_01));
+///
+/// let a = WithPolicy::new(&[3.0f32, 1.0, 2.0][..], Unordered);
+/// let b = WithPolicy::new(&[1.0f32, 2.0, 3.0][..], Unordered);
+/// # #[cfg(not(feature = "checks-only"))]
+/// assert_float_eq!(a, b, ulps_all <= 0);
+/// ```
+///
+/// [`CollectionCmpPolicy`]: trait.CollectionCmpPolicy.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WithPolicy<'a, T, P> {
+    values: &'a [T],
+    policy: P,
+}
+
+impl<'a, T, P> WithPolicy<'a, T, P> {
+    /// Wrap `values` so that they are compared according to `policy`.
+    #[inline]
+    pub fn new(values: &'a [T], policy: P) -> Self {
+        WithPolicy { values, policy }
+    }
+
+    /// The wrapped values.
+    #[inline]
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// The policy used to compare the values.
+    #[inline]
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+}
+
+impl<A, B, P> FloatEq<WithPolicy<'_, B, P>> for WithPolicy<'_, A, P>
+where
+    A: FloatEq<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    P: CollectionCmpPolicy<A, B>,
+{
+    type Tol = A::Tol;
+    const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_abs(b, tol))
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_rmax(b, tol))
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_rmin(b, tol))
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_r1st(b, tol))
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_r2nd(b, tol))
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &WithPolicy<'_, B, P>, tol: &UlpsTol<Self::Tol>) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_ulps(b, tol))
+    }
+}
+
+impl<A, B, P> FloatEqAll<WithPolicy<'_, B, P>> for WithPolicy<'_, A, P>
+where
+    A: FloatEqAll<B>,
+    P: CollectionCmpPolicy<A, B>,
+{
+    type AllTol = A::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &WithPolicy<'_, B, P>, tol: &Self::AllTol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_abs_all(b, tol))
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &WithPolicy<'_, B, P>, tol: &Self::AllTol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_rmax_all(b, tol))
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &WithPolicy<'_, B, P>, tol: &Self::AllTol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_rmin_all(b, tol))
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &WithPolicy<'_, B, P>, tol: &Self::AllTol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_r1st_all(b, tol))
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &WithPolicy<'_, B, P>, tol: &Self::AllTol) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_r2nd_all(b, tol))
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &WithPolicy<'_, B, P>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        (self.policy).eq_by(self.values, other.values, |a, b| a.eq_ulps_all(b, tol))
+    }
+}
+
+// The results of calling `f` with each pair of elements displayed by the
+// policy of `a` and `b`.
+#[cfg(not(feature = "checks-only"))]
+fn debug_pairs<A, B, P, D, F>(
+    a: &WithPolicy<'_, A, P>,
+    b: &WithPolicy<'_, B, P>,
+    mut f: F,
+) -> Vec<D>
+where
+    P: CollectionCmpPolicy<A, B>,
+    F: FnMut(&A, &B) -> D,
+{
+    let mut result = Vec::new();
+    (a.policy).for_each_debug_pair(a.values, b.values, |a, b| result.push(f(a, b)));
+    result
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<A, B, P> AssertFloatEq<WithPolicy<'_, B, P>> for WithPolicy<'_, A, P>
+where
+    A: AssertFloatEq<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+    P: CollectionCmpPolicy<A, B>,
+{
+    type DebugAbsDiff = Vec<A::DebugAbsDiff>;
+    type DebugTol = Vec<A::DebugTol>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &WithPolicy<'_, B, P>) -> Self::DebugAbsDiff {
+        debug_pairs(self, other, |a, b| a.debug_abs_diff(b))
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &WithPolicy<'_, B, P>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        debug_pairs(self, other, |a, b| a.debug_ulps_diff(b))
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> Self::DebugTol {
+        debug_pairs(self, other, |a, b| a.debug_abs_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> Self::DebugTol {
+        debug_pairs(self, other, |a, b| a.debug_rmax_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> Self::DebugTol {
+        debug_pairs(self, other, |a, b| a.debug_rmin_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> Self::DebugTol {
+        debug_pairs(self, other, |a, b| a.debug_r1st_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &WithPolicy<'_, B, P>, tol: &Self::Tol) -> Self::DebugTol {
+        debug_pairs(self, other, |a, b| a.debug_r2nd_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol> {
+        debug_pairs(self, other, |a, b| a.debug_ulps_tol(b, tol))
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<A, B, P> AssertFloatEqAll<WithPolicy<'_, B, P>> for WithPolicy<'_, A, P>
+where
+    A: AssertFloatEqAll<B>,
+    UlpsTol<A::AllDebugTol>: Sized,
+    P: CollectionCmpPolicy<A, B>,
+{
+    type AllDebugTol = Vec<A::AllDebugTol>;
+
+    #[inline]
+    fn debug_abs_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        debug_pairs(self, other, |a, b| a.debug_abs_all_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        debug_pairs(self, other, |a, b| a.debug_rmax_all_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        debug_pairs(self, other, |a, b| a.debug_rmin_all_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        debug_pairs(self, other, |a, b| a.debug_r1st_all_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        debug_pairs(self, other, |a, b| a.debug_r2nd_all_tol(b, tol))
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &WithPolicy<'_, B, P>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol> {
+        debug_pairs(self, other, |a, b| a.debug_ulps_all_tol(b, tol))
+    }
+}
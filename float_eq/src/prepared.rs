@@ -0,0 +1,97 @@
+use crate::{AssertFloatEq, FloatEq};
+
+/// An expected value with a precomputed tolerance, for comparing many
+/// candidates against the same reference.
+///
+/// Table driven tests often check a long list of candidates against a single
+/// expected value. A relative tolerance scaled to the expected value (an
+/// `r2nd` check) only depends on the expected value, so it may be scaled once
+/// up front and then applied to each candidate as an absolute tolerance, which
+/// avoids recalculating it per field for every comparison.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_float_eq, PreparedExpected};
+/// let expected = [1.0f32, 100.0];
+/// let prepared = PreparedExpected::r2nd(expected, &[0.01, 0.01]);
+/// assert_eq!(prepared.tol(), &[0.01, 1.0]);
+///
+/// assert!(prepared.eq_expected(&[1.005, 100.5]));
+/// assert!(prepared.ne_expected(&[1.005, 101.5]));
+///
+/// // The prepared tolerance may be used directly with the assert macros
+/// assert_float_eq!([1.005, 100.5], *prepared.expected(), abs <= *prepared.tol());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedExpected<T>
+where
+    T: AssertFloatEq<DebugTol = <T as FloatEq>::Tol>,
+    T::Tol: Sized,
+{
+    expected: T,
+    abs_tol: T::Tol,
+}
+
+impl<T> PreparedExpected<T>
+where
+    T: AssertFloatEq<DebugTol = <T as FloatEq>::Tol>,
+    T::Tol: Sized + Clone,
+{
+    /// Prepare `expected` for an [absolute tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[inline]
+    pub fn abs(expected: T, tol: &T::Tol) -> Self {
+        PreparedExpected {
+            expected,
+            abs_tol: tol.clone(),
+        }
+    }
+
+    /// Prepare `expected` for a [relative tolerance comparison], scaled to the
+    /// precision of the expected value. This is equivalent to an `r2nd` check
+    /// with the expected value as the second operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    #[inline]
+    pub fn r2nd(expected: T, tol: &T::Tol) -> Self {
+        let abs_tol = expected.debug_r2nd_tol(&expected, tol);
+        PreparedExpected { expected, abs_tol }
+    }
+}
+
+impl<T> PreparedExpected<T>
+where
+    T: AssertFloatEq<DebugTol = <T as FloatEq>::Tol>,
+    T::Tol: Sized,
+{
+    /// The expected value.
+    #[inline]
+    pub fn expected(&self) -> &T {
+        &self.expected
+    }
+
+    /// The precomputed per-field absolute tolerance.
+    #[inline]
+    pub fn tol(&self) -> &T::Tol {
+        &self.abs_tol
+    }
+
+    /// Check whether `candidate` is equal to the expected value.
+    #[inline]
+    pub fn eq_expected(&self, candidate: &T) -> bool {
+        candidate.eq_abs(&self.expected, &self.abs_tol)
+    }
+
+    /// Check whether `candidate` is not equal to the expected value.
+    #[inline]
+    pub fn ne_expected(&self, candidate: &T) -> bool {
+        !self.eq_expected(candidate)
+    }
+
+    /// Unwrap the expected value.
+    #[inline]
+    pub fn into_expected(self) -> T {
+        self.expected
+    }
+}
@@ -0,0 +1,96 @@
+use crate::FloatBits;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// IEEE floating point primitive types, `f32` and `f64`.
+///
+/// This trait provides the handful of operations required by the utility
+/// functions in this crate that work directly on primitive values rather than
+/// via the comparison traits. It is sealed and may not be implemented outside
+/// of float_eq.
+pub trait FloatPrimitive:
+    FloatBits
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + fmt::LowerExp
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + sealed::Sealed
+{
+    /// Positive zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// The difference between one and the next larger representable value.
+    const EPSILON: Self;
+
+    /// Positive infinity.
+    const INFINITY: Self;
+
+    /// Not a number.
+    const NAN: Self;
+
+    /// The absolute value of `self`, available in `no_std` builds.
+    fn abs_value(self) -> Self;
+
+    /// The position of `self` among all non-NaN values, counted in [ULPs] from
+    /// zero, so that adjacent values are one apart and both zeros are at zero.
+    /// Negative values have negative positions. NaNs lie beyond the infinity
+    /// of the same sign.
+    ///
+    /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    fn ulps_position(self) -> i64;
+
+    /// The value at `position`, as returned by [`ulps_position`], clamped to
+    /// the infinities so that it is never NaN. Position zero is positive zero.
+    ///
+    /// [`ulps_position`]: #tymethod.ulps_position
+    fn from_ulps_position(position: i64) -> Self;
+
+    /// The nearest value to `num / den`.
+    fn from_ratio(num: u64, den: u64) -> Self;
+
+    /// Computes `self * a + b` with a single rounding, available in `std`
+    /// builds.
+    #[cfg(feature = "std")]
+    fn fused_mul_add(self, a: Self, b: Self) -> Self;
+
+    /// The square root of `self`, available in `std` builds.
+    #[cfg(feature = "std")]
+    fn sqrt_value(self) -> Self;
+
+    /// The nearest integer to `self`, rounding half-way cases away from zero,
+    /// available in `std` builds.
+    #[cfg(feature = "std")]
+    fn round_value(self) -> Self;
+
+    /// The absolute difference between `self` and `other`, relative to the
+    /// magnitude of the larger of the two.
+    ///
+    /// Returns zero if the values are equal, including if both are zero or
+    /// both are the same infinity.
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    fn rel_diff(self, other: Self) -> Self {
+        if self == other {
+            Self::ZERO
+        } else {
+            let a = self.abs_value();
+            let b = other.abs_value();
+            let largest = if b > a { b } else { a };
+            (self - other).abs_value() / largest
+        }
+    }
+}
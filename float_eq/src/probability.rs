@@ -0,0 +1,52 @@
+use crate::FloatPrimitive;
+use core::cmp::Ordering;
+
+/// The sum of a slice of values, using compensated summation.
+///
+/// Used by the stochasticity asserts, such as [`assert_simplex!`], so that the
+/// rounding error of the sum itself stays close to one ULP even for long
+/// vectors, rather than growing with their length.
+///
+/// # Examples
+/// ```
+/// # use float_eq::probability_sum;
+/// assert_eq!(probability_sum(&[0.25f64, 0.5, 0.25]), 1.0);
+/// assert_eq!(probability_sum(&[1.0f64, 1e100, 1.0, -1e100]), 2.0);
+/// ```
+///
+/// [`assert_simplex!`]: macro.assert_simplex.html
+pub fn probability_sum<T: FloatPrimitive>(values: &[T]) -> T {
+    let mut sum = T::ZERO;
+    let mut compensation = T::ZERO;
+    for x in values.iter().copied() {
+        let t = sum + x;
+        if sum.abs_value() >= x.abs_value() {
+            compensation = compensation + ((sum - t) + x);
+        } else {
+            compensation = compensation + ((x - t) + sum);
+        }
+        sum = t;
+    }
+    sum + compensation
+}
+
+/// The index of the first value that is negative or NaN, and so is not a
+/// valid probability.
+///
+/// Returns `None` if every value is zero or positive, including negative zero.
+///
+/// # Examples
+/// ```
+/// # use float_eq::first_negative;
+/// assert_eq!(first_negative(&[0.5f32, 0.0, 0.5]), None);
+/// assert_eq!(first_negative(&[0.5f32, -1e-9, 0.5]), Some(1));
+/// assert_eq!(first_negative(&[0.5f32, f32::NAN]), Some(1));
+/// ```
+pub fn first_negative<T: FloatPrimitive>(values: &[T]) -> Option<usize> {
+    values.iter().position(|x| {
+        !matches!(
+            x.partial_cmp(&T::ZERO),
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        )
+    })
+}
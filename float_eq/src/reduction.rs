@@ -0,0 +1,55 @@
+use crate::FloatPrimitive;
+
+/// The largest absolute difference that rounding error may cause between the
+/// sequential sum of `len` values, each of magnitude at most `max_abs`, and a
+/// sum of the same values reduced in parallel.
+///
+/// The parallel reduction is modelled as a tree, whose leaves each sum up to
+/// `chunk` consecutive values sequentially, and whose partial sums are then
+/// added pairwise, as most parallel reductions do. Each result may differ from
+/// the exact sum by at most `γ(d) * len * max_abs`, where `d` is the depth of
+/// its additions, `γ(d) = d * u / (1 - d * u)` and `u` is half of `EPSILON`.
+/// The sequential sum has a depth of `len - 1`, and the tree a depth of
+/// `chunk - 1` plus the base two logarithm of the number of chunks, rounded
+/// up, so the tolerance is the sum of those two bounds.
+///
+/// A `chunk` of 0 is treated as 1, which is a fully pairwise reduction. The
+/// bound holds for any order of addition within those depths, so it does not
+/// depend on how the work is scheduled across threads. It is infinite if
+/// either depth is so large that the error could not be bounded.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, parallel_sum_tol};
+/// let values: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.37).sin()).collect();
+/// let sequential: f32 = values.iter().sum();
+/// let parallel: f32 = values.chunks(64).map(|c| c.iter().sum::<f32>()).sum();
+///
+/// let tol = parallel_sum_tol(values.len(), 1.0f32, 64);
+/// assert!(float_eq!(parallel, sequential, abs <= tol));
+/// ```
+pub fn parallel_sum_tol<T: FloatPrimitive>(len: usize, max_abs: T, chunk: usize) -> T {
+    if len == 0 {
+        return T::ZERO;
+    }
+    let chunk = chunk.clamp(1, len);
+    let chunks = len.div_ceil(chunk);
+    let tree_depth = (chunk - 1) + ceil_log2(chunks);
+    let abs_sum = T::from_ratio(len as u64, 1) * max_abs.abs_value();
+    (gamma::<T>(len - 1) + gamma::<T>(tree_depth)) * abs_sum
+}
+
+/// The bound on the relative error of `depth` consecutive roundings.
+fn gamma<T: FloatPrimitive>(depth: usize) -> T {
+    let two = T::ONE + T::ONE;
+    let du = T::from_ratio(depth as u64, 1) * (T::EPSILON / two);
+    if du < T::ONE {
+        du / (T::ONE - du)
+    } else {
+        T::INFINITY
+    }
+}
+
+fn ceil_log2(n: usize) -> usize {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
+}
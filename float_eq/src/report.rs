@@ -0,0 +1,149 @@
+use crate::{AssertFailure, DebugCategories, UlpsDiffReason};
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static PANIC_WITH_REPORT: AtomicBool = AtomicBool::new(false);
+
+/// The payload that failed asserts panic with.
+///
+/// By default, asserts panic with a `String` message, like those from the
+/// standard library. Test harnesses that catch unwinds may instead select
+/// [`Report`], so that the details of a failure can be inspected without
+/// parsing the message. The payload is a process wide setting.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{assert_float_eq, FailurePayload, FailureReport};
+/// FailurePayload::Report.set_current();
+///
+/// let result = std::panic::catch_unwind(|| {
+///     assert_float_eq!(1.0f32, 1.5, abs <= 0.1);
+/// });
+/// let payload = result.unwrap_err();
+/// let report = payload.downcast_ref::<FailureReport>().unwrap();
+/// assert_eq!(report.field("abs_diff"), Some("0.5"));
+/// assert_eq!(report.field("[abs] t"), Some("0.1"));
+/// # FailurePayload::Message.set_current();
+/// ```
+///
+/// [`Report`]: #variant.Report
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailurePayload {
+    /// A `String` holding the failure message.
+    Message,
+    /// A [`FailureReport`] holding the details of the failure. Its `Display`
+    /// implementation gives the failure message. Since the default panic hook
+    /// only prints string payloads, the message is written to standard error
+    /// just before panicking, so that it still appears in the test output.
+    ///
+    /// [`FailureReport`]: struct.FailureReport.html
+    Report,
+}
+
+impl FailurePayload {
+    /// The payload that failed asserts currently panic with.
+    pub fn current() -> Self {
+        if PANIC_WITH_REPORT.load(Ordering::Relaxed) {
+            FailurePayload::Report
+        } else {
+            FailurePayload::Message
+        }
+    }
+
+    /// Make failed asserts panic with this payload from now on.
+    pub fn set_current(self) {
+        PANIC_WITH_REPORT.store(self == FailurePayload::Report, Ordering::Relaxed);
+    }
+}
+
+/// The details of a failed assert, used as its panic payload when
+/// [`FailurePayload::Report`] is selected.
+///
+/// Values are held as their `fmt::Debug` representations, since the types of
+/// the operands are erased by the time the assert panics.
+///
+/// [`FailurePayload::Report`]: enum.FailurePayload.html#variant.Report
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailureReport {
+    /// The check that failed, for example `float_eq!(left, right, abs <= t)`.
+    pub check: String,
+    /// The labelled values displayed by the failure message, in order. These
+    /// are `left`, `right` and any further values shown by the assert, such as
+    /// `abs_diff`, `ulps_diff` and tolerances labelled like `[abs] t`.
+    pub fields: Vec<(String, String)>,
+    /// Any operands that are NaN, infinite or subnormal.
+    pub note: Option<DebugCategories>,
+    /// Why the `ulps_diff` field is not a count of the values between the
+    /// operands, such as their differing in sign, if it is not.
+    pub ulps_diff_reason: Option<UlpsDiffReason>,
+    /// The custom message passed to the assert, if there was one.
+    pub message: Option<String>,
+}
+
+impl FailureReport {
+    /// The value of the field with the given label, if it is present.
+    pub fn field(&self, label: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl From<&AssertFailure<'_>> for FailureReport {
+    fn from(failure: &AssertFailure<'_>) -> Self {
+        FailureReport {
+            check: failure.check.to_owned(),
+            fields: failure
+                .fields
+                .iter()
+                .map(|(label, value)| ((*label).to_owned(), format!("{:?}", value)))
+                .collect(),
+            note: failure.note,
+            ulps_diff_reason: failure.ulps_diff_reason,
+            message: failure.message.map(|message| message.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FailureReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<(&str, Verbatim<'_>)> = self
+            .fields
+            .iter()
+            .map(|(label, value)| (label.as_str(), Verbatim(value)))
+            .collect();
+        let fields: Vec<(&str, &dyn fmt::Debug)> = fields
+            .iter()
+            .map(|(label, value)| (*label, value as &dyn fmt::Debug))
+            .collect();
+        let message = self.message.as_deref().map(Verbatim);
+        match &message {
+            Some(message) => AssertFailure {
+                check: &self.check,
+                fields: &fields,
+                note: self.note,
+                ulps_diff_reason: self.ulps_diff_reason,
+                message: Some(format_args!("{:?}", message)),
+            }
+            .fmt(f),
+            None => AssertFailure {
+                check: &self.check,
+                fields: &fields,
+                note: self.note,
+                ulps_diff_reason: self.ulps_diff_reason,
+                message: None,
+            }
+            .fmt(f),
+        }
+    }
+}
+
+// Writes an already formatted value as it is.
+struct Verbatim<'a>(&'a str);
+
+impl fmt::Debug for Verbatim<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
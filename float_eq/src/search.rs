@@ -0,0 +1,181 @@
+use crate::FloatEq;
+
+/// Extension trait for searching a slice for a value that is approximately
+/// equal to another.
+///
+/// This replaces the hand-written `iter().any(|v| (v - x).abs() < eps)`
+/// pattern with one that works for any type that implements [`FloatEq`], such
+/// as arrays, tuples and complex numbers, and for any of its checks.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{FloatEq, FloatEqSliceExt};
+/// let values = [1.0f32, 2.0, 3.0];
+///
+/// assert!(values.contains_float(&2.000_001, 0.000_01));
+/// assert!(!values.contains_float(&2.5, 0.1));
+/// assert_eq!(values.position_float(&3.000_001, 0.000_01), Some(2));
+///
+/// // Other checks may be used by providing them explicitly
+/// let found = values.position_float_by(&2.000_001, 4, |a, b, tol| a.eq_ulps(b, tol));
+/// assert_eq!(found, Some(1));
+/// ```
+///
+/// [`FloatEq`]: trait.FloatEq.html
+pub trait FloatEqSliceExt<T> {
+    /// Whether any element is equal to `x`, using an [absolute tolerance
+    /// comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[inline]
+    fn contains_float<U>(&self, x: &U, tol: <T as FloatEq<U>>::Tol) -> bool
+    where
+        T: FloatEq<U>,
+        <T as FloatEq<U>>::Tol: Sized,
+    {
+        self.position_float(x, tol).is_some()
+    }
+
+    /// The index of the first element that is equal to `x`, using an
+    /// [absolute tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[inline]
+    fn position_float<U>(&self, x: &U, tol: <T as FloatEq<U>>::Tol) -> Option<usize>
+    where
+        T: FloatEq<U>,
+        <T as FloatEq<U>>::Tol: Sized,
+    {
+        self.position_float_by(x, tol, FloatEq::eq_abs)
+    }
+
+    /// Whether any element is equal to `x`, using `check` to decide whether
+    /// they are equal given `tol`.
+    #[inline]
+    fn contains_float_by<U, Tol, F>(&self, x: &U, tol: Tol, check: F) -> bool
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U, &Tol) -> bool,
+    {
+        self.position_float_by(x, tol, check).is_some()
+    }
+
+    /// The index of the first element that is equal to `x`, using `check` to
+    /// decide whether they are equal given `tol`.
+    fn position_float_by<U, Tol, F>(&self, x: &U, tol: Tol, check: F) -> Option<usize>
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U, &Tol) -> bool;
+}
+
+impl<T> FloatEqSliceExt<T> for [T] {
+    #[inline]
+    fn position_float_by<U, Tol, F>(&self, x: &U, tol: Tol, mut check: F) -> Option<usize>
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U, &Tol) -> bool,
+    {
+        self.iter().position(|v| check(v, x, &tol))
+    }
+}
+
+/// Extension trait for looking up a map entry by a floating point key that is
+/// approximately equal to another.
+///
+/// Float keys are rarely looked up exactly, since a key that is recomputed
+/// will often differ from the stored one by a few ULPs. Every key is compared
+/// in turn, so a lookup takes linear time. If several keys match, which of
+/// their values is returned follows the iteration order of the map: the
+/// smallest for a `BTreeMap`, and unspecified for a `HashMap`.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{FloatEq, FloatEqMapExt};
+/// # use std::cmp::Ordering;
+/// # use std::collections::BTreeMap;
+/// // Floats are not `Ord`, so they must be wrapped to be used as keys
+/// #[derive(Debug, PartialEq)]
+/// struct Energy(f64);
+/// # impl Eq for Energy {}
+/// # impl PartialOrd for Energy {
+/// #     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+/// #         Some(self.cmp(other))
+/// #     }
+/// # }
+/// # impl Ord for Energy {
+/// #     fn cmp(&self, other: &Self) -> Ordering {
+/// #         self.0.total_cmp(&other.0)
+/// #     }
+/// # }
+/// # impl FloatEq for Energy {
+/// #     type Tol = f64;
+/// #     fn eq_abs(&self, other: &Self, tol: &f64) -> bool { self.0.eq_abs(&other.0, tol) }
+/// #     fn eq_rmax(&self, other: &Self, tol: &f64) -> bool { self.0.eq_rmax(&other.0, tol) }
+/// #     fn eq_rmin(&self, other: &Self, tol: &f64) -> bool { self.0.eq_rmin(&other.0, tol) }
+/// #     fn eq_r1st(&self, other: &Self, tol: &f64) -> bool { self.0.eq_r1st(&other.0, tol) }
+/// #     fn eq_r2nd(&self, other: &Self, tol: &f64) -> bool { self.0.eq_r2nd(&other.0, tol) }
+/// #     fn eq_ulps(&self, other: &Self, tol: &u64) -> bool { self.0.eq_ulps(&other.0, tol) }
+/// # }
+///
+/// let mut levels = BTreeMap::new();
+/// levels.insert(Energy(0.3), "ground");
+/// levels.insert(Energy(0.7), "excited");
+///
+/// assert_eq!(levels.get(&Energy(0.1 + 0.2)), None);
+/// assert_eq!(levels.get_float_key(&Energy(0.1 + 0.2), 1e-12), Some(&"ground"));
+/// assert_eq!(levels.get_float_key(&Energy(0.5), 1e-12), None);
+///
+/// // Other checks may be used by providing them explicitly
+/// let found = levels.get_float_key_by(&Energy(0.1 + 0.2), 1, |a, b, tol| a.eq_ulps(b, tol));
+/// assert_eq!(found, Some(&"ground"));
+/// ```
+#[cfg(feature = "std")]
+pub trait FloatEqMapExt<K, V> {
+    /// The value of the first key that is equal to `k`, using an [absolute
+    /// tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[inline]
+    fn get_float_key<Q>(&self, k: &Q, tol: <K as FloatEq<Q>>::Tol) -> Option<&V>
+    where
+        K: FloatEq<Q>,
+        <K as FloatEq<Q>>::Tol: Sized,
+    {
+        self.get_float_key_by(k, tol, FloatEq::eq_abs)
+    }
+
+    /// The value of the first key that is equal to `k`, using `check` to
+    /// decide whether they are equal given `tol`.
+    fn get_float_key_by<Q, Tol, F>(&self, k: &Q, tol: Tol, check: F) -> Option<&V>
+    where
+        Q: ?Sized,
+        F: FnMut(&K, &Q, &Tol) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> FloatEqMapExt<K, V> for std::collections::HashMap<K, V, S> {
+    #[inline]
+    fn get_float_key_by<Q, Tol, F>(&self, k: &Q, tol: Tol, mut check: F) -> Option<&V>
+    where
+        Q: ?Sized,
+        F: FnMut(&K, &Q, &Tol) -> bool,
+    {
+        self.iter()
+            .find(|(key, _)| check(key, k, &tol))
+            .map(|(_, v)| v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V> FloatEqMapExt<K, V> for std::collections::BTreeMap<K, V> {
+    #[inline]
+    fn get_float_key_by<Q, Tol, F>(&self, k: &Q, tol: Tol, mut check: F) -> Option<&V>
+    where
+        Q: ?Sized,
+        F: FnMut(&K, &Q, &Tol) -> bool,
+    {
+        self.iter()
+            .find(|(key, _)| check(key, k, &tol))
+            .map(|(_, v)| v)
+    }
+}
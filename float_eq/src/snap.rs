@@ -0,0 +1,138 @@
+use crate::FloatEq;
+#[cfg(feature = "std")]
+use crate::FloatPrimitive;
+
+/// Replace `x` with `target` if they are equal within an [absolute tolerance
+/// comparison], otherwise return `x` unchanged.
+///
+/// This is useful for cleaning up values that should be exact, such as the
+/// coordinates of a vertex that should lie on an axis, but have picked up
+/// round-off error along the way. It works with any type that may be compared,
+/// such as arrays and tuples, in which case the whole value is replaced.
+///
+/// # Examples
+/// ```
+/// # use float_eq::snap_to;
+/// assert_eq!(snap_to(0.1 + 0.2, 0.3, 1e-12), 0.3);
+/// assert_eq!(snap_to(0.31, 0.3, 1e-12), 0.31);
+/// assert_eq!(snap_to([1e-17, 1.0], [0.0, 1.0], [1e-12; 2]), [0.0, 1.0]);
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+#[inline]
+pub fn snap_to<T>(x: T, target: T, tol: T::Tol) -> T
+where
+    T: FloatEq,
+    T::Tol: Sized,
+{
+    snap_to_by(x, target, tol, FloatEq::eq_abs)
+}
+
+/// Replace `x` with `target` if `check` finds them equal given `tol`,
+/// otherwise return `x` unchanged.
+///
+/// See [`snap_to`] for details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{snap_to_by, FloatEq};
+/// let x = f32::from_bits(1.0f32.to_bits() + 1);
+/// assert_eq!(snap_to_by(x, 1.0, 1, |a, b, tol| a.eq_ulps(b, tol)), 1.0);
+/// ```
+///
+/// [`snap_to`]: fn.snap_to.html
+#[inline]
+pub fn snap_to_by<T, Tol, F>(x: T, target: T, tol: Tol, mut check: F) -> T
+where
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    if check(&x, &target, &tol) {
+        target
+    } else {
+        x
+    }
+}
+
+/// Replace `x` with the nearest multiple of `step` if they are equal within an
+/// [absolute tolerance comparison], otherwise return `x` unchanged.
+///
+/// The nearest grid point is calculated as `(x / step).round() * step`, so if
+/// `step` is not exactly representable, such as `0.1`, it is subject to the
+/// same round-off as any other multiplication. Values half-way between grid
+/// points are snapped away from zero, if the tolerance allows it. Only
+/// available in `std` builds.
+///
+/// # Examples
+/// ```
+/// # use float_eq::snap_to_grid;
+/// assert_eq!(snap_to_grid(2.999_999_999_999_999_6, 0.5, 1e-12), 3.0);
+/// assert_eq!(snap_to_grid(2.500_000_1f32, 0.5, 1e-6), 2.5);
+/// assert_eq!(snap_to_grid(2.6f32, 0.5, 1e-6), 2.6);
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+#[cfg(feature = "std")]
+#[inline]
+pub fn snap_to_grid<T>(x: T, step: T, tol: T) -> T
+where
+    T: FloatPrimitive + FloatEq<Tol = T>,
+{
+    snap_to_grid_by(x, step, tol, FloatEq::eq_abs)
+}
+
+/// Replace `x` with the nearest multiple of `step` if `check` finds them equal
+/// given `tol`, otherwise return `x` unchanged.
+///
+/// See [`snap_to_grid`] for details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{snap_to_grid_by, FloatEq};
+/// assert_eq!(snap_to_grid_by(0.749_999_9f32, 0.25, 2, |a, b, tol| a.eq_ulps(b, tol)), 0.75);
+/// ```
+///
+/// [`snap_to_grid`]: fn.snap_to_grid.html
+#[cfg(feature = "std")]
+#[inline]
+pub fn snap_to_grid_by<T, Tol, F>(x: T, step: T, tol: Tol, check: F) -> T
+where
+    T: FloatPrimitive,
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    let nearest = (x / step).round_value() * step;
+    snap_to_by(x, nearest, tol, check)
+}
+
+/// Clamp `x` to the range `min..=max` if it lies outside it but is equal to
+/// the nearest endpoint within an [absolute tolerance comparison], otherwise
+/// return `x` unchanged.
+///
+/// Unlike clamping, values that are outside the range by more than the
+/// tolerance are left alone, so that a genuine error is not hidden. This suits
+/// values such as probabilities that round-off has pushed just past their
+/// bounds.
+///
+/// # Examples
+/// ```
+/// # use float_eq::snap_into;
+/// assert_eq!(snap_into(1.000_000_000_000_000_2, 0.0, 1.0, 1e-12), 1.0);
+/// assert_eq!(snap_into(-1e-17, 0.0, 1.0, 1e-12), 0.0);
+/// assert_eq!(snap_into(0.5, 0.0, 1.0, 1e-12), 0.5);
+/// assert_eq!(snap_into(1.5, 0.0, 1.0, 1e-12), 1.5);
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+#[inline]
+pub fn snap_into<T>(x: T, min: T, max: T, tol: T::Tol) -> T
+where
+    T: PartialOrd + FloatEq,
+    T::Tol: Sized,
+{
+    if x < min {
+        snap_to(x, min, tol)
+    } else if x > max {
+        snap_to(x, max, tol)
+    } else {
+        x
+    }
+}
@@ -0,0 +1,113 @@
+use crate::FloatEq;
+use core::cmp::Ordering;
+
+/// Iterator over the inversions in a slice that should be sorted in ascending
+/// order, ignoring ties within a tolerance.
+///
+/// Created by [`sort_inversions`] and [`sort_inversions_by`].
+///
+/// [`sort_inversions`]: fn.sort_inversions.html
+/// [`sort_inversions_by`]: fn.sort_inversions_by.html
+#[derive(Clone, Debug)]
+pub struct SortInversions<'a, T, Tol, F> {
+    values: &'a [T],
+    tol: Tol,
+    check: F,
+    max: Option<usize>,
+    index: usize,
+}
+
+impl<T, Tol, F> Iterator for SortInversions<'_, T, Tol, F>
+where
+    T: PartialOrd,
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(value) = self.values.get(self.index) {
+            let index = self.index;
+            self.index += 1;
+            let ordering = match self.max {
+                Some(max) => value.partial_cmp(&self.values[max]),
+                None => value.partial_cmp(value),
+            };
+            match (self.max, ordering) {
+                (Some(max), Some(Ordering::Less))
+                    if !(self.check)(&self.values[max], value, &self.tol) =>
+                {
+                    return Some((max, index));
+                }
+                (None, Some(_)) | (Some(_), Some(Ordering::Greater)) => self.max = Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// The inversions in a slice that should be sorted in ascending order, where
+/// values that are equal within an [absolute tolerance comparison] may appear
+/// in either order.
+///
+/// This suits checking the output of a sort by a score that is subject to
+/// round-off, such as one computed in parallel, where near ties may be swapped
+/// from run to run. Each inversion is a pair of indices `(i, j)` with `i < j`,
+/// where the element at `j` is less than the largest element before it, at
+/// `i`, by more than the tolerance. Each element is reported in at most one
+/// inversion. NaNs are unordered, so they are ignored.
+///
+/// # Examples
+/// ```
+/// # use float_eq::sort_inversions;
+/// let scores = [0.1f64, 0.300_000_000_000_000_04, 0.3, 0.5, 0.2, 0.6];
+///
+/// let inversions: Vec<_> = sort_inversions(&scores, 1e-12).collect();
+/// assert_eq!(inversions, vec![(3, 4)]);
+/// ```
+///
+/// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+#[allow(clippy::type_complexity)]
+pub fn sort_inversions<T>(
+    values: &[T],
+    tol: T::Tol,
+) -> SortInversions<'_, T, T::Tol, fn(&T, &T, &T::Tol) -> bool>
+where
+    T: PartialOrd + FloatEq,
+    T::Tol: Sized,
+{
+    sort_inversions_by(values, tol, FloatEq::eq_abs)
+}
+
+/// The inversions in a slice that should be sorted in ascending order, where
+/// values that `check` finds equal given `tol` may appear in either order.
+///
+/// See [`sort_inversions`] for details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{sort_inversions_by, FloatEq};
+/// let scores = [1.0f32, 1.000_000_1, 1.0, 0.999_999];
+///
+/// let inversions: Vec<_> = sort_inversions_by(&scores, 1, |a, b, tol| a.eq_ulps(b, tol)).collect();
+/// assert_eq!(inversions, vec![(1, 3)]);
+/// ```
+///
+/// [`sort_inversions`]: fn.sort_inversions.html
+pub fn sort_inversions_by<T, Tol, F>(
+    values: &[T],
+    tol: Tol,
+    check: F,
+) -> SortInversions<'_, T, Tol, F>
+where
+    T: PartialOrd,
+    F: FnMut(&T, &T, &Tol) -> bool,
+{
+    SortInversions {
+        values,
+        tol,
+        check,
+        max: None,
+        index: 0,
+    }
+}
@@ -0,0 +1,443 @@
+use crate::FailureReport;
+use core::fmt;
+
+/// A single value within the operands of a failed assert, such as one field of
+/// a struct, along with the matching values of the assert's other fields.
+///
+/// Listed by [`FailureReport::rows`], which breaks the `fmt::Debug`
+/// representations of composite values down into the individual values they
+/// hold, so that the ones that were not within any tolerance may be picked out.
+/// This is also how the [`FailureFormat::Table`] layout decides which values
+/// to show.
+///
+/// [`FailureReport::rows`]: struct.FailureReport.html#method.rows
+/// [`FailureFormat::Table`]: enum.FailureFormat.html#variant.Table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailureRow {
+    /// The path to the value within the operands, such as `velocity`, `[2]`
+    /// or `pos.x`. It is empty if the operands hold a single value, such as a
+    /// primitive.
+    pub path: String,
+    /// The labelled value at this path within each of the report's fields, in
+    /// the same order. The value is empty if a field has no value at the path.
+    pub values: Vec<(String, String)>,
+    /// Whether the value was not within any of the tolerances checked. This is
+    /// also true if it could not be determined, such as for `bits` checks.
+    pub failed: bool,
+}
+
+impl FailureRow {
+    /// The value at this row's path within the field with the given label, if
+    /// the field is present.
+    pub fn value(&self, label: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn failed(values: &[(String, String)]) -> bool {
+        let value = |label| {
+            values
+                .iter()
+                .find(|(l, _)| l == label)
+                .map(|(_, value)| value.as_str())
+        };
+        let abs_diff = value("abs_diff").and_then(|d| d.parse::<f64>().ok());
+        let ulps_diff = value("ulps_diff").and_then(|d| {
+            let d = d.strip_prefix("Some(").and_then(|d| d.strip_suffix(')'))?;
+            d.parse::<u64>().ok()
+        });
+        let within = |label: &str, tol: &str| {
+            let check = match label.strip_prefix('[').and_then(|l| l.strip_suffix("] t")) {
+                Some(check) => check,
+                None => return false,
+            };
+            if check.starts_with("ulps") {
+                matches!((ulps_diff, tol.parse::<u64>()), (Some(d), Ok(t)) if d <= t)
+            } else if check.starts_with("bits") {
+                false
+            } else {
+                matches!((abs_diff, tol.parse::<f64>()), (Some(d), Ok(t)) if d <= t)
+            }
+        };
+        !values.iter().any(|(label, tol)| within(label, tol))
+    }
+}
+
+impl FailureReport {
+    /// The individual values held by the operands, one row per value, in the
+    /// order they appear in `left`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::{assert_float_eq, FailurePayload, FailureReport};
+    /// FailurePayload::Report.set_current();
+    ///
+    /// let result = std::panic::catch_unwind(|| {
+    ///     assert_float_eq!([1.0f32, 2.0], [1.0, 2.5], abs_all <= 0.1);
+    /// });
+    /// let payload = result.unwrap_err();
+    /// let report = payload.downcast_ref::<FailureReport>().unwrap();
+    ///
+    /// let rows = report.rows();
+    /// assert_eq!(rows.len(), 2);
+    /// assert!(!rows[0].failed);
+    /// assert!(rows[1].failed);
+    /// assert_eq!(rows[1].path, "[1]");
+    /// assert_eq!(rows[1].value("abs_diff"), Some("0.5"));
+    /// # FailurePayload::Message.set_current();
+    /// ```
+    pub fn rows(&self) -> Vec<FailureRow> {
+        let flattened: Vec<Vec<(String, &str)>> = self
+            .fields
+            .iter()
+            .map(|(_, value)| flatten(value))
+            .collect();
+        let paths = flattened.first().map(Vec::as_slice).unwrap_or(&[]);
+        paths
+            .iter()
+            .map(|(path, _)| {
+                let values: Vec<(String, String)> = self
+                    .fields
+                    .iter()
+                    .zip(&flattened)
+                    .map(|((label, _), values)| {
+                        let value = values
+                            .iter()
+                            .find(|(p, _)| p == path)
+                            .map_or("", |(_, value)| value);
+                        (label.clone(), value.to_owned())
+                    })
+                    .collect();
+                FailureRow {
+                    path: path.clone(),
+                    failed: FailureRow::failed(&values),
+                    values,
+                }
+            })
+            .collect()
+    }
+
+    /// Writes the report as a table of the values that failed, as described by
+    /// [`FailureFormat::Table`](enum.FailureFormat.html#variant.Table).
+    pub(crate) fn fmt_table(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.rows();
+        let is_eq = self.check.starts_with("float_eq!");
+        let failed = rows.iter().filter(|row| row.failed).count();
+        let shown: Vec<&FailureRow> = if is_eq && failed > 0 {
+            rows.iter().filter(|row| row.failed).collect()
+        } else {
+            rows.iter().collect()
+        };
+        let color = Color::current();
+
+        write!(f, "assertion failed: `{}`", self.check)?;
+        if is_eq && failed > 0 && rows.len() > 1 {
+            write!(f, "\n  {} of {} values differ:", failed, rows.len())?;
+        }
+
+        let has_paths = shown.iter().any(|row| !row.path.is_empty());
+        let mut header: Vec<&str> = Vec::new();
+        if has_paths {
+            header.push("path");
+        }
+        header.extend(self.fields.iter().map(|(label, _)| label.as_str()));
+        let cells: Vec<Vec<&str>> = shown
+            .iter()
+            .map(|row| {
+                let path = Some(row.path.as_str()).filter(|_| has_paths);
+                path.into_iter()
+                    .chain(row.values.iter().map(|(_, value)| value.as_str()))
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|i| {
+                cells
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(Some(header[i].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        f.write_str("\n ")?;
+        write_cells(f, &header, &widths, color.header())?;
+        for (row, cells) in shown.iter().zip(&cells) {
+            f.write_str("\n ")?;
+            let style = if is_eq && row.failed {
+                color.failed()
+            } else {
+                ""
+            };
+            write_cells(f, cells, &widths, style)?;
+        }
+
+        if let Some(reason) = self.ulps_diff_reason {
+            write!(f, "\n  ulps_diff: {}", reason)?;
+        }
+        if let Some(note) = &self.note {
+            write!(f, "\n  note: {}", note)?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, "\n  message: {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_cells(
+    f: &mut fmt::Formatter<'_>,
+    cells: &[&str],
+    widths: &[usize],
+    style: &str,
+) -> fmt::Result {
+    for (i, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        f.write_str(" ")?;
+        if !style.is_empty() {
+            f.write_str(style)?;
+        }
+        // The last column is not padded, to avoid trailing whitespace.
+        if i + 1 == cells.len() {
+            f.write_str(cell)?;
+        } else {
+            write!(f, "{:<width$}", cell, width = width + 1)?;
+        }
+        if !style.is_empty() {
+            f.write_str(RESET)?;
+        }
+    }
+    Ok(())
+}
+
+const RESET: &str = "\x1b[0m";
+
+// Tables are only colored if the `pretty` feature is enabled, and then not if
+// the `NO_COLOR` environment variable is set.
+#[derive(Clone, Copy)]
+struct Color(bool);
+
+impl Color {
+    fn current() -> Self {
+        Color(cfg!(feature = "pretty") && std::env::var_os("NO_COLOR").is_none())
+    }
+
+    fn header(self) -> &'static str {
+        if self.0 {
+            "\x1b[1m"
+        } else {
+            ""
+        }
+    }
+
+    fn failed(self) -> &'static str {
+        if self.0 {
+            "\x1b[31m"
+        } else {
+            ""
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// Breaking down debug representations
+//------------------------------------------------------------------------------
+
+/// The individual values in a `fmt::Debug` representation, with their paths.
+/// If it cannot be broken down, it is a single value with an empty path.
+fn flatten(text: &str) -> Vec<(String, &str)> {
+    let mut parser = Parser { text, pos: 0 };
+    let mut values = Vec::new();
+    match parser.value() {
+        Some(node) if parser.at_end() => node.flatten(String::new(), &mut values),
+        _ => values.push((String::new(), text.trim())),
+    }
+    values
+}
+
+enum Node<'a> {
+    Value(&'a str),
+    Items(Vec<(Key<'a>, Node<'a>)>),
+}
+
+enum Key<'a> {
+    Field(&'a str),
+    Index(usize),
+    Entry(&'a str),
+}
+
+impl<'a> Node<'a> {
+    fn flatten(self, path: String, values: &mut Vec<(String, &'a str)>) {
+        match self {
+            Node::Value(value) => values.push((path, value)),
+            Node::Items(items) => {
+                for (key, node) in items {
+                    let path = match key {
+                        Key::Field(name) if path.is_empty() => name.to_owned(),
+                        Key::Field(name) => format!("{}.{}", path, name),
+                        Key::Index(i) => format!("{}[{}]", path, i),
+                        Key::Entry(key) => format!("{}[{}]", path, key),
+                    };
+                    node.flatten(path, values);
+                }
+            }
+        }
+    }
+}
+
+// A parser for the compact debug representations written by derived `Debug`
+// impls and the standard library's collections. Anything else is treated as a
+// single value. Delimiters are all ASCII, so slicing at them is always on a
+// char boundary.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        self.skip_spaces();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_spaces();
+        self.pos == self.text.len()
+    }
+
+    fn value(&mut self) -> Option<Node<'a>> {
+        self.skip_spaces();
+        let start = self.pos;
+        match self.peek()? {
+            b'[' => {
+                self.pos += 1;
+                let items = self.items(b']', |p, i| Some((Key::Index(i), p.value()?)))?;
+                Some(Node::Items(items))
+            }
+            b'(' => {
+                self.pos += 1;
+                let items = self.items(b')', |p, i| Some((Key::Index(i), p.value()?)))?;
+                Some(Node::Items(items))
+            }
+            b'{' => {
+                self.pos += 1;
+                let items = self.items(b'}', |p, _| {
+                    p.skip_spaces();
+                    let key_start = p.pos;
+                    p.value()?;
+                    let key = p.text[key_start..p.pos].trim_end();
+                    if !p.eat(b':') {
+                        return None;
+                    }
+                    Some((Key::Entry(key), p.value()?))
+                })?;
+                Some(Node::Items(items))
+            }
+            b'"' | b'\'' => {
+                self.quoted()?;
+                Some(Node::Value(&self.text[start..self.pos]))
+            }
+            _ => {
+                let name = self.word()?;
+                if self.peek() == Some(b'(') {
+                    self.pos += 1;
+                    let mut items = self.items(b')', |p, i| Some((Key::Index(i), p.value()?)))?;
+                    // Wrappers such as `Some(...)` are transparent, and are
+                    // kept whole around a single value.
+                    if items.len() == 1 {
+                        return Some(match items.pop()?.1 {
+                            Node::Value(_) => Node::Value(&self.text[start..self.pos]),
+                            node => node,
+                        });
+                    }
+                    return Some(Node::Items(items));
+                }
+                let before_brace = self.pos;
+                if self.eat(b'{') {
+                    let items = self.items(b'}', |p, _| {
+                        p.skip_spaces();
+                        let field = p.word()?;
+                        if !p.eat(b':') {
+                            return None;
+                        }
+                        Some((Key::Field(field), p.value()?))
+                    })?;
+                    return Some(Node::Items(items));
+                }
+                self.pos = before_brace;
+                Some(Node::Value(name))
+            }
+        }
+    }
+
+    // The items of a sequence, up to and including its closing delimiter.
+    fn items<T>(
+        &mut self,
+        close: u8,
+        mut item: impl FnMut(&mut Self, usize) -> Option<T>,
+    ) -> Option<Vec<T>> {
+        let mut items = Vec::new();
+        if self.eat(close) {
+            return Some(items);
+        }
+        loop {
+            items.push(item(self, items.len())?);
+            if self.eat(close) {
+                return Some(items);
+            }
+            if !self.eat(b',') {
+                return None;
+            }
+        }
+    }
+
+    // A run of characters up to the next delimiter, which may not be empty.
+    fn word(&mut self) -> Option<&'a str> {
+        let rest = self.rest();
+        let len = rest
+            .find([' ', ',', ':', '(', ')', '[', ']', '{', '}'])
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    // A string or char literal, including its quotes.
+    fn quoted(&mut self) -> Option<()> {
+        let rest = self.rest();
+        let quote = rest.chars().next()?;
+        let mut chars = rest.char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            if c == '\\' {
+                chars.next()?;
+            } else if c == quote {
+                self.pos += i + c.len_utf8();
+                return Some(());
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,263 @@
+/// A pair of floats along with the smallest tolerances for which they compare
+/// equal, used to validate implementations of the comparison traits.
+///
+/// Each tolerance is the tightest one for which the corresponding check
+/// passes, so that the check must also fail for any smaller tolerance. It is
+/// `None` if no tolerance is large enough, such as when either value is NaN,
+/// or for a `ulps` check between values of differing signs.
+///
+/// The `rmax` tolerance is scaled by the larger magnitude before it is
+/// compared, and that product is rounded. Outside of the subnormal range, the
+/// larger magnitude of each pair is a power of two, so the tolerance is simply
+/// the relative difference. Within it, the product loses precision, so the
+/// tolerance is whatever makes it round up to the absolute difference, which
+/// is rarely what a naive calculation gives.
+///
+/// # Examples
+/// Validating a wrapper type against the corpus that `f32` is tested with:
+/// ```
+/// # use float_eq::{FloatEq, F32_TEST_VECTORS};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Meters(f32);
+///
+/// impl Meters {
+///     fn eq_abs(&self, other: &Self, tol: f32) -> bool {
+///         self.0.eq_abs(&other.0, &tol)
+///     }
+/// }
+///
+/// for v in F32_TEST_VECTORS {
+///     let (a, b) = (Meters(v.a), Meters(v.b));
+///     match v.abs {
+///         Some(tol) => assert!(a.eq_abs(&b, tol), "{:?}", v),
+///         None => assert!(!a.eq_abs(&b, f32::INFINITY), "{:?}", v),
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestVector<T, U> {
+    /// The first value, used as the left hand side of checks.
+    pub a: T,
+    /// The second value, used as the right hand side of checks.
+    pub b: T,
+    /// The smallest `abs` tolerance for which `a` and `b` are equal.
+    pub abs: Option<T>,
+    /// The smallest `rmax` tolerance for which `a` and `b` are equal.
+    pub rmax: Option<T>,
+    /// The smallest `ulps` tolerance for which `a` and `b` are equal.
+    pub ulps: Option<U>,
+}
+
+/// Adversarial pairs of `f32` values, covering zeros, subnormals, the
+/// boundaries of the exponent range, infinities and NaN.
+///
+/// See [`TestVector`] for how they are used.
+///
+/// [`TestVector`]: struct.TestVector.html
+pub const F32_TEST_VECTORS: &[TestVector<f32, u32>] = &[
+    // identical and signed zeros
+    v32(1.0, 1.0, Some(0.0), Some(0.0), Some(0)),
+    v32(0.0, -0.0, Some(0.0), Some(0.0), Some(0)),
+    v32(-0.0, 0.0, Some(0.0), Some(0.0), Some(0)),
+    // neighbours of powers of two
+    v32(
+        1.0,
+        0.999_999_94,
+        Some(5.960_464_5e-8),
+        Some(5.960_464_5e-8),
+        Some(1),
+    ),
+    v32(
+        2.0,
+        1.999_999_9,
+        Some(1.192_092_9e-7),
+        Some(5.960_464_5e-8),
+        Some(1),
+    ),
+    v32(
+        -1.999_999_9,
+        -2.0,
+        Some(1.192_092_9e-7),
+        Some(5.960_464_5e-8),
+        Some(1),
+    ),
+    v32(1.0, 2.0, Some(1.0), Some(0.5), Some(8_388_608)),
+    v32(-1.0, -0.5, Some(0.5), Some(0.5), Some(8_388_608)),
+    // differing signs
+    v32(1.0, -1.0, Some(2.0), Some(2.0), None),
+    v32(-0.0, 1e-45, Some(1e-45), Some(0.500_000_06), None),
+    // subnormals
+    v32(0.0, 1e-45, Some(1e-45), Some(0.500_000_06), Some(1)),
+    v32(1e-45, 3e-45, Some(1e-45), Some(0.250_000_03), Some(1)),
+    v32(1e-45, -1e-45, Some(3e-45), Some(1.5), None),
+    v32(
+        1.175_494_4e-38,
+        1.175_494_2e-38,
+        Some(1e-45),
+        Some(5.960_465e-8),
+        Some(1),
+    ),
+    // top of the exponent range
+    v32(
+        1.701_411_8e38,
+        1.701_411_7e38,
+        Some(1.014_120_5e31),
+        Some(5.960_464_5e-8),
+        Some(1),
+    ),
+    // infinities
+    v32(f32::INFINITY, f32::INFINITY, Some(0.0), Some(0.0), Some(0)),
+    v32(
+        f32::MAX,
+        f32::INFINITY,
+        Some(f32::INFINITY),
+        Some(1e-45),
+        Some(1),
+    ),
+    v32(
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        Some(f32::INFINITY),
+        Some(1e-45),
+        None,
+    ),
+    // NaN
+    v32(f32::NAN, f32::NAN, None, None, None),
+    v32(f32::NAN, 1.0, None, None, None),
+    v32(0.0, f32::NAN, None, None, None),
+];
+
+/// Adversarial pairs of `f64` values, covering zeros, subnormals, the
+/// boundaries of the exponent range, infinities and NaN.
+///
+/// See [`TestVector`] for how they are used.
+///
+/// [`TestVector`]: struct.TestVector.html
+pub const F64_TEST_VECTORS: &[TestVector<f64, u64>] = &[
+    // identical and signed zeros
+    v64(1.0, 1.0, Some(0.0), Some(0.0), Some(0)),
+    v64(0.0, -0.0, Some(0.0), Some(0.0), Some(0)),
+    v64(-0.0, 0.0, Some(0.0), Some(0.0), Some(0)),
+    // neighbours of powers of two
+    v64(
+        1.0,
+        0.999_999_999_999_999_9,
+        Some(1.110_223_024_625_156_5e-16),
+        Some(1.110_223_024_625_156_5e-16),
+        Some(1),
+    ),
+    v64(
+        2.0,
+        1.999_999_999_999_999_8,
+        Some(2.220_446_049_250_313e-16),
+        Some(1.110_223_024_625_156_5e-16),
+        Some(1),
+    ),
+    v64(
+        -1.999_999_999_999_999_8,
+        -2.0,
+        Some(2.220_446_049_250_313e-16),
+        Some(1.110_223_024_625_156_5e-16),
+        Some(1),
+    ),
+    v64(1.0, 2.0, Some(1.0), Some(0.5), Some(4_503_599_627_370_496)),
+    v64(
+        -1.0,
+        -0.5,
+        Some(0.5),
+        Some(0.5),
+        Some(4_503_599_627_370_496),
+    ),
+    // differing signs
+    v64(1.0, -1.0, Some(2.0), Some(2.0), None),
+    v64(
+        -0.0,
+        5e-324,
+        Some(5e-324),
+        Some(0.500_000_000_000_000_1),
+        None,
+    ),
+    // subnormals
+    v64(
+        0.0,
+        5e-324,
+        Some(5e-324),
+        Some(0.500_000_000_000_000_1),
+        Some(1),
+    ),
+    v64(
+        5e-324,
+        1e-323,
+        Some(5e-324),
+        Some(0.250_000_000_000_000_06),
+        Some(1),
+    ),
+    v64(5e-324, -5e-324, Some(1e-323), Some(1.5), None),
+    v64(
+        2.225_073_858_507_201_4e-308,
+        2.225_073_858_507_201e-308,
+        Some(5e-324),
+        Some(1.110_223_024_625_156_8e-16),
+        Some(1),
+    ),
+    // top of the exponent range
+    v64(
+        8.988_465_674_311_58e307,
+        8.988_465_674_311_579e307,
+        Some(9.979_201_547_673_6e291),
+        Some(1.110_223_024_625_156_5e-16),
+        Some(1),
+    ),
+    // infinities
+    v64(f64::INFINITY, f64::INFINITY, Some(0.0), Some(0.0), Some(0)),
+    v64(
+        f64::MAX,
+        f64::INFINITY,
+        Some(f64::INFINITY),
+        Some(5e-324),
+        Some(1),
+    ),
+    v64(
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        Some(f64::INFINITY),
+        Some(5e-324),
+        None,
+    ),
+    // NaN
+    v64(f64::NAN, f64::NAN, None, None, None),
+    v64(f64::NAN, 1.0, None, None, None),
+    v64(0.0, f64::NAN, None, None, None),
+];
+
+const fn v32(
+    a: f32,
+    b: f32,
+    abs: Option<f32>,
+    rmax: Option<f32>,
+    ulps: Option<u32>,
+) -> TestVector<f32, u32> {
+    TestVector {
+        a,
+        b,
+        abs,
+        rmax,
+        ulps,
+    }
+}
+
+const fn v64(
+    a: f64,
+    b: f64,
+    abs: Option<f64>,
+    rmax: Option<f64>,
+    ulps: Option<u64>,
+) -> TestVector<f64, u64> {
+    TestVector {
+        a,
+        b,
+        abs,
+        rmax,
+        ulps,
+    }
+}
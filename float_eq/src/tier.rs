@@ -0,0 +1,50 @@
+/// Which of the two tolerances given to [`float_eq_tiered!`] and
+/// [`assert_float_eq_tiered!`] is checked.
+///
+/// Tiered checks name both a strict tolerance, which is what a test should be
+/// held to, and a lenient one, which is good enough whilst an algorithm is
+/// still being prototyped. The strict tier is used by default. Enabling the
+/// `lenient` feature selects the lenient tier instead, and in `std` builds,
+/// setting the `FLOAT_EQ_TIER` environment variable to `strict` or `lenient`
+/// overrides the feature, so that CI may always run strict regardless of how
+/// the crate was built.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq_tiered, ToleranceTier};
+/// let a = 1.0f64;
+/// let b = 1.000_1;
+///
+/// let is_eq = float_eq_tiered!(a, b, strict: ulps <= 4, lenient: abs <= 0.001);
+/// assert_eq!(is_eq, ToleranceTier::current() == ToleranceTier::Lenient);
+/// ```
+///
+/// [`float_eq_tiered!`]: macro.float_eq_tiered.html
+/// [`assert_float_eq_tiered!`]: macro.assert_float_eq_tiered.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ToleranceTier {
+    /// The tolerance that a test should ultimately be held to.
+    Strict,
+    /// A looser tolerance, for use whilst prototyping.
+    Lenient,
+}
+
+impl ToleranceTier {
+    /// The tier selected by the `FLOAT_EQ_TIER` environment variable, if it is
+    /// set to `strict` or `lenient`, or else by the `lenient` feature.
+    pub fn current() -> Self {
+        #[cfg(feature = "std")]
+        {
+            match std::env::var_os("FLOAT_EQ_TIER") {
+                Some(v) if v == "strict" => return ToleranceTier::Strict,
+                Some(v) if v == "lenient" => return ToleranceTier::Lenient,
+                _ => {}
+            }
+        }
+        if cfg!(feature = "lenient") {
+            ToleranceTier::Lenient
+        } else {
+            ToleranceTier::Strict
+        }
+    }
+}
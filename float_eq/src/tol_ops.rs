@@ -0,0 +1,155 @@
+/// Elementwise arithmetic on tolerances, so that tolerance presets for
+/// composite types may be merged and adjusted without writing code for each
+/// field.
+///
+/// This is implemented for the tolerance types of the primitives, arrays,
+/// tuples and feature-gated composite types in this crate. It may be derived
+/// for any struct whose fields implement it, which includes those types that
+/// derive `FloatEq`, since they are their own tolerance type.
+///
+/// # Examples
+/// ```
+/// # use float_eq::TolOps;
+/// let preset = [0.1, 0.001, 0.5];
+/// let other = [0.01, 0.01, 0.01];
+///
+/// // Take the looser of the two presets.
+/// assert_eq!(preset.max_elementwise(&other), [0.1, 0.01, 0.5]);
+/// assert_eq!(preset.min_elementwise(&other), [0.01, 0.001, 0.01]);
+/// assert_eq!(other.scale(2.0), [0.02, 0.02, 0.02]);
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// # use float_eq::{derive_float_eq, TolOps};
+/// #[derive_float_eq(
+///     ulps_tol = "PointUlps",
+///     debug_ulps_diff = "PointDebugUlpsDiff",
+///     debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+/// )]
+/// #[derive(Debug, PartialEq, Clone, Copy, TolOps)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let strict = Point { x: 0.001, y: 0.5 };
+/// let lenient = Point { x: 0.25, y: 0.25 };
+/// assert_eq!(strict.max_elementwise(&lenient), Point { x: 0.25, y: 0.5 });
+/// assert_eq!(strict.min_elementwise(&lenient), Point { x: 0.001, y: 0.25 });
+/// assert_eq!(lenient.scale(4.0), Point { x: 1.0, y: 1.0 });
+/// # }
+/// ```
+pub trait TolOps: Sized {
+    /// The larger of each tolerance in `self` and the corresponding one in
+    /// `other`.
+    fn max_elementwise(&self, other: &Self) -> Self;
+
+    /// The smaller of each tolerance in `self` and the corresponding one in
+    /// `other`.
+    fn min_elementwise(&self, other: &Self) -> Self;
+
+    /// Each tolerance in `self` multiplied by `factor`.
+    fn scale(&self, factor: f64) -> Self;
+}
+
+impl TolOps for f32 {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        f32::max(*self, *other)
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        f32::min(*self, *other)
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        (f64::from(*self) * factor) as f32
+    }
+}
+
+impl TolOps for f64 {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        f64::max(*self, *other)
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        f64::min(*self, *other)
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        *self * factor
+    }
+}
+
+impl<T: TolOps, const N: usize> TolOps for [T; N] {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].max_elementwise(&other[i]))
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].min_elementwise(&other[i]))
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        core::array::from_fn(|i| self[i].scale(factor))
+    }
+}
+
+impl TolOps for () {
+    #[inline]
+    fn max_elementwise(&self, _other: &Self) -> Self {}
+
+    #[inline]
+    fn min_elementwise(&self, _other: &Self) -> Self {}
+
+    #[inline]
+    fn scale(&self, _factor: f64) -> Self {}
+}
+
+macro_rules! impl_tol_ops_for_tuple {
+    ($(($($idx:tt $T:ident),+))+) => {
+        $(
+            impl<$($T: TolOps),+> TolOps for ($($T,)+) {
+                #[inline]
+                fn max_elementwise(&self, other: &Self) -> Self {
+                    ($(self.$idx.max_elementwise(&other.$idx),)+)
+                }
+
+                #[inline]
+                fn min_elementwise(&self, other: &Self) -> Self {
+                    ($(self.$idx.min_elementwise(&other.$idx),)+)
+                }
+
+                #[inline]
+                fn scale(&self, factor: f64) -> Self {
+                    ($(self.$idx.scale(factor),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_tol_ops_for_tuple! {
+    (0 A)
+    (0 A, 1 B)
+    (0 A, 1 B, 2 C)
+    (0 A, 1 B, 2 C, 3 D)
+    (0 A, 1 B, 2 C, 3 D, 4 E)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L)
+}
@@ -3,6 +3,10 @@ mod core_types;
 mod primitives;
 mod tuples;
 
+#[cfg(feature = "alloc")]
+mod alloc_types;
+#[cfg(feature = "alloc")]
+pub use self::alloc_types::*;
 #[cfg(feature = "std")]
 mod std_types;
 
@@ -10,3 +14,28 @@ mod std_types;
 mod num_complex;
 #[cfg(feature = "num")]
 pub use self::num_complex::*;
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "bigdecimal")]
+pub use self::bigdecimal::*;
+
+#[cfg(any(feature = "time", feature = "chrono"))]
+mod durations;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "glam")]
+mod glam;
+
+#[cfg(feature = "half")]
+mod half;
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "approx")]
+pub use self::approx::*;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
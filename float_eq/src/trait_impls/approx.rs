@@ -0,0 +1,253 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
+use crate::{CheckSet, FloatEq, FloatEqAll, FloatEqUlpsTol, UlpsTol};
+use approx::{RelativeEq, UlpsEq};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// Wraps a value of a type that implements the [`approx`] crate's comparison
+/// traits, so that it may be compared by [`float_eq!`] and [`assert_float_eq!`].
+///
+/// Many crates implement [`AbsDiffEq`], [`RelativeEq`] and [`UlpsEq`] for their
+/// types but not this crate's traits. Wrapping both operands is enough to use
+/// the equivalent checks, each of which calls through to the `approx` method
+/// that implements it, so values compare exactly as they would with `approx`:
+///
+/// - `abs <= tol` calls `abs_diff_eq(other, tol)`.
+/// - `rel <= tol` and `rmax <= tol` call `relative_eq(other, 0, tol)`, since
+///   `approx` scales its relative tolerance to the larger magnitude.
+/// - `ulps <= tol` calls `ulps_eq(other, 0, tol)`, saturating `tol` to `u32`.
+///
+/// The tolerances are of the wrapped type's [`AbsDiffEq::Epsilon`], which is
+/// usually the scalar type, and the `_all` checks are the same as the plain
+/// ones since `approx` already applies a single tolerance to every field. The
+/// `epsilon` passed to the relative and ULPs comparisons is the `Default` for
+/// that type, which is zero for floats, so that each check stands alone rather
+/// than falling back to an absolute comparison.
+///
+/// `approx` does not provide the differences between values, so when an assert
+/// fails it displays them as `()` and the tolerances as they were given.
+///
+/// # Panics
+///
+/// The `rmin`, `r1st` and `r2nd` checks have no equivalent in `approx`, and
+/// panic if they are used. They are left out of its [`SUPPORTED_CHECKS`], so
+/// that a [`FloatCmp`] including them skips them instead.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{float_eq, float_ne, FromApprox};
+/// // Any type implementing approx::RelativeEq and approx::UlpsEq, such as
+/// // nalgebra matrices, or as here slices of floats.
+/// let a = FromApprox(&[1.0f64, 2.0][..]);
+/// let b = FromApprox(&[1.000_000_1, 2.0][..]);
+///
+/// assert!(float_eq!(a, b, abs <= 1e-6));
+/// assert!(float_eq!(a, b, rmax <= 1e-6));
+/// assert!(float_ne!(a, b, ulps <= 4));
+/// ```
+///
+/// [`approx`]: https://docs.rs/approx/0.5
+/// [`AbsDiffEq`]: https://docs.rs/approx/0.5/approx/trait.AbsDiffEq.html
+/// [`AbsDiffEq::Epsilon`]: https://docs.rs/approx/0.5/approx/trait.AbsDiffEq.html#associatedtype.Epsilon
+/// [`RelativeEq`]: https://docs.rs/approx/0.5/approx/trait.RelativeEq.html
+/// [`UlpsEq`]: https://docs.rs/approx/0.5/approx/trait.UlpsEq.html
+/// [`float_eq!`]: macro.float_eq.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+/// [`SUPPORTED_CHECKS`]: trait.FloatEq.html#associatedconstant.SUPPORTED_CHECKS
+/// [`FloatCmp`]: struct.FloatCmp.html
+#[derive(Clone, Copy, Default, PartialEq)]
+#[repr(transparent)]
+pub struct FromApprox<T>(pub T);
+
+impl<T> From<T> for FromApprox<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        FromApprox(value)
+    }
+}
+
+// Displayed as the wrapped value, so that assert messages are the same as
+// they would be without the wrapper.
+impl<T: fmt::Debug> fmt::Debug for FromApprox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn unsupported(check: &str) -> ! {
+    panic!(
+        "FromApprox does not support the `{}` check, which has no equivalent in approx",
+        check
+    )
+}
+
+fn max_ulps<U: Copy + Into<u64>>(tol: &U) -> u32 {
+    u32::try_from((*tol).into()).unwrap_or(u32::MAX)
+}
+
+impl<T> FloatEq for FromApprox<T>
+where
+    T: RelativeEq + UlpsEq,
+    T::Epsilon: Clone + Default + FloatEqUlpsTol,
+    UlpsTol<T::Epsilon>: Copy + Into<u64>,
+{
+    type Tol = T::Epsilon;
+    const SUPPORTED_CHECKS: CheckSet = CheckSet::ABS.union(CheckSet::RMAX).union(CheckSet::ULPS);
+
+    #[inline]
+    fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.0.abs_diff_eq(&other.0, tol.clone())
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.0
+            .relative_eq(&other.0, T::Epsilon::default(), tol.clone())
+    }
+
+    fn eq_rmin(&self, _other: &Self, _tol: &Self::Tol) -> bool {
+        unsupported("rmin")
+    }
+
+    fn eq_r1st(&self, _other: &Self, _tol: &Self::Tol) -> bool {
+        unsupported("r1st")
+    }
+
+    fn eq_r2nd(&self, _other: &Self, _tol: &Self::Tol) -> bool {
+        unsupported("r2nd")
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.0
+            .ulps_eq(&other.0, T::Epsilon::default(), max_ulps(tol))
+    }
+}
+
+impl<T> FloatEqAll for FromApprox<T>
+where
+    T: RelativeEq + UlpsEq,
+    T::Epsilon: Clone + Default + FloatEqUlpsTol,
+    UlpsTol<T::Epsilon>: Copy + Into<u64>,
+{
+    type AllTol = T::Epsilon;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.eq_abs(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.eq_rmax(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.eq_rmin(other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.eq_r1st(other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.eq_r2nd(other, tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.eq_ulps(other, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEq for FromApprox<T>
+where
+    T: RelativeEq + UlpsEq,
+    T::Epsilon: Clone + Default + FloatEqUlpsTol + fmt::Debug,
+    UlpsTol<T::Epsilon>: Copy + Into<u64>,
+{
+    type DebugAbsDiff = ();
+    type DebugTol = T::Epsilon;
+
+    #[inline]
+    fn debug_abs_diff(&self, _other: &Self) {}
+
+    #[inline]
+    fn debug_ulps_diff(&self, _other: &Self) {}
+
+    #[inline]
+    fn debug_abs_tol(&self, _other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        tol.clone()
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, _other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        tol.clone()
+    }
+
+    fn debug_rmin_tol(&self, _other: &Self, _tol: &Self::Tol) -> Self::DebugTol {
+        unsupported("rmin")
+    }
+
+    fn debug_r1st_tol(&self, _other: &Self, _tol: &Self::Tol) -> Self::DebugTol {
+        unsupported("r1st")
+    }
+
+    fn debug_r2nd_tol(&self, _other: &Self, _tol: &Self::Tol) -> Self::DebugTol {
+        unsupported("r2nd")
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, _other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
+        *tol
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEqAll for FromApprox<T>
+where
+    T: RelativeEq + UlpsEq,
+    T::Epsilon: Clone + Default + FloatEqUlpsTol + fmt::Debug,
+    UlpsTol<T::Epsilon>: Copy + Into<u64>,
+{
+    type AllDebugTol = T::Epsilon;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.debug_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.debug_rmax_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.debug_rmin_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.debug_r1st_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.debug_r2nd_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Self,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol> {
+        self.debug_ulps_tol(other, tol)
+    }
+}
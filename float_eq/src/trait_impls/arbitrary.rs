@@ -0,0 +1,123 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::FailureFormat;
+use crate::{
+    DebugCategories, FloatCategory, FloatCmp, FloatEqUlpsTol, TestVector, ToleranceTier,
+    UlpsDiffReason, UlpsTol,
+};
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+use crate::{FailurePayload, FailureReport};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for FloatCategory {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            FloatCategory::Nan,
+            FloatCategory::Infinite,
+            FloatCategory::Subnormal,
+        ])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for UlpsDiffReason {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            UlpsDiffReason::Nan,
+            UlpsDiffReason::DifferentSigns,
+            UlpsDiffReason::Saturated,
+        ])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for DebugCategories {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(DebugCategories {
+            left: u.arbitrary()?,
+            right: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ToleranceTier {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[ToleranceTier::Strict, ToleranceTier::Lenient])?)
+    }
+}
+
+impl<'a, T, U> Arbitrary<'a> for TestVector<T, U>
+where
+    T: Arbitrary<'a>,
+    U: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(TestVector {
+            a: u.arbitrary()?,
+            b: u.arbitrary()?,
+            abs: u.arbitrary()?,
+            rmax: u.arbitrary()?,
+            ulps: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for FloatCmp<T>
+where
+    T: FloatEqUlpsTol + Arbitrary<'a>,
+    UlpsTol<T>: Sized + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut cmp = FloatCmp::new();
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.abs(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.rel(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.rmax(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.rmin(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.r1st(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.r2nd(tol);
+        }
+        if let Some(tol) = u.arbitrary()? {
+            cmp = cmp.ulps(tol);
+        }
+        Ok(cmp)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<'a> Arbitrary<'a> for FailureFormat {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            FailureFormat::Human,
+            FailureFormat::KeyValue,
+            FailureFormat::Table,
+        ])?)
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+impl<'a> Arbitrary<'a> for FailurePayload {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[FailurePayload::Message, FailurePayload::Report])?)
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "checks-only")))]
+impl<'a> Arbitrary<'a> for FailureReport {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(FailureReport {
+            check: u.arbitrary()?,
+            fields: u.arbitrary()?,
+            note: u.arbitrary()?,
+            ulps_diff_reason: u.arbitrary()?,
+            message: u.arbitrary()?,
+        })
+    }
+}
@@ -1,20 +1,9 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    CheckSet, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, FloatEqWeighted, UlpsTol,
 };
-use core::mem::MaybeUninit;
-
-// Uses the same technique as MaybeUninit::uninit_array.
-#[inline(always)]
-fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
-    unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
-}
-
-// Uses the same technique as MaybeUninit::array_assume_init.
-#[inline(always)]
-unsafe fn array_assume_init<T, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
-    (&array as *const _ as *const [T; N]).read()
-}
 
 impl<T: FloatEqUlpsTol, const N: usize> FloatEqUlpsTol for [T; N]
 where
@@ -27,6 +16,29 @@ impl<T: FloatEqDebugUlpsDiff, const N: usize> FloatEqDebugUlpsDiff for [T; N] {
     type DebugUlpsDiff = [DebugUlpsDiff<T>; N];
 }
 
+// The tolerance and debug types of an array are arrays of those of its
+// elements, held inline, so that comparing and asserting on arrays never
+// allocates and `no_std` targets without a heap pay only for their size.
+const _: () = {
+    use core::mem::size_of;
+    assert!(size_of::<UlpsTol<[f32; 16]>>() == 16 * size_of::<u32>());
+    assert!(size_of::<UlpsTol<[[f64; 4]; 4]>>() == 16 * size_of::<u64>());
+    assert!(size_of::<DebugUlpsDiff<[f32; 16]>>() == 16 * size_of::<Option<u32>>());
+    assert!(size_of::<DebugUlpsDiff<[[f64; 4]; 4]>>() == 16 * size_of::<Option<u64>>());
+    assert!(size_of::<DebugUlpsDiff<[f32; 0]>>() == 0);
+};
+
+#[cfg(not(feature = "checks-only"))]
+const _: () = {
+    use core::mem::size_of;
+    type DebugAbsDiff<T> = <T as AssertFloatEq>::DebugAbsDiff;
+    type DebugTol<T> = <T as AssertFloatEq>::DebugTol;
+    type AllDebugTol<T> = <T as AssertFloatEqAll>::AllDebugTol;
+    assert!(size_of::<DebugAbsDiff<[f32; 16]>>() == size_of::<[f32; 16]>());
+    assert!(size_of::<DebugTol<[f64; 16]>>() == size_of::<[f64; 16]>());
+    assert!(size_of::<AllDebugTol<[[f32; 4]; 4]>>() == size_of::<[f32; 16]>());
+};
+
 impl<A, B, const N: usize> FloatEq<[B; N]> for [A; N]
 where
     A: FloatEq<B>,
@@ -34,6 +46,7 @@ where
     UlpsTol<A::Tol>: Sized,
 {
     type Tol = [A::Tol; N];
+    const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &[B; N], tol: &Self::Tol) -> bool {
@@ -96,6 +109,37 @@ where
     }
 }
 
+impl<A, B, const N: usize> FloatEqBits<[B; N]> for [A; N]
+where
+    A: FloatEqBits<B>,
+{
+    #[inline]
+    fn eq_bits(&self, other: &[B; N]) -> bool {
+        for i in 0..N {
+            if !self[i].eq_bits(&other[i]) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<A, B, const N: usize> FloatEqWeighted<[B; N]> for [A; N]
+where
+    A: FloatEqWeighted<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+{
+    #[inline]
+    fn weighted_error(&self, other: &[B; N], tol: &Self::Tol, weights: &Self::Tol) -> f64 {
+        let mut total = 0.0;
+        for i in 0..N {
+            total += self[i].weighted_error(&other[i], &tol[i], &weights[i]);
+        }
+        total
+    }
+}
+
 impl<A, B, const N: usize> FloatEqAll<[B; N]> for [A; N]
 where
     A: FloatEqAll<B>,
@@ -104,9 +148,7 @@ where
 
     #[inline]
     fn eq_abs_all(&self, other: &[B; N], tol: &Self::AllTol) -> bool {
-        self.iter()
-            .zip(other.iter())
-            .all(|(a, b)| a.eq_abs_all(b, tol))
+        A::eq_abs_all_elements(self, other, tol)
     }
 
     #[inline]
@@ -139,12 +181,11 @@ where
 
     #[inline]
     fn eq_ulps_all(&self, other: &[B; N], tol: &UlpsTol<Self::AllTol>) -> bool {
-        self.iter()
-            .zip(other.iter())
-            .all(|(a, b)| a.eq_ulps_all(b, tol))
+        A::eq_ulps_all_elements(self, other, tol)
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<A, B, const N: usize> AssertFloatEq<[B; N]> for [A; N]
 where
     A: AssertFloatEq<B>,
@@ -158,77 +199,46 @@ where
 
     #[inline]
     fn debug_abs_diff(&self, other: &[B; N]) -> Self::DebugAbsDiff {
-        let mut result: [MaybeUninit<A::DebugAbsDiff>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_abs_diff(&other[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_abs_diff(&other[i]))
     }
 
     #[inline]
     fn debug_ulps_diff(&self, other: &[B; N]) -> DebugUlpsDiff<Self::DebugAbsDiff> {
-        let mut result: [MaybeUninit<DebugUlpsDiff<A::DebugAbsDiff>>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_ulps_diff(&other[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_ulps_diff(&other[i]))
     }
 
     #[inline]
     fn debug_abs_tol(&self, other: &[B; N], tol: &Self::Tol) -> Self::DebugTol {
-        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_abs_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_abs_tol(&other[i], &tol[i]))
     }
 
     #[inline]
     fn debug_rmax_tol(&self, other: &[B; N], tol: &Self::Tol) -> Self::DebugTol {
-        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_rmax_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_rmax_tol(&other[i], &tol[i]))
     }
 
     #[inline]
     fn debug_rmin_tol(&self, other: &[B; N], tol: &Self::Tol) -> Self::DebugTol {
-        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_rmin_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_rmin_tol(&other[i], &tol[i]))
     }
 
     #[inline]
     fn debug_r1st_tol(&self, other: &[B; N], tol: &Self::Tol) -> Self::DebugTol {
-        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_r1st_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_r1st_tol(&other[i], &tol[i]))
     }
 
     #[inline]
     fn debug_r2nd_tol(&self, other: &[B; N], tol: &Self::Tol) -> Self::DebugTol {
-        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_r2nd_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_r2nd_tol(&other[i], &tol[i]))
     }
 
     #[inline]
     fn debug_ulps_tol(&self, other: &[B; N], tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
-        let mut result: [MaybeUninit<UlpsTol<A::DebugTol>>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_ulps_tol(&other[i], &tol[i]));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_ulps_tol(&other[i], &tol[i]))
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<A, B, const N: usize> AssertFloatEqAll<[B; N]> for [A; N]
 where
     A: AssertFloatEqAll<B>,
@@ -238,47 +248,27 @@ where
 
     #[inline]
     fn debug_abs_all_tol(&self, other: &[B; N], tol: &Self::AllTol) -> Self::AllDebugTol {
-        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_abs_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_abs_all_tol(&other[i], tol))
     }
 
     #[inline]
     fn debug_rmax_all_tol(&self, other: &[B; N], tol: &Self::AllTol) -> Self::AllDebugTol {
-        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_rmax_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_rmax_all_tol(&other[i], tol))
     }
 
     #[inline]
     fn debug_rmin_all_tol(&self, other: &[B; N], tol: &Self::AllTol) -> Self::AllDebugTol {
-        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_rmin_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_rmin_all_tol(&other[i], tol))
     }
 
     #[inline]
     fn debug_r1st_all_tol(&self, other: &[B; N], tol: &Self::AllTol) -> Self::AllDebugTol {
-        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_r1st_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_r1st_all_tol(&other[i], tol))
     }
 
     #[inline]
     fn debug_r2nd_all_tol(&self, other: &[B; N], tol: &Self::AllTol) -> Self::AllDebugTol {
-        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_r2nd_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_r2nd_all_tol(&other[i], tol))
     }
 
     #[inline]
@@ -287,10 +277,6 @@ where
         other: &[B; N],
         tol: &UlpsTol<Self::AllTol>,
     ) -> UlpsTol<Self::AllDebugTol> {
-        let mut result: [MaybeUninit<UlpsTol<A::AllDebugTol>>; N] = uninit_array();
-        for i in 0..N {
-            result[i] = MaybeUninit::new(self[i].debug_ulps_all_tol(&other[i], tol));
-        }
-        unsafe { array_assume_init(result) }
+        core::array::from_fn(|i| self[i].debug_ulps_all_tol(&other[i], tol))
     }
 }
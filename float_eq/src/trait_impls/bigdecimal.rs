@@ -0,0 +1,214 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, UlpsDiffReason};
+use crate::{FloatEq, FloatEqAll, UlpsTol};
+use bigdecimal::BigDecimal;
+
+/// The nearest representable float to an arbitrary precision decimal.
+///
+/// Conversion is correctly rounded, with values too large to be represented
+/// becoming infinite. This is used by comparisons between [`BigDecimal`] and
+/// `f64` values, which compare against the nearest float. Compare with `f32`
+/// values by converting explicitly.
+///
+/// # Examples
+/// ```
+/// # use float_eq::to_nearest_float;
+/// # use bigdecimal::BigDecimal;
+/// # use std::str::FromStr;
+/// let tenth = BigDecimal::from_str("0.1").unwrap();
+/// assert_eq!(to_nearest_float::<f64>(&tenth), 0.1);
+/// ```
+///
+/// [`BigDecimal`]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+pub fn to_nearest_float<T: NearestFloat>(value: &BigDecimal) -> T {
+    T::nearest(value)
+}
+
+/// Floating point types that an arbitrary precision decimal may be rounded to,
+/// via [`to_nearest_float`].
+///
+/// [`to_nearest_float`]: fn.to_nearest_float.html
+pub trait NearestFloat: Sized + crate::primitive::sealed::Sealed {
+    /// The nearest representable value to `value`.
+    fn nearest(value: &BigDecimal) -> Self;
+}
+
+macro_rules! impl_nearest_float {
+    ($float:ident) => {
+        impl NearestFloat for $float {
+            #[inline]
+            fn nearest(value: &BigDecimal) -> Self {
+                // Parsing from a string is correctly rounded.
+                value
+                    .to_string()
+                    .parse()
+                    .expect("BigDecimal should format as a valid float")
+            }
+        }
+    };
+}
+
+impl_nearest_float!(f32);
+impl_nearest_float!(f64);
+
+// Only implemented with the decimal on the left and an f64 on the right, since
+// an impl on the primitive types for another right hand side would break type
+// inference of their comparisons elsewhere.
+impl FloatEq<f64> for BigDecimal {
+    type Tol = f64;
+
+    #[inline]
+    fn eq_abs(&self, other: &f64, tol: &f64) -> bool {
+        f64::nearest(self).eq_abs(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &f64, tol: &f64) -> bool {
+        f64::nearest(self).eq_rmax(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &f64, tol: &f64) -> bool {
+        f64::nearest(self).eq_rmin(other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &f64, tol: &f64) -> bool {
+        f64::nearest(self).eq_r1st(other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &f64, tol: &f64) -> bool {
+        f64::nearest(self).eq_r2nd(other, tol)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &f64, tol: &UlpsTol<f64>) -> bool {
+        f64::nearest(self).eq_ulps(other, tol)
+    }
+}
+
+impl FloatEqAll<f64> for BigDecimal {
+    type AllTol = f64;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &f64, tol: &f64) -> bool {
+        self.eq_abs(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &f64, tol: &f64) -> bool {
+        self.eq_rmax(other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &f64, tol: &f64) -> bool {
+        self.eq_rmin(other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &f64, tol: &f64) -> bool {
+        self.eq_r1st(other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &f64, tol: &f64) -> bool {
+        self.eq_r2nd(other, tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &f64, tol: &UlpsTol<f64>) -> bool {
+        self.eq_ulps(other, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl AssertFloatEq<f64> for BigDecimal {
+    type DebugAbsDiff = f64;
+    type DebugTol = f64;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &f64) -> f64 {
+        f64::nearest(self).debug_abs_diff(other)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &f64) -> DebugUlpsDiff<f64> {
+        f64::nearest(self).debug_ulps_diff(other)
+    }
+
+    #[inline]
+    fn debug_categories(&self, other: &f64) -> Option<DebugCategories> {
+        f64::nearest(self).debug_categories(other)
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &f64) -> Option<UlpsDiffReason> {
+        f64::nearest(self).debug_ulps_diff_reason(other)
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &f64, tol: &f64) -> f64 {
+        f64::nearest(self).debug_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &f64, tol: &f64) -> f64 {
+        f64::nearest(self).debug_rmax_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &f64, tol: &f64) -> f64 {
+        f64::nearest(self).debug_rmin_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &f64, tol: &f64) -> f64 {
+        f64::nearest(self).debug_r1st_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &f64, tol: &f64) -> f64 {
+        f64::nearest(self).debug_r2nd_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &f64, tol: &UlpsTol<f64>) -> UlpsTol<f64> {
+        f64::nearest(self).debug_ulps_tol(other, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl AssertFloatEqAll<f64> for BigDecimal {
+    type AllDebugTol = f64;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &f64, tol: &f64) -> f64 {
+        self.debug_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &f64, tol: &f64) -> f64 {
+        self.debug_rmax_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &f64, tol: &f64) -> f64 {
+        self.debug_rmin_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &f64, tol: &f64) -> f64 {
+        self.debug_r1st_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &f64, tol: &f64) -> f64 {
+        self.debug_r2nd_tol(other, tol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(&self, other: &f64, tol: &UlpsTol<f64>) -> UlpsTol<f64> {
+        self.debug_ulps_tol(other, tol)
+    }
+}
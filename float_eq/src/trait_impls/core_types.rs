@@ -1,8 +1,12 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugCategories, UlpsDiffReason};
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    CheckSet, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff,
     FloatEqUlpsTol, UlpsTol,
 };
 use core::cell::{Cell, RefCell};
+use core::ops::{Range, RangeInclusive};
+use core::slice::{ChunksExact, Windows};
 
 //------------------------------------------------------------------------------
 // ref types
@@ -15,6 +19,7 @@ macro_rules! impl_for_refs {
             A: FloatEq<B>,
         {
             type Tol = A::Tol;
+            const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
 
             #[inline]
             fn eq_abs(&self, other: &&$($b)? B, tol: &Self::Tol) -> bool {
@@ -47,6 +52,16 @@ macro_rules! impl_for_refs {
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> FloatEqBits<&$($b)? B> for &$($a)? A
+        where
+            A: FloatEqBits<B>,
+        {
+            #[inline]
+            fn eq_bits(&self, other: &&$($b)? B) -> bool {
+                FloatEqBits::eq_bits(*self, *other)
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> FloatEqAll<&$($b)? B> for &$($a)? A
         where
             A: FloatEqAll<B>,
@@ -84,6 +99,7 @@ macro_rules! impl_for_refs {
             }
         }
 
+        #[cfg(not(feature = "checks-only"))]
         impl<A: ?Sized, B: ?Sized> AssertFloatEq<&$($b)? B> for &$($a)? A
         where
             A: AssertFloatEq<B>,
@@ -101,6 +117,16 @@ macro_rules! impl_for_refs {
                 AssertFloatEq::debug_ulps_diff(*self, *other)
             }
 
+            #[inline]
+            fn debug_categories(&self, other: &&$($b)? B) -> Option<DebugCategories> {
+                AssertFloatEq::debug_categories(*self, *other)
+            }
+
+            #[inline]
+            fn debug_ulps_diff_reason(&self, other: &&$($b)? B) -> Option<UlpsDiffReason> {
+                AssertFloatEq::debug_ulps_diff_reason(*self, *other)
+            }
+
             #[inline]
             fn debug_abs_tol(
                 &self,
@@ -159,6 +185,7 @@ macro_rules! impl_for_refs {
             }
         }
 
+        #[cfg(not(feature = "checks-only"))]
         impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<&$($b)? B> for &$($a)? A
         where
             A: AssertFloatEqAll<B>,
@@ -236,7 +263,7 @@ impl_for_refs!(&mut, &mut);
 // Note: The Option impls are over `impl<T>` and not `impl<A, B>` since that breaks
 // type inference and makes it harder to use `None`.
 //
-// Open question: should None == None? it currently does not.
+// None is equal to None, whatever the tolerance, and never to Some.
 //------------------------------------------------------------------------------
 impl<T: FloatEqUlpsTol> FloatEqUlpsTol for Option<T>
 where
@@ -255,58 +282,70 @@ where
     UlpsTol<T::Tol>: Sized,
 {
     type Tol = Option<T::Tol>;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &Option<T>, tol: &Self::Tol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_abs(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_abs(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_rmax(&self, other: &Option<T>, tol: &Self::Tol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_rmax(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_rmax(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_rmin(&self, other: &Option<T>, tol: &Self::Tol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_rmin(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_rmin(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_r1st(&self, other: &Option<T>, tol: &Self::Tol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_r1st(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_r1st(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_r2nd(&self, other: &Option<T>, tol: &Self::Tol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_r2nd(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_r2nd(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_ulps(&self, other: &Option<T>, tol: &UlpsTol<Self::Tol>) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_ulps(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_ulps(o, t),
+            (None, None, _) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: FloatEqBits> FloatEqBits for Option<T> {
+    #[inline]
+    fn eq_bits(&self, other: &Option<T>) -> bool {
+        match (self, other) {
+            (Some(s), Some(o)) => s.eq_bits(o),
+            (None, None) => true,
+            _ => false,
         }
     }
 }
@@ -320,59 +359,60 @@ where
 
     #[inline]
     fn eq_abs_all(&self, other: &Option<T>, tol: &Self::AllTol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_abs_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_abs_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_rmax_all(&self, other: &Option<T>, tol: &Self::AllTol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_rmax_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_rmax_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_rmin_all(&self, other: &Option<T>, tol: &Self::AllTol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_rmin_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_rmin_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_r1st_all(&self, other: &Option<T>, tol: &Self::AllTol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_r1st_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_r1st_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_r2nd_all(&self, other: &Option<T>, tol: &Self::AllTol) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_r2nd_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_r2nd_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 
     #[inline]
     fn eq_ulps_all(&self, other: &Option<T>, tol: &UlpsTol<Self::AllTol>) -> bool {
-        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
-            s.eq_ulps_all(o, t)
-        } else {
-            false
+        match (self, other, tol) {
+            (Some(s), Some(o), Some(t)) => s.eq_ulps_all(o, t),
+            (None, None, _) => true,
+            _ => false,
         }
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<T: AssertFloatEq> AssertFloatEq for Option<T>
 where
     T::Tol: Sized,
@@ -398,6 +438,16 @@ where
         ))
     }
 
+    #[inline]
+    fn debug_categories(&self, other: &Option<T>) -> Option<DebugCategories> {
+        AssertFloatEq::debug_categories(self.as_ref()?, other.as_ref()?)
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &Option<T>) -> Option<UlpsDiffReason> {
+        AssertFloatEq::debug_ulps_diff_reason(self.as_ref()?, other.as_ref()?)
+    }
+
     #[inline]
     fn debug_abs_tol(&self, other: &Option<T>, tol: &Self::Tol) -> Self::DebugTol {
         Some(AssertFloatEq::debug_abs_tol(
@@ -457,6 +507,7 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<T: AssertFloatEqAll> AssertFloatEqAll for Option<T>
 where
     T::AllTol: Sized,
@@ -527,6 +578,314 @@ where
     }
 }
 
+//------------------------------------------------------------------------------
+// Result
+//
+// Ok values are compared with the tolerance given, Err values are compared with
+// PartialEq, and an Ok is never equal to an Err. Debug output is None unless
+// both values are Ok.
+//------------------------------------------------------------------------------
+impl<T: FloatEqUlpsTol, E> FloatEqUlpsTol for Result<T, E>
+where
+    UlpsTol<T>: Sized,
+{
+    type UlpsTol = UlpsTol<T>;
+}
+
+impl<T: FloatEqDebugUlpsDiff, E> FloatEqDebugUlpsDiff for Result<T, E> {
+    type DebugUlpsDiff = Option<DebugUlpsDiff<T>>;
+}
+
+impl<T: FloatEq, E: PartialEq> FloatEq for Result<T, E> {
+    type Tol = T::Tol;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &Result<T, E>, tol: &Self::Tol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_abs(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Result<T, E>, tol: &Self::Tol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_rmax(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Result<T, E>, tol: &Self::Tol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_rmin(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Result<T, E>, tol: &Self::Tol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_r1st(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Result<T, E>, tol: &Self::Tol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_r2nd(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Result<T, E>, tol: &UlpsTol<Self::Tol>) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_ulps(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+}
+
+impl<T: FloatEqBits, E: PartialEq> FloatEqBits for Result<T, E> {
+    #[inline]
+    fn eq_bits(&self, other: &Result<T, E>) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_bits(o),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+}
+
+impl<T: FloatEqAll, E: PartialEq> FloatEqAll<Result<T, E>> for Result<T, E> {
+    type AllTol = T::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Result<T, E>, tol: &Self::AllTol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_abs_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Result<T, E>, tol: &Self::AllTol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_rmax_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Result<T, E>, tol: &Self::AllTol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_rmin_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Result<T, E>, tol: &Self::AllTol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_r1st_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Result<T, E>, tol: &Self::AllTol) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_r2nd_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Result<T, E>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        match (self, other) {
+            (Ok(s), Ok(o)) => s.eq_ulps_all(o, tol),
+            (Err(s), Err(o)) => s == o,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T: AssertFloatEq, E: PartialEq> AssertFloatEq for Result<T, E>
+where
+    UlpsTol<T::DebugTol>: Sized,
+{
+    type DebugAbsDiff = Option<T::DebugAbsDiff>;
+    type DebugTol = Option<T::DebugTol>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Result<T, E>) -> Self::DebugAbsDiff {
+        Some(AssertFloatEq::debug_abs_diff(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Result<T, E>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        Some(AssertFloatEq::debug_ulps_diff(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_categories(&self, other: &Result<T, E>) -> Option<DebugCategories> {
+        AssertFloatEq::debug_categories(self.as_ref().ok()?, other.as_ref().ok()?)
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &Result<T, E>) -> Option<UlpsDiffReason> {
+        AssertFloatEq::debug_ulps_diff_reason(self.as_ref().ok()?, other.as_ref().ok()?)
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Result<T, E>, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_abs_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Result<T, E>, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_rmax_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Result<T, E>, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_rmin_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Result<T, E>, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_r1st_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Result<T, E>, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_r2nd_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &Result<T, E>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol> {
+        Some(AssertFloatEq::debug_ulps_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T: AssertFloatEqAll, E: PartialEq> AssertFloatEqAll for Result<T, E>
+where
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    type AllDebugTol = Option<T::AllDebugTol>;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Result<T, E>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_abs_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Result<T, E>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_rmax_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Result<T, E>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_rmin_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Result<T, E>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_r1st_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Result<T, E>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_r2nd_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Result<T, E>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        Some(AssertFloatEqAll::debug_ulps_all_tol(
+            self.as_ref().ok()?,
+            other.as_ref().ok()?,
+            tol,
+        ))
+    }
+}
+
 //------------------------------------------------------------------------------
 // Cell
 //------------------------------------------------------------------------------
@@ -536,6 +895,7 @@ where
     B: Copy,
 {
     type Tol = A::Tol;
+    const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &Cell<B>, tol: &Self::Tol) -> bool {
@@ -568,6 +928,17 @@ where
     }
 }
 
+impl<A, B> FloatEqBits<Cell<B>> for Cell<A>
+where
+    A: FloatEqBits<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn eq_bits(&self, other: &Cell<B>) -> bool {
+        FloatEqBits::eq_bits(&self.get(), &other.get())
+    }
+}
+
 impl<A, B> FloatEqAll<Cell<B>> for Cell<A>
 where
     A: FloatEqAll<B> + Copy,
@@ -606,6 +977,7 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<A, B> AssertFloatEq<Cell<B>> for Cell<A>
 where
     A: AssertFloatEq<B> + Copy,
@@ -624,6 +996,16 @@ where
         AssertFloatEq::debug_ulps_diff(&self.get(), &other.get())
     }
 
+    #[inline]
+    fn debug_categories(&self, other: &Cell<B>) -> Option<DebugCategories> {
+        AssertFloatEq::debug_categories(&self.get(), &other.get())
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &Cell<B>) -> Option<UlpsDiffReason> {
+        AssertFloatEq::debug_ulps_diff_reason(&self.get(), &other.get())
+    }
+
     #[inline]
     fn debug_abs_tol(&self, other: &Cell<B>, tol: &Self::Tol) -> Self::DebugTol {
         AssertFloatEq::debug_abs_tol(&self.get(), &other.get(), tol)
@@ -658,6 +1040,7 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<A, B> AssertFloatEqAll<Cell<B>> for Cell<A>
 where
     A: AssertFloatEqAll<B> + Copy,
@@ -711,6 +1094,7 @@ where
     A: FloatEq<B>,
 {
     type Tol = A::Tol;
+    const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &RefCell<B>, tol: &Self::Tol) -> bool {
@@ -743,6 +1127,16 @@ where
     }
 }
 
+impl<A: ?Sized, B: ?Sized> FloatEqBits<RefCell<B>> for RefCell<A>
+where
+    A: FloatEqBits<B>,
+{
+    #[inline]
+    fn eq_bits(&self, other: &RefCell<B>) -> bool {
+        FloatEqBits::eq_bits(&*self.borrow(), &*other.borrow())
+    }
+}
+
 impl<A: ?Sized, B: ?Sized> FloatEqAll<RefCell<B>> for RefCell<A>
 where
     A: FloatEqAll<B>,
@@ -780,6 +1174,8 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
+#[allow(clippy::needless_maybe_sized)]
 impl<A: ?Sized, B: ?Sized> AssertFloatEq<RefCell<B>> for RefCell<A>
 where
     A: AssertFloatEq<B> + Copy,
@@ -798,6 +1194,16 @@ where
         AssertFloatEq::debug_ulps_diff(&*self.borrow(), &*other.borrow())
     }
 
+    #[inline]
+    fn debug_categories(&self, other: &RefCell<B>) -> Option<DebugCategories> {
+        AssertFloatEq::debug_categories(&*self.borrow(), &*other.borrow())
+    }
+
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &RefCell<B>) -> Option<UlpsDiffReason> {
+        AssertFloatEq::debug_ulps_diff_reason(&*self.borrow(), &*other.borrow())
+    }
+
     #[inline]
     fn debug_abs_tol(&self, other: &RefCell<B>, tol: &Self::Tol) -> Self::DebugTol {
         AssertFloatEq::debug_abs_tol(&*self.borrow(), &*other.borrow(), tol)
@@ -836,6 +1242,8 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
+#[allow(clippy::needless_maybe_sized)]
 impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<RefCell<B>> for RefCell<A>
 where
     A: AssertFloatEqAll<B> + Copy,
@@ -881,6 +1289,310 @@ where
     }
 }
 
+//------------------------------------------------------------------------------
+// Ranges
+//------------------------------------------------------------------------------
+// Ranges are compared endpoint by endpoint, with the same tolerance applied to
+// both. Debug output is a range of the per-endpoint values.
+trait RangeEnds<T> {
+    fn ends(&self) -> (&T, &T);
+    #[cfg(not(feature = "checks-only"))]
+    fn from_ends(start: T, end: T) -> Self;
+}
+
+impl<T> RangeEnds<T> for Range<T> {
+    #[inline]
+    fn ends(&self) -> (&T, &T) {
+        (&self.start, &self.end)
+    }
+
+    #[cfg(not(feature = "checks-only"))]
+    #[inline]
+    fn from_ends(start: T, end: T) -> Self {
+        start..end
+    }
+}
+
+impl<T> RangeEnds<T> for RangeInclusive<T> {
+    #[inline]
+    fn ends(&self) -> (&T, &T) {
+        (self.start(), self.end())
+    }
+
+    #[cfg(not(feature = "checks-only"))]
+    #[inline]
+    fn from_ends(start: T, end: T) -> Self {
+        start..=end
+    }
+}
+
+macro_rules! impl_traits_for_range {
+    ($range:ident) => {
+        impl<T: FloatEqUlpsTol> FloatEqUlpsTol for $range<T>
+        where
+            UlpsTol<T>: Sized,
+        {
+            type UlpsTol = $range<UlpsTol<T>>;
+        }
+
+        impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for $range<T> {
+            type DebugUlpsDiff = $range<DebugUlpsDiff<T>>;
+        }
+
+        impl<A, B> FloatEq<$range<B>> for $range<A>
+        where
+            A: FloatEq<B>,
+            A::Tol: Sized,
+            UlpsTol<A::Tol>: Sized,
+        {
+            type Tol = A::Tol;
+            const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
+
+            #[inline]
+            fn eq_abs(&self, other: &$range<B>, tol: &Self::Tol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_abs(b0, tol) && a1.eq_abs(b1, tol)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &$range<B>, tol: &Self::Tol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_rmax(b0, tol) && a1.eq_rmax(b1, tol)
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &$range<B>, tol: &Self::Tol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_rmin(b0, tol) && a1.eq_rmin(b1, tol)
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &$range<B>, tol: &Self::Tol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_r1st(b0, tol) && a1.eq_r1st(b1, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &$range<B>, tol: &Self::Tol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_r2nd(b0, tol) && a1.eq_r2nd(b1, tol)
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &$range<B>, tol: &UlpsTol<Self::Tol>) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_ulps(b0, tol) && a1.eq_ulps(b1, tol)
+            }
+        }
+
+        impl<A, B> FloatEqBits<$range<B>> for $range<A>
+        where
+            A: FloatEqBits<B>,
+        {
+            #[inline]
+            fn eq_bits(&self, other: &$range<B>) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_bits(b0) && a1.eq_bits(b1)
+            }
+        }
+
+        impl<A, B> FloatEqAll<$range<B>> for $range<A>
+        where
+            A: FloatEqAll<B>,
+        {
+            type AllTol = A::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &$range<B>, tol: &Self::AllTol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_abs_all(b0, tol) && a1.eq_abs_all(b1, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &$range<B>, tol: &Self::AllTol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_rmax_all(b0, tol) && a1.eq_rmax_all(b1, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &$range<B>, tol: &Self::AllTol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_rmin_all(b0, tol) && a1.eq_rmin_all(b1, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &$range<B>, tol: &Self::AllTol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_r1st_all(b0, tol) && a1.eq_r1st_all(b1, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &$range<B>, tol: &Self::AllTol) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_r2nd_all(b0, tol) && a1.eq_r2nd_all(b1, tol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &$range<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                a0.eq_ulps_all(b0, tol) && a1.eq_ulps_all(b1, tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl<A, B> AssertFloatEq<$range<B>> for $range<A>
+        where
+            A: AssertFloatEq<B>,
+            A::Tol: Sized,
+            A::DebugTol: Sized,
+            UlpsTol<A::Tol>: Sized,
+            UlpsTol<A::DebugTol>: Sized,
+        {
+            type DebugAbsDiff = $range<A::DebugAbsDiff>;
+            type DebugTol = $range<A::DebugTol>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$range<B>) -> Self::DebugAbsDiff {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_abs_diff(b0), a1.debug_abs_diff(b1))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &$range<B>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_ulps_diff(b0), a1.debug_ulps_diff(b1))
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &$range<B>, tol: &Self::Tol) -> Self::DebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_abs_tol(b0, tol), a1.debug_abs_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &$range<B>, tol: &Self::Tol) -> Self::DebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_rmax_tol(b0, tol), a1.debug_rmax_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &$range<B>, tol: &Self::Tol) -> Self::DebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_rmin_tol(b0, tol), a1.debug_rmin_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &$range<B>, tol: &Self::Tol) -> Self::DebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_r1st_tol(b0, tol), a1.debug_r1st_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &$range<B>, tol: &Self::Tol) -> Self::DebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_r2nd_tol(b0, tol), a1.debug_r2nd_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &$range<B>,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_ulps_tol(b0, tol), a1.debug_ulps_tol(b1, tol))
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl<A, B> AssertFloatEqAll<$range<B>> for $range<A>
+        where
+            A: AssertFloatEqAll<B>,
+            UlpsTol<A::AllDebugTol>: Sized,
+        {
+            type AllDebugTol = $range<A::AllDebugTol>;
+
+            #[inline]
+            fn debug_abs_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(a0.debug_abs_all_tol(b0, tol), a1.debug_abs_all_tol(b1, tol))
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(
+                    a0.debug_rmax_all_tol(b0, tol),
+                    a1.debug_rmax_all_tol(b1, tol),
+                )
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(
+                    a0.debug_rmin_all_tol(b0, tol),
+                    a1.debug_rmin_all_tol(b1, tol),
+                )
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(
+                    a0.debug_r1st_all_tol(b0, tol),
+                    a1.debug_r1st_all_tol(b1, tol),
+                )
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(
+                    a0.debug_r2nd_all_tol(b0, tol),
+                    a1.debug_r2nd_all_tol(b1, tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &$range<B>,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                let ((a0, a1), (b0, b1)) = (self.ends(), other.ends());
+                RangeEnds::from_ends(
+                    a0.debug_ulps_all_tol(b0, tol),
+                    a1.debug_ulps_all_tol(b1, tol),
+                )
+            }
+        }
+    };
+}
+
+impl_traits_for_range!(Range);
+impl_traits_for_range!(RangeInclusive);
+
 //------------------------------------------------------------------------------
 // Slices
 //------------------------------------------------------------------------------
@@ -898,6 +1610,7 @@ where
     UlpsTol<A::Tol>: Sized,
 {
     type Tol = [A::Tol];
+    const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &[B], tol: &Self::Tol) -> bool {
@@ -966,6 +1679,16 @@ where
     }
 }
 
+impl<A, B> FloatEqBits<[B]> for [A]
+where
+    A: FloatEqBits<B>,
+{
+    #[inline]
+    fn eq_bits(&self, other: &[B]) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.eq_bits(b))
+    }
+}
+
 impl<A, B> FloatEqAll<[B]> for [A]
 where
     A: FloatEqAll<B>,
@@ -974,11 +1697,7 @@ where
 
     #[inline]
     fn eq_abs_all(&self, other: &[B], tol: &Self::AllTol) -> bool {
-        self.len() == other.len()
-            && self
-                .iter()
-                .zip(other.iter())
-                .all(|(a, b)| a.eq_abs_all(b, tol))
+        self.len() == other.len() && A::eq_abs_all_elements(self, other, tol)
     }
 
     #[inline]
@@ -1019,10 +1738,144 @@ where
 
     #[inline]
     fn eq_ulps_all(&self, other: &[B], tol: &UlpsTol<Self::AllTol>) -> bool {
-        self.len() == other.len()
-            && self
-                .iter()
-                .zip(other.iter())
-                .all(|(a, b)| a.eq_ulps_all(b, tol))
+        self.len() == other.len() && A::eq_ulps_all_elements(self, other, tol)
     }
 }
+
+//------------------------------------------------------------------------------
+// Chunk iterators
+//------------------------------------------------------------------------------
+// Chunk iterators are compared block by block without consuming them. A Tol is
+// a slice with one tolerance per position in a block, which is applied to every
+// block in turn.
+macro_rules! impl_traits_for_chunk_iter {
+    ($t:ident) => {
+        impl<'a, 'b, A, B> FloatEq<$t<'b, B>> for $t<'a, A>
+        where
+            A: FloatEq<B>,
+            A::Tol: Sized,
+            UlpsTol<A::Tol>: Sized,
+        {
+            type Tol = [A::Tol];
+            const SUPPORTED_CHECKS: CheckSet = A::SUPPORTED_CHECKS;
+
+            #[inline]
+            fn eq_abs(&self, other: &$t<'b, B>, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_abs(b, tol))
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &$t<'b, B>, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_rmax(b, tol))
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &$t<'b, B>, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_rmin(b, tol))
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &$t<'b, B>, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_r1st(b, tol))
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &$t<'b, B>, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_r2nd(b, tol))
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &$t<'b, B>, tol: &UlpsTol<Self::Tol>) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_ulps(b, tol))
+            }
+        }
+
+        impl<'a, 'b, A, B> FloatEqAll<$t<'b, B>> for $t<'a, A>
+        where
+            A: FloatEqAll<B>,
+        {
+            type AllTol = A::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &$t<'b, B>, tol: &Self::AllTol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_abs_all(b, tol))
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &$t<'b, B>, tol: &Self::AllTol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_rmax_all(b, tol))
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &$t<'b, B>, tol: &Self::AllTol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_rmin_all(b, tol))
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &$t<'b, B>, tol: &Self::AllTol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_r1st_all(b, tol))
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &$t<'b, B>, tol: &Self::AllTol) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_r2nd_all(b, tol))
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &$t<'b, B>, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.len() == other.len()
+                    && self
+                        .clone()
+                        .zip(other.clone())
+                        .all(|(a, b)| a.eq_ulps_all(b, tol))
+            }
+        }
+    };
+}
+
+impl_traits_for_chunk_iter!(ChunksExact);
+impl_traits_for_chunk_iter!(Windows);
@@ -0,0 +1,187 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, UlpsDiffReason};
+use crate::{FloatEq, FloatEqAll, UlpsTol};
+
+// Durations are compared by their length in fractional seconds, so tolerances
+// are given in seconds as an f64, much as a physics timestep usually is.
+macro_rules! impl_traits_for_duration {
+    ($t:ty, $secs:expr) => {
+        impl FloatEq for $t {
+            type Tol = f64;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &f64) -> bool {
+                FloatEq::eq_abs(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &f64) -> bool {
+                FloatEq::eq_rmax(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &f64) -> bool {
+                FloatEq::eq_rmin(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &f64) -> bool {
+                FloatEq::eq_r1st(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &f64) -> bool {
+                FloatEq::eq_r2nd(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<f64>) -> bool {
+                FloatEq::eq_ulps(&$secs(self), &$secs(other), tol)
+            }
+        }
+
+        impl FloatEqAll for $t {
+            type AllTol = f64;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &f64) -> bool {
+                self.eq_abs(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &f64) -> bool {
+                self.eq_rmax(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &f64) -> bool {
+                self.eq_rmin(other, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &f64) -> bool {
+                self.eq_r1st(other, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &f64) -> bool {
+                self.eq_r2nd(other, tol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<f64>) -> bool {
+                self.eq_ulps(other, tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEq for $t {
+            type DebugAbsDiff = f64;
+            type DebugTol = f64;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> f64 {
+                AssertFloatEq::debug_abs_diff(&$secs(self), &$secs(other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<f64> {
+                AssertFloatEq::debug_ulps_diff(&$secs(self), &$secs(other))
+            }
+
+            #[inline]
+            fn debug_categories(&self, other: &Self) -> Option<DebugCategories> {
+                AssertFloatEq::debug_categories(&$secs(self), &$secs(other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff_reason(&self, other: &Self) -> Option<UlpsDiffReason> {
+                AssertFloatEq::debug_ulps_diff_reason(&$secs(self), &$secs(other))
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &f64) -> f64 {
+                AssertFloatEq::debug_abs_tol(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &f64) -> f64 {
+                AssertFloatEq::debug_rmax_tol(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &f64) -> f64 {
+                AssertFloatEq::debug_rmin_tol(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &f64) -> f64 {
+                AssertFloatEq::debug_r1st_tol(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &f64) -> f64 {
+                AssertFloatEq::debug_r2nd_tol(&$secs(self), &$secs(other), tol)
+            }
+
+            #[inline]
+            fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<f64>) -> UlpsTol<f64> {
+                AssertFloatEq::debug_ulps_tol(&$secs(self), &$secs(other), tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEqAll for $t {
+            type AllDebugTol = f64;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &f64) -> f64 {
+                self.debug_abs_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &f64) -> f64 {
+                self.debug_rmax_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &f64) -> f64 {
+                self.debug_rmin_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &f64) -> f64 {
+                self.debug_r1st_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &f64) -> f64 {
+                self.debug_r2nd_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(&self, other: &Self, tol: &UlpsTol<f64>) -> UlpsTol<f64> {
+                self.debug_ulps_tol(other, tol)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "time")]
+impl_traits_for_duration!(time::Duration, time_secs);
+
+#[cfg(feature = "time")]
+#[inline]
+fn time_secs(duration: &time::Duration) -> f64 {
+    duration.as_seconds_f64()
+}
+
+#[cfg(feature = "chrono")]
+impl_traits_for_duration!(chrono::Duration, chrono_secs);
+
+#[cfg(feature = "chrono")]
+#[inline]
+fn chrono_secs(duration: &chrono::Duration) -> f64 {
+    // subsec_nanos has the same sign as num_seconds, so the two may be summed.
+    duration.num_seconds() as f64 + f64::from(duration.subsec_nanos()) * 1e-9
+}
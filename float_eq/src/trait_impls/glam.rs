@@ -0,0 +1,236 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
+use crate::{
+    DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff, FloatEqUlpsTol, TolOps,
+    UlpsTol,
+};
+use glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3,
+    Vec3A, Vec4,
+};
+
+// glam types are compared element by element via their array representation,
+// which for matrices is column-major. The tolerance of each type is the type
+// itself, whilst the ULPs types are arrays, since glam has no vectors of
+// Option<u32> and the like.
+macro_rules! impl_traits_for_glam {
+    (vector $t:ident, $arr:ty) => {
+        impl_traits_for_glam!(@impl $t, $arr, |v: &$t| v.to_array(), |a: $arr| $t::from_array(a));
+    };
+    (matrix $t:ident, $arr:ty) => {
+        impl_traits_for_glam!(
+            @impl $t,
+            $arr,
+            |m: &$t| m.to_cols_array_2d(),
+            |a: $arr| $t::from_cols_array_2d(&a)
+        );
+    };
+    (@impl $t:ident, $arr:ty, $to:expr, $from:expr) => {
+        impl FloatEqUlpsTol for $t {
+            type UlpsTol = UlpsTol<$arr>;
+        }
+
+        impl FloatEqDebugUlpsDiff for $t {
+            type DebugUlpsDiff = DebugUlpsDiff<$arr>;
+        }
+
+        impl FloatEq for $t {
+            type Tol = $t;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $to(self).eq_abs(&$to(other), &$to(tol))
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $to(self).eq_rmax(&$to(other), &$to(tol))
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $to(self).eq_rmin(&$to(other), &$to(tol))
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $to(self).eq_r1st(&$to(other), &$to(tol))
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $to(self).eq_r2nd(&$to(other), &$to(tol))
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+                $to(self).eq_ulps(&$to(other), tol)
+            }
+        }
+
+        impl TolOps for $t {
+            #[inline]
+            fn max_elementwise(&self, other: &Self) -> Self {
+                $from($to(self).max_elementwise(&$to(other)))
+            }
+
+            #[inline]
+            fn min_elementwise(&self, other: &Self) -> Self {
+                $from($to(self).min_elementwise(&$to(other)))
+            }
+
+            #[inline]
+            fn scale(&self, factor: f64) -> Self {
+                $from($to(self).scale(factor))
+            }
+        }
+
+        impl FloatEqBits for $t {
+            #[inline]
+            fn eq_bits(&self, other: &Self) -> bool {
+                $to(self).eq_bits(&$to(other))
+            }
+        }
+
+        impl FloatEqAll for $t {
+            type AllTol = <$arr as FloatEqAll>::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $to(self).eq_abs_all(&$to(other), tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $to(self).eq_rmax_all(&$to(other), tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $to(self).eq_rmin_all(&$to(other), tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $to(self).eq_r1st_all(&$to(other), tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $to(self).eq_r2nd_all(&$to(other), tol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                $to(self).eq_ulps_all(&$to(other), tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEq for $t {
+            type DebugAbsDiff = $t;
+            type DebugTol = $t;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $from($to(self).debug_abs_diff(&$to(other)))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                $to(self).debug_ulps_diff(&$to(other))
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $from($to(self).debug_abs_tol(&$to(other), &$to(tol)))
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $from($to(self).debug_rmax_tol(&$to(other), &$to(tol)))
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $from($to(self).debug_rmin_tol(&$to(other), &$to(tol)))
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $from($to(self).debug_r1st_tol(&$to(other), &$to(tol)))
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $from($to(self).debug_r2nd_tol(&$to(other), &$to(tol)))
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                $to(self).debug_ulps_tol(&$to(other), tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEqAll for $t {
+            type AllDebugTol = $t;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $from($to(self).debug_abs_all_tol(&$to(other), tol))
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $from($to(self).debug_rmax_all_tol(&$to(other), tol))
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $from($to(self).debug_rmin_all_tol(&$to(other), tol))
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $from($to(self).debug_r1st_all_tol(&$to(other), tol))
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $from($to(self).debug_r2nd_all_tol(&$to(other), tol))
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                $to(self).debug_ulps_all_tol(&$to(other), tol)
+            }
+        }
+    };
+}
+
+impl_traits_for_glam!(vector Vec2, [f32; 2]);
+impl_traits_for_glam!(vector Vec3, [f32; 3]);
+impl_traits_for_glam!(vector Vec3A, [f32; 3]);
+impl_traits_for_glam!(vector Vec4, [f32; 4]);
+impl_traits_for_glam!(vector Quat, [f32; 4]);
+impl_traits_for_glam!(matrix Mat2, [[f32; 2]; 2]);
+impl_traits_for_glam!(matrix Mat3, [[f32; 3]; 3]);
+impl_traits_for_glam!(matrix Mat3A, [[f32; 3]; 3]);
+impl_traits_for_glam!(matrix Mat4, [[f32; 4]; 4]);
+
+impl_traits_for_glam!(vector DVec2, [f64; 2]);
+impl_traits_for_glam!(vector DVec3, [f64; 3]);
+impl_traits_for_glam!(vector DVec4, [f64; 4]);
+impl_traits_for_glam!(vector DQuat, [f64; 4]);
+impl_traits_for_glam!(matrix DMat2, [[f64; 2]; 2]);
+impl_traits_for_glam!(matrix DMat3, [[f64; 3]; 3]);
+impl_traits_for_glam!(matrix DMat4, [[f64; 4]; 4]);
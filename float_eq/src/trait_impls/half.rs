@@ -0,0 +1,291 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, FloatCategory, UlpsDiffReason,
+};
+use crate::{
+    FloatBits, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    FloatEqWeighted, TolOps, UlpsMetric, UlpsTol,
+};
+#[cfg(not(feature = "checks-only"))]
+use core::num::FpCategory;
+use half::{bf16, f16};
+
+// Half precision floats are compared in the same way as f32 and f64, using
+// their own arithmetic, with ULPs counted in u16 via their bit patterns.
+macro_rules! impl_traits {
+    ($half:ident) => {
+        impl FloatEqUlpsTol for $half {
+            type UlpsTol = u16;
+        }
+
+        impl FloatEqDebugUlpsDiff for $half {
+            type DebugUlpsDiff = Option<u16>;
+        }
+
+        impl FloatEq for $half {
+            type Tol = Self;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                // the PartialEq check covers equality of infinities
+                self == other || abs(*self - *other).le(tol)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                // the PartialEq check covers equality of infinities
+                self == other || {
+                    let largest = abs(*self).max(abs(*other));
+                    abs(*self - *other) <= largest * *tol
+                }
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                // the PartialEq check covers equality of infinities
+                self == other || {
+                    let smallest = abs(*self).min(abs(*other));
+                    abs(*self - *other) <= smallest * *tol
+                }
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                // the PartialEq check covers equality of infinities
+                self == other || abs(*self - *other) <= abs(*self) * *tol
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                // the PartialEq check covers equality of infinities
+                self == other || abs(*self - *other) <= abs(*other) * *tol
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+                FloatBits::eq_ulps_within(*self, *other, *tol)
+            }
+        }
+
+        impl FloatEqAll for $half {
+            type AllTol = $half;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.eq_abs(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.eq_rmax(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.eq_rmin(other, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.eq_r1st(other, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.eq_r2nd(other, tol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.eq_ulps(other, tol)
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEq for $half {
+            type DebugAbsDiff = Self;
+            type DebugTol = Self::Tol;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                abs(*self - *other)
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                FloatBits::ulps_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_categories(&self, other: &Self) -> Option<DebugCategories> {
+                let classify = |value: &$half| {
+                    FloatCategory::classify(
+                        value.is_nan(),
+                        value.is_infinite(),
+                        value.classify() == FpCategory::Subnormal,
+                    )
+                };
+                DebugCategories::new(classify(self), classify(other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff_reason(&self, other: &Self) -> Option<UlpsDiffReason> {
+                UlpsDiffReason::classify(
+                    self.is_nan() || other.is_nan(),
+                    self.is_sign_positive() != other.is_sign_positive() && self != other,
+                )
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, _other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                *tol
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                abs(*self).max(abs(*other)) * *tol
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                abs(*self).min(abs(*other)) * *tol
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, _other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                abs(*self) * *tol
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                abs(*other) * *tol
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                _other: &Self,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                *tol
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl AssertFloatEqAll for $half {
+            type AllDebugTol = Self::AllTol;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_abs_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmax_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmin_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r1st_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r2nd_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                self.debug_ulps_tol(other, tol)
+            }
+        }
+
+        impl FloatEqBits for $half {
+            #[inline]
+            fn eq_bits(&self, other: &Self) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+        }
+
+        impl FloatEqWeighted for $half {
+            #[inline]
+            fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64 {
+                if self == other {
+                    0.0
+                } else {
+                    f64::from(*weights) * f64::from(abs(*self - *other)) / f64::from(*tol)
+                }
+            }
+        }
+
+        impl TolOps for $half {
+            #[inline]
+            fn max_elementwise(&self, other: &Self) -> Self {
+                $half::max(*self, *other)
+            }
+
+            #[inline]
+            fn min_elementwise(&self, other: &Self) -> Self {
+                $half::min(*self, *other)
+            }
+
+            #[inline]
+            fn scale(&self, factor: f64) -> Self {
+                $half::from_f64(f64::from(*self) * factor)
+            }
+        }
+
+        impl FloatBits for $half {
+            type Bits = u16;
+
+            #[inline]
+            fn to_float_bits(self) -> Self::Bits {
+                self.to_bits()
+            }
+
+            #[inline]
+            fn from_float_bits(bits: Self::Bits) -> Self {
+                $half::from_bits(bits)
+            }
+
+            #[inline]
+            fn is_nan_value(self) -> bool {
+                self.is_nan()
+            }
+
+            #[inline]
+            fn is_sign_positive_value(self) -> bool {
+                self.is_sign_positive()
+            }
+        }
+
+        impl UlpsMetric for $half {
+            type Ulps = u16;
+
+            #[inline]
+            fn ulps_between(&self, other: &Self) -> Option<Self::Ulps> {
+                FloatBits::ulps_diff(*self, *other)
+            }
+        }
+    };
+}
+
+impl_traits!(f16);
+impl_traits!(bf16);
+
+/// The absolute value, by masking away the sign bit, since the half types do
+/// not provide an abs method.
+#[inline]
+fn abs<T: FloatBits<Bits = u16>>(value: T) -> T {
+    T::from_float_bits(value.to_float_bits() & 0x7fff)
+}
@@ -0,0 +1,655 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
+use crate::{
+    CheckSet, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, TolOps, UlpsTol, UlpsTolOps,
+};
+use nalgebra::{Quaternion, SMatrix, Unit};
+
+// Statically sized matrices, which includes vectors such as Vector3, are
+// compared element by element via their column-major array storage. The
+// tolerance and ULPs types are matrices of the same shape, so that each
+// element may be given its own tolerance.
+#[inline]
+fn matrix<T, const R: usize, const C: usize>(data: [[T; R]; C]) -> SMatrix<T, R, C> {
+    SMatrix::from_data(nalgebra::ArrayStorage(data))
+}
+
+impl<T: FloatEqUlpsTol, const R: usize, const C: usize> FloatEqUlpsTol for SMatrix<T, R, C>
+where
+    UlpsTol<T>: Sized,
+{
+    type UlpsTol = SMatrix<UlpsTol<T>, R, C>;
+}
+
+impl<T: UlpsTolOps, const R: usize, const C: usize> UlpsTolOps for SMatrix<T, R, C> {
+    #[inline]
+    fn saturating_add(&self, other: &Self) -> Self {
+        matrix(self.data.0.saturating_add(&other.data.0))
+    }
+
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        matrix(self.data.0.max_elementwise(&other.data.0))
+    }
+}
+
+impl<T: FloatEqDebugUlpsDiff, const R: usize, const C: usize> FloatEqDebugUlpsDiff
+    for SMatrix<T, R, C>
+{
+    type DebugUlpsDiff = SMatrix<DebugUlpsDiff<T>, R, C>;
+}
+
+impl<T: TolOps, const R: usize, const C: usize> TolOps for SMatrix<T, R, C> {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        matrix(self.data.0.max_elementwise(&other.data.0))
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        matrix(self.data.0.min_elementwise(&other.data.0))
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        matrix(self.data.0.scale(factor))
+    }
+}
+
+impl<T: FloatEq, const R: usize, const C: usize> FloatEq for SMatrix<T, R, C>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    type Tol = SMatrix<T::Tol, R, C>;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.data.0.eq_abs(&other.data.0, &tol.data.0)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.data.0.eq_rmax(&other.data.0, &tol.data.0)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.data.0.eq_rmin(&other.data.0, &tol.data.0)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.data.0.eq_r1st(&other.data.0, &tol.data.0)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.data.0.eq_r2nd(&other.data.0, &tol.data.0)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.data.0.eq_ulps(&other.data.0, &tol.data.0)
+    }
+}
+
+impl<T: FloatEqBits, const R: usize, const C: usize> FloatEqBits for SMatrix<T, R, C> {
+    #[inline]
+    fn eq_bits(&self, other: &Self) -> bool {
+        self.data.0.eq_bits(&other.data.0)
+    }
+}
+
+impl<T: FloatEqAll, const R: usize, const C: usize> FloatEqAll for SMatrix<T, R, C> {
+    type AllTol = T::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.data.0.eq_abs_all(&other.data.0, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.data.0.eq_rmax_all(&other.data.0, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.data.0.eq_rmin_all(&other.data.0, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.data.0.eq_r1st_all(&other.data.0, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.data.0.eq_r2nd_all(&other.data.0, tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.data.0.eq_ulps_all(&other.data.0, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T, const R: usize, const C: usize> AssertFloatEq for SMatrix<T, R, C>
+where
+    T: AssertFloatEq,
+    T::Tol: Sized,
+    T::DebugTol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    type DebugAbsDiff = SMatrix<T::DebugAbsDiff, R, C>;
+    type DebugTol = SMatrix<T::DebugTol, R, C>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        matrix(self.data.0.debug_abs_diff(&other.data.0))
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        matrix(self.data.0.debug_ulps_diff(&other.data.0))
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        matrix(self.data.0.debug_abs_tol(&other.data.0, &tol.data.0))
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        matrix(self.data.0.debug_rmax_tol(&other.data.0, &tol.data.0))
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        matrix(self.data.0.debug_rmin_tol(&other.data.0, &tol.data.0))
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        matrix(self.data.0.debug_r1st_tol(&other.data.0, &tol.data.0))
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        matrix(self.data.0.debug_r2nd_tol(&other.data.0, &tol.data.0))
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        matrix(self.data.0.debug_ulps_tol(&other.data.0, &tol.data.0))
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T, const R: usize, const C: usize> AssertFloatEqAll for SMatrix<T, R, C>
+where
+    T: AssertFloatEqAll,
+    T::AllDebugTol: Sized,
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    type AllDebugTol = SMatrix<T::AllDebugTol, R, C>;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        matrix(self.data.0.debug_abs_all_tol(&other.data.0, tol))
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        matrix(self.data.0.debug_rmax_all_tol(&other.data.0, tol))
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        matrix(self.data.0.debug_rmin_all_tol(&other.data.0, tol))
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        matrix(self.data.0.debug_r1st_all_tol(&other.data.0, tol))
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        matrix(self.data.0.debug_r2nd_all_tol(&other.data.0, tol))
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Self,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        matrix(self.data.0.debug_ulps_all_tol(&other.data.0, tol))
+    }
+}
+
+// Quaternions are compared by their coordinates, in [x, y, z, w] order. Unit
+// quaternions are compared in the same way, with plain quaternions as their
+// tolerance, since a tolerance is not normalized.
+#[inline]
+fn quaternion<T>(coords: SMatrix<T, 4, 1>) -> Quaternion<T> {
+    Quaternion { coords }
+}
+
+impl<T: FloatEqUlpsTol> FloatEqUlpsTol for Quaternion<T>
+where
+    UlpsTol<T>: Sized,
+{
+    type UlpsTol = Quaternion<UlpsTol<T>>;
+}
+
+impl<T: UlpsTolOps> UlpsTolOps for Quaternion<T> {
+    #[inline]
+    fn saturating_add(&self, other: &Self) -> Self {
+        quaternion(self.coords.saturating_add(&other.coords))
+    }
+
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        quaternion(self.coords.max_elementwise(&other.coords))
+    }
+}
+
+impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for Quaternion<T> {
+    type DebugUlpsDiff = Quaternion<DebugUlpsDiff<T>>;
+}
+
+impl<T: TolOps> TolOps for Quaternion<T> {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        quaternion(self.coords.max_elementwise(&other.coords))
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        quaternion(self.coords.min_elementwise(&other.coords))
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        quaternion(self.coords.scale(factor))
+    }
+}
+
+impl<T: FloatEq> FloatEq for Quaternion<T>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    type Tol = Quaternion<T::Tol>;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.coords.eq_abs(&other.coords, &tol.coords)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.coords.eq_rmax(&other.coords, &tol.coords)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.coords.eq_rmin(&other.coords, &tol.coords)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.coords.eq_r1st(&other.coords, &tol.coords)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.coords.eq_r2nd(&other.coords, &tol.coords)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.coords.eq_ulps(&other.coords, &tol.coords)
+    }
+}
+
+impl<T: FloatEqBits> FloatEqBits for Quaternion<T> {
+    #[inline]
+    fn eq_bits(&self, other: &Self) -> bool {
+        self.coords.eq_bits(&other.coords)
+    }
+}
+
+impl<T: FloatEqAll> FloatEqAll for Quaternion<T> {
+    type AllTol = T::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.coords.eq_abs_all(&other.coords, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.coords.eq_rmax_all(&other.coords, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.coords.eq_rmin_all(&other.coords, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.coords.eq_r1st_all(&other.coords, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.coords.eq_r2nd_all(&other.coords, tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.coords.eq_ulps_all(&other.coords, tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEq for Quaternion<T>
+where
+    T: AssertFloatEq,
+    T::Tol: Sized,
+    T::DebugTol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    type DebugAbsDiff = Quaternion<T::DebugAbsDiff>;
+    type DebugTol = Quaternion<T::DebugTol>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        quaternion(self.coords.debug_abs_diff(&other.coords))
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        quaternion(self.coords.debug_ulps_diff(&other.coords))
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        quaternion(self.coords.debug_abs_tol(&other.coords, &tol.coords))
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        quaternion(self.coords.debug_rmax_tol(&other.coords, &tol.coords))
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        quaternion(self.coords.debug_rmin_tol(&other.coords, &tol.coords))
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        quaternion(self.coords.debug_r1st_tol(&other.coords, &tol.coords))
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        quaternion(self.coords.debug_r2nd_tol(&other.coords, &tol.coords))
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        quaternion(self.coords.debug_ulps_tol(&other.coords, &tol.coords))
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEqAll for Quaternion<T>
+where
+    T: AssertFloatEqAll,
+    T::AllDebugTol: Sized,
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    type AllDebugTol = Quaternion<T::AllDebugTol>;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        quaternion(self.coords.debug_abs_all_tol(&other.coords, tol))
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        quaternion(self.coords.debug_rmax_all_tol(&other.coords, tol))
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        quaternion(self.coords.debug_rmin_all_tol(&other.coords, tol))
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        quaternion(self.coords.debug_r1st_all_tol(&other.coords, tol))
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        quaternion(self.coords.debug_r2nd_all_tol(&other.coords, tol))
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Self,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        quaternion(self.coords.debug_ulps_all_tol(&other.coords, tol))
+    }
+}
+
+impl<T: FloatEq> FloatEq for Unit<Quaternion<T>>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    type Tol = Quaternion<T::Tol>;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
+
+    #[inline]
+    fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.as_ref().eq_abs(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.as_ref().eq_rmax(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.as_ref().eq_rmin(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.as_ref().eq_r1st(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+        self.as_ref().eq_r2nd(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.as_ref().eq_ulps(other.as_ref(), tol)
+    }
+}
+
+impl<T: FloatEqBits> FloatEqBits for Unit<Quaternion<T>> {
+    #[inline]
+    fn eq_bits(&self, other: &Self) -> bool {
+        self.as_ref().eq_bits(other.as_ref())
+    }
+}
+
+impl<T: FloatEqAll> FloatEqAll for Unit<Quaternion<T>> {
+    type AllTol = T::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.as_ref().eq_abs_all(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.as_ref().eq_rmax_all(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.as_ref().eq_rmin_all(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.as_ref().eq_r1st_all(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+        self.as_ref().eq_r2nd_all(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.as_ref().eq_ulps_all(other.as_ref(), tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEq for Unit<Quaternion<T>>
+where
+    T: AssertFloatEq,
+    T::Tol: Sized,
+    T::DebugTol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    type DebugAbsDiff = Quaternion<T::DebugAbsDiff>;
+    type DebugTol = Quaternion<T::DebugTol>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        self.as_ref().debug_abs_diff(other.as_ref())
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        self.as_ref().debug_ulps_diff(other.as_ref())
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        self.as_ref().debug_abs_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        self.as_ref().debug_rmax_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        self.as_ref().debug_rmin_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        self.as_ref().debug_r1st_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+        self.as_ref().debug_r2nd_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        self.as_ref().debug_ulps_tol(other.as_ref(), tol)
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
+impl<T> AssertFloatEqAll for Unit<Quaternion<T>>
+where
+    T: AssertFloatEqAll,
+    T::AllDebugTol: Sized,
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    type AllDebugTol = Quaternion<T::AllDebugTol>;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.as_ref().debug_abs_all_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.as_ref().debug_rmax_all_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.as_ref().debug_rmin_all_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.as_ref().debug_r1st_all_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+        self.as_ref().debug_r2nd_all_tol(other.as_ref(), tol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Self,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        self.as_ref().debug_ulps_all_tol(other.as_ref(), tol)
+    }
+}
@@ -1,6 +1,8 @@
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    CheckSet, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, MatrixScalar, TolOps, UlpsTol, UlpsTolOps,
 };
 use num_complex::Complex;
 
@@ -26,6 +28,24 @@ impl<T> ComplexUlps<T> {
     }
 }
 
+impl<T: UlpsTolOps> UlpsTolOps for ComplexUlps<T> {
+    #[inline]
+    fn saturating_add(&self, other: &Self) -> Self {
+        ComplexUlps {
+            re: self.re.saturating_add(&other.re),
+            im: self.im.saturating_add(&other.im),
+        }
+    }
+
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        ComplexUlps {
+            re: self.re.max_elementwise(&other.re),
+            im: self.im.max_elementwise(&other.im),
+        }
+    }
+}
+
 impl<T: FloatEqUlpsTol> FloatEqUlpsTol for Complex<T>
 where
     UlpsTol<T>: Sized,
@@ -47,12 +67,36 @@ pub type ComplexUlps32 = UlpsTol<Complex<f32>>;
 /// [`num::Complex64`]: https://docs.rs/num-complex/0.3/num_complex/type.Complex64.html
 pub type ComplexUlps64 = UlpsTol<Complex<f64>>;
 
+impl<T: TolOps> TolOps for Complex<T> {
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        Complex::new(
+            self.re.max_elementwise(&other.re),
+            self.im.max_elementwise(&other.im),
+        )
+    }
+
+    #[inline]
+    fn min_elementwise(&self, other: &Self) -> Self {
+        Complex::new(
+            self.re.min_elementwise(&other.re),
+            self.im.min_elementwise(&other.im),
+        )
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        Complex::new(self.re.scale(factor), self.im.scale(factor))
+    }
+}
+
 impl<T: FloatEq> FloatEq for Complex<T>
 where
     T::Tol: Sized,
     UlpsTol<T::Tol>: Sized,
 {
     type Tol = Complex<T::Tol>;
+    const SUPPORTED_CHECKS: CheckSet = T::SUPPORTED_CHECKS;
 
     #[inline]
     fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
@@ -85,6 +129,13 @@ where
     }
 }
 
+impl<T: FloatEqBits> FloatEqBits for Complex<T> {
+    #[inline]
+    fn eq_bits(&self, other: &Self) -> bool {
+        self.re.eq_bits(&other.re) && self.im.eq_bits(&other.im)
+    }
+}
+
 impl<T: FloatEqAll> FloatEqAll for Complex<T> {
     type AllTol = T::AllTol;
 
@@ -119,6 +170,7 @@ impl<T: FloatEqAll> FloatEqAll for Complex<T> {
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<T> AssertFloatEq for Complex<T>
 where
     T: AssertFloatEq,
@@ -198,6 +250,7 @@ where
     }
 }
 
+#[cfg(not(feature = "checks-only"))]
 impl<T> AssertFloatEqAll for Complex<T>
 where
     T: AssertFloatEqAll,
@@ -261,3 +314,23 @@ where
         }
     }
 }
+
+impl MatrixScalar for Complex<f32> {
+    const ZERO: Self = Complex { re: 0.0, im: 0.0 };
+    const ONE: Self = Complex { re: 1.0, im: 0.0 };
+
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+}
+
+impl MatrixScalar for Complex<f64> {
+    const ZERO: Self = Complex { re: 0.0, im: 0.0 };
+    const ONE: Self = Complex { re: 1.0, im: 0.0 };
+
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+}
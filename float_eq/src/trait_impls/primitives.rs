@@ -1,8 +1,13 @@
 #![allow(clippy::float_cmp)]
 
+use crate::primitive::sealed::Sealed;
+#[cfg(not(feature = "checks-only"))]
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    AssertFloatEq, AssertFloatEqAll, DebugCategories, DebugUlpsDiff, FloatCategory, UlpsDiffReason,
+};
+use crate::{
+    FloatBits, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    FloatEqWeighted, FloatPrimitive, UlpsMetric, UlpsTol,
 };
 
 macro_rules! impl_traits {
@@ -23,6 +28,28 @@ macro_rules! impl_traits {
                 const MASK: $uint = !(1 << ((::core::mem::size_of::<$float>() * 8) - 1));
                 $float::from_bits(value.to_bits() & MASK)
             }
+
+            // a negative or NaN tolerance is never met, which is easy to miss
+            // when it is computed, so catch it in debug builds when asked to
+            #[cfg(feature = "validate-tol")]
+            #[inline]
+            pub(crate) fn validate_tol(check: &str, tol: $float) {
+                debug_assert!(
+                    !tol.is_nan(),
+                    "float_eq: the `{}` tolerance is NaN, which is never met, so only identical values may be equal",
+                    check
+                );
+                debug_assert!(
+                    tol >= 0.0,
+                    "float_eq: the `{}` tolerance `{:?}` is negative, which is never met, so only identical values may be equal",
+                    check,
+                    tol
+                );
+            }
+
+            #[cfg(not(feature = "validate-tol"))]
+            #[inline(always)]
+            pub(crate) fn validate_tol(_check: &str, _tol: $float) {}
         }
 
         impl FloatEqUlpsTol for $float {
@@ -38,12 +65,14 @@ macro_rules! impl_traits {
 
             #[inline]
             fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $float::validate_tol("abs", *tol);
                 // the PartialEq check covers equality of infinities
                 self == other || $float::abs(self - other).le(tol)
             }
 
             #[inline]
             fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $float::validate_tol("rmax", *tol);
                 // the PartialEq check covers equality of infinities
                 self == other || {
                     let largest = $float::abs(*self).max($float::abs(*other));
@@ -54,6 +83,7 @@ macro_rules! impl_traits {
 
             #[inline]
             fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $float::validate_tol("rmin", *tol);
                 // the PartialEq check covers equality of infinities
                 self == other || {
                     let largest = $float::abs(*self).min($float::abs(*other));
@@ -64,6 +94,7 @@ macro_rules! impl_traits {
 
             #[inline]
             fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $float::validate_tol("r1st", *tol);
                 // the PartialEq check covers equality of infinities
                 self == other || {
                     let tol = $float::abs(*self) * tol;
@@ -73,6 +104,7 @@ macro_rules! impl_traits {
 
             #[inline]
             fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $float::validate_tol("r2nd", *tol);
                 // the PartialEq check covers equality of infinities
                 self == other || {
                     let tol = $float::abs(*other) * tol;
@@ -82,17 +114,7 @@ macro_rules! impl_traits {
 
             #[inline]
             fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
-                if self.is_nan() || other.is_nan() {
-                    false // NaNs are never equal
-                } else if self.is_sign_positive() != other.is_sign_positive() {
-                    self == other // account for zero == negative zero
-                } else {
-                    let a = self.to_bits();
-                    let b = other.to_bits();
-                    let max = a.max(b);
-                    let min = a.min(b);
-                    (max - min).le(tol)
-                }
+                FloatBits::eq_ulps_within(*self, *other, *tol)
             }
         }
 
@@ -128,8 +150,38 @@ macro_rules! impl_traits {
             fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
                 self.eq_ulps(other, tol)
             }
+
+            #[cfg(feature = "simd")]
+            #[inline]
+            fn eq_abs_all_elements(a: &[Self], b: &[Self], tol: &Self::AllTol) -> bool {
+                if !a.is_empty() {
+                    $float::validate_tol("abs", *tol);
+                }
+                let tol = *tol;
+                crate::chunked::all_lanes(a, b, |a, b| (a == b) | ($float::abs(a - b) <= tol))
+            }
+
+            #[cfg(feature = "simd")]
+            #[inline]
+            fn eq_ulps_all_elements(a: &[Self], b: &[Self], tol: &UlpsTol<Self::AllTol>) -> bool {
+                const MAGNITUDE: $uint = !0 >> 1;
+                // to_bits is not const until Rust 1.83
+                let infinity = $float::INFINITY.to_bits();
+                let tol = *tol;
+                crate::chunked::all_lanes(a, b, |a, b| {
+                    // The same rules as FloatBits::eq_ulps_within, without
+                    // branching on them.
+                    let (a, b) = (a.to_bits(), b.to_bits());
+                    let (a_mag, b_mag) = (a & MAGNITUDE, b & MAGNITUDE);
+                    let is_nan = (a_mag > infinity) | (b_mag > infinity);
+                    let same_sign = (a ^ b) <= MAGNITUDE;
+                    let diff = a_mag.max(b_mag) - a_mag.min(b_mag);
+                    !is_nan & ((same_sign & (diff <= tol)) | (a_mag | b_mag == 0))
+                })
+            }
         }
 
+        #[cfg(not(feature = "checks-only"))]
         impl AssertFloatEq for $float {
             type DebugAbsDiff = Self;
             type DebugTol = Self::Tol;
@@ -141,19 +193,27 @@ macro_rules! impl_traits {
 
             #[inline]
             fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
-                if self == other {
-                    Some(0)
-                } else if self.is_nan() || other.is_nan() {
-                    None
-                } else if self.is_sign_positive() != other.is_sign_positive() {
-                    None
-                } else {
-                    let a = self.to_bits();
-                    let b = other.to_bits();
-                    let max = a.max(b);
-                    let min = a.min(b);
-                    Some(max - min)
-                }
+                FloatBits::ulps_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_categories(&self, other: &Self) -> Option<DebugCategories> {
+                DebugCategories::new(
+                    FloatCategory::classify(self.is_nan(), self.is_infinite(), self.is_subnormal()),
+                    FloatCategory::classify(
+                        other.is_nan(),
+                        other.is_infinite(),
+                        other.is_subnormal(),
+                    ),
+                )
+            }
+
+            #[inline]
+            fn debug_ulps_diff_reason(&self, other: &Self) -> Option<UlpsDiffReason> {
+                UlpsDiffReason::classify(
+                    self.is_nan() || other.is_nan(),
+                    self.is_sign_positive() != other.is_sign_positive() && self != other,
+                )
             }
 
             #[inline]
@@ -191,6 +251,7 @@ macro_rules! impl_traits {
             }
         }
 
+        #[cfg(not(feature = "checks-only"))]
         impl AssertFloatEqAll for $float {
             type AllDebugTol = Self::AllTol;
 
@@ -228,6 +289,116 @@ macro_rules! impl_traits {
                 self.debug_ulps_tol(other, tol)
             }
         }
+
+        impl FloatEqBits for $float {
+            #[inline]
+            fn eq_bits(&self, other: &Self) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+        }
+
+        impl FloatEqWeighted for $float {
+            #[inline]
+            fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64 {
+                if self == other {
+                    0.0
+                } else {
+                    f64::from(*weights) * f64::from($float::abs(self - other)) / f64::from(*tol)
+                }
+            }
+        }
+
+        impl Sealed for $float {}
+
+        impl FloatBits for $float {
+            type Bits = $uint;
+
+            #[inline]
+            fn to_float_bits(self) -> Self::Bits {
+                self.to_bits()
+            }
+
+            #[inline]
+            fn from_float_bits(bits: Self::Bits) -> Self {
+                $float::from_bits(bits)
+            }
+
+            #[inline]
+            fn is_nan_value(self) -> bool {
+                self.is_nan()
+            }
+
+            #[inline]
+            fn is_sign_positive_value(self) -> bool {
+                self.is_sign_positive()
+            }
+        }
+
+        impl UlpsMetric for $float {
+            type Ulps = $uint;
+
+            #[inline]
+            fn ulps_between(&self, other: &Self) -> Option<Self::Ulps> {
+                FloatBits::ulps_diff(*self, *other)
+            }
+        }
+
+        impl FloatPrimitive for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $float::EPSILON;
+            const INFINITY: Self = $float::INFINITY;
+            const NAN: Self = $float::NAN;
+
+            #[inline]
+            fn abs_value(self) -> Self {
+                $float::abs(self)
+            }
+
+            #[inline]
+            fn ulps_position(self) -> i64 {
+                let magnitude = $float::abs(self).to_bits() as i64;
+                if self.is_sign_negative() {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            #[inline]
+            fn from_ulps_position(position: i64) -> Self {
+                let infinity = $float::INFINITY.to_bits() as u64;
+                let magnitude = $float::from_bits(position.unsigned_abs().min(infinity) as $uint);
+                if position < 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            #[inline]
+            fn from_ratio(num: u64, den: u64) -> Self {
+                (num as f64 / den as f64) as $float
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn fused_mul_add(self, a: Self, b: Self) -> Self {
+                $float::mul_add(self, a, b)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn sqrt_value(self) -> Self {
+                $float::sqrt(self)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn round_value(self) -> Self {
+                $float::round(self)
+            }
+        }
     };
 }
 
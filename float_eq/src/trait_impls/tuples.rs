@@ -1,4 +1,10 @@
-use crate::{AssertFloatEq, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol};
+#[cfg(not(feature = "checks-only"))]
+use crate::{AssertFloatEq, AssertFloatEqAll};
+use crate::{
+    CheckSet, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqBits, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, FloatEqWeighted, UlpsTol,
+};
+#[cfg(not(feature = "checks-only"))]
 use core::fmt;
 
 impl FloatEqUlpsTol for () {
@@ -43,6 +49,21 @@ impl FloatEq for () {
     }
 }
 
+impl FloatEqBits for () {
+    #[inline]
+    fn eq_bits(&self, _other: &()) -> bool {
+        true
+    }
+}
+
+impl FloatEqWeighted for () {
+    #[inline]
+    fn weighted_error(&self, _other: &(), _tol: &(), _weights: &()) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(not(feature = "checks-only"))]
 impl AssertFloatEq for () {
     type DebugAbsDiff = ();
     type DebugTol = ();
@@ -100,6 +121,7 @@ macro_rules! tuple_impls {
                 $(UlpsTol<$T::Tol>: Sized,)+
             {
                 type Tol = ($($T::Tol,)+);
+                const SUPPORTED_CHECKS: CheckSet = CheckSet::ALL$(.intersection($T::SUPPORTED_CHECKS))+;
 
                 #[inline]
                 fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
@@ -132,6 +154,29 @@ macro_rules! tuple_impls {
                 }
             }
 
+            impl<$($T:FloatEqBits),+> FloatEqBits for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+            {
+                #[inline]
+                fn eq_bits(&self, other: &Self) -> bool {
+                    $(self.$idx.eq_bits(&other.$idx))&&+
+                }
+            }
+
+            impl<$($T:FloatEqWeighted),+> FloatEqWeighted for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+                $($T::Tol: Sized,)+
+                $(UlpsTol<$T::Tol>: Sized,)+
+            {
+                #[inline]
+                fn weighted_error(&self, other: &Self, tol: &Self::Tol, weights: &Self::Tol) -> f64 {
+                    0.0 $(+ self.$idx.weighted_error(&other.$idx, &tol.$idx, &weights.$idx))+
+                }
+            }
+
+            #[cfg(not(feature = "checks-only"))]
             impl<$($T:AssertFloatEq + fmt::Debug),+> AssertFloatEq for ($($T,)+)
             where
                 last_type!($($T,)+): ?Sized,
@@ -183,10 +228,95 @@ macro_rules! tuple_impls {
                     ($(self.$idx.debug_ulps_tol(&other.$idx, &tol.$idx),)+)
                 }
             }
+
+            tuple_all_impls! { $(($idx) -> $T)+ }
         )+
     };
 }
 
+// Tuples support the `_all` checks if all of their fields share an AllTol, which
+// is taken from the first field.
+macro_rules! tuple_all_impls {
+    (($idx0:tt) -> $T0:ident $(($idx:tt) -> $T:ident)*) => {
+        impl<$T0: FloatEqAll, $($T: FloatEqAll<AllTol = $T0::AllTol>),*> FloatEqAll for ($T0, $($T,)*)
+        where
+            last_type!($T0, $($T,)*): ?Sized,
+        {
+            type AllTol = $T0::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.$idx0.eq_abs_all(&other.$idx0, tol) $(&& self.$idx.eq_abs_all(&other.$idx, tol))*
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.$idx0.eq_rmax_all(&other.$idx0, tol) $(&& self.$idx.eq_rmax_all(&other.$idx, tol))*
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.$idx0.eq_rmin_all(&other.$idx0, tol) $(&& self.$idx.eq_rmin_all(&other.$idx, tol))*
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.$idx0.eq_r1st_all(&other.$idx0, tol) $(&& self.$idx.eq_r1st_all(&other.$idx, tol))*
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.$idx0.eq_r2nd_all(&other.$idx0, tol) $(&& self.$idx.eq_r2nd_all(&other.$idx, tol))*
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.$idx0.eq_ulps_all(&other.$idx0, tol) $(&& self.$idx.eq_ulps_all(&other.$idx, tol))*
+            }
+        }
+
+        #[cfg(not(feature = "checks-only"))]
+        impl<$T0: AssertFloatEqAll, $($T: AssertFloatEqAll<AllTol = $T0::AllTol>),*> AssertFloatEqAll for ($T0, $($T,)*)
+        where
+            last_type!($T0, $($T,)*): ?Sized,
+            UlpsTol<$T0::AllDebugTol>: Sized,
+            $(UlpsTol<$T::AllDebugTol>: Sized,)*
+        {
+            type AllDebugTol = ($T0::AllDebugTol, $($T::AllDebugTol,)*);
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                (self.$idx0.debug_abs_all_tol(&other.$idx0, tol), $(self.$idx.debug_abs_all_tol(&other.$idx, tol),)*)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                (self.$idx0.debug_rmax_all_tol(&other.$idx0, tol), $(self.$idx.debug_rmax_all_tol(&other.$idx, tol),)*)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                (self.$idx0.debug_rmin_all_tol(&other.$idx0, tol), $(self.$idx.debug_rmin_all_tol(&other.$idx, tol),)*)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                (self.$idx0.debug_r1st_all_tol(&other.$idx0, tol), $(self.$idx.debug_r1st_all_tol(&other.$idx, tol),)*)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                (self.$idx0.debug_r2nd_all_tol(&other.$idx0, tol), $(self.$idx.debug_r2nd_all_tol(&other.$idx, tol),)*)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> UlpsTol<Self::AllDebugTol> {
+                (self.$idx0.debug_ulps_all_tol(&other.$idx0, tol), $(self.$idx.debug_ulps_all_tol(&other.$idx, tol),)*)
+            }
+        }
+    };
+}
+
 macro_rules! last_type {
     ($a:ident,) => { $a };
     ($a:ident, $($rest_a:ident,)+) => { last_type!($($rest_a,)+) };
@@ -1,3 +1,7 @@
+use crate::{CheckSet, TolOps};
+#[cfg(not(feature = "checks-only"))]
+use crate::{DebugCategories, SaturatingUlpsDiff, UlpsDiffReason};
+#[cfg(not(feature = "checks-only"))]
 use core::fmt;
 
 /// Per-field tolerances for [ULPs comparisons](https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison).
@@ -85,6 +89,20 @@ pub trait FloatEq<Rhs: ?Sized = Self> {
     /// considered equal.
     type Tol: ?Sized + FloatEqUlpsTol;
 
+    /// The checks that are meaningful for this type, which is all of them
+    /// unless it is overridden.
+    ///
+    /// A type should leave out any check that it cannot implement, such as a
+    /// wrapper over another library that has no equivalent of it, so that
+    /// code applying checks chosen at runtime, such as [`FloatCmp`], may skip
+    /// or flag those that do not apply rather than failing. Composite types
+    /// should support only the checks that are supported by all of their
+    /// fields, via [`CheckSet::intersection`].
+    ///
+    /// [`FloatCmp`]: struct.FloatCmp.html
+    /// [`CheckSet::intersection`]: struct.CheckSet.html#method.intersection
+    const SUPPORTED_CHECKS: CheckSet = CheckSet::ALL;
+
     /// Check whether `self` is equal to `other`, using an [absolute tolerance
     /// comparison].
     ///
@@ -138,6 +156,39 @@ pub trait FloatEq<Rhs: ?Sized = Self> {
         !self.eq_rel(other, tol)
     }
 
+    /// Check whether `self` is equal to `other`, using a [relative tolerance
+    /// comparison] where `tol` is a percentage, so that a `tol` of `0.1`
+    /// allows a difference of 0.1% of the larger magnitude.
+    ///
+    /// Equal to `self.eq_rmax(other, &tol.scale(0.01))`, so is available to
+    /// any type whose tolerance implements [`TolOps`], there is no need to
+    /// reimplement this for your own types.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`TolOps`]: trait.TolOps.html
+    #[inline]
+    fn eq_percent(&self, other: &Rhs, tol: &Self::Tol) -> bool
+    where
+        Self::Tol: TolOps,
+    {
+        self.eq_rmax(other, &tol.scale(0.01))
+    }
+
+    /// Check whether `self` is not equal to `other`, using a [relative tolerance
+    /// comparison] where `tol` is a percentage.
+    ///
+    /// Equal to `!self.eq_percent(other, tol)`, there is no need to reimplement
+    /// this for your own types.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    #[inline]
+    fn ne_percent(&self, other: &Rhs, tol: &Self::Tol) -> bool
+    where
+        Self::Tol: TolOps,
+    {
+        !self.eq_percent(other, tol)
+    }
+
     /// Check whether `self` is equal to `other`, using a [relative tolerance
     /// comparison], scaled to the granularity of the input with the largest
     /// magnitude.
@@ -293,7 +344,12 @@ pub trait FloatEq<Rhs: ?Sized = Self> {
     /// # }}
     /// ```
     ///
+    /// For primitive types, and any other type that implements [`FloatBits`],
+    /// this is provided by [`FloatBits::eq_ulps_within`].
+    ///
     /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    /// [`FloatBits`]: trait.FloatBits.html
+    /// [`FloatBits::eq_ulps_within`]: trait.FloatBits.html#method.eq_ulps_within
     fn eq_ulps(&self, other: &Rhs, tol: &UlpsTol<Self::Tol>) -> bool;
 
     /// Check whether `self` is not equal to `other`, using an [ULPs comparison].
@@ -306,6 +362,26 @@ pub trait FloatEq<Rhs: ?Sized = Self> {
     fn ne_ulps(&self, other: &Rhs, tol: &UlpsTol<Self::Tol>) -> bool {
         !self.eq_ulps(other, tol)
     }
+
+    /// Check whether `self` is equal to `other`, using an absolute tolerance
+    /// comparison near zero and an ULPs comparison otherwise.
+    ///
+    /// Equal to `self.eq_abs(other, tol) || self.eq_ulps(other, ulps_tol)`,
+    /// there is no need to reimplement this for your own types.
+    #[inline]
+    fn eq_abs_ulps(&self, other: &Rhs, tol: &Self::Tol, ulps_tol: &UlpsTol<Self::Tol>) -> bool {
+        self.eq_abs(other, tol) || self.eq_ulps(other, ulps_tol)
+    }
+
+    /// Check whether `self` is not equal to `other`, using an absolute
+    /// tolerance comparison near zero and an ULPs comparison otherwise.
+    ///
+    /// Equal to `!self.eq_abs_ulps(other, tol, ulps_tol)`, there is no need to
+    /// reimplement this for your own types.
+    #[inline]
+    fn ne_abs_ulps(&self, other: &Rhs, tol: &Self::Tol, ulps_tol: &UlpsTol<Self::Tol>) -> bool {
+        !self.eq_abs_ulps(other, tol, ulps_tol)
+    }
 }
 
 /// Compare IEEE floating point values for equality using a uniform tolerance.
@@ -385,6 +461,38 @@ pub trait FloatEqAll<Rhs: ?Sized = Self> {
         !self.eq_rel_all(other, tol)
     }
 
+    /// Check whether `self` is equal to `other`, using a [relative tolerance
+    /// comparison] where `tol` is a percentage.
+    ///
+    /// Equal to `self.eq_rmax_all(other, &tol.scale(0.01))`, so is available
+    /// to any type whose tolerance implements [`TolOps`], there is no need to
+    /// reimplement this for your own types.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    /// [`TolOps`]: trait.TolOps.html
+    #[inline]
+    fn eq_percent_all(&self, other: &Rhs, tol: &Self::AllTol) -> bool
+    where
+        Self::AllTol: TolOps,
+    {
+        self.eq_rmax_all(other, &tol.scale(0.01))
+    }
+
+    /// Check whether `self` is not equal to `other`, using a [relative tolerance
+    /// comparison] where `tol` is a percentage.
+    ///
+    /// Equal to `!self.eq_percent_all(other, tol)`, there is no need to
+    /// reimplement this for your own types.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    #[inline]
+    fn ne_percent_all(&self, other: &Rhs, tol: &Self::AllTol) -> bool
+    where
+        Self::AllTol: TolOps,
+    {
+        !self.eq_percent_all(other, tol)
+    }
+
     /// Check whether `self` is equal to `other`, using a [relative tolerance
     /// comparison].
     ///
@@ -482,6 +590,74 @@ pub trait FloatEqAll<Rhs: ?Sized = Self> {
     fn ne_ulps_all(&self, other: &Rhs, tol: &UlpsTol<Self::AllTol>) -> bool {
         !self.eq_ulps_all(other, tol)
     }
+
+    /// Check whether `self` is equal to `other`, using an absolute tolerance
+    /// comparison near zero and an ULPs comparison otherwise.
+    ///
+    /// Equal to `self.eq_abs_all(other, tol) || self.eq_ulps_all(other, ulps_tol)`,
+    /// there is no need to reimplement this for your own types.
+    #[inline]
+    fn eq_abs_ulps_all(
+        &self,
+        other: &Rhs,
+        tol: &Self::AllTol,
+        ulps_tol: &UlpsTol<Self::AllTol>,
+    ) -> bool {
+        self.eq_abs_all(other, tol) || self.eq_ulps_all(other, ulps_tol)
+    }
+
+    /// Check whether `self` is not equal to `other`, using an absolute
+    /// tolerance comparison near zero and an ULPs comparison otherwise.
+    ///
+    /// Equal to `!self.eq_abs_ulps_all(other, tol, ulps_tol)`, there is no need
+    /// to reimplement this for your own types.
+    #[inline]
+    fn ne_abs_ulps_all(
+        &self,
+        other: &Rhs,
+        tol: &Self::AllTol,
+        ulps_tol: &UlpsTol<Self::AllTol>,
+    ) -> bool {
+        !self.eq_abs_ulps_all(other, tol, ulps_tol)
+    }
+
+    /// Check whether each element of `a` is equal to the element of `b` at
+    /// the same position, which must be of the same length, using an
+    /// [absolute tolerance comparison].
+    ///
+    /// Arrays and slices compare their elements via this, so that `f32` and
+    /// `f64` may compare them in chunks with the `simd` feature. There is no
+    /// need to reimplement this for your own types.
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[doc(hidden)]
+    #[inline]
+    fn eq_abs_all_elements(a: &[Self], b: &[Rhs], tol: &Self::AllTol) -> bool
+    where
+        Self: Sized,
+        Rhs: Sized,
+    {
+        a.iter().zip(b.iter()).all(|(a, b)| a.eq_abs_all(b, tol))
+    }
+
+    /// Check whether each element of `a` is equal to the element of `b` at
+    /// the same position, which must be of the same length, using an [ULPs
+    /// comparison].
+    ///
+    /// Arrays and slices compare their elements via this, so that `f32` and
+    /// `f64` may compare them in chunks with the `simd` feature. There is no
+    /// need to reimplement this for your own types.
+    ///
+    /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    #[doc(hidden)]
+    #[inline]
+    fn eq_ulps_all_elements(a: &[Self], b: &[Rhs], tol: &UlpsTol<Self::AllTol>) -> bool
+    where
+        Self: Sized,
+        Rhs: Sized,
+    {
+        a.iter().zip(b.iter()).all(|(a, b)| a.eq_ulps_all(b, tol))
+    }
 }
 
 /// Debug context for when an assert fails.
@@ -492,6 +668,7 @@ pub trait FloatEqAll<Rhs: ?Sized = Self> {
 ///
 /// [How to compare custom types]: https://jtempest.github.io/float_eq-rs/book/how_to/compare_custom_types.html
 /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+#[cfg(not(feature = "checks-only"))]
 pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
     /// The absolute difference between two values, displayed to the user via
     /// `fmt::Debug` when an assert fails.
@@ -554,9 +731,65 @@ pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
     /// # }}
     /// ```
     ///
+    /// For primitive types, and any other type that implements [`FloatBits`],
+    /// this is provided by [`FloatBits::ulps_diff`].
+    ///
     /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    /// [`FloatBits`]: trait.FloatBits.html
+    /// [`FloatBits::ulps_diff`]: trait.FloatBits.html#method.ulps_diff
     fn debug_ulps_diff(&self, other: &Rhs) -> DebugUlpsDiff<Self::DebugAbsDiff>;
 
+    /// The [`debug_ulps_diff`] between the operands in its saturating form,
+    /// where a difference that cannot be counted, such as between values of
+    /// differing sign, is the maximum ULPs value rather than `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float_eq::AssertFloatEq;
+    /// let a = [1.0f32, -1.0, 2.0];
+    /// let b = [1.000_000_1f32, 1.0, 2.0];
+    /// assert_eq!(a.debug_ulps_diff(&b), [Some(1), None, Some(0)]);
+    /// assert_eq!(a.debug_ulps_diff_saturating(&b), [1, u32::MAX, 0]);
+    /// ```
+    ///
+    /// [`debug_ulps_diff`]: #tymethod.debug_ulps_diff
+    #[inline]
+    fn debug_ulps_diff_saturating(
+        &self,
+        other: &Rhs,
+    ) -> <DebugUlpsDiff<Self::DebugAbsDiff> as SaturatingUlpsDiff>::Saturated
+    where
+        DebugUlpsDiff<Self::DebugAbsDiff>: SaturatingUlpsDiff,
+    {
+        self.debug_ulps_diff(other).saturating()
+    }
+
+    /// The categories of operands that are likely to have caused a comparison
+    /// to fail, such as NaN or infinity, displayed as a note when an assert
+    /// fails.
+    ///
+    /// Returns `None` by default. Implementations over primitive types return
+    /// `None` only if both operands are zero or normal, and wrapper types
+    /// forward to the types they wrap.
+    #[inline]
+    fn debug_categories(&self, other: &Rhs) -> Option<DebugCategories> {
+        let _ = other;
+        None
+    }
+
+    /// The reason that the ULPs difference between the operands is not a count
+    /// of the values between them, such as their differing in sign, displayed
+    /// in place of the ULPs difference when an assert fails.
+    ///
+    /// Returns `None` by default. Implementations over primitive types return
+    /// `None` only if the operands have a ULPs difference, and wrapper types
+    /// forward to the types they wrap.
+    #[inline]
+    fn debug_ulps_diff_reason(&self, other: &Rhs) -> Option<UlpsDiffReason> {
+        let _ = other;
+        None
+    }
+
     /// The tolerance used by an `abs` [comparison], displayed when an assert fails.
     ///
     /// [comparison]: index.html#comparison-algorithms
@@ -573,6 +806,21 @@ pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
         self.debug_rmax_tol(other, tol)
     }
 
+    /// The tolerance used by a `percent` [comparison], displayed when an assert
+    /// fails.
+    ///
+    /// Equivalent to `self.debug_rmax_tol(other, &tol.scale(0.01))`, there is
+    /// no need to reimplement this for your own types.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    #[inline]
+    fn debug_percent_tol(&self, other: &Rhs, tol: &Self::Tol) -> Self::DebugTol
+    where
+        Self::Tol: TolOps,
+    {
+        self.debug_rmax_tol(other, &tol.scale(0.01))
+    }
+
     /// The tolerance used by an `rmax` [comparison], displayed when an assert fails.
     ///
     /// Returns `tol` scaled by the magnitude of the larger operand.
@@ -607,6 +855,29 @@ pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
     fn debug_ulps_tol(&self, other: &Rhs, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
     where
         UlpsTol<Self::DebugTol>: Sized;
+
+    /// The tolerances used by an `abs_ulps` [comparison], displayed when an
+    /// assert fails.
+    ///
+    /// Returns the `abs` and `ulps` debug tolerances as a pair, there is no
+    /// need to reimplement this for your own types.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    #[inline]
+    fn debug_abs_ulps_tol(
+        &self,
+        other: &Rhs,
+        tol: &Self::Tol,
+        ulps_tol: &UlpsTol<Self::Tol>,
+    ) -> (Self::DebugTol, UlpsTol<Self::DebugTol>)
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        (
+            self.debug_abs_tol(other, tol),
+            self.debug_ulps_tol(other, ulps_tol),
+        )
+    }
 }
 
 /// Debug context for when an assert using an `all` check fails.
@@ -617,6 +888,7 @@ pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
 ///
 /// [How to compare custom types]: https://jtempest.github.io/float_eq-rs/book/how_to/compare_custom_types.html
 /// [ULPs]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+#[cfg(not(feature = "checks-only"))]
 pub trait AssertFloatEqAll<Rhs: ?Sized = Self>: FloatEqAll<Rhs> {
     /// Displayed to the user when an assert fails, using `fmt::Debug`.
     ///
@@ -639,6 +911,21 @@ pub trait AssertFloatEqAll<Rhs: ?Sized = Self>: FloatEqAll<Rhs> {
         self.debug_rmax_all_tol(other, tol)
     }
 
+    /// The tolerance used by a `percent_all` [comparison], displayed when an
+    /// assert fails.
+    ///
+    /// Equivalent to `self.debug_rmax_all_tol(other, &tol.scale(0.01))`, there
+    /// is no need to reimplement this for your own types.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    #[inline]
+    fn debug_percent_all_tol(&self, other: &Rhs, tol: &Self::AllTol) -> Self::AllDebugTol
+    where
+        Self::AllTol: TolOps,
+    {
+        self.debug_rmax_all_tol(other, &tol.scale(0.01))
+    }
+
     /// The tolerance used by an `rmax_all` [comparison], displayed when an assert fails.
     ///
     /// Returns `tol` scaled by the magnitude of the larger operand.
@@ -678,4 +965,153 @@ pub trait AssertFloatEqAll<Rhs: ?Sized = Self>: FloatEqAll<Rhs> {
     ) -> UlpsTol<Self::AllDebugTol>
     where
         UlpsTol<Self::AllDebugTol>: Sized;
+
+    /// The tolerances used by an `abs_ulps_all` [comparison], displayed when an
+    /// assert fails.
+    ///
+    /// Returns the `abs_all` and `ulps_all` debug tolerances as a pair, there is
+    /// no need to reimplement this for your own types.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    #[inline]
+    fn debug_abs_ulps_all_tol(
+        &self,
+        other: &Rhs,
+        tol: &Self::AllTol,
+        ulps_tol: &UlpsTol<Self::AllTol>,
+    ) -> (Self::AllDebugTol, UlpsTol<Self::AllDebugTol>)
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        (
+            self.debug_abs_all_tol(other, tol),
+            self.debug_ulps_all_tol(other, ulps_tol),
+        )
+    }
+}
+
+/// Compare IEEE floating point values for bitwise equality.
+///
+/// Two values are bitwise equal if their representations are identical, so
+/// unlike the tolerance based checks of [`FloatEq`], `-0.0` is not equal to
+/// `0.0` and NaNs are equal to each other only if their payloads match. This is
+/// used by the `bits` check of the [`float_eq!`] and [`assert_float_eq!`]
+/// macros, which is useful for reproducibility tests.
+///
+/// This trait may be derived for structs and enums with
+/// `#[derive(FloatEqBits)]` when the `derive` feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// # use float_eq::FloatEqBits;
+/// assert!(1.0f32.eq_bits(&1.0));
+/// assert!(f64::NAN.eq_bits(&f64::NAN));
+/// assert!(0.0f32.ne_bits(&-0.0));
+/// assert!([1.0f32, 2.0].ne_bits(&[1.0, 2.000_000_2]));
+/// ```
+///
+/// [`FloatEq`]: trait.FloatEq.html
+/// [`float_eq!`]: macro.float_eq.html
+/// [`assert_float_eq!`]: macro.assert_float_eq.html
+pub trait FloatEqBits<Rhs: ?Sized = Self> {
+    /// Check whether `self` is bitwise equal to `other`.
+    ///
+    /// Implementations over primitive types should be the equivalent of:
+    ///
+    /// ```
+    /// # trait TestFloatEqBits { fn eq_bits(&self, other: &Self) -> bool; }
+    /// # impl TestFloatEqBits for f32 {
+    /// # fn eq_bits(&self, other: &Self) -> bool {
+    /// self.to_bits() == other.to_bits()
+    /// # }}
+    /// ```
+    fn eq_bits(&self, other: &Rhs) -> bool;
+
+    /// Check whether `self` is not bitwise equal to `other`.
+    ///
+    /// Equal to `!self.eq_bits(other)`, there is no need to reimplement this
+    /// for your own types.
+    #[inline]
+    fn ne_bits(&self, other: &Rhs) -> bool {
+        !self.eq_bits(other)
+    }
+}
+
+/// Compare IEEE floating point values using a weighted sum of per-field errors.
+///
+/// Each field's absolute difference is normalized by its tolerance and then
+/// multiplied by its weight, and the results summed. Two values are considered
+/// equal if this total is no greater than a given bound. This is useful for
+/// composite types where an individual field may slightly exceed its tolerance
+/// as long as the overall state is acceptable.
+///
+/// This trait may be derived for structs and enums with
+/// `#[derive(FloatEqWeighted)]` when the `derive` feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// # use float_eq::FloatEqWeighted;
+/// let a = [1.0f32, 2.0];
+/// let b = [1.2f32, 2.1];
+/// let tol = [0.1, 0.1];
+///
+/// // the first field is twice its tolerance, but only weighted by a quarter
+/// let weights = [0.25, 1.0];
+/// assert!(a.eq_weighted(&b, &tol, &weights, 1.6));
+/// assert!(a.ne_weighted(&b, &tol, &weights, 1.4));
+/// ```
+pub trait FloatEqWeighted<Rhs: ?Sized = Self>: FloatEq<Rhs> {
+    /// The sum of each field's absolute difference, divided by that field's
+    /// tolerance and multiplied by that field's weight.
+    ///
+    /// Fields that compare equal contribute nothing to the total, even if
+    /// their tolerance is zero. Implementations over primitive types should be
+    /// the equivalent of:
+    ///
+    /// ```
+    /// # trait TestFloatEqWeighted { fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64; }
+    /// # impl TestFloatEqWeighted for f32 {
+    /// # fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64 {
+    /// if self == other {
+    ///     0.0
+    /// } else {
+    ///     f64::from(*weights) * f64::from((self - other).abs()) / f64::from(*tol)
+    /// }
+    /// # }}
+    /// ```
+    fn weighted_error(&self, other: &Rhs, tol: &Self::Tol, weights: &Self::Tol) -> f64;
+
+    /// Check whether `self` is equal to `other`, using a weighted sum of
+    /// per-field errors.
+    ///
+    /// Equal to `self.weighted_error(other, tol, weights) <= max_total`, there
+    /// is no need to reimplement this for your own types.
+    #[inline]
+    fn eq_weighted(
+        &self,
+        other: &Rhs,
+        tol: &Self::Tol,
+        weights: &Self::Tol,
+        max_total: f64,
+    ) -> bool {
+        self.weighted_error(other, tol, weights) <= max_total
+    }
+
+    /// Check whether `self` is not equal to `other`, using a weighted sum of
+    /// per-field errors.
+    ///
+    /// Equal to `!self.eq_weighted(other, tol, weights, max_total)`, there is no
+    /// need to reimplement this for your own types.
+    #[inline]
+    fn ne_weighted(
+        &self,
+        other: &Rhs,
+        tol: &Self::Tol,
+        weights: &Self::Tol,
+        max_total: f64,
+    ) -> bool {
+        !self.eq_weighted(other, tol, weights, max_total)
+    }
 }
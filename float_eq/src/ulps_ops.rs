@@ -0,0 +1,227 @@
+/// Arithmetic on ULPs tolerances, so that the tolerances of composite types
+/// may be combined and widened without writing code for each field.
+///
+/// This is implemented for the ULPs tolerance types of the primitives, arrays,
+/// tuples and feature-gated composite types in this crate. It may be derived
+/// for a ULPs tolerance type generated by `#[derive_float_eq]` by adding it to
+/// `ulps_tol_derive`, or for any other struct whose fields implement it.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{UlpsTol, UlpsTolOps};
+/// let algorithm: UlpsTol<[f32; 3]> = [2, 4, 1];
+/// let input_error = [1, 1, u32::MAX];
+/// assert_eq!(algorithm.saturating_add(&input_error), [3, 5, u32::MAX]);
+/// assert_eq!(algorithm.max_elementwise(&[3, 3, 3]), [3, 4, 3]);
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// # use float_eq::{derive_float_eq, UlpsTolOps};
+/// #[derive_float_eq(
+///     ulps_tol = "PointUlps",
+///     ulps_tol_derive = "Clone, Copy, Debug, PartialEq, UlpsTolOps",
+///     debug_ulps_diff = "PointDebugUlpsDiff",
+///     debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+/// )]
+/// #[derive(Debug, PartialEq, Clone, Copy)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let a = PointUlps { x: 4, y: 1 };
+/// let b = PointUlps { x: 2, y: 2 };
+/// assert_eq!(a.saturating_add(&b), PointUlps { x: 6, y: 3 });
+/// assert_eq!(a.max_elementwise(&b), PointUlps { x: 4, y: 2 });
+/// # }
+/// ```
+pub trait UlpsTolOps: Sized {
+    /// The sum of each ULPs tolerance in `self` and the corresponding one in
+    /// `other`, saturating at the maximum tolerance instead of overflowing.
+    fn saturating_add(&self, other: &Self) -> Self;
+
+    /// The larger of each ULPs tolerance in `self` and the corresponding one
+    /// in `other`.
+    fn max_elementwise(&self, other: &Self) -> Self;
+}
+
+macro_rules! impl_ulps_tol_ops {
+    ($($t:ty),+) => {
+        $(
+            impl UlpsTolOps for $t {
+                #[inline]
+                fn saturating_add(&self, other: &Self) -> Self {
+                    <$t>::saturating_add(*self, *other)
+                }
+
+                #[inline]
+                fn max_elementwise(&self, other: &Self) -> Self {
+                    Ord::max(*self, *other)
+                }
+            }
+        )+
+    };
+}
+
+impl_ulps_tol_ops!(u16, u32, u64);
+
+impl<T: UlpsTolOps, const N: usize> UlpsTolOps for [T; N] {
+    #[inline]
+    fn saturating_add(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].saturating_add(&other[i]))
+    }
+
+    #[inline]
+    fn max_elementwise(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].max_elementwise(&other[i]))
+    }
+}
+
+impl UlpsTolOps for () {
+    #[inline]
+    fn saturating_add(&self, _other: &Self) -> Self {}
+
+    #[inline]
+    fn max_elementwise(&self, _other: &Self) -> Self {}
+}
+
+macro_rules! impl_ulps_tol_ops_for_tuple {
+    ($(($($idx:tt $T:ident),+))+) => {
+        $(
+            impl<$($T: UlpsTolOps),+> UlpsTolOps for ($($T,)+) {
+                #[inline]
+                fn saturating_add(&self, other: &Self) -> Self {
+                    ($(self.$idx.saturating_add(&other.$idx),)+)
+                }
+
+                #[inline]
+                fn max_elementwise(&self, other: &Self) -> Self {
+                    ($(self.$idx.max_elementwise(&other.$idx),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_ulps_tol_ops_for_tuple! {
+    (0 A)
+    (0 A, 1 B)
+    (0 A, 1 B, 2 C)
+    (0 A, 1 B, 2 C, 3 D)
+    (0 A, 1 B, 2 C, 3 D, 4 E)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L)
+}
+
+/// Conversion of a [`DebugUlpsDiff`] into a saturating form, where a
+/// difference that cannot be counted is replaced by the maximum ULPs value.
+///
+/// The ULPs differences of primitives are an `Option`, which is `None` if the
+/// operands differ in sign or either is NaN, so those of large arrays hold an
+/// `Option` for every element, which doubles their size and clutters their
+/// debug output. The saturated form is the ULPs tolerance type of the same
+/// value instead, such as `u32` for `Option<u32>`, with `u32::MAX` in place of
+/// `None`. This loses the distinction between an uncountable difference and
+/// one of exactly the maximum value, which no tolerance but the maximum itself
+/// would allow anyway, and may be compared directly against a tolerance.
+///
+/// This is implemented for the ULPs differences of the primitives, arrays,
+/// tuples and `Vec`s of them. It is usually reached via
+/// [`AssertFloatEq::debug_ulps_diff_saturating`].
+///
+/// # Examples
+/// ```
+/// # use float_eq::{DebugUlpsDiff, SaturatingUlpsDiff};
+/// let diff: DebugUlpsDiff<[f32; 3]> = [Some(2), None, Some(0)];
+/// assert_eq!(diff.saturating(), [2, u32::MAX, 0]);
+/// ```
+///
+/// [`DebugUlpsDiff`]: type.DebugUlpsDiff.html
+/// [`AssertFloatEq::debug_ulps_diff_saturating`]: trait.AssertFloatEq.html#method.debug_ulps_diff_saturating
+pub trait SaturatingUlpsDiff {
+    /// Type of the saturated ULPs difference.
+    type Saturated;
+
+    /// The saturated form of this ULPs difference.
+    fn saturating(&self) -> Self::Saturated;
+}
+
+macro_rules! impl_saturating_ulps_diff {
+    ($($t:ty),+) => {
+        $(
+            impl SaturatingUlpsDiff for Option<$t> {
+                type Saturated = $t;
+
+                #[inline]
+                fn saturating(&self) -> Self::Saturated {
+                    self.unwrap_or(<$t>::MAX)
+                }
+            }
+        )+
+    };
+}
+
+impl_saturating_ulps_diff!(u16, u32, u64);
+
+impl<T: SaturatingUlpsDiff, const N: usize> SaturatingUlpsDiff for [T; N] {
+    type Saturated = [T::Saturated; N];
+
+    #[inline]
+    fn saturating(&self) -> Self::Saturated {
+        core::array::from_fn(|i| self[i].saturating())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SaturatingUlpsDiff> SaturatingUlpsDiff for alloc::vec::Vec<T> {
+    type Saturated = alloc::vec::Vec<T::Saturated>;
+
+    #[inline]
+    fn saturating(&self) -> Self::Saturated {
+        self.iter().map(T::saturating).collect()
+    }
+}
+
+impl SaturatingUlpsDiff for () {
+    type Saturated = ();
+
+    #[inline]
+    fn saturating(&self) -> Self::Saturated {}
+}
+
+macro_rules! impl_saturating_ulps_diff_for_tuple {
+    ($(($($idx:tt $T:ident),+))+) => {
+        $(
+            impl<$($T: SaturatingUlpsDiff),+> SaturatingUlpsDiff for ($($T,)+) {
+                type Saturated = ($($T::Saturated,)+);
+
+                #[inline]
+                fn saturating(&self) -> Self::Saturated {
+                    ($(self.$idx.saturating(),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_saturating_ulps_diff_for_tuple! {
+    (0 A)
+    (0 A, 1 B)
+    (0 A, 1 B, 2 C)
+    (0 A, 1 B, 2 C, 3 D)
+    (0 A, 1 B, 2 C, 3 D, 4 E)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K)
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L)
+}
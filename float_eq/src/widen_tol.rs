@@ -0,0 +1,168 @@
+/// Conversion of a tolerance to and from the tolerance of the same shape in a
+/// wider precision, so that a tolerance defined once may be reused when the
+/// same type exists in both `f32` and `f64` forms.
+///
+/// Widening is always exact. Narrowing rounds floating point tolerances to the
+/// nearest value and saturates ULPs tolerances that are too large to fit.
+///
+/// This is implemented for `f32` and `u32`, widening to `f64` and `u64`, and
+/// for arrays and tuples of types that implement it. `f64` and `u64` widen to
+/// themselves, so that fields which are already wide may be mixed with those
+/// that are not. It may be derived for a struct whose fields implement it by
+/// naming the wide type with `#[float_eq(wide = "...")]`, and for a ULPs
+/// tolerance type generated by `#[derive_float_eq]` by adding it to
+/// `ulps_tol_derive` along with a `ulps_tol_attr` naming the wide type.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{UlpsTol, WidenTol};
+/// let tol: UlpsTol<[f32; 2]> = [4, 8];
+/// let wide: UlpsTol<[f64; 2]> = tol.widen();
+/// assert_eq!(wide, [4, 8]);
+/// assert_eq!(<[u32; 2]>::narrow(&[1, u64::MAX]), [1, u32::MAX]);
+///
+/// assert_eq!(0.5f32.widen(), 0.5f64);
+/// assert_eq!(f32::narrow(&0.25f64), 0.25f32);
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// # use float_eq::{derive_float_eq, float_eq, WidenTol};
+/// #[derive_float_eq(
+///     ulps_tol = "Point64Ulps",
+///     ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+///     debug_ulps_diff = "Point64DebugUlpsDiff"
+/// )]
+/// #[derive(Debug, PartialEq, Clone, Copy)]
+/// struct Point64 {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// #[derive_float_eq(
+///     ulps_tol = "Point32Ulps",
+///     ulps_tol_derive = "Clone, Copy, Debug, PartialEq, WidenTol",
+///     ulps_tol_attr = r#"float_eq(wide = "Point64Ulps")"#,
+///     debug_ulps_diff = "Point32DebugUlpsDiff",
+///     wide = "Point64"
+/// )]
+/// #[derive(Debug, PartialEq, Clone, Copy, WidenTol)]
+/// struct Point32 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let ulps = Point32Ulps { x: 4, y: 8 };
+/// let a = Point64 { x: 1.0, y: 2.0 };
+/// assert!(float_eq!(a, a, ulps <= ulps.widen()));
+///
+/// let abs = Point32 { x: 0.5, y: 0.25 };
+/// assert_eq!(abs.widen(), Point64 { x: 0.5, y: 0.25 });
+/// assert_eq!(Point32::narrow(&Point64 { x: 0.5, y: 0.25 }), abs);
+/// # }
+/// ```
+pub trait WidenTol<Wide>: Sized {
+    /// The tolerance in `self`, converted to the wider precision.
+    fn widen(&self) -> Wide;
+
+    /// The tolerance in `wide`, converted to this precision.
+    fn narrow(wide: &Wide) -> Self;
+}
+
+impl WidenTol<f64> for f32 {
+    #[inline]
+    fn widen(&self) -> f64 {
+        f64::from(*self)
+    }
+
+    #[inline]
+    fn narrow(wide: &f64) -> Self {
+        *wide as f32
+    }
+}
+
+impl WidenTol<u64> for u32 {
+    #[inline]
+    fn widen(&self) -> u64 {
+        u64::from(*self)
+    }
+
+    #[inline]
+    fn narrow(wide: &u64) -> Self {
+        (*wide).min(u64::from(u32::MAX)) as u32
+    }
+}
+
+macro_rules! impl_widen_tol_identity {
+    ($($t:ty),+) => {
+        $(
+            impl WidenTol<$t> for $t {
+                #[inline]
+                fn widen(&self) -> $t {
+                    *self
+                }
+
+                #[inline]
+                fn narrow(wide: &$t) -> Self {
+                    *wide
+                }
+            }
+        )+
+    };
+}
+
+impl_widen_tol_identity!(f64, u64);
+
+impl<T: WidenTol<W>, W, const N: usize> WidenTol<[W; N]> for [T; N] {
+    #[inline]
+    fn widen(&self) -> [W; N] {
+        core::array::from_fn(|i| self[i].widen())
+    }
+
+    #[inline]
+    fn narrow(wide: &[W; N]) -> Self {
+        core::array::from_fn(|i| T::narrow(&wide[i]))
+    }
+}
+
+impl WidenTol<()> for () {
+    #[inline]
+    fn widen(&self) {}
+
+    #[inline]
+    fn narrow(_wide: &()) -> Self {}
+}
+
+macro_rules! impl_widen_tol_for_tuple {
+    ($(($($idx:tt $T:ident $W:ident),+))+) => {
+        $(
+            impl<$($T: WidenTol<$W>, $W),+> WidenTol<($($W,)+)> for ($($T,)+) {
+                #[inline]
+                fn widen(&self) -> ($($W,)+) {
+                    ($(self.$idx.widen(),)+)
+                }
+
+                #[inline]
+                fn narrow(wide: &($($W,)+)) -> Self {
+                    ($($T::narrow(&wide.$idx),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_widen_tol_for_tuple! {
+    (0 A WA)
+    (0 A WA, 1 B WB)
+    (0 A WA, 1 B WB, 2 C WC)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG, 7 H WH)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG, 7 H WH, 8 I WI)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG, 7 H WH, 8 I WI, 9 J WJ)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG, 7 H WH, 8 I WI, 9 J WJ, 10 K WK)
+    (0 A WA, 1 B WB, 2 C WC, 3 D WD, 4 E WE, 5 F WF, 6 G WG, 7 H WH, 8 I WI, 9 J WJ, 10 K WK, 11 L WL)
+}
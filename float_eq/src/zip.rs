@@ -0,0 +1,153 @@
+use crate::{AssertFloatEq, FloatEq};
+
+/// The result of comparing a single pair of elements, yielded by
+/// [`FloatEqZipExt::float_eq_zip`].
+///
+/// [`FloatEqZipExt::float_eq_zip`]: trait.FloatEqZipExt.html#method.float_eq_zip
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElementCmp<A, B, D> {
+    /// The position of the elements in their iterators.
+    pub index: usize,
+    /// The element from the first iterator.
+    pub a: A,
+    /// The element from the second iterator.
+    pub b: B,
+    /// Whether the elements compared equal.
+    pub passed: bool,
+    /// The absolute difference between the elements.
+    pub diff: D,
+}
+
+/// Iterator adapter that compares the elements of two iterators pairwise.
+///
+/// Created by [`FloatEqZipExt::float_eq_zip`] and
+/// [`FloatEqZipExt::float_eq_zip_by`].
+///
+/// [`FloatEqZipExt::float_eq_zip`]: trait.FloatEqZipExt.html#method.float_eq_zip
+/// [`FloatEqZipExt::float_eq_zip_by`]: trait.FloatEqZipExt.html#method.float_eq_zip_by
+#[derive(Clone, Debug)]
+pub struct FloatEqZip<I, J, T, F> {
+    a: I,
+    b: J,
+    tol: T,
+    check: F,
+    index: usize,
+}
+
+impl<I, J, T, F> Iterator for FloatEqZip<I, J, T, F>
+where
+    I: Iterator,
+    J: Iterator,
+    I::Item: AssertFloatEq<J::Item>,
+    F: FnMut(&I::Item, &J::Item, &T) -> bool,
+{
+    type Item = ElementCmp<I::Item, J::Item, <I::Item as AssertFloatEq<J::Item>>::DebugAbsDiff>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        let passed = (self.check)(&a, &b, &self.tol);
+        let diff = a.debug_abs_diff(&b);
+        Some(ElementCmp {
+            index,
+            a,
+            b,
+            passed,
+            diff,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+/// Extension trait for comparing the elements of two iterators pairwise.
+///
+/// Each comparison yields an [`ElementCmp`], so that mismatches may be
+/// filtered, counted or collected using ordinary iterator combinators. Like
+/// [`Iterator::zip`], iteration stops when either iterator is exhausted.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{FloatEq, FloatEqZipExt};
+/// let a = [1.0f32, 2.0, 3.0];
+/// let b = [1.0f32, 2.5, 3.0];
+///
+/// let mismatches: Vec<_> = a
+///     .iter()
+///     .float_eq_zip(b.iter(), 0.1)
+///     .filter(|cmp| !cmp.passed)
+///     .map(|cmp| (cmp.index, cmp.diff))
+///     .collect();
+/// assert_eq!(mismatches, vec![(1, 0.5)]);
+///
+/// // Other checks may be used by providing them explicitly
+/// let passed = a
+///     .iter()
+///     .float_eq_zip_by(b.iter(), 4, |a, b, tol| a.eq_ulps(b, tol))
+///     .all(|cmp| cmp.passed);
+/// assert!(!passed);
+/// ```
+///
+/// [`ElementCmp`]: struct.ElementCmp.html
+/// [`Iterator::zip`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.zip
+pub trait FloatEqZipExt: Iterator + Sized {
+    /// Compare each element with the corresponding element of `other`, using
+    /// an [absolute tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    #[allow(clippy::type_complexity)]
+    fn float_eq_zip<J>(
+        self,
+        other: J,
+        tol: <Self::Item as FloatEq<J::Item>>::Tol,
+    ) -> FloatEqZip<
+        Self,
+        J::IntoIter,
+        <Self::Item as FloatEq<J::Item>>::Tol,
+        fn(&Self::Item, &J::Item, &<Self::Item as FloatEq<J::Item>>::Tol) -> bool,
+    >
+    where
+        J: IntoIterator,
+        Self::Item: AssertFloatEq<J::Item>,
+        <Self::Item as FloatEq<J::Item>>::Tol: Sized,
+    {
+        self.float_eq_zip_by(other, tol, FloatEq::eq_abs)
+    }
+
+    /// Compare each element with the corresponding element of `other`, using
+    /// `check` to decide whether they are equal given `tol`.
+    fn float_eq_zip_by<J, T, F>(
+        self,
+        other: J,
+        tol: T,
+        check: F,
+    ) -> FloatEqZip<Self, J::IntoIter, T, F>
+    where
+        J: IntoIterator,
+        Self::Item: AssertFloatEq<J::Item>,
+        F: FnMut(&Self::Item, &J::Item, &T) -> bool,
+    {
+        FloatEqZip {
+            a: self,
+            b: other.into_iter(),
+            tol,
+            check,
+            index: 0,
+        }
+    }
+}
+
+impl<I: Iterator> FloatEqZipExt for I {}
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "std", not(feature = "checks-only")))]
+
+// Failures should be reported at the line where the user called into float_eq.
+// This replaces the panic hook, so these tests are kept in their own binary.
+
+use float_eq::{assert_float_eq, assert_float_eq_str, assert_float_ne, eq_sig_figs};
+use std::cell::Cell;
+use std::panic;
+use std::sync::Once;
+
+thread_local! {
+    static PANIC_LINE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+fn panic_line(f: impl FnOnce() + panic::UnwindSafe) -> Option<u32> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        panic::set_hook(Box::new(|info| {
+            PANIC_LINE.with(|line| line.set(info.location().map(|l| l.line())));
+        }))
+    });
+    PANIC_LINE.with(|line| line.set(None));
+    assert!(panic::catch_unwind(f).is_err());
+    PANIC_LINE.with(|line| line.get())
+}
+
+#[test]
+fn assert_float_eq() {
+    let line = panic_line(|| assert_float_eq!(1.0f32, 2.0, abs <= 0.1));
+    assert_eq!(line, Some(line!() - 1));
+
+    let line = panic_line(|| assert_float_eq!(1.0f32, 2.0, bits, "message"));
+    assert_eq!(line, Some(line!() - 1));
+
+    let line = panic_line(|| assert_float_eq!([1.0f32], [2.0], abs_all <= 0.1, ulps_all <= 1));
+    assert_eq!(line, Some(line!() - 1));
+}
+
+#[test]
+fn assert_float_ne() {
+    let line = panic_line(|| assert_float_ne!(1.0f64, 1.0, rmax <= 0.1));
+    assert_eq!(line, Some(line!() - 1));
+}
+
+#[test]
+fn assert_float_eq_str() {
+    let line = panic_line(|| assert_float_eq_str!(1.0f64, "2.0", sig_figs <= 3));
+    assert_eq!(line, Some(line!() - 1));
+
+    let line = panic_line(|| assert_float_eq_str!(1.0f64, "x", sig_figs <= 3));
+    assert_eq!(line, Some(line!() - 1));
+}
+
+#[test]
+fn eq_sig_figs_invalid() {
+    let line = panic_line(|| {
+        eq_sig_figs(1.0f64, "1.0", 0);
+    });
+    assert_eq!(line, Some(line!() - 2));
+
+    let line = panic_line(|| {
+        eq_sig_figs(1.0f64, "1.0.0", 3);
+    });
+    assert_eq!(line, Some(line!() - 2));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn tol_builder_build() {
+    use float_eq::{FloatEq, FloatEqTolBuilder, FloatEqUlpsTol};
+
+    #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqTolBuilder)]
+    #[float_eq(ulps_tol = "PointUlps", tol_builder = "PointTolBuilder")]
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    let line = panic_line(|| {
+        Point::tol_builder().x(0.1).build();
+    });
+    assert_eq!(line, Some(line!() - 2));
+}
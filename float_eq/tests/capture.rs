@@ -0,0 +1,72 @@
+#![cfg(all(feature = "capture", not(feature = "checks-only")))]
+
+use float_eq::{assert_float_eq_captured, assert_float_ne_captured};
+
+fn f(x: f32) -> f32 {
+    x * 2.0
+}
+
+fn g(y: f32) -> f32 {
+    y + 1.0
+}
+
+#[test]
+fn passes() {
+    assert_float_eq_captured!(f(1.0) * g(2.0), 6.0, abs <= 0.0);
+    assert_float_eq_captured!(6.0, f(1.0) * g(2.0), ulps <= 0);
+    assert_float_eq_captured!(f(1.0), 2.0, rmax <= 0.0);
+    assert_float_ne_captured!(f(1.0) - g(2.0), 0.0, abs <= 0.1);
+    assert_float_eq_captured!([f(1.0), 1.0], [2.0, 1.0], abs_all <= 0.0, ulps_all <= 0);
+    assert_float_eq_captured!((f(1.0) + 1.0), 3.0, abs <= 0.0, "custom {}", 1);
+}
+
+#[test]
+fn operands_are_evaluated_once_in_order() {
+    let mut calls = Vec::new();
+    let mut h = |x: f32| {
+        calls.push(x);
+        x
+    };
+    assert_float_eq_captured!(h(1.0) + h(2.0), h(3.0), abs <= 0.0);
+    assert_eq!(calls, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `6.0`,
+       right: `6.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(1048576)`,
+     [abs] t: `0.1`,
+      `f(x)`: `2.0`,
+      `g(y)`: `3.0`"#)]
+fn eq_fail_message() {
+    let (x, y) = (1.0, 2.0);
+    assert_float_eq_captured!(f(x) * g(y), 6.5, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_ne!(left, right, ulps <= t)`
+        left: `2.0`,
+       right: `2.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`,
+    [ulps] t: `4`,
+         `a`: `1.0`,
+         `a`: `1.0`,
+         `b`: `4.0`: custom message"#)]
+fn ne_fail_message() {
+    let (a, b) = (1.0f32, 4.0f32);
+    assert_float_ne_captured!(a + a, b / 2.0, ulps <= 4, "custom message");
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `2.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.1`"#)]
+fn nothing_captured_fail_message() {
+    assert_float_eq_captured!(f(0.5), 2.0, abs <= 0.1);
+}
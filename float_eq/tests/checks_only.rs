@@ -0,0 +1,45 @@
+#![cfg(feature = "checks-only")]
+
+// Only the boolean comparisons are available with the checks-only feature, so
+// the rest of the test suite is disabled and these tests are run instead.
+
+use float_eq::{float_eq, float_ne, FloatEq, FloatEqAll};
+
+#[test]
+fn primitives() {
+    assert!(float_eq!(1.0f32, 1.000_000_1, ulps <= 1));
+    assert!(float_ne!(1.0f64, 1.1, abs <= 0.01, rmax <= 0.01));
+    assert!(1.0f64.eq_r2nd(&1.0, &0.0));
+}
+
+#[test]
+fn composites() {
+    let a = [1.0f32, 2.0];
+    let b = [1.0f32, 2.000_000_2];
+    assert!(float_eq!(a, b, ulps_all <= 1));
+    assert!(a.eq_abs_all(&b, &0.000_001));
+    assert!(float_eq!((1.0f64, 2.0f32), (1.0, 2.0), abs <= (0.0, 0.0)));
+
+    #[cfg(feature = "std")]
+    assert!(float_ne!(vec![1.0f64], vec![2.0], abs_all <= 0.5));
+}
+
+#[cfg(feature = "derive-core")]
+#[test]
+fn derived() {
+    #[float_eq::derive_float_eq(
+        ulps_tol = "PointUlps",
+        debug_ulps_diff = "PointDebugUlpsDiff",
+        all_tol = "f64"
+    )]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point { x: 1.0, y: 2.5 };
+    assert!(float_eq!(a, b, abs_all <= 0.5));
+    assert!(float_ne!(a, b, abs <= Point { x: 0.5, y: 0.1 }));
+}
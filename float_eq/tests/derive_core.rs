@@ -0,0 +1,24 @@
+#![cfg(all(feature = "derive-core", not(feature = "derive-assert")))]
+
+use float_eq::{derive_float_eq, float_eq, float_ne, FloatEq, FloatEqAll};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn derived_without_asserts() {
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point { x: 1.0, y: 2.5 };
+    assert!(a.eq_abs(&b, &Point { x: 0.0, y: 0.5 }));
+    assert!(a.ne_abs_all(&b, &0.1));
+    assert!(float_eq!(a, b, abs_all <= 0.5));
+    assert!(float_ne!(a, b, ulps <= PointUlps { x: 0, y: 4 }));
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "derive")]
+#![cfg(all(feature = "derive", not(feature = "checks-only")))]
 
 use float_eq::{assert_float_eq, derive_float_eq};
 
@@ -1,4 +1,4 @@
-#![cfg(feature = "derive")]
+#![cfg(all(feature = "derive", not(feature = "checks-only")))]
 
 #[test]
 fn tests() {
@@ -10,8 +10,8 @@ fn tests() {
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_struct_no_fields.rs");
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_tuple_struct.rs");
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_unit.rs");
-    t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_enum.rs");
-    t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_generic.rs");
+    t.pass("tests/derive_tests/ulps_tol/ulps_tol_enum.rs");
+    t.pass("tests/derive_tests/ulps_tol/ulps_tol_generic.rs");
     t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_missing_type_name.rs");
     t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_duplicate_type_name.rs");
 
@@ -21,8 +21,8 @@ fn tests() {
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_struct_no_fields.rs");
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_tuple_struct.rs");
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_unit.rs");
-    t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_enum.rs");
-    t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_generic.rs");
+    t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_enum.rs");
+    t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_generic.rs");
     t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_missing_type_name.rs");
     t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_duplicate_type_name.rs");
 
@@ -31,45 +31,103 @@ fn tests() {
     t.pass("tests/derive_tests/float_eq/float_eq_struct_no_fields.rs");
     t.pass("tests/derive_tests/float_eq/float_eq_tuple_struct.rs");
     t.pass("tests/derive_tests/float_eq/float_eq_unit.rs");
-    t.compile_fail("tests/derive_tests/float_eq/float_eq_enum.rs");
-    t.compile_fail("tests/derive_tests/float_eq/float_eq_generic.rs");
+    t.pass("tests/derive_tests/float_eq/float_eq_enum.rs");
+    t.pass("tests/derive_tests/float_eq/float_eq_generic.rs");
 
     // FloatEqAll
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_struct.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_struct_no_fields.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_tuple_struct.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_unit.rs");
-    t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_enum.rs");
-    t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_generic.rs");
+    t.pass("tests/derive_tests/float_eq_all/float_eq_all_enum.rs");
+    t.pass("tests/derive_tests/float_eq_all/float_eq_all_generic.rs");
     t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_missing_tol.rs");
     t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_duplicate_tol.rs");
 
+    // FloatEqBits
+    t.pass("tests/derive_tests/float_eq_bits/float_eq_bits_struct.rs");
+    t.pass("tests/derive_tests/float_eq_bits/float_eq_bits_tuple_struct.rs");
+    t.pass("tests/derive_tests/float_eq_bits/float_eq_bits_unit.rs");
+    t.pass("tests/derive_tests/float_eq_bits/float_eq_bits_enum.rs");
+
+    // FloatEqWeighted
+    t.pass("tests/derive_tests/float_eq_weighted/float_eq_weighted_struct.rs");
+    t.pass("tests/derive_tests/float_eq_weighted/float_eq_weighted_tuple_struct.rs");
+    t.pass("tests/derive_tests/float_eq_weighted/float_eq_weighted_unit.rs");
+    t.pass("tests/derive_tests/float_eq_weighted/float_eq_weighted_enum.rs");
+
+    // UlpsTolOps
+    t.pass("tests/derive_tests/ulps_tol_ops/ulps_tol_ops_struct.rs");
+    t.pass("tests/derive_tests/ulps_tol_ops/ulps_tol_ops_tuple_struct.rs");
+    t.pass("tests/derive_tests/ulps_tol_ops/ulps_tol_ops_generic.rs");
+    t.compile_fail("tests/derive_tests/ulps_tol_ops/ulps_tol_ops_enum.rs");
+
+    // TolOps
+    t.pass("tests/derive_tests/tol_ops/tol_ops_struct.rs");
+    t.pass("tests/derive_tests/tol_ops/tol_ops_tuple_struct.rs");
+    t.pass("tests/derive_tests/tol_ops/tol_ops_generic.rs");
+    t.compile_fail("tests/derive_tests/tol_ops/tol_ops_enum.rs");
+
+    // WidenTol
+    t.pass("tests/derive_tests/widen_tol/widen_tol_struct.rs");
+    t.pass("tests/derive_tests/widen_tol/widen_tol_tuple_struct.rs");
+    t.compile_fail("tests/derive_tests/widen_tol/widen_tol_enum.rs");
+    t.compile_fail("tests/derive_tests/widen_tol/widen_tol_missing_wide.rs");
+
+    // FloatEqTolBuilder
+    t.pass("tests/derive_tests/tol_builder/tol_builder_struct.rs");
+    t.pass("tests/derive_tests/tol_builder/tol_builder_unit.rs");
+    t.compile_fail("tests/derive_tests/tol_builder/tol_builder_tuple_struct.rs");
+    t.compile_fail("tests/derive_tests/tol_builder/tol_builder_enum.rs");
+    t.compile_fail("tests/derive_tests/tol_builder/tol_builder_missing_type_name.rs");
+
     // AssertFloatEq
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_struct_no_fields.rs");
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_tuple_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_unit.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq/assert_float_eq_enum.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq/assert_float_eq_generic.rs");
+    t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_enum.rs");
+    t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_generic.rs");
 
     // AssertFloatEqAll
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_struct_no_fields.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_tuple_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_unit.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_enum.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_generic.rs");
+    t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_enum.rs");
+    t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_generic.rs");
 
     // #[float_eq(...)]
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_no_params_list.rs");
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_malformed_param.rs");
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_malformed_value.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_malformed_attr.rs");
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_unknown_param.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_unknown_field_param.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_duplicate_rename.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_rename_tuple_field.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_skip_and_cmp.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_rename_skipped_field.rs");
+    t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_malformed_cmp.rs");
 
     // #[derive_float_eq(...)]
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq.rs");
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_all.rs");
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_all_custom_debug.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_tol_builder.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_rename.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_tuple_struct.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_enum.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_generic.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_skip.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_cmp.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_passthrough.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_percent.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_abs_ulps.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_precisions.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_rhs.rs");
     t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_missing_ulps_tol.rs");
     t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_missing_debug_ulps_diff.rs");
+    t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_precisions_not_generic.rs");
+    t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_rhs_not_newtype.rs");
 }
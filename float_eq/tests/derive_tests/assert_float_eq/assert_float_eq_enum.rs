@@ -1,19 +1,108 @@
-use float_eq::{AssertFloatEq, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol};
+use float_eq::{
+    assert_float_eq, assert_float_ne, AssertFloatEq, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+};
 
 #[derive(
-    Clone,
-    Copy,
-    Debug,
-    PartialEq,
-    PartialOrd,
-    FloatEqUlpsTol,
-    FloatEq,
-    FloatEqDebugUlpsDiff,
-    AssertFloatEq,
+    Clone, Copy, Debug, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqDebugUlpsDiff, AssertFloatEq,
 )]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+enum Shape {
+    Circle {
+        #[float_eq(rename = "radius")]
+        r: f32,
+    },
+    Rect(f32, f32),
+    Empty,
+}
+
+fn debug_diff() {
+    let a = Shape::Circle { r: 1.0 };
+    let b = Shape::Circle { r: 1.000_000_1 };
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        Some(Shape::Circle {
+            r: 0.000_000_119_209_29
+        })
+    );
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Some(ShapeDebugUlpsDiff::Circle { radius: Some(1) })
+    );
+
+    let c = Shape::Rect(1.0, 2.0);
+    let d = Shape::Rect(1.0, -2.0);
+    assert_eq!(c.debug_abs_diff(&d), Some(Shape::Rect(0.0, 4.0)));
+    assert_eq!(
+        c.debug_ulps_diff(&d),
+        Some(ShapeDebugUlpsDiff::Rect(Some(0), None))
+    );
+
+    assert_eq!(
+        Shape::Empty.debug_abs_diff(&Shape::Empty),
+        Some(Shape::Empty)
+    );
+    assert_eq!(
+        Shape::Empty.debug_ulps_diff(&Shape::Empty),
+        Some(ShapeDebugUlpsDiff::Empty)
+    );
+
+    // different variants have no diff
+    assert_eq!(a.debug_abs_diff(&c), None);
+    assert_eq!(a.debug_ulps_diff(&Shape::Empty), None);
+}
+
+fn debug_tol() {
+    let a = Shape::Rect(1.0, 200.0);
+    let b = Shape::Rect(50.0, 1.0);
+
+    assert_eq!(
+        a.debug_abs_tol(&b, &Shape::Rect(0.1, 0.2)),
+        Some(Shape::Rect(0.1, 0.2))
+    );
+    assert_eq!(
+        a.debug_rel_tol(&b, &Shape::Rect(0.1, 0.2)),
+        Some(Shape::Rect(5.0, 40.0))
+    );
+    assert_eq!(
+        a.debug_rmin_tol(&b, &Shape::Rect(0.1, 0.2)),
+        Some(Shape::Rect(0.1, 0.2))
+    );
+    assert_eq!(
+        a.debug_ulps_tol(&b, &ShapeUlps::Rect(1, 2)),
+        Some(ShapeUlps::Rect(1, 2))
+    );
+
+    // different variants, including of the tolerance, have no tolerance
+    let c = Shape::Circle { r: 1.0 };
+    assert_eq!(a.debug_abs_tol(&c, &Shape::Rect(0.1, 0.2)), None);
+    assert_eq!(a.debug_abs_tol(&b, &Shape::Circle { r: 0.1 }), None);
+    assert_eq!(
+        c.debug_ulps_tol(&c, &ShapeUlps::Circle { radius: 1 }),
+        Some(ShapeUlps::Circle { radius: 1 })
+    );
+    assert_eq!(c.debug_ulps_tol(&c, &ShapeUlps::Empty), None);
 }
 
-fn main() {}
+fn asserts() {
+    let a = Shape::Circle { r: 1.0 };
+    let b = Shape::Circle { r: 1.000_000_1 };
+    assert_float_eq!(a, b, abs <= Shape::Circle { r: 0.000_001 });
+    assert_float_eq!(a, b, ulps <= ShapeUlps::Circle { radius: 1 });
+    assert_float_ne!(a, Shape::Rect(1.0, 1.0), abs <= Shape::Circle { r: 1.0 });
+
+    let result = std::panic::catch_unwind(|| {
+        assert_float_eq!(a, Shape::Empty, abs <= Shape::Circle { r: 1.0 });
+    });
+    assert!(result.is_err());
+}
+
+fn main() {
+    debug_diff();
+    debug_tol();
+    asserts();
+}
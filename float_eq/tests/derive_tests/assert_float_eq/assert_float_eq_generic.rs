@@ -1,11 +1,53 @@
-use float_eq::{AssertFloatEq, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol};
+use float_eq::{
+    assert_float_eq, assert_float_ne, AssertFloatEq, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, UlpsTol,
+};
 
 #[derive(
     Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqDebugUlpsDiff, AssertFloatEq,
 )]
-struct MyComplexUlps<T> {
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::new(1.0f32, 2.000_003_6);
+    let b = MyComplex::new(1.000_000_1f32, 2.0);
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        MyComplex::new(0.000_000_119_209_29, 0.000_003_576_278_7)
+    );
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        DebugUlpsDiff::<MyComplex<f32>> {
+            re: Some(1),
+            im: Some(15)
+        }
+    );
+    assert_eq!(
+        a.debug_ulps_tol(&b, &UlpsTol::<MyComplex<f32>> { re: 1, im: 2 }),
+        MyComplexUlps { re: 1, im: 2 }
+    );
+
+    let c = MyComplex::new(1.0f64, 2.0);
+    let d = MyComplex::new(1.000_000_000_000_000_2f64, 2.0);
+    assert_float_eq!(c, d, ulps <= MyComplexUlps { re: 1, im: 0 });
+    assert_float_ne!(c, d, abs <= MyComplex::new(0.0, 0.0));
+
+    let diffs = c.diff_fields(&d, &MyComplex::new(0.0, 0.0));
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].field, "re");
+}
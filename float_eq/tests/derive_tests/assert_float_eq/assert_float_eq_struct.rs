@@ -80,7 +80,31 @@ fn debug_tol() {
     );
 }
 
+fn diff_fields() {
+    let a = MyComplex32::new(1.0, 2.0);
+    let tol = MyComplex32::new(0.1, 0.1);
+    assert_eq!(a.diff_fields(&a, &tol), Vec::new());
+
+    let b = MyComplex32::new(1.000_000_1, 2.5);
+    let diffs = a.diff_fields(&b, &tol);
+    assert_eq!(
+        diffs,
+        vec![float_eq::FieldDiff {
+            field: "im",
+            left: "2.0".to_string(),
+            right: "2.5".to_string(),
+            abs_diff: "0.5".to_string(),
+            ulps_diff: "Some(2097152)".to_string(),
+        }]
+    );
+
+    let diffs = a.diff_fields(&b, &MyComplex32::new(0.0, 0.0));
+    let fields: Vec<_> = diffs.iter().map(|d| d.field).collect();
+    assert_eq!(fields, vec!["re", "im"]);
+}
+
 fn main() {
     debug_diff();
     debug_tol();
+    diff_fields();
 }
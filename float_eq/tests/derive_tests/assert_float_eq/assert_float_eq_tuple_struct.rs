@@ -57,7 +57,21 @@ fn debug_tol() {
     );
 }
 
+fn diff_fields() {
+    let a = MyTupleType(1.0, 2.0);
+    let tol = MyTupleType(0.1, 0.1);
+    assert_eq!(a.diff_fields(&a, &tol), Vec::new());
+
+    let b = MyTupleType(1.5, -2.0);
+    let diffs = a.diff_fields(&b, &tol);
+    let fields: Vec<_> = diffs.iter().map(|d| d.field).collect();
+    assert_eq!(fields, vec!["0", "1"]);
+    assert_eq!(diffs[1].abs_diff, "4.0");
+    assert_eq!(diffs[1].ulps_diff, "None");
+}
+
 fn main() {
     debug_diff();
     debug_tol();
+    diff_fields();
 }
@@ -30,7 +30,13 @@ fn debug_tol() {
     assert_eq!(a.debug_ulps_tol(&b, &MyUnitTypeUlps {}), MyUnitTypeUlps {});
 }
 
+fn diff_fields() {
+    let a = MyUnitType {};
+    assert_eq!(a.diff_fields(&a, &MyUnitType {}), Vec::new());
+}
+
 fn main() {
     debug_diff();
     debug_tol();
+    diff_fields();
 }
@@ -1,5 +1,6 @@
 use float_eq::{
-    AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    assert_float_eq, AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol,
 };
 
 #[derive(
@@ -7,7 +8,6 @@ use float_eq::{
     Copy,
     Debug,
     PartialEq,
-    PartialOrd,
     FloatEqUlpsTol,
     FloatEq,
     FloatEqDebugUlpsDiff,
@@ -15,9 +15,45 @@ use float_eq::{
     FloatEqAll,
     AssertFloatEqAll,
 )]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+enum Shape {
+    Circle {
+        #[float_eq(rename = "radius")]
+        r: f32,
+    },
+    Rect(f32, f32),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = Shape::Rect(1.0, 200.0);
+    let b = Shape::Rect(50.0, 1.0);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Some(Shape::Rect(0.2, 0.2)));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), Some(Shape::Rect(10.0, 40.0)));
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.2), Some(Shape::Rect(0.2, 0.2)));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Some(ShapeUlps::Rect(2, 2)));
+
+    let c = Shape::Circle { r: 1.0 };
+    assert_eq!(
+        c.debug_ulps_all_tol(&c, &2),
+        Some(ShapeUlps::Circle { radius: 2 })
+    );
+    assert_eq!(
+        Shape::Empty.debug_abs_all_tol(&Shape::Empty, &0.2),
+        Some(Shape::Empty)
+    );
+
+    // different variants have no tolerance
+    assert_eq!(a.debug_abs_all_tol(&c, &0.2), None);
+    assert_eq!(a.debug_ulps_all_tol(&Shape::Empty, &2), None);
+
+    assert_float_eq!(c, Shape::Circle { r: 1.000_000_1 }, abs_all <= 0.000_001);
+    assert_float_eq!(a, b, rmax_all <= 1.0);
+}
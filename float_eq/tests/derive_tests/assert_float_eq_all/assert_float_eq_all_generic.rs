@@ -1,5 +1,6 @@
 use float_eq::{
-    AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    assert_float_eq, AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol,
 };
 
 #[derive(
@@ -14,9 +15,31 @@ use float_eq::{
     FloatEqAll,
     AssertFloatEqAll,
 )]
-struct MyComplexUlps<T> {
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::new(1.0f32, 200.0);
+    let b = MyComplex::new(50.0f32, 1.0);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), MyComplex::new(0.2, 0.2));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), MyComplex::new(10.0, 40.0));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), MyComplexUlps { re: 2, im: 2 });
+
+    assert_float_eq!(a, a, rmax_all <= 0.0);
+}
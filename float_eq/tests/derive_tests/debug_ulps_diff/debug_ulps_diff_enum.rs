@@ -1,9 +1,22 @@
-use float_eq::FloatEqDebugUlpsDiff;
+use float_eq::{DebugUlpsDiff, FloatEqDebugUlpsDiff};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqDebugUlpsDiff)]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[derive(Clone, Copy, Debug, PartialEq, FloatEqDebugUlpsDiff)]
+#[float_eq(
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+enum Shape {
+    Circle {
+        #[float_eq(rename = "radius")]
+        r: f32,
+    },
+    Rect(f32, f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let circle: DebugUlpsDiff<Shape> = ShapeDebugUlpsDiff::Circle { radius: Some(1) };
+    assert_eq!(circle, ShapeDebugUlpsDiff::Circle { radius: Some(1u32) });
+    assert_ne!(circle, ShapeDebugUlpsDiff::Rect(Some(1u32), None));
+    assert_eq!(ShapeDebugUlpsDiff::Empty, ShapeDebugUlpsDiff::Empty);
+}
@@ -1,9 +1,33 @@
-use float_eq::FloatEqDebugUlpsDiff;
+use float_eq::{DebugUlpsDiff, FloatEqDebugUlpsDiff};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqDebugUlpsDiff)]
-struct MyComplexUlps<T> {
+#[float_eq(
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+fn main() {
+    let a = DebugUlpsDiff::<MyComplex<f32>> {
+        re: Some(1),
+        im: Some(2),
+    };
+    let b = a; // Clone, Copy
+    assert_eq!(a, b);
+    assert_ne!(
+        a,
+        MyComplexDebugUlpsDiff {
+            re: None,
+            im: Some(2u32)
+        }
+    );
+
+    let c = DebugUlpsDiff::<MyComplex<f64>> {
+        re: Some(1),
+        im: None,
+    };
+    assert_eq!(c.re, Some(1u64));
+}
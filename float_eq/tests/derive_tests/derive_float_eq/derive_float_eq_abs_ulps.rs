@@ -0,0 +1,31 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn main() {
+    let a = Point { x: 0.0, y: 1.0 };
+    let b = Point {
+        x: -1e-12,
+        y: 1.000_000_000_000_000_2,
+    };
+
+    let tol = Point { x: 1e-9, y: 1e-9 };
+    let ulps_tol = PointUlps { x: 0, y: 1 };
+    assert_float_eq!(a, b, abs_ulps <= (tol, ulps_tol));
+    assert_float_ne!(a, b, abs_ulps <= (Point { x: 1e-9, y: 0.0 }, ulps_tol));
+    assert_float_eq!(a, b, abs_ulps_all <= (1e-9, 1));
+    assert_float_ne!(a, b, abs_ulps_all <= (1e-13, 1));
+
+    assert_eq!(a.debug_abs_ulps_tol(&b, &tol, &ulps_tol), (tol, ulps_tol));
+}
@@ -0,0 +1,107 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq, FloatEqBits, FloatEqWeighted,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Star,
+    Planet,
+}
+
+fn same_name(a: &String, b: &String) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+mod cmp {
+    pub fn exact<T: PartialEq>(a: &T, b: &T) -> bool {
+        a == b
+    }
+}
+
+#[derive_float_eq(
+    ulps_tol = "BodyUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "BodyDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, PartialEq, FloatEqBits, FloatEqWeighted)]
+struct Body {
+    #[float_eq(cmp = "same_name")]
+    name: String,
+    #[float_eq(cmp = "cmp::exact")]
+    kind: Kind,
+    mass: f64,
+}
+
+#[derive_float_eq(
+    ulps_tol = "OrbitUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "OrbitDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, PartialEq)]
+enum Orbit {
+    Circular(#[float_eq(cmp = "cmp::exact")] Kind, f64),
+}
+
+fn main() {
+    let body = |name: &str, kind, mass| Body {
+        name: name.to_string(),
+        kind,
+        mass,
+    };
+    let a = body("Earth", Kind::Planet, 1.0);
+    let b = body("EARTH", Kind::Planet, 1.000_000_000_000_000_2);
+    let c = body("Mars", Kind::Planet, 1.0);
+    let d = body("Earth", Kind::Star, 1.0);
+
+    // fields with a custom comparison ignore the tolerance
+    let tol = body("", Kind::Star, 1e-15);
+    assert_float_eq!(a, b, abs <= tol);
+    assert_float_ne!(a, c, abs <= tol);
+    assert_float_ne!(a, d, abs <= tol);
+    assert_float_eq!(a, b, ulps <= BodyUlps { mass: 1 });
+    assert_float_ne!(a, c, ulps <= BodyUlps { mass: 1 });
+    assert_float_eq!(a, b, rmax_all <= 1e-15);
+    assert_float_ne!(a, d, rmax_all <= 1e-15);
+
+    assert!(a.eq_bits(&a.clone()));
+    assert!(a.eq_bits(&body("earth", Kind::Planet, 1.0)));
+    assert!(!a.eq_bits(&b));
+    assert!(!a.eq_bits(&d));
+
+    let weights = body("", Kind::Star, 1.0);
+    assert!(a.weighted_error(&b, &tol, &weights) < 1.0);
+    assert_eq!(a.weighted_error(&c, &tol, &weights), f64::INFINITY);
+
+    assert_eq!(Body::FIELDS, &["name", "kind", "mass"]);
+    assert_eq!(a.debug_ulps_diff(&b), BodyDebugUlpsDiff { mass: Some(1) });
+    assert_eq!(
+        a.debug_abs_tol(&c, &tol),
+        body("Earth", Kind::Planet, 1e-15)
+    );
+    let diffs = a.diff_fields(&c, &tol);
+    assert!(diffs.is_empty());
+
+    let result = std::panic::catch_unwind(|| assert_float_eq!(a, c, abs <= tol));
+    assert!(result.is_err());
+
+    let a = Orbit::Circular(Kind::Planet, 1.0);
+    assert_float_eq!(
+        a,
+        Orbit::Circular(Kind::Planet, 1.0),
+        ulps <= OrbitUlps::Circular(0)
+    );
+    assert_float_ne!(
+        a,
+        Orbit::Circular(Kind::Star, 1.0),
+        ulps <= OrbitUlps::Circular(0)
+    );
+    assert_float_ne!(a, Orbit::Circular(Kind::Star, 1.0), abs_all <= 1.0);
+    assert_eq!(
+        a.debug_ulps_diff(&Orbit::Circular(Kind::Star, 2.0)),
+        Some(OrbitDebugUlpsDiff::Circular(Some(4_503_599_627_370_496)))
+    );
+}
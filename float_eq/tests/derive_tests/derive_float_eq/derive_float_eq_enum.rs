@@ -0,0 +1,37 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, float_eq, float_ne};
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Circle { r: f32 },
+    Rect { w: f32, h: f32 },
+}
+
+fn main() {
+    let a = Shape::Rect { w: 1.0, h: 2.0 };
+    let b = Shape::Rect {
+        w: 1.000_000_1,
+        h: 2.0,
+    };
+    assert!(float_eq!(a, b, abs_all <= 0.000_001));
+    assert!(float_eq!(a, b, ulps <= ShapeUlps::Rect { w: 1, h: 0 }));
+    assert_float_eq!(a, b, rmax_all <= 0.000_001);
+    assert_float_eq!(
+        a,
+        b,
+        r2nd <= Shape::Rect {
+            w: 0.000_001,
+            h: 0.0
+        }
+    );
+
+    let c = Shape::Circle { r: 1.0 };
+    assert!(float_ne!(a, c, abs_all <= f32::INFINITY));
+    assert_float_ne!(c, a, ulps_all <= u32::MAX);
+}
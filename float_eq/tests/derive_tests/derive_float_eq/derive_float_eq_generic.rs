@@ -0,0 +1,53 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, derive_float_eq, float_eq, FloatEqBits, FloatEqWeighted,
+};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64",
+    tol_builder = "PointTolBuilder"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqBits, FloatEqWeighted)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape<T> {
+    Circle { centre: Point<T>, r: T },
+    Line(Point<T>, Point<T>),
+}
+
+fn main() {
+    let a = Point { x: 1.0f64, y: 2.0 };
+    let b = Point {
+        x: 1.000_000_000_000_000_2f64,
+        y: 2.0,
+    };
+    assert_float_eq!(a, b, ulps <= PointUlps { x: 1, y: 0 });
+    assert_float_eq!(a, b, abs_all <= 0.000_001);
+    assert_float_ne!(a, b, abs <= Point { x: 0.0, y: 0.0 });
+    assert!(float_eq!(a, a, bits));
+    let ones = Point { x: 1.0, y: 1.0 };
+    assert_eq!(a.weighted_error(&a, &ones, &ones), 0.0);
+    assert!(a.eq_weighted(&b, &ones, &ones, 0.1));
+
+    let tol = Point::tol_builder().x(0.5).rest(0.25).build();
+    assert_eq!(tol, Point { x: 0.5, y: 0.25 });
+
+    let c = Shape::Circle { centre: a, r: 1.0 };
+    let d = Shape::Circle { centre: b, r: 1.0 };
+    assert_float_eq!(c, d, ulps_all <= 1);
+    assert_float_ne!(c, Shape::Line(a, b), abs_all <= f64::INFINITY);
+}
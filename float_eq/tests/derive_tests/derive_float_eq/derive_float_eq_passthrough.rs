@@ -0,0 +1,32 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+#[derive_float_eq(
+    ulps_tol = "MyComplex32Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, Default, PartialEq, serde::Deserialize",
+    ulps_tol_attr = r#"serde(rename_all = "UPPERCASE"), serde(deny_unknown_fields)"#,
+    debug_ulps_diff = "MyComplex32UlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq, serde::Serialize",
+    debug_ulps_diff_attr = r#"serde(rename = "Diff")"#
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+fn main() {
+    let a = MyComplex32 { re: 1.0, im: -2.0 };
+    let b = MyComplex32 {
+        re: 1.000_000_1,
+        im: -2.000_000_5,
+    };
+
+    let tol: MyComplex32Ulps = serde_json::from_str(r#"{ "RE": 1, "IM": 2 }"#).unwrap();
+    assert_eq!(tol, MyComplex32Ulps { re: 1, im: 2 });
+    assert_float_eq!(a, b, ulps <= tol);
+    assert_float_ne!(a, b, ulps <= MyComplex32Ulps::default());
+    assert!(serde_json::from_str::<MyComplex32Ulps>(r#"{ "RE": 1, "IM": 2, "X": 3 }"#).is_err());
+
+    let diff = float_eq::AssertFloatEq::debug_ulps_diff(&a, &b);
+    assert_eq!(serde_json::to_string(&diff).unwrap(), r#"{"re":1,"im":2}"#);
+}
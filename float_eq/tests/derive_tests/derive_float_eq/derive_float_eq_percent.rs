@@ -0,0 +1,43 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq, TolOps};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, TolOps)]
+struct Reading {
+    temperature: f64,
+    pressure: f64,
+}
+
+fn main() {
+    let a = Reading {
+        temperature: 300.0,
+        pressure: 101_325.0,
+    };
+    let b = Reading {
+        temperature: 300.25,
+        pressure: 101_400.0,
+    };
+
+    let tol = Reading {
+        temperature: 0.1,
+        pressure: 0.1,
+    };
+    let strict = Reading {
+        temperature: 0.05,
+        pressure: 0.1,
+    };
+    assert_float_eq!(a, b, percent <= tol);
+    assert_float_ne!(a, b, percent <= strict);
+    assert_float_eq!(a, b, percent_all <= 0.1);
+    assert_float_ne!(a, b, percent_all <= 0.05);
+
+    assert_eq!(
+        a.debug_percent_tol(&b, &tol),
+        a.debug_rmax_tol(&b, &tol.scale(0.01))
+    );
+}
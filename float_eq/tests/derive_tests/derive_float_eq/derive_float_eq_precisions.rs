@@ -0,0 +1,57 @@
+use float_eq::{assert_float_eq, derive_float_eq, AssertFloatEq, UlpsTol};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    precisions = "f32, core::primitive::f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    precisions = "f32, f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape<T> {
+    Circle { centre: Point<T>, r: T },
+}
+
+fn main() {
+    let tol: UlpsTol<Point<f32>> = PointF32Ulps { x: 1, y: 4 };
+    let wide: UlpsTol<Point<f64>> = PointF64Ulps { x: u64::MAX, y: 4 };
+    let a = Point { x: 1.0f32, y: 2.0 };
+    assert_float_eq!(a, a, ulps <= tol);
+    assert_float_eq!(
+        Point { x: 1.0f64, y: 2.0 },
+        Point { x: 1.0, y: 2.0 },
+        ulps <= wide
+    );
+
+    let diff: PointF32DebugUlpsDiff = a.debug_ulps_diff(&a);
+    assert_eq!(
+        diff,
+        PointF32DebugUlpsDiff {
+            x: Some(0),
+            y: Some(0)
+        }
+    );
+    let _: PointF64DebugUlpsDiff =
+        Point { x: 1.0f64, y: 2.0 }.debug_ulps_diff(&Point { x: 1.0, y: 2.0 });
+
+    let c = Shape::Circle { centre: a, r: 1.0 };
+    let tol = ShapeF32Ulps::Circle {
+        centre: PointF32Ulps { x: 0, y: 0 },
+        r: 0,
+    };
+    assert_float_eq!(c, c, ulps <= tol);
+    let _: Option<ShapeF64DebugUlpsDiff> = None;
+}
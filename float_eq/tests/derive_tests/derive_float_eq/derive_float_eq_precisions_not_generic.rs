@@ -0,0 +1,13 @@
+use float_eq::derive_float_eq;
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    precisions = "f32, f64"
+)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+fn main() {}
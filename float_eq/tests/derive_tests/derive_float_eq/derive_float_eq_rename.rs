@@ -0,0 +1,108 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq, AssertFloatEqAll,
+};
+
+#[derive_float_eq(
+    ulps_tol = "VelocityUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "VelocityDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Velocity {
+    #[float_eq(rename = "east")]
+    v_x: f32,
+    #[float_eq(rename = "north")]
+    v_y: f32,
+    up: f32,
+}
+
+fn main() {
+    let a = Velocity {
+        v_x: 1.0,
+        v_y: 2.0,
+        up: 4.0,
+    };
+    let b = Velocity {
+        v_x: 1.000_000_1,
+        v_y: 2.000_000_5,
+        up: 4.0,
+    };
+
+    assert_float_eq!(
+        a,
+        b,
+        ulps <= VelocityUlps {
+            east: 1,
+            north: 2,
+            up: 0
+        }
+    );
+    assert_float_ne!(
+        a,
+        b,
+        ulps <= VelocityUlps {
+            east: 0,
+            north: 2,
+            up: 0
+        }
+    );
+    assert_float_eq!(a, b, ulps_all <= 2);
+
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        VelocityDebugUlpsDiff {
+            east: Some(1),
+            north: Some(2),
+            up: Some(0),
+        }
+    );
+    assert_eq!(
+        a.debug_ulps_tol(
+            &b,
+            &VelocityUlps {
+                east: 1,
+                north: 2,
+                up: 3
+            }
+        ),
+        VelocityUlps {
+            east: 1,
+            north: 2,
+            up: 3
+        }
+    );
+    assert_eq!(
+        a.debug_ulps_all_tol(&b, &4),
+        VelocityUlps {
+            east: 4,
+            north: 4,
+            up: 4
+        }
+    );
+
+    let result = std::panic::catch_unwind(|| {
+        assert_float_eq!(
+            a,
+            b,
+            ulps <= VelocityUlps {
+                east: 0,
+                north: 0,
+                up: 0
+            }
+        )
+    });
+    assert!(result.is_err());
+
+    // the names of the fields themselves are unchanged
+    assert_eq!(Velocity::FIELDS, &["v_x", "v_y", "up"]);
+    let exact = Velocity {
+        v_x: 0.0,
+        v_y: 0.0,
+        up: 0.0,
+    };
+    let diffs = a.diff_fields(&b, &exact);
+    let fields: Vec<_> = diffs.iter().map(|d| d.field).collect();
+    assert_eq!(fields, vec!["v_x", "v_y"]);
+}
@@ -0,0 +1,56 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, float_eq, AssertFloatEq};
+
+#[derive_float_eq(
+    ulps_tol = "ColorUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ColorDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32",
+    rhs = "[f32; 3]"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Color([f32; 3]);
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T",
+    rhs = "[f32; 2], [f64; 2]"
+)]
+#[derive(Debug, Clone, PartialEq)]
+struct Point<T> {
+    xy: [T; 2],
+    #[float_eq(skip)]
+    label: &'static str,
+}
+
+fn main() {
+    let red = Color([1.0, 0.0, 0.0]);
+    assert_float_eq!(red, red, abs <= Color([0.0; 3]));
+    assert_float_eq!(red, [1.0, 0.0, 0.0], abs <= [0.0; 3]);
+    assert_float_eq!(red, [1.000_000_1, 0.0, 0.0], ulps <= [1, 0, 0]);
+    assert_float_ne!(red, [1.000_000_1, 0.0, 0.0], ulps <= [0, 0, 0]);
+    assert_float_eq!(red, [0.9, 0.05, 0.0], abs_all <= 0.11);
+    assert_float_ne!(red, [0.9, 0.05, 0.0], rmax_all <= 0.01);
+    assert!(float_eq!(red, [1.0, 0.0, 0.1], abs <= [0.0, 0.0, 0.1]));
+
+    let diff = red.debug_abs_diff(&[0.5, 0.0, 0.0]);
+    assert_eq!(diff, [0.5, 0.0, 0.0]);
+
+    let p = Point {
+        xy: [1.0f64, 2.0],
+        label: "p",
+    };
+    assert_float_eq!(p, [1.0, 2.0], r2nd <= [0.0; 2]);
+    assert_float_eq!(p, [1.0, 2.5], abs_all <= 0.5);
+    assert_float_ne!(p, [1.0, 2.5], abs_all <= 0.25);
+
+    let q = Point {
+        xy: [1.0f32, 2.0],
+        label: "q",
+    };
+    assert_float_eq!(q, [1.0, 2.0], ulps_all <= 0);
+    assert_float_eq!(q, q, ulps_all <= 0);
+}
@@ -0,0 +1,13 @@
+use float_eq::derive_float_eq;
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    rhs = "[f32; 2]"
+)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+fn main() {}
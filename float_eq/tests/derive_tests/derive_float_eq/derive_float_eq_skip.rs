@@ -0,0 +1,161 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq, AssertFloatEqAll,
+};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32",
+    tol_builder = "ReadingTolBuilder"
+)]
+#[derive(Debug, Clone, PartialEq)]
+struct Reading {
+    #[float_eq(skip)]
+    id: u32,
+    value: f32,
+    #[float_eq(skip)]
+    sensor: String,
+    error: f32,
+}
+
+#[derive_float_eq(
+    ulps_tol = "SampleUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SampleDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, PartialEq)]
+struct Sample(#[float_eq(skip)] u64, f32, #[float_eq(skip)] bool, f32);
+
+#[derive_float_eq(
+    ulps_tol = "EventUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "EventDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Hit {
+        #[float_eq(skip)]
+        id: u32,
+        energy: f32,
+    },
+    Miss(#[float_eq(skip)] u32),
+}
+
+#[derive_float_eq(
+    ulps_tol = "TaggedUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "TaggedDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T",
+    tol_builder = "TaggedTolBuilder"
+)]
+#[derive(Debug, Clone, PartialEq)]
+struct Tagged<T, Id> {
+    #[float_eq(skip)]
+    id: Id,
+    value: T,
+}
+
+fn main() {
+    // skipped fields are not compared
+    let a = Reading {
+        id: 1,
+        value: 1.0,
+        sensor: "left".to_string(),
+        error: 0.5,
+    };
+    let b = Reading {
+        id: 2,
+        value: 1.000_000_1,
+        sensor: "right".to_string(),
+        error: 0.5,
+    };
+    let tol = Reading::tol_builder().value(1e-6).error(0.0).build();
+    assert_eq!(tol.id, 0);
+    assert_eq!(tol.sensor, "");
+    assert_float_eq!(a, b, abs <= tol);
+    assert_float_eq!(a, b, rmax <= Reading::tol_builder().rest(2e-7).build());
+    assert_float_eq!(a, b, ulps <= ReadingUlps { value: 1, error: 0 });
+    assert_float_ne!(a, b, ulps <= ReadingUlps { value: 0, error: 0 });
+    assert_float_eq!(a, b, abs_all <= 1e-6);
+    assert_float_eq!(a, b, ulps_all <= 1);
+    assert_float_ne!(a, b, ulps_all <= 0);
+
+    // and are omitted from the generated types, or taken from self
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        ReadingDebugUlpsDiff {
+            value: Some(1),
+            error: Some(0)
+        }
+    );
+    assert_eq!(
+        a.debug_ulps_all_tol(&b, &3),
+        ReadingUlps { value: 3, error: 3 }
+    );
+    let abs_diff = a.debug_abs_diff(&b);
+    assert_eq!((abs_diff.id, abs_diff.sensor.as_str()), (1, "left"));
+    let abs_tol = b.debug_abs_all_tol(&a, &0.1);
+    assert_eq!((abs_tol.id, abs_tol.sensor.as_str()), (2, "right"));
+    assert_eq!(abs_tol.value, 0.1);
+
+    assert_eq!(Reading::FIELDS, &["value", "error"]);
+    let diffs = a.diff_fields(&b, &Reading::tol_builder().rest(0.0).build());
+    let fields: Vec<_> = diffs.iter().map(|d| d.field).collect();
+    assert_eq!(fields, vec!["value"]);
+
+    // tuple structs renumber the fields of the generated types
+    let a = Sample(1, 1.0, true, 2.0);
+    let b = Sample(2, 1.0, false, 2.000_000_2);
+    assert_float_eq!(a, b, ulps <= SampleUlps(0, 1));
+    assert_float_ne!(a, b, ulps <= SampleUlps(1, 0));
+    assert_float_eq!(a, b, abs_all <= 1e-6);
+    assert_eq!(a.debug_ulps_diff(&b), SampleDebugUlpsDiff(Some(0), Some(1)));
+    assert_eq!(Sample::FIELDS, &["1", "3"]);
+
+    // as do enums
+    let a = Event::Hit { id: 1, energy: 1.0 };
+    let b = Event::Hit { id: 2, energy: 1.0 };
+    assert_float_eq!(a, b, ulps <= EventUlps::Hit { energy: 0 });
+    assert_float_eq!(a, b, abs <= Event::Hit { id: 0, energy: 0.0 });
+    assert_float_eq!(Event::Miss(1), Event::Miss(2), ulps_all <= 0);
+    assert_float_ne!(a, Event::Miss(1), ulps_all <= 0);
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Some(EventDebugUlpsDiff::Hit { energy: Some(0) })
+    );
+    assert_eq!(
+        Event::Miss(1).debug_ulps_all_tol(&Event::Miss(2), &4),
+        Some(EventUlps::Miss)
+    );
+    assert_eq!(
+        a.debug_abs_all_tol(&b, &0.5),
+        Some(Event::Hit { id: 1, energy: 0.5 })
+    );
+
+    // skipped fields may be generic
+    let a = Tagged {
+        id: "a",
+        value: 1.0f64,
+    };
+    let b = Tagged {
+        id: "b",
+        value: 1.0f64,
+    };
+    assert_float_eq!(a, b, ulps <= TaggedUlps { value: 0 });
+    assert_float_eq!(a, b, abs_all <= 0.0);
+    assert_float_eq!(a, b, abs <= Tagged::tol_builder().value(0.0).build());
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        Tagged {
+            id: "a",
+            value: 0.0
+        }
+    );
+}
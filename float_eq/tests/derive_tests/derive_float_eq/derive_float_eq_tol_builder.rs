@@ -0,0 +1,33 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+#[derive_float_eq(
+    ulps_tol = "MyComplex32Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplex32UlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol_builder = "MyComplex32TolBuilder"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+fn main() {
+    let a = MyComplex32 { re: 1.0, im: -2.0 };
+    let b = MyComplex32 {
+        re: 1.000_000_1,
+        im: -2.000_000_5,
+    };
+
+    let tol = MyComplex32::tol_builder()
+        .re(0.000_000_15)
+        .im(0.000_000_55)
+        .build();
+    assert_float_eq!(a, b, abs <= tol);
+    let tol = MyComplex32TolBuilder::new()
+        .re(0.000_000_05)
+        .rest(0.000_000_55f32)
+        .build();
+    assert_float_ne!(a, b, abs <= tol);
+}
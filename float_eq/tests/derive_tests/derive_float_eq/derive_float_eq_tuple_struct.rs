@@ -0,0 +1,54 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, derive_float_eq, AssertFloatEq, AssertFloatEqAll,
+};
+
+#[derive_float_eq(
+    ulps_tol = "Vec2Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "Vec2DebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec2(f32, f32);
+
+#[derive_float_eq(
+    ulps_tol = "OriginUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "OriginDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Origin;
+
+fn main() {
+    let a = Vec2(1.0, 2.0);
+    let b = Vec2(1.000_000_1, 2.0);
+    assert_float_eq!(a, b, abs <= Vec2(1.2e-7, 0.0));
+    assert_float_ne!(a, b, abs <= Vec2(0.0, 1.0));
+    assert_float_eq!(a, b, ulps <= Vec2Ulps(1, 0));
+    assert_float_eq!(a, b, rmax_all <= 1.2e-7);
+    assert_float_ne!(a, b, ulps_all <= 0);
+
+    assert_eq!(a.debug_abs_diff(&b), Vec2(0.000_000_119_209_29, 0.0));
+    assert_eq!(a.debug_ulps_diff(&b), Vec2DebugUlpsDiff(Some(1), Some(0)));
+    assert_eq!(a.debug_abs_tol(&b, &Vec2(0.5, 0.25)), Vec2(0.5, 0.25));
+    assert_eq!(
+        a.debug_rmax_tol(&b, &Vec2(0.5, 0.25)),
+        Vec2(0.500_000_06, 0.5)
+    );
+    assert_eq!(a.debug_ulps_tol(&b, &Vec2Ulps(1, 2)), Vec2Ulps(1, 2));
+    assert_eq!(a.debug_abs_all_tol(&b, &0.5), Vec2(0.5, 0.5));
+    assert_eq!(a.debug_ulps_all_tol(&b, &3), Vec2Ulps(3, 3));
+
+    assert_float_eq!(Origin, Origin, abs <= Origin);
+    assert_float_eq!(Origin, Origin, ulps <= OriginUlps);
+    assert_float_eq!(Origin, Origin, ulps_all <= 0);
+    assert_eq!(Origin.debug_abs_diff(&Origin), Origin);
+    assert_eq!(Origin.debug_ulps_diff(&Origin), OriginDebugUlpsDiff);
+    assert_eq!(Origin.debug_rmin_tol(&Origin, &Origin), Origin);
+    assert_eq!(Origin.debug_ulps_tol(&Origin, &OriginUlps), OriginUlps);
+    assert_eq!(Origin.debug_r2nd_all_tol(&Origin, &1.0), Origin);
+    assert_eq!(Origin.debug_ulps_all_tol(&Origin, &1), OriginUlps);
+}
@@ -1,9 +1,40 @@
 use float_eq::{FloatEq, FloatEqUlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol, FloatEq)]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[derive(Clone, Copy, Debug, PartialEq, FloatEqUlpsTol, FloatEq)]
+#[float_eq(ulps_tol = "ShapeUlps")]
+enum Shape {
+    Circle { r: f32 },
+    Rect(f32, f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = Shape::Circle { r: 2.0 };
+    let b = Shape::Circle { r: 2.000_000_5 };
+
+    assert!(a.eq_abs(&b, &Shape::Circle { r: 0.000_000_5 }));
+    assert!(a.ne_abs(&b, &Shape::Circle { r: 0.000_000_4 }));
+    assert!(a.eq_rel(&b, &Shape::Circle { r: 0.000_000_25 }));
+    assert!(a.ne_rel(&b, &Shape::Circle { r: 0.000_000_15 }));
+    assert!(a.eq_ulps(&b, &ShapeUlps::Circle { r: 2 }));
+    assert!(a.ne_ulps(&b, &ShapeUlps::Circle { r: 1 }));
+
+    let c = Shape::Rect(1.0, 2.0);
+    let d = Shape::Rect(1.000_000_1, 2.0);
+    assert!(c.eq_abs(&d, &Shape::Rect(0.000_000_2, 0.0)));
+    assert!(c.ne_abs(&d, &Shape::Rect(0.0, 0.0)));
+    assert!(c.eq_ulps(&d, &ShapeUlps::Rect(1, 0)));
+
+    // tolerances must be of the same variant as the values
+    assert!(a.ne_abs(&b, &Shape::Rect(1.0, 1.0)));
+    assert!(a.ne_ulps(&b, &ShapeUlps::Empty));
+
+    // values of different variants are never equal
+    assert!(a.ne_abs(&c, &Shape::Circle { r: f32::INFINITY }));
+    assert!(a.ne_abs(&c, &Shape::Rect(f32::INFINITY, f64::INFINITY)));
+    assert!(Shape::Empty.ne_rmax(&a, &Shape::Empty));
+
+    assert!(Shape::Empty.eq_abs(&Shape::Empty, &Shape::Empty));
+    assert!(Shape::Empty.eq_rmin(&Shape::Empty, &Shape::Empty));
+    assert!(Shape::Empty.eq_ulps(&Shape::Empty, &ShapeUlps::Empty));
+}
@@ -1,9 +1,52 @@
 use float_eq::{FloatEq, FloatEqUlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq)]
-struct MyComplexUlps<T> {
-    re: T,
-    im: T,
+#[float_eq(ulps_tol = "PointUlps")]
+struct Point<T, const N: usize>
+where
+    T: Copy,
+{
+    coords: [T; N],
+    weight: f32,
 }
 
-fn main() {}
+fn main() {
+    let a = Point {
+        coords: [2.0f32, -1_000_000.0],
+        weight: 1.0,
+    };
+    let b = Point {
+        coords: [2.000_000_5f32, -1_000_000.06],
+        weight: 1.0,
+    };
+
+    let tol = |x, y| Point {
+        coords: [x, y],
+        weight: 0.0,
+    };
+    assert!(a.eq_abs(&b, &tol(0.000_000_5, 0.07)));
+    assert!(a.ne_abs(&b, &tol(0.000_000_4, 0.07)));
+    assert!(a.eq_rel(&b, &tol(0.000_000_25, 0.000_000_1)));
+    assert!(a.ne_rel(&b, &tol(0.000_000_15, 0.000_000_1)));
+
+    let ulps = |x, y| PointUlps {
+        coords: [x, y],
+        weight: 0,
+    };
+    assert!(a.eq_ulps(&b, &ulps(2, 1)));
+    assert!(a.ne_ulps(&b, &ulps(1, 1)));
+
+    let c = Point {
+        coords: [1.0f64, 2.0, 3.0],
+        weight: 0.5,
+    };
+    assert!(c.eq_abs(
+        &c,
+        &Point {
+            coords: [0.0; 3],
+            weight: 0.0
+        }
+    ));
+
+    assert_eq!(Point::<f64, 3>::FIELDS, &["coords", "weight"]);
+}
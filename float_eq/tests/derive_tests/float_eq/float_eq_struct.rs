@@ -1,4 +1,4 @@
-use float_eq::{FloatEq, FloatEqUlpsTol};
+use float_eq::{CheckSet, FloatEq, FloatEqUlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq)]
 #[float_eq(ulps_tol = "MyComplex32Ulps")]
@@ -28,4 +28,7 @@ fn main() {
     assert!(a.eq_ulps(&b, &MyComplex32Ulps { re: 2, im: 1 }));
     assert!(a.ne_ulps(&b, &MyComplex32Ulps { re: 1, im: 1 }));
     assert!(a.ne_ulps(&b, &MyComplex32Ulps { re: 2, im: 0 }));
+
+    assert_eq!(MyComplex32::FIELDS, &["re", "im"]);
+    assert_eq!(MyComplex32::SUPPORTED_CHECKS, CheckSet::ALL);
 }
@@ -11,4 +11,6 @@ fn main() {
     assert!(a.eq_abs(&b, &MyNoFieldsType {}));
     assert!(a.eq_rel(&b, &MyNoFieldsType {}));
     assert!(a.eq_ulps(&b, &MyNoFieldsTypeUlps {}));
+
+    assert_eq!(MyNoFieldsType::FIELDS, &[] as &[&str]);
 }
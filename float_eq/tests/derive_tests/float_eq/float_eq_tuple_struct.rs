@@ -19,4 +19,6 @@ fn main() {
     assert!(a.eq_ulps(&b, &MyTupleTypeUlps(2, 1)));
     assert!(a.ne_ulps(&b, &MyTupleTypeUlps(1, 1)));
     assert!(a.ne_ulps(&b, &MyTupleTypeUlps(2, 0)));
+
+    assert_eq!(MyTupleType::FIELDS, &["0", "1"]);
 }
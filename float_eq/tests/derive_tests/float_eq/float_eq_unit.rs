@@ -11,4 +11,6 @@ fn main() {
     assert!(a.eq_abs(&b, &MyUnitType {}));
     assert!(a.eq_rel(&b, &MyUnitType {}));
     assert!(a.eq_ulps(&b, &MyUnitTypeUlps {}));
+
+    assert_eq!(MyUnitType::FIELDS, &[] as &[&str]);
 }
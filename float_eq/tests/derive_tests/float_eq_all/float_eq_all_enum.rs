@@ -1,9 +1,29 @@
 use float_eq::{FloatEq, FloatEqAll, FloatEqUlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol, FloatEq, FloatEqAll)]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[derive(Clone, Copy, Debug, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqAll)]
+#[float_eq(ulps_tol = "ShapeUlps", all_tol = "f32")]
+enum Shape {
+    Circle { r: f32 },
+    Rect(f32, f32),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = Shape::Rect(2.0, -1_000_000.0);
+    let b = Shape::Rect(2.000_000_5, -1_000_000.06);
+
+    assert!(a.eq_abs_all(&b, &0.07));
+    assert!(a.ne_abs_all(&b, &0.06));
+
+    assert!(a.eq_rel_all(&b, &0.000_000_25));
+    assert!(a.ne_rel_all(&b, &0.000_000_15));
+
+    assert!(a.eq_ulps_all(&b, &2));
+    assert!(a.ne_ulps_all(&b, &1));
+
+    let c = Shape::Circle { r: 2.0 };
+    assert!(c.eq_abs_all(&Shape::Circle { r: 2.0 }, &0.0));
+    assert!(c.ne_abs_all(&a, &f32::INFINITY));
+    assert!(Shape::Empty.eq_rmax_all(&Shape::Empty, &0.0));
+    assert!(Shape::Empty.ne_ulps_all(&c, &u32::MAX));
+}
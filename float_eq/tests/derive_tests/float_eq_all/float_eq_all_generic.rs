@@ -1,9 +1,32 @@
 use float_eq::{FloatEq, FloatEqAll, FloatEqUlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqAll)]
-struct MyComplexUlps<T> {
+#[float_eq(ulps_tol = "MyComplexUlps", all_tol = "f32")]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::new(2.0f32, -1_000_000.0);
+    let b = MyComplex::new(2.000_000_5f32, -1_000_000.06);
+
+    assert!(a.eq_abs_all(&b, &0.07));
+    assert!(a.ne_abs_all(&b, &0.06));
+
+    assert!(a.eq_rel_all(&b, &0.000_000_25));
+    assert!(a.ne_rel_all(&b, &0.000_000_15));
+
+    assert!(a.eq_ulps_all(&b, &2));
+    assert!(a.ne_ulps_all(&b, &1));
+
+    // arrays of f32 share its AllTol
+    let c = MyComplex::new([1.0f32, 2.0], [3.0, 4.0]);
+    assert!(c.eq_abs_all(&c, &0.0));
+}
@@ -0,0 +1,10 @@
+use float_eq::FloatEqUlpsTol;
+
+#[derive(Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(ulps_tol = "MyComplex32Ulps", ulps_tol_attr = "serde(")]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+fn main() {}
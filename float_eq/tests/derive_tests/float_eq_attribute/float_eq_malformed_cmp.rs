@@ -0,0 +1,11 @@
+use float_eq::FloatEqUlpsTol;
+
+#[derive(Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(ulps_tol = "ReadingUlps")]
+struct Reading {
+    #[float_eq(cmp = "not a path")]
+    id: u32,
+    value: f32,
+}
+
+fn main() {}
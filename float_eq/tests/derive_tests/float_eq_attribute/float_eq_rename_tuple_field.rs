@@ -0,0 +1,7 @@
+use float_eq::FloatEqUlpsTol;
+
+#[derive(Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(ulps_tol = "MyComplex32Ulps")]
+struct MyComplex32(#[float_eq(rename = "re")] f32, f32);
+
+fn main() {}
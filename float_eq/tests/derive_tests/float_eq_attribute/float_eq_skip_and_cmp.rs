@@ -0,0 +1,15 @@
+use float_eq::FloatEqUlpsTol;
+
+fn same(a: &u32, b: &u32) -> bool {
+    a == b
+}
+
+#[derive(Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(ulps_tol = "ReadingUlps")]
+struct Reading {
+    #[float_eq(skip, cmp = "same")]
+    id: u32,
+    value: f32,
+}
+
+fn main() {}
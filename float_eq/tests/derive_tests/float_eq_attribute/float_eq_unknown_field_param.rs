@@ -0,0 +1,11 @@
+use float_eq::FloatEqUlpsTol;
+
+#[derive(Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(ulps_tol = "MyComplex32Ulps")]
+struct MyComplex32 {
+    #[float_eq(cheese = "Hello")]
+    re: f32,
+    im: f32,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use float_eq::FloatEqBits;
+
+#[derive(FloatEqBits)]
+enum Shape {
+    Circle { r: f32 },
+    Rect(f32, f64),
+    Empty,
+}
+
+fn main() {
+    let a = Shape::Circle { r: 1.0 };
+    assert!(a.eq_bits(&Shape::Circle { r: 1.0 }));
+    assert!(a.ne_bits(&Shape::Circle { r: -1.0 }));
+    assert!(a.ne_bits(&Shape::Rect(1.0, 1.0)));
+
+    assert!(Shape::Rect(f32::NAN, 0.0).eq_bits(&Shape::Rect(f32::NAN, 0.0)));
+    assert!(Shape::Rect(1.0, 0.0).ne_bits(&Shape::Rect(1.0, -0.0)));
+    assert!(Shape::Empty.eq_bits(&Shape::Empty));
+}
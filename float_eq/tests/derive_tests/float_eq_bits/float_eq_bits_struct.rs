@@ -0,0 +1,31 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, FloatEqBits};
+
+#[derive_float_eq(
+    ulps_tol = "MyComplex32Ulps",
+    debug_ulps_diff = "MyComplex32DebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqBits)]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+fn main() {
+    let a = MyComplex32 { re: 1.0, im: -0.0 };
+    let b = MyComplex32 { re: 1.0, im: 0.0 };
+    let c = MyComplex32 {
+        re: f32::NAN,
+        im: 2.0,
+    };
+
+    assert!(a.eq_bits(&a));
+    assert!(a.ne_bits(&b));
+    assert!(c.eq_bits(&c));
+
+    assert_float_eq!(a, a, bits);
+    assert_float_eq!(c, c, bits);
+    assert_float_ne!(a, b, bits);
+    assert_float_eq!(c, c, bits_all <= ());
+    assert_float_ne!(a, b, bits <= ());
+}
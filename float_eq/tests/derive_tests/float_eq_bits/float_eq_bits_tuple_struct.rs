@@ -0,0 +1,11 @@
+use float_eq::FloatEqBits;
+
+#[derive(FloatEqBits)]
+struct MyComplex32(f32, f32);
+
+fn main() {
+    let a = MyComplex32(1.0, 2.0);
+    let b = MyComplex32(1.0, 2.000_000_2);
+    assert!(a.eq_bits(&a));
+    assert!(a.ne_bits(&b));
+}
@@ -0,0 +1,8 @@
+use float_eq::FloatEqBits;
+
+#[derive(FloatEqBits)]
+struct MyNoFloat;
+
+fn main() {
+    assert!(MyNoFloat.eq_bits(&MyNoFloat));
+}
@@ -0,0 +1,29 @@
+use float_eq::{FloatEq, FloatEqUlpsTol, FloatEqWeighted};
+
+#[derive(Clone, Copy, Debug, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqWeighted)]
+#[float_eq(ulps_tol = "ShapeUlps")]
+enum Shape {
+    Circle { r: f32 },
+    Rect(f32, f32),
+    Empty,
+}
+
+fn main() {
+    let a = Shape::Rect(1.0, 2.0);
+    let b = Shape::Rect(1.5, 2.25);
+    let tol = Shape::Rect(0.25, 0.25);
+
+    assert_eq!(a.weighted_error(&b, &tol, &Shape::Rect(1.0, 1.0)), 3.0);
+    assert_eq!(a.weighted_error(&a, &tol, &Shape::Rect(1.0, 1.0)), 0.0);
+    assert!(a.eq_weighted(&b, &tol, &Shape::Rect(0.25, 1.0), 1.5));
+    assert!(a.ne_weighted(&b, &tol, &Shape::Rect(0.25, 1.0), 1.4));
+
+    let c = Shape::Circle { r: 1.0 };
+    let error = a.weighted_error(&c, &tol, &Shape::Rect(1.0, 1.0));
+    assert_eq!(error, f64::INFINITY);
+    assert!(a.ne_weighted(&c, &tol, &Shape::Rect(1.0, 1.0), f64::MAX));
+    assert_eq!(
+        Shape::Empty.weighted_error(&Shape::Empty, &Shape::Empty, &Shape::Empty),
+        0.0
+    );
+}
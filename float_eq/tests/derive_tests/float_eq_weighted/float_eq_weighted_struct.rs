@@ -0,0 +1,27 @@
+use float_eq::{FloatEq, FloatEqUlpsTol, FloatEqWeighted};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqWeighted)]
+#[float_eq(ulps_tol = "MyComplex32Ulps")]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+impl MyComplex32 {
+    fn new(re: f32, im: f32) -> MyComplex32 {
+        MyComplex32 { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex32::new(1.0, 2.0);
+    let b = MyComplex32::new(1.5, 2.25);
+    let tol = MyComplex32::new(0.25, 0.25);
+
+    assert_eq!(a.weighted_error(&b, &tol, &MyComplex32::new(1.0, 1.0)), 3.0);
+    assert_eq!(a.weighted_error(&b, &tol, &MyComplex32::new(0.5, 2.0)), 3.0);
+    assert_eq!(a.weighted_error(&a, &tol, &MyComplex32::new(1.0, 1.0)), 0.0);
+
+    assert!(a.eq_weighted(&b, &tol, &MyComplex32::new(0.25, 1.0), 1.5));
+    assert!(a.ne_weighted(&b, &tol, &MyComplex32::new(0.25, 1.0), 1.4));
+}
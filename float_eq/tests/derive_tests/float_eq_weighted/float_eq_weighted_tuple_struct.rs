@@ -0,0 +1,15 @@
+use float_eq::{FloatEq, FloatEqUlpsTol, FloatEqWeighted};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqWeighted)]
+#[float_eq(ulps_tol = "MyTupleTypeUlps")]
+struct MyTupleType(f32, f64);
+
+fn main() {
+    let a = MyTupleType(1.0, 2.0);
+    let b = MyTupleType(1.5, 2.25);
+    let tol = MyTupleType(0.25, 0.25);
+
+    assert_eq!(a.weighted_error(&b, &tol, &MyTupleType(1.0, 1.0)), 3.0);
+    assert!(a.eq_weighted(&b, &tol, &MyTupleType(0.25, 1.0), 1.5));
+    assert!(a.ne_weighted(&b, &tol, &MyTupleType(0.25, 1.0), 1.4));
+}
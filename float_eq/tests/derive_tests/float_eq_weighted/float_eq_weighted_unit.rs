@@ -0,0 +1,11 @@
+use float_eq::{FloatEq, FloatEqUlpsTol, FloatEqWeighted};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqWeighted)]
+#[float_eq(ulps_tol = "MyNoFieldsTypeUlps")]
+struct MyNoFieldsType;
+
+fn main() {
+    let a = MyNoFieldsType;
+    assert_eq!(a.weighted_error(&a, &a, &a), 0.0);
+    assert!(a.eq_weighted(&a, &a, &a, 0.0));
+}
@@ -0,0 +1,10 @@
+use float_eq::FloatEqTolBuilder;
+
+#[derive(FloatEqTolBuilder)]
+#[float_eq(tol_builder = "SomeEnumTolBuilder")]
+enum SomeEnum {
+    Float(f32),
+    Double(f64),
+}
+
+fn main() {}
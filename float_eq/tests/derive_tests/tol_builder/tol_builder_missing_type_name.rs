@@ -0,0 +1,9 @@
+use float_eq::FloatEqTolBuilder;
+
+#[derive(FloatEqTolBuilder)]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+fn main() {}
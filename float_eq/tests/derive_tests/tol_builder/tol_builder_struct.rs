@@ -0,0 +1,86 @@
+use float_eq::{FloatEq, FloatEqTolBuilder, FloatEqUlpsTol};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqTolBuilder)]
+#[float_eq(ulps_tol = "BodyUlps", tol_builder = "BodyTolBuilder")]
+struct Body {
+    mass: f64,
+    x: f32,
+    y: f32,
+}
+
+fn main() {
+    let tol = Body::tol_builder().mass(0.5).x(0.1).y(0.2).build();
+    assert_eq!(
+        tol,
+        Body {
+            mass: 0.5,
+            x: 0.1,
+            y: 0.2,
+        }
+    );
+
+    let tol = BodyTolBuilder::new().mass(1.0).rest(0.25f32).build();
+    assert_eq!(
+        tol,
+        Body {
+            mass: 1.0,
+            x: 0.25,
+            y: 0.25,
+        }
+    );
+
+    let tol = BodyTolBuilder::default().y(2.0).rest(0.5f32).build();
+    assert_eq!(
+        tol,
+        Body {
+            mass: 0.5,
+            x: 0.5,
+            y: 2.0,
+        }
+    );
+
+    const PRESET: Body = Body {
+        mass: 1e-6,
+        x: 1e-3,
+        y: 1e-4,
+    };
+    let tol = Body::tol_builder().x(0.5).rest_from(PRESET).build();
+    assert_eq!(
+        tol,
+        Body {
+            mass: 1e-6,
+            x: 0.5,
+            y: 1e-4,
+        }
+    );
+
+    let tol = Body::tol_builder()
+        .y(0.5)
+        .rest_from(Body::default())
+        .rest(1.0f32)
+        .build();
+    assert_eq!(
+        tol,
+        Body {
+            mass: 0.0,
+            x: 0.0,
+            y: 0.5,
+        }
+    );
+
+    let a = Body {
+        mass: 1.0,
+        x: 0.0,
+        y: 2.0,
+    };
+    let b = Body {
+        mass: 1.5,
+        x: 0.25,
+        y: 2.25,
+    };
+    assert!(a.eq_abs(&b, &Body::tol_builder().mass(0.5).rest(0.25f32).build()));
+    assert!(a.ne_abs(&b, &Body::tol_builder().mass(0.4).rest(0.25f32).build()));
+
+    let result = std::panic::catch_unwind(|| Body::tol_builder().mass(1.0).build());
+    assert!(result.is_err());
+}
@@ -0,0 +1,7 @@
+use float_eq::FloatEqTolBuilder;
+
+#[derive(FloatEqTolBuilder)]
+#[float_eq(tol_builder = "MyComplex32TolBuilder")]
+struct MyComplex32(f32, f32);
+
+fn main() {}
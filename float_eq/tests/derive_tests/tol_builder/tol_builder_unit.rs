@@ -0,0 +1,10 @@
+use float_eq::FloatEqTolBuilder;
+
+#[derive(Debug, PartialEq, FloatEqTolBuilder)]
+#[float_eq(tol_builder = "UnitTolBuilder")]
+struct Unit;
+
+fn main() {
+    assert_eq!(Unit::tol_builder().build(), Unit);
+    assert_eq!(UnitTolBuilder::new().rest(1.0f32).build(), Unit);
+}
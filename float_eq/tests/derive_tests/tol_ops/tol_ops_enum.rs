@@ -0,0 +1,9 @@
+use float_eq::TolOps;
+
+#[derive(TolOps)]
+enum Tol {
+    Scalar(f64),
+    Pair(f64, f64),
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use float_eq::TolOps;
+
+#[derive(Debug, PartialEq, TolOps)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn loosest<T: TolOps>(presets: &[T]) -> Option<T> {
+    let (first, rest) = presets.split_first()?;
+    Some(
+        rest.iter()
+            .fold(first.scale(1.0), |tol, t| tol.max_elementwise(t)),
+    )
+}
+
+fn main() {
+    let presets = [
+        Pair { a: 0.5, b: 0.125 },
+        Pair { a: 0.25, b: 1.0 },
+        Pair { a: 0.0, b: 0.0 },
+    ];
+    assert_eq!(loosest(&presets), Some(Pair { a: 0.5, b: 1.0 }));
+}
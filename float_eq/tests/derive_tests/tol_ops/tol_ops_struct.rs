@@ -0,0 +1,24 @@
+use float_eq::{derive_float_eq, FloatEq, TolOps};
+
+#[derive_float_eq(ulps_tol = "PointUlps", debug_ulps_diff = "PointDebugUlpsDiff")]
+#[derive(Debug, Clone, Copy, PartialEq, TolOps)]
+struct Point {
+    x: f32,
+    y: f64,
+}
+
+fn main() {
+    let strict = Point { x: 0.5, y: 0.001 };
+    let lenient = Point { x: 0.25, y: 0.25 };
+    assert_eq!(strict.max_elementwise(&lenient), Point { x: 0.5, y: 0.25 });
+    assert_eq!(
+        strict.min_elementwise(&lenient),
+        Point { x: 0.25, y: 0.001 }
+    );
+    assert_eq!(lenient.scale(2.0), Point { x: 0.5, y: 0.5 });
+
+    let a = Point { x: 1.0, y: 1.0 };
+    let b = Point { x: 1.3, y: 1.1 };
+    assert!(a.ne_abs(&b, &lenient));
+    assert!(a.eq_abs(&b, &lenient.scale(2.0)));
+}
@@ -0,0 +1,18 @@
+use float_eq::TolOps;
+
+#[derive(Debug, PartialEq, TolOps)]
+struct Range(f64, [f32; 2]);
+
+#[derive(Debug, PartialEq, TolOps)]
+struct Empty;
+
+fn main() {
+    let a = Range(0.5, [1.0, 0.125]);
+    let b = Range(0.25, [2.0, 0.0625]);
+    assert_eq!(a.max_elementwise(&b), Range(0.5, [2.0, 0.125]));
+    assert_eq!(a.min_elementwise(&b), Range(0.25, [1.0, 0.0625]));
+    assert_eq!(a.scale(0.5), Range(0.25, [0.5, 0.0625]));
+
+    assert_eq!(Empty.max_elementwise(&Empty), Empty);
+    assert_eq!(Empty.scale(2.0), Empty);
+}
@@ -1,9 +1,19 @@
-use float_eq::FloatEqUlpsTol;
+use float_eq::{FloatEqUlpsTol, UlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol)]
-enum SomeEnum {
-    Float(f32),
-    Double(f64),
+#[derive(Clone, Copy, Debug, PartialEq, FloatEqUlpsTol)]
+#[float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq"
+)]
+enum Shape {
+    Circle { r: f32 },
+    Rect(f32, f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let circle: UlpsTol<Shape> = ShapeUlps::Circle { r: 1 };
+    assert_eq!(circle, ShapeUlps::Circle { r: 1u32 });
+    assert_ne!(circle, ShapeUlps::Rect(1u32, 1u64));
+    assert_eq!(ShapeUlps::Empty, ShapeUlps::Empty);
+}
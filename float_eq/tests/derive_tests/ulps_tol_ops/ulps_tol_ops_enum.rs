@@ -0,0 +1,9 @@
+use float_eq::UlpsTolOps;
+
+#[derive(UlpsTolOps)]
+enum Budget {
+    Scalar(u32),
+    Pair(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use float_eq::{derive_float_eq, UlpsTol, UlpsTolOps};
+
+#[derive_float_eq(
+    ulps_tol = "PairUlps",
+    ulps_tol_derive = "Debug, PartialEq, UlpsTolOps",
+    debug_ulps_diff = "PairDebugUlpsDiff"
+)]
+#[derive(Debug, PartialEq)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn widen<T: UlpsTolOps>(tol: T, floor: T) -> T {
+    tol.max_elementwise(&floor)
+}
+
+fn main() {
+    let tol: UlpsTol<Pair<f64>> = PairUlps { a: 1, b: 8 };
+    assert_eq!(widen(tol, PairUlps { a: 4, b: 4 }), PairUlps { a: 4, b: 8 });
+}
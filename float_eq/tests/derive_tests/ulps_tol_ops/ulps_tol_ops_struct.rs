@@ -0,0 +1,42 @@
+use float_eq::{derive_float_eq, UlpsTolOps};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq, UlpsTolOps",
+    debug_ulps_diff = "PointDebugUlpsDiff"
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Point {
+    x: f32,
+    y: f64,
+    rgb: [f32; 3],
+}
+
+fn main() {
+    let a = PointUlps {
+        x: 1,
+        y: u64::MAX - 1,
+        rgb: [1, 2, 3],
+    };
+    let b = PointUlps {
+        x: 2,
+        y: 2,
+        rgb: [3, 2, 1],
+    };
+    assert_eq!(
+        a.saturating_add(&b),
+        PointUlps {
+            x: 3,
+            y: u64::MAX,
+            rgb: [4, 4, 4]
+        }
+    );
+    assert_eq!(
+        a.max_elementwise(&b),
+        PointUlps {
+            x: 2,
+            y: u64::MAX - 1,
+            rgb: [3, 2, 3]
+        }
+    );
+}
@@ -0,0 +1,16 @@
+use float_eq::UlpsTolOps;
+
+#[derive(Debug, PartialEq, UlpsTolOps)]
+struct Budget(u32, (u64, u32));
+
+#[derive(Debug, PartialEq, UlpsTolOps)]
+struct NoBudget;
+
+fn main() {
+    let a = Budget(u32::MAX, (1, 5));
+    let b = Budget(1, (4, 2));
+    assert_eq!(a.saturating_add(&b), Budget(u32::MAX, (5, 7)));
+    assert_eq!(a.max_elementwise(&b), Budget(u32::MAX, (4, 5)));
+    assert_eq!(NoBudget.saturating_add(&NoBudget), NoBudget);
+    assert_eq!(NoBudget.max_elementwise(&NoBudget), NoBudget);
+}
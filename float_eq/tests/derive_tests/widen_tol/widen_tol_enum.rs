@@ -0,0 +1,13 @@
+use float_eq::WidenTol;
+
+enum Budget64 {
+    Scalar(f64),
+}
+
+#[derive(WidenTol)]
+#[float_eq(wide = "Budget64")]
+enum Budget32 {
+    Scalar(f32),
+}
+
+fn main() {}
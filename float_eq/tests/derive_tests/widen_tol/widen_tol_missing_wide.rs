@@ -0,0 +1,6 @@
+use float_eq::WidenTol;
+
+#[derive(WidenTol)]
+struct Budget32(f32, u32);
+
+fn main() {}
@@ -0,0 +1,77 @@
+use float_eq::{assert_float_eq, derive_float_eq, WidenTol};
+
+#[derive_float_eq(
+    ulps_tol = "Point64Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "Point64DebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug"
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Point64 {
+    x: f64,
+    y: f64,
+    rgb: [f64; 3],
+}
+
+#[derive_float_eq(
+    ulps_tol = "Point32Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq, WidenTol",
+    ulps_tol_attr = r#"float_eq(wide = "Point64Ulps")"#,
+    debug_ulps_diff = "Point32DebugUlpsDiff",
+    wide = "Point64"
+)]
+#[derive(Debug, PartialEq, Clone, Copy, WidenTol)]
+struct Point32 {
+    x: f32,
+    y: f32,
+    rgb: [f32; 3],
+}
+
+fn main() {
+    let tol = Point32 {
+        x: 0.5,
+        y: 0.25,
+        rgb: [1.0, 2.0, 4.0],
+    };
+    let wide = Point64 {
+        x: 0.5,
+        y: 0.25,
+        rgb: [1.0, 2.0, 4.0],
+    };
+    assert_eq!(tol.widen(), wide);
+    assert_eq!(Point32::narrow(&wide), tol);
+
+    let ulps = Point32Ulps {
+        x: 1,
+        y: 2,
+        rgb: [3, 4, 5],
+    };
+    let wide_ulps = Point64Ulps {
+        x: 1,
+        y: u64::MAX,
+        rgb: [3, 4, 5],
+    };
+    assert_eq!(
+        ulps.widen(),
+        Point64Ulps {
+            x: 1,
+            y: 2,
+            rgb: [3, 4, 5]
+        }
+    );
+    assert_eq!(
+        Point32Ulps::narrow(&wide_ulps),
+        Point32Ulps {
+            x: 1,
+            y: u32::MAX,
+            rgb: [3, 4, 5]
+        }
+    );
+
+    let a = Point64 {
+        x: 1.0,
+        y: 2.0,
+        rgb: [0.0, 0.5, 1.0],
+    };
+    assert_float_eq!(a, a, abs <= tol.widen(), ulps <= ulps.widen());
+}
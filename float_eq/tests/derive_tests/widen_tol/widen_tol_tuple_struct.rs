@@ -0,0 +1,23 @@
+use float_eq::WidenTol;
+
+#[derive(Debug, PartialEq)]
+struct Budget64(f64, (u64, f64));
+
+#[derive(Debug, PartialEq, WidenTol)]
+#[float_eq(wide = "Budget64")]
+struct Budget32(f32, (u32, f64));
+
+#[derive(Debug, PartialEq)]
+struct NoBudget64;
+
+#[derive(Debug, PartialEq, WidenTol)]
+#[float_eq(wide = "NoBudget64")]
+struct NoBudget32;
+
+fn main() {
+    let a = Budget32(0.5, (u32::MAX, 0.25));
+    assert_eq!(a.widen(), Budget64(0.5, (u64::from(u32::MAX), 0.25)));
+    assert_eq!(Budget32::narrow(&Budget64(0.5, (u64::MAX, 0.25))), a);
+    assert_eq!(NoBudget32.widen(), NoBudget64);
+    assert_eq!(NoBudget32::narrow(&NoBudget64), NoBudget32);
+}
@@ -0,0 +1,102 @@
+#![cfg(all(feature = "std", not(feature = "checks-only")))]
+
+// The failure format is read from the environment, so these tests are kept in
+// their own test binary where it may be set without affecting other tests. It
+// is only read once, so every test sets it to the same value, and they are
+// serialised since setting a variable whilst another thread reads it is unsafe.
+
+use float_eq::{assert_float_eq, assert_float_ne, FailureFormat};
+use std::sync::{Mutex, MutexGuard};
+
+static FORMAT: Mutex<()> = Mutex::new(());
+
+fn key_value() -> MutexGuard<'static, ()> {
+    let guard = FORMAT.lock().unwrap_or_else(|e| e.into_inner());
+    std::env::set_var("FLOAT_EQ_FAILURE_FORMAT", "kv");
+    guard
+}
+
+#[test]
+fn current() {
+    let _format = key_value();
+    assert_eq!(FailureFormat::current(), FailureFormat::KeyValue);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_eq!(left, right, rmax <= t)" left="4.0" right="4.000008" abs_diff="8.106232e-6" ulps_diff="Some(17)" tol.rmax="4.000008e-6""#
+)]
+fn assert_float_eq_fail() {
+    let _format = key_value();
+    assert_float_eq!(4.0f32, 4.000_008, rmax <= 0.000_001);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_eq!(left, right, abs <= t, ulps <= t)" left="1.0" right="2.0" abs_diff="1.0" ulps_diff="Some(8388608)" tol.abs="0.5" tol.ulps="4""#
+)]
+fn assert_float_eq_fail_multiple() {
+    let _format = key_value();
+    assert_float_eq!(1.0f32, 2.0, abs <= 0.5, ulps <= 4);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_ne!(left, right, abs <= t)" left="1.0" right="1.0" abs_diff="0.0" ulps_diff="Some(0)" tol.abs="0.1""#
+)]
+fn assert_float_ne_fail() {
+    let _format = key_value();
+    assert_float_ne!(1.0f64, 1.0, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_eq!(left, right, abs <= t)" left="NaN" right="1.0" abs_diff="NaN" ulps_diff="None" ulps_diff_reason="an operand is NaN; ULPs comparison not applicable" tol.abs="0.1" note="left operand is NaN""#
+)]
+fn note() {
+    let _format = key_value();
+    assert_float_eq!(f64::NAN, 1.0, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"tol.abs="0.1" message="a \"quoted\"\n\\message""#)]
+fn message_is_escaped() {
+    let _format = key_value();
+    assert_float_eq!(1.0f64, 2.0, abs <= 0.1, "a {:?}\n\\message", "quoted");
+}
+
+#[test]
+#[should_panic(expected = r#"left="[1.0, 2.0]" right="[1.0, 3.0]""#)]
+fn composite_values() {
+    let _format = key_value();
+    assert_float_eq!([1.0f32, 2.0], [1.0f32, 3.0], abs_all <= 0.1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_eq_rounded!(left, right, increment = t, rounding = r)" left="0.125" right="0.12" rounded_left="0.13" rounded_right="0.12" tol.increment="0.01" rounding="HalfAwayFromZero""#
+)]
+fn assert_float_eq_rounded_fail() {
+    let _format = key_value();
+    float_eq::assert_float_eq_rounded!(0.125, 0.12, increment = 0.01);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: check="float_idempotent!(f, x, abs <= t)" once="3.0" twice="2.5" abs_diff="0.5" ulps_diff="Some(1125899906842624)" tol.abs="0.25""#
+)]
+fn assert_float_idempotent_fail() {
+    let _format = key_value();
+    float_eq::assert_float_idempotent!(|x: f64| x * 0.5 + 1.0, 4.0, abs <= 0.25);
+}
+
+#[cfg(feature = "capture")]
+#[test]
+#[should_panic(
+    expected = r#"tol.abs="0.1" capture.expr="x.sqrt()" capture.value="2.0" capture.expr="y" capture.value="1.0" message="m""#
+)]
+fn captured_operands() {
+    let _format = key_value();
+    let (x, y) = (4.0f64, 1.0);
+    float_eq::assert_float_eq_captured!(x.sqrt() * y, 1.0, abs <= 0.1, "m");
+}
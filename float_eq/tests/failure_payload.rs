@@ -0,0 +1,121 @@
+#![cfg(all(feature = "std", not(feature = "checks-only")))]
+
+// The failure payload is a process wide setting, so these tests are kept in
+// their own test binary where it may be changed without affecting other tests.
+
+use float_eq::{
+    assert_float_eq, assert_float_eq_str, assert_float_ne, DebugCategories, FailurePayload,
+    FailureReport, FloatCategory, UlpsDiffReason,
+};
+use std::panic;
+
+fn report(f: impl FnOnce() + panic::UnwindSafe) -> FailureReport {
+    FailurePayload::Report.set_current();
+    let payload = panic::catch_unwind(f).unwrap_err();
+    *payload.downcast::<FailureReport>().unwrap()
+}
+
+fn fields(fields: &[(&str, &str)]) -> Vec<(String, String)> {
+    fields
+        .iter()
+        .map(|(l, v)| (l.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn current() {
+    FailurePayload::Report.set_current();
+    assert_eq!(FailurePayload::current(), FailurePayload::Report);
+}
+
+#[test]
+fn assert_float_eq_report() {
+    let report = report(|| assert_float_eq!(4.0f32, 4.000_008, rmax <= 0.000_001));
+    assert_eq!(
+        report,
+        FailureReport {
+            check: "float_eq!(left, right, rmax <= t)".to_string(),
+            fields: fields(&[
+                ("left", "4.0"),
+                ("right", "4.000008"),
+                ("abs_diff", "8.106232e-6"),
+                ("ulps_diff", "Some(17)"),
+                ("[rmax] t", "4.000008e-6"),
+            ]),
+            note: None,
+            ulps_diff_reason: None,
+            message: None,
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        r#"assertion failed: `float_eq!(left, right, rmax <= t)`
+        left: `4.0`,
+       right: `4.000008`,
+    abs_diff: `8.106232e-6`,
+   ulps_diff: `Some(17)`,
+    [rmax] t: `4.000008e-6`"#
+    );
+}
+
+#[test]
+fn assert_float_eq_report_note_and_message() {
+    let report = report(|| assert_float_eq!(f64::INFINITY, 1.0, abs <= 0.1, "x = {}", 1));
+    assert_eq!(report.field("[abs] t"), Some("0.1"));
+    assert_eq!(
+        report.note,
+        DebugCategories::new(Some(FloatCategory::Infinite), None)
+    );
+    assert_eq!(report.message.as_deref(), Some("x = 1"));
+    assert!(report
+        .to_string()
+        .ends_with(",\n        note: left operand is infinite: x = 1"));
+}
+
+#[test]
+fn assert_float_eq_report_ulps_diff_reason() {
+    let report = report(|| assert_float_eq!(-1.0f32, 1.0, abs <= 0.1));
+    assert_eq!(report.field("ulps_diff"), Some("None"));
+    assert_eq!(
+        report.ulps_diff_reason,
+        Some(UlpsDiffReason::DifferentSigns)
+    );
+    assert!(report
+        .to_string()
+        .contains("ulps_diff: operands differ in sign; ULPs comparison not applicable,\n"));
+}
+
+#[test]
+fn assert_float_ne_report() {
+    let report = report(|| assert_float_ne!([1.0f32, 2.0], [1.0, 2.0], abs_all <= 0.1));
+    assert_eq!(report.check, "float_ne!(left, right, abs_all <= t)");
+    assert_eq!(report.field("left"), Some("[1.0, 2.0]"));
+    assert_eq!(report.field("abs_diff"), Some("[0.0, 0.0]"));
+    assert_eq!(report.field("missing"), None);
+}
+
+#[test]
+fn assert_float_eq_str_report() {
+    let report = report(|| assert_float_eq_str!(1.0f64, "1.1", sig_figs <= 2));
+    assert_eq!(
+        report.fields,
+        fields(&[("left", "1.0"), ("right", "\"1.1\""), ("[sig_figs] t", "2")])
+    );
+}
+
+#[cfg(feature = "capture")]
+#[test]
+fn assert_float_eq_captured_report() {
+    let report = report(|| {
+        let (x, y) = (4.0f32, 2.0f32);
+        float_eq::assert_float_eq_captured!(x - y, 1.0, abs <= 0.1)
+    });
+    assert_eq!(report.field("[abs] t"), Some("0.1"));
+    assert_eq!(report.field("`x`"), Some("4.0"));
+    assert_eq!(report.field("`y`"), Some("2.0"));
+    assert!(report.to_string().ends_with(
+        r#"     [abs] t: `0.1`,
+         `x`: `4.0`,
+         `y`: `2.0`"#
+    ));
+}
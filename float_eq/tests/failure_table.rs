@@ -0,0 +1,207 @@
+#![cfg(all(feature = "std", not(feature = "checks-only")))]
+
+// The failure format is read from the environment, so these tests are kept in
+// their own test binary where it may be set without affecting other tests. It
+// is only read once, so every test sets it to the same value, and they are
+// serialised since setting a variable whilst another thread reads it is unsafe.
+
+use float_eq::{assert_float_eq, assert_float_ne, FailureFormat, FailureReport, FailureRow};
+use std::sync::{Mutex, MutexGuard};
+
+static FORMAT: Mutex<()> = Mutex::new(());
+
+fn table() -> MutexGuard<'static, ()> {
+    let guard = FORMAT.lock().unwrap_or_else(|e| e.into_inner());
+    std::env::set_var("FLOAT_EQ_FAILURE_FORMAT", "table");
+    // so that the expected messages do not depend on the `pretty` feature
+    std::env::set_var("NO_COLOR", "1");
+    guard
+}
+
+fn report(fields: &[(&str, &str)]) -> FailureReport {
+    FailureReport {
+        check: "float_eq!(left, right, abs <= t)".to_string(),
+        fields: fields
+            .iter()
+            .map(|(label, value)| (label.to_string(), value.to_string()))
+            .collect(),
+        note: None,
+        ulps_diff_reason: None,
+        message: None,
+    }
+}
+
+fn paths(rows: &[FailureRow]) -> Vec<&str> {
+    rows.iter().map(|row| row.path.as_str()).collect()
+}
+
+#[test]
+fn current() {
+    let _format = table();
+    assert_eq!(FailureFormat::current(), FailureFormat::Table);
+}
+
+#[test]
+fn rows_of_primitives() {
+    let rows = report(&[
+        ("left", "1.0"),
+        ("right", "1.5"),
+        ("abs_diff", "0.5"),
+        ("ulps_diff", "Some(4194304)"),
+        ("[abs] t", "0.1"),
+    ])
+    .rows();
+    assert_eq!(paths(&rows), [""]);
+    assert!(rows[0].failed);
+    assert_eq!(rows[0].value("right"), Some("1.5"));
+    assert_eq!(rows[0].value("[abs] t"), Some("0.1"));
+    assert_eq!(rows[0].value("missing"), None);
+}
+
+#[test]
+fn rows_of_structs() {
+    let rows = report(&[
+        ("left", "Body { pos: Point { x: 1.0, y: 2.0 }, mass: 3.0 }"),
+        ("right", "Body { pos: Point { x: 1.0, y: 2.5 }, mass: 3.0 }"),
+        ("abs_diff", "Body { pos: Point { x: 0.0, y: 0.5 }, mass: 0.0 }"),
+        (
+            "ulps_diff",
+            "BodyDebugUlpsDiff { pos: PointDebugUlpsDiff { x: Some(0), y: Some(1048576) }, mass: Some(0) }",
+        ),
+        ("[abs] t", "Body { pos: Point { x: 0.1, y: 0.1 }, mass: 0.1 }"),
+        ("[ulps] t", "BodyUlps { pos: PointUlps { x: 4, y: 4 }, mass: 4 }"),
+    ])
+    .rows();
+    assert_eq!(paths(&rows), ["pos.x", "pos.y", "mass"]);
+    let failed: Vec<bool> = rows.iter().map(|row| row.failed).collect();
+    assert_eq!(failed, [false, true, false]);
+    assert_eq!(rows[1].value("ulps_diff"), Some("Some(1048576)"));
+    assert_eq!(rows[1].value("[ulps] t"), Some("4"));
+}
+
+#[test]
+fn rows_of_collections() {
+    let rows = report(&[
+        ("left", r#"{"a": [1.0, 2.0], "b, c": (3.0, None)}"#),
+        ("right", r#"{"a": [1.0, 2.0], "b, c": (3.0, Some(1.0))}"#),
+    ])
+    .rows();
+    assert_eq!(
+        paths(&rows),
+        [
+            r#"["a"][0]"#,
+            r#"["a"][1]"#,
+            r#"["b, c"][0]"#,
+            r#"["b, c"][1]"#
+        ]
+    );
+    assert_eq!(rows[3].value("right"), Some("Some(1.0)"));
+    // no tolerances to check against
+    assert!(rows.iter().all(|row| row.failed));
+}
+
+#[test]
+fn rows_of_unparsed_values() {
+    for value in [
+        "",
+        "1.0 m/s",
+        "Point { x: 1.0",
+        "[1.0, ]",
+        "\"unterminated",
+        "é(ü",
+    ] {
+        let rows = report(&[("left", value), ("right", "1.0")]).rows();
+        assert_eq!(paths(&rows), [""]);
+        assert_eq!(rows[0].value("left"), Some(value.trim()));
+    }
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs_all <= t)`
+  1 of 4 values differ:
+  path    left  right  abs_diff  ulps_diff      [abs_all] t
+  [0][1]  2.0   2.5    0.5       Some(2097152)  0.1"#)]
+fn assert_float_eq_fail() {
+    let _format = table();
+    assert_float_eq!(
+        [[1.0f32, 2.0], [3.0, 4.0]],
+        [[1.0, 2.5], [3.0, 4.0]],
+        abs_all <= 0.1
+    );
+}
+
+#[cfg(feature = "derive")]
+mod derived {
+    use super::table;
+    use float_eq::{assert_float_eq, derive_float_eq};
+
+    #[derive_float_eq(
+        ulps_tol = "PointUlps",
+        ulps_tol_derive = "Clone, Copy, Debug",
+        debug_ulps_diff = "PointDebugUlpsDiff",
+        debug_ulps_diff_derive = "Clone, Copy, Debug"
+    )]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive_float_eq(
+        ulps_tol = "BodyUlps",
+        ulps_tol_derive = "Clone, Copy, Debug",
+        debug_ulps_diff = "BodyDebugUlpsDiff",
+        debug_ulps_diff_derive = "Clone, Copy, Debug"
+    )]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Body {
+        pos: Point,
+        mass: f32,
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+  1 of 3 values differ:
+  path   left  right  abs_diff  ulps_diff      [abs] t
+  pos.y  2.0   2.5    0.5       Some(2097152)  0.1"#)]
+    fn assert_float_eq_fail() {
+        let _format = table();
+        let a = Body {
+            pos: Point { x: 1.0, y: 2.0 },
+            mass: 3.0,
+        };
+        let b = Body {
+            pos: Point { x: 1.0, y: 2.5 },
+            mass: 3.0,
+        };
+        let t = Body {
+            pos: Point { x: 0.1, y: 0.1 },
+            mass: 0.1,
+        };
+        assert_float_eq!(a, b, abs <= t);
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `float_eq!(left, right, abs <= t, ulps <= t)`
+  left  right  abs_diff  ulps_diff  [abs] t  [ulps] t
+  NaN   1.0    NaN       None       0.1      4
+  ulps_diff: an operand is NaN; ULPs comparison not applicable
+  note: left operand is NaN
+  message: m"#
+)]
+fn assert_float_eq_fail_primitive() {
+    let _format = table();
+    assert_float_eq!(f64::NAN, 1.0, abs <= 0.1, ulps <= 4, "m");
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_ne!(left, right, abs_all <= t)`
+  path  left  right  abs_diff  ulps_diff  [abs_all] t
+  [0]   1.0   1.0    0.0       Some(0)    0.1
+  [1]   2.0   2.0    0.0       Some(0)    0.1"#)]
+fn assert_float_ne_fail() {
+    let _format = table();
+    assert_float_ne!([1.0f32, 2.0], [1.0, 2.0], abs_all <= 0.1);
+}
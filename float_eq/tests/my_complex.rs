@@ -1,8 +1,9 @@
-#![allow(clippy::float_cmp)]
+#![cfg(not(feature = "checks-only"))]
+#![allow(clippy::float_cmp, clippy::needless_borrow)]
 
 use float_eq::{
-    assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq,
-    FloatEqAll, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol,
+    assert_float_eq, assert_float_ne, assert_impl_conforms, AssertFloatEq, AssertFloatEqAll,
+    DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol,
 };
 
 //------------------------------------------------------------------------------
@@ -426,3 +427,18 @@ fn assert_float_eq() {
     assert_float_eq!(a, b, ulps_all <= 2);
     assert_float_ne!(a, b, ulps_all <= 1);
 }
+
+#[test]
+fn impl_conforms() {
+    let values = [
+        MyComplex32::new(0.0, -0.0),
+        MyComplex32::new(1.0, 2.0),
+        MyComplex32::new(1.000_000_1, 1.999_999_9),
+        MyComplex32::new(-1.0, 2.0),
+        MyComplex32::new(f32::INFINITY, 0.5),
+    ];
+    let tol = MyComplex32::new(0.25, f32::EPSILON);
+    let ulps_tol = MyComplex32Ulps::new(1, 4);
+
+    assert_impl_conforms!(values, tol, ulps_tol, f32::EPSILON, 2);
+}
@@ -1,3 +1,4 @@
+#![cfg(not(feature = "checks-only"))]
 #![allow(clippy::float_cmp)]
 
 use float_eq::{
@@ -269,8 +270,12 @@ fn float_eq_macro_f32() {
     assert!(float_eq!(c, d, r2nd <= (0.5 * f32::EPSILON)));
     assert!(float_eq!(d, c, r2nd <= (1.0 * f32::EPSILON)));
 
-    assert!(float_ne!(c, d, ulps <= 0));
-    assert!(float_ne!(d, c, ulps <= 0));
+    // the tightest not equal check warns, but is wanted here
+    #[allow(deprecated)]
+    {
+        assert!(float_ne!(c, d, ulps <= 0));
+        assert!(float_ne!(d, c, ulps <= 0));
+    }
     assert!(float_eq!(c, d, ulps <= 1));
     assert!(float_eq!(d, c, ulps <= 1));
 }
@@ -573,8 +578,12 @@ fn assert_float_eq_f32() {
     assert_float_eq!(c, d, r2nd <= (0.5 * f32::EPSILON));
     assert_float_eq!(d, c, r2nd <= (1.0 * f32::EPSILON));
 
-    assert_float_ne!(c, d, ulps <= 0);
-    assert_float_ne!(d, c, ulps <= 0);
+    // the tightest not equal check warns, but is wanted here
+    #[allow(deprecated)]
+    {
+        assert_float_ne!(c, d, ulps <= 0);
+        assert_float_ne!(d, c, ulps <= 0);
+    }
     assert_float_eq!(c, d, ulps <= 1);
     assert_float_eq!(d, c, ulps <= 1);
 }
@@ -1,4 +1,5 @@
-#![allow(clippy::float_cmp)]
+#![cfg(not(feature = "checks-only"))]
+#![allow(clippy::float_cmp, clippy::needless_borrow)]
 
 use core::fmt;
 use float_eq::{
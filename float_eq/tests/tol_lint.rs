@@ -0,0 +1,15 @@
+#![cfg(not(feature = "checks-only"))]
+
+#[test]
+fn tests() {
+    let t = trybuild::TestCases::new();
+
+    t.pass("tests/tol_lint/valid_tol.rs");
+    t.compile_fail("tests/tol_lint/negative_tol.rs");
+    t.compile_fail("tests/tol_lint/nan_tol.rs");
+    t.compile_fail("tests/tol_lint/wrapped_negative_tol.rs");
+
+    // warnings, denied so that they may be checked
+    t.compile_fail("tests/tol_lint/relative_tol_too_large.rs");
+    t.compile_fail("tests/tol_lint/ne_zero_ulps.rs");
+}
@@ -0,0 +1,5 @@
+use float_eq::assert_float_eq;
+
+fn main() {
+    assert_float_eq!(1.0f64, 1.0, rmax <= f64::NAN);
+}
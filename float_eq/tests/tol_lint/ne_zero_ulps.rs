@@ -0,0 +1,9 @@
+#![deny(deprecated)]
+
+use float_eq::{assert_float_ne, float_ne};
+
+fn main() {
+    let _ = float_ne!(1.0f32, 2.0, ulps <= 0);
+    assert_float_ne!(1.0f64, 2.0, abs <= 0.1, ulps_all <= 0);
+    let _ = float_ne!([1.0f32, 2.0], [1.0, 3.0], max_ulps <= 0);
+}
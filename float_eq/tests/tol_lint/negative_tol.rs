@@ -0,0 +1,5 @@
+use float_eq::float_eq;
+
+fn main() {
+    let _ = float_eq!(1.0, 1.0, abs <= -1e-6);
+}
@@ -0,0 +1,8 @@
+#![deny(deprecated)]
+
+use float_eq::{assert_float_eq, float_eq};
+
+fn main() {
+    let _ = float_eq!(1.0, 1.0, rmax <= 1e6);
+    assert_float_eq!(1.0, 1.0, abs <= 1e-6, rel_all <= 10.0);
+}
@@ -0,0 +1,37 @@
+#![deny(warnings)]
+
+use float_eq::{
+    assert_both_near_reference, assert_float_eq, assert_float_eq_tiered,
+    assert_float_eq_tighter_than, assert_float_ne, float_eq, float_eq_tiered, float_ne,
+};
+
+fn main() {
+    let tol = 1.0;
+    let _ = float_eq!(
+        1.0f64,
+        1.0,
+        abs <= 0.0,
+        rel <= 1.0,
+        rmax <= 0.000_001,
+        ulps <= 0
+    );
+    let _ = float_ne!(
+        1.0f64,
+        2.0,
+        abs <= 1e-6,
+        rmin <= 9.9,
+        r1st <= 0.5e1,
+        ulps <= 1
+    );
+    let _ = float_eq!(1.0f64, 1.0, abs <= -0.0, r2nd <= 2.0 * 10.0, ulps_all <= 0);
+    let _ = float_ne!(1.0f64, 2.0, abs <= tol, rel <= 1e6 * tol);
+    // a negative expression is only checked at runtime
+    let _ = |tol: f64| float_ne!(1.0f64, 2.0, abs <= -tol);
+    let _ = float_eq!([1.0f64], [1.0], max_abs <= 10.0, mean_abs <= 1e6, max_ulps <= 0);
+    assert_float_eq!(1.0f64, 1.0, rmax <= 100e-3, ulps <= 0);
+    assert_float_ne!(1.0f64, 2.0, ulps <= 1, "{}", 1);
+    let _ = float_eq_tiered!(1.0f64, 1.0, strict: ulps <= 0, lenient: rmax <= 0.1);
+    assert_float_eq_tiered!(1.0f64, 1.0, strict: abs <= 0.0, lenient: abs <= 1e-6);
+    assert_float_eq_tighter_than!(1.0f64, 1.0, 0.001, factor = 0.5);
+    assert_both_near_reference!(1.0f64, 1.0, 1.0, abs <= 0.1, between ulps <= 4);
+}
@@ -0,0 +1,5 @@
+use float_eq::assert_both_near_reference;
+
+fn main() {
+    assert_both_near_reference!(1.0f64, 1.0, 1.0, abs <= -1.0);
+}
@@ -0,0 +1,105 @@
+#![cfg(all(feature = "std", not(feature = "checks-only")))]
+
+// Annealing is enabled from the environment, so these tests are kept in their
+// own test binary where it may be set without affecting other tests. They hold
+// a lock whilst doing so, since they may otherwise race each other.
+
+use float_eq::{assert_float_eq_annealed, float_eq, Annealed};
+use std::sync::{Mutex, MutexGuard};
+
+static ANNEAL: Mutex<()> = Mutex::new(());
+
+fn set_anneal(value: Option<&str>) -> MutexGuard<'static, ()> {
+    let guard = ANNEAL.lock().unwrap_or_else(|e| e.into_inner());
+    match value {
+        Some(value) => std::env::set_var("FLOAT_EQ_ANNEAL", value),
+        None => std::env::remove_var("FLOAT_EQ_ANNEAL"),
+    }
+    guard
+}
+
+#[test]
+fn enabled() {
+    let _guard = set_anneal(Some("1"));
+    assert!(Annealed::enabled());
+
+    std::env::set_var("FLOAT_EQ_ANNEAL", "0");
+    assert!(!Annealed::enabled());
+    std::env::set_var("FLOAT_EQ_ANNEAL", "yes");
+    assert!(!Annealed::enabled());
+    std::env::remove_var("FLOAT_EQ_ANNEAL");
+    assert!(!Annealed::enabled());
+}
+
+#[test]
+fn retry() {
+    let check = |t: &f32| float_eq!(1.0f32, 1.5, abs <= *t);
+    let double = |t: &f32| t * 2.0;
+    assert_eq!(Annealed::retry(&0.3, double, check), Annealed::PassesAt(2));
+    assert_eq!(Annealed::retry(&0.15, double, check), Annealed::PassesAt(4));
+    assert_eq!(
+        Annealed::retry(&0.0625, double, check),
+        Annealed::PassesAt(8)
+    );
+    assert_eq!(Annealed::retry(&0.06, double, check), Annealed::FailsAt(8));
+    // NaN tolerances panic instead with validate-tol
+    #[cfg(not(feature = "validate-tol"))]
+    assert_eq!(
+        Annealed::retry(&f32::NAN, double, check),
+        Annealed::FailsAt(8)
+    );
+
+    let check = |t: &u32| float_eq!(1.0f32, 1.000_000_6, ulps <= *t);
+    assert_eq!(Annealed::retry(&1, |t| t * 2, check), Annealed::PassesAt(8));
+}
+
+#[test]
+fn pass() {
+    let _guard = set_anneal(Some("1"));
+    assert_float_eq_annealed!(1.0f32, 1.000_000_1, ulps <= 1);
+    assert_float_eq_annealed!(1.0f32, 1.000_000_1, ulps <= 1,);
+    assert_float_eq_annealed!(1.0f32, 1.000_000_1, ulps <= 1, "testing {}", 1);
+    assert_float_eq_annealed!([1.0f64, 2.0], [1.001, 2.0], rmax_all <= 0.01);
+    assert_float_eq_annealed!((1.0f64, 2.0f32), (1.001, 2.0), abs <= (0.01, 0.0));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.3`,
+    abs_diff: `0.30000000000000004`,
+   ulps_diff: `Some(1351079888211149)`,
+     [abs] t: `0.1`"#)]
+fn fail_disabled() {
+    let _guard = set_anneal(None);
+    assert_float_eq_annealed!(1.0f64, 1.3, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.3`,
+    abs_diff: `0.30000000000000004`,
+   ulps_diff: `Some(1351079888211149)`,
+     [abs] t: `0.1`,
+    annealed: `Some(PassesAt(4))`"#)]
+fn fail_passes_at() {
+    let _guard = set_anneal(Some("1"));
+    assert_float_eq_annealed!(1.0f64, 1.3, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"[ulps_all] t: `[1, 1]`,
+    annealed: `Some(FailsAt(8))`: testing 2"#)]
+fn fail_fails_at_with_message() {
+    let _guard = set_anneal(Some("1"));
+    assert_float_eq_annealed!([1.0f32, 2.0], [1.0, 2.1], ulps_all <= 1, "testing {}", 2);
+}
+
+#[test]
+#[should_panic(expected = r#"[abs_ulps] t: `(0.0, 2)`,
+    annealed: `Some(PassesAt(2))`"#)]
+fn fail_abs_ulps() {
+    let _guard = set_anneal(Some("1"));
+    assert_float_eq_annealed!(1.0f32, 1.000_000_4, abs_ulps <= (0.0, 2));
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+// The tier is read from the environment, so these tests are kept in their own
+// test binary where it may be set without affecting other tests. They hold a
+// lock whilst doing so, since they may otherwise race each other.
+
+use float_eq::{float_eq_tiered, ToleranceTier};
+use std::sync::{Mutex, MutexGuard};
+
+static TIER: Mutex<()> = Mutex::new(());
+
+fn set_tier(value: &str) -> MutexGuard<'static, ()> {
+    let guard = TIER.lock().unwrap_or_else(|e| e.into_inner());
+    std::env::set_var("FLOAT_EQ_TIER", value);
+    guard
+}
+
+#[test]
+fn current() {
+    let _guard = set_tier("strict");
+    assert_eq!(ToleranceTier::current(), ToleranceTier::Strict);
+
+    std::env::set_var("FLOAT_EQ_TIER", "lenient");
+    assert_eq!(ToleranceTier::current(), ToleranceTier::Lenient);
+
+    let default = if cfg!(feature = "lenient") {
+        ToleranceTier::Lenient
+    } else {
+        ToleranceTier::Strict
+    };
+    std::env::set_var("FLOAT_EQ_TIER", "LENIENT");
+    assert_eq!(ToleranceTier::current(), default);
+    std::env::remove_var("FLOAT_EQ_TIER");
+    assert_eq!(ToleranceTier::current(), default);
+}
+
+#[test]
+fn float_eq_tiered() {
+    let _guard = set_tier("strict");
+    assert!(float_eq_tiered!(1.0f32, 1.000_000_1, strict: ulps <= 1, lenient: abs <= 0.0));
+    assert!(!float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01));
+    assert!(!float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01,));
+
+    std::env::set_var("FLOAT_EQ_TIER", "lenient");
+    assert!(!float_eq_tiered!(1.0f32, 1.000_000_1, strict: ulps <= 1, lenient: abs <= 0.0));
+    assert!(float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01));
+    assert!(
+        float_eq_tiered!([1.0f64, 2.0], [1.001, 2.0], strict: ulps_all <= 1, lenient: rmax_all <= 0.01)
+    );
+}
+
+#[cfg(not(feature = "checks-only"))]
+mod assert_tiered {
+    use super::set_tier;
+    use float_eq::assert_float_eq_tiered;
+
+    #[test]
+    fn pass() {
+        let _guard = set_tier("strict");
+        assert_float_eq_tiered!(1.0f32, 1.000_000_1, strict: ulps <= 1, lenient: abs <= 0.0);
+
+        std::env::set_var("FLOAT_EQ_TIER", "lenient");
+        assert_float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01);
+        assert_float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01,);
+        assert_float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01, "msg");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `float_eq!(left, right, ulps <= t)`
+        left: `1.0`,
+       right: `1.001`,
+    abs_diff: `0.0010000467`,
+   ulps_diff: `Some(8389)`,
+    [ulps] t: `1`,
+        tier: `Strict`"#)]
+    fn strict_fail() {
+        let _guard = set_tier("strict");
+        assert_float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.001`,
+    abs_diff: `0.0010000467`,
+   ulps_diff: `Some(8389)`,
+     [abs] t: `0.0001`,
+        tier: `Lenient`: testing"#)]
+    fn lenient_fail_with_message() {
+        let _guard = set_tier("lenient");
+        assert_float_eq_tiered!(1.0f32, 1.001, strict: ulps <= 1, lenient: abs <= 0.000_1, "testing");
+    }
+}
@@ -1,4 +1,10 @@
-#![allow(clippy::float_cmp, clippy::unit_cmp)]
+#![cfg(not(feature = "checks-only"))]
+#![allow(
+    clippy::float_cmp,
+    clippy::unit_cmp,
+    clippy::empty_line_after_doc_comments,
+    clippy::init_numbered_fields
+)]
 
 // A selection of NaN values from the edges of the ranges of negative and
 // positive NaN values and their payloads. Testing every single NaN value
@@ -181,17 +187,97 @@ macro_rules! wrapper_tests {
 }
 
 mod unit_tests {
+    mod aggregate;
     mod arrays;
+    mod bits;
+    mod bounds;
+    mod by;
+    mod category;
+    mod check_set;
+    mod checks;
+    mod clamped;
+    mod conformance;
+    mod const_tol;
+    mod convergence;
     mod core_types;
+    mod eq_ops;
+    mod fixed;
+    mod float_cmp;
+    mod gradcheck;
+    mod histogram;
     mod macros;
+    mod matrix;
+    mod measured;
+    mod neighbors;
+    mod prepared;
     mod primitives;
+    mod probability;
+    mod reduction;
+    mod search;
+    mod snap;
+    mod sorted;
+    mod test_vectors;
+    mod tol_ops;
     mod tuples;
+    mod ulps_ops;
+    mod weighted;
+    mod widen_tol;
+    mod zip;
 
+    #[cfg(feature = "alloc")]
+    mod alloc_types;
+    #[cfg(feature = "alloc")]
+    mod decimal;
+
+    #[cfg(feature = "std")]
+    mod canonical;
+    #[cfg(feature = "std")]
+    mod cluster;
+    #[cfg(feature = "std")]
+    mod csv;
+    #[cfg(feature = "std")]
+    mod eigen;
     #[cfg(feature = "std")]
-    mod std_types;
+    mod fma;
+    #[cfg(feature = "std")]
+    mod fuzz;
+    #[cfg(feature = "std")]
+    mod mismatches;
+    #[cfg(feature = "std")]
+    mod policy;
 
     #[cfg(feature = "num")]
     mod num_complex;
+
+    #[cfg(feature = "bigdecimal")]
+    mod bigdecimal;
+
+    #[cfg(any(feature = "time", feature = "chrono"))]
+    mod durations;
+
+    #[cfg(feature = "nalgebra")]
+    mod nalgebra;
+
+    #[cfg(feature = "glam")]
+    mod glam;
+
+    #[cfg(feature = "half")]
+    mod half;
+
+    #[cfg(feature = "approx")]
+    mod approx;
+
+    #[cfg(feature = "geo")]
+    mod geo;
+
+    #[cfg(feature = "harness")]
+    mod harness;
+
+    #[cfg(all(feature = "arbitrary", feature = "std"))]
+    mod arbitrary;
+
+    #[cfg(all(feature = "validate-tol", debug_assertions))]
+    mod validate_tol;
 }
 
 struct Foo(f32, f64);
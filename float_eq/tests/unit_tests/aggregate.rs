@@ -0,0 +1,120 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, float_ne, max_abs_diff, max_ulps_diff,
+    mean_abs_diff,
+};
+
+#[test]
+fn max_abs_diff_f32() {
+    assert_eq!(max_abs_diff::<f32>(&[], &[]), Some(0.0));
+    assert_eq!(max_abs_diff(&[1.0f32, 2.0], &[1.0, 2.0]), Some(0.0));
+    assert_eq!(max_abs_diff(&[1.0f32, 2.0], &[1.5, 1.0]), Some(1.0));
+    assert_eq!(max_abs_diff(&[-1.0f32, 2.0], &[1.0, 2.0]), Some(2.0));
+    assert_eq!(max_abs_diff(&[1.0f32, 2.0], &[1.0]), None);
+
+    let inf = f32::INFINITY;
+    assert_eq!(max_abs_diff(&[inf, 1.0], &[inf, 1.5]), Some(0.5));
+    assert_eq!(max_abs_diff(&[inf, 1.0], &[-inf, 1.5]), Some(inf));
+    let nan = f32::NAN;
+    assert!(max_abs_diff(&[nan, 1.0], &[nan, 2.0]).unwrap().is_nan());
+    assert!(max_abs_diff(&[1.0, 100.0], &[nan, 1.0]).unwrap().is_nan());
+}
+
+#[test]
+fn mean_abs_diff_f64() {
+    assert_eq!(mean_abs_diff::<f64>(&[], &[]), Some(0.0));
+    assert_eq!(mean_abs_diff(&[1.0f64, 2.0], &[1.0, 2.0]), Some(0.0));
+    assert_eq!(
+        mean_abs_diff(&[1.0f64, 2.0, 3.0, 4.0], &[1.5, 1.0, 3.0, 4.5]),
+        Some(0.5)
+    );
+    assert_eq!(mean_abs_diff(&[1.0f64], &[1.0, 2.0]), None);
+
+    let inf = f64::INFINITY;
+    assert_eq!(mean_abs_diff(&[inf, 1.0], &[inf, 2.0]), Some(0.5));
+    assert!(mean_abs_diff(&[f64::NAN], &[1.0]).unwrap().is_nan());
+}
+
+#[test]
+fn max_ulps_diff_f64() {
+    let next = |f: f64, n: u64| f64::from_bits(f.to_bits() + n);
+    assert_eq!(max_ulps_diff::<f64>(&[], &[]), Some(0));
+    assert_eq!(max_ulps_diff(&[1.0f64, 2.0], &[1.0, 2.0]), Some(0));
+    assert_eq!(
+        max_ulps_diff(&[1.0f64, 2.0], &[next(1.0, 2), next(2.0, 5)]),
+        Some(5)
+    );
+    assert_eq!(max_ulps_diff(&[0.0f64], &[-0.0]), Some(0));
+    assert_eq!(max_ulps_diff(&[1.0f64], &[-1.0]), None);
+    assert_eq!(max_ulps_diff(&[1.0f64, f64::NAN], &[1.0, f64::NAN]), None);
+    assert_eq!(max_ulps_diff(&[1.0f64], &[1.0, 2.0]), None);
+}
+
+#[test]
+fn float_eq_clauses() {
+    let a = [1.0f32, 2.0, 3.0, 4.0];
+    let b = [1.0f32, 2.5, 3.0, 4.1];
+
+    assert!(float_eq!(a, b, max_abs <= 0.5));
+    assert!(!float_eq!(a, b, max_abs <= 0.25));
+    assert!(float_eq!(a, b, mean_abs <= 0.15));
+    assert!(!float_eq!(a, b, mean_abs <= 0.1));
+    assert!(float_ne!(a, b, mean_abs <= 0.1));
+    assert!(float_eq!(a, b, mean_abs <= 0.1, max_abs <= 0.5));
+
+    // slices and vectors, of differing types
+    assert!(float_eq!(a[..], b.to_vec(), max_abs <= 0.5));
+    assert!(float_eq!(&a[..2], [1.0, 2.25], max_abs <= 0.25));
+    assert!(!float_eq!(a[..3], b.to_vec(), max_abs <= 1.0));
+    assert!(float_ne!(a.to_vec(), b[..3], mean_abs <= 1.0));
+
+    let c = [1.0f64, 2.0];
+    let d = [f64::from_bits(1.0f64.to_bits() + 3), 2.0];
+    assert!(float_eq!(c, d, max_ulps <= 3));
+    assert!(!float_eq!(c, d, max_ulps <= 2));
+    assert!(float_ne!(c, [1.0, -2.0], max_ulps <= u64::MAX));
+}
+
+#[test]
+fn assert_float_eq_clauses() {
+    let a = [1.0f64, 2.0];
+
+    assert_float_eq!(a, [1.25, 2.0], max_abs <= 0.25);
+    assert_float_eq!(a, [1.0, 2.0], max_ulps <= 0, "testing {}", 1);
+    assert_float_ne!(a, [1.25, 2.0], max_abs <= 0.125);
+
+    #[cfg(feature = "alloc")]
+    {
+        let b = vec![1.25f64, 2.0];
+        assert_float_eq!(a[..], b, mean_abs <= 0.125,);
+        assert_float_ne!(a[..], b, max_ulps <= 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, max_abs <= t)`
+        left: `[1.0, 2.0]`,
+       right: `[1.25, 2.5]`,
+    abs_diff: `[0.25, 0.5]`,
+   ulps_diff: `[Some(1125899906842624), Some(1125899906842624)]`,
+ [max_abs] t: `0.25`"#)]
+fn assert_float_eq_max_abs_fail() {
+    assert_float_eq!([1.0f64, 2.0], [1.25, 2.5], max_abs <= 0.25);
+}
+
+#[test]
+#[should_panic(expected = r#"[mean_abs] t: `0.25`: testing 2"#)]
+fn assert_float_eq_mean_abs_fail_with_message() {
+    assert_float_eq!(
+        [1.0f32, 2.0],
+        [1.25, 2.5],
+        mean_abs <= 0.25,
+        "testing {}",
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"[max_ulps] t: `1`"#)]
+fn assert_float_eq_max_ulps_fail() {
+    assert_float_eq!([1.0f32], [-1.0], max_ulps <= 1);
+}
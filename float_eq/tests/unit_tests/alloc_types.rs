@@ -1,8 +1,10 @@
 #![allow(clippy::many_single_char_names)]
 
-use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, FloatEqBits};
 use std::boxed::Box;
-use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use std::collections::{BTreeMap, LinkedList, VecDeque};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -147,6 +149,15 @@ macro_rules! impl_linear_collection_tests {
         mod $c {
             use super::*;
 
+            #[test]
+            fn float_eq_bits() {
+                let a = $c![1.0f32, -0.0];
+                assert!(a.eq_bits(&$c![1.0f32, -0.0]));
+                assert!(a.ne_bits(&$c![1.0f32, 0.0]));
+                assert!(a.ne_bits(&$c![1.0f32]));
+                assert_float_eq!($c![f32::NAN], $c![f32::NAN], bits);
+            }
+
             #[test]
             fn float_eq() {
                 let a = $c![0.999_999_9f32, 4.0];
@@ -419,6 +430,16 @@ macro_rules! impl_map_tests {
                 $c! { "one" => one, "three" => three }
             }
 
+            #[test]
+            fn float_eq_bits() {
+                let a = map12(1.0f32, -0.0);
+                assert!(a.eq_bits(&map12(1.0f32, -0.0)));
+                assert!(a.ne_bits(&map12(1.0f32, 0.0)));
+                assert!(a.ne_bits(&map13(1.0f32, -0.0)));
+                assert!(a.ne_bits(&map1(1.0f32)));
+                assert_float_eq!(map1(f32::NAN), map1(f32::NAN), bits);
+            }
+
             fn map123<T>(one: T, two: T, three: T) -> $t<&'static str, T> {
                 $c! { "one" => one, "two" => two, "three" => three }
             }
@@ -803,6 +824,7 @@ macro_rules! btree_map {
     }};
 }
 
+#[cfg(feature = "std")]
 macro_rules! hash_map {
     () => {{
         HashMap::new()
@@ -815,4 +837,274 @@ macro_rules! hash_map {
 }
 
 impl_map_tests!(BTreeMap, btree_map);
+#[cfg(feature = "std")]
 impl_map_tests!(HashMap, hash_map);
+
+mod map_values {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn owned_keys() {
+        let a: HashMap<String, f64> = hash_map! {
+            "drag".to_string() => 0.31,
+            "lift".to_string() => 1.2
+        };
+        let b = hash_map! {
+            "drag".to_string() => 0.310_000_1,
+            "lift".to_string() => 1.2
+        };
+        assert_float_eq!(a, b, abs_all <= 0.000_001);
+        assert_float_ne!(a, b, ulps_all <= 1);
+
+        let c = hash_map! {
+            "drag".to_string() => 0.31,
+            "thrust".to_string() => 1.2
+        };
+        assert_float_ne!(a, c, abs_all <= f64::INFINITY);
+    }
+
+    #[test]
+    fn array_values() {
+        let a: BTreeMap<u32, [f32; 3]> = btree_map! { 1 => [0.0, 1.0, 2.0], 2 => [3.0, 4.0, 5.0] };
+        let b = btree_map! { 1 => [0.0, 1.0, 2.0], 2 => [3.0, 4.000_001, 5.0] };
+        assert_float_eq!(a, b, ulps_all <= 2);
+        assert_float_ne!(a, b, ulps_all <= 1);
+        assert_float_ne!(
+            a,
+            btree_map! { 1 => [0.0, 1.0, 2.0] },
+            abs_all <= f32::INFINITY
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `{1: [0.0, 1.0, 2.0], 2: [3.0, 4.0, 5.0]}`,
+       right: `{1: [0.0, 1.0, 2.0], 2: [3.0, 4.5, 5.0]}`,
+    abs_diff: `Some({1: [0.0, 0.0, 0.0], 2: [0.0, 0.5, 0.0]})`,
+   ulps_diff: `Some({1: [Some(0), Some(0), Some(0)], 2: [Some(0), Some(1048576), Some(0)]})`,
+ [abs_all] t: `Some({1: [0.1, 0.1, 0.1], 2: [0.1, 0.1, 0.1]})`"#)]
+    fn assert_fail_message() {
+        let a: BTreeMap<u32, [f32; 3]> = btree_map! { 1 => [0.0, 1.0, 2.0], 2 => [3.0, 4.0, 5.0] };
+        let b = btree_map! { 1 => [0.0, 1.0, 2.0], 2 => [3.0, 4.5, 5.0] };
+        assert_float_eq!(a, b, abs_all <= 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `{1: [0.0, 1.0, 2.0]}`,
+       right: `{2: [0.0, 1.0, 2.0]}`,
+    abs_diff: `None`,
+   ulps_diff: `None`,
+ [abs_all] t: `None`"#)]
+    fn assert_fail_message_different_keys() {
+        let a: BTreeMap<u32, [f32; 3]> = btree_map! { 1 => [0.0, 1.0, 2.0] };
+        let b = btree_map! { 2 => [0.0, 1.0, 2.0] };
+        assert_float_eq!(a, b, abs_all <= 0.1);
+    }
+}
+
+mod vec_option {
+    use super::*;
+
+    #[test]
+    fn float_eq() {
+        let a: Vec<Option<[f32; 3]>> = vec![Some([0.0, 1.0, 2.0]), None];
+        let b = vec![Some([0.0, 1.000_000_1, 2.0]), None];
+        assert_float_eq!(a, b, ulps_all <= Some(1));
+        assert_float_ne!(a, b, ulps_all <= Some(0));
+        assert_float_ne!(
+            a,
+            vec![Some([0.0, 1.0, 2.0]), Some([0.0; 3])],
+            abs_all <= Some(f32::INFINITY)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `[Some([0.0, 1.0, 2.0]), None]`,
+       right: `[Some([0.0, 1.5, 2.0]), None]`,
+    abs_diff: `Some([Some([0.0, 0.5, 0.0]), None])`,"#)]
+    fn assert_fail_message() {
+        let a: Vec<Option<[f32; 3]>> = vec![Some([0.0, 1.0, 2.0]), None];
+        let b = vec![Some([0.0, 1.5, 2.0]), None];
+        assert_float_eq!(a, b, abs_all <= Some(0.1));
+    }
+}
+
+mod chunks_exact {
+    use super::*;
+    use float_eq::ChunksDebug;
+
+    #[test]
+    fn debug_diff() {
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let b = [1.0f32, 2.5, 3.0, 4.25];
+
+        assert_eq!(
+            a.chunks_exact(2).debug_abs_diff(&b.chunks_exact(2)),
+            ChunksDebug::Blocks(vec![vec![0.0, 0.5], vec![0.0, 0.25]])
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_ulps_diff(&b.chunks_exact(2)),
+            ChunksDebug::Blocks(vec![
+                vec![Some(0), Some(2_097_152)],
+                vec![Some(0), Some(524_288)]
+            ])
+        );
+
+        // Different shape
+        assert_eq!(
+            a.chunks_exact(2).debug_abs_diff(&b.chunks_exact(1)),
+            ChunksDebug::CountMismatch { left: 2, right: 4 }
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_ulps_diff(&b.chunks_exact(1)),
+            ChunksDebug::CountMismatch { left: 2, right: 4 }
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_abs_diff(&b[..2].chunks_exact(1)),
+            ChunksDebug::LenMismatch {
+                block: 0,
+                left: 2,
+                right: 1
+            }
+        );
+    }
+
+    #[test]
+    fn debug_tol() {
+        let a = [2.0f32, 4.25];
+        let b = [2.5f32, 4.0];
+        let eps = [0.1, 0.2];
+
+        assert_eq!(
+            a.chunks_exact(1)
+                .debug_abs_tol(&b.chunks_exact(1), &eps[..1]),
+            ChunksDebug::Blocks(vec![vec![0.1], vec![0.1]])
+        );
+        assert_eq!(
+            a.chunks_exact(1)
+                .debug_rmax_tol(&b.chunks_exact(1), &eps[..1]),
+            ChunksDebug::Blocks(vec![vec![0.25], vec![0.425]])
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_rmin_tol(&b.chunks_exact(2), &eps),
+            ChunksDebug::Blocks(vec![vec![0.2, 0.8]])
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_r1st_tol(&b.chunks_exact(2), &eps),
+            ChunksDebug::Blocks(vec![vec![0.2, 0.85]])
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_r2nd_tol(&b.chunks_exact(2), &eps),
+            ChunksDebug::Blocks(vec![vec![0.25, 0.8]])
+        );
+        assert_eq!(
+            a.chunks_exact(2)
+                .debug_ulps_tol(&b.chunks_exact(2), &[1, 2]),
+            ChunksDebug::Blocks(vec![vec![1, 2]])
+        );
+
+        // Different shape tol
+        assert_eq!(
+            a.chunks_exact(2)
+                .debug_abs_tol(&b.chunks_exact(2), &eps[..1]),
+            ChunksDebug::TolLenMismatch {
+                block: 0,
+                len: 2,
+                tol: 1
+            }
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_ulps_tol(&b.chunks_exact(2), &[1]),
+            ChunksDebug::TolLenMismatch {
+                block: 0,
+                len: 2,
+                tol: 1
+            }
+        );
+    }
+
+    #[test]
+    fn debug_all_tol() {
+        let a = [2.0f32, 4.25];
+        let b = [2.5f32, 4.0];
+
+        assert_eq!(
+            a.chunks_exact(1)
+                .debug_abs_all_tol(&b.chunks_exact(1), &0.2),
+            ChunksDebug::Blocks(vec![vec![0.2], vec![0.2]])
+        );
+        assert_eq!(
+            a.chunks_exact(2)
+                .debug_rmax_all_tol(&b.chunks_exact(2), &0.2),
+            ChunksDebug::Blocks(vec![vec![0.5, 0.85]])
+        );
+        assert_eq!(
+            a.chunks_exact(2).debug_ulps_all_tol(&b.chunks_exact(2), &2),
+            ChunksDebug::Blocks(vec![vec![2, 2]])
+        );
+        assert_eq!(
+            a.chunks_exact(2)
+                .debug_abs_all_tol(&b.chunks_exact(1), &0.2),
+            ChunksDebug::CountMismatch { left: 1, right: 2 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `ChunksExact { v: [1.0, 2.0, 3.0, 4.0], rem: [], chunk_size: 2 }`,
+       right: `ChunksExact { v: [1.0, 2.0, 3.0, 4.5], rem: [], chunk_size: 2 }`,
+    abs_diff: `Blocks([[0.0, 0.0], [0.0, 0.5]])`,
+   ulps_diff: `Blocks([[Some(0), Some(0)], [Some(0), Some(1048576)]])`,
+ [abs_all] t: `Blocks([[0.1, 0.1], [0.1, 0.1]])`"#)]
+    fn assert_fail_message() {
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let b = [1.0f32, 2.0, 3.0, 4.5];
+        assert_float_eq!(a.chunks_exact(2), b.chunks_exact(2), abs_all <= 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"abs_diff: `LenMismatch { block: 0, left: 2, right: 1 }`,"#)]
+    fn assert_fail_message_len_mismatch() {
+        let a = [1.0f32, 2.0];
+        let b = [1.0f32];
+        assert_float_eq!(a.chunks_exact(2), b.chunks_exact(1), abs_all <= 0.1);
+    }
+}
+
+mod windows {
+    use super::*;
+    use float_eq::ChunksDebug;
+
+    #[test]
+    fn debug_diff() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [1.0f32, 2.5, 3.0];
+
+        assert_eq!(
+            a.windows(2).debug_abs_diff(&b.windows(2)),
+            ChunksDebug::Blocks(vec![vec![0.0, 0.5], vec![0.5, 0.0]])
+        );
+        assert_eq!(
+            a.windows(2).debug_abs_diff(&b.windows(3)),
+            ChunksDebug::CountMismatch { left: 2, right: 1 }
+        );
+    }
+
+    #[test]
+    fn debug_tol() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [1.0f32, 2.5, 3.0];
+
+        assert_eq!(
+            a.windows(2).debug_abs_tol(&b.windows(2), &[0.1, 0.2]),
+            ChunksDebug::Blocks(vec![vec![0.1, 0.2], vec![0.1, 0.2]])
+        );
+        assert_eq!(
+            a.windows(2).debug_abs_all_tol(&b.windows(2), &0.1),
+            ChunksDebug::Blocks(vec![vec![0.1, 0.1], vec![0.1, 0.1]])
+        );
+    }
+}
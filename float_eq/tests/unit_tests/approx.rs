@@ -0,0 +1,213 @@
+#![allow(clippy::float_cmp)]
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, float_ne, CheckSet, FloatCmp, FloatEq, FromApprox,
+};
+
+// A type from a crate that only implements the approx traits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+impl AbsDiffEq for Point {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+impl RelativeEq for Point {
+    fn default_max_relative() -> f32 {
+        f32::EPSILON
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Point {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps) && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+    }
+}
+
+#[test]
+fn from() {
+    let p = Point { x: 1.0, y: 2.0 };
+    assert_eq!(FromApprox::from(p), FromApprox(p));
+    assert_eq!(format!("{:?}", FromApprox(p)), format!("{:?}", p));
+}
+
+#[test]
+fn point() {
+    let a = FromApprox(Point { x: 1.0, y: 2.0 });
+    let b = FromApprox(Point {
+        x: 1.000_000_2,
+        y: 2.0,
+    });
+
+    assert_float_eq!(a, b, abs <= 0.000_000_3);
+    assert_float_ne!(a, b, abs <= 0.000_000_1);
+    assert_float_eq!(a, b, rel <= 2.0 * f32::EPSILON);
+    assert_float_ne!(a, b, rel <= 0.5 * f32::EPSILON);
+    assert_float_eq!(a, b, rmax <= 2.0 * f32::EPSILON);
+    assert_float_ne!(a, b, rmax <= 0.5 * f32::EPSILON);
+    assert_float_eq!(a, b, ulps <= 2);
+    assert_float_ne!(a, b, ulps <= 1);
+
+    assert_float_eq!(a, b, abs_all <= 0.000_000_3);
+    assert_float_eq!(a, b, rel_all <= 2.0 * f32::EPSILON);
+    assert_float_eq!(a, b, rmax_all <= 2.0 * f32::EPSILON);
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+
+    assert_float_eq!(a, b, abs <= 0.0, ulps <= 2);
+    assert!(float_eq!(a, a, abs <= 0.0));
+    assert!(float_eq!(a, a, rmax <= 0.0));
+    assert!(float_eq!(a, a, ulps <= 0));
+}
+
+#[test]
+fn matches_approx() {
+    let values = [
+        0.0,
+        -0.0,
+        1e-300,
+        1.0,
+        1.000_000_000_000_000_2,
+        1.1,
+        -1.0,
+        1e300,
+        f64::INFINITY,
+        f64::NAN,
+    ];
+    let tols = [0.0, 1e-16, 1e-10, 0.1, 1.0];
+    let ulps = [0, 1, 4, 1 << 32];
+
+    for a in values.iter() {
+        for b in values.iter() {
+            let (fa, fb) = (FromApprox(*a), FromApprox(*b));
+            for tol in tols.iter() {
+                assert_eq!(float_eq!(fa, fb, abs <= *tol), a.abs_diff_eq(b, *tol));
+                assert_eq!(float_eq!(fa, fb, rmax <= *tol), a.relative_eq(b, 0.0, *tol));
+                assert_eq!(float_eq!(fa, fb, rel <= *tol), a.relative_eq(b, 0.0, *tol));
+                assert_eq!(float_ne!(fa, fb, abs <= *tol), !a.abs_diff_eq(b, *tol));
+            }
+            for tol in ulps.iter() {
+                let max_ulps = (*tol).min(u64::from(u32::MAX)) as u32;
+                assert_eq!(float_eq!(fa, fb, ulps <= *tol), a.ulps_eq(b, 0.0, max_ulps));
+            }
+        }
+    }
+}
+
+#[test]
+fn slices() {
+    let a = [1.0f64, 2.0, 3.0];
+    let b = [1.0f64, 2.000_000_000_000_000_4, 3.0];
+    assert_float_eq!(FromApprox(&a[..]), FromApprox(&b[..]), ulps <= 1);
+    // the tightest not equal check warns, but is wanted here
+    #[allow(deprecated)]
+    {
+        assert_float_ne!(FromApprox(&a[..]), FromApprox(&b[..]), ulps <= 0);
+    }
+    assert_float_ne!(FromApprox(&a[..]), FromApprox(&a[..2]), abs <= 1.0);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra() {
+    let a = nalgebra::Vector3::new(1.0f64, 2.0, 3.0);
+    let b = nalgebra::Vector3::new(1.0f64, 2.000_000_000_000_000_4, 3.0);
+    assert_float_eq!(FromApprox(a), FromApprox(b), rmax <= f64::EPSILON);
+    assert_float_ne!(FromApprox(a), FromApprox(b), rmax <= 0.1 * f64::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `Point { x: 1.0, y: 2.0 }`,
+       right: `Point { x: 1.5, y: 2.0 }`,
+    abs_diff: `()`,
+   ulps_diff: `()`,
+     [abs] t: `0.1`"#)]
+fn assert_fail_message() {
+    let a = FromApprox(Point { x: 1.0, y: 2.0 });
+    let b = FromApprox(Point { x: 1.5, y: 2.0 });
+    assert_float_eq!(a, b, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = "FromApprox does not support the `rmin` check")]
+fn rmin_unsupported() {
+    let a = FromApprox(1.0f64);
+    let _ = float_eq!(a, a, rmin <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = "FromApprox does not support the `r1st` check")]
+fn r1st_unsupported() {
+    let a = FromApprox(1.0f64);
+    let _ = float_eq!(a, a, r1st_all <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = "FromApprox does not support the `r2nd` check")]
+fn r2nd_unsupported() {
+    let a = FromApprox(1.0f64);
+    assert_float_eq!(a, a, r2nd <= 0.1);
+}
+
+#[test]
+fn supported_checks() {
+    let supported = CheckSet::ABS.union(CheckSet::RMAX).union(CheckSet::ULPS);
+    assert_eq!(<FromApprox<f64> as FloatEq>::SUPPORTED_CHECKS, supported);
+    assert_eq!(
+        <[FromApprox<f64>; 2] as FloatEq>::SUPPORTED_CHECKS,
+        supported
+    );
+    assert_eq!(
+        <Option<FromApprox<f64>> as FloatEq>::SUPPORTED_CHECKS,
+        supported
+    );
+    assert_eq!(
+        <(f64, FromApprox<f64>) as FloatEq>::SUPPORTED_CHECKS,
+        supported
+    );
+    assert_eq!(
+        <Vec<FromApprox<f64>> as FloatEq>::SUPPORTED_CHECKS,
+        supported
+    );
+}
+
+#[test]
+fn float_cmp_skips_unsupported() {
+    let a = FromApprox(1.0f64);
+    let b = FromApprox(1.1f64);
+
+    let cmp = FloatCmp::new().rmin(0.5).r1st(0.5).r2nd(0.5);
+    assert_eq!(
+        cmp.unsupported_checks::<FromApprox<f64>, _>(),
+        CheckSet::RMIN.union(CheckSet::R1ST).union(CheckSet::R2ND)
+    );
+    assert!(cmp.ne(&a, &b));
+    assert_eq!(cmp.debug_tol(&a, &b), FloatCmp::new());
+
+    let cmp = cmp.abs(0.2);
+    assert!(cmp.eq(&a, &b));
+    assert_float_eq!(a, b, tol(&cmp));
+    assert_eq!(cmp.debug_tol(&a, &b), FloatCmp::new().abs(0.2));
+}
@@ -0,0 +1,78 @@
+use arbitrary::{Arbitrary, Unstructured};
+use float_eq::{
+    DebugCategories, FailureFormat, FailurePayload, FailureReport, FloatCategory, FloatCmp,
+    TestVector, ToleranceTier, UlpsDiffReason,
+};
+
+// Every value of a type with only a few of them is generated from some input.
+fn assert_covers<'a, T>(inputs: &'a [[u8; 8]], expected: &[T])
+where
+    T: Arbitrary<'a> + std::fmt::Debug + PartialEq,
+{
+    let generated: Vec<T> = inputs
+        .iter()
+        .map(|input| T::arbitrary(&mut Unstructured::new(input)).unwrap())
+        .collect();
+    for value in expected {
+        assert!(generated.contains(value), "{:?} not generated", value);
+    }
+}
+
+fn inputs() -> Vec<[u8; 8]> {
+    (0..=255u8).map(|i| [i; 8]).collect()
+}
+
+#[test]
+fn enums() {
+    let inputs = inputs();
+    assert_covers(
+        &inputs,
+        &[
+            FloatCategory::Nan,
+            FloatCategory::Infinite,
+            FloatCategory::Subnormal,
+        ],
+    );
+    assert_covers(&inputs, &[ToleranceTier::Strict, ToleranceTier::Lenient]);
+    assert_covers(
+        &inputs,
+        &[
+            UlpsDiffReason::Nan,
+            UlpsDiffReason::DifferentSigns,
+            UlpsDiffReason::Saturated,
+        ],
+    );
+    assert_covers(&inputs, &[FailureFormat::Human, FailureFormat::KeyValue]);
+    assert_covers(&inputs, &[FailurePayload::Message, FailurePayload::Report]);
+}
+
+#[test]
+fn structs() {
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&data);
+
+    let _: DebugCategories = u.arbitrary().unwrap();
+    let _: TestVector<f32, u32> = u.arbitrary().unwrap();
+    let _: FailureReport = u.arbitrary().unwrap();
+
+    let mut has_tol = false;
+    for _ in 0..64 {
+        let cmp: FloatCmp<f64> = u.arbitrary().unwrap();
+        has_tol |= cmp.abs_tol().is_some() && cmp.ulps_tol().is_some();
+    }
+    assert!(has_tol);
+}
+
+#[test]
+fn empty_input() {
+    let mut u = Unstructured::new(&[]);
+    let cmp: FloatCmp<f32> = u.arbitrary().unwrap();
+    assert_eq!(cmp, FloatCmp::new());
+    assert_eq!(
+        DebugCategories::arbitrary(&mut Unstructured::new(&[])).unwrap(),
+        DebugCategories {
+            left: None,
+            right: None
+        }
+    );
+}
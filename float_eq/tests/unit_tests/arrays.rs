@@ -1,6 +1,9 @@
 #![allow(clippy::float_cmp, clippy::reversed_empty_ranges)]
 
-use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+use core::mem::size_of;
+use float_eq::{
+    assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, UlpsTol,
+};
 
 macro_rules! impl_tests {
     ($float:ident) => {
@@ -355,6 +358,39 @@ macro_rules! impl_tests {
 impl_tests!(f32);
 impl_tests!(f64);
 
+#[test]
+fn debug_types_are_inline() {
+    macro_rules! check {
+        ($t:ty, $n:literal) => {
+            assert_eq!(
+                size_of::<UlpsTol<[$t; $n]>>(),
+                $n * size_of::<UlpsTol<$t>>()
+            );
+            assert_eq!(
+                size_of::<DebugUlpsDiff<[$t; $n]>>(),
+                $n * size_of::<DebugUlpsDiff<$t>>()
+            );
+            assert_eq!(
+                size_of::<<[$t; $n] as AssertFloatEq>::DebugAbsDiff>(),
+                $n * size_of::<<$t as AssertFloatEq>::DebugAbsDiff>()
+            );
+            assert_eq!(
+                size_of::<<[$t; $n] as AssertFloatEq>::DebugTol>(),
+                $n * size_of::<<$t as AssertFloatEq>::DebugTol>()
+            );
+            assert_eq!(
+                size_of::<<[$t; $n] as AssertFloatEqAll>::AllDebugTol>(),
+                $n * size_of::<<$t as AssertFloatEqAll>::AllDebugTol>()
+            );
+        };
+    }
+    check!(f32, 0);
+    check!(f32, 1);
+    check!(f64, 7);
+    check!(f32, 1024);
+    check!([f64; 3], 3);
+}
+
 #[test]
 #[should_panic(expected = r#"`float_eq!(left, right, abs <= t, rel <= t, ulps <= t)`
         left: `[1.0, 2.0]`,
@@ -394,3 +430,73 @@ fn assert_fail_all_message() {
         ulps_all <= 3u64
     );
 }
+
+// Arrays and slices of primitives may be compared in chunks, which must give
+// the same results as comparing each element in turn.
+macro_rules! impl_element_tests {
+    ($float:ident, $uint:ident) => {
+        mod $float {
+            use float_eq::FloatEqAll;
+
+            const EDGES: [$float; 14] = [
+                0.0,
+                -0.0,
+                $float::from_bits(1),
+                -$float::from_bits(1),
+                1.0,
+                -1.0,
+                $float::from_bits(1.0f64.to_bits() as $uint),
+                $float::MAX,
+                $float::MIN,
+                $float::INFINITY,
+                $float::NEG_INFINITY,
+                $float::NAN,
+                -$float::NAN,
+                $float::from_bits($float::NAN.to_bits() + 1),
+            ];
+
+            // An array in which one element at `i` is `x` and the rest match.
+            fn with(i: usize, x: $float) -> [$float; 37] {
+                let mut a = [0.5; 37];
+                a[i] = x;
+                a
+            }
+
+            #[test]
+            fn all_elements_match_each_element() {
+                for &x in EDGES.iter() {
+                    for &y in EDGES.iter() {
+                        for &i in [0, 15, 16, 36].iter() {
+                            let (a, b) = (with(i, x), with(i, y));
+                            for &tol in [0.0, 1.0, $float::INFINITY].iter() {
+                                let expected = x.eq_abs_all(&y, &tol);
+                                assert_eq!(a.eq_abs_all(&b, &tol), expected);
+                                assert_eq!(a[..].eq_abs_all(&b[..], &tol), expected);
+                            }
+                            for &tol in [0, 1, 3, $uint::MAX].iter() {
+                                let expected = x.eq_ulps_all(&y, &tol);
+                                assert_eq!(a.eq_ulps_all(&b, &tol), expected);
+                                assert_eq!(a[..].eq_ulps_all(&b[..], &tol), expected);
+                            }
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn all_elements_ulps_near_zero() {
+                let a = [$float::from_bits(1); 20];
+                let b = [-$float::from_bits(1); 20];
+                assert!(!a.eq_ulps_all(&b, &$uint::MAX));
+                assert!([0.0 as $float; 20].eq_ulps_all(&[-0.0; 20], &0));
+                assert!(a.eq_ulps_all(&[0.0; 20], &1));
+                assert!(!a.eq_ulps_all(&[-0.0; 20], &1));
+            }
+        }
+    };
+}
+
+mod elements {
+    impl_element_tests!(f32, u32);
+    impl_element_tests!(f64, u64);
+}
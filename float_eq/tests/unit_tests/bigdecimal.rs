@@ -0,0 +1,79 @@
+use bigdecimal::BigDecimal;
+use float_eq::{
+    assert_float_eq, assert_float_ne, to_nearest_float, AssertFloatEq, AssertFloatEqAll,
+};
+use std::str::FromStr;
+
+fn big(s: &str) -> BigDecimal {
+    BigDecimal::from_str(s).unwrap()
+}
+
+#[test]
+fn nearest_float() {
+    assert_eq!(to_nearest_float::<f32>(&big("0.1")), 0.1f32);
+    assert_eq!(to_nearest_float::<f64>(&big("0.1")), 0.1f64);
+    assert_eq!(to_nearest_float::<f64>(&big("-1.5e-300")), -1.5e-300);
+    assert_eq!(to_nearest_float::<f64>(&big("1e400")), f64::INFINITY);
+    assert_eq!(to_nearest_float::<f32>(&big("1e-50")), 0.0);
+
+    // Exactly halfway between 1.0 and the next f64, which rounds to even
+    let halfway = "1.00000000000000011102230246251565404236316680908203125";
+    assert_eq!(to_nearest_float::<f64>(&big(halfway)), 1.0);
+    let above = format!("{}1", halfway);
+    assert_eq!(
+        to_nearest_float::<f64>(&big(&above)),
+        1.000_000_000_000_000_2
+    );
+}
+
+#[test]
+fn float_eq() {
+    let pi = big("3.14159265358979323846264338327950288419716939937510");
+    let x = core::f64::consts::PI;
+    let next = f64::from_bits(x.to_bits() + 1);
+
+    assert_float_eq!(pi, x, ulps <= 0);
+    assert_float_eq!(pi, next, ulps <= 1);
+    // the tightest not equal check warns, but is wanted here
+    #[allow(deprecated)]
+    {
+        assert_float_ne!(pi, next, ulps <= 0);
+    }
+    assert_float_eq!(pi, x, abs <= 0.0);
+    assert_float_eq!(pi, next, rmax <= f64::EPSILON);
+    assert_float_eq!(pi, next, r1st <= f64::EPSILON);
+    assert_float_ne!(pi, 3.0, rel <= 0.01);
+    assert_float_eq!(pi, x, ulps_all <= 0);
+    assert_float_eq!(pi, x, abs_all <= 0.0);
+    assert_float_eq!(
+        to_nearest_float::<f32>(&pi),
+        core::f32::consts::PI,
+        ulps <= 0
+    );
+}
+
+#[test]
+fn debug_diff() {
+    let one = big("1.0");
+    assert_eq!(one.debug_abs_diff(&2.0), 1.0);
+    assert_eq!(one.debug_ulps_diff(&1.0), Some(0));
+    assert_eq!(one.debug_ulps_diff(&1.5), Some(2_251_799_813_685_248));
+}
+
+#[test]
+fn debug_tol() {
+    let two = big("2");
+    assert_eq!(two.debug_abs_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_rmax_tol(&1.0, &0.5), 1.0);
+    assert_eq!(two.debug_rmin_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_r1st_tol(&1.0, &0.5), 1.0);
+    assert_eq!(two.debug_r2nd_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_ulps_tol(&1.0, &2), 2);
+
+    assert_eq!(two.debug_abs_all_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_rmax_all_tol(&1.0, &0.5), 1.0);
+    assert_eq!(two.debug_rmin_all_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_r1st_all_tol(&1.0, &0.5), 1.0);
+    assert_eq!(two.debug_r2nd_all_tol(&1.0, &0.5), 0.5);
+    assert_eq!(two.debug_ulps_all_tol(&1.0, &2), 2);
+}
@@ -0,0 +1,171 @@
+use float_eq::{
+    eq_ulps_by_bits, eq_ulps_by_metric, float_eq, float_ne, ulps_diff_by_bits, FloatBitPattern,
+    FloatBits, FloatEq, FloatEqUlpsTol, UlpsMetric,
+};
+
+#[test]
+fn round_trip() {
+    assert_eq!(1.0f32.to_float_bits(), 0x3f80_0000);
+    assert_eq!(f32::from_float_bits(0x3f80_0000), 1.0);
+    assert_eq!((-2.0f64).to_float_bits(), 0xc000_0000_0000_0000);
+    assert_eq!(f64::from_float_bits(0xc000_0000_0000_0000), -2.0);
+    assert!(f64::NAN.is_nan_value());
+    assert!(!(-0.0f32).is_sign_positive_value());
+}
+
+#[test]
+fn ulps_diff() {
+    assert_eq!(1.0f32.ulps_diff(1.0), Some(0));
+    assert_eq!(0.0f64.ulps_diff(-0.0), Some(0));
+    assert_eq!(
+        1.0f32.ulps_diff(f32::from_bits(1.0f32.to_bits() + 3)),
+        Some(3)
+    );
+    assert_eq!(f64::from_bits(1.0f64.to_bits() - 7).ulps_diff(1.0), Some(7));
+    assert_eq!(1.0f32.ulps_diff(-1.0), None);
+    assert_eq!(f64::NAN.ulps_diff(1.0), None);
+    assert_eq!(f32::NAN.ulps_diff(f32::NAN), None);
+}
+
+#[test]
+fn eq_ulps_within() {
+    let next = f32::from_bits(1.0f32.to_bits() + 1);
+    assert!(1.0f32.eq_ulps_within(next, 1));
+    assert!(!1.0f32.eq_ulps_within(next, 0));
+    assert!(0.0f64.eq_ulps_within(-0.0, 0));
+    assert!(!f64::MIN_POSITIVE.eq_ulps_within(-f64::MIN_POSITIVE, u64::MAX));
+    assert!(!f32::NAN.eq_ulps_within(f32::NAN, u32::MAX));
+}
+
+// A downstream type that reuses the ULPs rules of the primitive it wraps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Meters(f64);
+
+impl FloatBits for Meters {
+    type Bits = u64;
+
+    fn to_float_bits(self) -> u64 {
+        self.0.to_float_bits()
+    }
+
+    fn from_float_bits(bits: u64) -> Self {
+        Meters(f64::from_float_bits(bits))
+    }
+
+    fn is_nan_value(self) -> bool {
+        self.0.is_nan_value()
+    }
+
+    fn is_sign_positive_value(self) -> bool {
+        self.0.is_sign_positive_value()
+    }
+}
+
+impl FloatEqUlpsTol for Meters {
+    type UlpsTol = u64;
+}
+
+impl FloatEq for Meters {
+    type Tol = Meters;
+
+    fn eq_abs(&self, other: &Self, tol: &Meters) -> bool {
+        self.0.eq_abs(&other.0, &tol.0)
+    }
+
+    fn eq_rmax(&self, other: &Self, tol: &Meters) -> bool {
+        self.0.eq_rmax(&other.0, &tol.0)
+    }
+
+    fn eq_rmin(&self, other: &Self, tol: &Meters) -> bool {
+        self.0.eq_rmin(&other.0, &tol.0)
+    }
+
+    fn eq_r1st(&self, other: &Self, tol: &Meters) -> bool {
+        self.0.eq_r1st(&other.0, &tol.0)
+    }
+
+    fn eq_r2nd(&self, other: &Self, tol: &Meters) -> bool {
+        self.0.eq_r2nd(&other.0, &tol.0)
+    }
+
+    fn eq_ulps(&self, other: &Self, tol: &u64) -> bool {
+        self.eq_ulps_within(*other, *tol)
+    }
+}
+
+#[test]
+fn custom_type() {
+    let a = Meters(1.0);
+    let b = Meters::from_float_bits(a.to_float_bits() + 2);
+    assert_eq!(a.ulps_diff(b), Some(2));
+    assert!(float_eq!(a, b, ulps <= 2));
+    assert!(float_ne!(a, b, ulps <= 1));
+    assert!(float_ne!(Meters(1.0), Meters(-1.0), ulps <= u64::MAX));
+}
+
+// A 16.16 fixed point type that supports ULPs via its bit pattern.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fixed(i32);
+
+impl FloatBitPattern for Fixed {
+    type Bits = u32;
+
+    fn to_bits(&self) -> u32 {
+        (self.0 as u32) ^ (1 << 31)
+    }
+}
+
+#[test]
+fn bit_pattern() {
+    assert_eq!(ulps_diff_by_bits(&Fixed(5), &Fixed(5)), 0);
+    assert_eq!(ulps_diff_by_bits(&Fixed(-1), &Fixed(1)), 2);
+    assert_eq!(ulps_diff_by_bits(&Fixed(1), &Fixed(-1)), 2);
+    assert_eq!(
+        ulps_diff_by_bits(&Fixed(i32::MIN), &Fixed(i32::MAX)),
+        u32::MAX
+    );
+
+    assert!(eq_ulps_by_bits(&Fixed(-1), &Fixed(1), &2));
+    assert!(!eq_ulps_by_bits(&Fixed(-1), &Fixed(1), &1));
+    assert!(eq_ulps_by_bits(
+        &Fixed(i32::MIN),
+        &Fixed(i32::MAX),
+        &u32::MAX
+    ));
+}
+
+// An 8-bit posit, which measures ULPs with its own metric.
+struct Posit8(u8);
+
+impl UlpsMetric for Posit8 {
+    type Ulps = u8;
+
+    fn ulps_between(&self, other: &Self) -> Option<u8> {
+        const NAR: u8 = 0x80;
+        if self.0 == NAR || other.0 == NAR {
+            None
+        } else {
+            Some((self.0 as i8).abs_diff(other.0 as i8))
+        }
+    }
+}
+
+#[test]
+fn ulps_metric() {
+    assert_eq!(
+        1.0f32.ulps_between(&f32::from_bits(1.0f32.to_bits() + 4)),
+        Some(4)
+    );
+    assert_eq!(0.0f64.ulps_between(&-0.0), Some(0));
+    assert_eq!(1.0f64.ulps_between(&-1.0), None);
+    assert_eq!(Fixed(-2).ulps_between(&Fixed(3)), Some(5));
+    assert_eq!(Posit8(0x7f).ulps_between(&Posit8(0x81)), Some(254));
+    assert_eq!(Posit8(0x80).ulps_between(&Posit8(0x00)), None);
+
+    assert!(eq_ulps_by_metric(&1.0f32, &1.0, &0));
+    assert!(!eq_ulps_by_metric(&f32::NAN, &f32::NAN, &u32::MAX));
+    assert!(eq_ulps_by_metric(&Fixed(-2), &Fixed(3), &5));
+    assert!(!eq_ulps_by_metric(&Fixed(-2), &Fixed(3), &4));
+    assert!(eq_ulps_by_metric(&Posit8(0x01), &Posit8(0xff), &2));
+    assert!(!eq_ulps_by_metric(&Posit8(0x80), &Posit8(0x80), &u8::MAX));
+}
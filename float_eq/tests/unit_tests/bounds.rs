@@ -0,0 +1,66 @@
+use core::ops::Bound;
+use float_eq::{approx_contains, approx_contains_by, FloatEq};
+
+#[test]
+fn included_bounds_widened() {
+    assert!(approx_contains(&(1.0f32..=2.0), &1.5, 0.1));
+    assert!(approx_contains(&(1.0f32..=2.0), &0.95, 0.1));
+    assert!(approx_contains(&(1.0f32..=2.0), &2.05, 0.1));
+    assert!(!approx_contains(&(1.0f32..=2.0), &0.85, 0.1));
+    assert!(!approx_contains(&(1.0f32..=2.0), &2.15, 0.1));
+}
+
+#[test]
+fn excluded_bounds_narrowed() {
+    assert!(approx_contains(&(1.0f32..2.0), &1.0, 0.1));
+    assert!(approx_contains(&(1.0f32..2.0), &1.85, 0.1));
+    assert!(!approx_contains(&(1.0f32..2.0), &1.95, 0.1));
+    assert!(!approx_contains(&(1.0f32..2.0), &2.0, 0.1));
+
+    let range = (Bound::Excluded(1.0f32), Bound::Unbounded);
+    assert!(!approx_contains(&range, &1.05, 0.1));
+    assert!(approx_contains(&range, &1.15, 0.1));
+    assert!(approx_contains(&range, &f32::INFINITY, 0.1));
+}
+
+#[test]
+fn unbounded() {
+    assert!(approx_contains(&(1.0f64..), &0.95, 0.1));
+    assert!(!approx_contains(&(1.0f64..), &0.85, 0.1));
+    assert!(approx_contains(&(..2.0f64), &-1e300, 0.1));
+    assert!(!approx_contains(&(..2.0f64), &1.95, 0.1));
+    assert!(approx_contains(&(..=2.0f64), &2.05, 0.1));
+    assert!(approx_contains::<f64, _>(&(..), &f64::NAN, 0.0));
+}
+
+#[test]
+fn nan() {
+    assert!(!approx_contains(&(0.0f32..=1.0), &f32::NAN, f32::INFINITY));
+    assert!(!approx_contains(&(0.0f32..), &f32::NAN, f32::INFINITY));
+    assert!(!approx_contains(&(f32::NAN..=1.0), &0.5, 1.0));
+}
+
+#[test]
+fn zero_tolerance_matches_contains() {
+    let values = [-1.0f64, 0.0, 0.5, 1.0, 2.0];
+    for x in &values {
+        assert_eq!(approx_contains(&(0.0..1.0), x, 0.0), (0.0..1.0).contains(x));
+        assert_eq!(
+            approx_contains(&(0.0..=1.0), x, 0.0),
+            (0.0..=1.0).contains(x)
+        );
+    }
+}
+
+#[test]
+fn by_check() {
+    let end = 1.0f32;
+    let above = f32::from_bits(end.to_bits() + 1);
+    let below = f32::from_bits(end.to_bits() - 1);
+    let ulps = |a: &f32, b: &f32, tol: &u32| a.eq_ulps(b, tol);
+
+    assert!(approx_contains_by(&(0.0..=end), &above, 1, ulps));
+    assert!(!approx_contains_by(&(0.0..=end), &above, 0, ulps));
+    assert!(!approx_contains_by(&(0.0..end), &below, 1, ulps));
+    assert!(approx_contains_by(&(0.0..end), &below, 0, ulps));
+}
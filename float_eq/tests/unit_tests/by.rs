@@ -0,0 +1,130 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, AssertFloatEq, AssertFloatEqAll, By, FloatEq,
+    FloatEqAll,
+};
+
+fn sum(v: &[f32; 2]) -> f32 {
+    v[0] + v[1]
+}
+
+fn first(v: &(f32, &str)) -> f32 {
+    v.0
+}
+
+#[test]
+fn accessors() {
+    let a = By::new([1.0f32, 2.0], sum);
+    assert_eq!(a.value(), &[1.0, 2.0]);
+    assert_eq!(a.projected(), 3.0);
+    assert_eq!(a.into_value(), [1.0, 2.0]);
+}
+
+#[test]
+fn debug() {
+    let a = By::new([1.0f32, 2.0], sum);
+    assert_eq!(
+        format!("{:?}", a),
+        "By { value: [1.0, 2.0], projected: 3.0 }"
+    );
+}
+
+#[test]
+fn float_eq() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new([2.0f32, 1.5], sum);
+
+    assert!(a.eq_abs(&b, &0.5));
+    assert!(a.ne_abs(&b, &0.4));
+    assert!(a.eq_rmax(&b, &0.15));
+    assert!(a.ne_rmax(&b, &0.14));
+    assert!(a.eq_rmin(&b, &0.17));
+    assert!(a.ne_rmin(&b, &0.16));
+    assert!(a.eq_r1st(&b, &0.17));
+    assert!(a.ne_r1st(&b, &0.16));
+    assert!(a.eq_r2nd(&b, &0.15));
+    assert!(a.ne_r2nd(&b, &0.14));
+    assert!(a.eq_ulps(&b, &2_097_152));
+    assert!(a.ne_ulps(&b, &2_097_151));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new([2.0f32, 1.5], sum);
+
+    assert!(a.eq_abs_all(&b, &0.5));
+    assert!(a.ne_abs_all(&b, &0.4));
+    assert!(a.eq_rmax_all(&b, &0.15));
+    assert!(a.ne_rmax_all(&b, &0.14));
+    assert!(a.eq_rmin_all(&b, &0.17));
+    assert!(a.ne_rmin_all(&b, &0.16));
+    assert!(a.eq_r1st_all(&b, &0.17));
+    assert!(a.ne_r1st_all(&b, &0.16));
+    assert!(a.eq_r2nd_all(&b, &0.15));
+    assert!(a.ne_r2nd_all(&b, &0.14));
+    assert!(a.eq_ulps_all(&b, &2_097_152));
+    assert!(a.ne_ulps_all(&b, &2_097_151));
+}
+
+#[test]
+fn different_projections() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new((3.0f32, "three"), first);
+    assert!(float_eq!(a, b, ulps <= 0));
+    assert!(float_eq!(b, a, ulps <= 0));
+
+    let c = By::new([1.0f32, 2.0], |v: &[f32; 2]| v[0] * v[1]);
+    assert!(float_eq!(a, c, abs <= 1.0));
+}
+
+#[test]
+fn debug_diff() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new([2.0f32, 1.5], sum);
+
+    assert_eq!(a.debug_abs_diff(&b), 0.5);
+    assert_eq!(a.debug_ulps_diff(&b), Some(2_097_152));
+}
+
+#[test]
+fn debug_tol() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new([2.0f32, 2.0], sum);
+
+    assert_eq!(a.debug_abs_tol(&b, &0.1), 0.1);
+    assert_eq!(a.debug_rmax_tol(&b, &0.5), 2.0);
+    assert_eq!(a.debug_rmin_tol(&b, &0.5), 1.5);
+    assert_eq!(a.debug_r1st_tol(&b, &0.5), 1.5);
+    assert_eq!(a.debug_r2nd_tol(&b, &0.5), 2.0);
+    assert_eq!(a.debug_ulps_tol(&b, &2), 2);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.1), 0.1);
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.5), 2.0);
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.5), 1.5);
+    assert_eq!(a.debug_r1st_all_tol(&b, &0.5), 1.5);
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.5), 2.0);
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), 2);
+}
+
+#[test]
+fn asserts() {
+    let a = By::new([1.0f32, 2.0], sum);
+    let b = By::new([2.0f32, 1.5], sum);
+    assert_float_eq!(a, b, abs <= 0.5);
+    assert_float_ne!(a, b, abs <= 0.4);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `By { value: [1.0, 2.0], projected: 3.0 }`,
+       right: `By { value: [2.0, 2.0], projected: 4.0 }`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.1`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        By::new([1.0f32, 2.0], sum),
+        By::new([2.0f32, 2.0], sum),
+        abs <= 0.1
+    );
+}
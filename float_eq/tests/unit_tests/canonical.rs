@@ -0,0 +1,72 @@
+use float_eq::{assert_float_eq, canonicalize, Canonical};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn bucket_centres() {
+    assert_eq!(canonicalize(1.24f32, 0.5), 1.0);
+    assert_eq!(canonicalize(1.26f32, 0.5), 1.5);
+    assert_eq!(canonicalize(-1.26f32, 0.5), -1.5);
+    assert_eq!(canonicalize(1.26f32, 1.0), 1.0);
+    assert_eq!(canonicalize(1e10f64, 1e-3), 1e10);
+}
+
+#[test]
+fn within_half_tolerance_of_representative() {
+    let tol = 0.01;
+    for i in -1000..1000 {
+        let x = f64::from(i) * 0.000_37;
+        assert_float_eq!(canonicalize(x, tol), x, abs <= tol / 2.0 + 1e-15);
+    }
+}
+
+#[test]
+fn signed_zero() {
+    assert_eq!(canonicalize(-0.0f32, 0.1).to_bits(), 0.0f32.to_bits());
+    assert_eq!(canonicalize(-0.01f64, 0.1).to_bits(), 0.0f64.to_bits());
+    assert_eq!(canonicalize(-0.0f64, 0.0).to_bits(), 0.0f64.to_bits());
+}
+
+#[test]
+fn nan() {
+    let nan = canonicalize(f32::NAN, 0.1);
+    assert!(nan.is_nan());
+    assert_eq!(canonicalize(-f32::NAN, 0.1).to_bits(), nan.to_bits());
+    assert_eq!(
+        canonicalize(f32::from_bits(0x7fc0_1234), 0.1).to_bits(),
+        nan.to_bits()
+    );
+}
+
+#[test]
+fn unchanged() {
+    assert_eq!(canonicalize(f64::INFINITY, 0.1), f64::INFINITY);
+    assert_eq!(canonicalize(f64::NEG_INFINITY, 0.1), f64::NEG_INFINITY);
+    assert_eq!(canonicalize(1.23f64, 0.0), 1.23);
+    assert_eq!(canonicalize(1.23f64, -0.1), 1.23);
+    assert_eq!(canonicalize(1.23f64, f64::INFINITY), 1.23);
+    assert_eq!(canonicalize(1.23f64, f64::NAN), 1.23);
+    assert_eq!(canonicalize(f64::MAX, f64::MIN_POSITIVE), f64::MAX);
+}
+
+#[test]
+fn canonical_eq_and_hash() {
+    let a = Canonical::new(0.1 + 0.2, 1e-6);
+    let b = Canonical::new(0.3, 1e-6);
+    let c = Canonical::new(0.31, 1e-6);
+    assert_eq!(a.value(), 0.3);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(hash(&a), hash(&b));
+
+    let nan = Canonical::new(f32::NAN, 0.1);
+    assert_eq!(nan, Canonical::new(-f32::NAN, 0.1));
+    assert_eq!(hash(&nan), hash(&Canonical::new(-f32::NAN, 0.1)));
+    assert_eq!(Canonical::new(-0.0f32, 0.1), Canonical::new(0.0f32, 0.1));
+}
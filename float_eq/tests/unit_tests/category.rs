@@ -0,0 +1,204 @@
+use core::cell::{Cell, RefCell};
+use float_eq::{assert_float_eq, AssertFloatEq, DebugCategories, FloatCategory, UlpsDiffReason};
+
+#[test]
+fn classify() {
+    assert_eq!(FloatCategory::classify(false, false, false), None);
+    assert_eq!(
+        FloatCategory::classify(true, false, false),
+        Some(FloatCategory::Nan)
+    );
+    assert_eq!(
+        FloatCategory::classify(false, true, false),
+        Some(FloatCategory::Infinite)
+    );
+    assert_eq!(
+        FloatCategory::classify(false, false, true),
+        Some(FloatCategory::Subnormal)
+    );
+}
+
+#[test]
+fn display() {
+    let nan = Some(FloatCategory::Nan);
+    let inf = Some(FloatCategory::Infinite);
+    let sub = Some(FloatCategory::Subnormal);
+
+    assert_eq!(DebugCategories::new(None, None), None);
+    let display = |l, r| DebugCategories::new(l, r).unwrap().to_string();
+    assert_eq!(display(nan, None), "left operand is NaN");
+    assert_eq!(display(None, inf), "right operand is infinite");
+    assert_eq!(display(sub, sub), "both operands are subnormal");
+    assert_eq!(
+        display(inf, nan),
+        "left operand is infinite, right operand is NaN"
+    );
+}
+
+#[test]
+fn ulps_diff_reason() {
+    assert_eq!(UlpsDiffReason::classify(false, false), None);
+    assert_eq!(
+        UlpsDiffReason::classify(true, true),
+        Some(UlpsDiffReason::Nan)
+    );
+    assert_eq!(
+        UlpsDiffReason::classify(false, true),
+        Some(UlpsDiffReason::DifferentSigns)
+    );
+    assert_eq!(
+        UlpsDiffReason::Nan.to_string(),
+        "an operand is NaN; ULPs comparison not applicable"
+    );
+    assert_eq!(
+        UlpsDiffReason::Saturated.to_string(),
+        "operands are too far apart to count; ULPs difference saturated"
+    );
+}
+
+macro_rules! impl_tests {
+    ($float:ident) => {
+        mod $float {
+            use crate::$float::*;
+            use float_eq::{AssertFloatEq, DebugCategories, FloatCategory, UlpsDiffReason};
+
+            fn categories(a: $float, b: $float) -> Option<DebugCategories> {
+                a.debug_categories(&b)
+            }
+
+            #[test]
+            fn debug_categories() {
+                let nan = Some(FloatCategory::Nan);
+                let inf = Some(FloatCategory::Infinite);
+                let sub = Some(FloatCategory::Subnormal);
+
+                assert_eq!(categories(1.0, 2.0), None);
+                assert_eq!(categories(0.0, -0.0), None);
+                assert_eq!(categories(MIN_NORMAL, -MAX_NORMAL), None);
+
+                for n in &nan_test_values() {
+                    assert_eq!(categories(*n, 1.0), DebugCategories::new(nan, None));
+                    assert_eq!(categories(1.0, *n), DebugCategories::new(None, nan));
+                }
+                assert_eq!(
+                    categories(INFINITY, -INFINITY),
+                    DebugCategories::new(inf, inf)
+                );
+                assert_eq!(categories(next(0.0), 0.0), DebugCategories::new(sub, None));
+                assert_eq!(
+                    categories(-prev(MIN_NORMAL), $float::NAN),
+                    DebugCategories::new(sub, nan)
+                );
+            }
+
+            #[test]
+            fn debug_ulps_diff_reason() {
+                let reason = |a: $float, b: $float| a.debug_ulps_diff_reason(&b);
+                let nan = Some(UlpsDiffReason::Nan);
+                let signs = Some(UlpsDiffReason::DifferentSigns);
+
+                assert_eq!(reason(1.0, 2.0), None);
+                assert_eq!(reason(-1.0, -INFINITY), None);
+                assert_eq!(reason(0.0, -0.0), None);
+                assert_eq!(reason(-1.0, 1.0), signs);
+                assert_eq!(reason(next(0.0), -next(0.0)), signs);
+                assert_eq!(reason(-INFINITY, 0.0), signs);
+                for n in &nan_test_values() {
+                    assert_eq!(reason(*n, 1.0), nan);
+                    assert_eq!(reason(-1.0, *n), nan);
+                    assert_eq!(reason(*n, *n), nan);
+                }
+            }
+        }
+    };
+}
+
+impl_tests!(f32);
+impl_tests!(f64);
+
+#[test]
+fn wrappers() {
+    let expected = DebugCategories::new(Some(FloatCategory::Nan), None);
+    assert_eq!(
+        AssertFloatEq::debug_categories(&&core::primitive::f32::NAN, &&1.0f32),
+        expected
+    );
+    assert_eq!(
+        Some(core::primitive::f32::NAN).debug_categories(&Some(1.0)),
+        expected
+    );
+    assert_eq!(
+        Some(core::primitive::f32::NAN).debug_categories(&None),
+        None
+    );
+    assert_eq!(
+        Cell::new(core::primitive::f32::NAN).debug_categories(&Cell::new(1.0)),
+        expected
+    );
+    assert_eq!(
+        RefCell::new(core::primitive::f32::NAN).debug_categories(&RefCell::new(1.0)),
+        expected
+    );
+
+    // Composite types do not describe their fields
+    assert_eq!([core::primitive::f32::NAN].debug_categories(&[1.0]), None);
+
+    let expected = Some(UlpsDiffReason::DifferentSigns);
+    assert_eq!(
+        AssertFloatEq::debug_ulps_diff_reason(&&-1.0f32, &&1.0f32),
+        expected
+    );
+    assert_eq!(Some(-1.0f32).debug_ulps_diff_reason(&Some(1.0)), expected);
+    assert_eq!(Some(-1.0f32).debug_ulps_diff_reason(&None), None);
+    assert_eq!(
+        Cell::new(-1.0f32).debug_ulps_diff_reason(&Cell::new(1.0)),
+        expected
+    );
+    assert_eq!(
+        RefCell::new(-1.0f32).debug_ulps_diff_reason(&RefCell::new(1.0)),
+        expected
+    );
+    assert_eq!([-1.0f32].debug_ulps_diff_reason(&[1.0]), None);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `NaN`,
+       right: `1.0`,
+    abs_diff: `NaN`,
+   ulps_diff: an operand is NaN; ULPs comparison not applicable,
+     [abs] t: `0.1`,
+        note: left operand is NaN"#)]
+fn assert_fail_note() {
+    assert_float_eq!(core::primitive::f32::NAN, 1.0, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t, ulps <= t)`
+        left: `1.0`,
+       right: `inf`,
+    abs_diff: `inf`,
+   ulps_diff: `Some(1073741824)`,
+     [abs] t: `0.1`,
+    [ulps] t: `1`,
+        note: right operand is infinite: testing"#)]
+fn assert_fail_note_with_message() {
+    assert_float_eq!(
+        1.0f32,
+        core::primitive::f32::INFINITY,
+        abs <= 0.1,
+        ulps <= 1,
+        "testing"
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, bits)`
+        left: `NaN`,
+       right: `NaN`,
+    abs_diff: `NaN`,
+   ulps_diff: an operand is NaN; ULPs comparison not applicable,
+        note: both operands are NaN"#)]
+fn assert_bits_fail_note() {
+    assert_float_eq!(core::primitive::f64::NAN, -core::primitive::f64::NAN, bits);
+}
@@ -0,0 +1,63 @@
+use float_eq::{CheckSet, FloatEq};
+
+#[test]
+fn set_ops() {
+    let abs_ulps = CheckSet::ABS.union(CheckSet::ULPS);
+    assert!(abs_ulps.contains(CheckSet::ABS));
+    assert!(abs_ulps.contains(abs_ulps));
+    assert!(abs_ulps.contains(CheckSet::NONE));
+    assert!(!abs_ulps.contains(CheckSet::RMAX));
+    assert!(!abs_ulps.contains(CheckSet::ALL));
+    assert!(CheckSet::ALL.contains(abs_ulps));
+
+    assert_eq!(abs_ulps.intersection(CheckSet::ULPS), CheckSet::ULPS);
+    assert_eq!(abs_ulps.intersection(CheckSet::RMIN), CheckSet::NONE);
+    assert_eq!(abs_ulps.difference(CheckSet::ABS), CheckSet::ULPS);
+    assert_eq!(CheckSet::ALL.difference(CheckSet::ALL), CheckSet::NONE);
+
+    assert!(CheckSet::NONE.is_empty());
+    assert!(!CheckSet::R2ND.is_empty());
+    assert_eq!(CheckSet::default(), CheckSet::NONE);
+}
+
+#[test]
+fn names() {
+    assert_eq!(
+        CheckSet::ALL.names().collect::<Vec<_>>(),
+        vec!["abs", "rmax", "rmin", "r1st", "r2nd", "ulps"]
+    );
+    assert_eq!(CheckSet::NONE.names().count(), 0);
+
+    for name in ["abs", "rmax", "rmin", "r1st", "r2nd", "ulps"] {
+        let check = CheckSet::from_name(name).unwrap();
+        assert_eq!(check.names().collect::<Vec<_>>(), vec![name]);
+        assert_eq!(CheckSet::from_name(&format!("{}_all", name)), Some(check));
+    }
+    assert_eq!(CheckSet::from_name("rel"), Some(CheckSet::RMAX));
+    assert_eq!(CheckSet::from_name("percent_all"), Some(CheckSet::RMAX));
+    assert_eq!(
+        CheckSet::from_name("abs_ulps"),
+        Some(CheckSet::ABS.union(CheckSet::ULPS))
+    );
+    assert_eq!(CheckSet::from_name("bits"), None);
+    assert_eq!(CheckSet::from_name("ABS"), None);
+    assert_eq!(CheckSet::from_name(""), None);
+}
+
+#[test]
+fn display() {
+    let set = CheckSet::R1ST.union(CheckSet::ABS);
+    assert_eq!(set.to_string(), "abs, r1st");
+    assert_eq!(format!("{:?}", set), r#"{"abs", "r1st"}"#);
+    assert_eq!(CheckSet::NONE.to_string(), "");
+    assert_eq!(format!("{:?}", CheckSet::NONE), "{}");
+}
+
+#[test]
+fn supported_checks() {
+    assert_eq!(<f32 as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+    assert_eq!(<[f64; 3] as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+    assert_eq!(<(f32, f64) as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+    assert_eq!(<Option<f32> as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+    assert_eq!(<&[f64] as FloatEq>::SUPPORTED_CHECKS, CheckSet::ALL);
+}
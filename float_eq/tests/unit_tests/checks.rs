@@ -0,0 +1,91 @@
+use float_eq::checks::{self, eq_with_check, ne_with_check, Check};
+
+fn names<C: Check<f32>>() -> &'static str {
+    C::NAME
+}
+
+#[test]
+fn name() {
+    assert_eq!(names::<checks::Abs>(), "abs");
+    assert_eq!(names::<checks::Rmax>(), "rmax");
+    assert_eq!(names::<checks::Rmin>(), "rmin");
+    assert_eq!(names::<checks::R1st>(), "r1st");
+    assert_eq!(names::<checks::R2nd>(), "r2nd");
+    assert_eq!(names::<checks::Ulps>(), "ulps");
+}
+
+#[test]
+fn abs() {
+    assert!(eq_with_check::<checks::Abs, _, _>(&1.0f32, &1.5, &0.5));
+    assert!(ne_with_check::<checks::Abs, _, _>(&1.0f32, &1.6, &0.5));
+}
+
+#[test]
+fn rmax() {
+    assert!(eq_with_check::<checks::Rmax, _, _>(&4.0f64, &3.0, &0.25));
+    assert!(ne_with_check::<checks::Rmax, _, _>(&4.0f64, &2.9, &0.25));
+}
+
+#[test]
+fn rmin() {
+    assert!(eq_with_check::<checks::Rmin, _, _>(&4.0f64, &3.0, &0.5));
+    assert!(ne_with_check::<checks::Rmin, _, _>(&4.0f64, &3.0, &0.25));
+}
+
+#[test]
+fn r1st() {
+    assert!(eq_with_check::<checks::R1st, _, _>(&4.0f32, &3.0, &0.25));
+    assert!(ne_with_check::<checks::R1st, _, _>(&3.0f32, &4.0, &0.25));
+}
+
+#[test]
+fn r2nd() {
+    assert!(eq_with_check::<checks::R2nd, _, _>(&3.0f32, &4.0, &0.25));
+    assert!(ne_with_check::<checks::R2nd, _, _>(&4.0f32, &3.0, &0.25));
+}
+
+#[test]
+fn ulps() {
+    assert!(eq_with_check::<checks::Ulps, _, _>(
+        &1.0f32,
+        &1.000_000_1,
+        &1
+    ));
+    assert!(ne_with_check::<checks::Ulps, _, _>(
+        &1.0f32,
+        &1.000_000_2,
+        &1
+    ));
+    assert!(eq_with_check::<checks::Ulps, _, _>(
+        &[1.0f64, 2.0],
+        &[1.0, 2.000_000_000_000_000_4],
+        &[0, 1]
+    ));
+}
+
+#[test]
+fn composite() {
+    let a = (1.0f32, [2.0f64, 3.0]);
+    let b = (1.1f32, [2.0f64, 3.5]);
+    assert!(eq_with_check::<checks::Abs, _, _>(
+        &a,
+        &b,
+        &(0.2, [0.0, 0.5])
+    ));
+    assert!(ne_with_check::<checks::Abs, _, _>(
+        &a,
+        &b,
+        &(0.2, [0.0, 0.4])
+    ));
+}
+
+#[test]
+fn nan() {
+    let nan = f64::NAN;
+    assert!(ne_with_check::<checks::Abs, _, _>(
+        &nan,
+        &nan,
+        &f64::INFINITY
+    ));
+    assert!(ne_with_check::<checks::Ulps, _, _>(&nan, &nan, &u64::MAX));
+}
@@ -0,0 +1,144 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, AssertFloatEq, AssertFloatEqAll, Clamped, FloatEq,
+    FloatEqAll,
+};
+
+fn unit(x: f32) -> Clamped<f32> {
+    Clamped::new(x, 0.0, 1.0)
+}
+
+#[test]
+fn accessors() {
+    let a = Clamped::new(0.5f32, -1.0, 1.0);
+    assert_eq!(a.value(), &0.5);
+    assert_eq!(a.min(), &-1.0);
+    assert_eq!(a.max(), &1.0);
+    assert_eq!(a.into_value(), 0.5);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", unit(1.0)),
+        "Clamped { value: 1.0, min: 0.0, max: 1.0 }"
+    );
+}
+
+#[test]
+fn same_rail() {
+    // Both beyond or at a rail
+    assert!(unit(1.0).eq_abs(&1.5, &0.0));
+    assert!(unit(0.0).eq_abs(&-0.5, &0.0));
+    assert!(unit(1.5).eq_abs(&1.0, &0.0));
+    assert!(unit(f32::INFINITY).eq_abs(&1.0, &0.0));
+
+    // Within tolerance of a rail
+    assert!(unit(1.0).eq_abs(&0.99, &0.01));
+    assert!(unit(0.99).eq_abs(&1.5, &0.01));
+    assert!(unit(0.0).eq_abs(&0.01, &0.01));
+    assert!(unit(1.0).eq_ulps(&0.999_999_94, &1));
+
+    // Not near a rail, or at different rails
+    assert!(unit(1.0).ne_abs(&0.98, &0.01));
+    assert!(unit(0.0).ne_abs(&1.0, &0.5));
+    assert!(unit(0.5).ne_abs(&1.0, &0.01));
+    assert!(unit(1.0).ne_ulps(&0.999_999_9, &1));
+}
+
+#[test]
+fn between_rails() {
+    assert!(unit(0.5).eq_abs(&0.5, &0.0));
+    assert!(unit(0.5).eq_abs(&0.6, &0.11));
+    assert!(unit(0.5).ne_abs(&0.6, &0.09));
+}
+
+#[test]
+fn nan() {
+    assert!(unit(f32::NAN).ne_abs(&1.0, &1.0));
+    assert!(unit(1.0).ne_abs(&f32::NAN, &1.0));
+    assert!(unit(f32::NAN).ne_abs(&f32::NAN, &1.0));
+}
+
+#[test]
+fn float_eq() {
+    let a = Clamped::new(2.0f32, 0.0, 2.0);
+    assert!(a.eq_abs(&1.95, &0.05));
+    assert!(a.ne_abs(&1.8, &0.05));
+    assert!(a.eq_rmax(&1.95, &0.025));
+    assert!(a.ne_rmax(&1.8, &0.025));
+    assert!(a.eq_rmin(&1.95, &0.026));
+    assert!(a.ne_rmin(&1.8, &0.026));
+    assert!(a.eq_r1st(&1.95, &0.026));
+    assert!(a.ne_r1st(&1.8, &0.026));
+    assert!(a.eq_r2nd(&1.95, &0.025));
+    assert!(a.ne_r2nd(&1.8, &0.025));
+    assert!(a.eq_ulps(&2.5, &0));
+    assert!(a.ne_ulps(&1.999_999_9, &0));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Clamped::new(2.0f32, 0.0, 2.0);
+    assert!(a.eq_abs_all(&1.95, &0.05));
+    assert!(a.ne_abs_all(&1.8, &0.05));
+    assert!(a.eq_rmax_all(&1.95, &0.025));
+    assert!(a.ne_rmax_all(&1.8, &0.025));
+    assert!(a.eq_rmin_all(&1.95, &0.026));
+    assert!(a.ne_rmin_all(&1.8, &0.026));
+    assert!(a.eq_r1st_all(&1.95, &0.026));
+    assert!(a.ne_r1st_all(&1.8, &0.026));
+    assert!(a.eq_r2nd_all(&1.95, &0.025));
+    assert!(a.ne_r2nd_all(&1.8, &0.025));
+    assert!(a.eq_ulps_all(&2.5, &0));
+    assert!(a.ne_ulps_all(&1.999_999_9, &0));
+}
+
+#[test]
+fn arrays() {
+    let a = [-0.1f32, 0.5, 1.0].map(unit);
+    assert!(float_eq!(a, [0.0, 0.5, 2.0], abs <= [0.0; 3]));
+    assert!(float_eq!(a, [-1.0, 0.5, 1.0], ulps_all <= 0));
+    assert!(!float_eq!(a, [0.0, 0.6, 1.0], abs_all <= 0.05));
+}
+
+#[test]
+fn debug_diff() {
+    let a = unit(1.0);
+    assert_eq!(a.debug_abs_diff(&1.5), 0.5);
+    assert_eq!(a.debug_ulps_diff(&2.0), Some(8_388_608));
+}
+
+#[test]
+fn debug_tol() {
+    let a = unit(1.0);
+    assert_eq!(a.debug_abs_tol(&2.0, &0.1), 0.1);
+    assert_eq!(a.debug_rmax_tol(&2.0, &0.5), 1.0);
+    assert_eq!(a.debug_rmin_tol(&2.0, &0.5), 0.5);
+    assert_eq!(a.debug_r1st_tol(&2.0, &0.5), 0.5);
+    assert_eq!(a.debug_r2nd_tol(&2.0, &0.5), 1.0);
+    assert_eq!(a.debug_ulps_tol(&2.0, &2), 2);
+
+    assert_eq!(a.debug_abs_all_tol(&2.0, &0.1), 0.1);
+    assert_eq!(a.debug_rmax_all_tol(&2.0, &0.5), 1.0);
+    assert_eq!(a.debug_rmin_all_tol(&2.0, &0.5), 0.5);
+    assert_eq!(a.debug_r1st_all_tol(&2.0, &0.5), 0.5);
+    assert_eq!(a.debug_r2nd_all_tol(&2.0, &0.5), 1.0);
+    assert_eq!(a.debug_ulps_all_tol(&2.0, &2), 2);
+}
+
+#[test]
+fn asserts() {
+    assert_float_eq!(unit(1.0), 1.25, abs <= 0.01);
+    assert_float_ne!(unit(0.5), 1.25, abs <= 0.01);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `Clamped { value: 0.0, min: 0.0, max: 1.0 }`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(unit(0.0), 1.0, abs <= 0.1);
+}
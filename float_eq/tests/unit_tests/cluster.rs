@@ -0,0 +1,66 @@
+use float_eq::{cluster, cluster_by, FloatEq};
+
+#[test]
+fn empty() {
+    let clusters = cluster::<f32>(&[], 1.0);
+    assert!(clusters.is_empty());
+    assert_eq!(clusters.len(), 0);
+    assert!(clusters.assignments().is_empty());
+    assert!(clusters.members().is_empty());
+}
+
+#[test]
+fn distinct_values() {
+    let clusters = cluster(&[1.0f32, 2.0, 3.0], 0.1);
+    assert_eq!(clusters.len(), 3);
+    assert_eq!(clusters.assignments(), &[0, 1, 2]);
+    assert_eq!(clusters.representatives(), &[0, 1, 2]);
+}
+
+#[test]
+fn near_duplicates() {
+    let clusters = cluster(&[1.0f64, 5.0, 1.05, 4.95, 1.0, 9.0], 0.1);
+    assert_eq!(clusters.assignments(), &[0, 1, 0, 1, 0, 2]);
+    assert_eq!(clusters.representatives(), &[0, 1, 5]);
+    assert_eq!(clusters.members(), vec![vec![0, 2, 4], vec![1, 3], vec![5]]);
+}
+
+#[test]
+fn members_within_tolerance_of_representative() {
+    // a chain of values each within tolerance of the next is not merged
+    let values = [0.0f64, 0.08, 0.16, 0.24];
+    let clusters = cluster(&values, 0.1);
+    assert_eq!(clusters.assignments(), &[0, 0, 1, 1]);
+    for (index, &c) in clusters.assignments().iter().enumerate() {
+        let rep = values[clusters.representatives()[c]];
+        assert!(values[index].eq_abs(&rep, &0.1));
+    }
+}
+
+#[test]
+fn first_matching_cluster() {
+    // 1.0 is within tolerance of both representatives, and joins the first
+    let clusters = cluster(&[0.9f64, 1.1, 1.0], 0.15);
+    assert_eq!(clusters.assignments(), &[0, 1, 0]);
+}
+
+#[test]
+fn nans_are_singletons() {
+    let clusters = cluster(&[f32::NAN, 1.0, f32::NAN], f32::INFINITY);
+    assert_eq!(clusters.assignments(), &[0, 1, 2]);
+}
+
+#[test]
+fn points() {
+    let vertices = [(0.0f32, 1.0f64), (1.0, 0.0), (1e-7, 1.0 + 1e-9)];
+    let clusters = cluster(&vertices, (1e-6, 1e-6));
+    assert_eq!(clusters.assignments(), &[0, 1, 0]);
+}
+
+#[test]
+fn by_check() {
+    let values = [1.0f32, f32::from_bits(1.0f32.to_bits() + 2), 2.0];
+    let ulps = |a: &f32, b: &f32, tol: &u32| a.eq_ulps(b, tol);
+    assert_eq!(cluster_by(&values, 2, ulps).assignments(), &[0, 0, 1]);
+    assert_eq!(cluster_by(&values, 1, ulps).assignments(), &[0, 1, 2]);
+}
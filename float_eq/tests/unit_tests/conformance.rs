@@ -0,0 +1,330 @@
+use float_eq::conformance::{check_impl, Rule, Violation};
+use float_eq::{
+    assert_impl_conforms, AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol, F32_TEST_VECTORS, F64_TEST_VECTORS,
+};
+
+// A hand-written implementation that delegates to f64, apart from a mistake
+// selected by BUG, which is one of the constants below.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Buggy<const BUG: u8>(f64);
+
+const NONE: u8 = 0;
+const R1ST_IS_R2ND: u8 = 1;
+const NE_IS_EQ: u8 = 2;
+const REL_IS_RMIN: u8 = 3;
+const RMAX_TOL_IS_RMIN: u8 = 4;
+const ABS_IS_STRICT: u8 = 5;
+const ABS_DIFF_IS_SIGNED: u8 = 6;
+const ULPS_ALL_TOL_IS_DOUBLED: u8 = 7;
+
+impl<const BUG: u8> FloatEqUlpsTol for Buggy<BUG> {
+    type UlpsTol = u64;
+}
+
+impl<const BUG: u8> FloatEqDebugUlpsDiff for Buggy<BUG> {
+    type DebugUlpsDiff = Option<u64>;
+}
+
+impl<const BUG: u8> FloatEq for Buggy<BUG> {
+    type Tol = Self;
+
+    fn eq_abs(&self, other: &Self, tol: &Self) -> bool {
+        if BUG == ABS_IS_STRICT {
+            (self.0 - other.0).abs() < tol.0
+        } else {
+            self.0.eq_abs(&other.0, &tol.0)
+        }
+    }
+
+    fn ne_abs(&self, other: &Self, tol: &Self) -> bool {
+        if BUG == NE_IS_EQ {
+            self.eq_abs(other, tol)
+        } else {
+            !self.eq_abs(other, tol)
+        }
+    }
+
+    fn eq_rel(&self, other: &Self, tol: &Self) -> bool {
+        if BUG == REL_IS_RMIN {
+            self.eq_rmin(other, tol)
+        } else {
+            self.eq_rmax(other, tol)
+        }
+    }
+
+    fn eq_rmax(&self, other: &Self, tol: &Self) -> bool {
+        self.0.eq_rmax(&other.0, &tol.0)
+    }
+
+    fn eq_rmin(&self, other: &Self, tol: &Self) -> bool {
+        self.0.eq_rmin(&other.0, &tol.0)
+    }
+
+    fn eq_r1st(&self, other: &Self, tol: &Self) -> bool {
+        if BUG == R1ST_IS_R2ND {
+            self.0.eq_r2nd(&other.0, &tol.0)
+        } else {
+            self.0.eq_r1st(&other.0, &tol.0)
+        }
+    }
+
+    fn eq_r2nd(&self, other: &Self, tol: &Self) -> bool {
+        self.0.eq_r2nd(&other.0, &tol.0)
+    }
+
+    fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self>) -> bool {
+        self.0.eq_ulps(&other.0, tol)
+    }
+}
+
+impl<const BUG: u8> FloatEqAll for Buggy<BUG> {
+    type AllTol = f64;
+
+    fn eq_abs_all(&self, other: &Self, tol: &f64) -> bool {
+        self.eq_abs(other, &Buggy(*tol))
+    }
+
+    fn eq_rmax_all(&self, other: &Self, tol: &f64) -> bool {
+        self.eq_rmax(other, &Buggy(*tol))
+    }
+
+    fn eq_rmin_all(&self, other: &Self, tol: &f64) -> bool {
+        self.eq_rmin(other, &Buggy(*tol))
+    }
+
+    fn eq_r1st_all(&self, other: &Self, tol: &f64) -> bool {
+        self.eq_r1st(other, &Buggy(*tol))
+    }
+
+    fn eq_r2nd_all(&self, other: &Self, tol: &f64) -> bool {
+        self.eq_r2nd(other, &Buggy(*tol))
+    }
+
+    fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<f64>) -> bool {
+        self.eq_ulps(other, tol)
+    }
+}
+
+impl<const BUG: u8> AssertFloatEq for Buggy<BUG> {
+    type DebugAbsDiff = Self;
+    type DebugTol = Self;
+
+    fn debug_abs_diff(&self, other: &Self) -> Self {
+        if BUG == ABS_DIFF_IS_SIGNED {
+            Buggy(self.0 - other.0)
+        } else {
+            Buggy(self.0.debug_abs_diff(&other.0))
+        }
+    }
+
+    fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self> {
+        self.0.debug_ulps_diff(&other.0)
+    }
+
+    fn debug_abs_tol(&self, other: &Self, tol: &Self) -> Self {
+        Buggy(self.0.debug_abs_tol(&other.0, &tol.0))
+    }
+
+    fn debug_rmax_tol(&self, other: &Self, tol: &Self) -> Self {
+        if BUG == RMAX_TOL_IS_RMIN {
+            self.debug_rmin_tol(other, tol)
+        } else {
+            Buggy(self.0.debug_rmax_tol(&other.0, &tol.0))
+        }
+    }
+
+    fn debug_rmin_tol(&self, other: &Self, tol: &Self) -> Self {
+        Buggy(self.0.debug_rmin_tol(&other.0, &tol.0))
+    }
+
+    fn debug_r1st_tol(&self, other: &Self, tol: &Self) -> Self {
+        Buggy(self.0.debug_r1st_tol(&other.0, &tol.0))
+    }
+
+    fn debug_r2nd_tol(&self, other: &Self, tol: &Self) -> Self {
+        Buggy(self.0.debug_r2nd_tol(&other.0, &tol.0))
+    }
+
+    fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self>) -> UlpsTol<Self> {
+        self.0.debug_ulps_tol(&other.0, tol)
+    }
+}
+
+impl<const BUG: u8> AssertFloatEqAll for Buggy<BUG> {
+    type AllDebugTol = Self;
+
+    fn debug_abs_all_tol(&self, other: &Self, tol: &f64) -> Self {
+        self.debug_abs_tol(other, &Buggy(*tol))
+    }
+
+    fn debug_rmax_all_tol(&self, other: &Self, tol: &f64) -> Self {
+        self.debug_rmax_tol(other, &Buggy(*tol))
+    }
+
+    fn debug_rmin_all_tol(&self, other: &Self, tol: &f64) -> Self {
+        self.debug_rmin_tol(other, &Buggy(*tol))
+    }
+
+    fn debug_r1st_all_tol(&self, other: &Self, tol: &f64) -> Self {
+        self.debug_r1st_tol(other, &Buggy(*tol))
+    }
+
+    fn debug_r2nd_all_tol(&self, other: &Self, tol: &f64) -> Self {
+        self.debug_r2nd_tol(other, &Buggy(*tol))
+    }
+
+    fn debug_ulps_all_tol(&self, other: &Self, tol: &UlpsTol<f64>) -> UlpsTol<Self> {
+        if BUG == ULPS_ALL_TOL_IS_DOUBLED {
+            tol * 2
+        } else {
+            self.debug_ulps_tol(other, tol)
+        }
+    }
+}
+
+fn buggy<const BUG: u8>() -> Result<(), Violation> {
+    let values = [
+        Buggy::<BUG>(1.0),
+        Buggy(1.3),
+        Buggy(-2.0),
+        Buggy(1.000_000_000_000_000_4),
+    ];
+    check_impl(&values, &Buggy(0.25), &1, &0.25, &1)
+}
+
+fn violation(rule: Rule, check: &'static str, left: usize, right: usize) -> Violation {
+    Violation {
+        rule,
+        check,
+        left,
+        right,
+    }
+}
+
+#[test]
+fn primitives_conform() {
+    let f32_values: Vec<f32> = F32_TEST_VECTORS
+        .iter()
+        .flat_map(|v| vec![v.a, v.b])
+        .collect();
+    for &tol in [0.0, f32::EPSILON, 0.5, 2.0, f32::INFINITY].iter() {
+        for &ulps_tol in [0, 1, 4, u32::MAX].iter() {
+            assert_eq!(
+                check_impl(&f32_values, &tol, &ulps_tol, &tol, &ulps_tol),
+                Ok(())
+            );
+        }
+    }
+
+    let f64_values: Vec<f64> = F64_TEST_VECTORS
+        .iter()
+        .flat_map(|v| vec![v.a, v.b])
+        .collect();
+    for &tol in [0.0, f64::EPSILON, 0.5, 2.0, f64::INFINITY].iter() {
+        for &ulps_tol in [0, 1, 4, u64::MAX].iter() {
+            assert_eq!(
+                check_impl(&f64_values, &tol, &ulps_tol, &tol, &ulps_tol),
+                Ok(())
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_values() {
+    assert_eq!(check_impl::<f64>(&[], &0.1, &1, &0.1, &1), Ok(()));
+}
+
+#[test]
+fn violations() {
+    assert_eq!(buggy::<NONE>(), Ok(()));
+    assert_eq!(
+        buggy::<R1ST_IS_R2ND>(),
+        Err(violation(Rule::Symmetric, "r1st", 0, 1))
+    );
+    assert_eq!(
+        buggy::<NE_IS_EQ>(),
+        Err(violation(Rule::Negation, "abs", 0, 0))
+    );
+    assert_eq!(
+        buggy::<REL_IS_RMIN>(),
+        Err(violation(Rule::RelIsRmax, "rel", 0, 1))
+    );
+    // only the ulps debug tolerances are checked with validate-tol
+    let rmax_tol_is_rmin = if cfg!(feature = "validate-tol") {
+        Ok(())
+    } else {
+        Err(violation(Rule::DebugTol, "rel", 0, 1))
+    };
+    assert_eq!(buggy::<RMAX_TOL_IS_RMIN>(), rmax_tol_is_rmin);
+    assert_eq!(
+        buggy::<ABS_DIFF_IS_SIGNED>(),
+        Err(violation(Rule::DebugDiffSymmetric, "abs", 0, 1))
+    );
+    assert_eq!(
+        buggy::<ULPS_ALL_TOL_IS_DOUBLED>(),
+        Err(violation(Rule::DebugTol, "ulps_all", 0, 3))
+    );
+
+    let values = [Buggy::<ABS_IS_STRICT>(1.0), Buggy(2.0)];
+    assert_eq!(
+        check_impl(&values, &Buggy(0.0), &0, &0.0, &0),
+        Err(violation(Rule::Reflexive, "abs", 0, 0))
+    );
+}
+
+#[test]
+fn violation_display() {
+    assert_eq!(
+        violation(Rule::DebugTol, "rmax_all", 2, 5).to_string(),
+        "DebugTol rule broken by the rmax_all check, comparing values 2 and 5"
+    );
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+#[test]
+fn violation_error() {
+    fn check() -> Result<(), Box<dyn std::error::Error>> {
+        buggy::<R1ST_IS_R2ND>()?;
+        Ok(())
+    }
+    assert_eq!(
+        check().unwrap_err().to_string(),
+        "Symmetric rule broken by the r1st check, comparing values 0 and 1"
+    );
+}
+
+#[test]
+fn assert_impl_conforms() {
+    let values = [0.0f64, 1.0, 1.000_000_000_000_000_2, -3.0];
+    assert_impl_conforms!(values, 0.1, 1, 0.1, 1);
+    assert_impl_conforms!(values.to_vec(), 0.1, 1, 0.1, 1,);
+    assert_impl_conforms!(&values[..2], 0.1, 1, 0.1, 1, "with {} values", 2);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `conformance::check_impl(values, t, ulps_t, all_t, ulps_all_t)`
+        rule: `Symmetric`,
+       check: `"r1st"`,
+        left: `Buggy(1.0)`,
+       right: `Buggy(1.3)`"#
+)]
+fn assert_impl_conforms_fail() {
+    let values = [Buggy::<R1ST_IS_R2ND>(1.0), Buggy(1.3)];
+    assert_impl_conforms!(values, Buggy(0.25), 1, 0.25, 1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `conformance::check_impl(values, t, ulps_t, all_t, ulps_all_t)`
+        rule: `Negation`,
+       check: `"abs"`,
+        left: `Buggy(1.0)`,
+       right: `Buggy(1.0)`: for a custom type"#
+)]
+fn assert_impl_conforms_fail_message() {
+    let values = [Buggy::<NE_IS_EQ>(1.0)];
+    assert_impl_conforms!(values, Buggy(0.25), 1, 0.25, 1, "for a {} type", "custom");
+}
@@ -0,0 +1,53 @@
+use float_eq::{ConstAbs, ConstTol, ConstUlps};
+
+fn count<C: ConstTol<f64>>(values: &[f64], x: f64) -> usize {
+    values.iter().filter(|v| C::eq(v, &x)).count()
+}
+
+#[test]
+fn const_ulps() {
+    assert_eq!(ConstUlps::<4>::ULPS, 4);
+
+    assert!(<ConstUlps<0>>::eq(&1.0f32, &1.0));
+    assert!(<ConstUlps<0>>::ne(&1.0f32, &1.000_000_1));
+    assert!(<ConstUlps<1>>::eq(&1.0f32, &1.000_000_1));
+    assert!(<ConstUlps<1>>::ne(&1.0f32, &1.000_000_2));
+    assert!(<ConstUlps<1>>::eq(&-1.0f64, &-1.000_000_000_000_000_2));
+    assert!(<ConstUlps<1>>::ne(&-1.0f64, &-1.000_000_000_000_000_4));
+}
+
+#[test]
+fn const_abs() {
+    assert_eq!(ConstAbs::<1, 4>::tol::<f32>(), 0.25);
+    assert_eq!(ConstAbs::<3>::tol::<f64>(), 3.0);
+    assert_eq!(ConstAbs::<1, 10>::tol::<f64>(), 0.1);
+    assert_eq!(ConstAbs::<1, 10>::tol::<f32>(), 0.1);
+
+    assert!(<ConstAbs<1, 4>>::eq(&1.0f32, &1.25));
+    assert!(<ConstAbs<1, 4>>::ne(&1.0f32, &1.26));
+    assert!(<ConstAbs<2>>::eq(&-1.0f64, &1.0));
+    assert!(<ConstAbs<2>>::ne(&-1.0f64, &1.1));
+}
+
+#[test]
+fn nan() {
+    assert!(<ConstUlps<{ u32::MAX }>>::ne(&f32::NAN, &f32::NAN));
+    assert!(<ConstAbs<{ u64::MAX }>>::ne(&f64::NAN, &1.0));
+}
+
+#[test]
+fn composite() {
+    assert!(<ConstUlps<1>>::eq(&[1.0f32, 2.0], &[1.000_000_1, 2.0]));
+    assert!(<ConstUlps<1>>::ne(&[1.0f32, 2.0], &[1.000_000_2, 2.0]));
+    assert!(<ConstAbs<1, 2>>::eq(&[1.0f64, 2.0], &[1.5, 1.5]));
+    assert!(<ConstAbs<1, 2>>::ne(&[1.0f64, 2.0], &[1.5, 1.4]));
+}
+
+#[test]
+fn generic() {
+    let values = [1.0, 1.000_000_000_000_000_2, 1.5, 2.0];
+    assert_eq!(count::<ConstUlps<0>>(&values, 1.0), 1);
+    assert_eq!(count::<ConstUlps<1>>(&values, 1.0), 2);
+    assert_eq!(count::<ConstAbs<1, 2>>(&values, 1.0), 3);
+    assert_eq!(count::<ConstAbs<1>>(&values, 1.0), 4);
+}
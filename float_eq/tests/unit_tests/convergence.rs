@@ -0,0 +1,156 @@
+use float_eq::{assert_converges, linear_rate_violation, ConvergenceHistory, RateViolation};
+
+#[test]
+fn rate_violation() {
+    let iterates = [2.0f64, 1.5, 1.25, 1.2, 1.01];
+    assert_eq!(linear_rate_violation(&iterates, 1.0, 0.9), None);
+    assert_eq!(
+        linear_rate_violation(&iterates, 1.0, 0.5),
+        Some(RateViolation {
+            index: 2,
+            error: 0.25,
+            next_error: 0.19999999999999996,
+        })
+    );
+
+    let empty: [f32; 0] = [];
+    assert_eq!(linear_rate_violation(&empty, 1.0, 0.5), None);
+    assert_eq!(linear_rate_violation(&[1.0f32], 0.0, 0.5), None);
+
+    // staying at the target satisfies any rate
+    assert_eq!(linear_rate_violation(&[1.0f32, 0.0, 0.0], 0.0, 0.0), None);
+
+    let violation = linear_rate_violation(&[1.0f32, f32::NAN], 0.0, 1.0).unwrap();
+    assert_eq!(violation.index, 0);
+    assert!(violation.next_error.is_nan());
+}
+
+#[test]
+fn violation_rate() {
+    let violation = RateViolation {
+        index: 0,
+        error: 0.5f32,
+        next_error: 0.25,
+    };
+    assert_eq!(violation.rate(), 0.5);
+}
+
+#[test]
+fn history() {
+    let history = ConvergenceHistory::new(&[3.0f32, 2.0, 1.5], 1.0);
+    assert_eq!(
+        format!("{:?}", history),
+        "[(3.0, 2.0), (2.0, 1.0), (1.5, 0.5)]"
+    );
+
+    let empty: [f64; 0] = [];
+    assert_eq!(format!("{:?}", ConvergenceHistory::new(&empty, 1.0)), "[]");
+}
+
+#[test]
+fn assert_converges() {
+    let iterates = [1.0f64, 0.5, 0.25, 0.125];
+
+    assert_converges!(iterates, 0.0, abs <= 0.125);
+    assert_converges!(iterates, 0.0, abs <= 0.125,);
+    assert_converges!(&iterates[..], 0.0, abs <= 0.1, rmax <= 1.0);
+    assert_converges!(iterates.to_vec(), 0.0, abs <= 0.125, "x = {}", 1);
+    assert_converges!(iterates, 0.0, rate <= 0.5, abs <= 0.125);
+    assert_converges!(iterates, 0.0, rate <= 0.5, abs <= 0.125,);
+    assert_converges!(iterates, 0.0, rate <= 0.5, ulps <= 0, abs <= 0.125);
+    assert_converges!(iterates, 0.0, rate <= 0.5, abs <= 0.125, "x = {}", 1);
+    assert_converges!(iterates, 0.0, abs <= 0.125, rmax <= 1.0, "x = {}", 1);
+    assert_converges!(
+        iterates,
+        0.0,
+        abs <= 0.125,
+        rmax <= 1.0,
+        ulps <= 0,
+        "x = {}",
+        1
+    );
+    assert_converges!(
+        iterates,
+        0.0,
+        rate <= 0.5,
+        ulps <= 0,
+        abs <= 0.125,
+        "x = {}",
+        1
+    );
+    assert_converges!(
+        iterates,
+        0.0,
+        rate <= 0.5,
+        ulps <= 0,
+        abs <= 0.125,
+        rmax <= 1.0,
+        "x = {}",
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `0.125`,
+       right: `0.0`,
+    abs_diff: `0.125`,
+   ulps_diff: `Some(4593671619917905920)`,
+     [abs] t: `0.1`: iterates did not converge to the target, history: [(1.0, 1.0), (0.5, 0.5), (0.25, 0.25), (0.125, 0.125)]"#)]
+fn assert_converges_fail() {
+    assert_converges!([1.0f64, 0.5, 0.25, 0.125], 0.0, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"iterates did not converge to the target, history: [(1.0, 1.0), (0.5, 0.5)]: x = 1"#
+)]
+fn assert_converges_fail_message() {
+    assert_converges!([1.0f32, 0.5], 0.0, rate <= 0.5, abs <= 0.1, "x = {}", 1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `converges!(iterates, target, rate <= t, abs <= t)`
+       index: `1`,
+       error: `0.5`,
+  next_error: `0.375`,
+        rate: `0.75`,
+     history: `[(1.0, 1.0), (0.5, 0.5), (0.375, 0.375), (0.125, 0.125)]`,
+    [rate] t: `0.5`"#
+)]
+fn assert_converges_fail_rate() {
+    assert_converges!([1.0f32, 0.5, 0.375, 0.125], 0.0, rate <= 0.5, abs <= 0.125);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `converges!(iterates, target, rate <= t, ulps <= t, abs <= t)`
+       index: `1`,
+       error: `0.5`,
+  next_error: `0.375`,
+        rate: `0.75`,
+     history: `[(1.0, 1.0), (0.5, 0.5), (0.375, 0.375), (0.125, 0.125)]`,
+    [rate] t: `0.5`: x = 1"#
+)]
+fn assert_converges_fail_rate_message() {
+    assert_converges!(
+        [1.0f32, 0.5, 0.375, 0.125],
+        0.0,
+        rate <= 0.5,
+        ulps <= 0,
+        abs <= 0.125,
+        "x = {}",
+        1
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `converges!(iterates, target, abs <= t)`
+    iterates: `[]`: there are no iterates: x = 1"#
+)]
+fn assert_converges_fail_empty() {
+    let empty: [f64; 0] = [];
+    assert_converges!(empty, 0.0, abs <= 0.1, "x = {}", 1);
+}
@@ -3,6 +3,7 @@
 use core::cell::{Cell, RefCell};
 use float_eq::{
     assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq, AssertFloatEqAll,
+    FloatEqBits,
 };
 
 #[allow(clippy::unnecessary_mut_passed)]
@@ -220,6 +221,18 @@ mod refs {
 mod option {
     use super::*;
 
+    #[test]
+    fn float_eq_bits() {
+        let a = Some(-0.0f32);
+        assert!(a.eq_bits(&Some(-0.0)));
+        assert!(a.ne_bits(&Some(0.0)));
+        assert!(a.ne_bits(&None));
+        assert!(None::<f32>.eq_bits(&None));
+        assert_float_eq!(Some(f32::NAN), Some(f32::NAN), bits);
+        assert_float_eq!(None::<f32>, None, bits);
+        assert_float_ne!(Some(0.0f32), None, bits);
+    }
+
     #[test]
     fn float_eq() {
         let a = Some([0.999_999_9f32, 4.0]);
@@ -257,13 +270,15 @@ mod option {
 
         // Same shape None
         let none = Option::<[f32; 2]>::None;
-        assert_float_ne!(none, None, abs <= None);
-        assert_float_ne!(none, None, rel <= None);
-        assert_float_ne!(none, None, rmax <= None);
-        assert_float_ne!(none, None, rmin <= None);
-        assert_float_ne!(none, None, r1st <= None);
-        assert_float_ne!(none, None, r2nd <= None);
-        assert_float_ne!(none, None, ulps <= None);
+        assert_float_eq!(none, None, abs <= None);
+        assert_float_eq!(none, None, rel <= None);
+        assert_float_eq!(none, None, rmax <= None);
+        assert_float_eq!(none, None, rmin <= None);
+        assert_float_eq!(none, None, r1st <= None);
+        assert_float_eq!(none, None, r2nd <= None);
+        assert_float_eq!(none, None, ulps <= None);
+        assert_float_eq!(none, None, abs <= Some([0.0; 2]));
+        assert_float_eq!(none, None, ulps <= Some([0; 2]));
 
         // Differing a/b shape
         assert_float_ne!(a, None, abs <= Some([f32::INFINITY; 2]));
@@ -322,13 +337,15 @@ mod option {
 
         // Same shape None
         let none = Option::<[f32; 2]>::None;
-        assert_float_ne!(none, None, abs_all <= None);
-        assert_float_ne!(none, None, rel_all <= None);
-        assert_float_ne!(none, None, rmax_all <= None);
-        assert_float_ne!(none, None, rmin_all <= None);
-        assert_float_ne!(none, None, r1st_all <= None);
-        assert_float_ne!(none, None, r2nd_all <= None);
-        assert_float_ne!(none, None, ulps_all <= None);
+        assert_float_eq!(none, None, abs_all <= None);
+        assert_float_eq!(none, None, rel_all <= None);
+        assert_float_eq!(none, None, rmax_all <= None);
+        assert_float_eq!(none, None, rmin_all <= None);
+        assert_float_eq!(none, None, r1st_all <= None);
+        assert_float_eq!(none, None, r2nd_all <= None);
+        assert_float_eq!(none, None, ulps_all <= None);
+        assert_float_eq!(none, None, abs_all <= Some(0.0));
+        assert_float_eq!(none, None, ulps_all <= Some(0));
 
         // Differing a/b shape
         assert_float_ne!(a, None, abs_all <= Some(f32::INFINITY));
@@ -490,6 +507,168 @@ mod option {
     }
 }
 
+mod result {
+    use super::*;
+
+    type R = Result<[f32; 2], &'static str>;
+
+    #[test]
+    fn float_eq_bits() {
+        let a: Result<f32, ()> = Ok(-0.0);
+        assert!(a.eq_bits(&Ok(-0.0)));
+        assert!(a.ne_bits(&Ok(0.0)));
+        assert!(a.ne_bits(&Err(())));
+        assert!(Err::<f32, ()>(()).eq_bits(&Err(())));
+        assert_float_eq!(Ok::<f32, ()>(f32::NAN), Ok(f32::NAN), bits);
+    }
+
+    #[test]
+    fn float_eq() {
+        let a: R = Ok([0.999_999_9f32, 4.0]);
+        let b: R = Ok([1.0f32, 3.999_999_5]);
+        let eps = f32::EPSILON;
+
+        // Ok a/b
+        assert_float_eq!(a, b, abs <= [1.0 * eps, 4.0 * eps]);
+        assert_float_ne!(a, b, abs <= [0.5 * eps, 4.0 * eps]);
+
+        assert_float_eq!(a, b, rmax <= [1.0 * eps, 1.0 * eps]);
+        assert_float_ne!(a, b, rmax <= [0.5 * eps, 1.0 * eps]);
+
+        assert_float_eq!(a, b, rmin <= [2.0 * eps, 2.0 * eps]);
+        assert_float_ne!(a, b, rmin <= [1.0 * eps, 2.0 * eps]);
+
+        assert_float_eq!(a, b, r1st <= [2.0 * eps, 1.0 * eps]);
+        assert_float_ne!(a, b, r1st <= [1.0 * eps, 1.0 * eps]);
+
+        assert_float_eq!(a, b, r2nd <= [1.0 * eps, 2.0 * eps]);
+        assert_float_ne!(a, b, r2nd <= [0.5 * eps, 2.0 * eps]);
+
+        assert_float_eq!(a, b, ulps <= [2, 2]);
+        assert_float_ne!(a, b, ulps <= [1, 2]);
+
+        // Err a/b
+        let e: R = Err("e");
+        assert_float_eq!(e, Err("e"), abs <= [0.0; 2]);
+        assert_float_eq!(e, Err("e"), ulps <= [0; 2]);
+        assert_float_ne!(e, Err("f"), abs <= [f32::INFINITY; 2]);
+        assert_float_ne!(e, Err("f"), ulps <= [u32::MAX; 2]);
+
+        // Differing a/b shape
+        assert_float_ne!(a, e, abs <= [f32::INFINITY; 2]);
+        assert_float_ne!(a, e, rmax <= [f32::INFINITY; 2]);
+        assert_float_ne!(a, e, rmin <= [f32::INFINITY; 2]);
+        assert_float_ne!(a, e, r1st <= [f32::INFINITY; 2]);
+        assert_float_ne!(a, e, r2nd <= [f32::INFINITY; 2]);
+        assert_float_ne!(a, e, ulps <= [u32::MAX; 2]);
+        assert_float_ne!(e, a, abs <= [f32::INFINITY; 2]);
+        assert_float_ne!(e, a, ulps <= [u32::MAX; 2]);
+    }
+
+    #[test]
+    fn float_eq_all() {
+        let a: R = Ok([0.999_999_9f32, 4.0]);
+        let b: R = Ok([1.0f32, 3.999_999_5]);
+        let eps = f32::EPSILON;
+
+        // Ok a/b
+        assert_float_eq!(a, b, abs_all <= 4.0 * eps);
+        assert_float_ne!(a, b, abs_all <= 2.0 * eps);
+
+        assert_float_eq!(a, b, rmax_all <= 1.0 * eps);
+        assert_float_ne!(a, b, rmax_all <= 0.5 * eps);
+
+        assert_float_eq!(a, b, rmin_all <= 2.0 * eps);
+        assert_float_ne!(a, b, rmin_all <= 1.0 * eps);
+
+        assert_float_eq!(a, b, r1st_all <= 2.0 * eps);
+        assert_float_ne!(a, b, r1st_all <= 1.0 * eps);
+
+        assert_float_eq!(a, b, r2nd_all <= 2.0 * eps);
+        assert_float_ne!(a, b, r2nd_all <= 1.0 * eps);
+
+        assert_float_eq!(a, b, ulps_all <= 2);
+        assert_float_ne!(a, b, ulps_all <= 1);
+
+        // Err a/b
+        let e: R = Err("e");
+        assert_float_eq!(e, Err("e"), abs_all <= 0.0);
+        assert_float_ne!(e, Err("f"), abs_all <= f32::INFINITY);
+
+        // Differing a/b shape
+        assert_float_ne!(a, e, abs_all <= f32::INFINITY);
+        assert_float_ne!(a, e, ulps_all <= u32::MAX);
+        assert_float_ne!(e, a, abs_all <= f32::INFINITY);
+        assert_float_ne!(e, a, ulps_all <= u32::MAX);
+    }
+
+    #[test]
+    fn debug_diff() {
+        let a: R = Ok([1.0f32, 2.0]);
+        let b: R = Ok([1.5f32, 2.25]);
+        let e: R = Err("e");
+
+        assert_eq!(a.debug_abs_diff(&b), Some([0.5, 0.25]));
+        assert_eq!(
+            a.debug_ulps_diff(&b),
+            Some([Some(4_194_304), Some(1_048_576)])
+        );
+
+        assert_eq!(e.debug_abs_diff(&e), None);
+        assert_eq!(e.debug_ulps_diff(&e), None);
+        assert_eq!(a.debug_abs_diff(&e), None);
+        assert_eq!(e.debug_ulps_diff(&a), None);
+    }
+
+    #[test]
+    fn debug_tol() {
+        let a: R = Ok([2.0f32, 4.25]);
+        let b: R = Ok([2.5f32, 4.0]);
+        let e: R = Err("e");
+        let eps = [0.1, 0.2];
+
+        assert_eq!(a.debug_abs_tol(&b, &eps), Some([0.1, 0.2]));
+        assert_eq!(a.debug_rmax_tol(&b, &eps), Some([0.25, 0.85]));
+        assert_eq!(a.debug_rmin_tol(&b, &eps), Some([0.2, 0.8]));
+        assert_eq!(a.debug_r1st_tol(&b, &eps), Some([0.2, 0.85]));
+        assert_eq!(a.debug_r2nd_tol(&b, &eps), Some([0.25, 0.8]));
+        assert_eq!(a.debug_ulps_tol(&b, &[1, 2]), Some([1, 2]));
+
+        assert_eq!(a.debug_abs_tol(&e, &eps), None);
+        assert_eq!(e.debug_rmax_tol(&a, &eps), None);
+        assert_eq!(e.debug_ulps_tol(&e, &[1, 2]), None);
+    }
+
+    #[test]
+    fn debug_all_tol() {
+        let a: R = Ok([2.0f32, 4.25]);
+        let b: R = Ok([2.5f32, 4.0]);
+        let e: R = Err("e");
+
+        assert_eq!(a.debug_abs_all_tol(&b, &0.2), Some([0.2, 0.2]));
+        assert_eq!(a.debug_rmax_all_tol(&b, &0.2), Some([0.5, 0.85]));
+        assert_eq!(a.debug_rmin_all_tol(&b, &0.2), Some([0.4, 0.8]));
+        assert_eq!(a.debug_r1st_all_tol(&b, &0.2), Some([0.4, 0.85]));
+        assert_eq!(a.debug_r2nd_all_tol(&b, &0.2), Some([0.5, 0.8]));
+        assert_eq!(a.debug_ulps_all_tol(&b, &2), Some([2, 2]));
+
+        assert_eq!(a.debug_abs_all_tol(&e, &0.2), None);
+        assert_eq!(e.debug_ulps_all_tol(&a, &2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `Ok(1.0)`,
+       right: `Err("e")`,
+    abs_diff: `None`,
+   ulps_diff: `None`,
+     [abs] t: `None`"#)]
+    fn assert_fail_message_different_variants() {
+        let a: Result<f32, &str> = Ok(1.0);
+        assert_float_eq!(a, Err("e"), abs <= 0.1);
+    }
+}
+
 mod cell {
     use super::*;
     wrapper_tests!(Cell);
@@ -500,6 +679,99 @@ mod ref_cell {
     wrapper_tests!(RefCell);
 }
 
+macro_rules! range_tests {
+    ($a:expr, $b:expr, $sep:expr) => {
+        #[test]
+        fn float_eq() {
+            let a = $a;
+            let b = $b;
+            let eps = f32::EPSILON;
+
+            assert!(float_eq!(a, b, abs <= 4.0 * eps));
+            assert!(float_ne!(a, b, abs <= 2.0 * eps));
+            assert!(float_eq!(a, b, rmax <= 1.0 * eps));
+            assert!(float_ne!(a, b, rmax <= 0.5 * eps));
+            assert!(float_eq!(a, b, rmin <= 2.0 * eps));
+            assert!(float_ne!(a, b, rmin <= 1.0 * eps));
+            assert!(float_eq!(a, b, r1st <= 2.0 * eps));
+            assert!(float_ne!(a, b, r1st <= 1.0 * eps));
+            assert!(float_eq!(a, b, r2nd <= 2.0 * eps));
+            assert!(float_ne!(a, b, r2nd <= 1.0 * eps));
+            assert!(float_eq!(a, b, ulps <= 2));
+            assert!(float_ne!(a, b, ulps <= 1));
+
+            assert!(float_eq!(a, a, bits));
+            assert!(float_ne!(a, b, bits));
+        }
+
+        #[test]
+        fn float_eq_all() {
+            let a = $a;
+            let b = $b;
+            let eps = f32::EPSILON;
+
+            assert!(float_eq!(a, b, abs_all <= 4.0 * eps));
+            assert!(float_ne!(a, b, abs_all <= 2.0 * eps));
+            assert!(float_eq!(a, b, rmax_all <= 1.0 * eps));
+            assert!(float_ne!(a, b, rmax_all <= 0.5 * eps));
+            assert!(float_eq!(a, b, ulps_all <= 2));
+            assert!(float_ne!(a, b, ulps_all <= 1));
+        }
+
+        #[test]
+        fn debug() {
+            let a = $a;
+            let b = $b;
+            let eps = f32::EPSILON;
+
+            let ends = |start: &dyn core::fmt::Debug, end: &dyn core::fmt::Debug| {
+                format!("{:?}{}{:?}", start, $sep, end)
+            };
+            assert_eq!(
+                format!("{:?}", a.debug_abs_diff(&b)),
+                ends(&(1.0 - 0.999_999_9f32), &(4.0 - 3.999_999_5f32))
+            );
+            assert_eq!(
+                format!("{:?}", a.debug_ulps_diff(&b)),
+                ends(&Some(2), &Some(2))
+            );
+            assert_eq!(format!("{:?}", a.debug_abs_tol(&b, &eps)), ends(&eps, &eps));
+            assert_eq!(
+                format!("{:?}", a.debug_rmax_tol(&b, &eps)),
+                ends(&eps, &(4.0 * eps))
+            );
+            assert_eq!(format!("{:?}", a.debug_ulps_tol(&b, &2)), ends(&2, &2));
+            assert_eq!(
+                format!("{:?}", a.debug_abs_all_tol(&b, &eps)),
+                ends(&eps, &eps)
+            );
+            assert_eq!(format!("{:?}", a.debug_ulps_all_tol(&b, &2)), ends(&2, &2));
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_fail() {
+            assert_float_eq!($a, $b, abs <= 2.0 * f32::EPSILON);
+        }
+
+        #[test]
+        fn assert_pass() {
+            assert_float_eq!($a, $b, abs <= 4.0 * f32::EPSILON);
+            assert_float_ne!($a, $b, ulps <= 1);
+        }
+    };
+}
+
+mod range {
+    use super::*;
+    range_tests!(0.999_999_9f32..4.0, 1.0f32..3.999_999_5, "..");
+}
+
+mod range_inclusive {
+    use super::*;
+    range_tests!(0.999_999_9f32..=4.0, 1.0f32..=3.999_999_5, "..=");
+}
+
 // Note: there are more slice tests in std_types, since only some of the slice
 // comparison implementation is no_std.
 mod slice {
@@ -621,3 +893,134 @@ mod slice {
         assert!(float_ne!(a[..], b[..1], ulps_all <= u32::MAX));
     }
 }
+
+mod chunks_exact {
+    use super::*;
+
+    #[test]
+    fn float_eq() {
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let b = [1.0f32, 2.5, 3.0, 4.25];
+
+        assert!(float_eq!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            abs <= [0.0, 0.5]
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            abs <= [0.0, 0.25]
+        ));
+        assert!(float_eq!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            rmax <= [0.0, 0.2]
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            rmax <= [0.0, 0.1]
+        ));
+        assert!(float_eq!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            ulps <= [0, 2_097_152]
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            ulps <= [0, 524_288]
+        ));
+
+        // Different number of blocks
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b[..2].chunks_exact(2),
+            abs <= [f32::INFINITY; 2]
+        ));
+
+        // Different block or tol size
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(1),
+            abs <= [f32::INFINITY; 2]
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            abs <= [f32::INFINITY]
+        ));
+
+        // The iterators are not consumed
+        let a_chunks = a.chunks_exact(2);
+        let b_chunks = b.chunks_exact(2);
+        assert!(float_eq!(a_chunks, b_chunks, abs <= [0.0, 0.5]));
+        assert_eq!(a_chunks.len(), 2);
+    }
+
+    #[test]
+    fn float_eq_all() {
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let b = [1.0f32, 2.5, 3.0, 4.25];
+
+        assert!(float_eq!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            abs_all <= 0.5
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            abs_all <= 0.25
+        ));
+        assert!(float_eq!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            ulps_all <= 2_097_152
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b.chunks_exact(2),
+            ulps_all <= 524_288
+        ));
+        assert!(float_ne!(
+            a.chunks_exact(2),
+            b[..2].chunks_exact(2),
+            abs_all <= f32::INFINITY
+        ));
+    }
+}
+
+mod windows {
+    use super::*;
+
+    #[test]
+    fn float_eq() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [1.0f32, 2.5, 3.0];
+
+        assert!(float_eq!(a.windows(2), b.windows(2), abs <= [0.5, 0.5]));
+        assert!(float_ne!(a.windows(2), b.windows(2), abs <= [0.5, 0.25]));
+        assert!(float_ne!(a.windows(2), b.windows(2), abs <= [0.25, 0.5]));
+        assert!(float_ne!(
+            a.windows(2),
+            b.windows(3),
+            abs <= [f32::INFINITY; 2]
+        ));
+    }
+
+    #[test]
+    fn float_eq_all() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [1.0f32, 2.5, 3.0];
+
+        assert!(float_eq!(a.windows(2), b.windows(2), abs_all <= 0.5));
+        assert!(float_ne!(a.windows(2), b.windows(2), abs_all <= 0.25));
+        assert!(float_ne!(
+            a.windows(2),
+            b.windows(3),
+            abs_all <= f32::INFINITY
+        ));
+    }
+}
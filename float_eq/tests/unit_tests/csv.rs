@@ -0,0 +1,62 @@
+use float_eq::write_diff_csv;
+use std::io;
+
+fn diff_csv<T: float_eq::FloatPrimitive>(a: &[T], b: &[T]) -> String {
+    let mut csv = Vec::new();
+    write_diff_csv(&mut csv, a, b).unwrap();
+    String::from_utf8(csv).unwrap()
+}
+
+#[test]
+fn empty() {
+    let empty: [f32; 0] = [];
+    assert_eq!(
+        diff_csv(&empty, &empty),
+        "index,a,b,abs_diff,rel_diff,ulps_diff\n"
+    );
+}
+
+#[test]
+fn rows_f32() {
+    let a = [1.0f32, 0.0, 4.0];
+    let b = [1.0f32, -0.0, 3.0];
+    assert_eq!(
+        diff_csv(&a, &b),
+        "index,a,b,abs_diff,rel_diff,ulps_diff\n\
+         0,1,1,0,0,0\n\
+         1,0,-0,0,0,0\n\
+         2,4,3,1,0.25,4194304\n"
+    );
+}
+
+#[test]
+fn rows_f64() {
+    let a = [2.0f64, 1.0];
+    let b = [2.0f64, f64::from_bits(1.0f64.to_bits() + 3)];
+    assert_eq!(
+        diff_csv(&a, &b),
+        "index,a,b,abs_diff,rel_diff,ulps_diff\n\
+         0,2,2,0,0,0\n\
+         1,1,1.0000000000000007,0.0000000000000006661338147750939,0.0000000000000006661338147750935,3\n"
+    );
+}
+
+#[test]
+fn undefined_ulps() {
+    let a = [f32::NAN, 1.0];
+    let b = [1.0f32, -1.0];
+    assert_eq!(
+        diff_csv(&a, &b),
+        "index,a,b,abs_diff,rel_diff,ulps_diff\n\
+         0,NaN,1,NaN,NaN,\n\
+         1,1,-1,2,2,\n"
+    );
+}
+
+#[test]
+fn length_mismatch() {
+    let mut csv = Vec::new();
+    let err = write_diff_csv(&mut csv, &[1.0f32, 2.0], &[1.0f32]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(csv.is_empty());
+}
@@ -0,0 +1,266 @@
+use float_eq::{
+    assert_float_eq_rounded, assert_float_eq_str, eq_rounded, eq_sig_figs, round_to_increment,
+    Rounding,
+};
+
+#[test]
+fn sig_figs() {
+    let pi = core::f64::consts::PI;
+    assert!(eq_sig_figs(pi, "3.14159265358979", 1));
+    assert!(eq_sig_figs(pi, "3.14159265358979", 15));
+    assert!(!eq_sig_figs(pi, "3.14159265358979", 16));
+    assert!(eq_sig_figs(pi, "3.141592653589793238462643383279", 16));
+    assert!(!eq_sig_figs(pi, "3.141592653589793238462643383279", 17));
+    assert!(!eq_sig_figs(-pi, "3.14159265358979", 1));
+    assert!(eq_sig_figs(-pi, "-3.14159265358979", 15));
+}
+
+#[test]
+fn full_precision() {
+    // 0.1 is not exactly representable, which is visible at 17 digits
+    assert!(eq_sig_figs(0.1f64, "0.1", 16));
+    assert!(!eq_sig_figs(0.1f64, "0.1", 17));
+    assert!(eq_sig_figs(
+        0.1f64,
+        "0.1000000000000000055511151231257827",
+        34
+    ));
+    assert!(eq_sig_figs(0.1f32, "0.100000001490116119384765625", 27));
+    assert!(!eq_sig_figs(0.1f32, "0.100000001490116119384765626", 28));
+
+    assert!(eq_sig_figs(f64::MAX, "1.7976931348623157e308", 17));
+    assert!(eq_sig_figs(
+        f64::from_bits(1),
+        "4.9406564584124654e-324",
+        17
+    ));
+}
+
+#[test]
+fn half_unit_tolerance() {
+    assert!(eq_sig_figs(1.25f32, "1.2", 2));
+    assert!(eq_sig_figs(1.15f32 + 0.000_001, "1.2", 2));
+    // 1.15f32 is slightly less than 1.15
+    assert!(!eq_sig_figs(1.15f32, "1.2", 2));
+    assert!(!eq_sig_figs(1.26f32, "1.2", 2));
+    assert!(!eq_sig_figs(1.125f32, "1.2", 2));
+    assert!(eq_sig_figs(0.99f32, "1.0", 1));
+    assert!(eq_sig_figs(9.5f32, "10", 1));
+    assert!(!eq_sig_figs(100.0f32, "1", 1));
+}
+
+#[test]
+fn formats() {
+    assert!(eq_sig_figs(1.5e-19f64, "1.5e-19", 15));
+    assert!(eq_sig_figs(1.5e-19f64, "+0.15E-18", 15));
+    assert!(eq_sig_figs(1500.0f64, "1.5e+3", 15));
+    assert!(eq_sig_figs(1500.0f64, "1500.", 15));
+    assert!(eq_sig_figs(0.5f64, ".5", 15));
+    assert!(eq_sig_figs(0.5f64, " 000.50000 ", 15));
+}
+
+#[test]
+fn zero() {
+    assert!(eq_sig_figs(0.0f32, "0", 3));
+    assert!(eq_sig_figs(-0.0f32, "0.000", 3));
+    assert!(eq_sig_figs(0.0f64, "-0e10", 3));
+    assert!(!eq_sig_figs(f64::from_bits(1), "0", 3));
+    assert!(!eq_sig_figs(0.0f64, "1e-300", 1));
+}
+
+#[test]
+fn non_finite() {
+    assert!(!eq_sig_figs(f32::INFINITY, "1e39", 1));
+    assert!(!eq_sig_figs(f32::NAN, "0", 1));
+}
+
+#[test]
+fn extreme_exponents() {
+    assert!(!eq_sig_figs(1.0f64, "1e-9223372036854775808", 1));
+    assert!(!eq_sig_figs(1e-300f64, "1e9223372036854775807", 1));
+    assert!(!eq_sig_figs(1.0f32, "9e9223372036854775807", 1));
+}
+
+#[test]
+#[should_panic(expected = "`1.0.0` is not a valid decimal number")]
+fn invalid_decimal() {
+    eq_sig_figs(1.0f32, "1.0.0", 3);
+}
+
+#[test]
+#[should_panic(expected = "`1e` is not a valid decimal number")]
+fn invalid_exponent() {
+    eq_sig_figs(1.0f32, "1e", 3);
+}
+
+#[test]
+#[should_panic(expected = "sig_figs must be at least one")]
+fn zero_sig_figs() {
+    eq_sig_figs(1.0f32, "1", 0);
+}
+
+#[test]
+fn assert_float_eq_str() {
+    let pi = core::f64::consts::PI;
+    assert_float_eq_str!(pi, "3.14159265358979", sig_figs <= 12);
+    assert_float_eq_str!(pi, "3.14159265358979", sig_figs <= 12,);
+    assert_float_eq_str!(pi, "3.14159265358979", sig_figs <= 12, "testing {}", pi);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, sig_figs <= t)`
+        left: `3.1415927`,
+       right: `"3.14159265358979"`,
+[sig_figs] t: `8`"#)]
+fn assert_float_eq_str_fail() {
+    assert_float_eq_str!(core::f32::consts::PI, "3.14159265358979", sig_figs <= 8);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, sig_figs <= t)`
+        left: `3.1415927`,
+       right: `"3.14159265358979"`,
+[sig_figs] t: `8`: testing: 3.1415927"#)]
+fn assert_float_eq_str_fail_with_message() {
+    let pi = core::f32::consts::PI;
+    assert_float_eq_str!(pi, "3.14159265358979", sig_figs <= 8, "testing: {}", pi);
+}
+
+#[test]
+fn rounded_half_away_from_zero() {
+    let r = Rounding::HalfAwayFromZero;
+    assert!(eq_rounded(1.004f64, 0.995, 0.01, r));
+    assert!(!eq_rounded(1.004f64, 1.005, 0.01, r));
+    assert!(eq_rounded(0.125f64, 0.13, 0.01, r));
+    assert!(eq_rounded(-0.125f64, -0.13, 0.01, r));
+    assert!(!eq_rounded(-0.125f64, 0.13, 0.01, r));
+    assert!(eq_rounded(0.135f32, 0.14, 0.01, r));
+}
+
+#[test]
+fn rounded_half_even() {
+    let r = Rounding::HalfEven;
+    assert!(eq_rounded(0.125f64, 0.12, 0.01, r));
+    assert!(eq_rounded(0.135f64, 0.14, 0.01, r));
+    assert!(eq_rounded(-0.125f64, -0.12, 0.01, r));
+    assert!(eq_rounded(2.5f32, 2.0, 1.0, r));
+    assert!(eq_rounded(3.5f32, 4.0, 1.0, r));
+    // Only exactly half-way values are rounded to even
+    assert!(eq_rounded(0.125_000_1f64, 0.13, 0.01, r));
+}
+
+#[test]
+fn rounded_as_displayed() {
+    // 2.675 and 1.005 are both stored as slightly less than written
+    assert!(eq_rounded(2.675f64, 2.68, 0.01, Rounding::HalfAwayFromZero));
+    assert!(eq_rounded(1.005f64, 1.01, 0.01, Rounding::HalfAwayFromZero));
+    assert!(eq_rounded(1.005f64, 1.0, 0.01, Rounding::HalfEven));
+    assert!(eq_rounded(0.1f64 + 0.2, 0.3, 0.01, Rounding::HalfEven));
+}
+
+#[test]
+fn rounded_increments() {
+    let r = Rounding::HalfEven;
+    assert!(eq_rounded(10.024f64, 10.0, 0.05, r));
+    assert!(eq_rounded(10.025f64, 10.0, 0.05, r));
+    assert!(eq_rounded(10.075f64, 10.1, 0.05, r));
+    assert!(eq_rounded(1249.0f64, 1000.0, 500.0, r));
+    assert!(eq_rounded(1250.0f64, 1000.0, 500.0, r));
+    assert!(eq_rounded(1750.0f64, 2000.0, 500.0, r));
+    assert!(eq_rounded(0.004f64, 0.0, 0.01, r));
+    assert!(eq_rounded(-0.004f64, 0.0, 0.01, r));
+    assert!(eq_rounded(1e-300f64, 0.0, 0.01, r));
+    assert!(eq_rounded(1e300f64, 1e300, 0.01, r));
+    assert!(!eq_rounded(1e300f64, 1.000_000_000_000_001e300, 0.01, r));
+}
+
+#[test]
+fn rounded_non_finite() {
+    let r = Rounding::HalfEven;
+    assert!(eq_rounded(f64::INFINITY, f64::INFINITY, 0.01, r));
+    assert!(!eq_rounded(f64::INFINITY, f64::NEG_INFINITY, 0.01, r));
+    assert!(!eq_rounded(f64::INFINITY, f64::MAX, 0.01, r));
+    assert!(!eq_rounded(f32::NAN, f32::NAN, 0.01, r));
+    assert!(!eq_rounded(f32::NAN, 0.0, 0.01, r));
+}
+
+#[test]
+fn round_to_increment_values() {
+    let r = Rounding::HalfAwayFromZero;
+    assert_eq!(round_to_increment(2.675f64, 0.01, r), 2.68);
+    assert_eq!(round_to_increment(-2.675f64, 0.01, r), -2.68);
+    assert_eq!(round_to_increment(0.1f64 + 0.2, 0.01, r), 0.3);
+    assert_eq!(round_to_increment(0.004f32, 0.01, r), 0.0);
+    assert!(round_to_increment(-0.004f32, 0.01, r).is_sign_positive());
+    assert_eq!(round_to_increment(12.3f32, 0.25, r), 12.25);
+    assert_eq!(round_to_increment(f64::MAX, 1e308, r), f64::INFINITY);
+    assert_eq!(
+        round_to_increment(f64::NEG_INFINITY, 0.01, r),
+        f64::NEG_INFINITY
+    );
+    assert!(round_to_increment(f64::NAN, 0.01, r).is_nan());
+}
+
+#[test]
+#[should_panic(expected = "increment must be positive and finite, not 0")]
+fn zero_increment() {
+    eq_rounded(1.0f64, 1.0, 0.0, Rounding::HalfEven);
+}
+
+#[test]
+#[should_panic(expected = "increment must be positive and finite, not NaN")]
+fn nan_increment() {
+    round_to_increment(1.0f32, f32::NAN, Rounding::HalfEven);
+}
+
+#[test]
+fn assert_float_eq_rounded() {
+    assert_float_eq_rounded!(2.675, 2.68, increment = 0.01);
+    assert_float_eq_rounded!(2.675, 2.68, increment = 0.01,);
+    assert_float_eq_rounded!(2.675, 2.68, increment = 0.01, "testing {}", 1);
+    assert_float_eq_rounded!(0.125, 0.12, increment = 0.01, rounding = Rounding::HalfEven);
+    assert_float_eq_rounded!(0.125, 0.12, increment = 0.01, rounding = Rounding::HalfEven,);
+    assert_float_eq_rounded!(
+        0.125,
+        0.12,
+        increment = 0.01,
+        rounding = Rounding::HalfEven,
+        "testing {}",
+        1
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"`float_eq_rounded!(left, right, increment = t, rounding = r)`
+        left: `0.125`,
+       right: `0.12`,
+rounded_left: `0.13`,
+rounded_right: `0.12`,
+[increment] t: `0.01`,
+    rounding: `HalfAwayFromZero`"#
+)]
+fn assert_float_eq_rounded_fail() {
+    assert_float_eq_rounded!(0.125, 0.12, increment = 0.01);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"`float_eq_rounded!(left, right, increment = t, rounding = r)`
+        left: `1.25`,
+       right: `1.5`,
+rounded_left: `1.0`,
+rounded_right: `2.0`,
+[increment] t: `1.0`,
+    rounding: `HalfEven`: testing: 1"#
+)]
+fn assert_float_eq_rounded_fail_with_message() {
+    assert_float_eq_rounded!(
+        1.25f32,
+        1.5,
+        increment = 1.0,
+        rounding = Rounding::HalfEven,
+        "testing: {}",
+        1
+    );
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "chrono")]
+use chrono::Duration as ChronoDuration;
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+#[cfg(feature = "time")]
+use time::Duration as TimeDuration;
+
+macro_rules! duration_tests {
+    ($name:ident, $t:ty, $millis:path, $nanos:path) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn float_eq() {
+                let a: $t = $millis(1500);
+                let b: $t = $millis(1501);
+
+                assert_float_eq!(a, b, abs <= 0.001_000_1);
+                assert_float_ne!(a, b, abs <= 0.000_999_9);
+                assert_float_eq!(a, b, rmax <= 0.000_667);
+                assert_float_ne!(a, b, rmax <= 0.000_666);
+                assert_float_eq!(a, b, rmin <= 0.000_667);
+                assert_float_ne!(a, b, rmin <= 0.000_666);
+                assert_float_eq!(a, b, r1st <= 0.000_667);
+                assert_float_eq!(a, b, r2nd <= 0.000_667);
+                assert_float_eq!(a, a, ulps <= 0);
+                assert_float_eq!($nanos(1), $nanos(1), ulps <= 0);
+                // the tightest not equal check warns, but is wanted here
+                #[allow(deprecated)]
+                {
+                    assert_float_ne!($nanos(1), $nanos(2), ulps <= 0);
+                }
+
+                // negative durations keep their fractional part
+                let c: $t = $millis(-1500);
+                assert_float_eq!(c, $millis(-1501), abs <= 0.001_000_1);
+                assert_float_ne!(c, $millis(-1000), abs <= 0.4);
+                assert_float_ne!(c, a, abs <= 2.999);
+            }
+
+            #[test]
+            fn float_eq_all() {
+                let a: $t = $millis(1500);
+                let b: $t = $millis(1501);
+
+                assert_float_eq!(a, b, abs_all <= 0.001_000_1);
+                assert_float_ne!(a, b, abs_all <= 0.000_999_9);
+                assert_float_eq!(a, b, rmax_all <= 0.000_667);
+                assert_float_ne!(a, b, rmax_all <= 0.000_666);
+                assert_float_eq!(a, a, ulps_all <= 0);
+            }
+
+            #[test]
+            fn debug() {
+                let a: $t = $millis(2000);
+                let b: $t = $millis(2500);
+
+                assert_eq!(a.debug_abs_diff(&b), 0.5);
+                assert_eq!(a.debug_ulps_diff(&b), Some(1_125_899_906_842_624));
+                assert_eq!(a.debug_abs_tol(&b, &0.1), 0.1);
+                assert_eq!(a.debug_rmax_tol(&b, &0.1), 0.25);
+                assert_eq!(a.debug_rmin_tol(&b, &0.1), 0.2);
+                assert_eq!(a.debug_r1st_tol(&b, &0.1), 0.2);
+                assert_eq!(a.debug_r2nd_tol(&b, &0.1), 0.25);
+                assert_eq!(a.debug_ulps_tol(&b, &3), 3);
+                assert_eq!(a.debug_rmax_all_tol(&b, &0.1), 0.25);
+                assert_eq!(a.debug_ulps_all_tol(&b, &3), 3);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "time")]
+duration_tests!(
+    time_duration,
+    TimeDuration,
+    TimeDuration::milliseconds,
+    TimeDuration::nanoseconds
+);
+
+#[cfg(feature = "chrono")]
+duration_tests!(
+    chrono_duration,
+    ChronoDuration,
+    ChronoDuration::milliseconds,
+    ChronoDuration::nanoseconds
+);
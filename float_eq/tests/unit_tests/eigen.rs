@@ -0,0 +1,239 @@
+use float_eq::{
+    assert_float_eq, assert_positive_definite, eigenvalues_at_least, symmetric_eigenvalues,
+};
+
+#[test]
+fn eigenvalues() {
+    let empty: [[f64; 0]; 0] = [];
+    assert!(symmetric_eigenvalues(&empty).is_empty());
+    assert_eq!(symmetric_eigenvalues(&[[2.5f32]]), vec![2.5]);
+    assert_eq!(
+        symmetric_eigenvalues(&[[3.0f64, 0.0], [0.0, -1.0]]),
+        vec![-1.0, 3.0]
+    );
+
+    let eigenvalues = symmetric_eigenvalues(&[[2.0f64, 1.0], [1.0, 2.0]]);
+    assert_float_eq!(eigenvalues, vec![1.0, 3.0], ulps_all <= 4);
+
+    let eigenvalues =
+        symmetric_eigenvalues(&[[2.0f64, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    let root2 = core::f64::consts::SQRT_2;
+    assert_float_eq!(
+        eigenvalues,
+        vec![2.0 - root2, 2.0, 2.0 + root2],
+        abs_all <= 1e-15
+    );
+}
+
+#[test]
+fn eigenvalues_of_symmetric_part() {
+    let eigenvalues = symmetric_eigenvalues(&[[2.0f64, 0.0], [2.0, 2.0]]);
+    assert_float_eq!(eigenvalues, vec![1.0, 3.0], ulps_all <= 4);
+}
+
+#[test]
+fn eigenvalues_nan() {
+    let eigenvalues = symmetric_eigenvalues(&[[1.0f32, f32::NAN], [f32::NAN, 1.0]]);
+    assert!(eigenvalues[0].is_nan());
+}
+
+#[test]
+fn at_least() {
+    assert!(eigenvalues_at_least::<f64>(&[], 0.0));
+    assert!(eigenvalues_at_least(&[0.0f64, 1.0], 0.0));
+    assert!(eigenvalues_at_least(&[-1e-12f64, 1.0], -1e-10));
+    assert!(!eigenvalues_at_least(&[-1e-9f64, 1.0], -1e-10));
+    assert!(!eigenvalues_at_least(&[f32::NAN, 1.0], -1.0));
+}
+
+#[test]
+fn positive_definite() {
+    let m = [[4.0f64, 2.0, 0.6], [2.0, 2.0, 0.4], [0.6, 0.4, 1.0]];
+    assert_positive_definite!(m, eig_floor <= 0.0);
+    assert_positive_definite!(m, eig_floor <= 0.0,);
+    assert_positive_definite!(m, eig_floor <= 0.5, "testing {}", 1);
+
+    // singular, with a smallest eigenvalue that round-off may make negative
+    let x = [0.1f64, 0.2, 0.3];
+    let mut outer = [[0.0f64; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            outer[i][j] = x[i] * x[j];
+        }
+    }
+    assert_positive_definite!(outer, eig_floor <= -1e-15);
+
+    let empty: [[f32; 0]; 0] = [];
+    assert_positive_definite!(empty, eig_floor <= 1.0);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `positive_definite!(m, eig_floor <= t)`
+    smallest: `-0.9999999999999998`,
+ eigenvalues: `[-0.9999999999999998, 2.9999999999999996]`,
+[eig_floor] t: `-1e-10`"#
+)]
+fn positive_definite_fail() {
+    assert_positive_definite!([[1.0f64, 2.0], [2.0, 1.0]], eig_floor <= -1e-10);
+}
+
+#[test]
+#[should_panic(expected = r#"[eig_floor] t: `0.0`: testing 2"#)]
+fn positive_definite_fail_message() {
+    assert_positive_definite!(
+        [[1.0f32, f32::NAN], [0.0, 1.0]],
+        eig_floor <= 0.0,
+        "testing {}",
+        2
+    );
+}
+
+#[cfg(feature = "num")]
+mod complex {
+    use float_eq::{assert_complex_eigenvalues_eq, match_complex_eigenvalues, EigenvalueMismatch};
+    use num_complex::{Complex32, Complex64};
+
+    #[test]
+    fn match_eigenvalues() {
+        let empty: [Complex64; 0] = [];
+        assert_eq!(match_complex_eigenvalues(&empty, &empty, 0.0), Ok(vec![]));
+
+        let actual = [
+            Complex64::new(1.0, 2.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, -2.0),
+        ];
+        let expected = [
+            Complex64::new(1.0, -2.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(-1.0, 0.0),
+        ];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.0),
+            Ok(vec![2, 0, 1])
+        );
+    }
+
+    #[test]
+    fn match_eigenvalues_within_tol() {
+        let actual = [
+            Complex32::new(2.0, 1e-7),
+            Complex32::new(0.5, 1.0001),
+            Complex32::new(0.5001, -1.0),
+        ];
+        let expected = [
+            Complex32::new(0.5, 1.0),
+            Complex32::new(0.5, -1.0),
+            Complex32::new(2.0, 0.0),
+        ];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.001),
+            Ok(vec![1, 2, 0])
+        );
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 1e-6),
+            Err(EigenvalueMismatch::Unpaired {
+                index: 1,
+                value: Complex32::new(0.5, 1.0001)
+            })
+        );
+    }
+
+    #[test]
+    fn match_repeated_eigenvalues() {
+        // the first expected value may match either actual one, so the
+        // matching must be revised to fit the second
+        let actual = [Complex64::new(1.0, 0.0), Complex64::new(1.1, 0.0)];
+        let expected = [Complex64::new(1.05, 0.0), Complex64::new(1.0, 0.0)];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.06),
+            Ok(vec![1, 0])
+        );
+
+        let actual = [Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)];
+        let expected = [Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.1),
+            Err(EigenvalueMismatch::Unmatched {
+                index: 1,
+                value: Complex64::new(1.0, 0.0)
+            })
+        );
+    }
+
+    #[test]
+    fn match_eigenvalues_mismatch() {
+        let one = [Complex64::new(1.0, 0.0)];
+        let two = [Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)];
+        assert_eq!(
+            match_complex_eigenvalues(&one, &two, 0.1),
+            Err(EigenvalueMismatch::Len {
+                actual: 1,
+                expected: 2
+            })
+        );
+
+        let actual = [Complex64::new(3.0, 0.0), Complex64::new(1.0, -2.0)];
+        let expected = [Complex64::new(3.0, 0.0), Complex64::new(1.0, -2.0)];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.1),
+            Err(EigenvalueMismatch::Unpaired {
+                index: 1,
+                value: Complex64::new(1.0, -2.0)
+            })
+        );
+
+        let actual = [Complex64::new(1.0, 2.0), Complex64::new(1.0, -2.0)];
+        let expected = [Complex64::new(1.0, 2.0), Complex64::new(1.0, 3.0)];
+        assert_eq!(
+            match_complex_eigenvalues(&actual, &expected, 0.1),
+            Err(EigenvalueMismatch::Unmatched {
+                index: 1,
+                value: Complex64::new(1.0, 3.0)
+            })
+        );
+
+        let nan = f64::NAN;
+        let actual = [Complex64::new(nan, 0.0)];
+        assert!(match_complex_eigenvalues(&actual, &actual, 1.0).is_err());
+    }
+
+    #[test]
+    fn complex_eigenvalues_eq() {
+        let actual = vec![
+            Complex64::new(0.0, -2.0),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(0.0, 2.0),
+        ];
+        let expected = [
+            Complex64::new(3.0, 0.0),
+            Complex64::new(0.0, 2.0),
+            Complex64::new(0.0, -2.0),
+        ];
+        assert_complex_eigenvalues_eq!(actual, expected, abs <= 0.0);
+        assert_complex_eigenvalues_eq!(actual, expected, abs <= 0.0,);
+        assert_complex_eigenvalues_eq!(&actual[..], expected, abs <= 0.0, "testing {}", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `complex_eigenvalues_eq!(left, right, abs <= t)`
+        left: `[Complex { re: 1.0, im: 2.0 }]`,
+       right: `[Complex { re: 1.0, im: 2.0 }]`,
+    mismatch: `Unpaired { index: 0, value: Complex { re: 1.0, im: 2.0 } }`,
+     [abs] t: `0.1`"#
+    )]
+    fn complex_eigenvalues_eq_fail() {
+        let a = [Complex64::new(1.0, 2.0)];
+        assert_complex_eigenvalues_eq!(a, a, abs <= 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"[abs] t: `0.5`: testing 2"#)]
+    fn complex_eigenvalues_eq_fail_message() {
+        let a = [Complex32::new(1.0, 0.0)];
+        let b = [Complex32::new(2.0, 0.0)];
+        assert_complex_eigenvalues_eq!(a, b, abs <= 0.5, "testing {}", 2);
+    }
+}
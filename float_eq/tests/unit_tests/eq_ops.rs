@@ -0,0 +1,72 @@
+use float_eq::{Abs, R1st, R2nd, Rmax, Rmin, Ulps};
+
+#[test]
+fn abs() {
+    assert!(Abs(1.0f32, 0.5) == 1.5);
+    assert!(Abs(1.0f32, 0.5) != 1.6);
+    assert!(Abs(1.0f64, 0.5) == 0.5);
+    assert!(Abs(1.0f64, 0.5) != 0.4);
+}
+
+#[test]
+fn rmax() {
+    assert!(Rmax(4.0f32, 0.25) == 3.0);
+    assert!(Rmax(3.0f32, 0.25) == 4.0);
+    assert!(Rmax(4.0f32, 0.25) != 2.9);
+}
+
+#[test]
+fn rmin() {
+    assert!(Rmin(4.0f32, 0.5) == 3.0);
+    assert!(Rmin(4.0f32, 0.25) != 3.1);
+}
+
+#[test]
+fn r1st() {
+    assert!(R1st(4.0f32, 0.25) == 3.0);
+    assert!(R1st(3.0f32, 0.25) != 4.0);
+}
+
+#[test]
+fn r2nd() {
+    assert!(R2nd(3.0f32, 0.25) == 4.0);
+    assert!(R2nd(4.0f32, 0.25) != 3.0);
+}
+
+#[test]
+fn ulps() {
+    assert!(Ulps(1.0f32, 1) == 1.000_000_1);
+    assert!(Ulps(1.0f32, 1) != 1.000_000_2);
+    assert!(Ulps(-1.0f64, 0) == -1.0);
+    assert!(Ulps(1.0f64, 0) != -1.0);
+}
+
+#[test]
+fn nan() {
+    let (nan32, nan64) = (f32::NAN, f64::NAN);
+    assert!(Abs(nan32, f32::INFINITY) != nan32);
+    assert!(Rmax(1.0f32, 1.0) != nan32);
+    assert!(Ulps(nan64, u64::MAX) != nan64);
+}
+
+#[test]
+fn composite() {
+    assert!(Abs([1.0f32, 2.0], [0.1, 0.2]) == [1.05, 1.85]);
+    assert!(Abs([1.0f32, 2.0], [0.1, 0.2]) != [1.05, 1.7]);
+    assert!(Ulps((1.0f32, 2.0f64), (1, 0)) == (1.000_000_1, 2.0));
+    assert!(Ulps((1.0f32, 2.0f64), (1, 0)) != (1.0, 2.000_000_000_000_001));
+}
+
+#[test]
+fn closures() {
+    let values = [1.0f64, 2.0, 3.0];
+    assert_eq!(values.iter().position(|&x| Abs(x, 0.1) == 2.05), Some(1));
+    assert_eq!(values.iter().filter(|&&x| Rmax(x, 0.5) == 2.0).count(), 3);
+    assert_eq!(values.iter().position(|&x| Ulps(x, 4) == 4.0), None);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", Abs(1.0f32, 0.5f32)), "Abs(1.0, 0.5)");
+    assert_eq!(format!("{:?}", Ulps(1.0f32, 2u32)), "Ulps(1.0, 2)");
+}
@@ -0,0 +1,74 @@
+use float_eq::{assert_float_eq_fixed, eq_fixed, QFixed};
+
+#[test]
+fn q_formats() {
+    let q15 = QFixed::q15(-0x8000);
+    assert_eq!((q15.raw(), q15.frac_bits()), (-0x8000, 15));
+    assert_eq!(q15.to_f64(), -1.0);
+    assert_eq!(QFixed::q15(0x7fff).to_f64(), 1.0 - 1.0 / 32768.0);
+
+    let q31 = QFixed::q31(0x4000_0000);
+    assert_eq!((q31.raw(), q31.frac_bits()), (0x4000_0000, 31));
+    assert_eq!(q31.to_f64(), 0.5);
+    assert_eq!(q31.lsb(), 1.0 / 2_147_483_648.0);
+
+    assert_eq!(QFixed::new(5, 0).to_f64(), 5.0);
+    assert_eq!(QFixed::new(1, 63).lsb(), 1.0 / 9_223_372_036_854_775_808.0);
+}
+
+#[test]
+#[should_panic(expected = "a Q format has at most 63 fractional bits, not 64")]
+fn too_many_frac_bits() {
+    QFixed::new(1, 64);
+}
+
+#[test]
+fn lsb_diff() {
+    let q = QFixed::new(-12, 4); // -0.75
+    assert_eq!(q.lsb_diff(-0.75), 0.0);
+    assert_eq!(q.lsb_diff(-0.75f32 + 0.0625), 1.0);
+    assert_eq!(q.lsb_diff(-0.75 - 0.03125), 0.5);
+    assert_eq!(q.lsb_diff(0.75), 24.0);
+    assert_eq!(q.lsb_diff(f64::INFINITY), f64::INFINITY);
+    assert_eq!(q.lsb_diff(f64::NEG_INFINITY), f64::INFINITY);
+    assert!(q.lsb_diff(f32::NAN).is_nan());
+}
+
+#[test]
+fn eq() {
+    let q = QFixed::q15(9830);
+    assert!(eq_fixed(0.3, q, 0.5));
+    assert!(eq_fixed(0.3f32, q, 0.5));
+    assert!(!eq_fixed(0.3, q, 0.39));
+    assert!(!eq_fixed(-0.3, q, 100.0));
+    assert!(eq_fixed(0.0f32, QFixed::q31(0), 0.0));
+    assert!(eq_fixed(-0.0f32, QFixed::q31(0), 0.0));
+    assert!(!eq_fixed(f64::NAN, q, f64::INFINITY));
+    assert!(!eq_fixed(f64::INFINITY, q, f64::MAX));
+}
+
+#[test]
+fn assert_float_eq_fixed() {
+    let x = 0.3f32;
+    assert_float_eq_fixed!(x, QFixed::q15(9830), lsb <= 0.5);
+    assert_float_eq_fixed!(x, QFixed::q15(9830), lsb <= 0.5,);
+    assert_float_eq_fixed!(x, QFixed::q15(9830), lsb <= 0.5, "testing {}", x);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, lsb <= t)`
+        left: `0.5`,
+       right: `QFixed { raw: 16385, frac_bits: 15 }`,
+ right_value: `0.500030517578125`,
+    lsb_diff: `1.0`,
+     [lsb] t: `0.5`"#)]
+fn assert_float_eq_fixed_fail() {
+    assert_float_eq_fixed!(0.5f32, QFixed::q15(16385), lsb <= 0.5);
+}
+
+#[test]
+#[should_panic(expected = r#"     [lsb] t: `0.5`: testing: 0.5"#)]
+fn assert_float_eq_fixed_fail_with_message() {
+    let x = 0.5;
+    assert_float_eq_fixed!(x, QFixed::q15(16385), lsb <= 0.5, "testing: {}", x);
+}
@@ -0,0 +1,292 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, debug_assert_float_eq, debug_assert_float_ne, float_eq,
+    float_ne, CheckSet, FloatCmp,
+};
+
+fn is_close(cmp: &FloatCmp<f32>, a: f32, b: f32) -> bool {
+    cmp.eq(&a, &b)
+}
+
+#[test]
+fn new() {
+    let cmp = FloatCmp::<f64>::new();
+    assert_eq!(cmp, FloatCmp::default());
+    assert_eq!(cmp.abs_tol(), None);
+    assert_eq!(cmp.rel_tol(), None);
+    assert_eq!(cmp.rmax_tol(), None);
+    assert_eq!(cmp.rmin_tol(), None);
+    assert_eq!(cmp.r1st_tol(), None);
+    assert_eq!(cmp.r2nd_tol(), None);
+    assert_eq!(cmp.ulps_tol(), None);
+
+    // without any checks, nothing is equal
+    assert!(!cmp.eq(&1.0, &1.0));
+    assert!(cmp.ne(&1.0, &1.0));
+}
+
+#[test]
+fn builder() {
+    let cmp = FloatCmp::new()
+        .abs(0.1f32)
+        .rel(0.2)
+        .rmax(0.3)
+        .rmin(0.4)
+        .r1st(0.5)
+        .r2nd(0.6)
+        .ulps(7);
+    assert_eq!(cmp.abs_tol(), Some(&0.1));
+    assert_eq!(cmp.rel_tol(), Some(&0.2));
+    assert_eq!(cmp.rmax_tol(), Some(&0.3));
+    assert_eq!(cmp.rmin_tol(), Some(&0.4));
+    assert_eq!(cmp.r1st_tol(), Some(&0.5));
+    assert_eq!(cmp.r2nd_tol(), Some(&0.6));
+    assert_eq!(cmp.ulps_tol(), Some(&7));
+
+    // later tolerances replace earlier ones
+    assert_eq!(cmp.abs(0.01).abs_tol(), Some(&0.01));
+}
+
+#[test]
+fn checks() {
+    assert_eq!(FloatCmp::<f32>::new().checks(), CheckSet::NONE);
+    assert_eq!(
+        FloatCmp::new().abs(0.1f64).ulps(2).checks(),
+        CheckSet::ABS.union(CheckSet::ULPS)
+    );
+    assert_eq!(FloatCmp::new().rel(0.1f64).checks(), CheckSet::RMAX);
+    assert_eq!(
+        FloatCmp::new().rmin(0.1f32).r1st(0.1).r2nd(0.1).checks(),
+        CheckSet::RMIN.union(CheckSet::R1ST).union(CheckSet::R2ND)
+    );
+    assert!(FloatCmp::new()
+        .rmax(0.1f32)
+        .unsupported_checks::<f32, f32>()
+        .is_empty());
+}
+
+#[test]
+fn eq_single_check() {
+    let eps = f32::EPSILON;
+    let a = 0.999_999_9f32;
+    let b = 1.0f32;
+
+    let check = |cmp: FloatCmp<f32>, is_eq: bool| {
+        assert_eq!(cmp.eq(&a, &b), is_eq);
+        assert_eq!(cmp.ne(&a, &b), !is_eq);
+    };
+    check(FloatCmp::new().abs(1.0 * eps), true);
+    check(FloatCmp::new().abs(0.5 * eps), false);
+    check(FloatCmp::new().rel(1.0 * eps), true);
+    check(FloatCmp::new().rel(0.5 * eps), false);
+    check(FloatCmp::new().rmax(1.0 * eps), true);
+    check(FloatCmp::new().rmax(0.5 * eps), false);
+    check(FloatCmp::new().rmin(2.0 * eps), true);
+    check(FloatCmp::new().rmin(1.0 * eps), false);
+    check(FloatCmp::new().r1st(2.0 * eps), true);
+    check(FloatCmp::new().r1st(1.0 * eps), false);
+    check(FloatCmp::new().r2nd(1.0 * eps), true);
+    check(FloatCmp::new().r2nd(0.5 * eps), false);
+    check(FloatCmp::new().ulps(2), true);
+    check(FloatCmp::new().ulps(1), false);
+}
+
+#[test]
+fn eq_any_check() {
+    let cmp = FloatCmp::new().abs(0.001f32).ulps(4);
+
+    // abs passes near zero, where ulps fails
+    assert!(is_close(&cmp, 0.0, 0.000_5));
+    // ulps passes for large values, where abs fails
+    assert!(is_close(&cmp, 1_000_000.0, 1_000_000.06));
+    // neither passes
+    assert!(!is_close(&cmp, 1.0, 1.01));
+}
+
+#[test]
+fn eq_all() {
+    let cmp = FloatCmp::new().rmax(0.1f64).ulps(1);
+    let a = [1.0f64, 10.0];
+    let b = [1.05f64, 10.5];
+
+    assert!(cmp.eq_all(&a, &b));
+    assert!(!cmp.ne_all(&a, &b));
+    assert!(!cmp.eq_all(&a, &[1.2, 10.0]));
+    assert!(cmp.ne_all(&a, &[1.2, 10.0]));
+}
+
+#[test]
+fn debug_tol() {
+    let cmp = FloatCmp::new()
+        .abs(0.1f32)
+        .rel(0.1)
+        .rmax(0.1)
+        .rmin(0.1)
+        .r1st(0.1)
+        .r2nd(0.1)
+        .ulps(3);
+    let expected = FloatCmp::new()
+        .abs(0.1f32)
+        .rel(0.4)
+        .rmax(0.4)
+        .rmin(0.2)
+        .r1st(0.2)
+        .r2nd(0.4)
+        .ulps(3);
+    assert_eq!(cmp.debug_tol(&2.0f32, &4.0), expected);
+    assert_eq!(
+        FloatCmp::new().abs(0.1f32).debug_tol(&2.0f32, &4.0),
+        FloatCmp::new().abs(0.1)
+    );
+
+    let cmp = FloatCmp::new().rmax(0.5f32).ulps(2);
+    assert_eq!(
+        cmp.debug_all_tol(&[1.0f32, 4.0], &[2.0, 3.0]),
+        FloatCmp::new().rmax([1.0, 2.0]).ulps([2, 2])
+    );
+}
+
+#[test]
+fn float_eq_macros() {
+    let cmp = FloatCmp::new().abs(0.1f64).ulps(1);
+    assert!(float_eq!(1.0, 1.05, tol(&cmp)));
+    assert!(float_eq!(1.0, 1.05, tol(cmp),));
+    assert!(!float_ne!(1.0, 1.05, tol(&cmp)));
+    assert!(float_ne!(1.0, 1.5, tol(&cmp),));
+
+    assert!(float_eq!([1.0, 2.0], [1.05, 2.05], tol_all(&cmp)));
+    assert!(float_eq!([1.0, 2.0], [1.05, 2.05], tol_all(cmp),));
+    assert!(!float_ne!([1.0, 2.0], [1.05, 2.05], tol_all(&cmp)));
+    assert!(float_ne!([1.0, 2.0], [1.05, 2.5], tol_all(&cmp),));
+}
+
+#[test]
+fn assert_macros() {
+    let cmp = FloatCmp::new().abs(0.1f64).ulps(1);
+    assert_float_eq!(1.0, 1.05, tol(&cmp));
+    assert_float_eq!(1.0, 1.05, tol(&cmp),);
+    assert_float_eq!(1.0, 1.05, tol(&cmp), "message {}", 1);
+    assert_float_ne!(1.0, 1.5, tol(&cmp));
+    assert_float_ne!(1.0, 1.5, tol(&cmp),);
+    assert_float_ne!(1.0, 1.5, tol(&cmp), "message {}", 1);
+    debug_assert_float_eq!(1.0, 1.05, tol(&cmp));
+    debug_assert_float_ne!(1.0, 1.5, tol(&cmp));
+
+    assert_float_eq!([1.0, 2.0], [1.05, 2.05], tol_all(&cmp));
+    assert_float_eq!([1.0, 2.0], [1.05, 2.05], tol_all(&cmp),);
+    assert_float_eq!([1.0, 2.0], [1.05, 2.05], tol_all(&cmp), "message {}", 1);
+    assert_float_ne!([1.0, 2.0], [1.05, 2.5], tol_all(&cmp));
+    assert_float_ne!([1.0, 2.0], [1.05, 2.5], tol_all(&cmp),);
+    assert_float_ne!([1.0, 2.0], [1.05, 2.5], tol_all(&cmp), "message {}", 1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, tol(cmp))`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [tol] t: `FloatCmp { abs: Some(0.1), rel: None, rmax: Some(0.15), rmin: None, r1st: None, r2nd: None, ulps: Some(4) }`"#)]
+fn assert_fail_message() {
+    let cmp = FloatCmp::new().abs(0.1f32).rmax(0.1).ulps(4);
+    assert_float_eq!(1.0f32, 1.5, tol(&cmp));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_ne!(left, right, tol(cmp))`
+        left: `1.0`,
+       right: `1.05`,
+    abs_diff: `0.049999952`,
+   ulps_diff: `Some(419430)`,
+     [tol] t: `FloatCmp { abs: Some(0.1), rel: None, rmax: None, rmin: None, r1st: None, r2nd: None, ulps: None }`: testing"#)]
+fn assert_ne_fail_message() {
+    let cmp = FloatCmp::new().abs(0.1f32);
+    assert_float_ne!(1.0f32, 1.05, tol(&cmp), "testing");
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, tol_all(cmp))`
+        left: `[1.0, 2.0]`,
+       right: `[1.5, 2.0]`,
+    abs_diff: `[0.5, 0.0]`,
+   ulps_diff: `[Some(4194304), Some(0)]`,
+ [tol_all] t: `FloatCmp { abs: None, rel: None, rmax: Some([0.15, 0.2]), rmin: None, r1st: None, r2nd: None, ulps: None }`"#)]
+fn assert_all_fail_message() {
+    let cmp = FloatCmp::new().rmax(0.1f32);
+    assert_float_eq!([1.0f32, 2.0], [1.5, 2.0], tol_all(&cmp));
+}
+
+#[test]
+fn display() {
+    assert_eq!(FloatCmp::<f64>::new().to_string(), "");
+    assert_eq!(FloatCmp::new().abs(0.1f32).to_string(), "abs <= 0.1");
+    assert_eq!(
+        FloatCmp::new()
+            .abs(1e-9f64)
+            .rel(0.5)
+            .rmax(1e-6)
+            .rmin(2.0)
+            .r1st(3.0)
+            .r2nd(4.0)
+            .ulps(4)
+            .to_string(),
+        "abs <= 1e-9, rel <= 0.5, rmax <= 1e-6, rmin <= 2.0, r1st <= 3.0, r2nd <= 4.0, ulps <= 4"
+    );
+    assert_eq!(
+        FloatCmp::new().rmax([0.1f32, 0.2]).ulps([1, 2]).to_string(),
+        "rmax <= [0.1, 0.2], ulps <= [1, 2]"
+    );
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("abs <= 0.1".parse(), Ok(FloatCmp::new().abs(0.1f32)));
+    assert_eq!(
+        " ulps<=4 ,rmax <= 1e-6 ".parse(),
+        Ok(FloatCmp::new().rmax(1e-6f64).ulps(4))
+    );
+    assert_eq!(
+        "r2nd <= 1, r1st <= 2, rmin <= 3, rel <= 4, abs <= 5".parse(),
+        Ok(FloatCmp::new()
+            .abs(5.0f64)
+            .rel(4.0)
+            .rmin(3.0)
+            .r1st(2.0)
+            .r2nd(1.0))
+    );
+
+    // round trips through display
+    let cmp = FloatCmp::new().abs(1e-300f64).rmax(0.1).ulps(u64::MAX);
+    assert_eq!(cmp.to_string().parse(), Ok(cmp));
+}
+
+#[test]
+fn from_str_invalid() {
+    let error = |spec: &str| spec.parse::<FloatCmp<f32>>().unwrap_err().to_string();
+    assert_eq!(error(""), "no checks were given");
+    assert_eq!(error("  "), "no checks were given");
+    assert_eq!(
+        error("abs 0.1"),
+        "expected checks of the form `name <= tol`, separated by commas"
+    );
+    assert_eq!(
+        error("abs <= 0.1,"),
+        "expected checks of the form `name <= tol`, separated by commas"
+    );
+    assert_eq!(
+        error("abso <= 0.1"),
+        "unknown check, expected one of `abs`, `rel`, `rmax`, `rmin`, `r1st`, `r2nd` or `ulps`"
+    );
+    assert_eq!(
+        error("ulps <= 1, ulps <= 2"),
+        "the `ulps` check was given more than once"
+    );
+    assert_eq!(error("rmax <= x"), "invalid tolerance for the `rmax` check");
+    assert_eq!(
+        error("ulps <= -1"),
+        "invalid tolerance for the `ulps` check"
+    );
+    assert_eq!(
+        error("ulps <= 0.5"),
+        "invalid tolerance for the `ulps` check"
+    );
+}
@@ -0,0 +1,91 @@
+use float_eq::{assert_fma_agrees, fma_differential, MulAdd};
+
+#[test]
+fn mul_add() {
+    assert_eq!(MulAdd::Fused.mul_add(2.0f32, 3.0, 4.0), 10.0);
+    assert_eq!(MulAdd::Unfused.mul_add(2.0f64, 3.0, 4.0), 10.0);
+
+    // 0.1 * 10.0 rounds to exactly 1.0 unless fused
+    assert_eq!(
+        MulAdd::Fused.mul_add(0.1f64, 10.0, -1.0),
+        5.551_115_123_125_783e-17
+    );
+    assert_eq!(MulAdd::Unfused.mul_add(0.1f64, 10.0, -1.0), 0.0);
+    assert_eq!(MulAdd::Fused.mul_add(0.1f32, 10.0, -1.0), 1.490_116_1e-8);
+    assert_eq!(MulAdd::Unfused.mul_add(0.1f32, 10.0, -1.0), 0.0);
+}
+
+#[test]
+fn differential() {
+    let mut modes = Vec::new();
+    let (fused, unfused) = fma_differential(|m| {
+        modes.push(m);
+        m.mul_add(0.1f64, 10.0, -1.0)
+    });
+    assert_eq!(modes, vec![MulAdd::Fused, MulAdd::Unfused]);
+    assert_eq!(fused, 5.551_115_123_125_783e-17);
+    assert_eq!(unfused, 0.0);
+
+    let (fused, unfused) = fma_differential(|m| [m.mul_add(1.0f32, 2.0, 3.0); 2]);
+    assert_eq!(fused, [5.0; 2]);
+    assert_eq!(unfused, [5.0; 2]);
+}
+
+#[test]
+fn agrees() {
+    let x = 0.1f64;
+    assert_fma_agrees!(|m| m.mul_add(x, 10.0, -1.0), abs <= 1e-16);
+    assert_fma_agrees!(|m| m.mul_add(x, 10.0, -1.0), abs <= 1e-16,);
+    assert_fma_agrees!(|m| m.mul_add(x, 10.0, 1.0), ulps <= 0, abs <= 0.0);
+    assert_fma_agrees!(|m| [m.mul_add(x, 10.0, 1.0); 2], ulps_all <= 0, "x = {}", x);
+    assert_fma_agrees!(
+        |m| m.mul_add(x, 10.0, 1.0),
+        ulps <= 0,
+        abs <= 0.0,
+        rmax <= 0.0,
+        "x = {}",
+        x
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `5.551115123125783e-17`,
+       right: `0.0`,
+    abs_diff: `5.551115123125783e-17`,
+   ulps_diff: `Some(4363988038922010624)`,
+    [ulps] t: `4`: fused (left) and unfused (right) results differ"#)]
+fn agrees_fail() {
+    assert_fma_agrees!(|m| m.mul_add(0.1f64, 10.0, -1.0), ulps <= 4);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `5.551115123125783e-17`,
+       right: `0.0`,
+    abs_diff: `5.551115123125783e-17`,
+   ulps_diff: `Some(4363988038922010624)`,
+    [ulps] t: `4`: fused (left) and unfused (right) results differ: x = 0.1"#)]
+fn agrees_fail_with_message() {
+    let x = 0.1f64;
+    assert_fma_agrees!(|m| m.mul_add(x, 10.0, -1.0), ulps <= 4, "x = {}", x);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, ulps <= t, abs <= t)`
+        left: `5.551115123125783e-17`,
+       right: `0.0`,
+    abs_diff: `5.551115123125783e-17`,
+   ulps_diff: `Some(4363988038922010624)`,
+    [ulps] t: `4`,
+     [abs] t: `0.0`: fused (left) and unfused (right) results differ: x = 0.1"#)]
+fn agrees_fail_with_message_and_two_checks() {
+    let x = 0.1f64;
+    assert_fma_agrees!(
+        |m| m.mul_add(x, 10.0, -1.0),
+        ulps <= 4,
+        abs <= 0.0,
+        "x = {}",
+        x
+    );
+}
@@ -0,0 +1,159 @@
+use float_eq::fuzz::{compare, parse_tol_spec, render_report};
+use float_eq::{FailureFormat, FloatCmp, F64_TEST_VECTORS};
+
+const SPECS: [&str; 6] = [
+    "abs <= 0.0",
+    "ulps <= 1",
+    "rmax <= 1e-16, ulps <= 4",
+    "abs<=1e-9,rel<=0.5,rmax<=0.1,rmin<=0.1,r1st<=0.1,r2nd<=0.1,ulps<=0",
+    "r1st <= inf",
+    "rmin <= NaN, r2nd <= -1",
+];
+
+// tolerances that are never met don't parse when they would panic
+fn specs() -> &'static [&'static str] {
+    if cfg!(feature = "validate-tol") {
+        &SPECS[..5]
+    } else {
+        &SPECS
+    }
+}
+
+#[test]
+fn parse_tol_spec_valid() {
+    assert_eq!(parse_tol_spec("abs <= 0.1"), Some(FloatCmp::new().abs(0.1)));
+    assert_eq!(
+        parse_tol_spec(" rmax<=1e-6 ,  ulps <= 4 "),
+        Some(FloatCmp::new().rmax(1e-6).ulps(4))
+    );
+    assert_eq!(
+        parse_tol_spec("ulps <= 2, rel <= 0.5, r2nd <= 1, r1st <= 2, rmin <= 3"),
+        Some(
+            FloatCmp::new()
+                .rel(0.5)
+                .rmin(3.0)
+                .r1st(2.0)
+                .r2nd(1.0)
+                .ulps(2)
+        )
+    );
+}
+
+#[test]
+fn parse_tol_spec_invalid() {
+    for spec in [
+        "",
+        " ",
+        "abs",
+        "abs <=",
+        "abs < 0.1",
+        "abs <= x",
+        "ulps <= 0.5",
+        "ulps <= -1",
+        "abs <= 0.1,",
+        "abs <= 0.1, abs <= 0.2",
+        "abs_all <= 0.1",
+        "bits",
+        "abs <= 0.1 <= 0.2",
+    ]
+    .iter()
+    {
+        assert_eq!(parse_tol_spec(spec), None, "{:?}", spec);
+    }
+}
+
+#[test]
+fn parse_tol_spec_never_met() {
+    let never_met = ["abs <= -0.1", "rmax <= NaN", "ulps <= 1, r2nd <= -inf"];
+    for spec in never_met.iter() {
+        let is_valid = parse_tol_spec(spec).is_some();
+        assert_eq!(is_valid, !cfg!(feature = "validate-tol"), "{:?}", spec);
+    }
+    assert!(parse_tol_spec("abs <= -0.0").is_some());
+}
+
+#[test]
+fn compare_test_vectors() {
+    for spec in specs() {
+        for v in F64_TEST_VECTORS {
+            compare(spec, v.a, v.b).unwrap();
+            compare(spec, v.b, v.a).unwrap();
+        }
+    }
+    assert_eq!(compare("abs <= 0.1", 1.0, 1.05), Some(true));
+    assert_eq!(compare("abs <= 0.1", 1.0, 1.5), Some(false));
+    assert_eq!(
+        compare("abs <= 0.1, ulps <= 1", f64::NAN, f64::NAN),
+        Some(false)
+    );
+    assert_eq!(compare("abs", 1.0, 1.0), None);
+}
+
+#[test]
+fn render_report_human() {
+    assert_eq!(
+        render_report(
+            "abs <= 0.1, rmax <= 0.1",
+            1.0,
+            1.5,
+            None,
+            FailureFormat::Human
+        )
+        .unwrap(),
+        r#"assertion failed: `float_eq!(left, right, tol(cmp))`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(2251799813685248)`,
+     [tol] t: `FloatCmp { abs: Some(0.1), rel: None, rmax: Some(0.15000000000000002), rmin: None, r1st: None, r2nd: None, ulps: None }`"#
+    );
+    assert_eq!(
+        render_report(
+            "ulps <= 1",
+            f64::NAN,
+            1.0,
+            Some("with\na message"),
+            FailureFormat::Human
+        )
+        .unwrap(),
+        r#"assertion failed: `float_eq!(left, right, tol(cmp))`
+        left: `NaN`,
+       right: `1.0`,
+    abs_diff: `NaN`,
+   ulps_diff: an operand is NaN; ULPs comparison not applicable,
+     [tol] t: `FloatCmp { abs: None, rel: None, rmax: None, rmin: None, r1st: None, r2nd: None, ulps: Some(1) }`,
+        note: left operand is NaN: with
+a message"#
+    );
+}
+
+#[test]
+fn render_report_key_value() {
+    assert_eq!(
+        render_report(
+            "abs <= 0.1",
+            1.0,
+            1.5,
+            Some("a \"quoted\"\nmessage"),
+            FailureFormat::KeyValue
+        )
+        .unwrap(),
+        r#"assertion failed: check="float_eq!(left, right, tol(cmp))" left="1.0" right="1.5" abs_diff="0.5" ulps_diff="Some(2251799813685248)" tol.tol="FloatCmp { abs: Some(0.1), rel: None, rmax: None, rmin: None, r1st: None, r2nd: None, ulps: None }" message="a \"quoted\"\nmessage""#
+    );
+}
+
+#[test]
+fn render_report_test_vectors() {
+    for spec in specs() {
+        for v in F64_TEST_VECTORS {
+            for format in [FailureFormat::Human, FailureFormat::KeyValue].iter() {
+                render_report(spec, v.a, v.b, None, *format).unwrap();
+                render_report(spec, v.a, v.b, Some("\"\\\t\r\n"), *format).unwrap();
+            }
+        }
+    }
+    assert_eq!(
+        render_report("", 1.0, 1.0, None, FailureFormat::Human),
+        None
+    );
+}
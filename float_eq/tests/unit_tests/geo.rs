@@ -0,0 +1,108 @@
+use float_eq::{assert_float_eq, assert_geo_eq, great_circle_distance, GeoPoint};
+
+#[test]
+fn point() {
+    let p = GeoPoint::new(51.5, -0.1);
+    assert_eq!(
+        p,
+        GeoPoint {
+            lat: 51.5,
+            lon: -0.1,
+            alt: 0.0
+        }
+    );
+    assert_eq!(p.with_alt(35.0).alt, 35.0);
+    assert_eq!(p.with_alt(35.0).lat, 51.5);
+}
+
+#[test]
+fn distance() {
+    let p = GeoPoint::new(12.0, 34.0);
+    assert_eq!(great_circle_distance(&p, &p), 0.0);
+
+    // a degree of latitude is the same length everywhere
+    let one_degree = core::f64::consts::PI * 6_371_008.8 / 180.0;
+    let d = great_circle_distance(&GeoPoint::new(0.0, 10.0), &GeoPoint::new(1.0, 10.0));
+    assert_float_eq!(d, one_degree, rmax <= 1e-12);
+    let d = great_circle_distance(&GeoPoint::new(70.0, 10.0), &GeoPoint::new(71.0, 10.0));
+    assert_float_eq!(d, one_degree, rmax <= 1e-12);
+
+    // but a degree of longitude shrinks with latitude
+    let d = great_circle_distance(&GeoPoint::new(60.0, 10.0), &GeoPoint::new(60.0, 11.0));
+    assert_float_eq!(d, one_degree * 0.5, rmax <= 1e-4);
+
+    // altitude is ignored
+    let a = GeoPoint::new(-33.9, 151.2);
+    assert_eq!(
+        great_circle_distance(&a, &a.with_alt(100.0)),
+        great_circle_distance(&a, &a)
+    );
+
+    // antipodal positions are half the circumference apart
+    let d = great_circle_distance(&GeoPoint::new(0.0, 0.0), &GeoPoint::new(0.0, 180.0));
+    assert_float_eq!(d, one_degree * 180.0, rmax <= 1e-15);
+}
+
+#[test]
+fn distance_nan() {
+    let p = GeoPoint::new(0.0, 0.0);
+    assert!(great_circle_distance(&p, &GeoPoint::new(f64::NAN, 0.0)).is_nan());
+    assert!(great_circle_distance(&GeoPoint::new(0.0, f64::NAN), &p).is_nan());
+}
+
+#[test]
+fn eq_geo() {
+    // either side of the antimeridian
+    let a = GeoPoint::new(-17.0, 179.9999);
+    let b = GeoPoint::new(-17.0, -179.9999);
+    assert!(a.eq_geo(&b, 22.0, 0.0));
+    assert!(!a.eq_geo(&b, 21.0, 0.0));
+
+    // either side of the north pole
+    let a = GeoPoint::new(89.9999, 0.0);
+    let b = GeoPoint::new(89.9999, 180.0);
+    assert!(a.eq_geo(&b, 22.3, 0.0));
+    assert!(!a.eq_geo(&b, 22.2, 0.0));
+
+    // altitude is compared separately
+    let b = a.with_alt(2.0);
+    assert!(a.eq_geo(&b, 0.0, 2.0));
+    assert!(!a.eq_geo(&b, 1000.0, 1.9));
+
+    let nan = GeoPoint::new(f64::NAN, 0.0);
+    assert!(!nan.eq_geo(&nan, f64::INFINITY, f64::INFINITY));
+    let nan = a.with_alt(f64::NAN);
+    assert!(!nan.eq_geo(&nan, f64::INFINITY, f64::INFINITY));
+}
+
+#[test]
+fn geo_eq() {
+    let a = GeoPoint::new(51.4779, -0.0015).with_alt(46.0);
+    let b = GeoPoint::new(51.47791, -0.00148).with_alt(47.5);
+    assert_geo_eq!(a, b, meters <= 2.0, alt <= 1.5);
+    assert_geo_eq!(a, b, meters <= 2.0, alt <= 1.5,);
+    assert_geo_eq!(a, b, meters <= 2.0, alt <= 1.5, "testing {}", 1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `geo_eq!(left, right, meters <= t, alt <= t)`
+        left: `GeoPoint { lat: 0.0, lon: 0.0, alt: 0.0 }`,
+       right: `GeoPoint { lat: 0.0, lon: 0.0, alt: 3.0 }`,
+    distance: `0.0`,
+    alt_diff: `3.0`,
+  [meters] t: `1.0`,
+     [alt] t: `2.0`"#
+)]
+fn geo_eq_fail() {
+    let a = GeoPoint::new(0.0, 0.0);
+    assert_geo_eq!(a, a.with_alt(3.0), meters <= 1.0, alt <= 2.0);
+}
+
+#[test]
+#[should_panic(expected = r#"[alt] t: `1.0`: testing 2"#)]
+fn geo_eq_fail_message() {
+    let a = GeoPoint::new(0.0, 0.0);
+    let b = GeoPoint::new(0.001, 0.0);
+    assert_geo_eq!(a, b, meters <= 1.0, alt <= 1.0, "testing {}", 2);
+}
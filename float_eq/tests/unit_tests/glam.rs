@@ -0,0 +1,151 @@
+#![allow(clippy::float_cmp)]
+
+use float_eq::{
+    assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, FloatEqBits, TolOps, UlpsTol,
+};
+use glam::{DMat2, DVec2, Mat4, Quat, Vec3, Vec3A};
+
+#[test]
+fn float_eq() {
+    let a = Vec3::new(0.999_999_9, 2.0, 4.0);
+    let b = Vec3::new(1.0, 2.0, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= Vec3::new(1.0 * eps, 0.0, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vec3::new(0.5 * eps, 0.0, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vec3::new(1.0 * eps, 0.0, 2.0 * eps));
+
+    assert_float_eq!(a, b, rmax <= Vec3::new(1.0 * eps, 0.0, 1.0 * eps));
+    assert_float_ne!(a, b, rmax <= Vec3::new(0.5 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, rmin <= Vec3::new(2.0 * eps, 0.0, 2.0 * eps));
+    assert_float_ne!(a, b, rmin <= Vec3::new(2.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, r1st <= Vec3::new(2.0 * eps, 0.0, 1.0 * eps));
+    assert_float_ne!(a, b, r1st <= Vec3::new(1.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, r2nd <= Vec3::new(1.0 * eps, 0.0, 2.0 * eps));
+    assert_float_ne!(a, b, r2nd <= Vec3::new(1.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, ulps <= [2, 0, 2]);
+    assert_float_ne!(a, b, ulps <= [1, 0, 2]);
+    assert_float_ne!(a, b, ulps <= [2, 0, 1]);
+
+    let a = Vec3A::from(a);
+    let b = Vec3A::from(b);
+    assert_float_eq!(a, b, rmax <= Vec3A::new(1.0 * eps, 0.0, 1.0 * eps));
+    assert_float_ne!(a, b, rmax <= Vec3A::new(0.5 * eps, 0.0, 1.0 * eps));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Vec3::new(0.999_999_9, 2.0, 4.0);
+    let b = Vec3::new(1.0, 2.0, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= (4.0 * eps));
+    assert_float_ne!(a, b, abs_all <= (2.0 * eps));
+
+    assert_float_eq!(a, b, rmax_all <= (1.0 * eps));
+    assert_float_ne!(a, b, rmax_all <= (0.5 * eps));
+
+    assert_float_eq!(a, b, rmin_all <= (2.0 * eps));
+    assert_float_ne!(a, b, rmin_all <= (1.0 * eps));
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn matrix_columns() {
+    let a = DMat2::from_cols(DVec2::new(1.0, 2.0), DVec2::new(3.0, 4.0));
+    let b = DMat2::from_cols(DVec2::new(1.0, 2.5), DVec2::new(3.0, 4.0));
+    let tol = DMat2::from_cols(DVec2::new(0.0, 0.5), DVec2::ZERO);
+
+    assert_float_eq!(a, b, abs <= tol);
+    assert_float_ne!(a, b, abs <= tol.transpose());
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, ulps <= [[1, 1], [1, 1]]);
+
+    assert_eq!(a.debug_abs_diff(&b), tol);
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        [[Some(0), Some(1_125_899_906_842_624)], [Some(0), Some(0)]]
+    );
+
+    let m = Mat4::from_scale(Vec3::splat(2.0));
+    assert_float_eq!(m, m * 1.000_000_1, rmax_all <= f32::EPSILON);
+    assert_float_ne!(m, m * 1.000_001, rmax_all <= f32::EPSILON);
+}
+
+#[test]
+fn tol_ops() {
+    let a = Vec3::new(0.5, 0.0, 1.0);
+    let b = Vec3::new(0.25, 0.25, 0.25);
+    assert_eq!(a.max_elementwise(&b), Vec3::new(0.5, 0.25, 1.0));
+    assert_eq!(a.min_elementwise(&b), Vec3::new(0.25, 0.0, 0.25));
+    assert_eq!(a.scale(2.0), Vec3::new(1.0, 0.0, 2.0));
+
+    let a = DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    let b = DMat2::from_cols_array(&[4.0, 3.0, 2.0, 1.0]);
+    assert_eq!(
+        a.max_elementwise(&b),
+        DMat2::from_cols_array(&[4.0, 3.0, 3.0, 4.0])
+    );
+    assert_eq!(a.scale(0.5), DMat2::from_cols_array(&[0.5, 1.0, 1.5, 2.0]));
+}
+
+#[test]
+fn float_eq_bits() {
+    let a = Vec3::new(0.0, 1.0, f32::NAN);
+    assert!(a.eq_bits(&a));
+    assert!(!a.eq_bits(&Vec3::new(-0.0, 1.0, f32::NAN)));
+}
+
+#[test]
+fn debug_tol() {
+    let a = Vec3::new(2.0, 4.25, 1.0);
+    let b = Vec3::new(2.5, 4.0, 1.0);
+    let tol = Vec3::new(0.1, 0.2, 0.0);
+
+    assert_eq!(a.debug_abs_tol(&b, &tol), Vec3::new(0.1, 0.2, 0.0));
+    assert_eq!(a.debug_rmax_tol(&b, &tol), Vec3::new(0.25, 0.85, 0.0));
+    assert_eq!(a.debug_rmin_tol(&b, &tol), Vec3::new(0.2, 0.8, 0.0));
+    assert_eq!(a.debug_ulps_tol(&b, &[1, 2, 3]), [1, 2, 3]);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Vec3::new(0.2, 0.2, 0.2));
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.2), Vec3::new(0.5, 0.85, 0.2));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), [2, 2, 2]);
+}
+
+#[test]
+fn quaternion() {
+    let a = Quat::from_xyzw(0.0, 0.6, 0.0, 0.8);
+    let b = Quat::from_xyzw(0.0, 0.6, 0.0, 0.8 + f32::EPSILON);
+    let ulps: UlpsTol<Quat> = [0, 0, 0, 2];
+
+    assert_float_eq!(a, b, ulps <= ulps);
+    assert_float_ne!(a, b, ulps_all <= 1);
+    assert_float_eq!(a, b, abs_all <= f32::EPSILON);
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        Quat::from_xyzw(0.0, 0.0, 0.0, f32::EPSILON)
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t, ulps <= t)`
+        left: `Vec3(1.0, 2.0, 3.0)`,
+       right: `Vec3(1.0, 2.5, 3.0)`,
+    abs_diff: `Vec3(0.0, 0.5, 0.0)`,
+   ulps_diff: `[Some(0), Some(2097152), Some(0)]`,
+     [abs] t: `Vec3(0.1, 0.25, 0.1)`,
+    [ulps] t: `[1, 2, 3]`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(1.0, 2.5, 3.0),
+        abs <= Vec3::new(0.1, 0.25, 0.1),
+        ulps <= [1, 2, 3]
+    );
+}
@@ -0,0 +1,186 @@
+use float_eq::{
+    assert_derivative_eq, assert_gradcheck, central_difference, central_difference_tol,
+    derivative_rel_diff, eq_gradcheck, gradcheck_rel_diff, gradcheck_worst, GradcheckWorst,
+};
+
+#[test]
+fn rel_diff() {
+    assert_eq!(gradcheck_rel_diff(0.0f32, 0.0, 1.0), 0.0);
+    assert_eq!(gradcheck_rel_diff(f64::INFINITY, f64::INFINITY, 1.0), 0.0);
+
+    // scaled by the larger operand
+    assert_eq!(gradcheck_rel_diff(2.0f64, 2.5, 1.0), 0.2);
+    assert_eq!(gradcheck_rel_diff(-2.5f64, -2.0, 1.0), 0.2);
+
+    // scaled by the typical magnitude near zero
+    assert_eq!(gradcheck_rel_diff(0.0f32, 0.25, 1.0), 0.25);
+    assert_eq!(gradcheck_rel_diff(0.0f32, 0.25, -2.0), 0.125);
+    assert_eq!(gradcheck_rel_diff(0.0f32, 0.25, 0.0), 1.0);
+
+    assert!(gradcheck_rel_diff(f32::NAN, 1.0, 1.0).is_nan());
+}
+
+#[test]
+fn eq() {
+    assert!(eq_gradcheck(1.0f64, 1.1, 1.0, 0.1));
+    assert!(!eq_gradcheck(1.0f64, 1.2, 1.0, 0.1));
+    assert!(eq_gradcheck(0.0f32, 1e-6, 1.0, 1e-6));
+    assert!(!eq_gradcheck(0.0f32, 1e-6, 1e-6, 1e-6));
+    assert!(!eq_gradcheck(f32::NAN, f32::NAN, 1.0, f32::INFINITY));
+}
+
+#[test]
+fn worst() {
+    let empty: [f32; 0] = [];
+    assert_eq!(gradcheck_worst(&empty, &empty, 1.0), None);
+    assert_eq!(gradcheck_worst(&[1.0f32], &[1.0, 2.0], 1.0), None);
+
+    assert_eq!(
+        gradcheck_worst(&[1.0f64, 4.0, 2.0], &[1.0, 5.0, 2.0], 1.0),
+        Some(GradcheckWorst {
+            index: 1,
+            analytic: 4.0,
+            numeric: 5.0,
+            rel_diff: 0.2,
+        })
+    );
+
+    // ties go to the earliest index
+    let worst = gradcheck_worst(&[1.0f32, 2.0], &[2.0, 1.0], 1.0).unwrap();
+    assert_eq!(worst.index, 0);
+
+    // NaN is the worst difference
+    let worst = gradcheck_worst(&[1.0f32, f32::NAN, 2.0], &[2.0, 1.0, 1.0], 1.0).unwrap();
+    assert_eq!(worst.index, 1);
+    assert!(worst.rel_diff.is_nan());
+}
+
+#[test]
+fn assert_gradcheck() {
+    let analytic = [2.0f64, -4.0, 0.0];
+    let numeric = [2.000_000_1f64, -3.999_999_8, 1e-9];
+
+    assert_gradcheck!(analytic, numeric, rel <= 1e-7, typical = 1.0);
+    assert_gradcheck!(analytic, numeric, rel <= 1e-7, typical = 1.0,);
+    assert_gradcheck!(&analytic[..], &numeric[..], rel <= 1e-7, typical = 1.0);
+    assert_gradcheck!(
+        analytic.to_vec(),
+        numeric,
+        rel <= 1e-7,
+        typical = 1.0,
+        "x = {}",
+        1
+    );
+    assert_gradcheck!([0.0f32; 0], [0.0f32; 0], rel <= 0.0, typical = 1.0);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `gradcheck!(analytic, numeric, rel <= t, typical = s)`
+       index: `1`,
+    analytic: `4.0`,
+     numeric: `5.0`,
+    rel_diff: `0.2`,
+     typical: `1.0`,
+     [rel] t: `0.1`"#
+)]
+fn assert_gradcheck_fail() {
+    assert_gradcheck!(
+        [1.0f64, 4.0, 2.0],
+        [1.0, 5.0, 2.0],
+        rel <= 0.1,
+        typical = 1.0
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `gradcheck!(analytic, numeric, rel <= t, typical = s)`
+       index: `0`,
+    analytic: `NaN`,
+     numeric: `1.0`,
+    rel_diff: `NaN`,
+     typical: `1.0`,
+     [rel] t: `0.1`,
+        note: left operand is NaN: x = 2"#
+)]
+fn assert_gradcheck_fail_nan_message() {
+    assert_gradcheck!([f32::NAN], [1.0], rel <= 0.1, typical = 1.0, "x = {}", 2);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `gradcheck!(analytic, numeric, rel <= t, typical = s)`
+analytic_len: `2`,
+ numeric_len: `1`"#
+)]
+fn assert_gradcheck_fail_len() {
+    assert_gradcheck!([1.0f32, 2.0], [1.0], rel <= 0.1, typical = 1.0);
+}
+
+#[test]
+fn central_diff() {
+    assert_eq!(central_difference(|x: f64| 3.0 * x + 1.0, 2.0, 0.5), 3.0);
+    assert_eq!(central_difference(|x: f32| x * x, 1.0, 0.25), 2.0);
+
+    let mut calls = Vec::new();
+    central_difference(
+        |x: f64| {
+            calls.push(x);
+            x
+        },
+        1.0,
+        0.5,
+    );
+    assert_eq!(calls, vec![1.5, 0.5]);
+}
+
+#[test]
+fn central_diff_tol() {
+    assert_eq!(
+        central_difference_tol(0.5f64, 0.5),
+        0.75 + f64::EPSILON * 2.0
+    );
+    assert_eq!(
+        central_difference_tol(0.5f64, -0.5),
+        0.75 + f64::EPSILON * 2.0
+    );
+    assert_eq!(central_difference_tol(0.0f32, 1.0), 1.0 + f32::EPSILON);
+}
+
+#[test]
+fn derivative_diff() {
+    assert_eq!(derivative_rel_diff(0.0f64, 0.5), 0.5);
+    assert_eq!(derivative_rel_diff(4.0f64, 5.0), 0.2);
+}
+
+#[test]
+fn assert_derivative() {
+    let f = |x: f64| x.sin() * x;
+    let df = |x: f64| x.cos() * x + x.sin();
+
+    assert_derivative_eq!(f, df, 0.5, 1e-5, 1e-8);
+    assert_derivative_eq!(f, df, 0.0, 1e-5, 1e-8,);
+    assert_derivative_eq!(f, df, -3.0, 1e-5, 1e-8, "x = {}", -3.0);
+    assert_derivative_eq!(|x: f32| x * x, |x: f32| 2.0 * x, 3.0, 1e-2, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `derivative_eq!(f, df, x, h, t)`
+           x: `1.0`,
+           h: `0.5`,
+    analytic: `3.0`,
+     numeric: `2.0`,
+    rel_diff: `0.33333334`,
+     [rel] t: `0.26000023`"#)]
+fn assert_derivative_fail() {
+    assert_derivative_eq!(|x: f32| x * x, |x: f32| 3.0 * x, 1.0, 0.5, 0.01);
+}
+
+#[test]
+#[should_panic(expected = r#"rel_diff: `NaN`,
+     [rel] t: `0.010000000000002222`,
+        note: left operand is NaN: oops"#)]
+fn assert_derivative_fail_nan_message() {
+    assert_derivative_eq!(|x: f64| x, |_| f64::NAN, 1.0, 0.1, 0.0, "oops");
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::float_cmp)]
+
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq, FloatEqBits,
+    FloatEqWeighted, TolOps, UlpsMetric,
+};
+use half::{bf16, f16};
+
+#[test]
+fn f16_float_eq() {
+    let a = f16::ONE;
+    let b = f16::from_bits(f16::ONE.to_bits() + 2);
+    let eps = f16::EPSILON;
+    let two = f16::from_f32(2.0);
+
+    assert_float_eq!(a, b, abs <= two * eps);
+    assert_float_ne!(a, b, abs <= eps);
+
+    assert_float_eq!(a, b, rmax <= two * eps);
+    assert_float_ne!(a, b, rmax <= eps);
+    assert_float_eq!(a, b, rmin <= two * eps);
+    assert_float_eq!(a, b, r1st <= two * eps);
+    assert_float_eq!(a, b, r2nd <= two * eps);
+
+    assert_float_eq!(a, b, ulps <= 2);
+    assert_float_ne!(a, b, ulps <= 1);
+    assert!(float_eq!(a, b, ulps <= 4));
+
+    assert_float_eq!(a, b, abs_all <= two * eps);
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn bf16_float_eq() {
+    let a = bf16::ONE;
+    let b = bf16::from_bits(bf16::ONE.to_bits() + 3);
+    let eps = bf16::EPSILON;
+
+    assert_float_eq!(a, b, abs <= bf16::from_f32(3.0) * eps);
+    assert_float_ne!(a, b, abs <= bf16::from_f32(2.0) * eps);
+    assert_float_eq!(a, b, rmax <= bf16::from_f32(3.0) * eps);
+
+    assert_float_eq!(a, b, ulps <= 3);
+    assert_float_ne!(a, b, ulps <= 2);
+    assert!(float_eq!(a, b, ulps <= 4));
+}
+
+#[test]
+fn special_values() {
+    assert!(float_eq!(f16::INFINITY, f16::INFINITY, abs <= f16::ZERO));
+    assert!(float_ne!(f16::NAN, f16::NAN, ulps <= u16::MAX));
+    assert!(float_eq!(f16::ZERO, f16::NEG_ZERO, ulps <= 0));
+    assert!(float_ne!(
+        f16::MIN_POSITIVE_SUBNORMAL,
+        -f16::MIN_POSITIVE_SUBNORMAL,
+        ulps <= u16::MAX
+    ));
+    assert!(float_eq!(
+        bf16::INFINITY,
+        bf16::INFINITY,
+        rmax <= bf16::ZERO
+    ));
+    assert!(float_ne!(bf16::NAN, bf16::NAN, abs <= bf16::INFINITY));
+}
+
+#[test]
+fn debug_diff() {
+    let a = f16::ONE;
+    let b = f16::from_f32(1.5);
+    assert_eq!(a.debug_abs_diff(&b), f16::from_f32(0.5));
+    assert_eq!(b.debug_abs_diff(&a), f16::from_f32(0.5));
+    assert_eq!(a.debug_ulps_diff(&b), Some(512));
+    assert_eq!(a.debug_ulps_diff(&-b), None);
+    assert_eq!(a.ulps_between(&b), Some(512));
+
+    let tol = f16::from_f32(0.25);
+    assert_eq!(a.debug_rmax_tol(&b, &tol), f16::from_f32(0.375));
+    assert_eq!(a.debug_rmin_tol(&b, &tol), f16::from_f32(0.25));
+    assert_eq!(bf16::ONE.debug_ulps_diff(&bf16::from_f32(1.5)), Some(64));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, ulps <= t)`
+        left: `1.0`,
+       right: `1.0019531`,
+    abs_diff: `0.001953125`,
+   ulps_diff: `Some(2)`,
+    [ulps] t: `1`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(f16::ONE, f16::from_bits(0x3c02), ulps <= 1);
+}
+
+#[test]
+fn float_eq_bits() {
+    assert!(f16::NAN.eq_bits(&f16::NAN));
+    assert!(!f16::ZERO.eq_bits(&f16::NEG_ZERO));
+    assert!(!bf16::ZERO.eq_bits(&bf16::NEG_ZERO));
+}
+
+#[test]
+fn weighted_and_tol_ops() {
+    let a = f16::ONE;
+    let b = f16::from_f32(1.5);
+    let error = a.weighted_error(&b, &f16::from_f32(0.25), &f16::from_f32(2.0));
+    assert_eq!(error, 4.0);
+
+    let tol = f16::from_f32(0.25);
+    assert_eq!(tol.max_elementwise(&f16::ONE), f16::ONE);
+    assert_eq!(tol.min_elementwise(&f16::ONE), tol);
+    assert_eq!(tol.scale(2.0), f16::from_f32(0.5));
+}
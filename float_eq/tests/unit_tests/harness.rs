@@ -0,0 +1,234 @@
+use float_eq::{DifferentialCase, F32Sweep, F64Sample};
+
+#[test]
+fn stratified_inputs() {
+    let inputs: Vec<u32> = F32Sweep::stratified(3).inputs().map(f32::to_bits).collect();
+    assert_eq!(inputs.len(), 3 * 512);
+    assert_eq!(
+        &inputs[..4],
+        &[0x0000_0000, 0x003f_ffff, 0x007f_ffff, 0x0080_0000]
+    );
+    assert_eq!(inputs[inputs.len() - 1], 0xffff_ffff);
+
+    let one: Vec<u32> = F32Sweep::stratified(1).inputs().map(f32::to_bits).collect();
+    assert_eq!(one.len(), 512);
+    assert_eq!(&one[..2], &[0x0000_0000, 0x0080_0000]);
+
+    // binades only have 2^23 inputs to sample
+    let dense: Vec<u32> = F32Sweep::stratified(1 << 24)
+        .inputs()
+        .take(3)
+        .map(f32::to_bits)
+        .collect();
+    assert_eq!(dense, vec![0, 1, 2]);
+    assert_eq!(F32Sweep::all(), F32Sweep::stratified(1 << 23));
+    assert_eq!(F32Sweep::stratified(0).compare(|x| x, |x| x).inputs, 0);
+}
+
+#[test]
+fn compare_identical() {
+    let report = F32Sweep::stratified(16).compare(|x| x * 2.0, |x| x + x);
+    assert_eq!(report.inputs, 16 * 512);
+    assert_eq!(report.mismatches, 0);
+    assert_eq!(report.worst, None);
+    assert_eq!(report.max_ulps(), Some(0));
+    assert!(report.is_within(0));
+    report.assert_ulps_within(0);
+}
+
+#[test]
+fn compare_worst() {
+    let report = F32Sweep::stratified(4).compare(
+        |x| {
+            if x == 1.0 {
+                f32::from_bits(x.to_bits() + 5)
+            } else if x == 2.0 {
+                f32::from_bits(x.to_bits() + 1)
+            } else {
+                x
+            }
+        },
+        |x| x,
+    );
+    assert_eq!(report.mismatches, 2);
+    assert_eq!(
+        report.worst,
+        Some(DifferentialCase {
+            input: 1.0,
+            actual: f32::from_bits(1.0f32.to_bits() + 5),
+            expected: 1.0,
+            ulps_diff: Some(5),
+        })
+    );
+    assert_eq!(report.max_ulps(), Some(5));
+    assert!(report.is_within(5));
+    assert!(!report.is_within(4));
+    assert_eq!(
+        report.to_string(),
+        "2 of 2048 inputs differ, worst is input 1.0: actual 1.0000006, expected 1.0, ulps_diff Some(5)"
+    );
+}
+
+#[test]
+fn compare_nan() {
+    // NaN results match whatever their payload, but not a NaN against a number
+    let report = F32Sweep::stratified(2).compare(
+        |x| if x.is_nan() { -f32::NAN } else { x },
+        |x| if x.is_infinite() { f32::NAN } else { x },
+    );
+    assert_eq!(report.mismatches, 2);
+    assert_eq!(report.max_ulps(), None);
+    assert!(!report.is_within(u32::MAX));
+    assert!(report.worst.unwrap().input.is_infinite());
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `differential!(actual, expected, ulps <= t)`
+       input: `2.0`,
+      actual: `2.0000002`,
+    expected: `2.0`,
+   ulps_diff: `Some(1)`,
+    [ulps] t: `0`: 1 of 1024 inputs differ"#
+)]
+fn assert_ulps_within_fail() {
+    F32Sweep::stratified(2)
+        .compare(
+            |x| {
+                if x == 2.0 {
+                    f32::from_bits(x.to_bits() + 1)
+                } else {
+                    x
+                }
+            },
+            |x| x,
+        )
+        .assert_ulps_within(0);
+}
+
+#[test]
+fn f64_edge_cases() {
+    let edges: Vec<u64> = F64Sample::new(0, 0).inputs().map(f64::to_bits).collect();
+    assert_eq!(edges.len(), F64Sample::EDGE_CASES.len());
+    assert!(edges.contains(&0x8000_0000_0000_0000));
+    assert!(edges.contains(&0x0000_0000_0000_0001));
+    assert!(edges.contains(&0x000f_ffff_ffff_ffff));
+    assert!(edges.contains(&0x7fef_ffff_ffff_fffe));
+    assert!(edges.contains(&f64::MAX.to_bits()));
+    assert!(edges.contains(&f64::NEG_INFINITY.to_bits()));
+}
+
+#[test]
+fn f64_random_inputs() {
+    let edges = F64Sample::EDGE_CASES.len();
+    let a: Vec<u64> = F64Sample::new(42, 10_000)
+        .inputs()
+        .map(f64::to_bits)
+        .collect();
+    let b: Vec<u64> = F64Sample::new(42, 10_000)
+        .inputs()
+        .map(f64::to_bits)
+        .collect();
+    let c: Vec<u64> = F64Sample::new(43, 10_000)
+        .inputs()
+        .map(f64::to_bits)
+        .collect();
+    assert_eq!(a.len(), edges + 10_000);
+    assert_eq!(a, b);
+    assert_ne!(a[edges..], c[edges..]);
+
+    // random inputs are finite, of both signs and spread across exponents
+    let random = F64Sample::new(42, 10_000).inputs().skip(edges);
+    let (mut negative, mut subnormal, mut huge) = (0, 0, 0);
+    for x in random {
+        assert!(x.is_finite());
+        negative += x.is_sign_negative() as u32;
+        subnormal += (x != 0.0 && x.abs() < f64::MIN_POSITIVE) as u32;
+        huge += (x.abs() > 1e300) as u32;
+    }
+    assert!((4_000..6_000).contains(&negative), "{}", negative);
+    assert!(subnormal > 0);
+    assert!(huge > 0);
+}
+
+#[test]
+fn f64_compare() {
+    let sample = F64Sample::new(7, 1_000);
+    let report = sample.compare(|x| x * 0.5, |x| x / 2.0);
+    assert_eq!(report.inputs, 1_000 + F64Sample::EDGE_CASES.len() as u64);
+    assert_eq!(report.mismatches, 0);
+
+    let report = sample.compare(|x| (x * 3.0) / 3.0, |x| x);
+    assert!(report.mismatches > 0);
+    assert!(report.max_ulps() > Some(1 << 52));
+    assert!(report.worst.unwrap().actual.is_infinite());
+
+    // flipping the lowest bit of an infinity makes a NaN
+    let report = sample.compare(|x| f64::from_bits(x.to_bits() ^ 1), |x| x);
+    assert_eq!(report.max_ulps(), None);
+    assert!(report.worst.unwrap().input.is_infinite());
+    assert_eq!(report.mismatches, report.inputs - 1);
+}
+
+#[cfg(feature = "std")]
+mod tolerance_sweep {
+    use float_eq::{float_eq, SweepRow, ToleranceSweep};
+
+    #[test]
+    fn tightest_per_scale() {
+        let scales = [1.0f64, 1e10, 1e20];
+        let tols = [1e-15, 1e-10, 1e-5, 1.0];
+        let report = ToleranceSweep::new(&scales, &tols)
+            .run(|scale, tol| float_eq!((scale + 0.5) - scale, 0.5, abs <= tol));
+        assert_eq!(report.tols(), &tols);
+        assert_eq!(
+            report.rows(),
+            &[
+                SweepRow {
+                    scale: 1.0,
+                    tightest: Some(0)
+                },
+                SweepRow {
+                    scale: 1e10,
+                    tightest: Some(0)
+                },
+                SweepRow {
+                    scale: 1e20,
+                    tightest: Some(3)
+                },
+            ]
+        );
+        assert_eq!(report.tightest(), vec![Some(1e-15), Some(1e-15), Some(1.0)]);
+        assert_eq!(report.required(), Some(1.0));
+    }
+
+    #[test]
+    fn stops_at_first_pass() {
+        let mut calls = Vec::new();
+        let report = ToleranceSweep::new(&[1, 2], &[0u32, 1, 2, 3]).run(|scale, tol| {
+            calls.push((scale, tol));
+            tol >= scale
+        });
+        assert_eq!(calls, vec![(1, 0), (1, 1), (2, 0), (2, 1), (2, 2)]);
+        assert_eq!(report.tightest(), vec![Some(1), Some(2)]);
+        assert_eq!(report.required(), Some(2));
+    }
+
+    #[test]
+    fn none_pass() {
+        let report = ToleranceSweep::new(&[1.0f32, 2.0], &[0.1f32]).run(|scale, _| scale < 1.5);
+        assert_eq!(report.tightest(), vec![Some(0.1), None]);
+        assert_eq!(report.required(), None);
+
+        let report = ToleranceSweep::new(&[1.0f32], &[] as &[f32]).run(|_, _| true);
+        assert_eq!(report.tightest(), vec![None]);
+        assert_eq!(report.required(), None);
+    }
+
+    #[test]
+    fn display() {
+        let report = ToleranceSweep::new(&[1.0f64, 1e100], &[0u64, 4, 16])
+            .run(|scale, tol| scale < 10.0 || tol >= 4);
+        assert_eq!(report.to_string(), "scale  tightest\n1.0    0\n1e100  4");
+    }
+}
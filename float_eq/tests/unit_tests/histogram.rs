@@ -0,0 +1,114 @@
+#[cfg(feature = "alloc")]
+use float_eq::assert_histogram_eq;
+use float_eq::{first_count_mismatch, CountTol, Histogram};
+
+#[test]
+fn histogram() {
+    let h = Histogram::new(&[0.0f32, 1.0, 2.0], &[3, 4]);
+    assert_eq!(h.edges(), &[0.0, 1.0, 2.0]);
+    assert_eq!(h.counts(), &[3, 4]);
+    assert_eq!(h.bins(), 2);
+
+    let empty = Histogram::<f64>::new(&[], &[]);
+    assert_eq!(empty.bins(), 0);
+}
+
+#[test]
+#[should_panic(expected = "a histogram with 2 bins must have 3 edges, not 2")]
+fn histogram_mismatched_edges() {
+    let _ = Histogram::new(&[0.0f64, 1.0], &[3, 4]);
+}
+
+#[test]
+fn count_tol() {
+    assert_eq!(CountTol::abs(3), CountTol::Abs(3));
+    assert_eq!(CountTol::rel(0.5), CountTol::Rel(0.5));
+
+    assert!(CountTol::abs(0).eq_counts(5, 5));
+    assert!(!CountTol::abs(0).eq_counts(5, 6));
+    assert!(CountTol::abs(1).eq_counts(6, 5));
+    assert!(CountTol::abs(u64::MAX).eq_counts(0, u64::MAX));
+
+    assert!(CountTol::rel(0.0).eq_counts(0, 0));
+    assert!(CountTol::rel(0.1).eq_counts(10, 9));
+    assert!(CountTol::rel(0.1).eq_counts(9, 10));
+    assert!(!CountTol::rel(0.1).eq_counts(10, 8));
+    assert!(!CountTol::rel(f64::NAN).eq_counts(10, 9));
+}
+
+#[test]
+fn count_mismatch() {
+    assert_eq!(first_count_mismatch(&[], &[], CountTol::abs(0)), None);
+    assert_eq!(
+        first_count_mismatch(&[1, 2], &[1, 2], CountTol::abs(0)),
+        None
+    );
+    assert_eq!(
+        first_count_mismatch(&[1, 2], &[2, 2], CountTol::abs(0)),
+        Some(0)
+    );
+    assert_eq!(
+        first_count_mismatch(&[1, 2], &[1, 2, 3], CountTol::abs(0)),
+        Some(2)
+    );
+    assert_eq!(
+        first_count_mismatch(&[1, 2, 3], &[1], CountTol::abs(5)),
+        Some(1)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn assert_histogram_eq() {
+    let a = Histogram::new(&[0.0f64, 0.5, 1.0], &[100, 200]);
+    let b = Histogram::new(&[0.0f64, 0.500_000_000_000_000_1, 1.0], &[98, 205]);
+
+    assert_histogram_eq!(a, b, edges: ulps_all <= 1, counts: abs <= 5);
+    assert_histogram_eq!(a, b, edges: ulps_all <= 1, counts: abs <= 5,);
+    assert_histogram_eq!(a, b, edges: abs_all <= 1e-15, counts: rel <= 0.025);
+    assert_histogram_eq!(a, b, edges: rmax_all <= 1e-15, counts: rel <= 0.025, "testing {}", 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `[0.0, 0.5, 1.0]`,
+       right: `[0.0, 0.25, 1.0]`,
+    abs_diff: `Some([0.0, 0.25, 0.0])`,
+   ulps_diff: `Some([Some(0), Some(8388608), Some(0)])`,
+ [abs_all] t: `Some([0.001, 0.001, 0.001])`: histogram bin edges differ: testing 2"#)]
+fn assert_histogram_eq_fail_edges() {
+    let a = Histogram::new(&[0.0f32, 0.5, 1.0], &[100, 200]);
+    let b = Histogram::new(&[0.0f32, 0.25, 1.0], &[100, 200]);
+    assert_histogram_eq!(a, b, edges: abs_all <= 0.001, counts: abs <= 0, "testing {}", 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `histogram_eq!(left, right, edges: abs_all <= t, counts: abs <= t)`
+         bin: `1`,
+        left: `200`,
+       right: `210`,
+     [abs] t: `5`"#
+)]
+fn assert_histogram_eq_fail_counts() {
+    let a = Histogram::new(&[0.0f64, 0.5, 1.0], &[100, 200]);
+    let b = Histogram::new(&[0.0f64, 0.5, 1.0], &[100, 210]);
+    assert_histogram_eq!(a, b, edges: abs_all <= 0.0, counts: abs <= 5);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `histogram_eq!(left, right, edges: abs_all <= t, counts: rel <= t)`
+         bin: `0`,
+        left: `10`,
+       right: `8`,
+     [rel] t: `0.1`: testing 2"#
+)]
+fn assert_histogram_eq_fail_message() {
+    let a = Histogram::new(&[0.0f64, 1.0], &[10]);
+    let b = Histogram::new(&[0.0f64, 1.0], &[8]);
+    assert_histogram_eq!(a, b, edges: abs_all <= 0.0, counts: rel <= 0.1, "testing {}", 2);
+}
@@ -1,6 +1,8 @@
 use float_eq::{
-    assert_float_eq, assert_float_ne, debug_assert_float_eq, debug_assert_float_ne, float_eq,
-    float_ne,
+    assert_all_float_eq_to, assert_all_pairwise_float_eq, assert_both_near_reference,
+    assert_float_eq, assert_float_eq_iter, assert_float_eq_slice, assert_float_eq_tighter_than,
+    assert_float_idempotent, assert_float_ne, assert_invariant_conserved, assert_sorted,
+    debug_assert_float_eq, debug_assert_float_ne, float_eq, float_ne,
 };
 
 #[test]
@@ -9,6 +11,76 @@ fn trailing_commas() {
     assert!(float_ne!(1.0, 1.5, abs <= 0.4,));
     assert_float_eq!(1.0, 1.5, abs <= 0.5,);
     assert_float_ne!(1.0, 1.5, abs <= 0.4);
+    assert!(float_eq!(1.0, 1.0, bits,));
+    assert!(float_ne!(0.0, -0.0, bits,));
+    assert_float_eq!(1.0, 1.0, bits,);
+    assert_float_ne!(0.0, -0.0, bits,);
+}
+
+#[test]
+fn bits() {
+    assert!(float_eq!(1.0f32, 1.0, bits));
+    assert!(float_eq!(f64::NAN, f64::NAN, bits));
+    assert!(float_ne!(0.0f32, -0.0, bits));
+    assert!(float_ne!(1.0f64, 1.000_000_000_000_000_2, bits));
+    assert!(float_ne!(f32::NAN, -f32::NAN, bits));
+    assert_float_eq!([1.0f32, f32::NAN], [1.0, f32::NAN], bits);
+    assert_float_ne!([1.0f32, 0.0], [1.0, -0.0], bits);
+}
+
+#[test]
+fn bits_tol() {
+    assert!(float_eq!(f64::NAN, f64::NAN, bits <= ()));
+    assert!(float_ne!(0.0f32, -0.0, bits <= ()));
+    assert!(float_eq!(f32::NAN, f32::NAN, abs <= 0.1, bits <= ()));
+    assert!(float_eq!(0.0f32, -0.0, abs <= 0.1, bits <= ()));
+    assert!(float_ne!(f32::NAN, -f32::NAN, abs <= 0.1, bits <= ()));
+    assert_float_eq!([1.0f32, f32::NAN], [1.0, f32::NAN], bits_all <= ());
+    assert_float_eq!((1.0f64, f64::NAN), (1.0, f64::NAN), bits_all <= ());
+    assert_float_eq!(
+        [f32::NAN, 1.0],
+        [f32::NAN, 1.0],
+        ulps_all <= 4,
+        bits_all <= ()
+    );
+    assert_float_ne!([1.0f32, 0.0], [1.0, -0.0], bits_all <= ());
+}
+
+#[test]
+fn operands_and_tolerances_evaluated_once() {
+    let count = core::cell::Cell::new(0);
+    let counted = |x: f64| {
+        count.set(count.get() + 1);
+        x
+    };
+
+    assert_float_eq!(
+        counted(1.0),
+        counted(1.0),
+        abs <= counted(0.0),
+        rel <= counted(0.0),
+        ulps <= 0
+    );
+    assert_eq!(count.replace(0), 4);
+
+    assert_float_ne!(counted(1.0), counted(2.0), abs <= counted(0.5), "{}", 1);
+    assert_eq!(count.replace(0), 3);
+
+    let series = [1.0f64, 1.0, 1.0, 1.0];
+    assert_invariant_conserved!(series, abs <= counted(0.0), rel <= counted(0.0));
+    assert_eq!(count.replace(0), 2);
+    assert_invariant_conserved!(series, abs <= counted(0.0), "{}", 1);
+    assert_eq!(count.replace(0), 1);
+    assert_all_float_eq_to!(series, counted(1.0), abs <= counted(0.0));
+    assert_eq!(count.replace(0), 2);
+    assert_all_pairwise_float_eq!(series, abs <= counted(0.0), ulps <= 0, "{}", 1);
+    assert_eq!(count.replace(0), 1);
+    assert_sorted!(series, abs <= counted(0.0), rmax <= counted(0.0));
+    assert_eq!(count.replace(0), 2);
+    assert_float_eq_slice!(series, series, abs <= counted(0.0));
+    assert_eq!(count.replace(0), 1);
+    assert_float_eq_iter!(series, series, abs <= counted(0.0));
+    assert_eq!(count.replace(0), 1);
 }
 
 mod assert_float_eq {
@@ -168,6 +240,59 @@ mod assert_float_eq {
         assert_float_eq!(1_f32, 1.000_000_2, ulps_all <= 1);
     }
 
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_ulps <= t)`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+[abs_ulps] t: `(1e-9, 1)`"#)]
+    fn abs_ulps_fail() {
+        assert_float_eq!(1_f32, 1.000_000_2, abs_ulps <= (1e-9, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_ulps_all <= t)`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+[abs_ulps_all] t: `(1e-9, 1)`"#)]
+    fn abs_ulps_all_fail() {
+        assert_float_eq!(1_f32, 1.000_000_2, abs_ulps_all <= (1e-9, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, bits)`
+        left: `0.0`,
+       right: `-0.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`"#)]
+    fn bits_fail() {
+        assert_float_eq!(0_f32, -0., bits);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, bits)`
+        left: `0.0`,
+       right: `-0.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`: testing: 0 != -0"#)]
+    fn bits_fail_with_message() {
+        assert_float_eq!(0_f32, -0., bits, "testing: {} != {}", 0_f32, -0_f32);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, bits_all <= t)`
+        left: `[NaN]`,
+       right: `[NaN]`,
+    abs_diff: `[NaN]`,
+   ulps_diff: `[None]`,
+[bits_all] t: `()`"#)]
+    fn bits_all_fail() {
+        assert_float_eq!([f32::NAN], [-f32::NAN], bits_all <= ());
+    }
+
     #[test]
     #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
         left: `0.0`,
@@ -466,6 +591,26 @@ mod assert_float_ne {
         assert_float_ne!(1_f32, 1.000_000_1, ulps <= 1);
     }
 
+    #[test]
+    #[should_panic(expected = r#"`float_ne!(left, right, bits)`
+        left: `1.0`,
+       right: `1.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`"#)]
+    fn bits_fail() {
+        assert_float_ne!(1_f32, 1., bits);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_ne!(left, right, bits)`
+        left: `1.0`,
+       right: `1.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`: testing: 1 == 1"#)]
+    fn bits_fail_with_message() {
+        assert_float_ne!(1_f32, 1., bits, "testing: {} == {}", 1_f32, 1_f32);
+    }
+
     #[test]
     #[should_panic(expected = r#"`float_ne!(left, right, abs <= t)`
         left: `0.0`,
@@ -616,7 +761,7 @@ mod debug_assert_float_eq {
         left: `-1.0`,
        right: `1.0`,
     abs_diff: `2.0`,
-   ulps_diff: `None`,
+   ulps_diff: operands differ in sign; ULPs comparison not applicable,
      [abs] t: `0.1`"#)
     )]
     fn fail_ulps_diff_none() {
@@ -941,3 +1086,466 @@ mod debug_assert_float_ne {
         );
     }
 }
+
+mod assert_float_eq_tighter_than {
+    use super::*;
+
+    #[test]
+    fn tighter_than() {
+        assert_float_eq_tighter_than!(1.0f32, 1.25, 1.0, factor = 0.25);
+        assert_float_eq_tighter_than!(1.0f32, 1.25, 1.0, factor = 0.25,);
+        assert_float_eq_tighter_than!(1.0f64, 1.25, 1.0, factor = 0.5, "message");
+        assert_float_eq_tighter_than!([1.0f32, 2.0], [1.25, 2.25], 0.5, factor = 0.5);
+        #[cfg(feature = "alloc")]
+        assert_float_eq_tighter_than!(vec![1.0f64, 2.0], vec![0.75, 2.0], 0.5, factor = 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= previous * factor)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+    previous: `1.0`,
+      factor: `0.25`,
+ [abs_all] t: `0.25`"#)]
+    fn fail() {
+        assert_float_eq_tighter_than!(1_f32, 1.5, 1.0, factor = 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= previous * factor)`
+        left: `[1.0, 2.0]`,
+       right: `[1.0, 2.5]`,
+    abs_diff: `[0.0, 0.5]`,
+   ulps_diff: `[Some(0), Some(2097152)]`,
+    previous: `0.5`,
+      factor: `0.5`,
+ [abs_all] t: `[0.25, 0.25]`: testing: 2 != 2.5"#)]
+    fn fail_with_message() {
+        assert_float_eq_tighter_than!(
+            [1_f32, 2.0],
+            [1.0, 2.5],
+            0.5,
+            factor = 0.5,
+            "testing: {} != {}",
+            2_f32,
+            2.5_f32
+        );
+    }
+}
+
+mod assert_both_near_reference {
+    use super::*;
+
+    #[test]
+    fn near_reference() {
+        let a = 1.000_000_1f32;
+        let b = 0.999_999_94f32;
+        assert_both_near_reference!(a, b, 1.0, ulps <= 1);
+        assert_both_near_reference!(a, b, 1.0, ulps <= 1,);
+        assert_both_near_reference!(a, b, 1.0, ulps <= 1, "testing {}", 1);
+        assert_both_near_reference!(a, b, 1.0, ulps <= 1, between ulps <= 2);
+        assert_both_near_reference!(a, b, 1.0, ulps <= 1, between abs <= 0.000_001,);
+        assert_both_near_reference!(a, b, 1.0, abs <= 0.000_001, between rmax <= 0.000_001, "testing");
+        assert_both_near_reference!([a, b], [b, a], [1.0; 2], ulps_all <= 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `1.0000001`,
+       right: `1.0`,
+    abs_diff: `1.1920929e-7`,
+   ulps_diff: `Some(1)`,
+    [ulps] t: `0`: first candidate is not near the reference"#)]
+    fn first_fail() {
+        assert_both_near_reference!(1.000_000_1f32, 1.0, 1.0, ulps <= 0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `1.0000002`,
+       right: `1.0`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+    [ulps] t: `1`: second candidate is not near the reference: testing 2"#)]
+    fn second_fail_with_message() {
+        assert_both_near_reference!(1.000_000_1f32, 1.000_000_2, 1.0, ulps <= 1, "testing {}", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `1.0000001`,
+       right: `0.99999994`,
+    abs_diff: `1.7881393e-7`,
+   ulps_diff: `Some(2)`,
+    [ulps] t: `1`: candidates are not near each other"#)]
+    fn between_fail() {
+        assert_both_near_reference!(1.000_000_1f32, 0.999_999_94, 1.0, ulps <= 1, between ulps <= 1);
+    }
+}
+
+mod assert_invariant_conserved {
+    use super::*;
+
+    #[test]
+    fn conserved() {
+        let energy = [10.0f64, 10.000_000_1, 9.999_999_9];
+        assert_invariant_conserved!(energy, rel <= 1e-6);
+        assert_invariant_conserved!(energy, rel <= 1e-6,);
+        assert_invariant_conserved!(&energy[..], abs <= 1e-3, ulps <= 1);
+        assert_invariant_conserved!(energy.to_vec(), rel <= 1e-6, "testing {}", 1);
+
+        let empty: [f32; 0] = [];
+        assert_invariant_conserved!(empty, abs <= 0.0);
+        assert_invariant_conserved!([[1.0f32, 2.0], [1.0, 2.5]], abs_all <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, rel <= t)`
+        left: `10.5`,
+       right: `10.0`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(281474976710656)`,
+     [rel] t: `0.0105`: invariant not conserved at step 2"#)]
+    fn first_violation() {
+        assert_invariant_conserved!([10.0f64, 10.0, 10.5, 12.0], rel <= 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.5`: invariant not conserved at step 1: testing 2"#)]
+    fn violation_with_message() {
+        assert_invariant_conserved!([1.0f32, 2.0], abs <= 0.5, "testing {}", 2);
+    }
+}
+
+mod assert_float_eq_slice {
+    use super::*;
+
+    #[test]
+    fn pass() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = vec![1.000_000_1f32, 2.0, 2.999_999_8];
+        assert_float_eq_slice!(a, b, ulps <= 2);
+        assert_float_eq_slice!(a, b, ulps <= 2,);
+        assert_float_eq_slice!(&a[..], &b[..], abs <= 0.001, "testing {}", 1);
+        assert_float_eq_slice!([[1.0f64, 2.0]], [[1.5, 2.5]], abs_all <= 0.5);
+
+        let empty: [f64; 0] = [];
+        assert_float_eq_slice!(empty, Vec::<f64>::new(), abs <= 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq_slice!(left, right, abs <= t)`
+       index: `1`,
+        left: `2.0`,
+       right: `2.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(2097152)`,
+     [abs] t: `0.1`"#)]
+    fn first_mismatch() {
+        assert_float_eq_slice!([1.0f32, 2.0, 3.0], [1.0f32, 2.5, 4.0], abs <= 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq_slice!(left, right, ulps <= t)`
+    left_len: `2`,
+   right_len: `3`: testing 1"#)]
+    fn length_mismatch() {
+        assert_float_eq_slice!(
+            [1.0f64, 2.0],
+            [1.0f64, 2.0, 3.0],
+            ulps <= 1,
+            "testing {}",
+            1
+        );
+    }
+}
+
+mod assert_float_eq_iter {
+    use super::*;
+
+    #[test]
+    fn pass() {
+        let a = [0.0f64, 0.5, 1.0];
+        assert_float_eq_iter!(
+            a.iter().map(|x| x * 0.1),
+            [0.0, 0.05, 0.1],
+            rmax <= f64::EPSILON
+        );
+        assert_float_eq_iter!(a.iter(), &a, abs <= 0.0,);
+        assert_float_eq_iter!(vec![0.0, 0.5, 1.0], a, ulps <= 0, "testing {}", 1);
+        assert_float_eq_iter!(core::iter::empty::<f32>(), [], abs <= 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq_iter!(left, right, rmax <= t)`
+       index: `2`,
+        left: `3.0`,
+       right: `4.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(4194304)`,
+    [rmax] t: `0.4`: testing 1"#)]
+    fn first_mismatch() {
+        let a = vec![1.0f32, 2.0, 3.0, 5.0];
+        assert_float_eq_iter!(a, [1.0f32, 2.0, 4.0, 6.0], rmax <= 0.1, "testing {}", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq_iter!(left, right, abs <= t)`
+    left_len: `4`,
+   right_len: `2`"#)]
+    fn left_longer() {
+        assert_float_eq_iter!((0..4).map(f64::from), [0.0, 1.0], abs <= 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq_iter!(left, right, abs <= t)`
+    left_len: `1`,
+   right_len: `3`"#)]
+    fn right_longer() {
+        assert_float_eq_iter!([1.0f32], [1.0f32, 2.0, 3.0], abs <= 0.0);
+    }
+}
+
+mod assert_all_float_eq_to {
+    use super::*;
+
+    #[test]
+    fn uniform() {
+        let weights = [0.25f64, 0.250_000_01, 0.249_999_99];
+        assert_all_float_eq_to!(weights, 0.25, abs <= 1e-7);
+        assert_all_float_eq_to!(weights, 0.25, abs <= 1e-7,);
+        assert_all_float_eq_to!(&weights[..], 0.25, rmax <= 1e-6, ulps <= 1);
+        assert_all_float_eq_to!(weights.to_vec(), 0.25, abs <= 1e-7, "testing {}", 1);
+
+        let empty: [f32; 0] = [];
+        assert_all_float_eq_to!(empty, 1.0, abs <= 0.0);
+        assert_all_float_eq_to!([[1.0f32, 2.0], [1.5, 2.5]], [1.0, 2.0], abs_all <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `0.5`,
+       right: `0.25`,
+    abs_diff: `0.25`,
+   ulps_diff: `Some(4503599627370496)`,
+     [abs] t: `0.1`: element 2 is not equal to the expected value"#)]
+    fn outlier() {
+        assert_all_float_eq_to!([0.25f64, 0.3, 0.5, 1.0], 0.25, abs <= 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.5`: element 1 is not equal to the expected value: testing 2"#)]
+    fn outlier_with_message() {
+        assert_all_float_eq_to!(vec![1.0f32, 2.0], 1.0, abs <= 0.5, "testing {}", 2);
+    }
+}
+
+mod assert_all_pairwise_float_eq {
+    use super::*;
+
+    #[test]
+    fn uniform() {
+        let replicas = [3.0f32, 3.000_000_2, 2.999_999_8];
+        assert_all_pairwise_float_eq!(replicas, abs <= 0.000_001);
+        assert_all_pairwise_float_eq!(replicas, abs <= 0.000_001,);
+        assert_all_pairwise_float_eq!(&replicas[..], rmax <= 0.000_001, ulps <= 4);
+        assert_all_pairwise_float_eq!(replicas.to_vec(), ulps <= 4, "testing {}", 1);
+
+        let single = [f32::NAN];
+        assert_all_pairwise_float_eq!(single, abs <= 0.0);
+        assert_all_pairwise_float_eq!([[1.0f32, 2.0], [1.5, 2.5]], abs_all <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.2`,
+    abs_diff: `0.19999999999999996`,
+   ulps_diff: `Some(900719925474099)`,
+     [abs] t: `0.15`: elements 0 and 2 are not equal"#)]
+    fn drift() {
+        // each element is within tolerance of its neighbours, but not of all
+        assert_all_pairwise_float_eq!([1.0f64, 1.1, 1.2], abs <= 0.15);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `2.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.5`: elements 0 and 1 are not equal: testing 2"#)]
+    fn mismatch_with_message() {
+        assert_all_pairwise_float_eq!([1.0f32, 2.0, 1.0], abs <= 0.5, "testing {}", 2);
+    }
+}
+
+mod assert_sorted {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        let scores = [0.1f64, 0.300_000_000_000_000_04, 0.3, 0.5];
+        assert_sorted!(scores, ulps <= 1);
+        assert_sorted!(scores, ulps <= 1,);
+        assert_sorted!(&scores[..], abs <= 1e-12, rmax <= 1e-12);
+        assert_sorted!(scores.to_vec(), abs <= 1e-12, "testing {}", 1);
+
+        let empty: [f32; 0] = [];
+        assert_sorted!(empty, abs <= 0.0);
+        assert_sorted!([f32::NAN, 1.0, f32::NAN, 2.0], abs <= 0.0);
+        assert_sorted!([[1.0f32, 2.0], [1.0, 1.9], [1.5, 0.0]], abs_all <= 0.2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `3.0`,
+       right: `2.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(2251799813685248)`,
+     [abs] t: `0.5`: elements 2 and 3 are out of order, of 2 inversions"#)]
+    fn inversion() {
+        assert_sorted!([1.0f64, 2.0, 3.0, 2.0, 2.9, 1.0, 4.0], abs <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.5`: elements 0 and 1 are out of order, of 1 inversions: testing 2"#)]
+    fn inversion_with_message() {
+        assert_sorted!(vec![2.0f32, 1.0], abs <= 0.5, "testing {}", 2);
+    }
+}
+
+mod assert_float_idempotent {
+    use super::*;
+
+    fn project(v: [f32; 3]) -> [f32; 3] {
+        [v[0], v[1], 0.0]
+    }
+
+    #[test]
+    fn idempotent() {
+        let clamp = |x: f64| x.clamp(-1.0, 1.0);
+        assert_float_idempotent!(clamp, 2.5, ulps <= 0);
+        assert_float_idempotent!(clamp, -0.5, ulps <= 0,);
+        assert_float_idempotent!(project, [1.0, 2.0, 3.0], abs_all <= 0.0);
+        assert_float_idempotent!(f32::round, 2.5, abs <= 0.0, "testing {}", 1);
+
+        #[cfg(feature = "alloc")]
+        {
+            let normalize = |v: Vec<f64>| {
+                let len = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+                v.into_iter().map(|x| x / len).collect::<Vec<_>>()
+            };
+            assert_float_idempotent!(normalize, vec![1.0, 2.0, 3.0], ulps_all <= 1);
+        }
+    }
+
+    #[test]
+    fn evaluated_once() {
+        let mut calls = 0;
+        let mut x = || {
+            calls += 1;
+            4.0f64
+        };
+        assert_float_idempotent!(|x: f64| x.sqrt().max(1.0), x(), abs <= 1.0);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `float_idempotent!(f, x, abs <= t)`
+        once: `3.0`,
+       twice: `2.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(1125899906842624)`,
+     [abs] t: `0.25`"#)]
+    fn not_idempotent() {
+        assert_float_idempotent!(|x: f64| x * 0.5 + 1.0, 4.0, abs <= 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"[rmax] t: `0.03`: testing 2"#)]
+    fn not_idempotent_with_message() {
+        assert_float_idempotent!(|x: f32| x * 0.5 + 1.0, 4.0, rmax <= 0.01, "testing {}", 2);
+    }
+}
+
+mod tol_lint {
+    use float_eq::tol_lint;
+
+    const OK: u8 = 0;
+    const RELATIVE_TOO_LARGE: u8 = 1;
+    const NE_ZERO_ULPS: u8 = 2;
+
+    #[test]
+    fn literals() {
+        let cases = [
+            ("abs", "1e6", OK),
+            ("abs", "-0.0", OK),
+            ("rel", "9.99", OK),
+            ("rel", "0.000_001", OK),
+            ("rel", "100e-3", OK),
+            ("rel", "10.0", RELATIVE_TOO_LARGE),
+            ("rel_all", "1e6", RELATIVE_TOO_LARGE),
+            ("rmax", "1E+1", RELATIVE_TOO_LARGE),
+            ("rmin", "0.5e2", RELATIVE_TOO_LARGE),
+            ("r1st", "1_0f32", RELATIVE_TOO_LARGE),
+            ("r2nd_all", "0x10", OK),
+            ("r2nd", "0.0", OK),
+            ("ulps", "0", OK),
+            ("ulps", "1e1", OK),
+        ];
+        for &(check, tol, expected) in cases.iter() {
+            assert_eq!(
+                tol_lint("eq", check, tol, true),
+                expected,
+                "{} <= {}",
+                check,
+                tol
+            );
+        }
+
+        assert_eq!(tol_lint("ne", "ulps", "0", true), NE_ZERO_ULPS);
+        assert_eq!(tol_lint("ne", "ulps_all", "0_u32", true), NE_ZERO_ULPS);
+        assert_eq!(tol_lint("ne", "ulps", "0x0", true), NE_ZERO_ULPS);
+        assert_eq!(tol_lint("ne", "ulps", "1", true), OK);
+        assert_eq!(tol_lint("ne", "rmax", "1e6", true), RELATIVE_TOO_LARGE);
+        assert_eq!(tol_lint("ne", "abs", "0.0", true), OK);
+    }
+
+    #[test]
+    fn expressions() {
+        assert_eq!(tol_lint("eq", "rmax", "1e6 * tol", false), OK);
+        assert_eq!(tol_lint("eq", "abs", "- tol", false), OK);
+        assert_eq!(tol_lint("ne", "ulps", "0 * n", false), OK);
+        assert_eq!(tol_lint("eq", "abs", "MY_NAN_TOL", false), OK);
+    }
+
+    #[test]
+    #[should_panic(expected = "float_eq: a negative tolerance is never met")]
+    fn negative() {
+        tol_lint("eq", "abs", "- 1e-6", true);
+    }
+
+    #[test]
+    #[should_panic(expected = "float_eq: a NaN tolerance is never met")]
+    fn nan() {
+        tol_lint("eq", "abs", "core::f32::NAN", false);
+    }
+}
@@ -0,0 +1,179 @@
+use float_eq::{
+    assert_antisymmetric, assert_orthogonal, assert_symmetric, assert_unitary, gram_entry,
+    identity_entry, MatrixScalar, SquareMatrix,
+};
+
+#[test]
+fn square_matrix() {
+    let m = [[1.0f32, 2.0], [3.0, 4.0]];
+    assert_eq!(m.dim(), 2);
+    assert_eq!(*m.elem(0, 1), 2.0);
+    assert_eq!(*m.elem(1, 0), 3.0);
+
+    let empty: [[f64; 0]; 0] = [];
+    assert_eq!(empty.dim(), 0);
+}
+
+#[test]
+fn matrix_scalar() {
+    assert_eq!(<f32 as MatrixScalar>::ZERO, 0.0);
+    assert_eq!(<f64 as MatrixScalar>::ONE, 1.0);
+    assert_eq!((-2.0f64).conj(), -2.0);
+}
+
+#[test]
+fn gram() {
+    let m = [[1.0f64, 2.0], [3.0, 4.0]];
+    assert_eq!(gram_entry(&m, 0, 0), 5.0);
+    assert_eq!(gram_entry(&m, 0, 1), 11.0);
+    assert_eq!(gram_entry(&m, 1, 0), 11.0);
+    assert_eq!(gram_entry(&m, 1, 1), 25.0);
+
+    assert_eq!(identity_entry(&m, 0, 0), 1.0);
+    assert_eq!(identity_entry(&m, 0, 1), 0.0);
+}
+
+#[test]
+fn symmetric() {
+    let m = [[2.0f64, 0.5], [0.5, 3.0]];
+    assert_symmetric!(m, abs <= 0.0);
+    assert_symmetric!(m, abs <= 0.0,);
+    assert_symmetric!(m, abs <= 0.0, ulps <= 0, "testing {}", 1);
+
+    // the diagonal is never compared
+    assert_symmetric!([[f32::NAN]], abs <= 0.0);
+    assert_symmetric!(
+        [[[1.0f32, 2.0], [0.5, 0.5]], [[0.5, 0.5], [1.0, 2.0]]],
+        abs_all <= 1.5
+    );
+}
+
+#[test]
+fn tolerances_evaluated_once() {
+    let count = core::cell::Cell::new(0);
+    let counted = |x: f64| {
+        count.set(count.get() + 1);
+        x
+    };
+
+    let m = [[1.0f64, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    assert_symmetric!(m, abs <= counted(0.0), ulps <= 0);
+    assert_orthogonal!(m, abs <= counted(0.0), "{}", 1);
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.1`: matrix is not symmetric at (1, 2) and (2, 1)"#)]
+fn symmetric_fail() {
+    assert_symmetric!(
+        [[1.0f32, 2.0, 3.0], [2.0, 1.0, 1.0], [3.0, 1.5, 1.0]],
+        abs <= 0.1
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"matrix is not symmetric at (0, 1) and (1, 0): testing 2"#)]
+fn symmetric_fail_message() {
+    assert_symmetric!([[1.0f64, 2.0], [3.0, 1.0]], abs <= 0.1, "testing {}", 2);
+}
+
+#[test]
+fn antisymmetric() {
+    let m = [[0.0f32, 2.0, -1.0], [-2.0, 0.0, 0.5], [1.0, -0.5, 0.0]];
+    assert_antisymmetric!(m, abs <= 0.0);
+    assert_antisymmetric!(m, abs <= 0.0,);
+    assert_antisymmetric!(m, ulps <= 0, "testing {}", 1);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `-1.0`,
+    abs_diff: `2.0`,
+   ulps_diff: operands differ in sign; ULPs comparison not applicable,
+     [abs] t: `0.1`: matrix is not antisymmetric at (1, 1) and (1, 1)"#)]
+fn antisymmetric_fail_diagonal() {
+    assert_antisymmetric!([[0.0f64, 1.0], [-1.0, 1.0]], abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"matrix is not antisymmetric at (0, 1) and (1, 0): testing 2"#)]
+fn antisymmetric_fail_message() {
+    assert_antisymmetric!([[0.0f64, 1.0], [1.0, 0.0]], abs <= 0.1, "testing {}", 2);
+}
+
+#[test]
+fn orthogonal() {
+    let (s, c) = 0.3f64.sin_cos();
+    let rotation = [[c, -s], [s, c]];
+    assert_orthogonal!(rotation, abs <= 1e-15);
+    assert_orthogonal!(rotation, abs <= 1e-15,);
+    assert_orthogonal!(rotation, abs <= 1e-15, "testing {}", 1);
+
+    let permutation = [[0.0f32, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]];
+    assert_orthogonal!(permutation, abs <= 0.0);
+    assert_unitary!(permutation, abs <= 0.0);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `4.0`,
+       right: `1.0`,
+    abs_diff: `3.0`,
+   ulps_diff: `Some(16777216)`,
+     [abs] t: `0.001`: matrix is not orthogonal, M·Mᵀ differs from the identity at (1, 1)"#)]
+fn orthogonal_fail() {
+    assert_orthogonal!([[1.0f32, 0.0], [0.0, 2.0]], abs <= 0.001);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"matrix is not orthogonal, M·Mᵀ differs from the identity at (0, 1): testing 2"#
+)]
+fn orthogonal_fail_message() {
+    assert_orthogonal!([[1.0f64, 0.0], [1.0, 0.0]], abs <= 0.001, "testing {}", 2);
+}
+
+#[cfg(feature = "num")]
+mod complex {
+    use super::*;
+    use num_complex::{Complex32, Complex64};
+
+    #[test]
+    fn matrix_scalar() {
+        assert_eq!(Complex32::ZERO, Complex32::new(0.0, 0.0));
+        assert_eq!(Complex64::ONE, Complex64::new(1.0, 0.0));
+        assert_eq!(
+            MatrixScalar::conj(Complex64::new(1.0, 2.0)),
+            Complex64::new(1.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn unitary() {
+        let h = core::f64::consts::FRAC_1_SQRT_2;
+        let m = [
+            [Complex64::new(h, 0.0), Complex64::new(0.0, h)],
+            [Complex64::new(0.0, h), Complex64::new(h, 0.0)],
+        ];
+        assert_eq!(gram_entry(&m, 0, 1), Complex64::new(0.0, 0.0));
+        assert_unitary!(m, abs_all <= 1e-15);
+        assert_unitary!(m, abs_all <= 1e-15,);
+        assert_unitary!(m, abs_all <= 1e-15, "testing {}", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"matrix is not unitary, M·Mᴴ differs from the identity at (0, 0): testing 2"#
+    )]
+    fn unitary_fail() {
+        let i = Complex32::new(0.0, 1.0);
+        let one = Complex32::new(1.0, 0.0);
+        assert_unitary!([[one, i], [i, one]], abs_all <= 0.1, "testing {}", 2);
+    }
+}
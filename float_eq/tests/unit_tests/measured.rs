@@ -0,0 +1,92 @@
+use float_eq::{assert_measured_eq, Measured};
+
+#[test]
+fn accessors() {
+    let a = Measured::new(1.5f32, 0.25);
+    assert_eq!(a.value(), &1.5);
+    assert_eq!(a.sigma(), &0.25);
+    assert_eq!(Measured::exact(2.0f64).sigma(), &0.0);
+}
+
+#[test]
+fn interval() {
+    assert_eq!(Measured::new(1.0f64, 0.25).interval(2.0), (0.5, 1.5));
+    assert_eq!(Measured::new(1.0f64, -0.25).interval(2.0), (0.5, 1.5));
+    assert_eq!(Measured::new(1.0f64, 0.25).interval(-2.0), (0.5, 1.5));
+    assert_eq!(Measured::exact(1.0f32).interval(3.0), (1.0, 1.0));
+}
+
+#[test]
+fn sigma_diff() {
+    let a = Measured::new(1.0f64, 0.25);
+    assert_eq!(a.sigma_diff(&a), 0.0);
+    assert_eq!(a.sigma_diff(&Measured::new(2.0, 0.25)), 2.0);
+    assert_eq!(Measured::new(2.0, 0.25).sigma_diff(&a), 2.0);
+    assert_eq!(a.sigma_diff(&Measured::new(2.0, -0.25)), 2.0);
+    assert_eq!(a.sigma_diff(&Measured::exact(1.5)), 2.0);
+
+    let exact = Measured::exact(1.0f32);
+    assert_eq!(exact.sigma_diff(&exact), 0.0);
+    assert_eq!(exact.sigma_diff(&Measured::exact(1.5)), f32::INFINITY);
+    assert_eq!(
+        Measured::new(1.0f32, f32::INFINITY).sigma_diff(&Measured::exact(1e30)),
+        0.0
+    );
+    let inf = Measured::exact(f32::INFINITY);
+    assert_eq!(inf.sigma_diff(&inf), 0.0);
+}
+
+#[test]
+fn sigma_diff_nan() {
+    let a = Measured::new(1.0f64, 0.25);
+    assert!(a.sigma_diff(&Measured::new(f64::NAN, 0.25)).is_nan());
+    assert!(a.sigma_diff(&Measured::new(1.0, f64::NAN)).is_nan());
+    assert!(a.sigma_diff(&Measured::new(2.0, f64::NAN)).is_nan());
+}
+
+#[test]
+fn overlaps() {
+    let a = Measured::new(1.0f64, 0.25);
+    let b = Measured::new(2.0f64, 0.25);
+    assert!(a.overlaps(&b, 2.0));
+    assert!(b.overlaps(&a, 2.0));
+    assert!(!a.overlaps(&b, 1.9));
+    assert!(a.overlaps(&a, 0.0));
+    assert!(!a.overlaps(&Measured::new(f64::NAN, 0.25), f64::INFINITY));
+    assert!(!a.overlaps(&a, f64::NAN));
+}
+
+#[test]
+fn asserts() {
+    let a = Measured::new(1.0f32, 0.25);
+    let b = Measured::new(1.5f32, 0.0);
+    assert_measured_eq!(a, b, k <= 2.0);
+    assert_measured_eq!(a, b, k <= 2.0,);
+    assert_measured_eq!(a, b, k <= 2.0, "with {}", "message");
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `measured_eq!(left, right, k <= t)`
+        left: `Measured { value: 1.0, sigma: 0.25 }`,
+       right: `Measured { value: 2.0, sigma: 0.25 }`,
+  sigma_diff: `2.0`,
+       [k] t: `1.5`"#)]
+fn assert_fail_message() {
+    assert_measured_eq!(
+        Measured::new(1.0f32, 0.25),
+        Measured::new(2.0, 0.25),
+        k <= 1.5
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"[k] t: `1.5`: in a message"#)]
+fn assert_fail_custom_message() {
+    assert_measured_eq!(
+        Measured::new(1.0f32, 0.25),
+        Measured::new(2.0, 0.25),
+        k <= 1.5,
+        "in a {}",
+        "message"
+    );
+}
@@ -0,0 +1,90 @@
+use float_eq::{worst_mismatches, worst_mismatches_rel, Mismatch};
+
+#[test]
+fn worst_mismatches_ulps() {
+    let a = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+    let b = [1.0f32, 2.000_000_2, 3.000_001, 4.0, -5.0];
+
+    let worst = worst_mismatches(&a, &b, 2).unwrap();
+    assert_eq!(
+        worst,
+        vec![
+            Mismatch {
+                index: 4,
+                a: 5.0,
+                b: -5.0,
+                abs_diff: 10.0,
+                rel_diff: 2.0,
+                ulps_diff: None,
+            },
+            Mismatch {
+                index: 2,
+                a: 3.0,
+                b: 3.000_001,
+                abs_diff: 3.000_001 - 3.0,
+                rel_diff: (3.000_001 - 3.0) / 3.000_001,
+                ulps_diff: Some(4),
+            },
+        ]
+    );
+
+    let indices: Vec<_> = worst_mismatches(&a, &b, 10)
+        .unwrap()
+        .iter()
+        .map(|m| m.index)
+        .collect();
+    assert_eq!(indices, vec![4, 2, 1]);
+}
+
+#[test]
+fn worst_mismatches_by_rel() {
+    let a = [1.0f64, 100.0, 3.0, f64::NAN, 0.5];
+    let b = [1.5f64, 101.0, 3.0, 1.0, 1.0];
+
+    let indices: Vec<_> = worst_mismatches_rel(&a, &b, 10)
+        .unwrap()
+        .iter()
+        .map(|m| m.index)
+        .collect();
+    assert_eq!(indices, vec![3, 4, 0, 1]);
+
+    let worst = worst_mismatches_rel(&a, &b, 2).unwrap();
+    assert_eq!(worst.len(), 2);
+    assert_eq!(worst[1].index, 4);
+    assert_eq!(worst[1].rel_diff, 0.5);
+}
+
+#[test]
+fn ties_prefer_earliest_index() {
+    let a = [1.0f32, 2.0, 1.0, 2.0];
+    let b = [1.5f32, 2.0, 1.5, 2.0];
+
+    let indices: Vec<_> = worst_mismatches(&a, &b, 1)
+        .unwrap()
+        .iter()
+        .map(|m| m.index)
+        .collect();
+    assert_eq!(indices, vec![0]);
+
+    let indices: Vec<_> = worst_mismatches_rel(&a, &b, 2)
+        .unwrap()
+        .iter()
+        .map(|m| m.index)
+        .collect();
+    assert_eq!(indices, vec![0, 2]);
+}
+
+#[test]
+fn no_mismatches() {
+    let a = [1.0f32, 2.0];
+    assert_eq!(worst_mismatches(&a, &a, 3), Some(vec![]));
+    assert_eq!(worst_mismatches_rel(&a, &a, 3), Some(vec![]));
+    assert_eq!(worst_mismatches(&a, &[1.0, 3.0], 0), Some(vec![]));
+    assert_eq!(worst_mismatches(&[0.0f32], &[-0.0], 3), Some(vec![]));
+}
+
+#[test]
+fn different_lengths() {
+    assert_eq!(worst_mismatches(&[1.0f32, 2.0], &[1.0], 1), None);
+    assert_eq!(worst_mismatches_rel(&[1.0f32], &[1.0, 2.0], 1), None);
+}
@@ -0,0 +1,169 @@
+#![allow(clippy::float_cmp)]
+
+use float_eq::{
+    assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, FloatEqBits, TolOps,
+    UlpsTol, UlpsTolOps,
+};
+use nalgebra::{Matrix2, Quaternion, UnitQuaternion, Vector3};
+
+#[test]
+fn float_eq() {
+    let a = Vector3::new(0.999_999_9f32, 2.0, 4.0);
+    let b = Vector3::new(1.0f32, 2.0, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= Vector3::new(1.0 * eps, 0.0, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vector3::new(0.5 * eps, 0.0, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vector3::new(1.0 * eps, 0.0, 2.0 * eps));
+
+    assert_float_eq!(a, b, rmax <= Vector3::new(1.0 * eps, 0.0, 1.0 * eps));
+    assert_float_ne!(a, b, rmax <= Vector3::new(0.5 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, rmin <= Vector3::new(2.0 * eps, 0.0, 2.0 * eps));
+    assert_float_ne!(a, b, rmin <= Vector3::new(2.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, r1st <= Vector3::new(2.0 * eps, 0.0, 1.0 * eps));
+    assert_float_ne!(a, b, r1st <= Vector3::new(1.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, r2nd <= Vector3::new(1.0 * eps, 0.0, 2.0 * eps));
+    assert_float_ne!(a, b, r2nd <= Vector3::new(1.0 * eps, 0.0, 1.0 * eps));
+
+    assert_float_eq!(a, b, ulps <= Vector3::new(2, 0, 2));
+    assert_float_ne!(a, b, ulps <= Vector3::new(1, 0, 2));
+    assert_float_ne!(a, b, ulps <= Vector3::new(2, 0, 1));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Vector3::new(0.999_999_9f32, 2.0, 4.0);
+    let b = Vector3::new(1.0f32, 2.0, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= (4.0 * eps));
+    assert_float_ne!(a, b, abs_all <= (2.0 * eps));
+
+    assert_float_eq!(a, b, rmax_all <= (1.0 * eps));
+    assert_float_ne!(a, b, rmax_all <= (0.5 * eps));
+
+    assert_float_eq!(a, b, rmin_all <= (2.0 * eps));
+    assert_float_ne!(a, b, rmin_all <= (1.0 * eps));
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn matrix_elements() {
+    // Constructed in row-major order, but stored and compared column-major.
+    let a = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(1.0f64, 2.5, 3.0, 4.0);
+
+    assert_float_eq!(a, b, abs <= Matrix2::new(0.0, 0.5, 0.0, 0.0));
+    assert_float_ne!(a, b, abs <= Matrix2::new(0.0, 0.0, 0.5, 0.0));
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, ulps <= UlpsTol::<Matrix2<f64>>::from_element(1));
+
+    assert_eq!(a.debug_abs_diff(&b), Matrix2::new(0.0, 0.5, 0.0, 0.0));
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Matrix2::new(Some(0), Some(1_125_899_906_842_624), Some(0), Some(0))
+    );
+}
+
+#[test]
+fn ulps_tol_ops() {
+    let a: UlpsTol<Matrix2<f32>> = Matrix2::new(1, 2, 3, u32::MAX);
+    let b = Matrix2::new(2, 2, 2, 2);
+    assert_eq!(a.saturating_add(&b), Matrix2::new(3, 4, 5, u32::MAX));
+    assert_eq!(a.max_elementwise(&b), Matrix2::new(2, 2, 3, u32::MAX));
+
+    let a: UlpsTol<Quaternion<f64>> = Quaternion::new(1, 2, 3, 4);
+    let b = Quaternion::new(4, 3, 2, 1);
+    assert_eq!(a.saturating_add(&b), Quaternion::new(5, 5, 5, 5));
+    assert_eq!(a.max_elementwise(&b), Quaternion::new(4, 3, 3, 4));
+}
+
+#[test]
+fn tol_ops() {
+    let a = Matrix2::new(0.5f32, 0.0, 1.0, 0.25);
+    let b = Matrix2::new(0.25, 0.25, 0.25, 0.25);
+    assert_eq!(a.max_elementwise(&b), Matrix2::new(0.5, 0.25, 1.0, 0.25));
+    assert_eq!(a.min_elementwise(&b), Matrix2::new(0.25, 0.0, 0.25, 0.25));
+    assert_eq!(a.scale(2.0), Matrix2::new(1.0, 0.0, 2.0, 0.5));
+
+    let a = Quaternion::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = Quaternion::new(4.0, 3.0, 2.0, 1.0);
+    assert_eq!(a.max_elementwise(&b), Quaternion::new(4.0, 3.0, 3.0, 4.0));
+    assert_eq!(a.min_elementwise(&b), Quaternion::new(1.0, 2.0, 2.0, 1.0));
+    assert_eq!(a.scale(0.5), Quaternion::new(0.5, 1.0, 1.5, 2.0));
+}
+
+#[test]
+fn float_eq_bits() {
+    let a = Vector3::new(0.0f32, 1.0, f32::NAN);
+    assert!(a.eq_bits(&a));
+    assert!(!a.eq_bits(&Vector3::new(-0.0f32, 1.0, f32::NAN)));
+}
+
+#[test]
+fn debug_tol() {
+    let a = Vector3::new(2.0f32, 4.25, 1.0);
+    let b = Vector3::new(2.5f32, 4.0, 1.0);
+    let tol = Vector3::new(0.1, 0.2, 0.0);
+
+    assert_eq!(a.debug_abs_tol(&b, &tol), Vector3::new(0.1, 0.2, 0.0));
+    assert_eq!(a.debug_rmax_tol(&b, &tol), Vector3::new(0.25, 0.85, 0.0));
+    assert_eq!(a.debug_rmin_tol(&b, &tol), Vector3::new(0.2, 0.8, 0.0));
+    assert_eq!(
+        a.debug_ulps_tol(&b, &Vector3::new(1, 2, 3)),
+        Vector3::new(1, 2, 3)
+    );
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Vector3::new(0.2, 0.2, 0.2));
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.2), Vector3::new(0.5, 0.85, 0.2));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Vector3::new(2, 2, 2));
+}
+
+#[test]
+fn quaternion() {
+    let a = Quaternion::new(1.0f32, 0.0, 0.5, 0.0);
+    let b = Quaternion::new(1.0f32, 0.0, 0.5 + f32::EPSILON, 0.0);
+
+    assert_float_eq!(a, b, ulps <= Quaternion::new(0, 0, 2, 0));
+    assert_float_ne!(a, b, ulps <= Quaternion::new(2, 2, 1, 2));
+    assert_float_eq!(a, b, abs_all <= f32::EPSILON);
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        Quaternion::new(0.0, 0.0, f32::EPSILON, 0.0)
+    );
+}
+
+#[test]
+fn unit_quaternion() {
+    let a = UnitQuaternion::new_unchecked(Quaternion::new(0.6f64, 0.0, 0.8, 0.0));
+    let b = UnitQuaternion::new_unchecked(Quaternion::new(0.6f64, 0.0, 0.800_000_1, 0.0));
+    let tol = Quaternion::new(0.0, 0.0, 1e-7, 0.0);
+
+    assert_float_eq!(a, b, abs <= Quaternion::new(1e-6, 1e-6, 1e-6, 1e-6));
+    assert_float_ne!(a, b, abs <= Quaternion::new(1e-6, 1e-6, 1e-8, 1e-6));
+    assert_float_eq!(a, b, abs_all <= 1e-6);
+    assert_float_ne!(a, b, abs_all <= 1e-8);
+    assert_eq!(a.debug_abs_tol(&b, &tol), tol);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t, ulps <= t)`
+        left: `[[1.0, 2.0, 3.0]]`,
+       right: `[[1.0, 2.5, 3.0]]`,
+    abs_diff: `[[0.0, 0.5, 0.0]]`,
+   ulps_diff: `[[Some(0), Some(2097152), Some(0)]]`,
+     [abs] t: `[[0.1, 0.25, 0.1]]`,
+    [ulps] t: `[[1, 2, 3]]`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        Vector3::new(1.0f32, 2.0, 3.0),
+        Vector3::new(1.0f32, 2.5, 3.0),
+        abs <= Vector3::new(0.1, 0.25, 0.1),
+        ulps <= Vector3::new(1, 2, 3)
+    );
+}
@@ -0,0 +1,216 @@
+use float_eq::{abs_neighbors, float_eq, ulps_neighbors, FloatPrimitive};
+
+fn next(x: f32, ulps: i64) -> f32 {
+    f32::from_ulps_position(x.ulps_position() + ulps)
+}
+
+#[test]
+fn ulps_position() {
+    assert_eq!(0.0f32.ulps_position(), 0);
+    assert_eq!((-0.0f32).ulps_position(), 0);
+    assert_eq!(f32::from_ulps_position(1), 1e-45);
+    assert_eq!(f32::from_ulps_position(-1), -1e-45);
+    assert_eq!(
+        f64::from_ulps_position(1.0f64.ulps_position() + 1),
+        1.0 + f64::EPSILON
+    );
+    assert_eq!(f32::from_ulps_position(i64::MAX), f32::INFINITY);
+    assert_eq!(f64::from_ulps_position(i64::MIN), f64::NEG_INFINITY);
+    assert!(f32::from_ulps_position(0).is_sign_positive());
+}
+
+#[test]
+fn ulps_within_and_outside() {
+    for &x in &[
+        1.0f32,
+        -1.0,
+        1e-45,
+        -1e-45,
+        0.0,
+        -0.0,
+        f32::MAX,
+        f32::MIN,
+        3e-44,
+    ] {
+        for &n in &[0, 1, 2, 5] {
+            let neighbors = ulps_neighbors(x, n);
+            assert!(!neighbors.is_empty());
+            for i in 0..neighbors.len() {
+                let y = neighbors.nth(i).unwrap();
+                assert!(float_eq!(x, y, ulps <= n), "{} {} {}", x, y, n);
+            }
+            assert_eq!(neighbors.nth(neighbors.len()), None);
+            if let Some(y) = neighbors.below() {
+                assert!(!float_eq!(x, y, ulps <= n), "{} {} {}", x, y, n);
+            }
+            if let Some(y) = neighbors.above() {
+                assert!(!float_eq!(x, y, ulps <= n), "{} {} {}", x, y, n);
+            }
+        }
+    }
+}
+
+#[test]
+fn ulps_bounds() {
+    let neighbors = ulps_neighbors(1.0f32, 2);
+    assert_eq!(neighbors.len(), 5);
+    assert_eq!(neighbors.min(), Some(next(1.0, -2)));
+    assert_eq!(neighbors.max(), Some(next(1.0, 2)));
+    assert_eq!(neighbors.below(), Some(next(1.0, -3)));
+    assert_eq!(neighbors.above(), Some(next(1.0, 3)));
+
+    // never crosses zero
+    let neighbors = ulps_neighbors(1e-45f32, 4);
+    assert_eq!(neighbors.min(), Some(0.0));
+    assert_eq!(neighbors.below(), Some(-1e-45));
+    let neighbors = ulps_neighbors(-1e-45f32, 4);
+    assert_eq!(neighbors.max(), Some(-1e-45));
+    assert_eq!(neighbors.above(), Some(0.0));
+
+    // stops at the infinities
+    let neighbors = ulps_neighbors(f64::MAX, u64::MAX);
+    assert_eq!(neighbors.max(), Some(f64::INFINITY));
+    assert_eq!(neighbors.above(), None);
+    assert_eq!(neighbors.min(), Some(0.0));
+    let neighbors = ulps_neighbors(f32::NEG_INFINITY, 1);
+    assert_eq!(neighbors.min(), Some(f32::NEG_INFINITY));
+    assert_eq!(neighbors.below(), None);
+}
+
+#[test]
+fn ulps_nan() {
+    let neighbors = ulps_neighbors(f32::NAN, 4);
+    assert!(neighbors.is_empty());
+    assert_eq!(neighbors.len(), 0);
+    assert_eq!(neighbors.min(), None);
+    assert_eq!(neighbors.max(), None);
+    assert_eq!(neighbors.nth(0), None);
+    assert_eq!(neighbors.below(), None);
+    assert_eq!(neighbors.above(), None);
+}
+
+#[test]
+fn abs_within_and_outside() {
+    for &x in &[1.0f32, -1.0, 0.1, 1e-40, 0.0, 1e30, f32::MAX] {
+        for &eps in &[0.0f32, 1e-45, 1e-7, 0.1, 1.0] {
+            let neighbors = abs_neighbors(x, eps);
+            assert!(!neighbors.is_empty());
+            let len = neighbors.len();
+            for i in (0..len.min(100)).chain(len.saturating_sub(100)..len) {
+                let y = neighbors.nth(i).unwrap();
+                assert!(float_eq!(x, y, abs <= eps), "{} {} {}", x, y, eps);
+            }
+            if let Some(y) = neighbors.below() {
+                assert!(!float_eq!(x, y, abs <= eps), "{} {} {}", x, y, eps);
+            }
+            if let Some(y) = neighbors.above() {
+                assert!(!float_eq!(x, y, abs <= eps), "{} {} {}", x, y, eps);
+            }
+        }
+    }
+}
+
+#[test]
+fn abs_bounds() {
+    // 1.0 - 0.49999999999999994 rounds to 0.5
+    let neighbors = abs_neighbors(1.0f64, 0.5);
+    assert_eq!(neighbors.min(), Some(0.5 - f64::EPSILON / 4.0));
+    assert_eq!(neighbors.max(), Some(1.5));
+
+    let neighbors = abs_neighbors(0.0f32, 0.0);
+    assert_eq!(neighbors.len(), 1);
+    assert_eq!(neighbors.min(), Some(0.0));
+    assert_eq!(neighbors.below(), Some(-1e-45));
+    assert_eq!(neighbors.above(), Some(1e-45));
+
+    let neighbors = abs_neighbors(1.0f32, f32::INFINITY);
+    assert_eq!(neighbors.min(), Some(f32::NEG_INFINITY));
+    assert_eq!(neighbors.max(), Some(f32::INFINITY));
+    assert_eq!(neighbors.below(), None);
+    assert_eq!(neighbors.above(), None);
+
+    let neighbors = abs_neighbors(f32::INFINITY, 1.0);
+    assert_eq!(neighbors.len(), 1);
+    assert_eq!(neighbors.below(), Some(f32::MAX));
+}
+
+#[test]
+fn abs_nan() {
+    assert!(abs_neighbors(f32::NAN, 1.0).is_empty());
+}
+
+#[test]
+#[cfg(not(feature = "validate-tol"))]
+fn abs_nan_tol() {
+    let neighbors = abs_neighbors(1.0f64, f64::NAN);
+    assert_eq!((neighbors.min(), neighbors.max()), (Some(1.0), Some(1.0)));
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use arbitrary::Unstructured;
+    use float_eq::{abs_neighbors, float_eq, ulps_neighbors};
+
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        (0..=255u8).map(|i| {
+            (0..16)
+                .map(|j| i.wrapping_mul(31).wrapping_add(j * 7))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn within() {
+        let ulps = ulps_neighbors(1.0f32, 4);
+        let abs = abs_neighbors(-2.0f64, 0.01);
+        for input in inputs() {
+            let x = ulps
+                .arbitrary_within(&mut Unstructured::new(&input))
+                .unwrap();
+            assert!(float_eq!(x, 1.0, ulps <= 4));
+            let x = abs
+                .arbitrary_within(&mut Unstructured::new(&input))
+                .unwrap();
+            assert!(float_eq!(x, -2.0, abs <= 0.01));
+        }
+    }
+
+    #[test]
+    fn outside() {
+        let ulps = ulps_neighbors(1.0f32, 4);
+        let abs = abs_neighbors(-2.0f64, 0.01);
+        let (mut below, mut above) = (false, false);
+        for input in inputs() {
+            let x = ulps
+                .arbitrary_outside(&mut Unstructured::new(&input))
+                .unwrap();
+            assert!(!x.is_nan());
+            assert!(!float_eq!(x, 1.0, ulps <= 4));
+            below |= x < 1.0;
+            above |= x > 1.0;
+            let x = abs
+                .arbitrary_outside(&mut Unstructured::new(&input))
+                .unwrap();
+            assert!(!float_eq!(x, -2.0, abs <= 0.01));
+        }
+        assert!(below && above);
+    }
+
+    #[test]
+    fn empty() {
+        let input = [0u8; 16];
+        let nan = ulps_neighbors(f32::NAN, 1);
+        assert!(nan
+            .arbitrary_within(&mut Unstructured::new(&input))
+            .is_err());
+        let x = nan
+            .arbitrary_outside(&mut Unstructured::new(&input))
+            .unwrap();
+        assert!(!x.is_nan());
+
+        let everything = abs_neighbors(0.0f32, f32::INFINITY);
+        assert!(everything
+            .arbitrary_outside(&mut Unstructured::new(&input))
+            .is_err());
+    }
+}
@@ -3,6 +3,7 @@
 use core::f32;
 use float_eq::{
     assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, ComplexUlps32, DebugUlpsDiff,
+    TolOps, UlpsTolOps,
 };
 use num_complex::Complex32;
 
@@ -15,6 +16,23 @@ fn complex_ulps() {
     assert_ne!(a, b);
 }
 
+#[test]
+fn complex_ulps_ops() {
+    let a = ComplexUlps32::new(u32::MAX, 2);
+    let b = ComplexUlps32::new(1, 3);
+    assert_eq!(a.saturating_add(&b), ComplexUlps32::new(u32::MAX, 5));
+    assert_eq!(a.max_elementwise(&b), ComplexUlps32::new(u32::MAX, 3));
+}
+
+#[test]
+fn complex_tol_ops() {
+    let a = Complex32::new(0.5, 0.125);
+    let b = Complex32::new(0.25, 0.25);
+    assert_eq!(a.max_elementwise(&b), Complex32::new(0.5, 0.25));
+    assert_eq!(a.min_elementwise(&b), Complex32::new(0.25, 0.125));
+    assert_eq!(a.scale(2.0), Complex32::new(1.0, 0.25));
+}
+
 #[test]
 fn float_eq() {
     let a = Complex32::new(0.999_999_9f32, 4.0);
@@ -0,0 +1,139 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq, AssertFloatEqAll,
+    CollectionCmpPolicy, FloatEq, FloatEqAll, LengthStrict, MissingAsZero, Prefix, Unordered,
+    WithPolicy,
+};
+
+#[test]
+fn accessors() {
+    let a = WithPolicy::new(&[1.0f32, 2.0][..], Prefix);
+    assert_eq!(a.values(), &[1.0, 2.0]);
+    assert_eq!(a.policy(), &Prefix);
+}
+
+#[test]
+fn length_strict() {
+    let eq = |a: &f32, b: &f32| a == b;
+    assert!(LengthStrict.eq_by(&[1.0f32, 2.0], &[1.0, 2.0], eq));
+    assert!(!LengthStrict.eq_by(&[1.0f32, 2.0], &[1.0], eq));
+    assert!(!LengthStrict.eq_by(&[1.0f32, 2.0], &[2.0, 1.0], eq));
+}
+
+#[test]
+fn prefix() {
+    let eq = |a: &f32, b: &f32| a == b;
+    assert!(Prefix.eq_by(&[1.0f32, 2.0], &[1.0, 2.0], eq));
+    assert!(Prefix.eq_by(&[1.0f32, 2.0], &[1.0], eq));
+    assert!(Prefix.eq_by(&[1.0f32], &[1.0, 2.0], eq));
+    assert!(Prefix.eq_by(&[], &[1.0, 2.0], eq));
+    assert!(!Prefix.eq_by(&[1.0f32, 2.0], &[2.0], eq));
+}
+
+#[test]
+fn missing_as_zero() {
+    let eq = |a: &f32, b: &f32| a == b;
+    assert!(MissingAsZero.eq_by(&[1.0f32, 2.0], &[1.0, 2.0], eq));
+    assert!(MissingAsZero.eq_by(&[1.0f32, 0.0, 0.0], &[1.0], eq));
+    assert!(MissingAsZero.eq_by(&[1.0f32], &[1.0, 0.0], eq));
+    assert!(MissingAsZero.eq_by(&[], &[0.0f32], eq));
+    assert!(!MissingAsZero.eq_by(&[1.0f32, 2.0], &[1.0], eq));
+    assert!(!MissingAsZero.eq_by(&[1.0f32], &[1.0, 2.0], eq));
+}
+
+#[test]
+fn unordered() {
+    let eq = |a: &f32, b: &f32| a == b;
+    assert!(Unordered.eq_by(&[], &[], eq));
+    assert!(Unordered.eq_by(&[1.0f32, 2.0, 3.0], &[3.0, 1.0, 2.0], eq));
+    assert!(Unordered.eq_by(&[1.0f32, 1.0, 2.0], &[1.0, 2.0, 1.0], eq));
+    assert!(!Unordered.eq_by(&[1.0f32, 1.0, 2.0], &[1.0, 2.0, 2.0], eq));
+    assert!(!Unordered.eq_by(&[1.0f32, 2.0], &[1.0, 2.0, 3.0], eq));
+}
+
+#[test]
+fn unordered_needs_reassignment() {
+    // a greedy matching pairs 1.0 with 1.5, leaving nothing for 2.0
+    let eq = |a: &f32, b: &f32| (a - b).abs() <= 0.5;
+    assert!(Unordered.eq_by(&[1.0f32, 2.0], &[1.5, 0.5], eq));
+    assert!(!Unordered.eq_by(&[1.0f32, 2.0], &[0.5, 0.6], eq));
+}
+
+#[test]
+fn float_eq() {
+    let a = WithPolicy::new(&[1.0f32, 2.0][..], MissingAsZero);
+    let b = WithPolicy::new(&[1.5f32, 2.0, 0.0][..], MissingAsZero);
+
+    assert!(a.eq_abs(&b, &0.5));
+    assert!(a.ne_abs(&b, &0.4));
+    assert!(a.eq_rmax(&b, &0.34));
+    assert!(a.ne_rmax(&b, &0.33));
+    assert!(a.eq_rmin(&b, &0.5));
+    assert!(a.ne_rmin(&b, &0.4));
+    assert!(a.eq_r1st(&b, &0.5));
+    assert!(a.ne_r1st(&b, &0.4));
+    assert!(a.eq_r2nd(&b, &0.34));
+    assert!(a.ne_r2nd(&b, &0.33));
+    assert!(float_eq!(a, b, abs <= 0.5));
+    assert!(float_ne!(a, b, ulps <= 4));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = WithPolicy::new(&[1.0f32, 2.0, 3.0][..], Unordered);
+    let b = WithPolicy::new(&[3.0f32, 2.0, 1.000_000_1][..], Unordered);
+
+    assert!(a.eq_abs_all(&b, &0.000_001));
+    assert!(a.ne_abs_all(&b, &0.0));
+    assert!(a.eq_rmax_all(&b, &0.000_001));
+    assert!(a.eq_rmin_all(&b, &0.000_001));
+    assert!(a.eq_r1st_all(&b, &0.000_001));
+    assert!(a.eq_r2nd_all(&b, &0.000_001));
+    assert!(a.eq_ulps_all(&b, &1));
+    assert!(a.ne_ulps_all(&b, &0));
+    assert!(float_eq!(a, b, ulps_all <= 1));
+}
+
+#[test]
+fn prefix_asserts() {
+    let a = WithPolicy::new(&[1.0f64, 2.0, 3.0][..], Prefix);
+    let b = WithPolicy::new(&[1.0f64, 2.0][..], Prefix);
+    assert_float_eq!(a, b, abs <= 0.0);
+    assert_float_eq!(a, b, rmax_all <= 0.0);
+
+    let c = WithPolicy::new(&[1.0f64, 2.5][..], Prefix);
+    assert_float_ne!(a, c, abs <= 0.4);
+}
+
+#[test]
+fn debug_diff() {
+    let a = WithPolicy::new(&[1.0f32, 2.0][..], MissingAsZero);
+    let b = WithPolicy::new(&[1.5f32, 2.0, 0.5][..], MissingAsZero);
+
+    assert_eq!(a.debug_abs_diff(&b), vec![0.5, 0.0, 0.5]);
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        vec![Some(4_194_304), Some(0), Some(1_056_964_608)]
+    );
+    assert_eq!(a.debug_abs_tol(&b, &0.1), vec![0.1, 0.1, 0.1]);
+    assert_eq!(a.debug_rmax_tol(&b, &0.5), vec![0.75, 1.0, 0.25]);
+    assert_eq!(a.debug_ulps_tol(&b, &1), vec![1, 1, 1]);
+    assert_eq!(a.debug_abs_all_tol(&b, &0.1), vec![0.1, 0.1, 0.1]);
+    assert_eq!(a.debug_ulps_all_tol(&b, &1), vec![1, 1, 1]);
+
+    let a = WithPolicy::new(&[1.0f32, 2.0, 3.0][..], Prefix);
+    let b = WithPolicy::new(&[1.5f32][..], Prefix);
+    assert_eq!(a.debug_abs_diff(&b), vec![0.5]);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `WithPolicy { values: [1.0, 2.0], policy: MissingAsZero }`,
+       right: `WithPolicy { values: [1.0, 2.0, 1.0], policy: MissingAsZero }`,
+    abs_diff: `[0.0, 0.0, 1.0]`,
+   ulps_diff: `[Some(0), Some(0), Some(1065353216)]`,
+     [abs] t: `[0.1, 0.1, 0.1]`"#)]
+fn assert_fail_message() {
+    let a = WithPolicy::new(&[1.0f32, 2.0][..], MissingAsZero);
+    let b = WithPolicy::new(&[1.0f32, 2.0, 1.0][..], MissingAsZero);
+    assert_float_eq!(a, b, abs <= 0.1);
+}
@@ -0,0 +1,53 @@
+use float_eq::{FloatEq, PreparedExpected};
+
+#[test]
+fn abs() {
+    let prepared = PreparedExpected::abs(2.0f32, &0.5);
+    assert_eq!(prepared.expected(), &2.0);
+    assert_eq!(prepared.tol(), &0.5);
+
+    assert!(prepared.eq_expected(&2.0));
+    assert!(prepared.eq_expected(&1.5));
+    assert!(prepared.eq_expected(&2.5));
+    assert!(prepared.ne_expected(&1.4));
+    assert!(prepared.ne_expected(&2.6));
+    assert!(prepared.ne_expected(&f32::NAN));
+}
+
+#[test]
+fn r2nd() {
+    let prepared = PreparedExpected::r2nd(-4.0f64, &0.25);
+    assert_eq!(prepared.tol(), &1.0);
+
+    assert!(prepared.eq_expected(&-3.0));
+    assert!(prepared.eq_expected(&-5.0));
+    assert!(prepared.ne_expected(&-2.9));
+    assert!(prepared.ne_expected(&-5.1));
+}
+
+#[test]
+fn r2nd_matches_eq_r2nd() {
+    let expected = [1.0f32, -100.0, 0.0, f32::INFINITY];
+    let tol = [0.1, 0.01, 0.1, 0.1];
+    let prepared = PreparedExpected::r2nd(expected, &tol);
+
+    let candidates = [
+        [1.1f32, -101.0, 0.0, f32::INFINITY],
+        [1.2, -100.0, 0.0, f32::INFINITY],
+        [1.0, -98.0, 0.0, f32::INFINITY],
+        [1.0, -100.0, 0.1, f32::INFINITY],
+        [1.0, -100.0, 0.0, f32::NEG_INFINITY],
+        [1.0, -100.0, 0.0, f32::MAX],
+    ];
+    for c in candidates.iter() {
+        assert_eq!(prepared.eq_expected(c), c.eq_r2nd(&expected, &tol));
+    }
+}
+
+#[test]
+fn into_expected() {
+    let prepared = PreparedExpected::abs((1.0f32, 2.0f64), &(0.1, 0.2));
+    assert_eq!(prepared.clone().into_expected(), (1.0, 2.0));
+    assert!(prepared.eq_expected(&(1.05, 2.15)));
+    assert!(prepared.ne_expected(&(1.05, 2.25)));
+}
@@ -1,5 +1,7 @@
 mod assert_float_eq;
 mod eq_abs;
+mod eq_abs_ulps;
+mod eq_percent;
 mod eq_rmax;
 mod eq_rmin;
 mod eq_ulps;
@@ -101,6 +101,9 @@ macro_rules! impl_tests {
                 assert_eq!(a.debug_rmax_tol(&b, &0.5), 12.5);
                 assert_eq!(b.debug_rmax_tol(&a, &0.5), 12.5);
 
+                assert_eq!(a.debug_percent_tol(&b, &50.0), 12.5);
+                assert_eq!(b.debug_percent_tol(&a, &50.0), 12.5);
+
                 assert_eq!(a.debug_rmin_tol(&b, &0.5), 5.0);
                 assert_eq!(b.debug_rmin_tol(&a, &0.5), 5.0);
 
@@ -128,6 +131,9 @@ macro_rules! impl_tests {
                 assert_eq!(a.debug_rmax_all_tol(&b, &0.5), 12.5);
                 assert_eq!(b.debug_rmax_all_tol(&a, &0.5), 12.5);
 
+                assert_eq!(a.debug_percent_all_tol(&b, &50.0), 12.5);
+                assert_eq!(b.debug_percent_all_tol(&a, &50.0), 12.5);
+
                 assert_eq!(a.debug_rmin_all_tol(&b, &0.5), 5.0);
                 assert_eq!(b.debug_rmin_all_tol(&a, &0.5), 5.0);
 
@@ -0,0 +1,116 @@
+// Tests of abs_ulps/abs_ulps_all behaviour over primitives, which passes if
+// either the abs or the ulps check would.
+
+macro_rules! impl_tests {
+    ($float:ident, $uint:ident) => {
+        mod $float {
+            use crate::$float::*;
+            use float_eq::{
+                assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq,
+                AssertFloatEqAll, FloatEq, FloatEqAll,
+            };
+
+            fn check_eq_abs_ulps(a: $float, b: $float, tol: $float, ulps_tol: $uint) {
+                assert_float_eq!(a, b, abs_ulps <= (tol, ulps_tol));
+                assert_float_eq!(a, b, abs_ulps_all <= (tol, ulps_tol));
+
+                assert!(float_eq!(a, b, abs_ulps <= (tol, ulps_tol)));
+                assert!(float_eq!(a, b, abs_ulps_all <= (tol, ulps_tol)));
+                assert!(!float_ne!(a, b, abs_ulps <= (tol, ulps_tol)));
+                assert!(!float_ne!(a, b, abs_ulps_all <= (tol, ulps_tol)));
+
+                assert!(a.eq_abs_ulps(&b, &tol, &ulps_tol));
+                assert!(a.eq_abs_ulps_all(&b, &tol, &ulps_tol));
+                assert!(!a.ne_abs_ulps(&b, &tol, &ulps_tol));
+                assert!(!a.ne_abs_ulps_all(&b, &tol, &ulps_tol));
+            }
+
+            fn check_ne_abs_ulps(a: $float, b: $float, tol: $float, ulps_tol: $uint) {
+                assert_float_ne!(a, b, abs_ulps <= (tol, ulps_tol));
+                assert_float_ne!(a, b, abs_ulps_all <= (tol, ulps_tol));
+
+                assert!(!float_eq!(a, b, abs_ulps <= (tol, ulps_tol)));
+                assert!(!float_eq!(a, b, abs_ulps_all <= (tol, ulps_tol)));
+                assert!(float_ne!(a, b, abs_ulps <= (tol, ulps_tol)));
+                assert!(float_ne!(a, b, abs_ulps_all <= (tol, ulps_tol)));
+
+                assert!(!a.eq_abs_ulps(&b, &tol, &ulps_tol));
+                assert!(!a.eq_abs_ulps_all(&b, &tol, &ulps_tol));
+                assert!(a.ne_abs_ulps(&b, &tol, &ulps_tol));
+                assert!(a.ne_abs_ulps_all(&b, &tol, &ulps_tol));
+            }
+
+            #[test]
+            fn abs_near_zero() {
+                // across zero the ULPs distance is huge
+                check_eq_abs_ulps(-next(0.0), next_n(0.0, 4), 1e-30, 0);
+                check_eq_abs_ulps(0.0, -0.1, 0.1, 0);
+                check_ne_abs_ulps(0.0, -0.1, 0.05, 4);
+            }
+
+            #[test]
+            fn ulps_away_from_zero() {
+                check_eq_abs_ulps(1.0, next_n(1.0, 4), 0.0, 4);
+                check_eq_abs_ulps(1e30, next_n(1e30, 4), 1e-9, 4);
+                check_ne_abs_ulps(1e30, next_n(1e30, 5), 1e-9, 4);
+            }
+
+            #[test]
+            fn same_as_abs_or_ulps() {
+                let values = [0.0, -0.0, next(0.0), 1.0, next(1.0), 1.5, -3.5, INFINITY];
+                let tols = [0.0, next(0.0), 0.5, 10.0];
+                let ulps_tols = [0, 1, 4];
+                for a in values.iter() {
+                    for b in values.iter() {
+                        for tol in tols.iter() {
+                            for ulps_tol in ulps_tols.iter() {
+                                assert_eq!(
+                                    float_eq!(a, b, abs_ulps <= (*tol, *ulps_tol)),
+                                    float_eq!(a, b, abs <= *tol, ulps <= *ulps_tol)
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn nan() {
+                for a in nan_test_values().iter() {
+                    check_ne_abs_ulps(*a, 1.0, INFINITY, $uint::MAX);
+                    check_ne_abs_ulps(1.0, *a, INFINITY, $uint::MAX);
+                    check_ne_abs_ulps(*a, *a, INFINITY, $uint::MAX);
+                }
+            }
+
+            #[test]
+            fn debug_tol() {
+                let (a, b): ($float, $float) = (1.0, 2.0);
+                assert_eq!(
+                    a.debug_abs_ulps_tol(&b, &0.5, &4),
+                    (a.debug_abs_tol(&b, &0.5), a.debug_ulps_tol(&b, &4))
+                );
+                assert_eq!(
+                    a.debug_abs_ulps_all_tol(&b, &0.5, &4),
+                    (a.debug_abs_all_tol(&b, &0.5), a.debug_ulps_all_tol(&b, &4))
+                );
+            }
+
+            // each check applies to the whole value, as when combining checks
+            #[test]
+            fn arrays() {
+                let a: [$float; 2] = [1.0, 2.0];
+                let b: [$float; 2] = [next_n(1.0, 2), 2.0];
+                assert_float_eq!(a, b, abs_ulps <= ([0.0, 0.0], [2, 0]));
+                assert_float_eq!(a, b, abs_ulps <= ([0.1, 0.0], [0, 0]));
+                assert_float_ne!(a, b, abs_ulps <= ([0.0, 0.1], [1, 1]));
+                assert_float_eq!(a, b, abs_ulps_all <= (0.0, 2));
+                assert_float_eq!(a, b, abs_ulps_all <= (0.1, 0));
+                assert_float_ne!(a, b, abs_ulps_all <= (0.0, 1));
+            }
+        }
+    };
+}
+
+impl_tests!(f32, u32);
+impl_tests!(f64, u64);
@@ -0,0 +1,100 @@
+// Tests of percent/percent_all behaviour over primitives, which is rmax with
+// the tolerance scaled from a percentage.
+
+macro_rules! impl_tests {
+    ($float:ident) => {
+        mod $float {
+            use crate::$float::*;
+            use float_eq::{
+                assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq, FloatEq,
+                FloatEqAll, TolOps,
+            };
+
+            fn check_eq_percent(a: $float, b: $float, tol: $float) {
+                assert_float_eq!(a, b, percent <= tol);
+                assert_float_eq!(a, b, percent_all <= tol);
+
+                assert!(float_eq!(a, b, percent <= tol));
+                assert!(float_eq!(a, b, percent_all <= tol));
+                assert!(!float_ne!(a, b, percent <= tol));
+                assert!(!float_ne!(a, b, percent_all <= tol));
+
+                assert!(a.eq_percent(&b, &tol));
+                assert!(a.eq_percent_all(&b, &tol));
+                assert!(!a.ne_percent(&b, &tol));
+                assert!(!a.ne_percent_all(&b, &tol));
+            }
+
+            fn check_ne_percent(a: $float, b: $float, tol: $float) {
+                assert_float_ne!(a, b, percent <= tol);
+                assert_float_ne!(a, b, percent_all <= tol);
+
+                assert!(!float_eq!(a, b, percent <= tol));
+                assert!(!float_eq!(a, b, percent_all <= tol));
+                assert!(float_ne!(a, b, percent <= tol));
+                assert!(float_ne!(a, b, percent_all <= tol));
+
+                assert!(!a.eq_percent(&b, &tol));
+                assert!(!a.eq_percent_all(&b, &tol));
+                assert!(a.ne_percent(&b, &tol));
+                assert!(a.ne_percent_all(&b, &tol));
+            }
+
+            #[test]
+            fn scaled_to_largest() {
+                check_eq_percent(100.0, 100.09, 0.1);
+                check_eq_percent(-100.09, -100.0, 0.1);
+                check_ne_percent(100.0, 100.2, 0.1);
+                check_eq_percent(1.0, 2.0, 50.0);
+                check_ne_percent(1.0, 2.0, 49.0);
+                check_eq_percent(1.0, -1.0, 200.0);
+                check_ne_percent(1.0, -1.0, 199.0);
+            }
+
+            #[test]
+            fn same_as_rmax() {
+                let values = [0.0, 1.0, next(1.0), 1.001, 100.0, -3.5, INFINITY];
+                let tols = [0.0, 0.1, 1.0, 5.0, 100.0];
+                for a in values.iter() {
+                    for b in values.iter() {
+                        for tol in tols.iter() {
+                            let rmax_tol = tol.scale(0.01);
+                            assert_eq!(
+                                float_eq!(a, b, percent <= *tol),
+                                float_eq!(a, b, rmax <= rmax_tol)
+                            );
+                            // compare bits, since an infinite value scales a
+                            // zero tolerance to NaN
+                            assert_eq!(
+                                a.debug_percent_tol(b, tol).to_bits(),
+                                a.debug_rmax_tol(b, &rmax_tol).to_bits()
+                            );
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn nan() {
+                for a in nan_test_values().iter() {
+                    check_ne_percent(*a, 1.0, 100.0);
+                    check_ne_percent(1.0, *a, 100.0);
+                    check_ne_percent(*a, *a, 100.0);
+                }
+            }
+
+            #[test]
+            fn arrays() {
+                let a: [$float; 2] = [100.0, 1.0];
+                let b: [$float; 2] = [100.09, 1.5];
+                assert_float_eq!(a, b, percent <= [0.1, 50.0]);
+                assert_float_ne!(a, b, percent <= [0.1, 10.0]);
+                assert_float_eq!(a, b, percent_all <= 50.0);
+                assert_float_ne!(a, b, percent_all <= 10.0);
+            }
+        }
+    };
+}
+
+impl_tests!(f32);
+impl_tests!(f64);
@@ -0,0 +1,133 @@
+use float_eq::{
+    assert_row_stochastic, assert_rows_sum_to_one, assert_simplex, assert_sums_to_one,
+    first_negative, probability_sum,
+};
+
+#[test]
+fn sum() {
+    assert_eq!(probability_sum::<f32>(&[]), 0.0);
+    assert_eq!(probability_sum(&[0.25f32, 0.5, 0.25]), 1.0);
+    assert_eq!(probability_sum(&[1.0f64, 1e100, 1.0, -1e100]), 2.0);
+    assert_eq!(probability_sum(&[0.1f64; 10]), 1.0);
+    assert!(probability_sum(&[0.5f64, f64::NAN]).is_nan());
+}
+
+#[test]
+fn negative() {
+    assert_eq!(first_negative::<f64>(&[]), None);
+    assert_eq!(first_negative(&[0.5f64, 0.0, -0.0, 0.5]), None);
+    assert_eq!(first_negative(&[0.5f64, -1e-300, -1.0]), Some(1));
+    assert_eq!(first_negative(&[f32::NAN, -1.0]), Some(0));
+    assert_eq!(first_negative(&[f32::NEG_INFINITY]), Some(0));
+}
+
+#[test]
+fn sums_to_one() {
+    let p = [0.1f64, 0.2, 0.3, 0.4];
+    assert_sums_to_one!(p, abs <= 1e-15);
+    assert_sums_to_one!(p, abs <= 1e-15,);
+    assert_sums_to_one!(&p[..], ulps <= 1, abs <= 0.0);
+    assert_sums_to_one!(p.to_vec(), abs <= 1e-15, "testing {}", 1);
+    assert_sums_to_one!(p, abs <= 1e-15, ulps <= 1, "testing {}", 1);
+
+    // negative values are allowed
+    assert_sums_to_one!([2.0f32, -1.0], abs <= 0.0);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`: values do not sum to one"#)]
+fn sums_to_one_fail_empty() {
+    let empty: [f32; 0] = [];
+    assert_sums_to_one!(empty, abs <= 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"values do not sum to one: testing 2"#)]
+fn sums_to_one_fail_message() {
+    assert_sums_to_one!([0.5f64, 0.6], abs <= 0.01, "testing {}", 2);
+}
+
+#[test]
+fn rows_sum_to_one() {
+    let m = [[0.9f64, 0.1, 0.0], [0.25, 0.5, 0.25]];
+    assert_rows_sum_to_one!(m, abs <= 0.0);
+    assert_rows_sum_to_one!(m, abs <= 0.0,);
+    assert_rows_sum_to_one!(&m[..], abs <= 0.0, "testing {}", 1);
+    assert_rows_sum_to_one!(vec![vec![1.0f32], vec![0.5, 0.5]], ulps <= 0);
+
+    let empty: Vec<Vec<f32>> = vec![];
+    assert_rows_sum_to_one!(empty, abs <= 0.0);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `1.5`,
+       right: `1.0`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.01`: row 1 does not sum to one: testing 2"#)]
+fn rows_sum_to_one_fail() {
+    assert_rows_sum_to_one!([[0.5f32, 0.5], [1.0, 0.5]], abs <= 0.01, "testing {}", 2);
+}
+
+#[test]
+fn simplex() {
+    let p = [0.0f64, 0.25, 0.75];
+    assert_simplex!(p, abs <= 0.0);
+    assert_simplex!(p, abs <= 0.0,);
+    assert_simplex!(p.to_vec(), abs <= 0.0, ulps <= 0, "testing {}", 1);
+    assert_simplex!([1.0f32], ulps <= 0, "testing {}", 1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `simplex!(values, abs <= t)`
+       index: `1`,
+       value: `-0.25`"#)]
+fn simplex_fail_negative() {
+    assert_simplex!([1.25f64, -0.25], abs <= 0.1);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `simplex!(values, abs <= t, ulps <= t)`
+       index: `0`,
+       value: `NaN`: probability is negative: testing 2"#
+)]
+fn simplex_fail_nan() {
+    assert_simplex!([f32::NAN, 1.0], abs <= 0.1, ulps <= 1, "testing {}", 2);
+}
+
+#[test]
+#[should_panic(expected = r#"values do not sum to one"#)]
+fn simplex_fail_sum() {
+    assert_simplex!([0.5f64, 0.25], abs <= 0.1);
+}
+
+#[test]
+fn row_stochastic() {
+    let m = [[0.9f64, 0.075, 0.025], [0.15, 0.8, 0.05], [0.25, 0.25, 0.5]];
+    assert_row_stochastic!(m, abs <= 1e-15);
+    assert_row_stochastic!(m, abs <= 1e-15,);
+    assert_row_stochastic!(m, abs <= 1e-15, "testing {}", 1);
+    assert_row_stochastic!(vec![[0.5f32, 0.5]], ulps <= 0);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `row_stochastic!(m, abs <= t)`
+         row: `1`,
+       index: `0`,
+       value: `-0.5`: probability is negative: testing 2"#)]
+fn row_stochastic_fail_negative() {
+    assert_row_stochastic!([[0.5f64, 0.5], [-0.5, 1.5]], abs <= 0.1, "testing {}", 2);
+}
+
+#[test]
+#[should_panic(expected = r#"row 0 does not sum to one"#)]
+fn row_stochastic_fail_sum() {
+    assert_row_stochastic!([[0.5f64, 0.4], [0.5, 0.5]], abs <= 0.01);
+}
@@ -0,0 +1,130 @@
+use float_eq::{assert_parallel_sum_eq, parallel_sum_tol};
+
+fn pairwise(values: &[f32]) -> f32 {
+    match values.len() {
+        0 => 0.0,
+        1 => values[0],
+        n => pairwise(&values[..n / 2]) + pairwise(&values[n / 2..]),
+    }
+}
+
+#[test]
+fn tol() {
+    let u = f64::EPSILON / 2.0;
+    let gamma = |d: f64| d * u / (1.0 - d * u);
+
+    assert_eq!(parallel_sum_tol(0, 1.0f64, 4), 0.0);
+    assert_eq!(parallel_sum_tol(1, 1.0f64, 4), 0.0);
+    assert_eq!(parallel_sum_tol(2, 1.0f64, 1), 2.0 * 2.0 * gamma(1.0));
+    assert_eq!(
+        parallel_sum_tol(8, 2.0f64, 1),
+        (gamma(7.0) + gamma(3.0)) * 16.0
+    );
+    assert_eq!(
+        parallel_sum_tol(8, -2.0f64, 1),
+        (gamma(7.0) + gamma(3.0)) * 16.0
+    );
+    assert_eq!(
+        parallel_sum_tol(9, 1.0f64, 0),
+        (gamma(8.0) + gamma(4.0)) * 9.0
+    );
+    assert_eq!(
+        parallel_sum_tol(100, 1.0f64, 10),
+        (gamma(99.0) + gamma(13.0)) * 100.0
+    );
+    assert_eq!(
+        parallel_sum_tol(100, 1.0f64, 1000),
+        2.0 * gamma(99.0) * 100.0
+    );
+}
+
+#[test]
+fn tol_grows_with_chunk() {
+    let mut prev = 0.0f32;
+    for chunk in 1..=64 {
+        let tol = parallel_sum_tol(64, 1.0f32, chunk);
+        assert!(tol >= prev);
+        prev = tol;
+    }
+}
+
+#[test]
+fn tol_unbounded() {
+    assert_eq!(parallel_sum_tol(1 << 25, 1.0f32, 1 << 25), f32::INFINITY);
+    assert!(parallel_sum_tol(1 << 20, 1.0f32, 1).is_finite());
+}
+
+#[test]
+fn bound_holds() {
+    // Alternating large and small values of both signs maximise the rounding
+    // error of each addition.
+    let values: Vec<f32> = (0..4096)
+        .map(|i| {
+            let x = if i % 3 == 0 {
+                1.0
+            } else {
+                1e-4 * (i as f32).sin()
+            };
+            if i % 2 == 0 {
+                x
+            } else {
+                -x * 0.999
+            }
+        })
+        .collect();
+    let sequential: f32 = values.iter().sum();
+    for &chunk in [1, 2, 7, 64, 1000, 4096].iter() {
+        let partials: Vec<f32> = values.chunks(chunk).map(|c| c.iter().sum()).collect();
+        let parallel = pairwise(&partials);
+        assert_parallel_sum_eq!(
+            parallel,
+            sequential,
+            len = values.len(),
+            max_abs = 1.0,
+            chunk = chunk
+        );
+    }
+}
+
+#[test]
+fn assert_message_forms() {
+    assert_parallel_sum_eq!(1.0f64, 1.0, len = 2, max_abs = 1.0, chunk = 1,);
+    assert_parallel_sum_eq!(
+        1.0f64,
+        1.0,
+        len = 2,
+        max_abs = 1.0,
+        chunk = 1,
+        "with {}",
+        "message"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"assertion failed: `parallel_sum_eq!(parallel, sequential, len = n, max_abs = m, chunk = c)`
+    parallel: `1.5`,
+  sequential: `1.0`,
+    abs_diff: `0.5`,
+         len: `2`,
+     max_abs: `1.0`,
+       chunk: `1`,
+     [abs] t: `2.384186e-7`"#
+)]
+fn assert_fail_message() {
+    assert_parallel_sum_eq!(1.5f32, 1.0, len = 2, max_abs = 1.0, chunk = 1);
+}
+
+#[test]
+#[should_panic(expected = r#"[abs] t: `2.384186e-7`: chunked"#)]
+fn assert_fail_custom_message() {
+    assert_parallel_sum_eq!(
+        1.5f32,
+        1.0,
+        len = 2,
+        max_abs = 1.0,
+        chunk = 1,
+        "{}",
+        "chunked"
+    );
+}
@@ -0,0 +1,108 @@
+use float_eq::{FloatEq, FloatEqAll, FloatEqSliceExt};
+
+#[test]
+fn contains_float() {
+    let values = [1.0f32, 2.0, 3.0];
+    assert!(values.contains_float(&2.1, 0.1));
+    assert!(!values.contains_float(&2.2, 0.1));
+    assert!(!values.contains_float(&f32::NAN, f32::INFINITY));
+
+    let empty: [f32; 0] = [];
+    assert!(!empty.contains_float(&1.0, f32::INFINITY));
+}
+
+#[test]
+fn position_float() {
+    let values = [1.0f64, 2.0, 2.05, 3.0];
+    assert_eq!(values.position_float(&2.04, 0.1), Some(1));
+    assert_eq!(values.position_float(&2.06, 0.02), Some(2));
+    assert_eq!(values.position_float(&4.0, 0.5), None);
+}
+
+#[test]
+fn by_check() {
+    let values = [1.0f32, 2.0, 3.0];
+    let x = f32::from_bits(3.0f32.to_bits() + 2);
+    assert_eq!(
+        values.position_float_by(&x, 2, |a, b, tol| a.eq_ulps(b, tol)),
+        Some(2)
+    );
+    assert!(!values.contains_float_by(&x, 1, |a, b, tol| a.eq_ulps(b, tol)));
+    assert!(values.contains_float_by(&x, 0.000_001, |a, b, tol| a.eq_rmax(b, tol)));
+}
+
+#[test]
+fn composite_elements() {
+    let values = [[1.0f32, 2.0], [3.0, 4.0]];
+    assert_eq!(values.position_float(&[3.1, 3.9], [0.1, 0.1]), Some(1));
+    assert_eq!(values.position_float(&[3.1, 3.9], [0.1, 0.05]), None);
+    assert!(values.contains_float_by(&[1.05, 2.05], 0.1, |a, b, tol| a.eq_abs_all(b, tol)));
+}
+
+#[cfg(feature = "std")]
+mod maps {
+    use float_eq::{FloatEq, FloatEqMapExt};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Millis(u32);
+
+    impl FloatEq<f32> for Millis {
+        type Tol = f32;
+
+        fn eq_abs(&self, other: &f32, tol: &f32) -> bool {
+            (self.0 as f32 / 1000.0).eq_abs(other, tol)
+        }
+
+        fn eq_rmax(&self, other: &f32, tol: &f32) -> bool {
+            (self.0 as f32 / 1000.0).eq_rmax(other, tol)
+        }
+
+        fn eq_rmin(&self, other: &f32, tol: &f32) -> bool {
+            (self.0 as f32 / 1000.0).eq_rmin(other, tol)
+        }
+
+        fn eq_r1st(&self, other: &f32, tol: &f32) -> bool {
+            (self.0 as f32 / 1000.0).eq_r1st(other, tol)
+        }
+
+        fn eq_r2nd(&self, other: &f32, tol: &f32) -> bool {
+            (self.0 as f32 / 1000.0).eq_r2nd(other, tol)
+        }
+
+        fn eq_ulps(&self, other: &f32, tol: &u32) -> bool {
+            (self.0 as f32 / 1000.0).eq_ulps(other, tol)
+        }
+    }
+
+    #[test]
+    fn btree_map() {
+        let mut map = BTreeMap::new();
+        map.insert(Millis(1500), "a");
+        map.insert(Millis(2500), "b");
+        map.insert(Millis(2600), "c");
+
+        assert_eq!(map.get_float_key(&1.5, 0.01), Some(&"a"));
+        assert_eq!(map.get_float_key(&2.55, 0.05), Some(&"b"));
+        assert_eq!(map.get_float_key(&2.59, 0.02), Some(&"c"));
+        assert_eq!(map.get_float_key(&2.0, 0.1), None);
+        assert_eq!(
+            map.get_float_key_by(&2.5, 0.01, |a, b, tol| a.eq_rmin(b, tol)),
+            Some(&"b")
+        );
+    }
+
+    #[test]
+    fn hash_map() {
+        let mut map = HashMap::new();
+        map.insert(Millis(1500), "a");
+        map.insert(Millis(2500), "b");
+
+        assert_eq!(map.get_float_key(&1.499, 0.01), Some(&"a"));
+        assert_eq!(map.get_float_key(&3.0, 0.1), None);
+        assert_eq!(
+            map.get_float_key_by(&2.5, 0, |a, b, tol| a.eq_ulps(b, tol)),
+            Some(&"b")
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use float_eq::{snap_into, snap_to, snap_to_by, FloatEq, FloatEqAll};
+
+#[test]
+fn snap_to_target() {
+    assert_eq!(snap_to(1.05f32, 1.0, 0.1), 1.0);
+    assert_eq!(snap_to(0.95f32, 1.0, 0.1), 1.0);
+    assert_eq!(snap_to(1.2f32, 1.0, 0.1), 1.2);
+    assert_eq!(snap_to(-1e-300f64, 0.0, 1e-12).to_bits(), 0.0f64.to_bits());
+    assert!(snap_to(f64::NAN, 0.0, f64::INFINITY).is_nan());
+}
+
+#[test]
+fn snap_to_composite() {
+    assert_eq!(
+        snap_to((1e-9f64, 2.0), (0.0, 2.0), (1e-6, 1e-6)),
+        (0.0, 2.0)
+    );
+    assert_eq!(snap_to([1e-9f32, 2.1], [0.0, 2.0], [1e-6; 2]), [1e-9, 2.1]);
+}
+
+#[test]
+fn snap_to_check() {
+    let above = f32::from_bits(1.0f32.to_bits() + 2);
+    let ulps = |a: &f32, b: &f32, tol: &u32| a.eq_ulps(b, tol);
+    assert_eq!(snap_to_by(above, 1.0, 2, ulps), 1.0);
+    assert_eq!(snap_to_by(above, 1.0, 1, ulps), above);
+
+    let all = |a: &[f32; 2], b: &[f32; 2], tol: &f32| a.eq_abs_all(b, tol);
+    assert_eq!(
+        snap_to_by([0.01f32, 0.99], [0.0, 1.0], 0.1, all),
+        [0.0, 1.0]
+    );
+}
+
+#[test]
+fn snap_into_range() {
+    assert_eq!(snap_into(1.05f32, 0.0, 1.0, 0.1), 1.0);
+    assert_eq!(snap_into(-0.05f32, 0.0, 1.0, 0.1), 0.0);
+    assert_eq!(snap_into(0.99f32, 0.0, 1.0, 0.1), 0.99);
+    assert_eq!(snap_into(0.01f32, 0.0, 1.0, 0.1), 0.01);
+    assert_eq!(snap_into(1.2f32, 0.0, 1.0, 0.1), 1.2);
+    assert_eq!(snap_into(-0.2f32, 0.0, 1.0, 0.1), -0.2);
+    assert!(snap_into(f32::NAN, 0.0, 1.0, 0.1).is_nan());
+}
+
+#[cfg(feature = "std")]
+mod grid {
+    use float_eq::{snap_to_grid, snap_to_grid_by, FloatEq};
+
+    #[test]
+    fn snap_to_grid_point() {
+        assert_eq!(snap_to_grid(2.01f64, 0.5, 0.05), 2.0);
+        assert_eq!(snap_to_grid(2.49f64, 0.5, 0.05), 2.5);
+        assert_eq!(snap_to_grid(2.3f64, 0.5, 0.05), 2.3);
+        assert_eq!(snap_to_grid(-7.02f32, 1.0, 0.05), -7.0);
+        assert_eq!(snap_to_grid(0.0f32, 0.25, 0.0), 0.0);
+    }
+
+    #[test]
+    fn half_way_snaps_away_from_zero() {
+        assert_eq!(snap_to_grid(0.5f32, 1.0, 0.5), 1.0);
+        assert_eq!(snap_to_grid(-0.5f32, 1.0, 0.5), -1.0);
+        assert_eq!(snap_to_grid(0.5f32, 1.0, 0.4), 0.5);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(snap_to_grid(f64::NAN, 1.0, f64::INFINITY).is_nan());
+        assert_eq!(snap_to_grid(f64::INFINITY, 1.0, 0.1), f64::INFINITY);
+    }
+
+    #[test]
+    fn snap_to_grid_check() {
+        let below = f32::from_bits(0.75f32.to_bits() - 1);
+        let ulps = |a: &f32, b: &f32, tol: &u32| a.eq_ulps(b, tol);
+        assert_eq!(snap_to_grid_by(below, 0.25, 1, ulps), 0.75);
+        assert_eq!(snap_to_grid_by(below, 0.25, 0, ulps), below);
+    }
+}
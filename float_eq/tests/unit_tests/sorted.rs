@@ -0,0 +1,55 @@
+use float_eq::{sort_inversions, sort_inversions_by, FloatEq, FloatEqAll};
+
+fn inversions<T: PartialOrd + FloatEq<Tol = T>>(values: &[T], tol: T) -> Vec<(usize, usize)> {
+    sort_inversions(values, tol).collect()
+}
+
+#[test]
+fn sorted() {
+    assert_eq!(inversions::<f32>(&[], 0.0), vec![]);
+    assert_eq!(inversions(&[1.0f32], 0.0), vec![]);
+    assert_eq!(inversions(&[1.0f32, 1.0, 2.0, 3.0], 0.0), vec![]);
+    assert_eq!(inversions(&[-0.0f64, 0.0, -0.0], 0.0), vec![]);
+}
+
+#[test]
+fn ties_within_tolerance() {
+    assert_eq!(inversions(&[1.0f64, 1.1, 1.05, 1.2], 0.1), vec![]);
+    assert_eq!(inversions(&[1.0f64, 1.1, 1.05, 1.2], 0.01), vec![(1, 2)]);
+}
+
+#[test]
+fn compared_against_running_maximum() {
+    // each step down is within tolerance, but the drift from the largest is not
+    assert_eq!(
+        inversions(&[2.0f64, 1.96, 1.92, 1.88, 3.0], 0.1),
+        vec![(0, 3)]
+    );
+}
+
+#[test]
+fn every_inversion_reported() {
+    assert_eq!(
+        inversions(&[3.0f32, 1.0, 2.0, 4.0, 3.9, 0.0], 0.5),
+        vec![(0, 1), (0, 2), (3, 5)]
+    );
+}
+
+#[test]
+fn nans_ignored() {
+    let nan = f64::NAN;
+    assert_eq!(inversions(&[nan, 1.0, nan, 2.0, nan], 0.0), vec![]);
+    assert_eq!(inversions(&[nan, 2.0, nan, 1.0], 0.0), vec![(1, 3)]);
+}
+
+#[test]
+fn by_check() {
+    let values = [1.0f32, 1.000_000_1, 1.0, 0.999_999_9];
+    let found: Vec<_> = sort_inversions_by(&values, 1, |a, b, tol| a.eq_ulps(b, tol)).collect();
+    assert_eq!(found, vec![(1, 3)]);
+
+    let values = [[1.0f32, 2.0], [1.0, 1.5], [2.0, 0.0]];
+    let found: Vec<_> =
+        sort_inversions_by(&values, 0.25, |a, b, tol| a.eq_abs_all(b, tol)).collect();
+    assert_eq!(found, vec![(0, 1)]);
+}
@@ -0,0 +1,60 @@
+use float_eq::{FloatBits, FloatEq, TestVector, F32_TEST_VECTORS, F64_TEST_VECTORS};
+
+// The next value towards zero, for checking that each tolerance is the
+// smallest for which the check passes.
+fn next_down<T: FloatBits>(value: T) -> T
+where
+    T::Bits: From<u8>,
+{
+    T::from_float_bits(value.to_float_bits() - T::Bits::from(1))
+}
+
+macro_rules! validate {
+    ($name:ident, $float:ident, $vectors:expr) => {
+        #[test]
+        fn $name() {
+            for v in $vectors {
+                let TestVector { a, b, .. } = *v;
+                match v.abs {
+                    Some(tol) => {
+                        assert!(a.eq_abs(&b, &tol), "{:?}", v);
+                        if tol > 0.0 {
+                            assert!(a.ne_abs(&b, &next_down(tol)), "{:?}", v);
+                        }
+                    }
+                    None => assert!(a.ne_abs(&b, &$float::INFINITY), "{:?}", v),
+                }
+                match v.rmax {
+                    Some(tol) => {
+                        assert!(a.eq_rmax(&b, &tol), "{:?}", v);
+                        if tol > 0.0 {
+                            assert!(a.ne_rmax(&b, &next_down(tol)), "{:?}", v);
+                        }
+                    }
+                    None => assert!(a.ne_rmax(&b, &$float::INFINITY), "{:?}", v),
+                }
+                match v.ulps {
+                    Some(tol) => {
+                        assert!(a.eq_ulps(&b, &tol), "{:?}", v);
+                        if tol > 0 {
+                            assert!(a.ne_ulps(&b, &(tol - 1)), "{:?}", v);
+                        }
+                        assert_eq!(a.ulps_diff(b), Some(tol), "{:?}", v);
+                    }
+                    None => assert!(a.ne_ulps(&b, &<$float as FloatBits>::Bits::MAX), "{:?}", v),
+                }
+            }
+        }
+    };
+}
+
+validate!(f32_vectors, f32, F32_TEST_VECTORS);
+validate!(f64_vectors, f64, F64_TEST_VECTORS);
+
+#[test]
+fn vectors_are_symmetric() {
+    for v in F32_TEST_VECTORS {
+        assert_eq!(v.a.eq_abs(&v.b, &1.0), v.b.eq_abs(&v.a, &1.0), "{:?}", v);
+        assert_eq!(v.a.eq_ulps(&v.b, &1), v.b.eq_ulps(&v.a, &1), "{:?}", v);
+    }
+}
@@ -0,0 +1,62 @@
+#![allow(clippy::float_cmp)]
+
+use float_eq::TolOps;
+
+#[test]
+fn primitives() {
+    assert_eq!(0.5f32.max_elementwise(&0.25), 0.5);
+    assert_eq!(0.25f32.max_elementwise(&0.5), 0.5);
+    assert_eq!(0.5f64.min_elementwise(&0.25), 0.25);
+    assert_eq!(0.25f64.min_elementwise(&0.5), 0.25);
+
+    assert_eq!(0.25f32.scale(2.0), 0.5);
+    assert_eq!(0.25f64.scale(0.5), 0.125);
+    assert_eq!(f32::MAX.scale(2.0), f32::INFINITY);
+}
+
+#[test]
+fn arrays() {
+    let a = [0.5f32, 0.0, 2.0];
+    let b = [0.25, 1.0, 2.0];
+    assert_eq!(a.max_elementwise(&b), [0.5, 1.0, 2.0]);
+    assert_eq!(a.min_elementwise(&b), [0.25, 0.0, 2.0]);
+    assert_eq!(a.scale(2.0), [1.0, 0.0, 4.0]);
+
+    let a = [[0.5f64, 0.125], [4.0, 1.0]];
+    let b = [[0.25, 0.25], [0.25, 0.25]];
+    assert_eq!(a.max_elementwise(&b), [[0.5, 0.25], [4.0, 1.0]]);
+    assert_eq!(a.min_elementwise(&b), [[0.25, 0.125], [0.25, 0.25]]);
+    assert_eq!(a.scale(0.5), [[0.25, 0.0625], [2.0, 0.5]]);
+
+    let empty: [f32; 0] = [];
+    assert_eq!(empty.scale(2.0), []);
+}
+
+#[test]
+fn tuples() {
+    let a = (0.5f32, [0.25f64, 4.0]);
+    let b = (1.0, [0.5, 2.0]);
+    assert_eq!(a.max_elementwise(&b), (1.0, [0.5, 4.0]));
+    assert_eq!(a.min_elementwise(&b), (0.5, [0.25, 2.0]));
+    assert_eq!(a.scale(4.0), (2.0, [1.0, 16.0]));
+
+    let a = (
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+    );
+    let b = (
+        12.0, 11.0, 10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0,
+    );
+    assert_eq!(
+        a.max_elementwise(&b),
+        (12.0, 11.0, 10.0, 9.0, 8.0, 7.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0)
+    );
+    assert_eq!(
+        a.min_elementwise(&b),
+        (1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0)
+    );
+
+    #[allow(clippy::let_unit_value)]
+    let unit = ();
+    assert_eq!(unit.max_elementwise(&()), ());
+    assert_eq!(unit.scale(2.0), ());
+}
@@ -1,5 +1,5 @@
 use crate::{f32, f64};
-use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq};
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll, FloatEqBits};
 
 #[test]
 fn float_eq() {
@@ -231,6 +231,18 @@ fn float_eq() {
     assert_float_ne!(a, c, ulps <= eps11);
 }
 
+#[test]
+fn float_eq_bits() {
+    assert!(().eq_bits(&()));
+    assert!((1.0f32,).eq_bits(&(1.0,)));
+    assert!((1.0f32,).ne_bits(&(f32::next(1.0),)));
+    assert!((1.0f32, -0.0f64).eq_bits(&(1.0, -0.0)));
+    assert!((1.0f32, -0.0f64).ne_bits(&(1.0, 0.0)));
+    assert!((1.0f32, (2.0f64, [3.0f32])).eq_bits(&(1.0, (2.0, [3.0]))));
+    assert_float_eq!((f32::NAN, 2.0f64), (f32::NAN, 2.0), bits);
+    assert_float_ne!((f32::NAN, 2.0f64), (-f32::NAN, 2.0), bits);
+}
+
 #[test]
 fn debug_diff() {
     // ()
@@ -501,6 +513,87 @@ fn debug_tol() {
     );
 }
 
+#[test]
+fn float_eq_all() {
+    // (A,)
+    assert_float_eq!((1.0f32,), (1.5,), abs_all <= 0.5);
+    assert_float_ne!((1.0f32,), (1.5,), abs_all <= f32::prev(0.5));
+    assert_float_eq!((4.0f32,), (3.999_999_5,), rmax_all <= 1.0 * f32::EPSILON);
+    assert_float_ne!((4.0f32,), (3.999_999_5,), rmax_all <= 0.5 * f32::EPSILON);
+    assert_float_eq!((-4.0f32,), (-4.000_001,), ulps_all <= 2);
+    assert_float_ne!((-4.0f32,), (-4.000_001,), ulps_all <= 1);
+
+    // (A, B, C)
+    let a = (1.0f32, 2.0f32, 4.0f32);
+    let b = (1.5f32, 2.25f32, 4.5f32);
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= f32::prev(0.5));
+    assert_float_eq!(a, b, rel_all <= 1.0 / 3.0);
+    assert_float_ne!(a, b, rel_all <= 0.3);
+    assert_float_eq!(a, b, rmax_all <= 1.0 / 3.0);
+    assert_float_ne!(a, b, rmax_all <= 0.3);
+    assert_float_eq!(a, b, rmin_all <= 0.5);
+    assert_float_ne!(a, b, rmin_all <= f32::prev(0.5));
+    assert_float_eq!(a, b, r1st_all <= 0.5);
+    assert_float_ne!(a, b, r1st_all <= f32::prev(0.5));
+    assert_float_eq!(a, b, r2nd_all <= 1.0 / 3.0);
+    assert_float_ne!(a, b, r2nd_all <= 0.3);
+    assert_float_eq!(a, b, ulps_all <= 4_194_304);
+    assert_float_ne!(a, b, ulps_all <= 4_194_303);
+}
+
+#[test]
+fn float_eq_all_nested() {
+    // arrays of tuples
+    let a = [(1.0f32, 2.0f32), (3.0, 4.0)];
+    let b = [(1.0f32, 2.5f32), (3.0, 3.75)];
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+
+    // tuples of arrays and scalars
+    let a = ([1.0f64, 2.0], 3.0f64, [[4.0f64; 2]; 2]);
+    let b = ([1.0f64, 2.0], 3.0f64, [[4.0f64, 4.5], [4.0, 4.0]]);
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+    assert_float_eq!(a, b, ulps_all <= 562_949_953_421_312);
+    assert_float_ne!(a, b, ulps_all <= 562_949_953_421_311);
+
+    // nested tuples
+    let a = ((1.0f32, (2.0f32,)), 3.0f32);
+    let b = ((1.0f32, (2.5f32,)), 3.0f32);
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+}
+
+#[test]
+fn debug_all_tol() {
+    let a = (2.0f32, [4.25f32, 1.0], (8.0f32,));
+    let b = (2.5f32, [4.0f32, 1.0], (-8.0f32,));
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), (0.2, [0.2, 0.2], (0.2,)));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.5), (1.25, [2.125, 0.5], (4.0,)));
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.5), (1.25, [2.125, 0.5], (4.0,)));
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.5), (1.0, [2.0, 0.5], (4.0,)));
+    assert_eq!(a.debug_r1st_all_tol(&b, &0.5), (1.0, [2.125, 0.5], (4.0,)));
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.5), (1.25, [2.0, 0.5], (4.0,)));
+    assert_eq!(a.debug_ulps_all_tol(&b, &3), (3, [3, 3], (3,)));
+}
+
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `([1.0, 2.0], 3.0)`,
+       right: `([1.0, 2.5], 3.0)`,
+    abs_diff: `([0.0, 0.5], 0.0)`,
+   ulps_diff: `([Some(0), Some(2097152)], Some(0))`,
+ [abs_all] t: `([0.1, 0.1], 0.1)"#)]
+fn test_assert_all_fail_message() {
+    assert_float_eq!(
+        ([1.0f32, 2.0], 3.0f32),
+        ([1.0f32, 2.5], 3.0f32),
+        abs_all <= 0.1
+    )
+}
+
 #[test]
 #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
         left: `(1.0, 2.0)`,
@@ -0,0 +1,99 @@
+use float_eq::{AssertFloatEq, DebugUlpsDiff, SaturatingUlpsDiff, UlpsTol, UlpsTolOps};
+
+#[test]
+fn primitives() {
+    assert_eq!(UlpsTolOps::saturating_add(&1u16, &2), 3);
+    assert_eq!(UlpsTolOps::saturating_add(&u16::MAX, &1), u16::MAX);
+    assert_eq!(UlpsTolOps::saturating_add(&1u32, &2), 3);
+    assert_eq!(UlpsTolOps::saturating_add(&u32::MAX, &1), u32::MAX);
+    assert_eq!(UlpsTolOps::saturating_add(&(u64::MAX - 1), &1), u64::MAX);
+    assert_eq!(UlpsTolOps::saturating_add(&u64::MAX, &u64::MAX), u64::MAX);
+
+    assert_eq!(1u32.max_elementwise(&2), 2);
+    assert_eq!(2u32.max_elementwise(&1), 2);
+    assert_eq!(u64::MAX.max_elementwise(&0), u64::MAX);
+}
+
+#[test]
+fn arrays() {
+    let a: UlpsTol<[f32; 3]> = [1, u32::MAX, 3];
+    let b = [2, 2, 2];
+    assert_eq!(a.saturating_add(&b), [3, u32::MAX, 5]);
+    assert_eq!(a.max_elementwise(&b), [2, u32::MAX, 3]);
+
+    let a: UlpsTol<[[f64; 2]; 2]> = [[1, 4], [u64::MAX, 0]];
+    let b = [[2, 2], [2, 2]];
+    assert_eq!(a.saturating_add(&b), [[3, 6], [u64::MAX, 2]]);
+    assert_eq!(a.max_elementwise(&b), [[2, 4], [u64::MAX, 2]]);
+
+    let empty: UlpsTol<[f32; 0]> = [];
+    assert_eq!(empty.saturating_add(&[]), []);
+}
+
+#[test]
+fn tuples() {
+    let a: UlpsTol<(f32, [f64; 2])> = (u32::MAX, [1, 4]);
+    let b = (1, [2, 2]);
+    assert_eq!(a.saturating_add(&b), (u32::MAX, [3, 6]));
+    assert_eq!(a.max_elementwise(&b), (u32::MAX, [2, 4]));
+
+    let a: (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32) =
+        (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    let b = (12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1);
+    assert_eq!(
+        a.saturating_add(&b),
+        (13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13)
+    );
+    assert_eq!(
+        a.max_elementwise(&b),
+        (12, 11, 10, 9, 8, 7, 7, 8, 9, 10, 11, 12)
+    );
+
+    #[allow(clippy::let_unit_value)]
+    let unit: UlpsTol<()> = ();
+    assert_eq!(unit.saturating_add(&()), ());
+    assert_eq!(unit.max_elementwise(&()), ());
+}
+
+#[test]
+fn saturating_primitives() {
+    assert_eq!(Some(3u16).saturating(), 3);
+    assert_eq!(None::<u16>.saturating(), u16::MAX);
+    assert_eq!(Some(3u32).saturating(), 3);
+    assert_eq!(None::<u32>.saturating(), u32::MAX);
+    assert_eq!(Some(3u64).saturating(), 3);
+    assert_eq!(None::<u64>.saturating(), u64::MAX);
+}
+
+#[test]
+fn saturating_composites() {
+    let diff: DebugUlpsDiff<[[f64; 2]; 2]> = [[Some(1), None], [Some(0), Some(u64::MAX)]];
+    assert_eq!(diff.saturating(), [[1, u64::MAX], [0, u64::MAX]]);
+
+    let diff: DebugUlpsDiff<(f32, [f64; 2])> = (None, [Some(1), Some(4)]);
+    assert_eq!(diff.saturating(), (u32::MAX, [1, 4]));
+
+    #[cfg(feature = "alloc")]
+    {
+        let diff: DebugUlpsDiff<Vec<f32>> = vec![Some(2), None];
+        assert_eq!(diff.saturating(), vec![2, u32::MAX]);
+    }
+
+    let empty: DebugUlpsDiff<[f32; 0]> = [];
+    assert_eq!(empty.saturating(), []);
+
+    #[allow(clippy::let_unit_value)]
+    let unit: DebugUlpsDiff<()> = ();
+    assert_eq!(unit.saturating(), ());
+}
+
+#[test]
+fn debug_ulps_diff_saturating() {
+    assert_eq!(1.0f32.debug_ulps_diff_saturating(&1.000_000_1), 1);
+    assert_eq!(1.0f64.debug_ulps_diff_saturating(&-1.0), u64::MAX);
+    assert_eq!(f32::NAN.debug_ulps_diff_saturating(&f32::NAN), u32::MAX);
+
+    let a = [0.0f32, 1.0, -2.0, f32::NAN];
+    let b = [-0.0f32, 1.000_000_2, 2.0, 1.0];
+    assert_eq!(a.debug_ulps_diff_saturating(&b), [0, 2, u32::MAX, u32::MAX]);
+}
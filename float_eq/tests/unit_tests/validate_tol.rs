@@ -0,0 +1,58 @@
+use float_eq::{assert_float_eq, assert_float_ne, float_eq, float_ne};
+
+#[test]
+fn valid_tolerances() {
+    assert!(float_eq!(1.0f32, 1.0, abs <= 0.0, rmax <= -0.0));
+    assert!(float_ne!(
+        1.0f64,
+        2.0,
+        rmin <= 0.5,
+        r1st <= 0.5,
+        r2nd <= 0.1
+    ));
+    assert!(float_eq!(1.0f64, 2.0, abs <= f64::INFINITY));
+    assert!(float_eq!(1.0f64, 1.0, ulps <= 0));
+    assert_float_eq!([1.0f32, 2.0], [1.0, 2.0], abs_all <= 0.0);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `abs` tolerance `-0.1` is negative")]
+fn negative_f32() {
+    let tol = -0.1f32;
+    let _ = float_eq!(1.0f32, 1.0, abs <= tol);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `rmax` tolerance is NaN")]
+fn nan_f64() {
+    let tol = f64::NAN;
+    let _ = float_ne!(1.0f64, 1.0, rel <= tol);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `rmin` tolerance `-inf` is negative")]
+fn negative_infinite() {
+    let _ = float_eq!(1.0f64, 2.0, rmin <= -f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `r1st` tolerance is NaN")]
+fn nan_identical_values() {
+    // even when the values are identical and so equal anyway
+    let tol = f32::NAN;
+    let _ = float_eq!(1.0f32, 1.0, r1st <= tol);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `r2nd` tolerance `-1e-6` is negative")]
+fn composite_field() {
+    let tol = [0.1f64, 1e-6 - 2e-6, 0.1];
+    assert_float_ne!([1.0f64, 2.0, 3.0], [1.0, 2.0, 4.0], r2nd <= tol);
+}
+
+#[test]
+#[should_panic(expected = "float_eq: the `abs` tolerance is NaN")]
+fn all_check() {
+    let tol = f32::NAN;
+    let _ = float_eq!([1.0f32; 2], [1.0f32; 2], abs_all <= tol);
+}
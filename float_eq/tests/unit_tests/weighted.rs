@@ -0,0 +1,43 @@
+use float_eq::FloatEqWeighted;
+
+#[test]
+fn primitives() {
+    assert_eq!(1.0f32.weighted_error(&1.0, &0.0, &1.0), 0.0);
+    assert_eq!(1.0f32.weighted_error(&1.5, &0.25, &1.0), 2.0);
+    assert_eq!(1.0f32.weighted_error(&1.5, &0.25, &0.5), 1.0);
+    assert_eq!(1.0f64.weighted_error(&0.5, &0.25, &2.0), 4.0);
+    assert!(1.0f32.weighted_error(&f32::NAN, &0.25, &1.0).is_nan());
+
+    assert!(1.0f32.eq_weighted(&1.5, &0.25, &0.5, 1.0));
+    assert!(1.0f32.ne_weighted(&1.5, &0.25, &0.5, 0.9));
+    assert!(1.0f32.ne_weighted(&f32::NAN, &0.25, &0.5, f64::INFINITY));
+}
+
+#[test]
+fn zero_tolerance() {
+    assert_eq!(0.0f32.weighted_error(&-0.0, &0.0, &1.0), 0.0);
+    assert_eq!(1.0f32.weighted_error(&2.0, &0.0, &1.0), f64::INFINITY);
+}
+
+#[test]
+fn arrays() {
+    let a = [1.0f32, 2.0, 3.0];
+    let b = [1.5f32, 2.0, 2.0];
+    let tol = [0.5, 0.5, 0.5];
+
+    assert_eq!(a.weighted_error(&b, &tol, &[1.0, 1.0, 1.0]), 3.0);
+    assert_eq!(a.weighted_error(&b, &tol, &[1.0, 1.0, 0.25]), 1.5);
+    assert!(a.eq_weighted(&b, &tol, &[1.0, 1.0, 0.25], 1.5));
+    assert!(a.ne_weighted(&b, &tol, &[1.0, 1.0, 0.5], 1.5));
+}
+
+#[test]
+fn tuples() {
+    assert_eq!(().weighted_error(&(), &(), &()), 0.0);
+
+    let a = (1.0f32, 2.0f64);
+    let b = (1.5f32, 1.0f64);
+    assert_eq!(a.weighted_error(&b, &(0.5, 0.5), &(1.0, 0.5)), 2.0);
+    assert!(a.eq_weighted(&b, &(0.5, 0.5), &(1.0, 0.5), 2.0));
+    assert!(a.ne_weighted(&b, &(0.5, 0.5), &(1.0, 1.0), 2.0));
+}
@@ -0,0 +1,55 @@
+use float_eq::{UlpsTol, WidenTol};
+
+#[test]
+fn primitives() {
+    assert_eq!(0.1f32.widen(), f64::from(0.1f32));
+    assert_eq!(f32::narrow(&0.1f64), 0.1f32);
+    assert_eq!(f32::narrow(&f64::MAX), f32::INFINITY);
+    assert_eq!(1.5f64.widen(), 1.5f64);
+    assert_eq!(f64::narrow(&1.5), 1.5);
+
+    assert_eq!(u32::MAX.widen(), u64::from(u32::MAX));
+    assert_eq!(u32::narrow(&4), 4);
+    assert_eq!(u32::narrow(&(u64::from(u32::MAX) + 1)), u32::MAX);
+    assert_eq!(u32::narrow(&u64::MAX), u32::MAX);
+    assert_eq!(u64::MAX.widen(), u64::MAX);
+    assert_eq!(u64::narrow(&u64::MAX), u64::MAX);
+}
+
+#[test]
+fn arrays() {
+    let a: UlpsTol<[[f32; 2]; 2]> = [[1, 2], [3, u32::MAX]];
+    let wide: UlpsTol<[[f64; 2]; 2]> = a.widen();
+    assert_eq!(wide, [[1, 2], [3, u64::from(u32::MAX)]]);
+    assert_eq!(<[[u32; 2]; 2]>::narrow(&[[1, 2], [3, u64::MAX]]), a);
+
+    assert_eq!([0.5f32, 0.25].widen(), [0.5f64, 0.25]);
+    assert_eq!(<[f32; 2]>::narrow(&[0.5, 0.25]), [0.5f32, 0.25]);
+
+    let empty: [f32; 0] = [];
+    let wide: [f64; 0] = empty.widen();
+    assert_eq!(wide, []);
+}
+
+#[test]
+fn tuples() {
+    ().widen();
+    <()>::narrow(&());
+
+    let a = (0.5f32, [1u32, 2], 0.25f64);
+    let wide: (f64, [u64; 2], f64) = a.widen();
+    assert_eq!(wide, (0.5, [1, 2], 0.25));
+    assert_eq!(
+        <(f32, [u32; 2], f64)>::narrow(&(0.5, [1, u64::MAX], 0.25)),
+        (0.5, [1, u32::MAX], 0.25)
+    );
+
+    let a: (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32) =
+        (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    let wide: (u64, u64, u64, u64, u64, u64, u64, u64, u64, u64, u64, u64) = a.widen();
+    assert_eq!(wide, (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+    assert_eq!(
+        <(u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32)>::narrow(&wide),
+        a
+    );
+}
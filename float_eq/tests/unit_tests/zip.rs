@@ -0,0 +1,81 @@
+use float_eq::{ElementCmp, FloatEq, FloatEqZipExt};
+
+#[test]
+fn float_eq_zip() {
+    let a = [1.0f32, 2.0, 3.0];
+    let b = [1.0f32, 2.5, 2.75];
+
+    let cmps: Vec<_> = a.iter().float_eq_zip(b.iter(), 0.25).collect();
+    assert_eq!(
+        cmps,
+        vec![
+            ElementCmp {
+                index: 0,
+                a: &1.0,
+                b: &1.0,
+                passed: true,
+                diff: 0.0
+            },
+            ElementCmp {
+                index: 1,
+                a: &2.0,
+                b: &2.5,
+                passed: false,
+                diff: 0.5
+            },
+            ElementCmp {
+                index: 2,
+                a: &3.0,
+                b: &2.75,
+                passed: true,
+                diff: 0.25
+            },
+        ]
+    );
+}
+
+#[test]
+fn float_eq_zip_by() {
+    let a = [1.0f64, 2.0, 3.0];
+    let b = [1.0f64, 2.000_000_000_000_000_4, 3.5];
+
+    let failed: Vec<_> = a
+        .iter()
+        .float_eq_zip_by(b.iter(), 1, |a, b, tol| a.eq_ulps(b, tol))
+        .filter(|cmp| !cmp.passed)
+        .map(|cmp| cmp.index)
+        .collect();
+    assert_eq!(failed, vec![2]);
+
+    let failed = a
+        .iter()
+        .float_eq_zip_by(b.iter(), 0.1, |a, b, tol| a.eq_rmax(b, tol))
+        .filter(|cmp| !cmp.passed)
+        .count();
+    assert_eq!(failed, 1);
+}
+
+#[test]
+fn composite_elements() {
+    let a = vec![[1.0f32, 2.0], [3.0, 4.0]];
+    let b = vec![[1.0f32, 2.0], [3.0, 4.5]];
+
+    let cmp = a
+        .into_iter()
+        .float_eq_zip(b, [0.1, 0.1])
+        .find(|cmp| !cmp.passed)
+        .unwrap();
+    assert_eq!(cmp.index, 1);
+    assert_eq!(cmp.diff, [0.0, 0.5]);
+}
+
+#[test]
+fn stops_at_shortest() {
+    let a = [1.0f32, 2.0, 3.0];
+    let b = [1.0f32, 2.0];
+
+    let zip = a.iter().float_eq_zip(b.iter(), 0.0);
+    assert_eq!(zip.size_hint(), (2, Some(2)));
+    assert_eq!(zip.count(), 2);
+    assert_eq!(b.iter().float_eq_zip(a.iter(), 0.0).count(), 2);
+}
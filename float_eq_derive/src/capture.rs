@@ -0,0 +1,96 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{BinOp, Expr, Token};
+
+/// The arguments to a capturing assert: the two operands, followed by the
+/// checks and any custom message, which are passed through as they are.
+pub(crate) struct CaptureArgs {
+    left: Expr,
+    right: Expr,
+    rest: TokenStream,
+}
+
+impl Parse for CaptureArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let left = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let right = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let rest = input.parse()?;
+        Ok(CaptureArgs { left, right, rest })
+    }
+}
+
+/// Expands to a call to the `assert` macro, with the operands of arithmetic at
+/// the top level of the left and right expressions evaluated up front, so that
+/// their values may be shown if it fails.
+pub(crate) fn expand_capture(args: CaptureArgs, assert: &str) -> TokenStream {
+    let mut captured = Vec::new();
+    let left = split_operands(args.left, &mut captured);
+    let right = split_operands(args.right, &mut captured);
+    let rest = args.rest;
+    let assert = Ident::new(assert, Span::call_site());
+
+    if captured.is_empty() {
+        return quote! { float_eq::#assert!(#left, #right, #rest) };
+    }
+
+    let names = captured.iter().map(|(name, _)| name);
+    let exprs = captured.iter().map(|(_, expr)| expr);
+    let labels = captured.iter().map(|(name, expr)| {
+        quote! { (concat!("`", stringify!(#expr), "`"), &#name as &dyn ::core::fmt::Debug) }
+    });
+
+    quote! {{
+        #(let #names = #exprs;)*
+        let __float_eq_capture_scope = float_eq::CaptureScope::new(&[#(#labels,)*]);
+        float_eq::#assert!(#left, #right, #rest);
+    }}
+}
+
+/// The expression with each operand of any arithmetic at its top level
+/// replaced by a reference to its captured value.
+fn split_operands(expr: Expr, captured: &mut Vec<(Ident, Expr)>) -> TokenStream {
+    match expr {
+        Expr::Binary(binary) if is_arithmetic(&binary.op) => {
+            let left = capture(*binary.left, captured);
+            let right = capture(*binary.right, captured);
+            let op = binary.op;
+            quote! { (#left #op #right) }
+        }
+        Expr::Paren(paren) => split_operands(*paren.expr, captured),
+        Expr::Group(group) => split_operands(*group.expr, captured),
+        expr => quote! { #expr },
+    }
+}
+
+/// A name for the operand's value, unless it is a literal that would gain
+/// nothing from being displayed separately.
+fn capture(operand: Expr, captured: &mut Vec<(Ident, Expr)>) -> TokenStream {
+    if let Expr::Lit(_) = operand {
+        return quote! { #operand };
+    }
+    let name = Ident::new(
+        &format!("__float_eq_captured_{}", captured.len()),
+        Span::call_site(),
+    );
+    captured.push((name.clone(), operand));
+    quote! { #name }
+}
+
+fn is_arithmetic(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::Add(_)
+            | BinOp::Sub(_)
+            | BinOp::Mul(_)
+            | BinOp::Div(_)
+            | BinOp::Rem(_)
+            | BinOp::BitXor(_)
+            | BinOp::BitAnd(_)
+            | BinOp::BitOr(_)
+            | BinOp::Shl(_)
+            | BinOp::Shr(_)
+    )
+}
@@ -0,0 +1,651 @@
+//! Expansion of the derived traits for enums.
+//!
+//! Each variant is compared field by field in the same way as a struct, and
+//! values of different variants are never equal. Tolerances are of the same
+//! type as the enum, so they must also be of the same variant for values to be
+//! equal. The debug types are `Option`s that are `None` if the variants differ,
+//! much like the implementations for `Option` itself.
+//!
+//! Fields that are not compared with a tolerance are absent from the generated
+//! types, and the debug values of the enum itself take them from `self`.
+
+use crate::generics;
+use crate::read::{FieldInfo, FieldListType, VariantInfo};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::DeriveInput;
+
+/// A pattern matching `variant` of `ty`, binding each field to a variable
+/// named with `prefix`, such as `Foo::Bar { x: self_x }`. If `debug_names`
+/// then `ty` is one of the generated types, which only has the fields that are
+/// compared with a tolerance.
+fn pattern(ty: &Ident, variant: &VariantInfo, prefix: &str, debug_names: bool) -> TokenStream {
+    let variant_name = variant.name;
+    let bind = |field: &FieldInfo| {
+        let name = if debug_names {
+            &field.debug_name
+        } else {
+            &field.name
+        };
+        let binding = field.binding(prefix);
+        quote! { #name: #binding }
+    };
+    let fields = if debug_names {
+        variant.fields.expand_float(bind)
+    } else {
+        variant.fields.expand(bind)
+    };
+    quote! { #ty::#variant_name { #(#fields,)* } }
+}
+
+/// An expression constructing `variant` of `ty`, with the value of each field
+/// compared with a tolerance given by `expr`. If `debug_names` then `ty` is
+/// one of the generated types, otherwise other fields are cloned from `self`.
+#[cfg(feature = "assert")]
+fn construct<F>(ty: &Ident, variant: &VariantInfo, debug_names: bool, expr: F) -> TokenStream
+where
+    F: Fn(&FieldInfo) -> TokenStream,
+{
+    let variant_name = variant.name;
+    let fields = variant.fields.expand(|field| {
+        let name = if debug_names {
+            &field.debug_name
+        } else {
+            &field.name
+        };
+        let value = if field.is_float() {
+            expr(field)
+        } else if debug_names {
+            return TokenStream::new();
+        } else {
+            let self_field = field.binding("self");
+            quote! { ::core::clone::Clone::clone(#self_field) }
+        };
+        quote! { #name: #value, }
+    });
+    quote! { #ty::#variant_name { #(#fields)* } }
+}
+
+/// Each field's check of a variant combined with `&&`, given `expr`.
+fn all_fields<F>(variant: &VariantInfo, expr: F) -> TokenStream
+where
+    F: Fn(&FieldInfo) -> TokenStream,
+{
+    let checks = variant.fields.expand_checks(field_refs, expr);
+    quote! { #(#checks)&&* }
+}
+
+/// The bindings of a field in `self` and `other`, for comparing it with a
+/// custom function.
+fn field_refs(field: &FieldInfo) -> (TokenStream, TokenStream) {
+    let self_field = field.binding("self");
+    let other_field = field.binding("other");
+    (quote! { #self_field }, quote! { #other_field })
+}
+
+/// The declaration of a type shaped like the enum, such as its ULPs tolerance,
+/// with each field's type wrapped by `wrapper`.
+pub fn expand_type(
+    input: &DeriveInput,
+    name: &Ident,
+    (generics, where_clause): (&TokenStream, &TokenStream),
+    variants: &[VariantInfo],
+    wrapper: TokenStream,
+) -> TokenStream {
+    let vis = &input.vis;
+    let variant_decls = variants.iter().map(|variant| {
+        let variant_name = variant.name;
+        match variant.fields.ty {
+            FieldListType::Named => {
+                let fields = variant.fields.expand_float(|field| {
+                    let name = &field.debug_name;
+                    let ty = &field.ty;
+                    quote! { #name: #wrapper<#ty> }
+                });
+                quote! { #variant_name { #(#fields,)* } }
+            }
+            FieldListType::Tuple => {
+                let fields = variant.fields.expand_float(|field| {
+                    let ty = &field.ty;
+                    quote! { #wrapper<#ty> }
+                });
+                if fields.is_empty() && !variant.fields.is_empty() {
+                    // every field is skipped or compared by a custom function
+                    quote! { #variant_name }
+                } else {
+                    quote! { #variant_name( #(#fields,)* ) }
+                }
+            }
+            FieldListType::Unit => quote! { #variant_name },
+        }
+    });
+    quote! {
+        #vis enum #name #generics #where_clause {
+            #(#variant_decls,)*
+        }
+    }
+}
+
+pub fn expand_float_eq(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+    ulps_name: &Ident,
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, ulps_generics) = generics::generated_params(input);
+
+    let expand_arms = |method: &str| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        let is_ulps = method == "eq_ulps";
+        variants.iter().map(move |variant| {
+            let self_pattern = pattern(enum_name, variant, "self", false);
+            let other_pattern = pattern(enum_name, variant, "other", false);
+            let tol_pattern = if is_ulps {
+                pattern(ulps_name, variant, "tol", true)
+            } else {
+                pattern(enum_name, variant, "tol", false)
+            };
+            let checks = all_fields(variant, |field| {
+                let self_field = field.binding("self");
+                let other_field = field.binding("other");
+                let tol_field = field.binding("tol");
+                quote! { float_eq::FloatEq::#method(#self_field, #other_field, #tol_field) }
+            });
+            quote! { (#self_pattern, #other_pattern, #tol_pattern) => #checks }
+        })
+    };
+
+    let eq_abs = expand_arms("eq_abs");
+    let eq_rmax = expand_arms("eq_rmax");
+    let eq_rmin = expand_arms("eq_rmin");
+    let eq_r1st = expand_arms("eq_r1st");
+    let eq_r2nd = expand_arms("eq_r2nd");
+    let eq_ulps = expand_arms("eq_ulps");
+    let field_checks = variants.iter().flat_map(|variant| {
+        variant.fields.expand_float(|field| {
+            let ty = &field.ty;
+            quote! { .intersection(<#ty as float_eq::FloatEq>::SUPPORTED_CHECKS) }
+        })
+    });
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::FloatEq for #enum_name #ty_generics #where_clause {
+            type Tol = Self;
+            const SUPPORTED_CHECKS: float_eq::CheckSet = float_eq::CheckSet::ALL #(#field_checks)*;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self) -> bool {
+                match (self, other, tol) {
+                    #(#eq_abs,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self) -> bool {
+                match (self, other, tol) {
+                    #(#eq_rmax,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self) -> bool {
+                match (self, other, tol) {
+                    #(#eq_rmin,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self) -> bool {
+                match (self, other, tol) {
+                    #(#eq_r1st,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self) -> bool {
+                match (self, other, tol) {
+                    #(#eq_r2nd,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &#ulps_name #ulps_generics) -> bool {
+                match (self, other, tol) {
+                    #(#eq_ulps,)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bits")]
+pub fn expand_float_eq_bits(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let arms = variants.iter().map(|variant| {
+        let self_pattern = pattern(enum_name, variant, "self", false);
+        let other_pattern = pattern(enum_name, variant, "other", false);
+        let checks = all_fields(variant, |field| {
+            let self_field = field.binding("self");
+            let other_field = field.binding("other");
+            quote! { float_eq::FloatEqBits::eq_bits(#self_field, #other_field) }
+        });
+        quote! { (#self_pattern, #other_pattern) => #checks }
+    });
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::FloatEqBits for #enum_name #ty_generics #where_clause {
+            #[inline]
+            fn eq_bits(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#arms,)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "weighted")]
+pub fn expand_float_eq_weighted(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let arms = variants.iter().map(|variant| {
+        let self_pattern = pattern(enum_name, variant, "self", false);
+        let other_pattern = pattern(enum_name, variant, "other", false);
+        let tol_pattern = pattern(enum_name, variant, "tol", false);
+        let weights_pattern = pattern(enum_name, variant, "weights", false);
+        let errors = variant.fields.expand_compared(|field| {
+            let self_field = field.binding("self");
+            let other_field = field.binding("other");
+            if let Some(eq) = field.custom_eq(quote! { #self_field }, quote! { #other_field }) {
+                return quote! { if #eq { 0.0 } else { ::core::f64::INFINITY } };
+            }
+            let tol_field = field.binding("tol");
+            let weights_field = field.binding("weights");
+            quote! {
+                float_eq::FloatEqWeighted::weighted_error(
+                    #self_field,
+                    #other_field,
+                    #tol_field,
+                    #weights_field,
+                )
+            }
+        });
+        quote! {
+            (#self_pattern, #other_pattern, #tol_pattern, #weights_pattern) => 0.0 #(+ #errors)*
+        }
+    });
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::FloatEqWeighted for #enum_name #ty_generics #where_clause {
+            #[inline]
+            fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64 {
+                match (self, other, tol, weights) {
+                    #(#arms,)*
+                    _ => ::core::f64::INFINITY,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "assert")]
+pub fn expand_assert_float_eq(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+    ulps_name: &Ident,
+    diff_name: &Ident,
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, ulps_generics) = generics::generated_params(input);
+
+    let expand_diff_arms = |method: &str, result_name: &Ident, debug_names: bool| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        variants
+            .iter()
+            .map(|variant| {
+                let self_pattern = pattern(enum_name, variant, "self", false);
+                let other_pattern = pattern(enum_name, variant, "other", false);
+                let result = construct(result_name, variant, debug_names, |field| {
+                    let self_field = field.binding("self");
+                    let other_field = field.binding("other");
+                    quote! { float_eq::AssertFloatEq::#method(#self_field, #other_field) }
+                });
+                quote! {
+                    (#self_pattern, #other_pattern) => ::core::option::Option::Some(#result)
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let abs_diff_arms = expand_diff_arms("debug_abs_diff", enum_name, false);
+    let ulps_diff_arms = expand_diff_arms("debug_ulps_diff", diff_name, true);
+
+    let expand_tol_arms = |method: &str| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        let is_ulps = method == "debug_ulps_tol";
+        variants
+            .iter()
+            .map(|variant| {
+                let self_pattern = pattern(enum_name, variant, "self", false);
+                let other_pattern = pattern(enum_name, variant, "other", false);
+                let result_name = if is_ulps { ulps_name } else { enum_name };
+                let tol_pattern = pattern(result_name, variant, "tol", is_ulps);
+                let result = construct(result_name, variant, is_ulps, |field| {
+                    let self_field = field.binding("self");
+                    let other_field = field.binding("other");
+                    let tol_field = field.binding("tol");
+                    quote! {
+                        float_eq::AssertFloatEq::#method(#self_field, #other_field, #tol_field)
+                    }
+                });
+                quote! {
+                    (#self_pattern, #other_pattern, #tol_pattern) => {
+                        ::core::option::Option::Some(#result)
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let abs_tol_arms = expand_tol_arms("debug_abs_tol");
+    let rmax_tol_arms = expand_tol_arms("debug_rmax_tol");
+    let rmin_tol_arms = expand_tol_arms("debug_rmin_tol");
+    let r1st_tol_arms = expand_tol_arms("debug_r1st_tol");
+    let r2nd_tol_arms = expand_tol_arms("debug_r2nd_tol");
+    let ulps_tol_arms = expand_tol_arms("debug_ulps_tol");
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::AssertFloatEq for #enum_name #ty_generics #where_clause {
+            type DebugAbsDiff = ::core::option::Option<Self>;
+            type DebugTol = ::core::option::Option<Self>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#abs_diff_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_diff(
+                &self,
+                other: &Self,
+            ) -> ::core::option::Option<#diff_name #ulps_generics> {
+                match (self, other) {
+                    #(#ulps_diff_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &Self) -> ::core::option::Option<Self> {
+                match (self, other, tol) {
+                    #(#abs_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self) -> ::core::option::Option<Self> {
+                match (self, other, tol) {
+                    #(#rmax_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self) -> ::core::option::Option<Self> {
+                match (self, other, tol) {
+                    #(#rmin_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self) -> ::core::option::Option<Self> {
+                match (self, other, tol) {
+                    #(#r1st_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self) -> ::core::option::Option<Self> {
+                match (self, other, tol) {
+                    #(#r2nd_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &#ulps_name #ulps_generics,
+            ) -> ::core::option::Option<#ulps_name #ulps_generics> {
+                match (self, other, tol) {
+                    #(#ulps_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+}
+
+pub fn expand_float_eq_all(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+    all_tol: &Ident,
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let expand_arms = |method: &str| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        variants
+            .iter()
+            .map(|variant| {
+                let self_pattern = pattern(enum_name, variant, "self", false);
+                let other_pattern = pattern(enum_name, variant, "other", false);
+                let checks = all_fields(variant, |field| {
+                    let self_field = field.binding("self");
+                    let other_field = field.binding("other");
+                    quote! { float_eq::FloatEqAll::#method(#self_field, #other_field, tol) }
+                });
+                quote! { (#self_pattern, #other_pattern) => #checks }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let eq_abs = expand_arms("eq_abs_all");
+    let eq_rmax = expand_arms("eq_rmax_all");
+    let eq_rmin = expand_arms("eq_rmin_all");
+    let eq_r1st = expand_arms("eq_r1st_all");
+    let eq_r2nd = expand_arms("eq_r2nd_all");
+    let eq_ulps = expand_arms("eq_ulps_all");
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::FloatEqAll for #enum_name #ty_generics #where_clause {
+            type AllTol = #all_tol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &#all_tol) -> bool {
+                match (self, other) {
+                    #(#eq_abs,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &#all_tol) -> bool {
+                match (self, other) {
+                    #(#eq_rmax,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &#all_tol) -> bool {
+                match (self, other) {
+                    #(#eq_rmin,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &#all_tol) -> bool {
+                match (self, other) {
+                    #(#eq_r1st,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &#all_tol) -> bool {
+                match (self, other) {
+                    #(#eq_r2nd,)*
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &::float_eq::UlpsTol<Self::AllTol>) -> bool {
+                match (self, other) {
+                    #(#eq_ulps,)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "assert")]
+pub fn expand_assert_float_eq_all(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    variants: &[VariantInfo],
+    ulps_name: &Ident,
+    all_tol: &Ident,
+) -> TokenStream {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, ulps_generics) = generics::generated_params(input);
+
+    let expand_arms = |method: &str| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        let is_ulps = method == "debug_ulps_all_tol";
+        let result_name = if is_ulps { ulps_name } else { enum_name };
+        variants
+            .iter()
+            .map(|variant| {
+                let self_pattern = pattern(enum_name, variant, "self", false);
+                let other_pattern = pattern(enum_name, variant, "other", false);
+                let result = construct(result_name, variant, is_ulps, |field| {
+                    let self_field = field.binding("self");
+                    let other_field = field.binding("other");
+                    quote! {
+                        float_eq::AssertFloatEqAll::#method(#self_field, #other_field, tol)
+                    }
+                });
+                quote! {
+                    (#self_pattern, #other_pattern) => ::core::option::Option::Some(#result)
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let abs_tol_arms = expand_arms("debug_abs_all_tol");
+    let rmax_tol_arms = expand_arms("debug_rmax_all_tol");
+    let rmin_tol_arms = expand_arms("debug_rmin_all_tol");
+    let r1st_tol_arms = expand_arms("debug_r1st_all_tol");
+    let r2nd_tol_arms = expand_arms("debug_r2nd_all_tol");
+    let ulps_tol_arms = expand_arms("debug_ulps_all_tol");
+
+    quote! {
+        #[allow(unreachable_patterns, unused_variables)]
+        impl #impl_generics float_eq::AssertFloatEqAll for #enum_name #ty_generics #where_clause {
+            type AllDebugTol = ::core::option::Option<Self>;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &#all_tol) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#abs_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &#all_tol) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#rmax_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &#all_tol) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#rmin_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &#all_tol) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#r1st_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &#all_tol) -> ::core::option::Option<Self> {
+                match (self, other) {
+                    #(#r2nd_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &Self,
+                tol: &::float_eq::UlpsTol<Self::AllTol>
+            ) -> ::core::option::Option<#ulps_name #ulps_generics> {
+                match (self, other) {
+                    #(#ulps_tol_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+}
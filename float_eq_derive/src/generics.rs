@@ -0,0 +1,328 @@
+//! Bounds for deriving the traits for generic types.
+//!
+//! Rather than bounding the generic parameters themselves, each field whose
+//! type uses one of them is bounded by the traits its comparison relies on, so
+//! that fields such as `[T; 3]` or `Vec<T>` are handled as well as `T`. Types
+//! that are not generic are expanded without any additional bounds. Fields
+//! that are skipped or compared by a custom function are bounded separately,
+//! since they are not compared using the float_eq traits.
+
+use crate::read::{self, FloatEqAttr};
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{Data, DeriveInput, GenericParam, Path, Type};
+
+/// Builds the where clause of a derived impl or type, by adding bounds to that
+/// of the input type.
+#[derive(Clone)]
+pub struct Bounds<'a> {
+    input: &'a DeriveInput,
+    field_types: Vec<&'a Type>,
+    other_types: Vec<&'a Type>,
+    predicates: Vec<TokenStream>,
+}
+
+impl<'a> Bounds<'a> {
+    pub fn new(input: &'a DeriveInput) -> Self {
+        let params: Vec<&Ident> = input
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(&param.ident),
+                GenericParam::Const(param) => Some(&param.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+
+        let mut field_types: Vec<(String, &Type)> = Vec::new();
+        let mut other_types: Vec<(String, &Type)> = Vec::new();
+        if !params.is_empty() {
+            for field in all_fields(input) {
+                let types = if read::is_float_field(field) {
+                    &mut field_types
+                } else {
+                    &mut other_types
+                };
+                let tokens = field.ty.to_token_stream();
+                let key = tokens.to_string();
+                let is_new = !types.iter().any(|(k, _)| *k == key);
+                if is_new && uses_params(tokens, &params) {
+                    types.push((key, &field.ty));
+                }
+            }
+        }
+
+        Bounds {
+            input,
+            field_types: field_types.into_iter().map(|(_, ty)| ty).collect(),
+            other_types: other_types.into_iter().map(|(_, ty)| ty).collect(),
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Add the predicate given by `bound` for the type of each generic field.
+    pub fn fields<F: Fn(&Type) -> TokenStream>(mut self, bound: F) -> Self {
+        self.predicates
+            .extend(self.field_types.iter().map(|ty| bound(ty)));
+        self
+    }
+
+    /// Add the predicate given by `bound` for the type of each generic field
+    /// that is skipped or compared by a custom function.
+    #[cfg(feature = "assert")]
+    pub fn other_fields<F: Fn(&Type) -> TokenStream>(mut self, bound: F) -> Self {
+        self.predicates
+            .extend(self.other_types.iter().map(|ty| bound(ty)));
+        self
+    }
+
+    /// Add `predicate` if the type has any generic fields.
+    pub fn predicate(mut self, predicate: TokenStream) -> Self {
+        if !self.field_types.is_empty() || !self.other_types.is_empty() {
+            self.predicates.push(predicate);
+        }
+        self
+    }
+
+    /// Add `predicate` whether or not the type has any generic fields.
+    pub fn always(mut self, predicate: TokenStream) -> Self {
+        self.predicates.push(predicate);
+        self
+    }
+
+    /// Bounds required to name the derived ULPs tolerance type.
+    pub fn ulps_tol(self, params: &FloatEqAttr) -> Self {
+        let derives = derive_trait_paths(params.ulps_tol_derive_types());
+        self.fields(|ty| quote! { #ty: float_eq::FloatEqUlpsTol })
+            .fields(|ty| {
+                quote! { float_eq::UlpsTol<#ty>: ::core::marker::Sized #(+ #derives)* }
+            })
+    }
+
+    /// Bounds required to name the derived debug ULPs diff type.
+    pub fn debug_ulps_diff(self, params: &FloatEqAttr) -> Self {
+        let derives = derive_trait_paths(params.debug_ulps_diff_derive_types());
+        self.fields(|ty| quote! { #ty: float_eq::FloatEqDebugUlpsDiff })
+            .fields(|ty| {
+                quote! { float_eq::DebugUlpsDiff<#ty>: ::core::marker::Sized #(+ #derives)* }
+            })
+    }
+
+    /// Bounds required to implement `FloatEq`.
+    pub fn float_eq(self, params: &FloatEqAttr) -> Self {
+        self.ulps_tol(params)
+            .fields(|ty| quote! { #ty: float_eq::FloatEq<Tol = #ty> })
+    }
+
+    /// Bounds required to implement `FloatEqAll`.
+    pub fn float_eq_all(self, all_tol: &Ident) -> Self {
+        self.fields(|ty| quote! { #ty: float_eq::FloatEqAll<AllTol = #all_tol> })
+            .predicate(quote! { #all_tol: float_eq::FloatEqUlpsTol })
+    }
+
+    /// Bound the type itself by `Debug`, for use as a debug value in assert
+    /// messages.
+    #[cfg(feature = "assert")]
+    pub fn debug(self) -> Self {
+        let name = &self.input.ident;
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        let predicate = quote! { #name #ty_generics: ::core::fmt::Debug };
+        self.predicate(predicate)
+    }
+
+    /// The where clause of the input type with the added bounds, if any.
+    pub fn where_clause(&self) -> TokenStream {
+        self.where_clause_without(&[])
+    }
+
+    /// The where clause of a generated ULPs tolerance or debug ULPs diff type,
+    /// which omits any predicates of the input type that name parameters the
+    /// generated type does not have.
+    pub fn generated_where_clause(&self) -> TokenStream {
+        self.where_clause_without(&unused_params(self.input))
+    }
+
+    fn where_clause_without(&self, unused: &[Ident]) -> TokenStream {
+        let unused: Vec<&Ident> = unused.iter().collect();
+        let existing = self
+            .input
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter())
+            .map(ToTokens::to_token_stream)
+            .filter(|tokens| !uses_params(tokens.clone(), &unused));
+        let predicates = &self.predicates;
+        let mut all = existing.peekable();
+        if all.peek().is_none() && predicates.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { where #(#all,)* #(#predicates,)* }
+        }
+    }
+}
+
+/// The generic parameters of the generated ULPs tolerance and debug ULPs diff
+/// types, as they are declared and then as the arguments used to name them.
+///
+/// These only include fields compared with a tolerance, so any parameters used
+/// solely by the fields that are not are omitted, since they would be unused.
+pub fn generated_params(input: &DeriveInput) -> (TokenStream, TokenStream) {
+    let unused = unused_params(input);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    if unused.is_empty() {
+        let params = &input.generics;
+        return (quote! { #params }, quote! { #ty_generics });
+    }
+
+    let kept: Vec<&GenericParam> = input
+        .generics
+        .params
+        .iter()
+        .filter(|param| !unused.contains(param_ident(param)))
+        .collect();
+    if kept.is_empty() {
+        return (TokenStream::new(), TokenStream::new());
+    }
+    let args = kept.iter().map(|param| match param {
+        GenericParam::Type(param) => param.ident.to_token_stream(),
+        GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        GenericParam::Const(param) => param.ident.to_token_stream(),
+    });
+    (quote! { <#(#kept),*> }, quote! { <#(#args),*> })
+}
+
+/// Checks that `precisions` may be used with a type, which requires that it
+/// has a single type parameter.
+pub fn check_precisions(input: &DeriveInput) -> Result<(), syn::Error> {
+    let is_single_type_param = input.generics.params.len() == 1
+        && matches!(input.generics.params.first(), Some(GenericParam::Type(_)));
+    if is_single_type_param {
+        Ok(())
+    } else {
+        let msg = format!(
+            "`precisions` may only be used with a type that has a single type parameter, such as `{}<T>`.",
+            input.ident
+        );
+        Err(syn::Error::new(input.ident.span(), msg))
+    }
+}
+
+/// Type aliases naming a generated type for each of the `precisions` of a
+/// type with a single type parameter, such as `MyComplexF32Ulps` for the
+/// `MyComplexUlps<f32>` generated for `MyComplex<T>` with a `suffix` of `Ulps`.
+pub fn precision_aliases(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+    type_name: &Ident,
+    suffix: &str,
+) -> Result<TokenStream, syn::Error> {
+    let precisions = params.precisions();
+    if precisions.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    check_precisions(input)?;
+
+    let vis = &input.vis;
+    let is_generic = unused_params(input).is_empty();
+    let aliases = precisions.iter().map(|precision| {
+        let last = precision
+            .segments
+            .last()
+            .map_or_else(String::new, |segment| segment.ident.to_string());
+        let mut chars = last.chars();
+        let capitalized = chars
+            .next()
+            .map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect());
+        let alias = Ident::new(
+            &format!("{}{}{}", input.ident, capitalized, suffix),
+            type_name.span(),
+        );
+        let args = if is_generic {
+            quote! { <#precision> }
+        } else {
+            TokenStream::new()
+        };
+        let doc = format!(
+            "`{}` instantiated with `{}`.",
+            type_name,
+            precision.to_token_stream().to_string().replace(' ', "")
+        );
+        quote! {
+            #[doc = #doc]
+            #vis type #alias = #type_name #args;
+        }
+    });
+    Ok(quote! { #(#aliases)* })
+}
+
+fn param_ident(param: &GenericParam) -> &Ident {
+    match param {
+        GenericParam::Type(param) => &param.ident,
+        GenericParam::Lifetime(param) => &param.lifetime.ident,
+        GenericParam::Const(param) => &param.ident,
+    }
+}
+
+/// The generic parameters that are not used by any field compared with a
+/// tolerance.
+pub fn unused_params(input: &DeriveInput) -> Vec<Ident> {
+    let fields = all_fields(input);
+    if fields.iter().all(|field| read::is_float_field(field)) {
+        return Vec::new();
+    }
+    input
+        .generics
+        .params
+        .iter()
+        .map(param_ident)
+        .filter(|ident| {
+            !fields
+                .iter()
+                .filter(|field| read::is_float_field(field))
+                .any(|field| uses_params(field.ty.to_token_stream(), &[ident]))
+        })
+        .cloned()
+        .collect()
+}
+
+/// The fields of every variant of the input type.
+fn all_fields(input: &DeriveInput) -> Vec<&syn::Field> {
+    match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+    }
+}
+
+/// Whether any of the tokens of a type name one of `params`.
+fn uses_params(tokens: TokenStream, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.iter().any(|p| **p == ident),
+        TokenTree::Group(group) => uses_params(group.stream(), params),
+        _ => false,
+    })
+}
+
+/// The paths of those traits that are derived by the standard library, so
+/// that fields of generated types may be bounded by them. Other derived traits
+/// must be bounded by the where clause of the input type.
+fn derive_trait_paths(derives: &[Path]) -> Vec<TokenStream> {
+    derives
+        .iter()
+        .filter_map(|path| path.get_ident())
+        .filter_map(|name| match name.to_string().as_str() {
+            "Clone" => Some(quote! { ::core::clone::Clone }),
+            "Copy" => Some(quote! { ::core::marker::Copy }),
+            "Debug" => Some(quote! { ::core::fmt::Debug }),
+            "Default" => Some(quote! { ::core::default::Default }),
+            "Hash" => Some(quote! { ::core::hash::Hash }),
+            "PartialEq" => Some(quote! { ::core::cmp::PartialEq }),
+            "Eq" => Some(quote! { ::core::cmp::Eq }),
+            "PartialOrd" => Some(quote! { ::core::cmp::PartialOrd }),
+            "Ord" => Some(quote! { ::core::cmp::Ord }),
+            _ => None,
+        })
+        .collect()
+}
@@ -8,12 +8,18 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::{parse_macro_input, DeriveInput};
 
+#[cfg(feature = "capture")]
+mod capture;
+mod enums;
+mod generics;
 mod read;
+mod rhs;
 
 /// Helper for deriving the various float_eq traits.
 ///
 /// By default, this will derive [`FloatEqUlpsTol`], [`FloatEq`], [`FloatEqDebugUlpsDiff`]
-/// and [`AssertFloatEq`].
+/// and [`AssertFloatEq`]. The assert traits are only derived if float_eq's
+/// `derive-assert` feature is enabled, and not if `checks-only` is.
 ///
 /// Attribute parameters are passed through to the `#[float_eq(...)]` attribute:
 /// `ulps_tol` is the name of the type generated for [`FloatEqUlpsTol`] and
@@ -22,7 +28,20 @@ mod read;
 ///
 /// If the optional `all_tol` parameter is provided, which names the type to be
 /// used by `FloatEqAll::AllTol` then [`FloatEqAll`] and [`AssertFloatEqAll`]
-/// are also derived.
+/// are also derived. If the optional `tol_builder` parameter is provided, which
+/// names the type generated to build tolerances fluently, then a tolerance
+/// builder is also derived. The optional `ulps_tol_derive`, `ulps_tol_attr`,
+/// `debug_ulps_diff_derive` and `debug_ulps_diff_attr` parameters list the
+/// traits to derive on and the attributes to add to the generated types.
+///
+/// For a type with a single type parameter, the optional `precisions`
+/// parameter lists the types it is instantiated with, such as `"f32, f64"`, and
+/// adds an alias of each generated type for each of them, named by inserting
+/// the capitalized type after the type's own name, such as `PointF32Ulps` and
+/// `PointF64DebugUlpsDiff` for `Point<T>`.
+///
+/// For a newtype, the optional `rhs` parameter lists other types to compare it
+/// against, such as `"[f32; 3]"`, by comparing them with its single field.
 ///
 /// See [How to derive the traits] for more information and example usage.
 ///
@@ -81,15 +100,30 @@ help: try specifying `debug_ulps_diff = "{}DebugUlpsDiff"` in `derive_float_eq`.
         return Err(syn::Error::new(Span::call_site(), msg));
     }
 
-    let mut trait_names = vec![
-        "FloatEqUlpsTol",
-        "FloatEq",
-        "FloatEqDebugUlpsDiff",
-        "AssertFloatEq",
-    ];
+    if has_arg("precisions") {
+        generics::check_precisions(&item)?;
+    }
+
+    if has_arg("tol_builder") && cfg!(not(feature = "tol-builder")) {
+        let msg = r#"Deriving a tolerance builder requires the `derive-tol-builder` feature.
+
+help: try enabling the `derive-tol-builder` or `derive` feature of float_eq."#;
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    let derive_asserts = cfg!(all(feature = "assert", not(feature = "checks-only")));
+    let mut trait_names = vec!["FloatEqUlpsTol", "FloatEq", "FloatEqDebugUlpsDiff"];
+    if derive_asserts {
+        trait_names.push("AssertFloatEq");
+    }
     if has_arg("all_tol") {
         trait_names.push("FloatEqAll");
-        trait_names.push("AssertFloatEqAll");
+        if derive_asserts {
+            trait_names.push("AssertFloatEqAll");
+        }
+    }
+    if has_arg("tol_builder") {
+        trait_names.push("FloatEqTolBuilder");
     }
 
     let mut traits = TokenStream::new();
@@ -105,6 +139,43 @@ help: try specifying `debug_ulps_diff = "{}DebugUlpsDiff"` in `derive_float_eq`.
     })
 }
 
+/// Asserts that two floating point expressions are equal to each other, like
+/// `assert_float_eq!`, and displays the values of the operands of any
+/// arithmetic at the top level of either expression if they are not.
+///
+/// For example, if `assert_float_eq_captured!(f(x) * g(y), expected, ...)`
+/// fails then the values of `f(x)` and `g(y)` are displayed after those of the
+/// left and right expressions. Literal operands are not displayed. The
+/// operands must implement `Debug`, and are formatted every time the assert is
+/// evaluated, so it is intended for use in tests rather than in hot loops.
+///
+/// This requires float_eq's `capture` feature. See [Capturing operands] for
+/// an example.
+///
+/// [Capturing operands]: index.html#capturing-operands
+#[cfg(feature = "capture")]
+#[proc_macro]
+pub fn assert_float_eq_captured(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(input as capture::CaptureArgs);
+    capture::expand_capture(args, "assert_float_eq").into()
+}
+
+/// Asserts that two floating point expressions are not equal to each other,
+/// like `assert_float_ne!`, and displays the values of the operands of any
+/// arithmetic at the top level of either expression if they are.
+///
+/// See [`assert_float_eq_captured!`] for details.
+///
+/// This requires float_eq's `capture` feature.
+///
+/// [`assert_float_eq_captured!`]: macro.assert_float_eq_captured.html
+#[cfg(feature = "capture")]
+#[proc_macro]
+pub fn assert_float_ne_captured(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(input as capture::CaptureArgs);
+    capture::expand_capture(args, "assert_float_ne").into()
+}
+
 #[doc(hidden)]
 #[proc_macro_derive(FloatEqUlpsTol, attributes(float_eq))]
 pub fn derive_float_eq_ulps_tol(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -115,40 +186,35 @@ pub fn derive_float_eq_ulps_tol(input: proc_macro::TokenStream) -> proc_macro::T
 }
 
 fn expand_float_eq_ulps_tol(input: DeriveInput) -> Result<TokenStream, syn::Error> {
-    let vis = &input.vis;
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqUlpsTol", &input)?;
+    let variants = read::enum_variants_info("FloatEqUlpsTol", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
-
-    let ulps_type = match fields.ty {
-        read::FieldListType::Named => {
-            let ulps_fields = fields.expand(|field| {
-                let name = &field.name;
-                let ty = &field.ty;
-                quote! { #name: float_eq::UlpsTol<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name {
-                    #(#ulps_fields,)*
-                }
-            }
+    let bounds = generics::Bounds::new(&input).ulps_tol(&params);
+    let where_clause = bounds.where_clause();
+    let type_where_clause = bounds.generated_where_clause();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (ulps_params, ulps_generics) = generics::generated_params(&input);
+
+    let wrapper = quote! { float_eq::UlpsTol };
+    let ulps_type = match variants {
+        Some(variants) => enums::expand_type(
+            &input,
+            ulps_name,
+            (&ulps_params, &type_where_clause),
+            &variants,
+            wrapper,
+        ),
+        None => {
+            let fields = read::all_fields_info("FloatEqUlpsTol", &input)?;
+            let generics = (&ulps_params, &type_where_clause);
+            expand_ulps_struct(&input, ulps_name, generics, &fields, wrapper)
         }
-        read::FieldListType::Tuple => {
-            let ulps_fields = fields.expand(|field| {
-                let ty = &field.ty;
-                quote! { float_eq::UlpsTol<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name( #(#ulps_fields,)* );
-            }
-        }
-        read::FieldListType::Unit => quote! {
-            #vis struct #ulps_name;
-        },
     };
 
     let derive_types = params.ulps_tol_derive_types();
+    let attrs = params.ulps_tol_attrs();
+    let aliases = generics::precision_aliases(&input, &params, ulps_name, "Ulps")?;
 
     let doc = format!(
         "Floating point ULPs tolerance representation derived from {}, used by float_eq.",
@@ -157,66 +223,241 @@ fn expand_float_eq_ulps_tol(input: DeriveInput) -> Result<TokenStream, syn::Erro
     Ok(quote! {
         #[doc = #doc]
         #[derive(#(#derive_types,)*)]
+        #(#[#attrs])*
         #ulps_type
 
-        impl float_eq::FloatEqUlpsTol for #struct_name {
-            type UlpsTol = #ulps_name;
+        #aliases
+
+        impl #impl_generics float_eq::FloatEqUlpsTol for #struct_name #ty_generics #where_clause {
+            type UlpsTol = #ulps_name #ulps_generics;
         }
     })
 }
 
-#[doc(hidden)]
-#[proc_macro_derive(FloatEqDebugUlpsDiff, attributes(float_eq))]
-pub fn derive_float_eq_debug_ulps_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    expand_float_eq_debug_ulps_diff(input)
-        .unwrap_or_else(|e| e.to_compile_error())
-        .into()
-}
-
-fn expand_float_eq_debug_ulps_diff(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+fn expand_ulps_struct(
+    input: &DeriveInput,
+    ulps_name: &Ident,
+    (generics, where_clause): (&TokenStream, &TokenStream),
+    fields: &read::FieldInfoList,
+    wrapper: TokenStream,
+) -> TokenStream {
     let vis = &input.vis;
-    let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqDebugUlpsDiff", &input)?;
-    let params = read::float_eq_attr(&input)?;
-    let ulps_name = params.debug_ulps_diff()?;
-
-    let ulps_type = match fields.ty {
+    match fields.ty {
         read::FieldListType::Named => {
-            let ulps_fields = fields.expand(|field| {
-                let name = &field.name;
+            let ulps_fields = fields.expand_float(|field| {
+                let name = &field.debug_name;
                 let ty = &field.ty;
-                quote! { #name: float_eq::DebugUlpsDiff<#ty> }
+                quote! { #name: #wrapper<#ty> }
             });
             quote! {
-                #vis struct #ulps_name {
+                #vis struct #ulps_name #generics #where_clause {
                     #(#ulps_fields,)*
                 }
             }
         }
         read::FieldListType::Tuple => {
-            let ulps_fields = fields.expand(|field| {
+            let ulps_fields = fields.expand_float(|field| {
                 let ty = &field.ty;
-                quote! { float_eq::DebugUlpsDiff<#ty> }
+                quote! { #wrapper<#ty> }
             });
-            quote! {
-                #vis struct #ulps_name( #(#ulps_fields,)* );
+            if ulps_fields.is_empty() && !fields.is_empty() {
+                // every field is skipped or compared by a custom function
+                quote! {
+                    #vis struct #ulps_name #generics #where_clause;
+                }
+            } else {
+                quote! {
+                    #vis struct #ulps_name #generics ( #(#ulps_fields,)* ) #where_clause;
+                }
             }
         }
         read::FieldListType::Unit => quote! {
-            #vis struct #ulps_name;
+            #vis struct #ulps_name #generics #where_clause;
         },
+    }
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(FloatEqDebugUlpsDiff, attributes(float_eq))]
+pub fn derive_float_eq_debug_ulps_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_debug_ulps_diff(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_float_eq_debug_ulps_diff(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let variants = read::enum_variants_info("FloatEqDebugUlpsDiff", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let ulps_name = params.debug_ulps_diff()?;
+    let bounds = generics::Bounds::new(&input).debug_ulps_diff(&params);
+    let where_clause = bounds.where_clause();
+    let type_where_clause = bounds.generated_where_clause();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (ulps_params, ulps_generics) = generics::generated_params(&input);
+
+    let wrapper = quote! { float_eq::DebugUlpsDiff };
+    let ulps_type = match variants {
+        Some(variants) => enums::expand_type(
+            &input,
+            ulps_name,
+            (&ulps_params, &type_where_clause),
+            &variants,
+            wrapper,
+        ),
+        None => {
+            let fields = read::all_fields_info("FloatEqDebugUlpsDiff", &input)?;
+            let generics = (&ulps_params, &type_where_clause);
+            expand_ulps_struct(&input, ulps_name, generics, &fields, wrapper)
+        }
     };
 
     let derive_types = params.debug_ulps_diff_derive_types();
+    let attrs = params.debug_ulps_diff_attrs();
+    let aliases = generics::precision_aliases(&input, &params, ulps_name, "DebugUlpsDiff")?;
 
     Ok(quote! {
         #[doc(hidden)]
         #[derive(#(#derive_types,)*)]
+        #(#[#attrs])*
         #ulps_type
 
-        impl float_eq::FloatEqDebugUlpsDiff for #struct_name {
-            type DebugUlpsDiff = #ulps_name;
+        #aliases
+
+        impl #impl_generics float_eq::FloatEqDebugUlpsDiff for #struct_name #ty_generics #where_clause {
+            type DebugUlpsDiff = #ulps_name #ulps_generics;
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(UlpsTolOps)]
+pub fn derive_ulps_tol_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_ulps_tol_ops(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_ulps_tol_ops(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let fields = read::all_fields_info("UlpsTolOps", &input)?;
+    let where_clause = generics::Bounds::new(&input)
+        .fields(|ty| quote! { #ty: float_eq::UlpsTolOps })
+        .where_clause();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let saturating_add = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::UlpsTolOps::saturating_add(&self.#name, &other.#name) }
+    });
+    let max_elementwise = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::UlpsTolOps::max_elementwise(&self.#name, &other.#name) }
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::UlpsTolOps for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn saturating_add(&self, other: &Self) -> Self {
+                Self { #(#saturating_add,)* }
+            }
+
+            #[inline]
+            fn max_elementwise(&self, other: &Self) -> Self {
+                Self { #(#max_elementwise,)* }
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(TolOps)]
+pub fn derive_tol_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_tol_ops(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_tol_ops(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let fields = read::all_fields_info("TolOps", &input)?;
+    let where_clause = generics::Bounds::new(&input)
+        .fields(|ty| quote! { #ty: float_eq::TolOps })
+        .where_clause();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let max_elementwise = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::TolOps::max_elementwise(&self.#name, &other.#name) }
+    });
+    let min_elementwise = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::TolOps::min_elementwise(&self.#name, &other.#name) }
+    });
+    let scale = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::TolOps::scale(&self.#name, factor) }
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::TolOps for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn max_elementwise(&self, other: &Self) -> Self {
+                Self { #(#max_elementwise,)* }
+            }
+
+            #[inline]
+            fn min_elementwise(&self, other: &Self) -> Self {
+                Self { #(#min_elementwise,)* }
+            }
+
+            #[inline]
+            fn scale(&self, factor: f64) -> Self {
+                Self { #(#scale,)* }
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(WidenTol, attributes(float_eq))]
+pub fn derive_widen_tol(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_widen_tol(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_widen_tol(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let fields = read::all_fields_info("WidenTol", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let wide_name = params.wide_type()?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let widen = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::WidenTol::widen(&self.#name) }
+    });
+    let narrow = fields.expand(|field| {
+        let name = &field.name;
+        quote! { #name: float_eq::WidenTol::narrow(&wide.#name) }
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::WidenTol<#wide_name> for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn widen(&self) -> #wide_name {
+                #wide_name { #(#widen,)* }
+            }
+
+            #[inline]
+            fn narrow(wide: &#wide_name) -> Self {
+                Self { #(#narrow,)* }
+            }
         }
     })
 }
@@ -231,21 +472,40 @@ pub fn derive_float_eq_attribute(input: proc_macro::TokenStream) -> proc_macro::
 }
 
 fn expand_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    use quote::ToTokens;
+
+    let vis = &input.vis;
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEq", &input)?;
+    let variants = read::enum_variants_info("FloatEq", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
+    let rhs_impls = rhs::expand_float_eq(&input, &params)?;
+    let where_clause = generics::Bounds::new(&input)
+        .float_eq(&params)
+        .where_clause();
+    if let Some(variants) = variants {
+        return Ok(enums::expand_float_eq(
+            &input,
+            &where_clause,
+            &variants,
+            ulps_name,
+        ));
+    }
+    let (impl_generics, ty_generics, fields_where_clause) = input.generics.split_for_impl();
+    let (_, ulps_generics) = generics::generated_params(&input);
+    let fields = read::all_fields_info("FloatEq", &input)?;
 
     let expand_exprs = |method| {
-        let mut expanded = fields.expand(|field| {
+        fields.expand_checks(field_refs, |field| {
             let name = &field.name;
             let method = Ident::new(method, Span::call_site());
-            quote! { self.#name.#method(&other.#name, &tol.#name) }
-        });
-        if expanded.is_empty() {
-            expanded.push(quote! { true });
-        }
-        expanded
+            if method == "eq_ulps" {
+                let tol_name = &field.debug_name;
+                quote! { self.#name.#method(&other.#name, &tol.#tol_name) }
+            } else {
+                quote! { self.#name.#method(&other.#name, &tol.#name) }
+            }
+        })
     };
 
     let eq_abs = expand_exprs("eq_abs");
@@ -254,10 +514,27 @@ fn expand_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let eq_r1st = expand_exprs("eq_r1st");
     let eq_r2nd = expand_exprs("eq_r2nd");
     let eq_ulps = expand_exprs("eq_ulps");
+    let field_checks = fields.expand_float(|field| {
+        let ty = &field.ty;
+        quote! { .intersection(<#ty as float_eq::FloatEq>::SUPPORTED_CHECKS) }
+    });
+
+    let field_names = fields.expand_compared(|field| {
+        let name = field.name.to_token_stream().to_string();
+        let name = name.trim_start_matches("r#");
+        quote! { #name }
+    });
 
     Ok(quote! {
-        impl float_eq::FloatEq for #struct_name {
+        impl #impl_generics #struct_name #ty_generics #fields_where_clause {
+            /// The names of the fields that are compared, in declaration
+            /// order, or their indices for a tuple struct.
+            #vis const FIELDS: &'static [&'static str] = &[#(#field_names),*];
+        }
+
+        impl #impl_generics float_eq::FloatEq for #struct_name #ty_generics #where_clause {
             type Tol = Self;
+            const SUPPORTED_CHECKS: float_eq::CheckSet = float_eq::CheckSet::ALL #(#field_checks)*;
 
             #[inline]
             fn eq_abs(&self, other: &Self, tol: &Self) -> bool {
@@ -285,14 +562,286 @@ fn expand_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
             }
 
             #[inline]
-            fn eq_ulps(&self, other: &Self, tol: &#ulps_name) -> bool {
+            fn eq_ulps(&self, other: &Self, tol: &#ulps_name #ulps_generics) -> bool {
                 #(#eq_ulps)&&*
             }
         }
+
+        #rhs_impls
     })
 }
 
 #[doc(hidden)]
+#[cfg(feature = "bits")]
+#[proc_macro_derive(FloatEqBits, attributes(float_eq))]
+pub fn derive_float_eq_bits(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_bits(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "bits")]
+fn expand_float_eq_bits(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let where_clause = generics::Bounds::new(&input)
+        .fields(|ty| quote! { #ty: float_eq::FloatEqBits })
+        .where_clause();
+    if let Some(variants) = read::enum_variants_info("FloatEqBits", &input)? {
+        return Ok(enums::expand_float_eq_bits(
+            &input,
+            &where_clause,
+            &variants,
+        ));
+    }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let fields = read::all_fields_info("FloatEqBits", &input)?;
+
+    let eq_bits = fields.expand_checks(field_refs, |field| {
+        let name = &field.name;
+        quote! { self.#name.eq_bits(&other.#name) }
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::FloatEqBits for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn eq_bits(&self, other: &Self) -> bool {
+                #(#eq_bits)&&*
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[cfg(feature = "weighted")]
+#[proc_macro_derive(FloatEqWeighted, attributes(float_eq))]
+pub fn derive_float_eq_weighted(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_weighted(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "weighted")]
+fn expand_float_eq_weighted(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let params = read::float_eq_attr(&input)?;
+    let where_clause = generics::Bounds::new(&input)
+        .float_eq(&params)
+        .fields(|ty| quote! { #ty: float_eq::FloatEqWeighted })
+        .where_clause();
+    if let Some(variants) = read::enum_variants_info("FloatEqWeighted", &input)? {
+        return Ok(enums::expand_float_eq_weighted(
+            &input,
+            &where_clause,
+            &variants,
+        ));
+    }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let fields = read::all_fields_info("FloatEqWeighted", &input)?;
+
+    let errors = fields.expand_compared(|field| {
+        let name = &field.name;
+        let (lhs, rhs) = field_refs(field);
+        match field.custom_eq(lhs, rhs) {
+            Some(eq) => quote! { if #eq { 0.0 } else { ::core::f64::INFINITY } },
+            None => {
+                quote! { self.#name.weighted_error(&other.#name, &tol.#name, &weights.#name) }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::FloatEqWeighted for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn weighted_error(&self, other: &Self, tol: &Self, weights: &Self) -> f64 {
+                0.0 #(+ #errors)*
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[cfg(feature = "tol-builder")]
+#[proc_macro_derive(FloatEqTolBuilder, attributes(float_eq))]
+pub fn derive_float_eq_tol_builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_tol_builder(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "tol-builder")]
+fn expand_float_eq_tol_builder(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    use quote::ToTokens;
+
+    let vis = &input.vis;
+    let struct_name = &input.ident;
+    let fields = read::all_fields_info("FloatEqTolBuilder", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let builder_name = params.tol_builder_type()?;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if let read::FieldListType::Tuple = fields.ty {
+        return Err(syn::Error::new(
+            struct_name.span(),
+            "FloatEqTolBuilder may only be derived for structs with named fields.",
+        ));
+    }
+
+    let mut builder_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        quote! { #name: ::core::option::Option<#ty> }
+    });
+    let mut empty_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        quote! { #name: ::core::option::Option::None }
+    });
+    if !generics::unused_params(&input).is_empty() {
+        // parameters used only by fields without a tolerance must still be used
+        builder_fields.push(quote! {
+            _marker: ::core::marker::PhantomData<fn() -> #struct_name #ty_generics>
+        });
+        empty_fields.push(quote! { _marker: ::core::marker::PhantomData });
+    }
+    let setters = fields.expand_float(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        let doc = format!(
+            "Set the tolerance of the `{}` field.",
+            name.to_token_stream()
+        );
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #name(mut self, tol: #ty) -> Self {
+                self.#name = ::core::option::Option::Some(tol);
+                self
+            }
+        }
+    });
+    let rest_bounds = fields.expand_float(|field| {
+        let ty = &field.ty;
+        quote! { #ty: ::core::convert::From<RestTol> }
+    });
+    let rest_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        quote! {
+            if self.#name.is_none() {
+                self.#name = ::core::option::Option::Some(::core::convert::From::from(tol.clone()));
+            }
+        }
+    });
+    let preset_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        quote! {
+            if self.#name.is_none() {
+                self.#name = ::core::option::Option::Some(preset.#name);
+            }
+        }
+    });
+    let built_fields = fields.expand(|field| {
+        let name = &field.name;
+        if !field.is_float() {
+            return quote! { #name: ::core::default::Default::default() };
+        }
+        let msg = format!(
+            "{} is missing a tolerance for field `{}`",
+            builder_name,
+            name.to_token_stream()
+        );
+        quote! { #name: self.#name.expect(#msg) }
+    });
+    let default_bounds = fields.expand(|field| {
+        let ty = &field.ty;
+        if field.is_float() {
+            TokenStream::new()
+        } else {
+            quote! { #ty: ::core::default::Default, }
+        }
+    });
+
+    let doc = format!(
+        "Fluent builder for {} tolerances, generated by float_eq.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Debug)]
+        #vis struct #builder_name #generics #where_clause {
+            #(#builder_fields,)*
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            /// Create a builder with no field tolerances set.
+            #[inline]
+            #vis fn new() -> Self {
+                #builder_name {
+                    #(#empty_fields,)*
+                }
+            }
+
+            #(#setters)*
+
+            /// Set the tolerance of every field that has not yet been set, by
+            /// converting `tol` into the type of each field.
+            #[inline]
+            #vis fn rest<RestTol: ::core::clone::Clone>(mut self, tol: RestTol) -> Self
+            where
+                #(#rest_bounds,)*
+            {
+                #(#rest_fields)*
+                self
+            }
+
+            /// Set the tolerance of every field that has not yet been set to
+            /// that of the same field in `preset`.
+            #[inline]
+            #vis fn rest_from(mut self, preset: #struct_name #ty_generics) -> Self {
+                #(#preset_fields)*
+                self
+            }
+
+            /// Build the tolerance.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the tolerance of any field has not been set. Fields
+            /// that are not compared with a tolerance are set to their default
+            /// values.
+            #[inline]
+            #[track_caller]
+            #vis fn build(self) -> #struct_name #ty_generics
+            where
+                #(#default_bounds)*
+            {
+                #struct_name {
+                    #(#built_fields,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::default::Default for #builder_name #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Start building a tolerance for this type one field at a time.
+            #[inline]
+            #vis fn tol_builder() -> #builder_name #ty_generics {
+                #builder_name::new()
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[cfg(feature = "assert")]
 #[proc_macro_derive(AssertFloatEq, attributes(float_eq))]
 pub fn derive_assert_float_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -301,29 +850,69 @@ pub fn derive_assert_float_eq(input: proc_macro::TokenStream) -> proc_macro::Tok
         .into()
 }
 
+#[cfg(feature = "assert")]
 fn expand_assert_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("AssertFloatEq", &input)?;
+    let variants = read::enum_variants_info("AssertFloatEq", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
     let diff_name = params.debug_ulps_diff()?;
-
-    let expand_diff_fields = |method| {
-        fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name) }
+    let rhs_impls = rhs::expand_assert_float_eq(&input, &params)?;
+    let bounds = generics::Bounds::new(&input)
+        .float_eq(&params)
+        .debug_ulps_diff(&params)
+        .fields(|ty| {
+            quote! { #ty: float_eq::AssertFloatEq<DebugAbsDiff = #ty, DebugTol = #ty> }
         })
+        .other_fields(|ty| quote! { #ty: ::core::clone::Clone })
+        .debug();
+    let where_clause = bounds.where_clause();
+    if let Some(variants) = variants {
+        return Ok(enums::expand_assert_float_eq(
+            &input,
+            &where_clause,
+            &variants,
+            ulps_name,
+            diff_name,
+        ));
+    }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, ulps_generics) = generics::generated_params(&input);
+    let fields = read::all_fields_info("AssertFloatEq", &input)?;
+
+    let diff_fields = if cfg!(feature = "std") {
+        let diff_where_clause = bounds
+            .fields(|ty| quote! { #ty: ::core::fmt::Debug })
+            .fields(|ty| quote! { float_eq::DebugUlpsDiff<#ty>: ::core::fmt::Debug })
+            .where_clause();
+        expand_field_diffs(&input, &diff_where_clause, &fields)
+    } else {
+        TokenStream::new()
     };
 
-    let abs_diff_fields = expand_diff_fields("debug_abs_diff");
-    let ulps_diff_fields = expand_diff_fields("debug_ulps_diff");
+    let abs_diff_fields = fields.expand(|field| {
+        let name = &field.name;
+        if field.is_float() {
+            quote! { #name: self.#name.debug_abs_diff(&other.#name) }
+        } else {
+            cloned_field(field)
+        }
+    });
+    let ulps_diff_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        let diff_name = &field.debug_name;
+        quote! { #diff_name: self.#name.debug_ulps_diff(&other.#name) }
+    });
 
     let expand_eps_fields = |method| {
         fields.expand(|field| {
             let name = &field.name;
             let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name, &tol.#name) }
+            if field.is_float() {
+                quote! { #name: self.#name.#method(&other.#name, &tol.#name) }
+            } else {
+                cloned_field(field)
+            }
         })
     };
 
@@ -332,10 +921,16 @@ fn expand_assert_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error>
     let rmin_eps_fields = expand_eps_fields("debug_rmin_tol");
     let r1st_eps_fields = expand_eps_fields("debug_r1st_tol");
     let r2nd_eps_fields = expand_eps_fields("debug_r2nd_tol");
-    let ulps_eps_fields = expand_eps_fields("debug_ulps_tol");
+    let ulps_eps_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        let tol_name = &field.debug_name;
+        quote! { #tol_name: self.#name.debug_ulps_tol(&other.#name, &tol.#tol_name) }
+    });
 
     Ok(quote! {
-        impl float_eq::AssertFloatEq for #struct_name {
+        #diff_fields
+
+        impl #impl_generics float_eq::AssertFloatEq for #struct_name #ty_generics #where_clause {
             type DebugAbsDiff = Self;
             type DebugTol = Self;
 
@@ -347,7 +942,7 @@ fn expand_assert_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error>
             }
 
             #[inline]
-            fn debug_ulps_diff(&self, other: &Self) -> #diff_name {
+            fn debug_ulps_diff(&self, other: &Self) -> #diff_name #ulps_generics {
                 #diff_name {
                     #(#ulps_diff_fields,)*
                 }
@@ -389,15 +984,68 @@ fn expand_assert_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error>
             }
 
             #[inline]
-            fn debug_ulps_tol(&self, other: &Self, tol: &#ulps_name) -> #ulps_name {
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &#ulps_name #ulps_generics,
+            ) -> #ulps_name #ulps_generics {
                 #ulps_name {
                     #(#ulps_eps_fields,)*
                 }
             }
         }
+
+        #rhs_impls
     })
 }
 
+#[cfg(feature = "assert")]
+fn expand_field_diffs(
+    input: &DeriveInput,
+    where_clause: &TokenStream,
+    fields: &read::FieldInfoList,
+) -> TokenStream {
+    use quote::ToTokens;
+
+    let vis = &input.vis;
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let field_diffs = fields.expand_float(|field| {
+        let name = &field.name;
+        let field_name = name.to_token_stream().to_string();
+        let field_name = field_name.trim_start_matches("r#");
+        quote! {
+            if !float_eq::FloatEq::eq_abs(&self.#name, &other.#name, &tol.#name) {
+                diffs.push(float_eq::FieldDiff::new(
+                    #field_name,
+                    &self.#name,
+                    &other.#name,
+                    &float_eq::AssertFloatEq::debug_abs_diff(&self.#name, &other.#name),
+                    &float_eq::AssertFloatEq::debug_ulps_diff(&self.#name, &other.#name),
+                ));
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// The fields that are not equal to those of `other` using an
+            /// absolute tolerance comparison, in declaration order.
+            #[allow(unused_variables)]
+            #vis fn diff_fields(
+                &self,
+                other: &Self,
+                tol: &Self,
+            ) -> ::std::vec::Vec<float_eq::FieldDiff> {
+                #[allow(unused_mut)]
+                let mut diffs = ::std::vec::Vec::new();
+                #(#field_diffs)*
+                diffs
+            }
+        }
+    }
+}
+
 #[doc(hidden)]
 #[proc_macro_derive(FloatEqAll, attributes(float_eq))]
 pub fn derive_float_eq_all(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -409,20 +1057,30 @@ pub fn derive_float_eq_all(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
 fn expand_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqAll", &input)?;
+    let variants = read::enum_variants_info("FloatEqAll", &input)?;
     let params = read::float_eq_attr(&input)?;
     let all_tol = params.all_tol_type()?;
+    let rhs_impls = rhs::expand_float_eq_all(&input, &params)?;
+    let where_clause = generics::Bounds::new(&input)
+        .float_eq_all(all_tol)
+        .where_clause();
+    if let Some(variants) = variants {
+        return Ok(enums::expand_float_eq_all(
+            &input,
+            &where_clause,
+            &variants,
+            all_tol,
+        ));
+    }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let fields = read::all_fields_info("FloatEqAll", &input)?;
 
     let expand_exprs = |method| {
-        let mut expanded = fields.expand(|field| {
+        fields.expand_checks(field_refs, |field| {
             let name = &field.name;
             let method = Ident::new(method, Span::call_site());
             quote! { self.#name.#method(&other.#name, tol) }
-        });
-        if expanded.is_empty() {
-            expanded.push(quote! { true });
-        }
-        expanded
+        })
     };
 
     let eq_abs = expand_exprs("eq_abs_all");
@@ -433,7 +1091,7 @@ fn expand_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let eq_ulps = expand_exprs("eq_ulps_all");
 
     Ok(quote! {
-        impl float_eq::FloatEqAll for #struct_name {
+        impl #impl_generics float_eq::FloatEqAll for #struct_name #ty_generics #where_clause {
             type AllTol = #all_tol;
 
             #[inline]
@@ -466,10 +1124,13 @@ fn expand_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
                 #(#eq_ulps)&&*
             }
         }
+
+        #rhs_impls
     })
 }
 
 #[doc(hidden)]
+#[cfg(feature = "assert")]
 #[proc_macro_derive(AssertFloatEqAll, attributes(float_eq))]
 pub fn derive_assert_float_eq_all(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -478,17 +1139,42 @@ pub fn derive_assert_float_eq_all(input: proc_macro::TokenStream) -> proc_macro:
         .into()
 }
 
+#[cfg(feature = "assert")]
 fn expand_assert_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("AssertFloatEqAll", &input)?;
+    let variants = read::enum_variants_info("AssertFloatEqAll", &input)?;
     let params = read::float_eq_attr(&input)?;
     let all_tol = params.all_tol_type()?;
+    let rhs_impls = rhs::expand_assert_float_eq_all(&input, &params)?;
+    let where_clause = generics::Bounds::new(&input)
+        .float_eq_all(all_tol)
+        .ulps_tol(&params)
+        .fields(|ty| quote! { #ty: float_eq::AssertFloatEqAll<AllDebugTol = #ty> })
+        .other_fields(|ty| quote! { #ty: ::core::clone::Clone })
+        .debug()
+        .where_clause();
+    if let Some(variants) = variants {
+        let ulps_name = params.ulps_tol_type()?;
+        return Ok(enums::expand_assert_float_eq_all(
+            &input,
+            &where_clause,
+            &variants,
+            ulps_name,
+            all_tol,
+        ));
+    }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let fields = read::all_fields_info("AssertFloatEqAll", &input)?;
 
     let expand_fields = |method| {
         fields.expand(|field| {
             let name = &field.name;
             let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name, tol) }
+            if field.is_float() {
+                quote! { #name: self.#name.#method(&other.#name, tol) }
+            } else {
+                cloned_field(field)
+            }
         })
     };
 
@@ -497,10 +1183,14 @@ fn expand_assert_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Er
     let rmin_eps_fields = expand_fields("debug_rmin_all_tol");
     let r1st_eps_fields = expand_fields("debug_r1st_all_tol");
     let r2nd_eps_fields = expand_fields("debug_r2nd_all_tol");
-    let ulps_eps_fields = expand_fields("debug_ulps_all_tol");
+    let ulps_eps_fields = fields.expand_float(|field| {
+        let name = &field.name;
+        let tol_name = &field.debug_name;
+        quote! { #tol_name: self.#name.debug_ulps_all_tol(&other.#name, tol) }
+    });
 
     Ok(quote! {
-        impl float_eq::AssertFloatEqAll for #struct_name {
+        impl #impl_generics float_eq::AssertFloatEqAll for #struct_name #ty_generics #where_clause {
             type AllDebugTol = Self;
 
             #[inline]
@@ -549,5 +1239,22 @@ fn expand_assert_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Er
                 }
             }
         }
+
+        #rhs_impls
     })
 }
+
+/// References to a field of a struct in `self` and `other`, for comparing it
+/// with a custom function.
+fn field_refs(field: &read::FieldInfo) -> (TokenStream, TokenStream) {
+    let name = &field.name;
+    (quote! { &self.#name }, quote! { &other.#name })
+}
+
+/// A field of a debug value that is not compared with a tolerance, which is
+/// given the value of the field in `self`.
+#[cfg(feature = "assert")]
+fn cloned_field(field: &read::FieldInfo) -> TokenStream {
+    let name = &field.name;
+    quote! { #name: ::core::clone::Clone::clone(&self.#name) }
+}
@@ -1,12 +1,13 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::{
-    spanned::Spanned, Attribute, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Lit,
-    LitInt, LitStr, Meta, NestedMeta, Type,
+    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, DeriveInput, Fields,
+    FieldsNamed, FieldsUnnamed, Lit, LitInt, LitStr, Meta, NestedMeta, Path, Type,
 };
 
 pub enum FieldName<'a> {
     Ident(&'a Ident),
+    Renamed(Ident),
     Num(Lit),
 }
 
@@ -14,14 +15,60 @@ impl ToTokens for FieldName<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             FieldName::Ident(ident) => ident.to_tokens(tokens),
+            FieldName::Renamed(ident) => ident.to_tokens(tokens),
             FieldName::Num(num) => num.to_tokens(tokens),
         }
     }
 }
 
+/// How a field is compared.
+pub enum FieldCmp {
+    /// Compared using the float_eq traits, with a tolerance.
+    Float,
+    /// Not compared, via `#[float_eq(skip)]`.
+    Skip,
+    /// Compared by a function of two references to the field that returns
+    /// whether they are equal, via `#[float_eq(cmp = "...")]`.
+    Custom(Path),
+}
+
 pub struct FieldInfo<'a> {
     pub name: FieldName<'a>,
+    /// Name of the field in the generated ULPs tolerance and debug ULPs diff
+    /// types, which may differ from `name` via `#[float_eq(rename = "...")]`.
+    /// Fields of tuple structs are renumbered, since only those compared using
+    /// the float_eq traits are included in the generated types.
+    pub debug_name: FieldName<'a>,
     pub ty: &'a Type,
+    pub cmp: FieldCmp,
+}
+
+impl FieldInfo<'_> {
+    /// Whether this field is compared using the float_eq traits, and so has a
+    /// tolerance and appears in the generated types.
+    pub fn is_float(&self) -> bool {
+        matches!(self.cmp, FieldCmp::Float)
+    }
+
+    /// The check of this field given expressions referencing it in each value,
+    /// if it is not compared using the float_eq traits.
+    pub fn custom_eq(&self, lhs: TokenStream, rhs: TokenStream) -> Option<TokenStream> {
+        match &self.cmp {
+            FieldCmp::Float => None,
+            FieldCmp::Skip => Some(quote! { true }),
+            FieldCmp::Custom(path) => Some(quote! { #path(#lhs, #rhs) }),
+        }
+    }
+
+    /// A variable to bind this field of a variant to when matching on it,
+    /// such as `self_x` for the `x` field of `self`.
+    pub fn binding(&self, prefix: &str) -> Ident {
+        let name = self.name.to_token_stream().to_string();
+        Ident::new(
+            &format!("{}_{}", prefix, name.trim_start_matches("r#")),
+            Span::call_site(),
+        )
+    }
 }
 
 pub enum FieldListType {
@@ -36,37 +83,77 @@ pub struct FieldInfoList<'a> {
 }
 
 impl FieldInfoList<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
     pub fn expand<F: std::ops::Fn(&FieldInfo) -> TokenStream>(&self, func: F) -> Vec<TokenStream> {
         self.fields.iter().map(func).collect()
     }
+
+    /// Expand only those fields compared using the float_eq traits.
+    pub fn expand_float<F: std::ops::Fn(&FieldInfo) -> TokenStream>(
+        &self,
+        func: F,
+    ) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .filter(|f| f.is_float())
+            .map(func)
+            .collect()
+    }
+
+    /// Expand only those fields that are compared, which excludes any that are
+    /// skipped.
+    pub fn expand_compared<F: std::ops::Fn(&FieldInfo) -> TokenStream>(
+        &self,
+        func: F,
+    ) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .filter(|f| !matches!(f.cmp, FieldCmp::Skip))
+            .map(func)
+            .collect()
+    }
+
+    /// The only field that is compared, if it is compared using the float_eq
+    /// traits, such as that of a newtype.
+    pub fn newtype_field(&self) -> Option<&FieldInfo<'_>> {
+        let mut compared = self
+            .fields
+            .iter()
+            .filter(|f| !matches!(f.cmp, FieldCmp::Skip));
+        match (compared.next(), compared.next()) {
+            (Some(field), None) if field.is_float() => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Expand the check of each field that is compared, using `func` for those
+    /// compared using the float_eq traits. Custom comparisons are given `refs`,
+    /// the expressions referencing the field in each value.
+    pub fn expand_checks<R, F>(&self, refs: R, func: F) -> Vec<TokenStream>
+    where
+        R: std::ops::Fn(&FieldInfo) -> (TokenStream, TokenStream),
+        F: std::ops::Fn(&FieldInfo) -> TokenStream,
+    {
+        let mut checks = self.expand_compared(|field| {
+            let (lhs, rhs) = refs(field);
+            field.custom_eq(lhs, rhs).unwrap_or_else(|| func(field))
+        });
+        if checks.is_empty() {
+            checks.push(quote! { true });
+        }
+        checks
+    }
 }
 
 pub fn all_fields_info<'a>(
     trait_name: &str,
     input: &'a DeriveInput,
 ) -> Result<FieldInfoList<'a>, syn::Error> {
-    if !input.generics.params.is_empty() {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "This trait does not yet support derive for generic types.",
-        ));
-    }
-
     match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(FieldsNamed { named, .. }) => Ok(FieldInfoList {
-                ty: FieldListType::Named,
-                fields: named.iter().map(named_field_info).collect(),
-            }),
-            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => Ok(FieldInfoList {
-                ty: FieldListType::Tuple,
-                fields: unnamed.iter().enumerate().map(unnamed_field_info).collect(),
-            }),
-            Fields::Unit => Ok(FieldInfoList {
-                ty: FieldListType::Unit,
-                fields: Vec::new(),
-            }),
-        },
+        Data::Struct(data) => fields_info(&data.fields),
         _ => Err(syn::Error::new(
             input.ident.span(),
             format!("{} may only be derived for structs.", trait_name),
@@ -74,28 +161,200 @@ pub fn all_fields_info<'a>(
     }
 }
 
-fn named_field_info(field: &syn::Field) -> FieldInfo {
-    FieldInfo {
-        name: FieldName::Ident(field.ident.as_ref().expect("Expected named field")),
-        ty: &field.ty,
+pub struct VariantInfo<'a> {
+    pub name: &'a Ident,
+    pub fields: FieldInfoList<'a>,
+}
+
+/// The variants of an enum, or `None` if the type is a struct, for traits that
+/// may be derived for both.
+pub fn enum_variants_info<'a>(
+    trait_name: &str,
+    input: &'a DeriveInput,
+) -> Result<Option<Vec<VariantInfo<'a>>>, syn::Error> {
+    match &input.data {
+        Data::Struct(_) => Ok(None),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                Ok(VariantInfo {
+                    name: &variant.ident,
+                    fields: fields_info(&variant.fields)?,
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Some),
+        Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            format!("{} may only be derived for structs and enums.", trait_name),
+        )),
     }
 }
 
-fn unnamed_field_info((n, field): (usize, &syn::Field)) -> FieldInfo {
-    FieldInfo {
-        name: FieldName::Num(Lit::Int(LitInt::new(&format!("{}", n), Span::call_site()))),
+fn fields_info(fields: &Fields) -> Result<FieldInfoList<'_>, syn::Error> {
+    match fields {
+        Fields::Named(FieldsNamed { named, .. }) => Ok(FieldInfoList {
+            ty: FieldListType::Named,
+            fields: named
+                .iter()
+                .map(named_field_info)
+                .collect::<Result<_, _>>()?,
+        }),
+        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+            let mut debug_n = 0;
+            Ok(FieldInfoList {
+                ty: FieldListType::Tuple,
+                fields: unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|field| unnamed_field_info(field, &mut debug_n))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+        Fields::Unit => Ok(FieldInfoList {
+            ty: FieldListType::Unit,
+            fields: Vec::new(),
+        }),
+    }
+}
+
+fn named_field_info(field: &syn::Field) -> Result<FieldInfo<'_>, syn::Error> {
+    let ident = field.ident.as_ref().expect("Expected named field");
+    let attr = field_float_eq_attr(field, ident)?;
+    let debug_name = match attr.rename {
+        Some(rename) => FieldName::Renamed(rename),
+        None => FieldName::Ident(ident),
+    };
+    Ok(FieldInfo {
+        name: FieldName::Ident(ident),
+        debug_name,
+        ty: &field.ty,
+        cmp: attr.cmp,
+    })
+}
+
+fn unnamed_field_info<'a>(
+    (n, field): (usize, &'a syn::Field),
+    debug_n: &mut usize,
+) -> Result<FieldInfo<'a>, syn::Error> {
+    let num =
+        |n: usize| FieldName::Num(Lit::Int(LitInt::new(&format!("{}", n), Span::call_site())));
+    let ident = Ident::new(&format!("field_{}", n), Span::call_site());
+    let attr = field_float_eq_attr(field, &ident)?;
+    if let Some(rename) = attr.rename {
+        return Err(syn::Error::new(
+            rename.span(),
+            "Only named fields may be renamed.",
+        ));
+    }
+    let debug_name = num(*debug_n);
+    if let FieldCmp::Float = attr.cmp {
+        *debug_n += 1;
+    }
+    Ok(FieldInfo {
+        name: num(n),
+        debug_name,
         ty: &field.ty,
+        cmp: attr.cmp,
+    })
+}
+
+/// Whether a field is compared using the float_eq traits, for use where any
+/// errors in its attributes are reported elsewhere.
+pub fn is_float_field(field: &syn::Field) -> bool {
+    let ident = Ident::new("field", Span::call_site());
+    field_float_eq_attr(field, &ident).map_or(true, |attr| matches!(attr.cmp, FieldCmp::Float))
+}
+
+struct FieldFloatEqAttr {
+    rename: Option<Ident>,
+    cmp: FieldCmp,
+}
+
+fn field_float_eq_attr(
+    field: &syn::Field,
+    field_name: &Ident,
+) -> Result<FieldFloatEqAttr, syn::Error> {
+    let mut rename = None;
+    let mut skip: Option<Ident> = None;
+    let mut cmp = None;
+
+    for attr in field.attrs.iter().filter(|a| a.path.is_ident("float_eq")) {
+        let options = if let Meta::List(list) = attr.parse_meta()? {
+            list.nested
+        } else {
+            let msg = format!(
+                r#"float_eq attribute must be a list of options, for example `#[float_eq(rename = "{}")]`"#,
+                field_name
+            );
+            return Err(syn::Error::new(attr.path.span(), msg));
+        };
+
+        for option in options.iter() {
+            if let NestedMeta::Meta(Meta::Path(path)) = option {
+                match path.get_ident() {
+                    Some(ident) if ident == "skip" => {
+                        if skip.is_some() {
+                            let msg = "Duplicate `skip` argument";
+                            return Err(syn::Error::new(ident.span(), msg));
+                        }
+                        skip = Some(ident.clone());
+                        continue;
+                    }
+                    _ => {
+                        let msg = format!(
+                            r"'{}' is not a valid float_eq field option.",
+                            path.to_token_stream()
+                        );
+                        return Err(syn::Error::new(path.span(), msg));
+                    }
+                }
+            }
+
+            let nv = name_value_pair(option)?;
+            if nv.name == "rename" {
+                set_float_eq_attr(&mut rename, &nv, &parse_ident)?;
+            } else if nv.name == "cmp" {
+                set_float_eq_attr(&mut cmp, &nv, &parse_path)?;
+            } else {
+                let msg = format!(r"'{}' is not a valid float_eq field option.", nv.name);
+                return Err(syn::Error::new(nv.name.span(), msg));
+            }
+        }
     }
+
+    let cmp = match (skip, cmp) {
+        (Some(skip), Some(_)) => {
+            let msg = "A field may not be both skipped and compared with `cmp`.";
+            return Err(syn::Error::new(skip.span(), msg));
+        }
+        (Some(_), None) => FieldCmp::Skip,
+        (None, Some(path)) => FieldCmp::Custom(path),
+        (None, None) => FieldCmp::Float,
+    };
+    if let (Some(rename), false) = (&rename, matches!(cmp, FieldCmp::Float)) {
+        let msg = "Only fields compared using the float_eq traits may be renamed.";
+        return Err(syn::Error::new(rename.span(), msg));
+    }
+
+    Ok(FieldFloatEqAttr { rename, cmp })
 }
 
 #[derive(Default)]
 pub struct FloatEqAttr {
     struct_name: String,
     ulps_tol_type_name: Option<Ident>,
-    ulps_tol_derive_types: Option<Vec<Ident>>,
+    ulps_tol_derive_types: Option<Vec<Path>>,
+    ulps_tol_attrs: Option<Vec<Meta>>,
     debug_ulps_diff_type_name: Option<Ident>,
-    debug_ulps_diff_derive_types: Option<Vec<Ident>>,
+    debug_ulps_diff_derive_types: Option<Vec<Path>>,
+    debug_ulps_diff_attrs: Option<Vec<Meta>>,
     all_tol_type_name: Option<Ident>,
+    tol_builder_type_name: Option<Ident>,
+    wide_type_name: Option<Path>,
+    precision_types: Option<Vec<Path>>,
+    rhs_types: Option<Vec<Type>>,
 }
 
 impl FloatEqAttr {
@@ -111,16 +370,22 @@ help: try adding `#[float_eq(ulps_tol = "{}Ulps")]` to your type."#,
         })
     }
 
-    pub fn ulps_tol_derive_types(&self) -> Vec<Ident> {
-        self.ulps_tol_derive_types
-            .as_ref()
-            .map_or_else(Vec::new, |v| v.clone())
+    pub fn ulps_tol_derive_types(&self) -> &[Path] {
+        self.ulps_tol_derive_types.as_deref().unwrap_or_default()
     }
 
-    pub fn debug_ulps_diff_derive_types(&self) -> Vec<Ident> {
+    pub fn ulps_tol_attrs(&self) -> &[Meta] {
+        self.ulps_tol_attrs.as_deref().unwrap_or_default()
+    }
+
+    pub fn debug_ulps_diff_derive_types(&self) -> &[Path] {
         self.debug_ulps_diff_derive_types
-            .as_ref()
-            .map_or_else(Vec::new, |v| v.clone())
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    pub fn debug_ulps_diff_attrs(&self) -> &[Meta] {
+        self.debug_ulps_diff_attrs.as_deref().unwrap_or_default()
     }
 
     pub fn debug_ulps_diff(&self) -> Result<&Ident, syn::Error> {
@@ -143,6 +408,39 @@ help: try adding `#[float_eq(all_tol = "T")]` to your type, where T is commonly
             syn::Error::new(Span::call_site(), msg)
         })
     }
+
+    pub fn precisions(&self) -> &[Path] {
+        self.precision_types.as_deref().unwrap_or_default()
+    }
+
+    pub fn rhs_types(&self) -> &[Type] {
+        self.rhs_types.as_deref().unwrap_or_default()
+    }
+
+    pub fn wide_type(&self) -> Result<&Path, syn::Error> {
+        self.wide_type_name.as_ref().ok_or({
+            let msg = format!(
+                r#"Missing wide type name required to derive trait.
+
+help: try adding `#[float_eq(wide = "{}64")]` to your type, naming the same type in a wider precision."#,
+                self.struct_name.trim_end_matches("32")
+            );
+            syn::Error::new(Span::call_site(), msg)
+        })
+    }
+
+    #[cfg(feature = "tol-builder")]
+    pub fn tol_builder_type(&self) -> Result<&Ident, syn::Error> {
+        self.tol_builder_type_name.as_ref().ok_or({
+            let msg = format!(
+                r#"Missing tolerance builder type name required to derive trait.
+
+help: try adding `#[float_eq(tol_builder = "{}TolBuilder")]` to your type."#,
+                self.struct_name
+            );
+            syn::Error::new(Span::call_site(), msg)
+        })
+    }
 }
 
 pub fn float_eq_attr(input: &DeriveInput) -> Result<FloatEqAttr, syn::Error> {
@@ -170,17 +468,33 @@ pub fn float_eq_attr(input: &DeriveInput) -> Result<FloatEqAttr, syn::Error> {
             )?;
         } else if name == "all_tol" {
             set_float_eq_attr(&mut attr_values.all_tol_type_name, &nv, &parse_ident)?;
+        } else if name == "tol_builder" {
+            set_float_eq_attr(&mut attr_values.tol_builder_type_name, &nv, &parse_ident)?;
+        } else if name == "precisions" {
+            set_float_eq_attr(&mut attr_values.precision_types, &nv, &parse_path_list)?;
+        } else if name == "rhs" {
+            set_float_eq_attr(&mut attr_values.rhs_types, &nv, &parse_type_list)?;
+        } else if name == "wide" {
+            set_float_eq_attr(&mut attr_values.wide_type_name, &nv, &parse_path)?;
         } else if name == "ulps_tol_derive" {
             set_float_eq_attr(
                 &mut attr_values.ulps_tol_derive_types,
                 &nv,
-                &parse_ident_list,
+                &parse_path_list,
             )?;
+        } else if name == "ulps_tol_attr" {
+            set_float_eq_attr(&mut attr_values.ulps_tol_attrs, &nv, &parse_meta_list)?;
         } else if name == "debug_ulps_diff_derive" {
             set_float_eq_attr(
                 &mut attr_values.debug_ulps_diff_derive_types,
                 &nv,
-                &parse_ident_list,
+                &parse_path_list,
+            )?;
+        } else if name == "debug_ulps_diff_attr" {
+            set_float_eq_attr(
+                &mut attr_values.debug_ulps_diff_attrs,
+                &nv,
+                &parse_meta_list,
             )?;
         } else {
             let msg = format!(r"'{}' is not a valid float_eq derive option.", name);
@@ -218,13 +532,23 @@ fn parse_ident(value: &LitStr) -> Result<Ident, syn::Error> {
     value.parse::<Ident>()
 }
 
-fn parse_ident_list(value: &LitStr) -> Result<Vec<Ident>, syn::Error> {
-    Ok(value
-        .value()
-        .split(',')
-        .map(str::trim)
-        .map(|trait_name| Ident::new(trait_name, Span::call_site()))
-        .collect())
+fn parse_path(value: &LitStr) -> Result<Path, syn::Error> {
+    value.parse::<Path>()
+}
+
+fn parse_path_list(value: &LitStr) -> Result<Vec<Path>, syn::Error> {
+    let paths = value.parse_with(Punctuated::<Path, Comma>::parse_terminated)?;
+    Ok(paths.into_iter().collect())
+}
+
+fn parse_type_list(value: &LitStr) -> Result<Vec<Type>, syn::Error> {
+    let types = value.parse_with(Punctuated::<Type, Comma>::parse_terminated)?;
+    Ok(types.into_iter().collect())
+}
+
+fn parse_meta_list(value: &LitStr) -> Result<Vec<Meta>, syn::Error> {
+    let metas = value.parse_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+    Ok(metas.into_iter().collect())
 }
 
 fn name_value_pair_list(
@@ -253,7 +577,7 @@ pub fn name_value_pair(meta: &NestedMeta) -> Result<NameValuePair, syn::Error> {
             if let Lit::Str(value) = &nv.lit {
                 return Ok(NameValuePair {
                     name: name.clone(),
-                    value: value.clone(),
+                    value: LitStr::new(&value.value(), value.span()),
                 });
             }
         }
@@ -0,0 +1,276 @@
+//! Expansion of the derived traits comparing a newtype against other types,
+//! via `#[float_eq(rhs = "...")]`.
+//!
+//! Each `rhs` type is compared against the newtype's single field, so the
+//! associated types of the impls are those of the field's own comparison with
+//! it. For example, `Color([f32; 3])` compared against `[f32; 3]` is given
+//! tolerances of type `[f32; 3]`, rather than `Color`.
+
+use crate::generics;
+use crate::read::{self, FieldName, FloatEqAttr};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Type};
+
+/// Expand an impl for each of the `rhs` types of the input, given the name
+/// and type of its field and the type it is compared against. Expands to
+/// nothing if there are none.
+fn expand_impls<F>(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+    expand: F,
+) -> Result<TokenStream, syn::Error>
+where
+    F: Fn(&FieldName, &Type, &Type) -> TokenStream,
+{
+    let rhs_types = params.rhs_types();
+    if rhs_types.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let error = || {
+        let msg = "`rhs` may only be used with a struct that has a single compared field, such as a newtype.";
+        syn::Error::new(input.ident.span(), msg)
+    };
+    let fields = read::all_fields_info("`rhs`", input).map_err(|_| error())?;
+    let field = fields.newtype_field().ok_or_else(error)?;
+
+    let impls = rhs_types
+        .iter()
+        .map(|rhs| expand(&field.name, field.ty, rhs));
+    Ok(quote! { #(#impls)* })
+}
+
+/// `FloatEq` against each of the `rhs` types.
+pub fn expand_float_eq(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    expand_impls(input, params, |name, ty, rhs| {
+        let where_clause = generics::Bounds::new(input)
+            .always(quote! { #ty: float_eq::FloatEq<#rhs> })
+            .where_clause();
+        quote! {
+            impl #impl_generics float_eq::FloatEq<#rhs> for #struct_name #ty_generics #where_clause {
+                type Tol = <#ty as float_eq::FloatEq<#rhs>>::Tol;
+                const SUPPORTED_CHECKS: float_eq::CheckSet = <#ty as float_eq::FloatEq<#rhs>>::SUPPORTED_CHECKS;
+
+                #[inline]
+                fn eq_abs(&self, other: &#rhs, tol: &Self::Tol) -> bool {
+                    float_eq::FloatEq::eq_abs(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_rmax(&self, other: &#rhs, tol: &Self::Tol) -> bool {
+                    float_eq::FloatEq::eq_rmax(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_rmin(&self, other: &#rhs, tol: &Self::Tol) -> bool {
+                    float_eq::FloatEq::eq_rmin(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_r1st(&self, other: &#rhs, tol: &Self::Tol) -> bool {
+                    float_eq::FloatEq::eq_r1st(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_r2nd(&self, other: &#rhs, tol: &Self::Tol) -> bool {
+                    float_eq::FloatEq::eq_r2nd(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_ulps(&self, other: &#rhs, tol: &float_eq::UlpsTol<Self::Tol>) -> bool {
+                    float_eq::FloatEq::eq_ulps(&self.#name, other, tol)
+                }
+            }
+        }
+    })
+}
+
+/// `FloatEqAll` against each of the `rhs` types.
+pub fn expand_float_eq_all(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    expand_impls(input, params, |name, ty, rhs| {
+        let where_clause = generics::Bounds::new(input)
+            .always(quote! { #ty: float_eq::FloatEqAll<#rhs> })
+            .where_clause();
+        quote! {
+            impl #impl_generics float_eq::FloatEqAll<#rhs> for #struct_name #ty_generics #where_clause {
+                type AllTol = <#ty as float_eq::FloatEqAll<#rhs>>::AllTol;
+
+                #[inline]
+                fn eq_abs_all(&self, other: &#rhs, tol: &Self::AllTol) -> bool {
+                    float_eq::FloatEqAll::eq_abs_all(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_rmax_all(&self, other: &#rhs, tol: &Self::AllTol) -> bool {
+                    float_eq::FloatEqAll::eq_rmax_all(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_rmin_all(&self, other: &#rhs, tol: &Self::AllTol) -> bool {
+                    float_eq::FloatEqAll::eq_rmin_all(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_r1st_all(&self, other: &#rhs, tol: &Self::AllTol) -> bool {
+                    float_eq::FloatEqAll::eq_r1st_all(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_r2nd_all(&self, other: &#rhs, tol: &Self::AllTol) -> bool {
+                    float_eq::FloatEqAll::eq_r2nd_all(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn eq_ulps_all(&self, other: &#rhs, tol: &float_eq::UlpsTol<Self::AllTol>) -> bool {
+                    float_eq::FloatEqAll::eq_ulps_all(&self.#name, other, tol)
+                }
+            }
+        }
+    })
+}
+
+/// `AssertFloatEq` against each of the `rhs` types.
+#[cfg(feature = "assert")]
+pub fn expand_assert_float_eq(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    expand_impls(input, params, |name, ty, rhs| {
+        let where_clause = generics::Bounds::new(input)
+            .always(quote! { #ty: float_eq::AssertFloatEq<#rhs> })
+            .where_clause();
+        quote! {
+            impl #impl_generics float_eq::AssertFloatEq<#rhs> for #struct_name #ty_generics #where_clause {
+                type DebugAbsDiff = <#ty as float_eq::AssertFloatEq<#rhs>>::DebugAbsDiff;
+                type DebugTol = <#ty as float_eq::AssertFloatEq<#rhs>>::DebugTol;
+
+                #[inline]
+                fn debug_abs_diff(&self, other: &#rhs) -> Self::DebugAbsDiff {
+                    float_eq::AssertFloatEq::debug_abs_diff(&self.#name, other)
+                }
+
+                #[inline]
+                fn debug_ulps_diff(&self, other: &#rhs) -> float_eq::DebugUlpsDiff<Self::DebugAbsDiff> {
+                    float_eq::AssertFloatEq::debug_ulps_diff(&self.#name, other)
+                }
+
+                #[inline]
+                fn debug_categories(&self, other: &#rhs) -> ::core::option::Option<float_eq::DebugCategories> {
+                    float_eq::AssertFloatEq::debug_categories(&self.#name, other)
+                }
+
+                #[inline]
+                fn debug_ulps_diff_reason(&self, other: &#rhs) -> ::core::option::Option<float_eq::UlpsDiffReason> {
+                    float_eq::AssertFloatEq::debug_ulps_diff_reason(&self.#name, other)
+                }
+
+                #[inline]
+                fn debug_abs_tol(&self, other: &#rhs, tol: &Self::Tol) -> Self::DebugTol {
+                    float_eq::AssertFloatEq::debug_abs_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_rmax_tol(&self, other: &#rhs, tol: &Self::Tol) -> Self::DebugTol {
+                    float_eq::AssertFloatEq::debug_rmax_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_rmin_tol(&self, other: &#rhs, tol: &Self::Tol) -> Self::DebugTol {
+                    float_eq::AssertFloatEq::debug_rmin_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_r1st_tol(&self, other: &#rhs, tol: &Self::Tol) -> Self::DebugTol {
+                    float_eq::AssertFloatEq::debug_r1st_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_r2nd_tol(&self, other: &#rhs, tol: &Self::Tol) -> Self::DebugTol {
+                    float_eq::AssertFloatEq::debug_r2nd_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_ulps_tol(
+                    &self,
+                    other: &#rhs,
+                    tol: &float_eq::UlpsTol<Self::Tol>,
+                ) -> float_eq::UlpsTol<Self::DebugTol>
+                where
+                    float_eq::UlpsTol<Self::DebugTol>: ::core::marker::Sized,
+                {
+                    float_eq::AssertFloatEq::debug_ulps_tol(&self.#name, other, tol)
+                }
+            }
+        }
+    })
+}
+
+/// `AssertFloatEqAll` against each of the `rhs` types.
+#[cfg(feature = "assert")]
+pub fn expand_assert_float_eq_all(
+    input: &DeriveInput,
+    params: &FloatEqAttr,
+) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    expand_impls(input, params, |name, ty, rhs| {
+        let where_clause = generics::Bounds::new(input)
+            .always(quote! { #ty: float_eq::AssertFloatEqAll<#rhs> })
+            .where_clause();
+        quote! {
+            impl #impl_generics float_eq::AssertFloatEqAll<#rhs> for #struct_name #ty_generics #where_clause {
+                type AllDebugTol = <#ty as float_eq::AssertFloatEqAll<#rhs>>::AllDebugTol;
+
+                #[inline]
+                fn debug_abs_all_tol(&self, other: &#rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                    float_eq::AssertFloatEqAll::debug_abs_all_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_rmax_all_tol(&self, other: &#rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                    float_eq::AssertFloatEqAll::debug_rmax_all_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_rmin_all_tol(&self, other: &#rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                    float_eq::AssertFloatEqAll::debug_rmin_all_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_r1st_all_tol(&self, other: &#rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                    float_eq::AssertFloatEqAll::debug_r1st_all_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_r2nd_all_tol(&self, other: &#rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                    float_eq::AssertFloatEqAll::debug_r2nd_all_tol(&self.#name, other, tol)
+                }
+
+                #[inline]
+                fn debug_ulps_all_tol(
+                    &self,
+                    other: &#rhs,
+                    tol: &float_eq::UlpsTol<Self::AllTol>,
+                ) -> float_eq::UlpsTol<Self::AllDebugTol>
+                where
+                    float_eq::UlpsTol<Self::AllDebugTol>: ::core::marker::Sized,
+                {
+                    float_eq::AssertFloatEqAll::debug_ulps_all_tol(&self.#name, other, tol)
+                }
+            }
+        }
+    })
+}